@@ -0,0 +1,39 @@
+use alloc::string::ToString;
+
+#[cfg(feature = "http")]
+use http::Error as HTTPError;
+#[cfg(feature = "std")]
+use std::io::Error as IoError;
+
+use crate::error::{ErrorCode, JsonResponseError, ResponseError};
+
+impl<E: ErrorCode> From<E> for JsonResponseError {
+    fn from(error: E) -> Self {
+        JsonResponseError::new()
+            .code(error.as_code())
+            .message(error.as_message())
+    }
+}
+
+impl From<serde_json::Error> for JsonResponseError {
+    fn from(error: serde_json::Error) -> Self {
+        JsonResponseError::new()
+            .code(ResponseError::Parse.as_code())
+            .message(error.to_string())
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<IoError> for JsonResponseError {
+    fn from(_: IoError) -> Self {
+        // hide the detail as it may contain internal information
+        JsonResponseError::from(ResponseError::Io)
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<HTTPError> for JsonResponseError {
+    fn from(_: HTTPError) -> Self {
+        JsonResponseError::from(ResponseError::Server)
+    }
+}
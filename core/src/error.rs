@@ -0,0 +1,432 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Error code with a default message,
+/// implemented by [`ResponseError`] and [`CustomError`].
+///
+/// Downstream crates can implement it for their own errors,
+/// which can be converted into [`JsonResponseError`]
+/// like the built-in errors.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_core::{ErrorCode, JsonResponseError};
+///
+/// enum OrderError {
+///     Locked,
+/// }
+///
+/// impl ErrorCode for OrderError {
+///     fn as_code(&self) -> &str {
+///         match self {
+///             | Self::Locked => "order_locked",
+///         }
+///     }
+///
+///     fn as_message(&self) -> &str {
+///         match self {
+///             | Self::Locked => "Order is locked",
+///         }
+///     }
+///
+///     fn status(&self) -> Option<u16> {
+///         Some(409)
+///     }
+/// }
+///
+/// let error: JsonResponseError = JsonResponseError::from(OrderError::Locked);
+/// ```
+pub trait ErrorCode {
+    /// Get the error code as `&str`.
+    fn as_code(&self) -> &str;
+
+    /// Get the default error message as `&str`.
+    fn as_message(&self) -> &str;
+
+    /// Get the suggested status code of the response, if any.
+    fn status(&self) -> Option<u16> {
+        None
+    }
+}
+
+/// Response error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResponseError {
+    /// Error while parsing.
+    Parse,
+    /// Payload too large.
+    TooLarge,
+    /// Unsupported media type of the request body.
+    UnsupportedMediaType,
+    /// Error while reading the request body,
+    /// such as the client is disconnected.
+    BodyRead,
+    /// Unsupported or invalid content encoding of the request body.
+    Encoding,
+    /// Timeout error.
+    Timeout,
+    /// Invalid response from an upstream server,
+    /// such as a reverse-proxied service.
+    BadGateway,
+    /// Service temporarily unavailable,
+    /// such as an overloaded upstream server.
+    Unavailable,
+    /// Conflict with the current state of the resource.
+    Conflict,
+    /// Content rejected by inspection,
+    /// such as a virus found in an uploaded file.
+    ContentRejected,
+    /// Input/output error.
+    Io,
+    /// Validation failed.
+    Validation,
+    /// Missing or invalid credentials.
+    Unauthorized,
+    /// Insufficient permissions for the resource.
+    Forbidden,
+    /// Resource not found.
+    NotFound,
+    /// Method not allowed for the resource.
+    MethodNotAllowed,
+    /// Too many requests.
+    TooManyRequests,
+    /// Server is draining for a shutdown,
+    /// such as a deployment.
+    Draining,
+    /// Internal server error.
+    Server,
+    /// Unknown error.
+    Unknown,
+}
+
+impl ResponseError {
+    // Create a new response error with default value.
+    pub fn new() -> Self {
+        Self::Unknown
+    }
+
+    /// Get the error code as `&str`.
+    pub fn as_code(&self) -> &str {
+        match self {
+            | Self::Parse => "parse",
+            | Self::TooLarge => "too_large",
+            | Self::UnsupportedMediaType => "unsupported_media_type",
+            | Self::BodyRead => "body_read",
+            | Self::Encoding => "encoding",
+            | Self::Timeout => "timeout",
+            | Self::BadGateway => "bad_gateway",
+            | Self::Unavailable => "unavailable",
+            | Self::Conflict => "conflict",
+            | Self::ContentRejected => "content_rejected",
+            | Self::Io => "io",
+            | Self::Validation => "validation",
+            | Self::Unauthorized => "unauthorized",
+            | Self::Forbidden => "forbidden",
+            | Self::NotFound => "not_found",
+            | Self::MethodNotAllowed => "method_not_allowed",
+            | Self::TooManyRequests => "too_many_requests",
+            | Self::Draining => "draining",
+            | Self::Server => "server",
+            | Self::Unknown => "unknown",
+        }
+    }
+
+    /// Get the error code as `String`.
+    pub fn to_code(&self) -> String {
+        self.as_code().to_string()
+    }
+
+    /// Get the error message as `&str`.
+    pub fn as_message(&self) -> &str {
+        match self {
+            | Self::Parse => "Failed to parse the request",
+            | Self::TooLarge => "Request body is too large",
+            | Self::UnsupportedMediaType => "Unsupported media type",
+            | Self::BodyRead => "Failed to read the request body",
+            | Self::Encoding => "Failed to decode the request body",
+            | Self::Timeout => "Gateway timeout",
+            | Self::BadGateway => "Bad gateway",
+            | Self::Unavailable => "Service unavailable",
+            | Self::Conflict => {
+                "Conflict with the current state of the resource"
+            },
+            | Self::ContentRejected => "Content rejected by inspection",
+            | Self::Io => "Input/output error",
+            | Self::Validation => "Validation failed",
+            | Self::Unauthorized => "Unauthorized",
+            | Self::Forbidden => "Forbidden",
+            | Self::NotFound => "Resource not found",
+            | Self::MethodNotAllowed => "Method not allowed",
+            | Self::TooManyRequests => "Too many requests",
+            | Self::Draining => "Server is draining",
+            | Self::Server => "Internal server error",
+            | Self::Unknown => "Unknown error",
+        }
+    }
+
+    /// Get the error message as `String`.
+    pub fn to_message(&self) -> String {
+        self.as_message().to_string()
+    }
+
+    /// Get the suggested status code of the response,
+    /// available with `http` feature.
+    ///
+    /// [`Unknown`](ResponseError::Unknown) has no suggested status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use http::StatusCode;
+    /// use jder_core::ResponseError;
+    ///
+    /// assert_eq!(
+    ///     ResponseError::Forbidden.as_status(),
+    ///     Some(StatusCode::FORBIDDEN),
+    /// );
+    /// ```
+    #[cfg(feature = "http")]
+    pub fn as_status(&self) -> Option<http::StatusCode> {
+        ErrorCode::status(self)
+            .and_then(|status| http::StatusCode::from_u16(status).ok())
+    }
+}
+
+impl Default for ResponseError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorCode for ResponseError {
+    fn as_code(&self) -> &str {
+        ResponseError::as_code(self)
+    }
+
+    fn as_message(&self) -> &str {
+        ResponseError::as_message(self)
+    }
+
+    fn status(&self) -> Option<u16> {
+        match self {
+            | Self::Parse | Self::Encoding | Self::BodyRead => Some(400),
+            | Self::TooLarge => Some(413),
+            | Self::UnsupportedMediaType => Some(415),
+            | Self::Timeout => Some(504),
+            | Self::BadGateway => Some(502),
+            | Self::Unavailable => Some(503),
+            | Self::Conflict => Some(409),
+            | Self::ContentRejected | Self::Validation => Some(422),
+            | Self::Unauthorized => Some(401),
+            | Self::Forbidden => Some(403),
+            | Self::NotFound => Some(404),
+            | Self::MethodNotAllowed => Some(405),
+            | Self::TooManyRequests => Some(429),
+            | Self::Draining => Some(503),
+            | Self::Io | Self::Server => Some(500),
+            | Self::Unknown => None,
+        }
+    }
+}
+
+/// Custom error code with a default message,
+/// which can be defined as a constant.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_core::{CustomError, JsonResponseError};
+///
+/// const ORDER_LOCKED: CustomError =
+///     CustomError::new("order_locked", "Order is locked").status(409);
+///
+/// let error: JsonResponseError = JsonResponseError::from(ORDER_LOCKED);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomError {
+    /// Code representing the error.
+    code: &'static str,
+    /// Default message of the error.
+    message: &'static str,
+    /// Suggested status code of the response.
+    status: Option<u16>,
+}
+
+impl CustomError {
+    /// Create a new custom error with a code and a default message.
+    pub const fn new(
+        code: &'static str,
+        message: &'static str,
+    ) -> Self {
+        Self { code, message, status: None }
+    }
+
+    /// Set the suggested status code of the response.
+    pub const fn status(
+        mut self,
+        status: u16,
+    ) -> Self {
+        self.status = Some(status);
+
+        self
+    }
+}
+
+impl ErrorCode for CustomError {
+    fn as_code(&self) -> &str {
+        self.code
+    }
+
+    fn as_message(&self) -> &str {
+        self.message
+    }
+
+    fn status(&self) -> Option<u16> {
+        self.status
+    }
+}
+
+/// JSON response error.
+///
+/// [`ResponseError`] and [`serde_json::Error`] can be converted into it,
+/// as well as `std::io::Error` with `std` feature
+/// and `http::Error` with `http` feature,
+/// with `parse`, `io` and `server` codes,
+/// where the details of I/O and HTTP errors are hidden.
+///
+/// For API documentation generation with utoipa,
+/// `ToSchema` derive is available with the `utoipa` feature.
+///
+/// For TypeScript type generation with ts-rs,
+/// `TS` derive is available with the `ts_export` feature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "ts_export", derive(ts_rs::TS))]
+pub struct JsonResponseError {
+    /// Code representing the error.
+    pub code: String,
+    /// Indicates where the error occurred.
+    pub path: Vec<String>,
+    /// Detail of the error.
+    pub message: Option<String>,
+}
+
+impl JsonResponseError {
+    /// Create a new JSON response error.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::JsonResponseError;
+    ///
+    /// let error: JsonResponseError = JsonResponseError::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            code: ResponseError::new().to_code(),
+            path: Vec::new(),
+            message: None,
+        }
+    }
+
+    /// Create a new JSON response error from an existing error.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::JsonResponseError;
+    ///
+    /// let error: JsonResponseError = JsonResponseError::new();
+    ///
+    /// let error: JsonResponseError = JsonResponseError::from(error);
+    /// ```
+    pub fn from<E: Into<JsonResponseError>>(error: E) -> Self {
+        let err: JsonResponseError = error.into();
+
+        Self { code: err.code, path: err.path, message: err.message }
+    }
+
+    /// A builder function to create a JSON response error.
+    #[deprecated = "Use `new` function instead"]
+    pub fn builder() -> Self {
+        Self::new()
+    }
+
+    /// Set an error code for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::JsonResponseError;
+    ///
+    /// let error: JsonResponseError = JsonResponseError::new()
+    ///     .code("parse");
+    /// ```
+    pub fn code<Code: Into<String>>(
+        mut self,
+        code: Code,
+    ) -> Self {
+        self.code = code.into();
+
+        self
+    }
+
+    /// Set an error path for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::JsonResponseError;
+    ///
+    /// let error: JsonResponseError = JsonResponseError::new()
+    ///     .path(["json", "title"]);
+    /// ```
+    pub fn path<P, S>(
+        mut self,
+        path: P,
+    ) -> Self
+    where
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.path = path.into_iter().map(|s| s.into()).collect();
+        self
+    }
+
+    /// Set an error message for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::JsonResponseError;
+    ///
+    /// let error: JsonResponseError = JsonResponseError::new()
+    ///     .message("Invalid title");
+    /// ```
+    pub fn message<Message: Into<String>>(
+        mut self,
+        message: Message,
+    ) -> Self {
+        self.message = Some(message.into());
+
+        self
+    }
+
+    /// Build the JSON response error.
+    #[deprecated = "No longer needed"]
+    pub fn build(self) -> Self {
+        self
+    }
+}
+
+impl Default for JsonResponseError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,46 @@
+//! # JDER core
+//!
+//! Response types of JDER without a server stack.
+//!
+//! This crate includes the envelope types of the JSON response structure
+//! specified in
+//! [JSON Data Errors Response (JDER)](https://github.com/jderstd/spec),
+//! which are shared with [jder_axum](https://docs.rs/jder_axum),
+//! so client applications and shared contract crates
+//! can depend on the types without axum or http.
+//!
+//! The crate is `no_std` with `alloc`
+//! when the default `std` feature is disabled.
+//!
+//! ## Usage
+//!
+//! To parse a response in a client application, use
+//! [`JsonResponse`]:
+//!
+//! ```no_run
+//! use jder_core::{JsonResponse, ResponseError};
+//!
+//! let body: &str = r#"{
+//!     "success": false,
+//!     "data": null,
+//!     "errors": [{ "code": "parse", "path": ["json", "name"], "message": null }]
+//! }"#;
+//!
+//! let response: JsonResponse = serde_json::from_str(body).unwrap();
+//!
+//! assert_eq!(response.errors[0].code, ResponseError::Parse.as_code());
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub(crate) mod convert;
+pub(crate) mod error;
+pub(crate) mod response;
+
+pub use crate::error::{
+    CustomError, ErrorCode, JsonResponseError, ResponseError,
+};
+
+pub use crate::response::JsonResponse;
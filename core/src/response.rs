@@ -0,0 +1,164 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::JsonResponseError;
+
+/// JSON response.
+///
+/// The envelope of a JDER response without the status code,
+/// which can be serialized and deserialized
+/// without a server stack.
+///
+/// For API documentation generation with utoipa,
+/// `ToSchema` derive is available with the `utoipa` feature.
+///
+/// For TypeScript type generation with ts-rs,
+/// `TS` derive is available with the `ts_export` feature,
+/// where the data type should implement `TS`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_core::JsonResponse;
+///
+/// let body: &str = r#"{"success":true,"data":"Name","errors":[]}"#;
+///
+/// let response: JsonResponse<String> = serde_json::from_str(body).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "ts_export", derive(ts_rs::TS))]
+pub struct JsonResponse<D = ()> {
+    /// Indicates whether the response is successful or not.
+    pub success: bool,
+    /// Requested information for the response when `success` is `true`.
+    pub data: Option<D>,
+    /// A list of errors for the response when `success` is `false`.
+    pub errors: Vec<JsonResponseError>,
+    /// Additional information for the response,
+    /// such as pagination, request id or timing.
+    ///
+    /// Omitted from the response when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
+    #[cfg_attr(
+        feature = "ts_export",
+        ts(optional, type = "Record<string, unknown>")
+    )]
+    pub meta: Option<Map<String, Value>>,
+}
+
+impl<D> JsonResponse<D> {
+    /// Create a new JSON response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::JsonResponse;
+    ///
+    /// let response: JsonResponse = JsonResponse::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { success: true, data: None, errors: vec![], meta: None }
+    }
+
+    /// Set the success status for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::JsonResponse;
+    ///
+    /// let response: JsonResponse = JsonResponse::new()
+    ///     .success(false);
+    /// ```
+    pub fn success(
+        mut self,
+        success: bool,
+    ) -> Self {
+        self.success = success;
+
+        self
+    }
+
+    /// Set the data for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::JsonResponse;
+    ///
+    /// let response: JsonResponse<String> = JsonResponse::new()
+    ///     .data("Name".to_string());
+    /// ```
+    pub fn data(
+        mut self,
+        data: D,
+    ) -> Self {
+        self.data = Some(data);
+
+        self
+    }
+
+    /// Set the errors for the response.
+    ///
+    /// This will overwrite any existing errors.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::{JsonResponse, JsonResponseError};
+    ///
+    /// let response: JsonResponse = JsonResponse::new()
+    ///     .success(false)
+    ///     .errors([
+    ///         JsonResponseError::new()
+    ///             .code("parse")
+    ///             .path(["json", "name"]),
+    ///     ]);
+    /// ```
+    pub fn errors<V, E>(
+        mut self,
+        errors: V,
+    ) -> Self
+    where
+        V: IntoIterator<Item = E>,
+        E: Into<JsonResponseError>,
+    {
+        self.errors = errors.into_iter().map(|e| e.into()).collect();
+
+        self
+    }
+
+    /// Set the meta for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_core::JsonResponse;
+    /// use serde_json::{Map, Value};
+    ///
+    /// let mut meta: Map<String, Value> = Map::new();
+    ///
+    /// meta.insert("request_id".to_string(), Value::from("abc"));
+    ///
+    /// let response: JsonResponse = JsonResponse::new()
+    ///     .meta(meta);
+    /// ```
+    pub fn meta(
+        mut self,
+        meta: Map<String, Value>,
+    ) -> Self {
+        self.meta = Some(meta);
+
+        self
+    }
+}
+
+impl Default for JsonResponse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
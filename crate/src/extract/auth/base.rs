@@ -0,0 +1,220 @@
+use std::fmt::{self, Debug, Formatter};
+
+use axum_core::extract::FromRequestParts;
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use http::{HeaderValue, StatusCode, header, request::Parts};
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
+};
+
+/// Get the credentials of the `Authorization` header
+/// with a scheme, such as `Bearer`, case-insensitively.
+pub(crate) fn get_credentials_fn<'a>(
+    parts: &'a Parts,
+    scheme: &'static str,
+) -> Result<&'a str, &'static str> {
+    let value: &HeaderValue = match parts.headers.get(header::AUTHORIZATION) {
+        | Some(value) => value,
+        | None => return Err("Missing header `authorization`"),
+    };
+
+    let value: &str = match value.to_str() {
+        | Ok(value) => value,
+        | Err(_) => return Err("Invalid header `authorization`"),
+    };
+
+    match value.split_once(' ') {
+        | Some((name, credentials))
+            if name.eq_ignore_ascii_case(scheme)
+                && !credentials.trim().is_empty() =>
+        {
+            Ok(credentials.trim())
+        },
+        | _ => Err("Invalid header `authorization`"),
+    }
+}
+
+/// Create an unauthorized failure response,
+/// with the `WWW-Authenticate` header of the scheme
+/// and the header in path, such as `["header", "authorization"]`.
+fn create_unauthorized_rejection_fn(
+    scheme: &'static str,
+    message: &str,
+) -> Response {
+    CreateJsonResponse::rejection()
+        .status(StatusCode::UNAUTHORIZED)
+        .header(header::WWW_AUTHENTICATE, scheme)
+        .add_error(
+            JsonResponseError::from(ResponseError::Unauthorized)
+                .path(["header", "authorization"])
+                .message(message),
+        )
+        .create()
+}
+
+/// Extractor that gets the token
+/// of an `Authorization: Bearer <token>` header,
+/// available with `auth` feature.
+///
+/// Missing or malformed headers are rejected
+/// with `401 Unauthorized` failure responses,
+/// the `WWW-Authenticate: Bearer` header and `unauthorized` code.
+///
+/// The token is redacted in the debug output.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::extract::auth::BearerToken;
+///
+/// async fn route(BearerToken(token): BearerToken) {
+///     // ...
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BearerToken(pub String);
+
+impl BearerToken {
+    /// Get the token as `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for BearerToken {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_tuple("BearerToken").field(&"[redacted]").finish()
+    }
+}
+
+impl<S> FromRequestParts<S> for BearerToken
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.bearer_token", async move {
+            match get_credentials_fn(parts, "Bearer") {
+                | Ok(token) => Ok(Self(token.to_string())),
+                | Err(message) => {
+                    Err(create_unauthorized_rejection_fn("Bearer", message))
+                },
+            }
+        })
+    }
+}
+
+/// Extractor that gets the username and the password
+/// of an `Authorization: Basic <credentials>` header,
+/// available with `auth` feature.
+///
+/// Missing or malformed headers, such as the credentials
+/// are not base64 encoded `username:password`,
+/// are rejected with `401 Unauthorized` failure responses,
+/// the `WWW-Authenticate: Basic` header and `unauthorized` code.
+///
+/// The password is redacted in the debug output.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::extract::auth::BasicAuth;
+///
+/// async fn route(auth: BasicAuth) {
+///     let username: &str = auth.username();
+///     let password: &str = auth.password();
+///
+///     // ...
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BasicAuth {
+    username: String,
+    password: String,
+}
+
+impl BasicAuth {
+    /// Get the username.
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// Get the password.
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+
+    /// Get the username and the password.
+    pub fn into_parts(self) -> (String, String) {
+        (self.username, self.password)
+    }
+}
+
+impl Debug for BasicAuth {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .finish()
+    }
+}
+
+/// Decode the base64 encoded `username:password` credentials.
+fn decode_basic_fn(credentials: &str) -> Option<BasicAuth> {
+    let decoded: Vec<u8> = STANDARD.decode(credentials).ok()?;
+
+    let decoded: String = String::from_utf8(decoded).ok()?;
+
+    let (username, password) = decoded.split_once(':')?;
+
+    Some(BasicAuth {
+        username: username.to_string(),
+        password: password.to_string(),
+    })
+}
+
+impl<S> FromRequestParts<S> for BasicAuth
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.basic_auth", async move {
+            let credentials: &str = match get_credentials_fn(parts, "Basic") {
+                | Ok(credentials) => credentials,
+                | Err(message) => {
+                    return Err(create_unauthorized_rejection_fn(
+                        "Basic", message,
+                    ));
+                },
+            };
+
+            match decode_basic_fn(credentials) {
+                | Some(auth) => Ok(auth),
+                | None => Err(create_unauthorized_rejection_fn(
+                    "Basic",
+                    "Invalid credentials of header `authorization`",
+                )),
+            }
+        })
+    }
+}
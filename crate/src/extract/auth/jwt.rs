@@ -0,0 +1,599 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use axum_core::extract::{FromRef, FromRequestParts};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use http::{StatusCode, header, request::Parts};
+#[cfg(feature = "jwt_rs256")]
+use rsa::{
+    RsaPublicKey,
+    pkcs1::DecodeRsaPublicKey,
+    pkcs1v15::{Signature, VerifyingKey},
+    pkcs8::DecodePublicKey,
+    signature::Verifier as _,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+#[cfg(feature = "jwt_rs256")]
+use sha2::Sha256;
+
+use crate::{
+    extract::{auth::base::get_credentials_fn, trace::trace_extract},
+    hmac::verify_sha256,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
+};
+
+/// Signing algorithm of a JWT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JwtAlgorithm {
+    /// HMAC with SHA-256.
+    Hs256,
+    /// RSASSA-PKCS1-v1_5 with SHA-256,
+    /// verified by an RSA public key or a [`JwtVerifier`].
+    Rs256,
+}
+
+impl JwtAlgorithm {
+    /// Get the algorithm name in JWT header as `&str`, such as `HS256`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            | Self::Hs256 => "HS256",
+            | Self::Rs256 => "RS256",
+        }
+    }
+}
+
+/// Verifier of JWT signatures for [`JwtAlgorithm::Rs256`],
+/// such as a verifier with a key kept in a hardware security module.
+///
+/// For RSA public keys in PEM, see
+/// [`JwtConfig::rs256_pem`] with `jwt_rs256` feature.
+///
+/// Functions and closures taking the signed message and the signature
+/// and returning whether the signature is valid are verifiers.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::extract::auth::JwtConfig;
+///
+/// let config: JwtConfig = JwtConfig::rs256(|message: &[u8], signature: &[u8]| {
+///     // verify with the public key
+///     false
+/// });
+/// ```
+pub trait JwtVerifier: Send + Sync {
+    /// Verify the signature of a message,
+    /// where the message is `<header>.<payload>` of the token.
+    fn verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+    ) -> bool;
+}
+
+impl<F> JwtVerifier for F
+where
+    F: Fn(&[u8], &[u8]) -> bool + Send + Sync,
+{
+    fn verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+    ) -> bool {
+        self(message, signature)
+    }
+}
+
+/// Key for verifying JWT signatures.
+#[derive(Clone)]
+enum JwtKey {
+    /// Shared secret of HMAC.
+    Secret(Arc<[u8]>),
+    /// RSA public key.
+    #[cfg(feature = "jwt_rs256")]
+    Rsa(Arc<VerifyingKey<Sha256>>),
+    /// Verifier of signatures.
+    Verifier(Arc<dyn JwtVerifier>),
+}
+
+/// JWT error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JwtError {
+    /// The token is missing.
+    MissingToken,
+    /// The token is malformed or the claims are invalid.
+    InvalidToken,
+    /// The signature of the token does not match.
+    InvalidSignature,
+    /// The token is expired.
+    Expired,
+}
+
+impl JwtError {
+    /// Get the error code as `&str`.
+    pub fn as_code(&self) -> &str {
+        match self {
+            | Self::MissingToken => "missing_token",
+            | Self::InvalidToken => "invalid_token",
+            | Self::InvalidSignature => "invalid_signature",
+            | Self::Expired => "expired",
+        }
+    }
+
+    /// Get the error message as `&str`.
+    pub fn as_message(&self) -> &str {
+        match self {
+            | Self::MissingToken => "Missing bearer token",
+            | Self::InvalidToken => "Invalid token",
+            | Self::InvalidSignature => "Invalid token signature",
+            | Self::Expired => "Token is expired",
+        }
+    }
+}
+
+impl fmt::Display for JwtError {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str(self.as_message())
+    }
+}
+
+impl std::error::Error for JwtError {}
+
+impl From<JwtError> for JsonResponseError {
+    fn from(error: JwtError) -> Self {
+        JsonResponseError::new()
+            .code(error.as_code())
+            .path(["header", "authorization"])
+            .message(error.as_message())
+    }
+}
+
+/// Error of an invalid RSA public key,
+/// available with `jwt_rs256` feature.
+#[cfg(feature = "jwt_rs256")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JwtKeyError;
+
+#[cfg(feature = "jwt_rs256")]
+impl fmt::Display for JwtKeyError {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str("Invalid RSA public key")
+    }
+}
+
+#[cfg(feature = "jwt_rs256")]
+impl std::error::Error for JwtKeyError {}
+
+/// Get the current time in seconds since Unix epoch.
+fn now_fn() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// Decode a base64url segment of a token.
+fn decode_segment_fn(segment: &str) -> Result<Vec<u8>, JwtError> {
+    URL_SAFE_NO_PAD.decode(segment).map_err(|_| JwtError::InvalidToken)
+}
+
+/// Decode a base64url JSON segment of a token.
+fn decode_json_segment_fn(segment: &str) -> Result<Value, JwtError> {
+    serde_json::from_slice(&decode_segment_fn(segment)?)
+        .map_err(|_| JwtError::InvalidToken)
+}
+
+/// Configuration of [`Jwt`] extractor,
+/// added to the router with an [`Extension`](axum::Extension),
+/// or of [`StateJwt`] extractor from the router state.
+///
+/// The `exp` and `nbf` claims are checked if present,
+/// with a leeway of 0 seconds by default.
+/// A token is expired at the time of `exp` claim.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Extension, Router};
+/// use jder_axum::extract::auth::JwtConfig;
+///
+/// let router: Router = Router::new().layer(Extension(
+///     JwtConfig::hs256("secret").issuer("https://auth.example.com").leeway(30),
+/// ));
+/// ```
+#[derive(Clone)]
+pub struct JwtConfig {
+    /// Signing algorithm.
+    algorithm: JwtAlgorithm,
+    /// Key for verifying signatures.
+    key: JwtKey,
+    /// Leeway of `exp` and `nbf` claims in seconds.
+    leeway: u64,
+    /// Required `iss` claim.
+    issuer: Option<String>,
+    /// Required `aud` claim.
+    audience: Option<String>,
+}
+
+impl JwtConfig {
+    /// Create a new configuration with [`JwtAlgorithm::Hs256`]
+    /// and a shared secret.
+    pub fn hs256<K: Into<Vec<u8>>>(secret: K) -> Self {
+        Self::new(JwtAlgorithm::Hs256, JwtKey::Secret(secret.into().into()))
+    }
+
+    /// Create a new configuration with [`JwtAlgorithm::Rs256`]
+    /// and a verifier of signatures.
+    pub fn rs256<V: JwtVerifier + 'static>(verifier: V) -> Self {
+        Self::new(JwtAlgorithm::Rs256, JwtKey::Verifier(Arc::new(verifier)))
+    }
+
+    /// Create a new configuration with [`JwtAlgorithm::Rs256`]
+    /// and an RSA public key in PEM,
+    /// either `PUBLIC KEY` or `RSA PUBLIC KEY`,
+    /// available with `jwt_rs256` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::auth::{JwtConfig, JwtKeyError};
+    ///
+    /// let pem: String = std::fs::read_to_string("public.pem").unwrap();
+    ///
+    /// let config: Result<JwtConfig, JwtKeyError> = JwtConfig::rs256_pem(&pem);
+    /// ```
+    #[cfg(feature = "jwt_rs256")]
+    pub fn rs256_pem<P: AsRef<str>>(pem: P) -> Result<Self, JwtKeyError> {
+        let pem: &str = pem.as_ref().trim();
+
+        let key: RsaPublicKey = RsaPublicKey::from_public_key_pem(pem)
+            .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+            .map_err(|_| JwtKeyError)?;
+
+        Ok(Self::new(
+            JwtAlgorithm::Rs256,
+            JwtKey::Rsa(Arc::new(VerifyingKey::new(key))),
+        ))
+    }
+
+    /// Create a new configuration with an algorithm and a key.
+    fn new(
+        algorithm: JwtAlgorithm,
+        key: JwtKey,
+    ) -> Self {
+        Self { algorithm, key, leeway: 0, issuer: None, audience: None }
+    }
+
+    /// Set the leeway of `exp` and `nbf` claims in seconds.
+    pub fn leeway(
+        mut self,
+        seconds: u64,
+    ) -> Self {
+        self.leeway = seconds;
+
+        self
+    }
+
+    /// Require the `iss` claim.
+    pub fn issuer<I: Into<String>>(
+        mut self,
+        issuer: I,
+    ) -> Self {
+        self.issuer = Some(issuer.into());
+
+        self
+    }
+
+    /// Require the `aud` claim to be or to contain an audience.
+    pub fn audience<A: Into<String>>(
+        mut self,
+        audience: A,
+    ) -> Self {
+        self.audience = Some(audience.into());
+
+        self
+    }
+
+    /// Get the signing algorithm.
+    pub fn algorithm(&self) -> JwtAlgorithm {
+        self.algorithm
+    }
+
+    /// Verify the signature of a message.
+    fn verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+    ) -> bool {
+        match &self.key {
+            | JwtKey::Secret(secret) => {
+                verify_sha256(secret, message, signature)
+            },
+            #[cfg(feature = "jwt_rs256")]
+            | JwtKey::Rsa(key) => Signature::try_from(signature)
+                .is_ok_and(|signature| key.verify(message, &signature).is_ok()),
+            | JwtKey::Verifier(verifier) => verifier.verify(message, signature),
+        }
+    }
+
+    /// Check the registered claims of a payload.
+    fn check_claims(
+        &self,
+        payload: &Value,
+    ) -> Result<(), JwtError> {
+        let now: u64 = now_fn();
+
+        if let Some(exp) = payload.get("exp") {
+            let exp: u64 = exp.as_u64().ok_or(JwtError::InvalidToken)?;
+
+            if now >= exp.saturating_add(self.leeway) {
+                return Err(JwtError::Expired);
+            }
+        }
+
+        if let Some(nbf) = payload.get("nbf") {
+            let nbf: u64 = nbf.as_u64().ok_or(JwtError::InvalidToken)?;
+
+            if now.saturating_add(self.leeway) < nbf {
+                return Err(JwtError::InvalidToken);
+            }
+        }
+
+        if let Some(issuer) = &self.issuer {
+            if payload.get("iss").and_then(Value::as_str) != Some(issuer) {
+                return Err(JwtError::InvalidToken);
+            }
+        }
+
+        if let Some(audience) = &self.audience {
+            let is_matched: bool = match payload.get("aud") {
+                | Some(Value::String(aud)) => aud == audience,
+                | Some(Value::Array(auds)) => {
+                    auds.iter().any(|aud| aud.as_str() == Some(audience))
+                },
+                | _ => false,
+            };
+
+            if !is_matched {
+                return Err(JwtError::InvalidToken);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode and validate a token into claims.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::auth::{JwtConfig, JwtError};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Claims {
+    ///     sub: String,
+    /// }
+    ///
+    /// let config: JwtConfig = JwtConfig::hs256("secret");
+    ///
+    /// let claims: Result<Claims, JwtError> = config.decode("...");
+    /// ```
+    pub fn decode<C: DeserializeOwned>(
+        &self,
+        token: &str,
+    ) -> Result<C, JwtError> {
+        let (message, signature) =
+            token.rsplit_once('.').ok_or(JwtError::InvalidToken)?;
+
+        let (header, payload) =
+            message.split_once('.').ok_or(JwtError::InvalidToken)?;
+
+        let header: Value = decode_json_segment_fn(header)?;
+
+        if header.get("alg").and_then(Value::as_str)
+            != Some(self.algorithm.as_str())
+        {
+            return Err(JwtError::InvalidToken);
+        }
+
+        let signature: Vec<u8> = decode_segment_fn(signature)?;
+
+        if !self.verify(message.as_bytes(), &signature) {
+            return Err(JwtError::InvalidSignature);
+        }
+
+        let payload: Value = decode_json_segment_fn(payload)?;
+
+        self.check_claims(&payload)?;
+
+        serde_json::from_value(payload).map_err(|_| JwtError::InvalidToken)
+    }
+}
+
+impl Debug for JwtConfig {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("JwtConfig")
+            .field("algorithm", &self.algorithm)
+            .field("leeway", &self.leeway)
+            .field("issuer", &self.issuer)
+            .field("audience", &self.audience)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Create an unauthorized failure response from a JWT error,
+/// with `WWW-Authenticate` header.
+fn create_jwt_rejection_fn(error: JwtError) -> Response {
+    let challenge: &str = match error {
+        | JwtError::MissingToken => "Bearer",
+        | _ => "Bearer error=\"invalid_token\"",
+    };
+
+    CreateJsonResponse::rejection()
+        .status(StatusCode::UNAUTHORIZED)
+        .header(header::WWW_AUTHENTICATE, challenge)
+        .add_error(error)
+        .create()
+}
+
+/// Decode and validate the JWT of a request into claims.
+fn extract_jwt_fn<C: DeserializeOwned>(
+    parts: &Parts,
+    config: &JwtConfig,
+) -> Result<C, JwtError> {
+    let token: &str = match get_credentials_fn(parts, "Bearer") {
+        | Ok(token) => token,
+        | Err(_) if !parts.headers.contains_key(header::AUTHORIZATION) => {
+            return Err(JwtError::MissingToken);
+        },
+        | Err(_) => return Err(JwtError::InvalidToken),
+    };
+
+    config.decode::<C>(token)
+}
+
+/// Extractor that decodes and validates the JWT
+/// of an `Authorization: Bearer <token>` header into claims,
+/// available with `jwt` feature.
+///
+/// The [`JwtConfig`] is required as an [`Extension`](axum::Extension),
+/// or the extraction fails with a server error.
+/// For the configuration from the router state, see [`StateJwt`].
+///
+/// Missing or invalid tokens are rejected
+/// with `401 Unauthorized` failure responses,
+/// and the codes of [`JwtError`], such as `expired`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Extension, Router, routing::get};
+/// use jder_axum::extract::auth::{Jwt, JwtConfig};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Claims {
+///     sub: String,
+/// }
+///
+/// async fn route(Jwt(claims): Jwt<Claims>) {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .layer(Extension(JwtConfig::hs256("secret")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Jwt<C>(pub C);
+
+axum_core::__impl_deref!(Jwt);
+
+impl<C, S> FromRequestParts<S> for Jwt<C>
+where
+    C: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.jwt", async move {
+            let config: &JwtConfig = match parts.extensions.get::<JwtConfig>() {
+                | Some(config) => config,
+                | None => {
+                    return Err(CreateJsonResponse::rejection()
+                        .status(StatusCode::INTERNAL_SERVER_ERROR)
+                        .add_error(
+                            JsonResponseError::from(ResponseError::Server)
+                                .message("Missing `JwtConfig` extension"),
+                        )
+                        .create());
+                },
+            };
+
+            extract_jwt_fn::<C>(parts, config)
+                .map(Self)
+                .map_err(create_jwt_rejection_fn)
+        })
+    }
+}
+
+/// Extractor that decodes and validates the JWT
+/// of an `Authorization: Bearer <token>` header into claims,
+/// with the [`JwtConfig`] from the router state,
+/// available with `jwt` feature.
+///
+/// Missing or invalid tokens are rejected
+/// the same as [`Jwt`] extractor.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, extract::FromRef, routing::get};
+/// use jder_axum::extract::auth::{JwtConfig, StateJwt};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Claims {
+///     sub: String,
+/// }
+///
+/// #[derive(Clone, FromRef)]
+/// struct AppState {
+///     jwt: JwtConfig,
+/// }
+///
+/// async fn route(StateJwt(claims): StateJwt<Claims>) {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .with_state(AppState { jwt: JwtConfig::hs256("secret") });
+/// ```
+#[derive(Debug, Clone)]
+pub struct StateJwt<C>(pub C);
+
+axum_core::__impl_deref!(StateJwt);
+
+impl<C, S> FromRequestParts<S> for StateJwt<C>
+where
+    C: DeserializeOwned + Send,
+    S: Send + Sync,
+    JwtConfig: FromRef<S>,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.jwt", async move {
+            let config: JwtConfig = JwtConfig::from_ref(state);
+
+            extract_jwt_fn::<C>(parts, &config)
+                .map(Self)
+                .map_err(create_jwt_rejection_fn)
+        })
+    }
+}
@@ -0,0 +1,15 @@
+pub(crate) mod base;
+
+/// JWT extractor module,
+/// available with `jwt` feature.
+#[cfg(feature = "jwt")]
+pub mod jwt;
+
+pub use crate::extract::auth::base::{BasicAuth, BearerToken};
+
+#[cfg(feature = "jwt_rs256")]
+pub use crate::extract::auth::jwt::JwtKeyError;
+#[cfg(feature = "jwt")]
+pub use crate::extract::auth::jwt::{
+    Jwt, JwtAlgorithm, JwtConfig, JwtError, JwtVerifier, StateJwt,
+};
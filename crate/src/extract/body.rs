@@ -0,0 +1,44 @@
+use axum::extract::rejection::{BytesRejection, FailedToBufferBody};
+
+use crate::response::{
+    Response,
+    json::{
+        CreateJsonResponse, JsonResponseConfig, JsonResponseError,
+        ResponseError,
+    },
+};
+
+/// Check if the body cannot be read, such as the client is disconnected,
+/// rather than exceeding the limit.
+#[cfg(any(feature = "form", feature = "json"))]
+pub(crate) fn is_body_read_error(rej: &BytesRejection) -> bool {
+    matches!(
+        rej,
+        BytesRejection::FailedToBufferBody(
+            FailedToBufferBody::UnknownBodyError(_)
+        )
+    )
+}
+
+/// Check if the body exceeds the limit.
+#[cfg(feature = "form")]
+pub(crate) fn is_body_too_large(rej: &BytesRejection) -> bool {
+    matches!(
+        rej,
+        BytesRejection::FailedToBufferBody(
+            FailedToBufferBody::LengthLimitError(_)
+        )
+    )
+}
+
+/// Create a failure response for a body read error,
+/// with the status from [`JsonResponseConfig`].
+pub(crate) fn create_body_read_rejection_fn(rej: BytesRejection) -> Response {
+    CreateJsonResponse::rejection()
+        .status(JsonResponseConfig::get().body_read_status)
+        .add_error(
+            JsonResponseError::from(ResponseError::BodyRead)
+                .message(rej.body_text()),
+        )
+        .create()
+}
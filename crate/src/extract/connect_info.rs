@@ -2,9 +2,12 @@ use axum::extract::ConnectInfo as _ConnectInfo;
 use axum_core::extract::FromRequestParts;
 use http::request::Parts;
 
-use crate::response::{
-    Response,
-    json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
 };
 
 /// Extractor for getting connection information produced
@@ -54,17 +57,18 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match _ConnectInfo::<T>::from_request_parts(parts, state).await {
-            | Ok(val) => Ok(Self(val.0)),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.connect_info", async move {
+            match _ConnectInfo::<T>::from_request_parts(parts, state).await {
+                | Ok(val) => Ok(Self(val.0)),
+                | Err(rej) => Err(CreateJsonResponse::rejection()
+                    .status(rej.status())
+                    .add_error(
+                        JsonResponseError::from(ResponseError::Parse)
+                            .message(rej.body_text()),
+                    )
+                    .create()),
+            }
+        })
     }
 }
 
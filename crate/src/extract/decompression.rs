@@ -0,0 +1,355 @@
+use std::{error::Error, fmt, io, pin::Pin};
+
+#[cfg(feature = "decompression_br")]
+use async_compression::tokio::bufread::BrotliDecoder;
+#[cfg(feature = "decompression_zstd")]
+use async_compression::tokio::bufread::ZstdDecoder;
+use async_compression::tokio::bufread::{
+    DeflateDecoder, GzipDecoder, ZlibDecoder,
+};
+use axum::extract::DefaultBodyLimit;
+use axum_core::{RequestExt as _, body::Body, extract::Request};
+use bytes::Bytes;
+use futures_util::{StreamExt as _, TryStreamExt as _};
+use http::{StatusCode, header};
+use http_body_util::LengthLimitError;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt as _, AsyncRead, BufReader};
+use tokio_util::io::{ReaderStream, StreamReader};
+
+use crate::response::{
+    Response,
+    json::{
+        CreateJsonResponse, JsonResponseConfig, JsonResponseError,
+        ResponseError,
+    },
+};
+
+/// Default maximum size of decompressed request bodies in bytes.
+pub const DECOMPRESSED_BODY_LIMIT_DEFAULT: usize = 8 * 1024 * 1024;
+
+/// Content encoding of a request body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContentEncoding {
+    Gzip,
+    Deflate,
+    #[cfg(feature = "decompression_br")]
+    Br,
+    #[cfg(feature = "decompression_zstd")]
+    Zstd,
+}
+
+/// Error while decompressing a request body,
+/// carried by the body to the extractors.
+#[derive(Debug)]
+pub(crate) enum DecompressionError {
+    /// Malformed or truncated data.
+    Invalid,
+    /// Decompressed body exceeds the limit.
+    TooLarge,
+    /// Encoded body exceeds the body limit.
+    EncodedTooLarge,
+    /// Encoded body cannot be read, such as the client is disconnected.
+    Read(String),
+}
+
+impl fmt::Display for DecompressionError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            | Self::Invalid => {
+                f.write_str(ResponseError::Encoding.as_message())
+            },
+            | Self::TooLarge => {
+                f.write_str("Decompressed request body is too large")
+            },
+            | Self::EncodedTooLarge => f.write_str(
+                "Failed to buffer the request body: length limit exceeded",
+            ),
+            | Self::Read(message) => {
+                write!(f, "Failed to buffer the request body: {message}")
+            },
+        }
+    }
+}
+
+impl Error for DecompressionError {}
+
+impl DecompressionError {
+    /// Create an error from an error of the encoded body.
+    fn from_body_error(err: axum_core::Error) -> Self {
+        let mut current: Option<&(dyn Error + 'static)> = Some(&err);
+
+        while let Some(source) = current {
+            if source.is::<LengthLimitError>() {
+                return Self::EncodedTooLarge;
+            }
+
+            current = source.source();
+        }
+
+        Self::Read(err.to_string())
+    }
+
+    /// Create an error from an error of a decoder,
+    /// which is invalid data unless it is from the encoded body.
+    fn from_io_error(err: io::Error) -> Self {
+        match err.into_inner().map(|err| err.downcast::<Self>()) {
+            | Some(Ok(err)) => *err,
+            | _ => Self::Invalid,
+        }
+    }
+
+    /// Get the status code of the error.
+    pub(crate) fn status(&self) -> StatusCode {
+        match self {
+            | Self::Invalid => StatusCode::BAD_REQUEST,
+            | Self::TooLarge | Self::EncodedTooLarge => {
+                StatusCode::PAYLOAD_TOO_LARGE
+            },
+            | Self::Read(_) => JsonResponseConfig::get().body_read_status,
+        }
+    }
+
+    /// Create the error of a failure response.
+    pub(crate) fn to_response_error(&self) -> JsonResponseError {
+        let error: JsonResponseError = match self {
+            | Self::Invalid | Self::TooLarge => {
+                JsonResponseError::from(ResponseError::Encoding)
+                    .path(["header", header::CONTENT_ENCODING.as_str()])
+            },
+            | Self::EncodedTooLarge => {
+                JsonResponseError::from(ResponseError::TooLarge)
+            },
+            | Self::Read(_) => JsonResponseError::from(ResponseError::BodyRead),
+        };
+
+        error.message(self.to_string())
+    }
+
+    /// Create a failure response of the error.
+    fn create_response(&self) -> Response {
+        CreateJsonResponse::rejection()
+            .status(self.status())
+            .add_error(self.to_response_error())
+            .create()
+    }
+}
+
+/// Get the decompression error in the sources of an error,
+/// such as a rejection of reading a decompressed body.
+pub(crate) fn get_decompression_error_fn<'a>(
+    err: &'a (dyn Error + 'static)
+) -> Option<&'a DecompressionError> {
+    let mut current: Option<&(dyn Error + 'static)> = Some(err);
+
+    while let Some(source) = current {
+        if let Some(err) = source.downcast_ref::<DecompressionError>() {
+            return Some(err);
+        }
+
+        current = source.source();
+    }
+
+    None
+}
+
+/// Check if an error is caused by decompressing the body.
+#[cfg(any(feature = "form", feature = "json"))]
+pub(crate) fn is_decompression_error(err: &(dyn Error + 'static)) -> bool {
+    get_decompression_error_fn(err).is_some()
+}
+
+/// Create a failure response of an error
+/// caused by decompressing the body.
+#[cfg(any(feature = "form", feature = "json"))]
+pub(crate) fn create_decompression_rejection_fn(
+    err: &(dyn Error + 'static)
+) -> Response {
+    get_decompression_error_fn(err)
+        .unwrap_or(&DecompressionError::Invalid)
+        .create_response()
+}
+
+/// Create a failure response of a content encoding.
+fn create_encoding_rejection_fn(
+    status: StatusCode,
+    message: &str,
+) -> Response {
+    CreateJsonResponse::rejection()
+        .status(status)
+        .add_error(
+            JsonResponseError::from(ResponseError::Encoding)
+                .path(["header", header::CONTENT_ENCODING.as_str()])
+                .message(message),
+        )
+        .create()
+}
+
+/// Get the content encodings of a request in the applied order,
+/// skipping `identity`,
+/// returns the status and the message of the rejection if invalid.
+fn get_encodings_fn(
+    req: &Request
+) -> Result<Vec<ContentEncoding>, (StatusCode, String)> {
+    let mut encodings: Vec<ContentEncoding> = Vec::new();
+
+    for value in req.headers().get_all(header::CONTENT_ENCODING) {
+        let value: &str = match value.to_str() {
+            | Ok(value) => value,
+            | Err(_) => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "Invalid content encoding".to_string(),
+                ));
+            },
+        };
+
+        for coding in value.split(',').map(str::trim) {
+            let encoding: ContentEncoding = match coding {
+                | "" => continue,
+                | c if c.eq_ignore_ascii_case("identity") => continue,
+                | c if c.eq_ignore_ascii_case("gzip")
+                    || c.eq_ignore_ascii_case("x-gzip") =>
+                {
+                    ContentEncoding::Gzip
+                },
+                | c if c.eq_ignore_ascii_case("deflate") => {
+                    ContentEncoding::Deflate
+                },
+                #[cfg(feature = "decompression_br")]
+                | c if c.eq_ignore_ascii_case("br") => ContentEncoding::Br,
+                #[cfg(feature = "decompression_zstd")]
+                | c if c.eq_ignore_ascii_case("zstd") => ContentEncoding::Zstd,
+                | c => {
+                    return Err((
+                        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                        format!("Unsupported content encoding `{c}`"),
+                    ));
+                },
+            };
+
+            encodings.push(encoding);
+        }
+    }
+
+    Ok(encodings)
+}
+
+/// Reader of an encoded or a decoded body.
+type BodyReader = Pin<Box<dyn AsyncBufRead + Send>>;
+
+/// Check if a `deflate` body has the zlib header,
+/// or it is a raw deflate stream sent by some clients.
+async fn is_zlib_fn(reader: &mut BodyReader) -> io::Result<bool> {
+    let header: &[u8] = reader.fill_buf().await?;
+
+    Ok(header.len() >= 2
+        && header[0] & 0x0f == 8
+        && header[1] & 0x20 == 0
+        && (u16::from_be_bytes([header[0], header[1]]) % 31) == 0)
+}
+
+/// Create the decoder of an encoding.
+async fn create_decoder_fn(
+    encoding: ContentEncoding,
+    mut reader: BodyReader,
+) -> io::Result<Pin<Box<dyn AsyncRead + Send>>> {
+    Ok(match encoding {
+        | ContentEncoding::Gzip => {
+            let mut decoder: GzipDecoder<BodyReader> = GzipDecoder::new(reader);
+
+            decoder.multiple_members(true);
+
+            Box::pin(decoder)
+        },
+        | ContentEncoding::Deflate => match is_zlib_fn(&mut reader).await? {
+            | true => Box::pin(ZlibDecoder::new(reader)),
+            | false => Box::pin(DeflateDecoder::new(reader)),
+        },
+        #[cfg(feature = "decompression_br")]
+        | ContentEncoding::Br => Box::pin(BrotliDecoder::new(reader)),
+        #[cfg(feature = "decompression_zstd")]
+        | ContentEncoding::Zstd => {
+            let mut decoder: ZstdDecoder<BodyReader> = ZstdDecoder::new(reader);
+
+            decoder.multiple_members(true);
+
+            Box::pin(decoder)
+        },
+    })
+}
+
+/// Decompress the body of a request with `Content-Encoding` header,
+/// returns the request unchanged if the body is not encoded.
+///
+/// The body is decompressed as a stream while the extractor reads it,
+/// where the encoded body is limited by
+/// [`DefaultBodyLimit`](axum::extract::DefaultBodyLimit),
+/// and the decompressed body is limited by
+/// [`JsonResponseConfig::decompressed_body_limit`]
+/// instead of the `DefaultBodyLimit`.
+///
+/// Errors while reading the body are [`DecompressionError`]s
+/// in the sources of the rejections of the extractor.
+pub(crate) async fn decompress_request_fn(
+    req: Request
+) -> Result<Request, Response> {
+    let encodings: Vec<ContentEncoding> = match get_encodings_fn(&req) {
+        | Ok(encodings) => encodings,
+        | Err((status, message)) => {
+            return Err(create_encoding_rejection_fn(status, &message));
+        },
+    };
+
+    if encodings.is_empty() {
+        return Ok(req);
+    }
+
+    let (mut parts, body) = req.with_limited_body().into_parts();
+
+    let stream = body.into_data_stream().map_err(|err| {
+        io::Error::other(DecompressionError::from_body_error(err))
+    });
+
+    let mut reader: BodyReader = Box::pin(StreamReader::new(stream));
+
+    // decode in the reverse order of the applied encodings
+    for encoding in encodings.iter().rev() {
+        let decoder: Pin<Box<dyn AsyncRead + Send>> =
+            match create_decoder_fn(*encoding, reader).await {
+                | Ok(decoder) => decoder,
+                | Err(err) => {
+                    return Err(DecompressionError::from_io_error(err)
+                        .create_response());
+                },
+            };
+
+        reader = Box::pin(BufReader::new(decoder));
+    }
+
+    let mut remaining: usize =
+        JsonResponseConfig::get().decompressed_body_limit;
+
+    let stream = ReaderStream::new(reader).map(move |chunk| {
+        let chunk: Bytes = chunk.map_err(DecompressionError::from_io_error)?;
+
+        remaining = remaining
+            .checked_sub(chunk.len())
+            .ok_or(DecompressionError::TooLarge)?;
+
+        Ok::<Bytes, DecompressionError>(chunk)
+    });
+
+    parts.headers.remove(header::CONTENT_ENCODING);
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    let mut req: Request =
+        Request::from_parts(parts, Body::from_stream(stream));
+
+    // the decompressed body is limited by the stream
+    DefaultBodyLimit::disable().apply(&mut req);
+
+    Ok(req)
+}
@@ -0,0 +1,160 @@
+use std::convert::Infallible;
+
+use axum_core::extract::FromRequestParts;
+use http::{HeaderMap, header, request::Parts};
+use sha1::{Digest, Sha1};
+
+use crate::extract::trace::trace_extract;
+
+/// Create a strong entity tag from the SHA-1 hash of a body,
+/// such as `"2fd4e1c67a2d28fced849ee1bb76e7391b93eb12"`.
+pub(crate) fn create_etag_fn(body: &[u8]) -> String {
+    let hash: String =
+        Sha1::digest(body).iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    format!("\"{}\"", hash)
+}
+
+/// Quote an entity tag if not quoted,
+/// such as `v1` into `"v1"`, keeping weak tags such as `W/"v1"`.
+pub(crate) fn quote_etag_fn(tag: &str) -> String {
+    let tag: &str = tag.trim();
+
+    match tag.starts_with('"') || tag.starts_with("W/\"") {
+        | true => tag.to_string(),
+        | false => format!("\"{}\"", tag),
+    }
+}
+
+/// Get the opaque tag of an entity tag without the weak indicator,
+/// such as `"v1"` of `W/"v1"`.
+fn opaque_tag_fn(tag: &str) -> &str {
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
+
+/// Extractor that gets the entity tags
+/// of the `if-none-match` header of a request,
+/// available with `etag` feature.
+///
+/// Set it with the `if_none_match` function of the success builder,
+/// so that a `304 Not Modified` response with an empty body is sent
+/// when the entity tag of the response matches.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::IfNoneMatch,
+///     response::{Response, json::CreateJsonResponse},
+/// };
+///
+/// async fn route(if_none_match: IfNoneMatch) -> Response {
+///     CreateJsonResponse::success::<String>()
+///         .data("Name".to_string())
+///         .etag_auto()
+///         .if_none_match(&if_none_match)
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IfNoneMatch {
+    /// Whether any entity tag matches, with `*`.
+    any: bool,
+    /// Entity tags of the header.
+    tags: Vec<String>,
+}
+
+impl IfNoneMatch {
+    /// Parse the entity tags of the `if-none-match` header,
+    /// ignoring malformed tags.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let mut if_none_match: Self = Self::default();
+
+        for value in headers.get_all(header::IF_NONE_MATCH) {
+            let mut rest: &str = match value.to_str() {
+                | Ok(value) => value,
+                | Err(_) => continue,
+            };
+
+            loop {
+                rest = rest.trim_start_matches([' ', '\t', ',']);
+
+                if rest.is_empty() {
+                    break;
+                }
+
+                if let Some(after) = rest.strip_prefix('*') {
+                    if_none_match.any = true;
+                    rest = after;
+
+                    continue;
+                }
+
+                let start: usize = match rest.starts_with("W/") {
+                    | true => 2,
+                    | false => 0,
+                };
+
+                // the closing quote of the opaque tag
+                let end: Option<usize> = rest[start..]
+                    .strip_prefix('"')
+                    .and_then(|tag| tag.find('"'))
+                    .map(|index| start + index + 2);
+
+                match end {
+                    | Some(end) => {
+                        if_none_match.tags.push(rest[..end].to_string());
+                        rest = &rest[end..];
+                    },
+                    | None => break,
+                }
+            }
+        }
+
+        if_none_match
+    }
+
+    /// Check whether the header is not set.
+    pub fn is_empty(&self) -> bool {
+        !self.any && self.tags.is_empty()
+    }
+
+    /// Check whether an entity tag matches the header
+    /// with the weak comparison.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::IfNoneMatch;
+    ///
+    /// fn is_cached(if_none_match: &IfNoneMatch) -> bool {
+    ///     if_none_match.matches("\"v1\"")
+    /// }
+    /// ```
+    pub fn matches(
+        &self,
+        etag: &str,
+    ) -> bool {
+        self.any
+            || self
+                .tags
+                .iter()
+                .any(|tag| opaque_tag_fn(tag) == opaque_tag_fn(etag))
+    }
+}
+
+impl<S> FromRequestParts<S> for IfNoneMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.if_none_match", async move {
+            Ok(Self::from_headers(&parts.headers))
+        })
+    }
+}
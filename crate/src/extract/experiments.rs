@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use axum_core::extract::FromRequestParts;
+use http::{StatusCode, request::Parts};
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
+};
+
+/// Provider assigning the experiment variants of a request,
+/// used by the [`Experiments`](crate::layers::Experiments) layer.
+///
+/// Functions and closures taking the request parts
+/// and returning the variants by experiment are providers.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::collections::BTreeMap;
+///
+/// use axum::http::request::Parts;
+/// use jder_axum::extract::experiments::ExperimentProvider;
+///
+/// struct UserBucket;
+///
+/// impl ExperimentProvider for UserBucket {
+///     fn assign(
+///         &self,
+///         parts: &Parts,
+///     ) -> BTreeMap<String, String> {
+///         let user: &str = parts
+///             .headers
+///             .get("x-user-id")
+///             .and_then(|value| value.to_str().ok())
+///             .unwrap_or_default();
+///
+///         let variant: &str = match user.len() % 2 {
+///             | 0 => "a",
+///             | _ => "b",
+///         };
+///
+///         BTreeMap::from([("checkout".to_string(), variant.to_string())])
+///     }
+/// }
+/// ```
+pub trait ExperimentProvider: Send + Sync + 'static {
+    /// Assign the variants of a request by experiment.
+    fn assign(
+        &self,
+        parts: &Parts,
+    ) -> BTreeMap<String, String>;
+}
+
+impl<F> ExperimentProvider for F
+where
+    F: Fn(&Parts) -> BTreeMap<String, String> + Send + Sync + 'static,
+{
+    fn assign(
+        &self,
+        parts: &Parts,
+    ) -> BTreeMap<String, String> {
+        self(parts)
+    }
+}
+
+/// Extractor for the experiment variants assigned to a request.
+///
+/// The variants are assigned by the provider of the
+/// [`Experiments`](crate::layers::Experiments) layer,
+/// which also stamps them into the `experiments` field of meta
+/// in the JSON response, so clients and analytics
+/// agree on the assignment.
+///
+/// Without the layer, a failure response with `server` code is returned.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::collections::BTreeMap;
+///
+/// use axum::{Router, http::request::Parts, routing::get};
+/// use jder_axum::{
+///     extract::experiments::ExperimentAssignment, layers::Experiments,
+/// };
+///
+/// async fn route(assignment: ExperimentAssignment) {
+///     if assignment.is_variant("checkout", "b") {
+///         // ...
+///     }
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .layer(Experiments::new(|_: &Parts| {
+///         BTreeMap::from([("checkout".to_string(), "b".to_string())])
+///     }));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExperimentAssignment {
+    variants: BTreeMap<String, String>,
+}
+
+impl ExperimentAssignment {
+    /// Create an assignment from the variants by experiment.
+    pub fn new(variants: BTreeMap<String, String>) -> Self {
+        Self { variants }
+    }
+
+    /// Get the assigned variant of an experiment.
+    pub fn variant(
+        &self,
+        experiment: &str,
+    ) -> Option<&str> {
+        self.variants.get(experiment).map(String::as_str)
+    }
+
+    /// Check if a variant is assigned for an experiment.
+    pub fn is_variant(
+        &self,
+        experiment: &str,
+        variant: &str,
+    ) -> bool {
+        self.variant(experiment) == Some(variant)
+    }
+
+    /// Get an iterator over the experiments and variants in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.variants.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Get the number of assigned experiments.
+    pub fn len(&self) -> usize {
+        self.variants.len()
+    }
+
+    /// Check if no experiment is assigned.
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+}
+
+impl<S> FromRequestParts<S> for ExperimentAssignment
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.experiment_assignment", async move {
+            match parts.extensions.get::<ExperimentAssignment>() {
+                | Some(assignment) => Ok(assignment.clone()),
+                | None => Err(CreateJsonResponse::rejection()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .add_error(
+                        JsonResponseError::from(ResponseError::Server)
+                            .message("Missing `Experiments` layer"),
+                    )
+                    .create()),
+            }
+        })
+    }
+}
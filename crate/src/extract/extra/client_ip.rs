@@ -0,0 +1,349 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::extract::ConnectInfo;
+use axum_core::extract::FromRequestParts;
+use http::{HeaderName, StatusCode, request::Parts};
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
+};
+
+/// Header name of the de-facto standard forwarded addresses.
+pub const X_FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// Header name of the client address set by a proxy.
+pub const X_REAL_IP_HEADER: &str = "x-real-ip";
+
+/// Header of the forwarded addresses read by [`ClientIp`] extractor,
+/// [`XForwardedFor`](ClientIpSource::XForwardedFor) by default.
+///
+/// Only the header set by the trusted proxies should be read,
+/// other headers are ignored, since they can be sent by the client.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClientIpSource {
+    /// `X-Forwarded-For` header.
+    #[default]
+    XForwardedFor,
+    /// `Forwarded` header (RFC 7239).
+    Forwarded,
+    /// `X-Real-IP` header.
+    XRealIp,
+}
+
+/// Network of trusted proxies, such as `10.0.0.0/8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IpNetwork {
+    /// Address of the network.
+    addr: IpAddr,
+    /// Length of the prefix in bits.
+    prefix: u8,
+}
+
+impl IpNetwork {
+    /// Check if the network contains an address.
+    fn contains(
+        &self,
+        ip: &IpAddr,
+    ) -> bool {
+        match (self.addr, ip) {
+            | (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask: u32 =
+                    u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+
+                u32::from(net) & mask == u32::from(*ip) & mask
+            },
+            | (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask: u128 = u128::MAX
+                    .checked_shl(128 - self.prefix as u32)
+                    .unwrap_or(0);
+
+                u128::from(net) & mask == u128::from(*ip) & mask
+            },
+            | _ => false,
+        }
+    }
+}
+
+/// Trust policy of [`ClientIp`] extractor,
+/// added to the router with an [`Extension`](axum::Extension).
+///
+/// Without the policy, no proxy is trusted,
+/// and the address of the connection is the client address.
+///
+/// The forwarded addresses are read from a single header,
+/// set with [`source`](ClientIpConfig::source).
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::net::{IpAddr, Ipv4Addr};
+///
+/// use axum::{Extension, Router};
+/// use jder_axum::extract::extra::{ClientIpConfig, ClientIpSource};
+///
+/// // behind a load balancer in 10.0.0.0/8
+/// let router: Router = Router::new().layer(Extension(
+///     ClientIpConfig::new()
+///         .source(ClientIpSource::XForwardedFor)
+///         .trusted_proxy(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8),
+/// ));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientIpConfig {
+    /// Header of the forwarded addresses.
+    source: ClientIpSource,
+    /// Number of trusted hops in front of the server.
+    trusted_hops: usize,
+    /// Networks of trusted proxies.
+    trusted_proxies: Vec<IpNetwork>,
+}
+
+impl ClientIpConfig {
+    /// Create a new policy trusting no proxy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the header of the forwarded addresses,
+    /// which is the only header read.
+    pub fn source(
+        mut self,
+        source: ClientIpSource,
+    ) -> Self {
+        self.source = source;
+
+        self
+    }
+
+    /// Set the number of trusted proxies in front of the server,
+    /// including the proxy connected to the server.
+    pub fn trusted_hops(
+        mut self,
+        hops: usize,
+    ) -> Self {
+        self.trusted_hops = hops;
+
+        self
+    }
+
+    /// Trust the proxies in a network,
+    /// with the address and the length of the prefix in bits,
+    /// where the prefix is capped to the length of the address.
+    pub fn trusted_proxy(
+        mut self,
+        addr: IpAddr,
+        prefix: u8,
+    ) -> Self {
+        let max: u8 = match addr {
+            | IpAddr::V4(_) => 32,
+            | IpAddr::V6(_) => 128,
+        };
+
+        self.trusted_proxies.push(IpNetwork { addr, prefix: prefix.min(max) });
+
+        self
+    }
+
+    /// Check if an address of a hop is trusted,
+    /// where the hop is counted from the server.
+    fn is_trusted(
+        &self,
+        hop: usize,
+        ip: Option<&IpAddr>,
+    ) -> bool {
+        hop < self.trusted_hops
+            || ip.is_some_and(|ip| {
+                self.trusted_proxies.iter().any(|net| net.contains(ip))
+            })
+    }
+}
+
+/// Parse an address of a forwarded header,
+/// such as `192.0.2.1`, `192.0.2.1:8080` or `"[2001:db8::1]:8080"`,
+/// returns `None` for unknown or obfuscated addresses.
+fn parse_forwarded_addr_fn(value: &str) -> Option<IpAddr> {
+    let value: &str = value.trim().trim_matches('"');
+
+    if let Ok(ip) = value.parse::<IpAddr>() {
+        return Some(ip);
+    }
+
+    if let Ok(addr) = value.parse::<SocketAddr>() {
+        return Some(addr.ip());
+    }
+
+    value.strip_prefix('[')?.split_once(']')?.0.parse::<IpAddr>().ok()
+}
+
+/// Get the values of a header joined by commas.
+fn get_header_values_fn(
+    parts: &Parts,
+    name: &HeaderName,
+) -> Option<String> {
+    let values: Vec<&str> = parts
+        .headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect();
+
+    match values.is_empty() {
+        | true => None,
+        | false => Some(values.join(",")),
+    }
+}
+
+/// Get the forwarded addresses from the client to the last proxy,
+/// from the header of the source only.
+fn get_forwarded_chain_fn(
+    parts: &Parts,
+    source: ClientIpSource,
+) -> Vec<Option<IpAddr>> {
+    match source {
+        | ClientIpSource::XForwardedFor => {
+            let xff: HeaderName =
+                HeaderName::from_static(X_FORWARDED_FOR_HEADER);
+
+            match get_header_values_fn(parts, &xff) {
+                | Some(value) => {
+                    value.split(',').map(parse_forwarded_addr_fn).collect()
+                },
+                | None => Vec::new(),
+            }
+        },
+        | ClientIpSource::Forwarded => {
+            match get_header_values_fn(parts, &http::header::FORWARDED) {
+                | Some(value) => value
+                    .split(',')
+                    .map(|element| {
+                        element
+                            .split(';')
+                            .filter_map(|pair| pair.split_once('='))
+                            .find(|(key, _)| {
+                                key.trim().eq_ignore_ascii_case("for")
+                            })
+                            .and_then(|(_, value)| {
+                                parse_forwarded_addr_fn(value)
+                            })
+                    })
+                    .collect(),
+                | None => Vec::new(),
+            }
+        },
+        | ClientIpSource::XRealIp => {
+            let real_ip: HeaderName = HeaderName::from_static(X_REAL_IP_HEADER);
+
+            match get_header_values_fn(parts, &real_ip) {
+                | Some(value) => vec![parse_forwarded_addr_fn(&value)],
+                | None => Vec::new(),
+            }
+        },
+    }
+}
+
+/// Resolve the client address from the forwarded addresses
+/// and the address of the connection.
+///
+/// Walking from the server to the client,
+/// the first untrusted address is the client address,
+/// or the farthest address if all addresses are trusted.
+fn resolve_client_ip_fn(
+    config: &ClientIpConfig,
+    chain: Vec<Option<IpAddr>>,
+    peer: Option<IpAddr>,
+) -> Option<IpAddr> {
+    let hops: Vec<Option<IpAddr>> =
+        chain.into_iter().chain(std::iter::once(peer)).collect();
+
+    for (hop, ip) in hops.iter().rev().enumerate() {
+        if !config.is_trusted(hop, ip.as_ref()) {
+            return *ip;
+        }
+    }
+
+    *hops.first()?
+}
+
+/// Extractor that resolves the client address
+/// from the `X-Forwarded-For`, `Forwarded` or `X-Real-IP` header
+/// of trusted proxies, falling back to the address of the connection,
+/// available with `extra_client_ip` feature.
+///
+/// The trust policy and the header, see [`ClientIpSource`],
+/// are set with a [`ClientIpConfig`] extension,
+/// and the address of the connection requires
+/// [`into_make_service_with_connect_info`](axum::Router::into_make_service_with_connect_info)
+/// with [`SocketAddr`].
+///
+/// Addresses that cannot be determined, such as an invalid address
+/// from an untrusted hop, are rejected with failure responses.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::net::{IpAddr, SocketAddr};
+///
+/// use axum::{
+///     Extension, Router,
+///     extract::connect_info::IntoMakeServiceWithConnectInfo,
+///     routing::get,
+/// };
+/// use jder_axum::extract::extra::{ClientIp, ClientIpConfig};
+///
+/// async fn route(ClientIp(ip): ClientIp) {
+///     // ...
+/// }
+///
+/// let router: IntoMakeServiceWithConnectInfo<Router, SocketAddr> =
+///     Router::new()
+///         .route("/", get(route))
+///         .layer(Extension(ClientIpConfig::new().trusted_hops(1)))
+///         .into_make_service_with_connect_info::<SocketAddr>();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+axum_core::__impl_deref!(ClientIp: IpAddr);
+
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.client_ip", async move {
+            let config: ClientIpConfig = parts
+                .extensions
+                .get::<ClientIpConfig>()
+                .cloned()
+                .unwrap_or_default();
+
+            let peer: Option<IpAddr> = parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|info| info.0.ip());
+
+            let chain: Vec<Option<IpAddr>> =
+                get_forwarded_chain_fn(parts, config.source);
+
+            match resolve_client_ip_fn(&config, chain, peer) {
+                | Some(ip) => Ok(Self(ip)),
+                | None => Err(CreateJsonResponse::rejection()
+                    .status(StatusCode::BAD_REQUEST)
+                    .add_error(
+                        JsonResponseError::from(ResponseError::Parse)
+                            .message("Failed to determine the client address"),
+                    )
+                    .create()),
+            }
+        })
+    }
+}
@@ -0,0 +1,450 @@
+use std::convert::Infallible;
+#[cfg(any(
+    feature = "extra_cookie_private",
+    feature = "extra_cookie_signed"
+))]
+use std::fmt;
+
+#[cfg(any(
+    feature = "extra_cookie_private",
+    feature = "extra_cookie_signed"
+))]
+use axum_core::extract::FromRef;
+use axum_core::{
+    extract::FromRequestParts,
+    response::{IntoResponse, IntoResponseParts, ResponseParts},
+};
+#[cfg(feature = "extra_cookie_private")]
+use axum_extra::extract::PrivateCookieJar as _PrivateCookieJar;
+#[cfg(feature = "extra_cookie_signed")]
+use axum_extra::extract::SignedCookieJar as _SignedCookieJar;
+#[cfg(any(
+    feature = "extra_cookie_private",
+    feature = "extra_cookie_signed"
+))]
+use axum_extra::extract::cookie::Key;
+use axum_extra::extract::{CookieJar as _CookieJar, cookie::Cookie};
+use http::{StatusCode, request::Parts};
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{ApiError, JsonResponseError, ResponseError},
+    },
+};
+
+/// Create an error of a missing cookie, with the cookie in path.
+fn create_missing_cookie_error_fn(name: &str) -> ApiError {
+    ApiError::new().status(StatusCode::BAD_REQUEST).add_error(
+        JsonResponseError::from(ResponseError::Parse)
+            .path(["cookie", name])
+            .message(format!("Missing cookie `{name}`")),
+    )
+}
+
+/// Extractor and response that works with the cookies of a request,
+/// available with `extra_cookie` feature.
+///
+/// Required cookies can be read with
+/// [`get_required`](CookieJar::get_required),
+/// which rejects missing cookies with failure responses,
+/// with the cookie in path, such as `["cookie", "session"]`.
+///
+/// Check [`CookieJar`](axum_extra::extract::CookieJar) for more information.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::extra::{Cookie, CookieJar},
+///     response::json::{ApiResult, JsonResponse},
+/// };
+///
+/// async fn route(jar: CookieJar) -> ApiResult<String> {
+///     let session: &Cookie = jar.get_required("session")?;
+///
+///     Ok(JsonResponse::new().data(session.value().to_string()))
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CookieJar(pub _CookieJar);
+
+impl CookieJar {
+    /// Create a new empty cookie jar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get a cookie by name.
+    pub fn get(
+        &self,
+        name: &str,
+    ) -> Option<&Cookie<'static>> {
+        self.0.get(name)
+    }
+
+    /// Get a required cookie by name,
+    /// or an error with the cookie in path when missing.
+    pub fn get_required(
+        &self,
+        name: &str,
+    ) -> Result<&Cookie<'static>, ApiError> {
+        self.0.get(name).ok_or_else(|| create_missing_cookie_error_fn(name))
+    }
+
+    /// Add a cookie, which is set when returned as a response.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        Self(self.0.add(cookie))
+    }
+
+    /// Remove a cookie, which is removed when returned as a response.
+    pub fn remove<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        Self(self.0.remove(cookie))
+    }
+
+    /// Get an iterator over the cookies.
+    pub fn iter(&self) -> impl Iterator<Item = &'_ Cookie<'static>> {
+        self.0.iter()
+    }
+}
+
+impl<S> FromRequestParts<S> for CookieJar
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.cookie_jar", async move {
+            let jar: Result<_CookieJar, Infallible> =
+                _CookieJar::from_request_parts(parts, state).await;
+
+            match jar {
+                | Ok(jar) => Ok(Self(jar)),
+                | Err(never) => match never {},
+            }
+        })
+    }
+}
+
+impl IntoResponseParts for CookieJar {
+    type Error = Infallible;
+
+    fn into_response_parts(
+        self,
+        res: ResponseParts,
+    ) -> Result<ResponseParts, Self::Error> {
+        self.0.into_response_parts(res)
+    }
+}
+
+impl IntoResponse for CookieJar {
+    fn into_response(self) -> Response {
+        self.0.into_response()
+    }
+}
+
+/// Extractor and response that works with the signed cookies of a request,
+/// available with `extra_cookie_signed` feature.
+///
+/// The cookies are signed with the [`Key`] from the router state,
+/// so they can be read but not tampered with by the client.
+/// Cookies with an invalid signature are treated as missing.
+///
+/// Check [`SignedCookieJar`](axum_extra::extract::SignedCookieJar)
+/// for more information.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, extract::FromRef, routing::get};
+/// use jder_axum::{
+///     extract::extra::{Cookie, Key, SignedCookieJar},
+///     response::json::{ApiResult, JsonResponse},
+/// };
+///
+/// #[derive(Clone, FromRef)]
+/// struct AppState {
+///     key: Key,
+/// }
+///
+/// async fn route(jar: SignedCookieJar) -> ApiResult<String> {
+///     let session: Cookie = jar.get_required("session")?;
+///
+///     Ok(JsonResponse::new().data(session.value().to_string()))
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .with_state(AppState { key: Key::generate() });
+/// ```
+#[cfg(feature = "extra_cookie_signed")]
+pub struct SignedCookieJar<K = Key>(pub _SignedCookieJar<K>);
+
+#[cfg(feature = "extra_cookie_signed")]
+impl SignedCookieJar {
+    /// Create a new empty signed cookie jar with a key.
+    pub fn new(key: Key) -> Self {
+        Self(_SignedCookieJar::new(key))
+    }
+}
+
+#[cfg(feature = "extra_cookie_signed")]
+impl<K> SignedCookieJar<K> {
+    /// Get a cookie by name, if the signature is valid.
+    pub fn get(
+        &self,
+        name: &str,
+    ) -> Option<Cookie<'static>> {
+        self.0.get(name)
+    }
+
+    /// Get a required cookie by name,
+    /// or an error with the cookie in path when missing or tampered.
+    pub fn get_required(
+        &self,
+        name: &str,
+    ) -> Result<Cookie<'static>, ApiError> {
+        self.0.get(name).ok_or_else(|| create_missing_cookie_error_fn(name))
+    }
+
+    /// Add a cookie, which is signed and set when returned as a response.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        Self(self.0.add(cookie))
+    }
+
+    /// Remove a cookie, which is removed when returned as a response.
+    pub fn remove<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        Self(self.0.remove(cookie))
+    }
+
+    /// Get an iterator over the cookies with a valid signature.
+    pub fn iter(&self) -> impl Iterator<Item = Cookie<'static>> + '_ {
+        self.0.iter()
+    }
+}
+
+#[cfg(feature = "extra_cookie_signed")]
+impl<K> Clone for SignedCookieJar<K> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(feature = "extra_cookie_signed")]
+impl<K> fmt::Debug for SignedCookieJar<K> {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_tuple("SignedCookieJar").field(&self.0).finish()
+    }
+}
+
+#[cfg(feature = "extra_cookie_signed")]
+impl<S, K> FromRequestParts<S> for SignedCookieJar<K>
+where
+    S: Send + Sync,
+    K: FromRef<S> + Into<Key>,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.signed_cookie_jar", async move {
+            let jar: Result<_SignedCookieJar<K>, Infallible> =
+                _SignedCookieJar::from_request_parts(parts, state).await;
+
+            match jar {
+                | Ok(jar) => Ok(Self(jar)),
+                | Err(never) => match never {},
+            }
+        })
+    }
+}
+
+#[cfg(feature = "extra_cookie_signed")]
+impl<K> IntoResponseParts for SignedCookieJar<K> {
+    type Error = Infallible;
+
+    fn into_response_parts(
+        self,
+        res: ResponseParts,
+    ) -> Result<ResponseParts, Self::Error> {
+        self.0.into_response_parts(res)
+    }
+}
+
+#[cfg(feature = "extra_cookie_signed")]
+impl<K> IntoResponse for SignedCookieJar<K> {
+    fn into_response(self) -> Response {
+        self.0.into_response()
+    }
+}
+
+/// Extractor and response that works with the private cookies of a request,
+/// available with `extra_cookie_private` feature.
+///
+/// The cookies are encrypted with the [`Key`] from the router state,
+/// so they can be neither read nor tampered with by the client.
+/// Cookies failing to decrypt are treated as missing.
+///
+/// Check [`PrivateCookieJar`](axum_extra::extract::PrivateCookieJar)
+/// for more information.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, extract::FromRef, routing::get};
+/// use jder_axum::{
+///     extract::extra::{Cookie, Key, PrivateCookieJar},
+///     response::json::{ApiResult, JsonResponse},
+/// };
+///
+/// #[derive(Clone, FromRef)]
+/// struct AppState {
+///     key: Key,
+/// }
+///
+/// async fn route(jar: PrivateCookieJar) -> ApiResult<String> {
+///     let session: Cookie = jar.get_required("session")?;
+///
+///     Ok(JsonResponse::new().data(session.value().to_string()))
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .with_state(AppState { key: Key::generate() });
+/// ```
+#[cfg(feature = "extra_cookie_private")]
+pub struct PrivateCookieJar<K = Key>(pub _PrivateCookieJar<K>);
+
+#[cfg(feature = "extra_cookie_private")]
+impl PrivateCookieJar {
+    /// Create a new empty private cookie jar with a key.
+    pub fn new(key: Key) -> Self {
+        Self(_PrivateCookieJar::new(key))
+    }
+}
+
+#[cfg(feature = "extra_cookie_private")]
+impl<K> PrivateCookieJar<K> {
+    /// Get a decrypted cookie by name.
+    pub fn get(
+        &self,
+        name: &str,
+    ) -> Option<Cookie<'static>> {
+        self.0.get(name)
+    }
+
+    /// Get a required decrypted cookie by name,
+    /// or an error with the cookie in path when missing or tampered.
+    pub fn get_required(
+        &self,
+        name: &str,
+    ) -> Result<Cookie<'static>, ApiError> {
+        self.0.get(name).ok_or_else(|| create_missing_cookie_error_fn(name))
+    }
+
+    /// Add a cookie, which is encrypted and set when returned as a response.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        Self(self.0.add(cookie))
+    }
+
+    /// Remove a cookie, which is removed when returned as a response.
+    pub fn remove<C: Into<Cookie<'static>>>(
+        self,
+        cookie: C,
+    ) -> Self {
+        Self(self.0.remove(cookie))
+    }
+
+    /// Get an iterator over the decrypted cookies.
+    pub fn iter(&self) -> impl Iterator<Item = Cookie<'static>> + '_ {
+        self.0.iter()
+    }
+}
+
+#[cfg(feature = "extra_cookie_private")]
+impl<K> Clone for PrivateCookieJar<K> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(feature = "extra_cookie_private")]
+impl<K> fmt::Debug for PrivateCookieJar<K> {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_tuple("PrivateCookieJar").field(&self.0).finish()
+    }
+}
+
+#[cfg(feature = "extra_cookie_private")]
+impl<S, K> FromRequestParts<S> for PrivateCookieJar<K>
+where
+    S: Send + Sync,
+    K: FromRef<S> + Into<Key>,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.private_cookie_jar", async move {
+            let jar: Result<_PrivateCookieJar<K>, Infallible> =
+                _PrivateCookieJar::from_request_parts(parts, state).await;
+
+            match jar {
+                | Ok(jar) => Ok(Self(jar)),
+                | Err(never) => match never {},
+            }
+        })
+    }
+}
+
+#[cfg(feature = "extra_cookie_private")]
+impl<K> IntoResponseParts for PrivateCookieJar<K> {
+    type Error = Infallible;
+
+    fn into_response_parts(
+        self,
+        res: ResponseParts,
+    ) -> Result<ResponseParts, Self::Error> {
+        self.0.into_response_parts(res)
+    }
+}
+
+#[cfg(feature = "extra_cookie_private")]
+impl<K> IntoResponse for PrivateCookieJar<K> {
+    fn into_response(self) -> Response {
+        self.0.into_response()
+    }
+}
@@ -2,13 +2,16 @@ use axum_core::extract::FromRequestParts;
 use axum_extra::extract::Host as _Host;
 use http::request::Parts;
 
-use crate::response::{
-    Response,
-    json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
 };
 
 /// Extractor that resolves the hostname of the request,
-/// available with `extra` feature.
+/// available with `extra_host` feature.
 ///
 /// Check [`Host`](axum_extra::extract::Host) for more information.
 ///
@@ -36,16 +39,17 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match _Host::from_request_parts(parts, state).await {
-            | Ok(val) => Ok(Self(val.0)),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.host", async move {
+            match _Host::from_request_parts(parts, state).await {
+                | Ok(val) => Ok(Self(val.0)),
+                | Err(rej) => Err(CreateJsonResponse::rejection()
+                    .status(rej.status())
+                    .add_error(
+                        JsonResponseError::from(ResponseError::Parse)
+                            .message(rej.body_text()),
+                    )
+                    .create()),
+            }
+        })
     }
 }
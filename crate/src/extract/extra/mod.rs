@@ -1,3 +1,18 @@
+/// Client IP extractor module,
+/// available with `extra_client_ip` feature.
+#[cfg(feature = "extra_client_ip")]
+pub mod client_ip;
+
+/// Cookie extractor module,
+/// available with `extra_cookie` feature,
+/// with signed and private cookies
+/// available with `extra_cookie_signed` and `extra_cookie_private` features.
+#[cfg(feature = "extra_cookie")]
+pub mod cookie;
+
+/// Host extractor module,
+/// available with `extra_host` feature.
+#[cfg(feature = "extra_host")]
 pub mod host;
 
 /// Scheme extractor module,
@@ -10,6 +25,30 @@ pub mod scheme;
 #[cfg(feature = "extra_typed_header")]
 pub mod typed_header;
 
+#[cfg(feature = "extra_client_ip")]
+pub use crate::extract::extra::client_ip::{
+    ClientIp, ClientIpConfig, ClientIpSource,
+};
+
+#[cfg(feature = "extra_cookie")]
+pub use crate::extract::extra::cookie::CookieJar;
+
+#[cfg(feature = "extra_cookie_private")]
+pub use crate::extract::extra::cookie::PrivateCookieJar;
+
+#[cfg(feature = "extra_cookie_signed")]
+pub use crate::extract::extra::cookie::SignedCookieJar;
+
+#[cfg(feature = "extra_cookie")]
+pub use axum_extra::extract::cookie::Cookie;
+
+#[cfg(any(
+    feature = "extra_cookie_private",
+    feature = "extra_cookie_signed"
+))]
+pub use axum_extra::extract::cookie::Key;
+
+#[cfg(feature = "extra_host")]
 pub use crate::extract::extra::host::Host;
 
 #[cfg(feature = "extra_scheme")]
@@ -2,12 +2,16 @@ use axum_core::extract::FromRequestParts;
 use axum_extra::extract::Scheme as _Scheme;
 use http::request::Parts;
 
-use crate::response::{
-    Response,
-    json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
 };
 
-/// Extractor that resolves the scheme / protocol of a request.
+/// Extractor that resolves the scheme / protocol of a request,
+/// available with `extra_scheme` feature.
 ///
 /// The scheme is resolved through the following, in order:
 /// - `Forwarded` header
@@ -41,16 +45,17 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match _Scheme::from_request_parts(parts, state).await {
-            | Ok(val) => Ok(Self(val.0)),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.scheme", async move {
+            match _Scheme::from_request_parts(parts, state).await {
+                | Ok(val) => Ok(Self(val.0)),
+                | Err(rej) => Err(CreateJsonResponse::rejection()
+                    .status(rej.status())
+                    .add_error(
+                        JsonResponseError::from(ResponseError::Parse)
+                            .message(rej.body_text()),
+                    )
+                    .create()),
+            }
+        })
     }
 }
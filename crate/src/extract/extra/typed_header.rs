@@ -5,16 +5,41 @@ use axum_core::{
 use axum_extra::{
     extract::TypedHeader as _TypedHeader,
     headers::{Header, HeaderMapExt as _},
+    typed_header::TypedHeaderRejection,
 };
 use http::{StatusCode, request::Parts};
 
-use crate::response::{
-    Response,
-    json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
 };
 
+/// Create a failure response from a typed header rejection,
+/// with the header in path, such as `["header", "user-agent"]`.
+fn create_typed_header_rejection_fn(rej: &TypedHeaderRejection) -> Response {
+    let message: String = match rej.is_missing() {
+        | true => format!("Missing header `{}`", rej.name()),
+        | false => format!("Invalid header `{}`", rej.name()),
+    };
+
+    CreateJsonResponse::rejection()
+        .status(StatusCode::BAD_REQUEST)
+        .add_error(
+            JsonResponseError::from(ResponseError::Parse)
+                .path(["header", rej.name().as_str()])
+                .message(message),
+        )
+        .create()
+}
+
 /// Extractor and response that works with typed header values from [`headers`].
 ///
+/// Missing or malformed headers are rejected with failure responses,
+/// with the header in path, such as `["header", "user-agent"]`.
+///
 /// Check [`TypedHeader`](axum_extra::extract::TypedHeader) for more information.
 ///
 /// ## Examples
@@ -59,22 +84,16 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match <_TypedHeader<T> as FromRequestParts<S>>::from_request_parts(
-            parts, state,
-        )
-        .await
-        {
-            | Ok(val) => Ok(Self(val.0)),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(StatusCode::BAD_REQUEST)
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .path([rej.name().as_str()])
-                        .message(format!("{:?}", rej.reason())),
-                )
-                .create()),
-        }
+        trace_extract!("extract.typed_header", async move {
+            match <_TypedHeader<T> as FromRequestParts<S>>::from_request_parts(
+                parts, state,
+            )
+            .await
+            {
+                | Ok(val) => Ok(Self(val.0)),
+                | Err(rej) => Err(create_typed_header_rejection_fn(&rej)),
+            }
+        })
     }
 }
 
@@ -89,23 +108,17 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Option<Self>, Self::Rejection> {
-        match <_TypedHeader<T> as OptionalFromRequestParts<S>>::from_request_parts(
-            parts, state,
-        )
-        .await
-        {
-            | Ok(Some(val)) => Ok(Some(Self(val.0))),
-            | Ok(None) => Ok(None),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(StatusCode::BAD_REQUEST)
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .path([rej.name().as_str()])
-                        .message(format!("{:?}", rej.reason()))
-                )
-                .create()),
-        }
+        trace_extract!("extract.typed_header", async move {
+            match <_TypedHeader<T> as OptionalFromRequestParts<S>>::from_request_parts(
+                parts, state,
+            )
+            .await
+            {
+                | Ok(Some(val)) => Ok(Some(Self(val.0))),
+                | Ok(None) => Ok(None),
+                | Err(rej) => Err(create_typed_header_rejection_fn(&rej)),
+            }
+        })
     }
 }
 
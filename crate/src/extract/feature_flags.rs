@@ -0,0 +1,158 @@
+use std::{
+    collections::BTreeSet,
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+
+use axum_core::extract::{FromRef, FromRequestParts};
+use http::request::Parts;
+
+use crate::extract::trace::trace_extract;
+
+/// Header name of the feature flags.
+pub const FEATURE_FLAGS_HEADER: &str = "x-feature-flags";
+
+/// Allow-list of the feature flags,
+/// obtained from the router state by [`FeatureFlags`].
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::extract::feature_flags::FeatureFlagsAllowList;
+///
+/// let allow_list: FeatureFlagsAllowList =
+///     FeatureFlagsAllowList::new(["new_checkout", "dark_mode"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FeatureFlagsAllowList {
+    flags: Arc<BTreeSet<String>>,
+}
+
+impl FeatureFlagsAllowList {
+    /// Create an allow-list from the flags.
+    pub fn new<I, F>(flags: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: Into<String>,
+    {
+        Self { flags: Arc::new(flags.into_iter().map(Into::into).collect()) }
+    }
+
+    /// Check if a flag is allowed.
+    pub fn is_allowed(
+        &self,
+        flag: &str,
+    ) -> bool {
+        self.flags.contains(flag)
+    }
+}
+
+/// Slot of the applied feature flags of a request,
+/// shared with the [`FeatureFlagsEcho`](crate::layers::FeatureFlagsEcho) layer.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AppliedFeatureFlags(
+    pub(crate) Arc<Mutex<Option<FeatureFlags>>>,
+);
+
+/// Extractor for the feature flags of a request.
+///
+/// The flags are parsed from the comma-separated
+/// [`X-Feature-Flags`](FEATURE_FLAGS_HEADER) header,
+/// and only the flags in the [`FeatureFlagsAllowList`]
+/// from the router state are applied, others are ignored.
+///
+/// With the [`FeatureFlagsEcho`](crate::layers::FeatureFlagsEcho) layer,
+/// the applied flags will be echoed into the `feature_flags` field of meta
+/// in the JSON response.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::extract::feature_flags::{
+///     FeatureFlags,
+///     FeatureFlagsAllowList,
+/// };
+///
+/// // X-Feature-Flags: new_checkout, unknown
+/// async fn route(flags: FeatureFlags) {
+///     // true
+///     let is_new_checkout: bool = flags.is_enabled("new_checkout");
+///     // false
+///     let is_unknown: bool = flags.is_enabled("unknown");
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .with_state(FeatureFlagsAllowList::new(["new_checkout"]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureFlags {
+    flags: BTreeSet<String>,
+}
+
+impl FeatureFlags {
+    /// Check if a flag is enabled.
+    pub fn is_enabled(
+        &self,
+        flag: &str,
+    ) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /// Get an iterator over the enabled flags in order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.flags.iter().map(String::as_str)
+    }
+
+    /// Get the number of enabled flags.
+    pub fn len(&self) -> usize {
+        self.flags.len()
+    }
+
+    /// Check if no flag is enabled.
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty()
+    }
+}
+
+impl<S> FromRequestParts<S> for FeatureFlags
+where
+    S: Send + Sync,
+    FeatureFlagsAllowList: FromRef<S>,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.feature_flags", async move {
+            let allow_list: FeatureFlagsAllowList =
+                FeatureFlagsAllowList::from_ref(state);
+
+            let flags: BTreeSet<String> = parts
+                .headers
+                .get_all(FEATURE_FLAGS_HEADER)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .map(str::trim)
+                .filter(|flag| allow_list.is_allowed(flag))
+                .map(str::to_string)
+                .collect();
+
+            let flags: FeatureFlags = FeatureFlags { flags };
+
+            // echo with the layer
+            if let Some(applied) = parts.extensions.get::<AppliedFeatureFlags>()
+            {
+                if let Ok(mut applied) = applied.0.lock() {
+                    *applied = Some(flags.clone());
+                }
+            }
+
+            Ok(flags)
+        })
+    }
+}
@@ -1,16 +1,73 @@
-use axum::extract::Form as _Form;
+use axum::extract::{Form as _Form, rejection::FormRejection};
 use axum_core::{
     extract::{FromRequest, Request},
     response::IntoResponse,
 };
 use http::{StatusCode, header};
-use serde::{Serialize, de::DeserializeOwned};
+use serde::{
+    Serialize,
+    de::{self, DeserializeOwned},
+};
 
-use crate::response::{
-    CreateResponse, Response,
-    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+#[cfg(feature = "decompression")]
+use crate::extract::decompression::{
+    create_decompression_rejection_fn, decompress_request_fn,
+    is_decompression_error,
+};
+use crate::{
+    extract::{
+        body::{
+            create_body_read_rejection_fn, is_body_read_error,
+            is_body_too_large,
+        },
+        rejection::get_error_path_fn,
+        trace::trace_extract,
+    },
+    response::{
+        CreateResponse, Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
 };
 
+/// Get the error code and the path of a form rejection.
+fn get_form_error_fn(rej: &FormRejection) -> (ResponseError, Vec<String>) {
+    match rej {
+        | FormRejection::InvalidFormContentType(_) => {
+            (ResponseError::UnsupportedMediaType, Vec::new())
+        },
+        // deserialized from the query string for `GET` and `HEAD` requests
+        | FormRejection::FailedToDeserializeForm(_) => (
+            ResponseError::Parse,
+            get_error_path_fn::<de::value::Error>(rej, "query"),
+        ),
+        | FormRejection::FailedToDeserializeFormBody(_) => (
+            ResponseError::Parse,
+            get_error_path_fn::<de::value::Error>(rej, "form"),
+        ),
+        | FormRejection::BytesRejection(rej) if is_body_too_large(rej) => {
+            (ResponseError::TooLarge, Vec::new())
+        },
+        | _ => (ResponseError::Parse, Vec::new()),
+    }
+}
+
+/// Create a failure response from a form rejection,
+/// with the status of the rejection,
+/// such as `415 Unsupported Media Type` for invalid content type
+/// and `422 Unprocessable Entity` for invalid body,
+/// and the location of the deserialization error in path,
+/// such as `["form", "email"]`.
+fn create_form_rejection_fn(rej: FormRejection) -> Response {
+    let (code, path) = get_form_error_fn(&rej);
+
+    CreateJsonResponse::rejection()
+        .status(rej.status())
+        .add_error(
+            JsonResponseError::from(code).path(path).message(rej.body_text()),
+        )
+        .create()
+}
+
 /// URL encoded extractor and response.
 ///
 /// Check [`Form`](axum::extract::Form) for more information.
@@ -69,17 +126,26 @@ where
         req: Request,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match _Form::<T>::from_request(req, state).await {
-            | Ok(val) => Ok(Self(val.0)),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.form", async move {
+            #[cfg(feature = "decompression")]
+            let req: Request = decompress_request_fn(req).await?;
+
+            match _Form::<T>::from_request(req, state).await {
+                | Ok(val) => Ok(Self(val.0)),
+                #[cfg(feature = "decompression")]
+                | Err(FormRejection::BytesRejection(rej))
+                    if is_decompression_error(&rej) =>
+                {
+                    Err(create_decompression_rejection_fn(&rej))
+                },
+                | Err(FormRejection::BytesRejection(rej))
+                    if is_body_read_error(&rej) =>
+                {
+                    Err(create_body_read_rejection_fn(rej))
+                },
+                | Err(rej) => Err(create_form_rejection_fn(rej)),
+            }
+        })
     }
 }
 
@@ -96,11 +162,10 @@ where
                 )
                 .body(body)
                 .create(),
-            | Err(err) => CreateJsonResponse::failure()
+            | Err(err) => CreateJsonResponse::rejection()
                 .status(StatusCode::INTERNAL_SERVER_ERROR)
                 .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Server.as_code())
+                    JsonResponseError::from(ResponseError::Server)
                         .message(err.to_string()),
                 )
                 .create(),
@@ -7,10 +7,38 @@ use bytes::{BufMut, BytesMut, buf::Writer};
 use http::{StatusCode, header};
 use serde::{Serialize, de::DeserializeOwned};
 
-use crate::response::{
-    CreateResponse, Response,
-    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+#[cfg(feature = "decompression")]
+use crate::extract::decompression::{
+    create_decompression_rejection_fn, decompress_request_fn,
+    is_decompression_error,
 };
+use crate::{
+    extract::{
+        body::{create_body_read_rejection_fn, is_body_read_error},
+        rejection::get_error_path_fn,
+        trace::trace_extract,
+    },
+    response::{
+        CreateResponse, Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
+};
+
+/// Create a failure response from a JSON rejection,
+/// with the status of the rejection,
+/// such as `400 Bad Request` for syntax errors,
+/// `415 Unsupported Media Type` for missing content type
+/// and `422 Unprocessable Entity` for data errors.
+fn create_json_rejection_fn(rej: JsonRejection) -> Response {
+    CreateJsonResponse::rejection()
+        .status(rej.status())
+        .add_error(
+            JsonResponseError::from(ResponseError::Parse)
+                .path(get_error_path_fn::<serde_json::Error>(&rej, "json"))
+                .message(rej.body_text()),
+        )
+        .create()
+}
 
 /// JSON extractor / response.
 ///
@@ -70,17 +98,26 @@ where
         req: Request,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match <_Json<T> as FromRequest<S>>::from_request(req, state).await {
-            | Ok(val) => Ok(Self(val.0)),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.json", async move {
+            #[cfg(feature = "decompression")]
+            let req: Request = decompress_request_fn(req).await?;
+
+            match <_Json<T> as FromRequest<S>>::from_request(req, state).await {
+                | Ok(val) => Ok(Self(val.0)),
+                #[cfg(feature = "decompression")]
+                | Err(JsonRejection::BytesRejection(rej))
+                    if is_decompression_error(&rej) =>
+                {
+                    Err(create_decompression_rejection_fn(&rej))
+                },
+                | Err(JsonRejection::BytesRejection(rej))
+                    if is_body_read_error(&rej) =>
+                {
+                    Err(create_body_read_rejection_fn(rej))
+                },
+                | Err(rej) => Err(create_json_rejection_fn(rej)),
+            }
+        })
     }
 }
 
@@ -95,20 +132,29 @@ where
         req: Request,
         state: &S,
     ) -> Result<Option<Self>, Self::Rejection> {
-        match <_Json<T> as OptionalFromRequest<S>>::from_request(req, state)
-            .await
-        {
-            | Ok(Some(val)) => Ok(Some(Self(val.0))),
-            | Ok(None) => Ok(None),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.json", async move {
+            #[cfg(feature = "decompression")]
+            let req: Request = decompress_request_fn(req).await?;
+
+            match <_Json<T> as OptionalFromRequest<S>>::from_request(req, state)
+                .await
+            {
+                | Ok(Some(val)) => Ok(Some(Self(val.0))),
+                | Ok(None) => Ok(None),
+                #[cfg(feature = "decompression")]
+                | Err(JsonRejection::BytesRejection(rej))
+                    if is_decompression_error(&rej) =>
+                {
+                    Err(create_decompression_rejection_fn(&rej))
+                },
+                | Err(JsonRejection::BytesRejection(rej))
+                    if is_body_read_error(&rej) =>
+                {
+                    Err(create_body_read_rejection_fn(rej))
+                },
+                | Err(rej) => Err(create_json_rejection_fn(rej)),
+            }
+        })
     }
 }
 
@@ -149,11 +195,10 @@ where
                 .header(header::CONTENT_TYPE, "application/json")
                 .body(buf.into_inner().freeze())
                 .create(),
-            | Err(err) => CreateJsonResponse::failure()
+            | Err(err) => CreateJsonResponse::rejection()
                 .status(StatusCode::INTERNAL_SERVER_ERROR)
                 .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Server.as_code())
+                    JsonResponseError::from(ResponseError::Server)
                         .message(err.to_string()),
                 )
                 .create(),
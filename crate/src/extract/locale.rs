@@ -0,0 +1,98 @@
+use axum_core::extract::FromRequestParts;
+use http::{StatusCode, request::Parts};
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{
+            CreateJsonResponse, JsonResponseError, MessageCatalog,
+            ResponseError,
+        },
+    },
+};
+
+/// Extractor that gets the locale of the request
+/// negotiated by the [`Localize`](crate::layers::Localize) layer,
+/// available with `i18n` feature.
+///
+/// Failure responses can be localized with the locale,
+/// see the `localized` function of the failure builder.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::Locale,
+///     response::{
+///         Response,
+///         json::{CreateJsonResponse, JsonResponseError, ResponseError},
+///     },
+/// };
+///
+/// async fn route(locale: Locale) -> Response {
+///     CreateJsonResponse::failure()
+///         .add_error(
+///             JsonResponseError::new()
+///                 .code(ResponseError::NotFound.as_code())
+///                 .message(ResponseError::NotFound.as_message()),
+///         )
+///         .localized(&locale)
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Locale {
+    /// Negotiated locale.
+    tag: String,
+    /// Catalog of the messages.
+    catalog: MessageCatalog,
+}
+
+impl Locale {
+    /// Create a new locale of a catalog.
+    pub fn new<T: Into<String>>(
+        tag: T,
+        catalog: MessageCatalog,
+    ) -> Self {
+        Self { tag: tag.into(), catalog }
+    }
+
+    /// Get the language tag of the locale, such as `fr`.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Get the message of an error code in the locale.
+    pub fn message(
+        &self,
+        code: &str,
+    ) -> Option<&str> {
+        self.catalog.get(&self.tag, code)
+    }
+}
+
+impl<S> FromRequestParts<S> for Locale
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.locale", async move {
+            match parts.extensions.get::<Locale>() {
+                | Some(locale) => Ok(locale.clone()),
+                | None => Err(CreateJsonResponse::rejection()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .add_error(
+                        JsonResponseError::from(ResponseError::Server)
+                            .message("Missing `Localize` layer"),
+                    )
+                    .create()),
+            }
+        })
+    }
+}
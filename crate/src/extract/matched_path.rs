@@ -4,9 +4,12 @@ use axum::extract::MatchedPath as _MatchedPath;
 use axum_core::extract::{FromRequestParts, OptionalFromRequestParts};
 use http::{StatusCode, request::Parts};
 
-use crate::response::{
-    Response,
-    json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
 };
 
 /// Access the path in the router that matches the request.
@@ -50,21 +53,22 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match <_MatchedPath as FromRequestParts<S>>::from_request_parts(
-            parts, state,
-        )
-        .await
-        {
-            | Ok(val) => Ok(MatchedPath(val.as_str().into())),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.matched_path", async move {
+            match <_MatchedPath as FromRequestParts<S>>::from_request_parts(
+                parts, state,
+            )
+            .await
+            {
+                | Ok(val) => Ok(MatchedPath(val.as_str().into())),
+                | Err(rej) => Err(CreateJsonResponse::rejection()
+                    .status(rej.status())
+                    .add_error(
+                        JsonResponseError::from(ResponseError::Parse)
+                            .message(rej.body_text()),
+                    )
+                    .create()),
+            }
+        })
     }
 }
 
@@ -78,25 +82,27 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Option<Self>, Self::Rejection> {
-        match <_MatchedPath as OptionalFromRequestParts<S>>::from_request_parts(
-            parts, state,
-        )
-        .await
-        {
-            | Ok(Some(val)) => Ok(Some(Self(val.as_str().into()))),
-            | Ok(None) => Ok(None),
-            | Err(_) => {
-                let rer: ResponseError = ResponseError::Server;
+        trace_extract!("extract.matched_path", async move {
+            match <_MatchedPath as OptionalFromRequestParts<S>>::from_request_parts(
+                parts, state,
+            )
+            .await
+            {
+                | Ok(Some(val)) => Ok(Some(Self(val.as_str().into()))),
+                | Ok(None) => Ok(None),
+                | Err(_) => {
+                    let rer: ResponseError = ResponseError::Server;
 
-                Err(CreateJsonResponse::failure()
-                    .status(StatusCode::INTERNAL_SERVER_ERROR)
-                    .add_error(
-                        JsonResponseError::new()
-                            .code(rer.as_code())
-                            .message(rer.as_message()),
-                    )
-                    .create())
-            },
-        }
+                    Err(CreateJsonResponse::rejection()
+                        .status(StatusCode::INTERNAL_SERVER_ERROR)
+                        .add_error(
+                            JsonResponseError::new()
+                                .code(rer.as_code())
+                                .message(rer.as_message()),
+                        )
+                        .create())
+                },
+            }
+        })
     }
 }
@@ -1,6 +1,47 @@
 pub mod nested_path;
 pub mod path;
 
+#[cfg(any(feature = "form", feature = "json"))]
+pub(crate) mod body;
+
+// used by `Json`, `Form` and `Multipart` extractors
+#[cfg(feature = "decompression")]
+#[cfg_attr(
+    not(any(feature = "form", feature = "json", feature = "multipart")),
+    allow(dead_code)
+)]
+pub(crate) mod decompression;
+
+#[cfg(any(
+    feature = "form",
+    feature = "json",
+    feature = "qs_query",
+    feature = "query"
+))]
+pub(crate) mod rejection;
+
+pub(crate) mod trace;
+
+/// Authorization extractor module,
+/// available with `auth` feature.
+#[cfg(feature = "auth")]
+pub mod auth;
+
+/// Entity tag extractor module,
+/// available with `etag` feature.
+#[cfg(feature = "etag")]
+pub mod etag;
+
+/// Experiments extractor module,
+/// available with `experiments` feature.
+#[cfg(feature = "experiments")]
+pub mod experiments;
+
+/// Feature flags extractor module,
+/// available with `feature_flags` feature.
+#[cfg(feature = "feature_flags")]
+pub mod feature_flags;
+
 /// Form extractor module,
 /// available with `form` feature.
 #[cfg(feature = "form")]
@@ -11,6 +52,11 @@ pub mod form;
 #[cfg(feature = "json")]
 pub mod json;
 
+/// Locale extractor module,
+/// available with `i18n` feature.
+#[cfg(feature = "i18n")]
+pub mod locale;
+
 /// Matched path extractor module,
 /// available with `matched_path` feature.
 #[cfg(feature = "matched_path")]
@@ -21,35 +67,116 @@ pub mod matched_path;
 #[cfg(feature = "multipart")]
 pub mod multipart;
 
+/// Nonce extractor module,
+/// available with `nonce` feature.
+#[cfg(feature = "nonce")]
+pub mod nonce;
+
+/// Pagination extractor module,
+/// available with `pagination_cursor` feature.
+#[cfg(feature = "pagination_cursor")]
+pub mod pagination;
+
+/// Query string extractor module with repeated keys and bracket segments,
+/// available with `qs_query` feature.
+#[cfg(feature = "qs_query")]
+pub mod qs_query;
+
 /// Query extractor module,
 /// available with `query` feature.
 #[cfg(feature = "query")]
 pub mod query;
 
+/// Request context extractor module,
+/// available with `request_context` feature.
+#[cfg(feature = "request_context")]
+pub mod request_context;
+
+/// Request ID extractor module,
+/// available with `request_id` feature.
+#[cfg(feature = "request_id")]
+pub mod request_id;
+
+/// Schema version extractor module,
+/// available with `schema_version` feature.
+#[cfg(feature = "schema_version")]
+pub mod schema_version;
+
+/// Valid extractor module,
+/// available with `valid` feature.
+#[cfg(feature = "valid")]
+pub mod valid;
+
 /// Connect info extractor module,
 /// available with `tokio` feature.
 #[cfg(feature = "tokio")]
 pub mod connect_info;
 
 /// axum extra extractor module,
-/// available with `extra` feature.
-#[cfg(feature = "extra")]
+/// available with `extra_client_ip`, `extra_cookie`, `extra_host`,
+/// `extra_scheme` or `extra_typed_header` feature.
+#[cfg(any(
+    feature = "extra_client_ip",
+    feature = "extra_cookie",
+    feature = "extra_host",
+    feature = "extra_scheme",
+    feature = "extra_typed_header"
+))]
 pub mod extra;
 
 pub use crate::extract::nested_path::NestedPath;
 pub use crate::extract::path::Path;
 
+#[cfg(feature = "auth")]
+pub use crate::extract::auth::{BasicAuth, BearerToken};
+
+#[cfg(feature = "etag")]
+pub use crate::extract::etag::IfNoneMatch;
+
+#[cfg(feature = "experiments")]
+pub use crate::extract::experiments::ExperimentAssignment;
+
+#[cfg(feature = "feature_flags")]
+pub use crate::extract::feature_flags::FeatureFlags;
+
 #[cfg(feature = "form")]
 pub use crate::extract::form::Form;
 
 #[cfg(feature = "json")]
 pub use crate::extract::json::Json;
 
+#[cfg(feature = "i18n")]
+pub use crate::extract::locale::Locale;
+
 #[cfg(feature = "matched_path")]
 pub use crate::extract::matched_path::MatchedPath;
 
+#[cfg(feature = "multipart")]
+pub use crate::extract::multipart::Multipart;
+
+#[cfg(feature = "nonce")]
+pub use crate::extract::nonce::Nonce;
+
+#[cfg(feature = "pagination_cursor")]
+pub use crate::extract::pagination::PaginationCursor;
+
+#[cfg(feature = "qs_query")]
+pub use crate::extract::qs_query::QsQuery;
+
 #[cfg(feature = "query")]
-pub use crate::extract::query::Query;
+pub use crate::extract::query::{Query, StrictQuery};
+
+#[cfg(feature = "request_context")]
+pub use crate::extract::request_context::RequestContext;
+
+#[cfg(feature = "request_id")]
+pub use crate::extract::request_id::RequestId;
+
+#[cfg(feature = "schema_version")]
+pub use crate::extract::schema_version::SchemaVersion;
+
+#[cfg(feature = "valid")]
+pub use crate::extract::valid::Valid;
 
 #[cfg(feature = "tokio")]
 pub use crate::extract::connect_info::ConnectInfo;
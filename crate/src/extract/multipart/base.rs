@@ -0,0 +1,145 @@
+use axum::extract::{
+    Multipart as _Multipart,
+    multipart::{Field, MultipartError, MultipartRejection},
+};
+use axum_core::{
+    extract::{FromRequest, Request},
+    response::IntoResponse,
+};
+use http::StatusCode;
+
+#[cfg(feature = "decompression")]
+use crate::extract::decompression::{
+    decompress_request_fn, get_decompression_error_fn,
+};
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{
+            ApiError, CreateJsonResponse, JsonResponseError, ResponseError,
+        },
+    },
+};
+
+/// Create an error from a multipart rejection,
+/// such as the content type is not `multipart/form-data`.
+pub(crate) fn create_multipart_rejection_error_fn(
+    rej: &MultipartRejection
+) -> JsonResponseError {
+    JsonResponseError::from(ResponseError::UnsupportedMediaType)
+        .message(rej.body_text())
+}
+
+/// Create an error from a multipart error while reading the fields,
+/// such as the body exceeds the limit.
+pub(crate) fn create_multipart_field_error_fn(
+    err: &MultipartError
+) -> JsonResponseError {
+    #[cfg(feature = "decompression")]
+    if let Some(err) = get_decompression_error_fn(err) {
+        return err.to_response_error();
+    }
+
+    let code: ResponseError = match err.status() {
+        | StatusCode::PAYLOAD_TOO_LARGE => ResponseError::TooLarge,
+        | _ => ResponseError::Parse,
+    };
+
+    JsonResponseError::from(code).message(err.body_text())
+}
+
+/// Extractor that parses `multipart/form-data` requests,
+/// available with `multipart` feature.
+///
+/// Rejections and errors while reading the fields
+/// are failure responses.
+///
+/// Check [`Multipart`](axum::extract::Multipart) for more information.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::Multipart,
+///     response::json::{ApiResult, JsonResponse},
+/// };
+///
+/// async fn route(mut multipart: Multipart) -> ApiResult<Vec<String>> {
+///     let mut names: Vec<String> = Vec::new();
+///
+///     while let Some(field) = multipart.next_field().await? {
+///         names.push(field.name().unwrap_or_default().to_string());
+///     }
+///
+///     Ok(JsonResponse::new().data(names))
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Multipart(pub _Multipart);
+
+impl Multipart {
+    /// Get the next field of the request.
+    pub async fn next_field(
+        &mut self
+    ) -> Result<Option<Field<'_>>, MultipartFailure> {
+        self.0.next_field().await.map_err(MultipartFailure)
+    }
+}
+
+impl<S> FromRequest<S> for Multipart
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(
+        req: Request,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.multipart", async move {
+            #[cfg(feature = "decompression")]
+            let req: Request = decompress_request_fn(req).await?;
+
+            match _Multipart::from_request(req, state).await {
+                | Ok(val) => Ok(Self(val)),
+                | Err(rej) => Err(CreateJsonResponse::rejection()
+                    .status(rej.status())
+                    .add_error(create_multipart_rejection_error_fn(&rej))
+                    .create()),
+            }
+        })
+    }
+}
+
+/// Error while reading the fields of a [`Multipart`],
+/// which can be converted into a failure response
+/// or an [`ApiError`].
+#[derive(Debug)]
+pub struct MultipartFailure(pub MultipartError);
+
+impl MultipartFailure {
+    /// Get the status code of the error.
+    pub fn status(&self) -> StatusCode {
+        #[cfg(feature = "decompression")]
+        if let Some(err) = get_decompression_error_fn(&self.0) {
+            return err.status();
+        }
+
+        self.0.status()
+    }
+}
+
+impl From<MultipartFailure> for ApiError {
+    fn from(failure: MultipartFailure) -> Self {
+        Self::new()
+            .status(failure.status())
+            .add_error(create_multipart_field_error_fn(&failure.0))
+    }
+}
+
+impl IntoResponse for MultipartFailure {
+    fn into_response(self) -> Response {
+        ApiError::from(self).into_response()
+    }
+}
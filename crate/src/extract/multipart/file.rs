@@ -0,0 +1,294 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use axum::extract::multipart::{Field, MultipartError};
+use axum_core::response::IntoResponse;
+use http::StatusCode;
+use tempfile::{Builder, NamedTempFile};
+use tokio::{fs::File, io::AsyncWriteExt as _};
+
+use crate::{
+    extract::multipart::base::create_multipart_field_error_fn,
+    response::{
+        Response,
+        json::{ApiError, JsonResponseError, ResponseError},
+    },
+};
+
+/// Options for spooling a multipart field into a temp file,
+/// available with `multipart_file` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::extract::multipart::MultipartFileOptions;
+///
+/// let options: MultipartFileOptions = MultipartFileOptions::new()
+///     .dir("/var/tmp/uploads")
+///     .max_size(64 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MultipartFileOptions {
+    dir: Option<PathBuf>,
+    max_size: Option<u64>,
+}
+
+impl MultipartFileOptions {
+    /// Create new options with the system temp directory
+    /// and without a size limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the directory of the temp files.
+    pub fn dir<P: Into<PathBuf>>(
+        mut self,
+        dir: P,
+    ) -> Self {
+        self.dir = Some(dir.into());
+
+        self
+    }
+
+    /// Set the max size of a file in bytes.
+    pub fn max_size(
+        mut self,
+        max_size: u64,
+    ) -> Self {
+        self.max_size = Some(max_size);
+
+        self
+    }
+
+    /// Spool a multipart field into a temp file with the options.
+    ///
+    /// The field is streamed chunk by chunk
+    /// instead of buffering in memory,
+    /// and the temp file is removed when the file is dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     extract::{
+    ///         Multipart,
+    ///         multipart::{MultipartFile, MultipartFileOptions},
+    ///     },
+    ///     response::json::{ApiResult, JsonResponse},
+    /// };
+    ///
+    /// async fn route(mut multipart: Multipart) -> ApiResult<u64> {
+    ///     let options: MultipartFileOptions =
+    ///         MultipartFileOptions::new().max_size(1024 * 1024);
+    ///
+    ///     let mut size: u64 = 0;
+    ///
+    ///     while let Some(field) = multipart.next_field().await? {
+    ///         let file: MultipartFile = options.spool(field).await?;
+    ///
+    ///         size += file.size();
+    ///     }
+    ///
+    ///     Ok(JsonResponse::new().data(size))
+    /// }
+    /// ```
+    pub async fn spool(
+        &self,
+        mut field: Field<'_>,
+    ) -> Result<MultipartFile, MultipartFileError> {
+        let name: Option<String> = field.name().map(ToString::to_string);
+        let file_name: Option<String> =
+            field.file_name().map(ToString::to_string);
+        let content_type: Option<String> =
+            field.content_type().map(ToString::to_string);
+
+        let mut builder: Builder = Builder::new();
+
+        builder.prefix("jder_axum-");
+
+        let temp: NamedTempFile = match &self.dir {
+            | Some(dir) => builder.tempfile_in(dir),
+            | None => builder.tempfile(),
+        }?;
+
+        let mut writer: File = File::from_std(temp.as_file().try_clone()?);
+
+        let mut size: u64 = 0;
+
+        while let Some(chunk) =
+            field.chunk().await.map_err(MultipartFileError::Multipart)?
+        {
+            size += chunk.len() as u64;
+
+            if let Some(max_size) = self.max_size {
+                if size > max_size {
+                    return Err(MultipartFileError::TooLarge {
+                        name,
+                        max_size,
+                    });
+                }
+            }
+
+            writer.write_all(&chunk).await?;
+        }
+
+        writer.flush().await?;
+
+        Ok(MultipartFile { name, file_name, content_type, size, temp })
+    }
+}
+
+/// File spooled from a multipart field into a temp file,
+/// available with `multipart_file` feature.
+///
+/// The temp file is removed when dropped,
+/// use [`persist`](MultipartFile::persist) to keep it.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::{Multipart, multipart::MultipartFile},
+///     response::json::{ApiResult, JsonResponse},
+/// };
+///
+/// async fn route(mut multipart: Multipart) -> ApiResult {
+///     while let Some(field) = multipart.next_field().await? {
+///         let file: MultipartFile = MultipartFile::from_field(field).await?;
+///
+///         file.persist("./uploads/file")?;
+///     }
+///
+///     Ok(JsonResponse::new())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MultipartFile {
+    name: Option<String>,
+    file_name: Option<String>,
+    content_type: Option<String>,
+    size: u64,
+    temp: NamedTempFile,
+}
+
+impl MultipartFile {
+    /// Spool a multipart field into a temp file
+    /// with the default options.
+    pub async fn from_field(
+        field: Field<'_>
+    ) -> Result<Self, MultipartFileError> {
+        MultipartFileOptions::new().spool(field).await
+    }
+
+    /// Get the name of the field.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get the file name of the field.
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    /// Get the content type of the field.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Get the size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Get the path of the temp file.
+    pub fn path(&self) -> &Path {
+        self.temp.path()
+    }
+
+    /// Open the temp file for reading.
+    pub async fn open(&self) -> io::Result<File> {
+        File::open(self.temp.path()).await
+    }
+
+    /// Persist the temp file to a path,
+    /// which is no longer removed.
+    pub fn persist<P: AsRef<Path>>(
+        self,
+        path: P,
+    ) -> io::Result<()> {
+        self.temp.persist(path).map(|_| ()).map_err(|err| err.error)
+    }
+
+    /// Get the inner temp file.
+    pub fn into_inner(self) -> NamedTempFile {
+        self.temp
+    }
+}
+
+/// Error while spooling a multipart field into a temp file,
+/// which can be converted into a failure response
+/// or an [`ApiError`].
+#[derive(Debug)]
+pub enum MultipartFileError {
+    /// The file exceeds the max size.
+    TooLarge {
+        /// Name of the field.
+        name: Option<String>,
+        /// Max size of a file in bytes.
+        max_size: u64,
+    },
+    /// Error while reading the field.
+    Multipart(MultipartError),
+    /// Error while writing the temp file.
+    Io(io::Error),
+}
+
+impl MultipartFileError {
+    /// Get the status code of the error.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            | Self::TooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            | Self::Multipart(err) => err.status(),
+            | Self::Io(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<io::Error> for MultipartFileError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<MultipartFileError> for ApiError {
+    fn from(err: MultipartFileError) -> Self {
+        let error: JsonResponseError = match &err {
+            | MultipartFileError::TooLarge { name, max_size } => {
+                JsonResponseError::from(ResponseError::TooLarge)
+                    .path(
+                        std::iter::once("multipart".to_string())
+                            .chain(name.clone()),
+                    )
+                    .message(format!(
+                        "File is larger than the limit of {max_size} bytes"
+                    ))
+            },
+            | MultipartFileError::Multipart(err) => {
+                create_multipart_field_error_fn(err)
+            },
+            | MultipartFileError::Io(_) => {
+                JsonResponseError::from(ResponseError::Io)
+            },
+        };
+
+        Self::new().status(err.status()).add_error(error)
+    }
+}
+
+impl IntoResponse for MultipartFileError {
+    fn into_response(self) -> Response {
+        ApiError::from(self).into_response()
+    }
+}
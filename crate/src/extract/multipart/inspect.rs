@@ -0,0 +1,354 @@
+use std::{
+    future::Future,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{
+        Multipart,
+        multipart::{Field, MultipartError},
+    },
+};
+use axum_core::extract::{FromRef, FromRequest, Request};
+use axum_typed_multipart::TryFromMultipartWithState;
+use http::{StatusCode, request::Parts};
+
+use crate::{
+    extract::{
+        multipart::typed::create_typed_multipart_error_fn, trace::trace_extract,
+    },
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
+};
+
+/// Rejection of an uploaded part by a [`ContentInspector`].
+#[derive(Debug, Clone, Default)]
+pub struct ContentRejection {
+    /// Detail of the rejection.
+    pub message: Option<String>,
+}
+
+impl ContentRejection {
+    /// Create a new content rejection.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::multipart::ContentRejection;
+    ///
+    /// let rejection: ContentRejection = ContentRejection::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the detail of the rejection.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::extract::multipart::ContentRejection;
+    ///
+    /// let rejection: ContentRejection = ContentRejection::new()
+    ///     .message("Virus found");
+    /// ```
+    pub fn message<S: Into<String>>(
+        mut self,
+        message: S,
+    ) -> Self {
+        self.message = Some(message.into());
+
+        self
+    }
+}
+
+/// An uploaded part to be inspected,
+/// with streaming access to its content.
+pub struct ContentPart<'a> {
+    /// Field of the part.
+    field: Field<'a>,
+    /// Error while reading the part.
+    error: Option<MultipartError>,
+}
+
+impl ContentPart<'_> {
+    /// Get the name of the part.
+    pub fn name(&self) -> Option<&str> {
+        self.field.name()
+    }
+
+    /// Get the file name of the part.
+    pub fn file_name(&self) -> Option<&str> {
+        self.field.file_name()
+    }
+
+    /// Get the content type of the part.
+    pub fn content_type(&self) -> Option<&str> {
+        self.field.content_type()
+    }
+
+    /// Get the next chunk of the content.
+    ///
+    /// Returns `None` when the content is finished,
+    /// or the request is failed to be parsed,
+    /// which will be rejected after the inspection.
+    pub async fn chunk(&mut self) -> Option<Bytes> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        match self.field.chunk().await {
+            | Ok(chunk) => chunk,
+            | Err(err) => {
+                self.error = Some(err);
+                None
+            },
+        }
+    }
+}
+
+/// Inspector of uploaded parts,
+/// such as a virus scanner.
+///
+/// The inspector is invoked by [`InspectedMultipart`] on each part,
+/// and obtained from the router state.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::extract::multipart::{
+///     ContentInspector,
+///     ContentPart,
+///     ContentRejection,
+/// };
+///
+/// #[derive(Clone)]
+/// struct Scanner;
+///
+/// impl ContentInspector for Scanner {
+///     async fn inspect(
+///         &self,
+///         part: &mut ContentPart<'_>,
+///     ) -> Result<(), ContentRejection> {
+///         while let Some(chunk) = part.chunk().await {
+///             if chunk.windows(5).any(|w| w == b"virus") {
+///                 return Err(ContentRejection::new().message("Virus found"));
+///             }
+///         }
+///
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait ContentInspector: Send + Sync {
+    /// Inspect an uploaded part,
+    /// returns a rejection if the content is not allowed.
+    fn inspect(
+        &self,
+        part: &mut ContentPart<'_>,
+    ) -> impl Future<Output = Result<(), ContentRejection>> + Send;
+}
+
+/// Extractor that inspects each part of `multipart/form-data` requests
+/// with a [`ContentInspector`] before parsing,
+/// available with `typed_multipart` feature.
+///
+/// The request body is buffered,
+/// and a rejected part will be responded with
+/// `422 Unprocessable Entity` status and `content_rejected` code.
+///
+/// Check [`TypedMultipart`](crate::extract::multipart::TypedMultipart)
+/// for more information on parsing.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::post};
+/// use axum_typed_multipart::TryFromMultipart;
+/// use jder_axum::extract::multipart::{
+///     ContentInspector,
+///     ContentPart,
+///     ContentRejection,
+///     InspectedMultipart,
+/// };
+///
+/// #[derive(Clone)]
+/// struct Scanner;
+///
+/// impl ContentInspector for Scanner {
+///     async fn inspect(
+///         &self,
+///         part: &mut ContentPart<'_>,
+///     ) -> Result<(), ContentRejection> {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(TryFromMultipart)]
+/// struct Data {
+///     name: String,
+/// }
+///
+/// async fn route(data: InspectedMultipart<Data, Scanner>) {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", post(route))
+///     .with_state(Scanner);
+/// ```
+#[derive(Debug, Clone)]
+pub struct InspectedMultipart<T, I> {
+    /// Parsed data.
+    pub data: T,
+    /// Inspector.
+    inspector: PhantomData<fn() -> I>,
+}
+
+impl<T, I> Deref for InspectedMultipart<T, I> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T, I> DerefMut for InspectedMultipart<T, I> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+/// Create a parse failure response.
+fn create_parse_rejection_fn(
+    status: StatusCode,
+    message: String,
+) -> Response {
+    CreateJsonResponse::rejection()
+        .status(status)
+        .add_error(
+            JsonResponseError::from(ResponseError::Parse).message(message),
+        )
+        .create()
+}
+
+/// Create a multipart extractor from a buffered body.
+async fn create_multipart_fn<S: Send + Sync>(
+    parts: &Parts,
+    body: &Bytes,
+    state: &S,
+) -> Result<Multipart, Response> {
+    let req: Request =
+        Request::from_parts(parts.clone(), Body::from(body.clone()));
+
+    Multipart::from_request(req, state)
+        .await
+        .map_err(|rej| create_parse_rejection_fn(rej.status(), rej.body_text()))
+}
+
+/// Inspect each part of a multipart request.
+async fn inspect_fn<I: ContentInspector>(
+    inspector: &I,
+    multipart: &mut Multipart,
+) -> Result<(), Response> {
+    loop {
+        let field: Field<'_> = match multipart.next_field().await {
+            | Ok(Some(field)) => field,
+            | Ok(None) => return Ok(()),
+            | Err(err) => {
+                return Err(create_parse_rejection_fn(
+                    err.status(),
+                    err.body_text(),
+                ));
+            },
+        };
+
+        let name: String = field.name().unwrap_or_default().to_string();
+
+        let mut part: ContentPart<'_> = ContentPart { field, error: None };
+
+        let result: Result<(), ContentRejection> =
+            inspector.inspect(&mut part).await;
+
+        if let Some(err) = part.error {
+            return Err(create_parse_rejection_fn(
+                err.status(),
+                err.body_text(),
+            ));
+        }
+
+        if let Err(rejection) = result {
+            return Err(CreateJsonResponse::rejection()
+                .status(StatusCode::UNPROCESSABLE_ENTITY)
+                .add_error(
+                    JsonResponseError::from(ResponseError::ContentRejected)
+                        .path(["multipart", &name])
+                        .message(rejection.message.unwrap_or_else(|| {
+                            ResponseError::ContentRejected
+                                .as_message()
+                                .to_string()
+                        })),
+                )
+                .create());
+        }
+    }
+}
+
+impl<S, T, I> FromRequest<S> for InspectedMultipart<T, I>
+where
+    S: Send + Sync,
+    T: TryFromMultipartWithState<S>,
+    I: ContentInspector + FromRef<S>,
+{
+    type Rejection = Response;
+
+    async fn from_request(
+        req: Request,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.inspected_multipart", async move {
+            let (parts, body) = req.into_parts();
+
+            // buffer the body with the body limit
+            let body: Bytes = match Bytes::from_request(
+                Request::from_parts(parts.clone(), body),
+                state,
+            )
+            .await
+            {
+                | Ok(body) => body,
+                | Err(rej) => {
+                    return Err(create_parse_rejection_fn(
+                        rej.status(),
+                        rej.body_text(),
+                    ));
+                },
+            };
+
+            // inspect
+            let inspector: I = I::from_ref(state);
+
+            let mut multipart: Multipart =
+                create_multipart_fn(&parts, &body, state).await?;
+
+            inspect_fn(&inspector, &mut multipart).await?;
+
+            // parse
+            let mut multipart: Multipart =
+                create_multipart_fn(&parts, &body, state).await?;
+
+            match T::try_from_multipart_with_state(&mut multipart, state).await
+            {
+                | Ok(data) => Ok(Self { data, inspector: PhantomData }),
+                | Err(err) => Err(CreateJsonResponse::rejection()
+                    .status(err.get_status())
+                    .add_error(create_typed_multipart_error_fn(&err))
+                    .create()),
+            }
+        })
+    }
+}
@@ -1,7 +1,28 @@
+pub(crate) mod base;
+#[cfg(feature = "multipart_file")]
+pub(crate) mod file;
+
 /// Typed multipart extractor module,
 /// available with `typed-multipart` feature.
 #[cfg(feature = "typed_multipart")]
 pub mod typed;
 
+/// Content inspection module,
+/// available with `typed-multipart` feature.
+#[cfg(feature = "typed_multipart")]
+pub mod inspect;
+
+pub use crate::extract::multipart::base::{Multipart, MultipartFailure};
+
+#[cfg(feature = "multipart_file")]
+pub use crate::extract::multipart::file::{
+    MultipartFile, MultipartFileError, MultipartFileOptions,
+};
+
 #[cfg(feature = "typed_multipart")]
 pub use crate::extract::multipart::typed::TypedMultipart;
+
+#[cfg(feature = "typed_multipart")]
+pub use crate::extract::multipart::inspect::{
+    ContentInspector, ContentPart, ContentRejection, InspectedMultipart,
+};
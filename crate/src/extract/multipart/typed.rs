@@ -1,17 +1,46 @@
-use axum_core::response::{IntoResponse, Response as AxumResponse};
 use axum_typed_multipart::{BaseMultipart, TypedMultipartError};
 
 use crate::{
-    extract::json::Json,
+    extract::multipart::base::{
+        create_multipart_field_error_fn, create_multipart_rejection_error_fn,
+    },
     response::json::{JsonResponse, JsonResponseError, error::ResponseError},
 };
 
 /// Multipart failure response.
 pub type TypedMultipartFailureResponse = JsonResponse<()>;
 
-impl IntoResponse for TypedMultipartFailureResponse {
-    fn into_response(self) -> AxumResponse {
-        Json(self).into_response()
+/// Create an error from a typed multipart error,
+/// with the field in path, such as `["multipart", "name"]`.
+pub(crate) fn create_typed_multipart_error_fn(
+    error: &TypedMultipartError
+) -> JsonResponseError {
+    let (code, field): (ResponseError, Option<&str>) = match error {
+        | TypedMultipartError::InvalidRequest { source } => {
+            return create_multipart_rejection_error_fn(source);
+        },
+        | TypedMultipartError::InvalidRequestBody { source } => {
+            return create_multipart_field_error_fn(source);
+        },
+        | TypedMultipartError::MissingField { field_name }
+        | TypedMultipartError::WrongFieldType { field_name, .. }
+        | TypedMultipartError::DuplicateField { field_name }
+        | TypedMultipartError::UnknownField { field_name } => {
+            (ResponseError::Parse, Some(field_name))
+        },
+        | TypedMultipartError::FieldTooLarge { field_name, .. } => {
+            (ResponseError::TooLarge, Some(field_name))
+        },
+        | TypedMultipartError::NamelessField => (ResponseError::Parse, None),
+        | _ => (ResponseError::Server, None),
+    };
+
+    let error: JsonResponseError =
+        JsonResponseError::from(code).message(error.to_string());
+
+    match field {
+        | Some(field) => error.path(["multipart", field]),
+        | None => error,
     }
 }
 
@@ -20,11 +49,9 @@ impl From<TypedMultipartError> for TypedMultipartFailureResponse {
         Self {
             success: false,
             data: None,
-            errors: vec![
-                JsonResponseError::new()
-                    .code(ResponseError::Parse.as_code())
-                    .message(error.to_string()),
-            ],
+            errors: vec![create_typed_multipart_error_fn(&error)],
+            meta: None,
+            status: Some(error.get_status()),
         }
     }
 }
@@ -4,9 +4,12 @@ use axum::extract::NestedPath as _NestedPath;
 use axum_core::extract::FromRequestParts;
 use http::request::Parts;
 
-use crate::response::{
-    Response,
-    json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
 };
 
 /// Access the path the matched the route is nested at.
@@ -53,16 +56,17 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match _NestedPath::from_request_parts(parts, state).await {
-            | Ok(val) => Ok(NestedPath(val.as_str().into())),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.nested_path", async move {
+            match _NestedPath::from_request_parts(parts, state).await {
+                | Ok(val) => Ok(NestedPath(val.as_str().into())),
+                | Err(rej) => Err(CreateJsonResponse::rejection()
+                    .status(rej.status())
+                    .add_error(
+                        JsonResponseError::from(ResponseError::Parse)
+                            .message(rej.body_text()),
+                    )
+                    .create()),
+            }
+        })
     }
 }
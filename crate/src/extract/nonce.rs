@@ -0,0 +1,439 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use axum_core::extract::FromRequestParts;
+use http::{HeaderName, StatusCode, request::Parts};
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
+};
+
+/// Default header name of the nonce.
+pub const NONCE_HEADER: &str = "x-nonce";
+
+/// Default header name of the timestamp of the nonce,
+/// in seconds since the Unix epoch.
+pub const NONCE_TIMESTAMP_HEADER: &str = "x-nonce-timestamp";
+
+/// Default time to live of the seen nonces,
+/// which is also the accepted age of the timestamps.
+pub const NONCE_TTL_DEFAULT: Duration = Duration::from_secs(300);
+
+/// Default interval of removing expired nonces
+/// from the [`MemoryNonceStore`].
+pub const NONCE_SWEEP_INTERVAL_DEFAULT: Duration = Duration::from_secs(60);
+
+/// Maximum length of the nonces.
+pub const NONCE_MAX_LENGTH: usize = 128;
+
+/// Store of the seen nonces, used by the [`Nonce`] extractor,
+/// such as an in-memory map or a shared cache like Redis.
+///
+/// Stores shared between instances of a service
+/// protect requests replayed to other instances.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::{collections::HashSet, sync::Mutex, time::Duration};
+///
+/// use jder_axum::extract::nonce::NonceStore;
+///
+/// struct ForeverStore(Mutex<HashSet<String>>);
+///
+/// impl NonceStore for ForeverStore {
+///     fn insert(
+///         &self,
+///         nonce: &str,
+///         _: Duration,
+///     ) -> impl Future<Output = bool> + Send {
+///         let inserted: bool = self.0.lock().unwrap().insert(nonce.to_string());
+///
+///         async move { inserted }
+///     }
+/// }
+/// ```
+pub trait NonceStore: Send + Sync + 'static {
+    /// Insert a nonce expiring after the time to live,
+    /// returns `false` if the nonce is seen and not expired.
+    ///
+    /// Checking and inserting must be atomic,
+    /// or concurrent replays may be accepted.
+    fn insert(
+        &self,
+        nonce: &str,
+        ttl: Duration,
+    ) -> impl Future<Output = bool> + Send;
+}
+
+/// Object safe [`NonceStore`] held by the [`NonceConfig`].
+trait DynNonceStore: Send + Sync {
+    /// Insert a nonce with a boxed future.
+    fn insert_boxed<'a>(
+        &'a self,
+        nonce: &'a str,
+        ttl: Duration,
+    ) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>>;
+}
+
+impl<T: NonceStore> DynNonceStore for T {
+    fn insert_boxed<'a>(
+        &'a self,
+        nonce: &'a str,
+        ttl: Duration,
+    ) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>> {
+        Box::pin(self.insert(nonce, ttl))
+    }
+}
+
+/// Seen nonces of the [`MemoryNonceStore`].
+#[derive(Debug)]
+struct SeenNonces {
+    /// Expiry of the nonces.
+    nonces: HashMap<String, Instant>,
+    /// Time of the last removal of expired nonces.
+    swept: Instant,
+}
+
+/// In-memory [`NonceStore`] of a single instance.
+///
+/// Expired nonces are removed at most once per
+/// [`sweep_interval`](MemoryNonceStore::sweep_interval),
+/// instead of on every insert.
+#[derive(Debug)]
+pub struct MemoryNonceStore {
+    seen: Mutex<SeenNonces>,
+    sweep_interval: Duration,
+}
+
+impl Default for MemoryNonceStore {
+    fn default() -> Self {
+        Self {
+            seen: Mutex::new(SeenNonces {
+                nonces: HashMap::new(),
+                swept: Instant::now(),
+            }),
+            sweep_interval: NONCE_SWEEP_INTERVAL_DEFAULT,
+        }
+    }
+}
+
+impl MemoryNonceStore {
+    /// Create a new empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the interval of removing expired nonces,
+    /// [`NONCE_SWEEP_INTERVAL_DEFAULT`] by default.
+    pub fn sweep_interval(
+        mut self,
+        interval: Duration,
+    ) -> Self {
+        self.sweep_interval = interval;
+
+        self
+    }
+
+    /// Insert a nonce, returns `false` if the nonce is seen and not expired.
+    fn insert_fn(
+        &self,
+        nonce: &str,
+        ttl: Duration,
+    ) -> bool {
+        let mut seen = match self.seen.lock() {
+            | Ok(seen) => seen,
+            | Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let now: Instant = Instant::now();
+
+        if now.duration_since(seen.swept) >= self.sweep_interval {
+            seen.nonces.retain(|_, expires| *expires > now);
+            seen.swept = now;
+        }
+
+        if seen.nonces.get(nonce).is_some_and(|expires| *expires > now) {
+            return false;
+        }
+
+        seen.nonces.insert(nonce.to_string(), now + ttl);
+
+        true
+    }
+}
+
+impl NonceStore for MemoryNonceStore {
+    fn insert(
+        &self,
+        nonce: &str,
+        ttl: Duration,
+    ) -> impl Future<Output = bool> + Send {
+        let inserted: bool = self.insert_fn(nonce, ttl);
+
+        async move { inserted }
+    }
+}
+
+/// Configuration of [`Nonce`] extractor,
+/// added to the router with an [`Extension`](axum::Extension).
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use axum::{Extension, Router};
+/// use jder_axum::extract::nonce::{MemoryNonceStore, NonceConfig};
+///
+/// let router: Router = Router::new().layer(Extension(
+///     NonceConfig::new(MemoryNonceStore::new()).ttl(Duration::from_secs(60)),
+/// ));
+/// ```
+#[derive(Clone)]
+pub struct NonceConfig {
+    /// Store of the seen nonces.
+    store: Arc<dyn DynNonceStore>,
+    /// Header name of the nonce.
+    header: HeaderName,
+    /// Header name of the timestamp.
+    timestamp_header: HeaderName,
+    /// Time to live of the seen nonces.
+    ttl: Duration,
+}
+
+impl NonceConfig {
+    /// Create a new configuration with a store,
+    /// the [`x-nonce`](NONCE_HEADER) and
+    /// [`x-nonce-timestamp`](NONCE_TIMESTAMP_HEADER) headers
+    /// and the [default](NONCE_TTL_DEFAULT) time to live.
+    pub fn new<S: NonceStore>(store: S) -> Self {
+        Self {
+            store: Arc::new(store),
+            header: HeaderName::from_static(NONCE_HEADER),
+            timestamp_header: HeaderName::from_static(NONCE_TIMESTAMP_HEADER),
+            ttl: NONCE_TTL_DEFAULT,
+        }
+    }
+
+    /// Set the header name of the nonce.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the header name is invalid or not lowercase.
+    pub fn header(
+        mut self,
+        name: &'static str,
+    ) -> Self {
+        self.header = HeaderName::from_static(name);
+
+        self
+    }
+
+    /// Set the header name of the timestamp.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the header name is invalid or not lowercase.
+    pub fn timestamp_header(
+        mut self,
+        name: &'static str,
+    ) -> Self {
+        self.timestamp_header = HeaderName::from_static(name);
+
+        self
+    }
+
+    /// Set the time to live of the seen nonces,
+    /// which is also the accepted age of the timestamps,
+    /// in both directions for clock skews.
+    pub fn ttl(
+        mut self,
+        ttl: Duration,
+    ) -> Self {
+        self.ttl = ttl;
+
+        self
+    }
+}
+
+/// Create a failure response of a nonce.
+fn create_nonce_rejection_fn(
+    status: StatusCode,
+    error: ResponseError,
+    header: &HeaderName,
+    message: &str,
+) -> Response {
+    CreateJsonResponse::rejection()
+        .status(status)
+        .add_error(
+            JsonResponseError::new()
+                .code(error.as_code())
+                .path(["header", header.as_str()])
+                .message(message),
+        )
+        .create()
+}
+
+/// Get the current time in seconds since the Unix epoch.
+fn now_fn() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// Extractor that rejects replayed requests
+/// with client-supplied nonce and timestamp headers,
+/// available with `nonce` feature.
+///
+/// The timestamp, in seconds since the Unix epoch,
+/// must be within the time to live from now,
+/// so the nonce is only remembered while the request can be accepted.
+///
+/// The nonce is checked against the store of a [`NonceConfig`] extension.
+/// Requests without a valid nonce or timestamp,
+/// or with a timestamp outside the window, are rejected with
+/// `401 Unauthorized` failure responses,
+/// and requests with a seen nonce are rejected with
+/// `409 Conflict` failure responses.
+///
+/// The nonce is consumed even if the handler fails,
+/// clients should retry with a new nonce.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Extension, Router, routing::post};
+/// use jder_axum::extract::nonce::{MemoryNonceStore, Nonce, NonceConfig};
+///
+/// async fn route(Nonce(nonce): Nonce) {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/transfer", post(route))
+///     .layer(Extension(NonceConfig::new(MemoryNonceStore::new())));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nonce(pub String);
+
+axum_core::__impl_deref!(Nonce: String);
+
+impl<S> FromRequestParts<S> for Nonce
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.nonce", async move {
+            let config: &NonceConfig =
+                match parts.extensions.get::<NonceConfig>() {
+                    | Some(config) => config,
+                    | None => {
+                        return Err(CreateJsonResponse::rejection()
+                            .status(StatusCode::INTERNAL_SERVER_ERROR)
+                            .add_error(
+                                JsonResponseError::from(ResponseError::Server)
+                                    .message("Missing `NonceConfig` extension"),
+                            )
+                            .create());
+                    },
+                };
+
+            let nonce: &str = match parts
+                .headers
+                .get(&config.header)
+                .and_then(|value| value.to_str().ok())
+            {
+                | Some(nonce)
+                    if !nonce.is_empty() && nonce.len() <= NONCE_MAX_LENGTH =>
+                {
+                    nonce
+                },
+                | Some(_) => {
+                    return Err(create_nonce_rejection_fn(
+                        StatusCode::UNAUTHORIZED,
+                        ResponseError::Unauthorized,
+                        &config.header,
+                        "Invalid nonce",
+                    ));
+                },
+                | None => {
+                    return Err(create_nonce_rejection_fn(
+                        StatusCode::UNAUTHORIZED,
+                        ResponseError::Unauthorized,
+                        &config.header,
+                        "Missing nonce",
+                    ));
+                },
+            };
+
+            let timestamp: u64 = match parts
+                .headers
+                .get(&config.timestamp_header)
+                .and_then(|value| value.to_str().ok())
+            {
+                | Some(timestamp) => match timestamp.parse::<u64>() {
+                    | Ok(timestamp) => timestamp,
+                    | Err(_) => {
+                        return Err(create_nonce_rejection_fn(
+                            StatusCode::UNAUTHORIZED,
+                            ResponseError::Unauthorized,
+                            &config.timestamp_header,
+                            "Invalid nonce timestamp",
+                        ));
+                    },
+                },
+                | None => {
+                    return Err(create_nonce_rejection_fn(
+                        StatusCode::UNAUTHORIZED,
+                        ResponseError::Unauthorized,
+                        &config.timestamp_header,
+                        "Missing nonce timestamp",
+                    ));
+                },
+            };
+
+            let now: u64 = now_fn();
+            let window: u64 = config.ttl.as_secs();
+
+            if now.abs_diff(timestamp) > window {
+                return Err(create_nonce_rejection_fn(
+                    StatusCode::UNAUTHORIZED,
+                    ResponseError::Unauthorized,
+                    &config.timestamp_header,
+                    "Nonce timestamp is outside the window",
+                ));
+            }
+
+            // remember until the timestamp leaves the window
+            let ttl: Duration = Duration::from_secs(
+                timestamp.saturating_add(window).saturating_add(1) - now,
+            );
+
+            match config.store.insert_boxed(nonce, ttl).await {
+                | true => Ok(Self(nonce.to_string())),
+                | false => Err(create_nonce_rejection_fn(
+                    StatusCode::CONFLICT,
+                    ResponseError::Conflict,
+                    &config.header,
+                    "Nonce has already been used",
+                )),
+            }
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use axum_core::extract::{FromRef, FromRequestParts};
+use http::{StatusCode, request::Parts};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{
+            CreateJsonResponse, JsonResponseError,
+            pagination::{PageCursor, PageCursorError, PageCursorKey},
+        },
+    },
+};
+
+/// Name of the query parameter of the page cursor.
+pub const PAGE_CURSOR_QUERY: &str = "cursor";
+
+/// Extractor for the page cursor
+/// in the [`cursor`](PAGE_CURSOR_QUERY) query parameter,
+/// decoded with the [`PageCursorKey`] from the router state.
+///
+/// The cursor is `None` when the parameter is missing or empty,
+/// such as the request of the first page.
+///
+/// Malformed, tampered, outdated or expired cursors are rejected
+/// with `400 Bad Request` failure responses,
+/// with `parse` code and `["query", "cursor"]` path.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, extract::FromRef, routing::get};
+/// use jder_axum::{
+///     extract::pagination::PaginationCursor,
+///     response::{
+///         Response,
+///         json::{
+///             CreateJsonResponse,
+///             pagination::{PageCursor, PageCursorKey, Pagination},
+///         },
+///     },
+/// };
+///
+/// #[derive(Clone, FromRef)]
+/// struct AppState {
+///     cursor_key: PageCursorKey,
+/// }
+///
+/// // /items?cursor=...
+/// async fn route(
+///     PaginationCursor(cursor): PaginationCursor<u64>,
+///     axum::extract::State(key): axum::extract::State<PageCursorKey>,
+/// ) -> Response {
+///     let offset: u64 = cursor.map(|cursor| cursor.data).unwrap_or(0);
+///
+///     let items: Vec<u64> = (offset..offset + 20).collect();
+///
+///     CreateJsonResponse::success()
+///         .paginated(
+///             items,
+///             Pagination::new(0, 20)
+///                 .next_page_cursor(&PageCursor::new(offset + 20), &key),
+///         )
+///         .create()
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/items", get(route))
+///     .with_state(AppState { cursor_key: PageCursorKey::new("secret") });
+/// ```
+#[derive(Debug, Clone)]
+pub struct PaginationCursor<T>(pub Option<PageCursor<T>>);
+
+/// Create a failure response of an invalid page cursor.
+fn create_cursor_rejection_fn(error: PageCursorError) -> Response {
+    CreateJsonResponse::rejection()
+        .status(StatusCode::BAD_REQUEST)
+        .add_error(JsonResponseError::from(error))
+        .create()
+}
+
+impl<T, S> FromRequestParts<S> for PaginationCursor<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+    PageCursorKey: FromRef<S>,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.pagination_cursor", async move {
+            let query: Vec<(String, String)> =
+                serde_urlencoded::from_str(parts.uri.query().unwrap_or(""))
+                    .map_err(|_| {
+                        create_cursor_rejection_fn(PageCursorError::Malformed)
+                    })?;
+
+            let token: &str = match query
+                .iter()
+                .find(|(name, _)| name == PAGE_CURSOR_QUERY)
+            {
+                | Some((_, token)) if !token.is_empty() => token,
+                | _ => return Ok(Self(None)),
+            };
+
+            let key: PageCursorKey = PageCursorKey::from_ref(state);
+
+            PageCursor::decode(token, key.as_bytes())
+                .map(|cursor| Self(Some(cursor)))
+                .map_err(create_cursor_rejection_fn)
+        })
+    }
+}
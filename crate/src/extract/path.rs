@@ -1,15 +1,102 @@
-use axum::extract::Path as _Path;
+#[cfg(feature = "matched_path")]
+use axum::extract::MatchedPath;
+use axum::extract::{Path as _Path, path::ErrorKind, rejection::PathRejection};
 use axum_core::extract::{FromRequestParts, OptionalFromRequestParts};
 use http::request::Parts;
 use serde::de::DeserializeOwned;
 
-use crate::response::{
-    Response,
-    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
 };
 
+/// Get the name of a path parameter by index
+/// from the matched route pattern, such as `id` of `/users/{id}`.
+#[cfg(feature = "matched_path")]
+fn get_param_name_fn(
+    parts: &Parts,
+    index: usize,
+) -> Option<String> {
+    let pattern: &str = parts.extensions.get::<MatchedPath>()?.as_str();
+
+    pattern
+        .split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .map(|name| name.trim_start_matches('*'))
+        .nth(index)
+        .map(ToString::to_string)
+}
+
+/// Get the name of a path parameter by index,
+/// not available without `matched_path` feature.
+#[cfg(not(feature = "matched_path"))]
+fn get_param_name_fn(
+    _: &Parts,
+    _: usize,
+) -> Option<String> {
+    None
+}
+
+/// Get the error code and the parameter of a path rejection.
+///
+/// Programmer errors, such as missing path parameters
+/// or unsupported types, are mapped to server errors.
+fn get_path_error_fn(
+    rej: &PathRejection,
+    parts: &Parts,
+) -> (ResponseError, Option<String>) {
+    let kind: &ErrorKind = match rej {
+        | PathRejection::FailedToDeserializePathParams(err) => err.kind(),
+        | _ => return (ResponseError::Server, None),
+    };
+
+    match kind {
+        | ErrorKind::ParseErrorAtKey { key, .. }
+        | ErrorKind::InvalidUtf8InPathParam { key }
+        | ErrorKind::DeserializeError { key, .. } => {
+            (ResponseError::Parse, Some(key.clone()))
+        },
+        | ErrorKind::ParseErrorAtIndex { index, .. } => (
+            ResponseError::Parse,
+            Some(
+                get_param_name_fn(parts, *index)
+                    .unwrap_or_else(|| index.to_string()),
+            ),
+        ),
+        | ErrorKind::UnsupportedType { .. } => (ResponseError::Server, None),
+        | _ => (ResponseError::Parse, None),
+    }
+}
+
+/// Create a failure response from a path rejection,
+/// with the parameter in path, such as `["path", "id"]`.
+fn create_path_rejection_fn(
+    rej: PathRejection,
+    parts: &Parts,
+) -> Response {
+    let (code, param) = get_path_error_fn(&rej, parts);
+
+    let path: Vec<String> =
+        std::iter::once("path".to_string()).chain(param).collect();
+
+    CreateJsonResponse::rejection()
+        .status(rej.status())
+        .add_error(
+            JsonResponseError::from(code).path(path).message(rej.body_text()),
+        )
+        .create()
+}
+
 /// Extractor that parses path parameters.
 ///
+/// Rejections are failure responses with the parameter in path,
+/// such as `["path", "id"]`,
+/// and the names of tuple parameters
+/// are resolved with `matched_path` feature.
+///
 /// Check [`Path`](axum::extract::Path) for more information.
 ///
 /// ## Example
@@ -40,21 +127,16 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match <_Path<T> as FromRequestParts<S>>::from_request_parts(
-            parts, state,
-        )
-        .await
-        {
-            | Ok(val) => Ok(Self(val.0)),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.path", async move {
+            match <_Path<T> as FromRequestParts<S>>::from_request_parts(
+                parts, state,
+            )
+            .await
+            {
+                | Ok(val) => Ok(Self(val.0)),
+                | Err(rej) => Err(create_path_rejection_fn(rej, parts)),
+            }
+        })
     }
 }
 
@@ -69,21 +151,16 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Option<Self>, Self::Rejection> {
-        match <_Path<T> as OptionalFromRequestParts<S>>::from_request_parts(
-            parts, state,
-        )
-        .await
-        {
-            | Ok(Some(val)) => Ok(Some(Self(val.0))),
-            | Ok(None) => Ok(None),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.path", async move {
+            match <_Path<T> as OptionalFromRequestParts<S>>::from_request_parts(
+                parts, state,
+            )
+            .await
+            {
+                | Ok(Some(val)) => Ok(Some(Self(val.0))),
+                | Ok(None) => Ok(None),
+                | Err(rej) => Err(create_path_rejection_fn(rej, parts)),
+            }
+        })
     }
 }
@@ -0,0 +1,181 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use axum_core::extract::FromRequestParts;
+use http::{StatusCode, request::Parts};
+use serde::de::DeserializeOwned;
+use serde_qs::{Config, Deserializer as QsDeserializer, Error};
+
+use crate::{
+    extract::{rejection::get_error_path_fn, trace::trace_extract},
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
+};
+
+/// Maximum depth of the bracket segments of a query parameter key,
+/// such as `filter[status][in]`.
+pub const QS_QUERY_MAX_DEPTH: usize = 5;
+
+/// Get the key of a query parameter,
+/// if it has no bracket segments.
+fn get_bare_key_fn(param: &str) -> Option<&str> {
+    let key: &str = param.split_once('=').map_or(param, |(key, _)| key);
+
+    let has_brackets: bool =
+        key.contains('[') || key.to_ascii_lowercase().contains("%5b");
+
+    match has_brackets {
+        | true => None,
+        | false => Some(key),
+    }
+}
+
+/// Normalize repeated keys without bracket segments into `[]` segments,
+/// such as `tag=a&tag=b` into `tag[]=a&tag[]=b`,
+/// which are rejected as multiple values by `serde_qs`.
+fn normalize_query_fn(query: &str) -> Cow<'_, str> {
+    let params: Vec<&str> =
+        query.split('&').filter(|param| !param.is_empty()).collect();
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+
+    for key in params.iter().filter_map(|param| get_bare_key_fn(param)) {
+        *counts.entry(key).or_default() += 1;
+    }
+
+    if counts.values().all(|count| *count < 2) {
+        return Cow::Borrowed(query);
+    }
+
+    let params: Vec<String> = params
+        .into_iter()
+        .map(|param| match get_bare_key_fn(param) {
+            | Some(key) if counts.get(key).is_some_and(|count| *count > 1) => {
+                format!("{key}[]{}", &param[key.len()..])
+            },
+            | _ => param.to_string(),
+        })
+        .collect();
+
+    Cow::Owned(params.join("&"))
+}
+
+/// Create a failure response of a query string.
+fn create_qs_query_rejection_fn<P, I>(
+    path: P,
+    message: &str,
+) -> Response
+where
+    P: IntoIterator<Item = I>,
+    I: Into<String>,
+{
+    CreateJsonResponse::rejection()
+        .status(StatusCode::BAD_REQUEST)
+        .add_error(
+            JsonResponseError::from(ResponseError::Parse)
+                .path(path)
+                .message(message),
+        )
+        .create()
+}
+
+/// Extractor for deserializing query strings with repeated keys
+/// and bracket segments into a specified type with `serde_qs`,
+/// available with `qs_query` feature.
+///
+/// Supported grammar:
+///
+/// - repeated keys without brackets into sequences, such as `?tag=a&tag=b`
+/// - `[]` segments into sequences, such as `?tag[]=a&tag[]=b`
+/// - indexed segments into sequences, such as `?tag[1]=b&tag[0]=a`
+/// - named segments into nested structs or maps,
+///   such as `?filter[status]=open`
+/// - percent-encoded brackets as segments, such as `?filter%5Bstatus%5D=open`
+///
+/// Limits:
+///
+/// - the top level must be a struct or a map
+/// - keys are nested up to [`QS_QUERY_MAX_DEPTH`] bracket segments,
+///   and the rest of a deeper key is kept as a literal key
+/// - integer segments are always sequence indices, not map keys
+/// - a single value without brackets is not a sequence,
+///   use `?tag[]=a` for a sequence of one value
+/// - invalid UTF-8 is replaced with `U+FFFD`
+/// - `#[serde(flatten)]` is not supported, as the format is not self-describing
+///
+/// Failures are rejected with `400 Bad Request` status
+/// and the location of the key in path,
+/// such as `["query", "filter", "status"]`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use serde::Deserialize;
+/// use jder_axum::extract::QsQuery;
+///
+/// #[derive(Deserialize)]
+/// struct Filter {
+///     status: Option<String>,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct QueryParams {
+///     #[serde(default)]
+///     tag: Vec<String>,
+///     #[serde(default)]
+///     filter: Option<Filter>,
+/// }
+///
+/// // /issues?tag=a&tag=b&filter[status]=open
+/// async fn route(
+///     QsQuery(query): QsQuery<QueryParams>,
+/// ) {
+///     // tag = ["a", "b"]
+///     // filter.status = Some("open")
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QsQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for QsQuery<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.qs_query", async move {
+            // non-strict mode for percent-encoded brackets
+            let config: Config = Config::new(QS_QUERY_MAX_DEPTH, false);
+
+            let query: Cow<'_, str> =
+                normalize_query_fn(parts.uri.query().unwrap_or_default());
+
+            let de: QsDeserializer<'_> =
+                match QsDeserializer::with_config(&config, query.as_bytes()) {
+                    | Ok(de) => de,
+                    | Err(err) => {
+                        return Err(create_qs_query_rejection_fn(
+                            ["query"],
+                            &format!("Failed to parse query string: {err}"),
+                        ));
+                    },
+                };
+
+            match serde_path_to_error::deserialize::<_, T>(de) {
+                | Ok(val) => Ok(Self(val)),
+                | Err(err) => Err(create_qs_query_rejection_fn(
+                    get_error_path_fn::<Error>(&err, "query"),
+                    &format!("Failed to deserialize query string: {err}"),
+                )),
+            }
+        })
+    }
+}
+
+axum_core::__impl_deref!(QsQuery);
@@ -1,14 +1,17 @@
 use axum::extract::{Query as _Query, rejection::QueryRejection};
 use axum_core::extract::FromRequestParts;
-use http::{Uri, request::Parts};
+use http::{StatusCode, Uri, request::Parts};
 use serde::{
     Deserialize, Deserializer,
     de::{self, DeserializeOwned},
 };
 
-use crate::response::{
-    Response,
-    json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+use crate::{
+    extract::{rejection::get_error_path_fn, trace::trace_extract},
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
 };
 
 /// Deserializes empty query parameters as `None` instead of empty strings.
@@ -57,6 +60,20 @@ where
     }
 }
 
+/// Create a failure response from a query rejection,
+/// with the location of the deserialization error in path,
+/// such as `["query", "page"]`.
+fn create_query_rejection_fn(rej: QueryRejection) -> Response {
+    CreateJsonResponse::rejection()
+        .status(rej.status())
+        .add_error(
+            JsonResponseError::from(ResponseError::Parse)
+                .path(get_error_path_fn::<de::value::Error>(&rej, "query"))
+                .message(rej.body_text()),
+        )
+        .create()
+}
+
 /// Extractor for deserializing query strings into a specified type.
 ///
 /// Can be used with [`empty_as_none`] to treat empty query parameters as `None`.
@@ -96,17 +113,12 @@ where
         parts: &mut Parts,
         state: &S,
     ) -> Result<Self, Self::Rejection> {
-        match _Query::<T>::from_request_parts(parts, state).await {
-            | Ok(val) => Ok(Self(val.0)),
-            | Err(rej) => Err(CreateJsonResponse::failure()
-                .status(rej.status())
-                .add_error(
-                    JsonResponseError::new()
-                        .code(ResponseError::Parse.as_code())
-                        .message(rej.body_text()),
-                )
-                .create()),
-        }
+        trace_extract!("extract.query", async move {
+            match _Query::<T>::from_request_parts(parts, state).await {
+                | Ok(val) => Ok(Self(val.0)),
+                | Err(rej) => Err(create_query_rejection_fn(rej)),
+            }
+        })
     }
 }
 
@@ -144,3 +156,154 @@ where
 }
 
 axum_core::__impl_deref!(Query);
+
+/// Error of probing the fields of a struct.
+#[derive(Debug)]
+struct FieldsProbeError;
+
+impl std::fmt::Display for FieldsProbeError {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str("fields probe")
+    }
+}
+
+impl std::error::Error for FieldsProbeError {}
+
+impl de::Error for FieldsProbeError {
+    fn custom<M: std::fmt::Display>(_: M) -> Self {
+        Self
+    }
+}
+
+/// Deserializer for getting the field names of a struct,
+/// which always fails after the names are recorded.
+struct FieldsProbe<'a> {
+    fields: &'a mut Option<&'static [&'static str]>,
+}
+
+impl<'de> Deserializer<'de> for FieldsProbe<'_> {
+    type Error = FieldsProbeError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(
+        self,
+        _: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(FieldsProbeError)
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.fields = Some(fields);
+
+        Err(FieldsProbeError)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Get the field names of a struct type.
+///
+/// Returns `None` if the type is not deserialized as a struct,
+/// such as maps or flattened structs.
+fn get_fields_fn<'de, T: Deserialize<'de>>() -> Option<&'static [&'static str]>
+{
+    let mut fields: Option<&'static [&'static str]> = None;
+
+    let _ = T::deserialize(FieldsProbe { fields: &mut fields });
+
+    fields
+}
+
+/// Extractor for deserializing query strings into a specified type,
+/// rejecting the query parameters that are not fields of the type.
+///
+/// Unknown parameters are rejected with `400 Bad Request` status
+/// and the parameter in path, such as `["query", "pgae"]`,
+/// as a single rejection for all extractions
+/// without `#[serde(deny_unknown_fields)]` on each type.
+///
+/// The check is skipped for types not deserialized as a struct,
+/// such as maps or structs with `#[serde(flatten)]` fields.
+///
+/// ## Example
+///
+/// ```no_run
+/// use serde::Deserialize;
+/// use jder_axum::extract::query::StrictQuery;
+///
+/// #[derive(Deserialize)]
+/// struct QueryParams {
+///     page: usize,
+/// }
+///
+/// // /products?page=1&per_page=60 is rejected
+/// async fn route(
+///     StrictQuery(query): StrictQuery<QueryParams>,
+/// ) {
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StrictQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for StrictQuery<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.strict_query", async move {
+            let query: T =
+                match _Query::<T>::from_request_parts(parts, state).await {
+                    | Ok(val) => val.0,
+                    | Err(rej) => return Err(create_query_rejection_fn(rej)),
+                };
+
+            let Some(fields) = get_fields_fn::<T>() else {
+                return Ok(Self(query));
+            };
+
+            let pairs: Vec<(String, String)> = serde_urlencoded::from_str(
+                parts.uri.query().unwrap_or_default(),
+            )
+            .unwrap_or_default();
+
+            let errors: Vec<JsonResponseError> = pairs
+                .into_iter()
+                .filter(|(key, _)| !fields.contains(&key.as_str()))
+                .map(|(key, _)| {
+                    JsonResponseError::from(ResponseError::Parse)
+                        .path(["query", &key])
+                        .message(format!("Unknown query parameter `{key}`"))
+                })
+                .collect();
+
+            if errors.is_empty() {
+                return Ok(Self(query));
+            }
+
+            Err(CreateJsonResponse::rejection()
+                .status(StatusCode::BAD_REQUEST)
+                .add_errors(errors)
+                .create())
+        })
+    }
+}
+
+axum_core::__impl_deref!(StrictQuery);
@@ -0,0 +1,35 @@
+use std::error::Error;
+
+use serde_path_to_error::Segment;
+
+/// Get the location of a deserialization error from a rejection,
+/// with the source of the request as the first segment,
+/// such as `["json", "user", "age"]`.
+///
+/// Returns an empty path if the location is not available.
+pub(crate) fn get_error_path_fn<E: Error + 'static>(
+    rej: &(dyn Error + 'static),
+    source: &str,
+) -> Vec<String> {
+    let mut current: Option<&(dyn Error + 'static)> = Some(rej);
+
+    while let Some(err) = current {
+        if let Some(err) = err.downcast_ref::<serde_path_to_error::Error<E>>() {
+            let segments =
+                err.path().iter().filter_map(|segment| match segment {
+                    | Segment::Seq { index } => Some(index.to_string()),
+                    | Segment::Map { key } => Some(key.clone()),
+                    | Segment::Enum { variant } => Some(variant.clone()),
+                    | Segment::Unknown => None,
+                });
+
+            return std::iter::once(source.to_string())
+                .chain(segments)
+                .collect();
+        }
+
+        current = err.source();
+    }
+
+    Vec::new()
+}
@@ -0,0 +1,287 @@
+use axum_core::extract::FromRequestParts;
+use http::{StatusCode, header, request::Parts};
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, error::ResponseError},
+    },
+};
+
+/// Header name of the timezone hint.
+pub const REQUEST_CONTEXT_TIMEZONE_HEADER: &str = "x-timezone";
+
+/// Header name of the currency hint.
+pub const REQUEST_CONTEXT_CURRENCY_HEADER: &str = "x-currency";
+
+/// Query key of the locale hint.
+pub const REQUEST_CONTEXT_LOCALE_QUERY: &str = "locale";
+
+/// Query key of the timezone hint.
+pub const REQUEST_CONTEXT_TIMEZONE_QUERY: &str = "timezone";
+
+/// Query key of the currency hint.
+pub const REQUEST_CONTEXT_CURRENCY_QUERY: &str = "currency";
+
+/// Extractor for the locale, timezone and currency hints of a request.
+///
+/// Each hint is resolved through the following, in order:
+/// - Query parameter (`locale`, `timezone`, `currency`)
+/// - Header (`Accept-Language`, `X-Timezone`, `X-Currency`)
+///
+/// For `Accept-Language`, the language tag with the highest quality is used.
+///
+/// The hints are validated by their syntax:
+/// - locale must be a BCP 47 language tag, such as `en-US`
+/// - timezone must be an IANA timezone name, such as `Asia/Tokyo`
+/// - currency must be an ISO 4217 code, such as `USD`
+///
+/// Invalid hints will be rejected with a failure response.
+///
+/// The context is stored in the request extensions after the first extraction,
+/// so that it can be reused by the later extractors and layers.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::extract::RequestContext;
+///
+/// // /products?currency=usd
+/// async fn route(
+///     ctx: RequestContext
+/// ) {
+///     let locale: Option<String> = ctx.locale;
+///     let timezone: Option<String> = ctx.timezone;
+///     // currency = Some("USD")
+///     let currency: Option<String> = ctx.currency;
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestContext {
+    /// Locale of the request, such as `en-US`.
+    pub locale: Option<String>,
+    /// Timezone of the request, such as `Asia/Tokyo`.
+    pub timezone: Option<String>,
+    /// Currency of the request in uppercase, such as `USD`.
+    pub currency: Option<String>,
+}
+
+impl<S> FromRequestParts<S> for RequestContext
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.request_context", async move {
+            if let Some(ctx) = parts.extensions.get::<RequestContext>() {
+                return Ok(ctx.clone());
+            }
+
+            let query: Vec<(String, String)> = match parts.uri.query() {
+                | Some(query) => match serde_urlencoded::from_str(query) {
+                    | Ok(query) => query,
+                    | Err(err) => {
+                        return Err(CreateJsonResponse::rejection()
+                            .status(StatusCode::BAD_REQUEST)
+                            .add_error(
+                                JsonResponseError::from(ResponseError::Parse)
+                                    .path(["query"])
+                                    .message(err.to_string()),
+                            )
+                            .create());
+                    },
+                },
+                | None => Vec::new(),
+            };
+
+            let mut errors: Vec<JsonResponseError> = Vec::new();
+
+            let locale: Option<String> = resolve(
+                parts,
+                &query,
+                REQUEST_CONTEXT_LOCALE_QUERY,
+                header::ACCEPT_LANGUAGE.as_str(),
+                &mut errors,
+                |value, is_header| {
+                    let value: &str = if is_header {
+                        preferred_language(value)?
+                    } else {
+                        value
+                    };
+
+                    is_language_tag(value).then(|| value.to_string())
+                },
+                "Invalid locale",
+            );
+
+            let timezone: Option<String> = resolve(
+                parts,
+                &query,
+                REQUEST_CONTEXT_TIMEZONE_QUERY,
+                REQUEST_CONTEXT_TIMEZONE_HEADER,
+                &mut errors,
+                |value, _| is_timezone(value).then(|| value.to_string()),
+                "Invalid timezone",
+            );
+
+            let currency: Option<String> = resolve(
+                parts,
+                &query,
+                REQUEST_CONTEXT_CURRENCY_QUERY,
+                REQUEST_CONTEXT_CURRENCY_HEADER,
+                &mut errors,
+                |value, _| {
+                    is_currency(value).then(|| value.to_ascii_uppercase())
+                },
+                "Invalid currency",
+            );
+
+            if !errors.is_empty() {
+                return Err(CreateJsonResponse::rejection()
+                    .status(StatusCode::BAD_REQUEST)
+                    .add_errors(errors)
+                    .create());
+            }
+
+            let ctx: RequestContext =
+                RequestContext { locale, timezone, currency };
+
+            parts.extensions.insert(ctx.clone());
+
+            Ok(ctx)
+        })
+    }
+}
+
+/// Resolve a hint from the query or the header.
+///
+/// The parser receives the raw value and whether it is from the header,
+/// and returns `None` for an invalid value.
+fn resolve<F>(
+    parts: &Parts,
+    query: &[(String, String)],
+    key: &str,
+    header: &str,
+    errors: &mut Vec<JsonResponseError>,
+    parser: F,
+    message: &str,
+) -> Option<String>
+where
+    F: Fn(&str, bool) -> Option<String>,
+{
+    if let Some((_, value)) = query.iter().find(|(k, _)| k == key) {
+        let value: &str = value.trim();
+
+        if value.is_empty() {
+            return None;
+        }
+
+        return match parser(value, false) {
+            | Some(value) => Some(value),
+            | None => {
+                errors.push(
+                    JsonResponseError::from(ResponseError::Parse)
+                        .path(["query", key])
+                        .message(message),
+                );
+
+                None
+            },
+        };
+    }
+
+    let value: &str = parts.headers.get(header)?.to_str().ok()?.trim();
+
+    if value.is_empty() {
+        return None;
+    }
+
+    match parser(value, true) {
+        | Some(value) => Some(value),
+        | None => {
+            errors.push(
+                JsonResponseError::from(ResponseError::Parse)
+                    .path(["header", header])
+                    .message(message),
+            );
+
+            None
+        },
+    }
+}
+
+/// Get the language tag with the highest quality
+/// from an `Accept-Language` header value.
+///
+/// Returns `None` if there is no valid language range.
+fn preferred_language(value: &str) -> Option<&str> {
+    let mut preferred: Option<(&str, f32)> = None;
+
+    for range in value.split(',') {
+        let mut params = range.split(';');
+
+        let tag: &str = params.next().unwrap_or_default().trim();
+
+        if tag.is_empty() || tag == "*" {
+            continue;
+        }
+
+        let quality: f32 = params
+            .find_map(|param| param.trim().strip_prefix("q="))
+            .map(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(Some(1.0))?;
+
+        if quality <= 0.0 {
+            continue;
+        }
+
+        if preferred.is_none_or(|(_, q)| quality > q) {
+            preferred = Some((tag, quality));
+        }
+    }
+
+    preferred.map(|(tag, _)| tag)
+}
+
+/// Check if a value is a BCP 47 language tag by syntax.
+fn is_language_tag(value: &str) -> bool {
+    let mut subtags = value.split('-');
+
+    let primary: &str = subtags.next().unwrap_or_default();
+
+    if !(2..=8).contains(&primary.len())
+        || !primary.chars().all(|c| c.is_ascii_alphabetic())
+    {
+        return false;
+    }
+
+    subtags.all(|subtag| {
+        (1..=8).contains(&subtag.len())
+            && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+/// Check if a value is an IANA timezone name by syntax.
+fn is_timezone(value: &str) -> bool {
+    if value.len() > 64 {
+        return false;
+    }
+
+    value.split('/').all(|part| {
+        part.starts_with(|c: char| c.is_ascii_alphabetic())
+            && part.len() <= 32
+            && part.chars().all(|c| {
+                c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '+'
+            })
+    })
+}
+
+/// Check if a value is an ISO 4217 currency code by syntax.
+fn is_currency(value: &str) -> bool {
+    value.len() == 3 && value.chars().all(|c| c.is_ascii_alphabetic())
+}
@@ -0,0 +1,115 @@
+use std::{
+    cell::RefCell,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use axum_core::extract::FromRequestParts;
+use http::{StatusCode, request::Parts};
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
+};
+
+/// Header name of the request ID.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Key of the request ID in meta.
+pub const REQUEST_ID_META_KEY: &str = "request_id";
+
+thread_local! {
+    /// Request ID of the request being handled on the current thread.
+    static CURRENT_REQUEST_ID: RefCell<Option<String>> =
+        const { RefCell::new(None) };
+}
+
+/// Get the request ID of the request being handled,
+/// set while the future of a [`RequestIdScope`] is polled.
+pub(crate) fn current_request_id_fn() -> Option<String> {
+    CURRENT_REQUEST_ID.with(|id| id.borrow().clone())
+}
+
+/// Future with the request ID of the request being handled,
+/// responses created when polling the future pick the ID up.
+pub(crate) struct RequestIdScope<F> {
+    id: Option<String>,
+    fut: Pin<Box<F>>,
+}
+
+impl<F> RequestIdScope<F> {
+    /// Create a new scope of a request ID.
+    pub(crate) fn new(
+        id: String,
+        fut: F,
+    ) -> Self {
+        Self { id: Some(id), fut: Box::pin(fut) }
+    }
+}
+
+impl<F: Future> Future for RequestIdScope<F> {
+    type Output = F::Output;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let this: &mut Self = self.get_mut();
+
+        let prev: Option<String> =
+            CURRENT_REQUEST_ID.with(|id| id.replace(this.id.take()));
+
+        let poll: Poll<F::Output> = this.fut.as_mut().poll(cx);
+
+        this.id = CURRENT_REQUEST_ID.with(|id| id.replace(prev));
+
+        poll
+    }
+}
+
+/// Extractor that gets the request ID
+/// generated or propagated by the
+/// [`PropagateRequestId`](crate::layers::request_id::PropagateRequestId) layer,
+/// available with `request_id` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::extract::RequestId;
+///
+/// async fn route(RequestId(id): RequestId) {
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RequestId(pub String);
+
+axum_core::__impl_deref!(RequestId: String);
+
+impl<S> FromRequestParts<S> for RequestId
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.request_id", async move {
+            match parts.extensions.get::<RequestId>() {
+                | Some(id) => Ok(id.clone()),
+                | None => Err(CreateJsonResponse::rejection()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .add_error(
+                        JsonResponseError::from(ResponseError::Server)
+                            .message("Missing `PropagateRequestId` layer"),
+                    )
+                    .create()),
+            }
+        })
+    }
+}
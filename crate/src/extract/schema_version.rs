@@ -0,0 +1,245 @@
+use std::{collections::BTreeMap, fmt, sync::Arc};
+
+use axum_core::extract::FromRequestParts;
+use http::{StatusCode, request::Parts};
+use serde_json::Value;
+
+use crate::{
+    extract::trace::trace_extract,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
+};
+
+/// Header name of the schema version.
+pub const SCHEMA_VERSION_HEADER: &str = "x-schema-version";
+
+/// Function migrating data to the previous schema version.
+type Migration = Arc<dyn Fn(Value) -> Value + Send + Sync>;
+
+/// Migrations of the response data to older schema versions,
+/// used by the [`SchemaVersion`] extractor,
+/// added to the router with an [`Extension`](axum::Extension).
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Extension, Router};
+/// use jder_axum::extract::schema_version::SchemaMigrations;
+/// use serde_json::Value;
+///
+/// let router: Router = Router::new().layer(Extension(
+///     SchemaMigrations::new(2)
+///         // from version 2 to version 1, `name` was `full_name`
+///         .downgrade(1, |mut data: Value| {
+///             if let Some(data) = data.as_object_mut() {
+///                 if let Some(name) = data.remove("name") {
+///                     data.insert("full_name".to_string(), name);
+///                 }
+///             }
+///
+///             data
+///         }),
+/// ));
+/// ```
+#[derive(Clone)]
+pub struct SchemaMigrations {
+    /// Current schema version.
+    current: u32,
+    /// Migrations by the version to migrate to.
+    migrations: Arc<BTreeMap<u32, Migration>>,
+}
+
+impl SchemaMigrations {
+    /// Create new migrations with the current schema version.
+    pub fn new(current: u32) -> Self {
+        Self { current, migrations: Arc::new(BTreeMap::new()) }
+    }
+
+    /// Register a migration from the data of `version + 1`
+    /// to the data of `version`.
+    pub fn downgrade<F>(
+        mut self,
+        version: u32,
+        migration: F,
+    ) -> Self
+    where
+        F: Fn(Value) -> Value + Send + Sync + 'static,
+    {
+        Arc::make_mut(&mut self.migrations)
+            .insert(version, Arc::new(migration));
+
+        self
+    }
+
+    /// Get the current schema version.
+    pub fn current(&self) -> u32 {
+        self.current
+    }
+
+    /// Get the oldest schema version the data can be migrated to,
+    /// with continuous migrations from the current version.
+    pub fn oldest(&self) -> u32 {
+        let mut version: u32 = self.current;
+
+        while version > 0 && self.migrations.contains_key(&(version - 1)) {
+            version -= 1;
+        }
+
+        version
+    }
+
+    /// Migrate the data of the current version to an older version.
+    pub(crate) fn migrate(
+        &self,
+        mut data: Value,
+        version: u32,
+    ) -> Value {
+        for (_, migration) in self.migrations.range(version..self.current).rev()
+        {
+            data = migration(data);
+        }
+
+        data
+    }
+}
+
+impl fmt::Debug for SchemaMigrations {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("SchemaMigrations")
+            .field("current", &self.current)
+            .field("versions", &self.migrations.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Extractor that gets the schema version pinned by the client
+/// with the [`x-schema-version`](SCHEMA_VERSION_HEADER) header,
+/// available with `schema_version` feature.
+///
+/// The current version is used without the header,
+/// and the data of success responses is migrated to the pinned version
+/// with the `schema_version` function of the success builder.
+///
+/// Rejections are `400 Bad Request` failure responses
+/// for invalid or unsupported versions,
+/// with `["header", "x-schema-version"]` in path.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::SchemaVersion,
+///     response::{Response, json::CreateJsonResponse},
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// async fn route(version: SchemaVersion) -> Response {
+///     CreateJsonResponse::success()
+///         .data(User { name: "Name".to_string() })
+///         .schema_version(&version)
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SchemaVersion {
+    /// Pinned schema version.
+    version: u32,
+    /// Migrations of the response data.
+    migrations: SchemaMigrations,
+}
+
+impl SchemaVersion {
+    /// Get the pinned schema version.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Check whether the pinned schema version is the current version.
+    pub fn is_current(&self) -> bool {
+        self.version == self.migrations.current
+    }
+
+    /// Migrate the data of the current version to the pinned version.
+    pub(crate) fn migrate(
+        &self,
+        data: Value,
+    ) -> Value {
+        self.migrations.migrate(data, self.version)
+    }
+}
+
+/// Create a rejection of an invalid schema version.
+fn create_schema_version_rejection_fn(message: &str) -> Response {
+    CreateJsonResponse::rejection()
+        .status(StatusCode::BAD_REQUEST)
+        .add_error(
+            JsonResponseError::from(ResponseError::Parse)
+                .path(["header", SCHEMA_VERSION_HEADER])
+                .message(message),
+        )
+        .create()
+}
+
+impl<S> FromRequestParts<S> for SchemaVersion
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.schema_version", async move {
+            let migrations: SchemaMigrations =
+                match parts.extensions.get::<SchemaMigrations>() {
+                    | Some(migrations) => migrations.clone(),
+                    | None => {
+                        return Err(CreateJsonResponse::rejection()
+                            .status(StatusCode::INTERNAL_SERVER_ERROR)
+                            .add_error(
+                                JsonResponseError::from(ResponseError::Server)
+                                    .message(
+                                        "Missing `SchemaMigrations` extension",
+                                    ),
+                            )
+                            .create());
+                    },
+                };
+
+            let version: u32 = match parts.headers.get(SCHEMA_VERSION_HEADER) {
+                | Some(value) => match value
+                    .to_str()
+                    .ok()
+                    .and_then(|value| value.trim().parse::<u32>().ok())
+                {
+                    | Some(version) => version,
+                    | None => {
+                        return Err(create_schema_version_rejection_fn(
+                            "Invalid schema version",
+                        ));
+                    },
+                },
+                | None => migrations.current,
+            };
+
+            if version < migrations.oldest() || version > migrations.current {
+                return Err(create_schema_version_rejection_fn(
+                    "Unsupported schema version",
+                ));
+            }
+
+            Ok(Self { version, migrations })
+        })
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "tracing")]
+use std::{convert::Infallible, future::Future, time::Instant};
+
+#[cfg(feature = "tracing")]
+use http::StatusCode;
+#[cfg(feature = "tracing")]
+use tracing::{Instrument as _, Span};
+
+#[cfg(feature = "tracing")]
+use crate::response::Response;
+
+/// Rejection of an extraction recorded in a span.
+#[cfg(feature = "tracing")]
+pub(crate) trait TraceRejection {
+    /// Status code of the rejection.
+    fn status(&self) -> StatusCode;
+}
+
+#[cfg(feature = "tracing")]
+impl TraceRejection for Response {
+    fn status(&self) -> StatusCode {
+        self.status()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl TraceRejection for Infallible {
+    fn status(&self) -> StatusCode {
+        match *self {}
+    }
+}
+
+/// Run an extraction in a span named by the extractor,
+/// such as `extract.json`, with `tracing` feature.
+///
+/// The span records the outcome, the status of the rejection
+/// and the duration in milliseconds.
+#[cfg(feature = "tracing")]
+macro_rules! trace_extract {
+    ($name:literal, $fut:expr) => {
+        crate::extract::trace::trace_extract_fn(
+            tracing::debug_span!(
+                $name,
+                outcome = tracing::field::Empty,
+                status = tracing::field::Empty,
+                duration_ms = tracing::field::Empty,
+            ),
+            $fut,
+        )
+        .await
+    };
+}
+
+/// Run an extraction without a span.
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace_extract {
+    ($name:literal, $fut:expr) => {
+        $fut.await
+    };
+}
+
+pub(crate) use trace_extract;
+
+/// Run an extraction in a span,
+/// and record the outcome and the duration.
+#[cfg(feature = "tracing")]
+pub(crate) async fn trace_extract_fn<T, R, F>(
+    span: Span,
+    fut: F,
+) -> Result<T, R>
+where
+    R: TraceRejection,
+    F: Future<Output = Result<T, R>>,
+{
+    let start: Instant = Instant::now();
+
+    let result: Result<T, R> = fut.instrument(span.clone()).await;
+
+    span.record("duration_ms", start.elapsed().as_millis() as u64);
+
+    match &result {
+        | Ok(_) => {
+            span.record("outcome", "ok");
+        },
+        | Err(rej) => {
+            span.record("outcome", "rejected");
+            span.record("status", TraceRejection::status(rej).as_u16());
+        },
+    }
+
+    result
+}
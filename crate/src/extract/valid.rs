@@ -0,0 +1,404 @@
+use axum_core::{
+    extract::{FromRequest, FromRequestParts, Request},
+    response::IntoResponse,
+};
+use http::{StatusCode, request::Parts};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    extract::{Path, trace::trace_extract},
+    response::{
+        Response,
+        json::{CreateJsonResponse, ValidationErrors},
+    },
+};
+
+/// Validation of an extracted value,
+/// used by the [`Valid`] extractor.
+///
+/// Each violated field should be added to the errors
+/// with the field path in `path`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::valid::Validate,
+///     response::json::ValidationErrors,
+/// };
+///
+/// struct CreateUser {
+///     name: String,
+/// }
+///
+/// impl Validate for CreateUser {
+///     fn validate(&self) -> Result<(), ValidationErrors> {
+///         let mut errors: ValidationErrors = ValidationErrors::new();
+///
+///         if self.name.len() < 3 {
+///             errors.add(["name"], "length", "Name is too short");
+///         }
+///
+///         match errors.is_empty() {
+///             | true => Ok(()),
+///             | false => Err(errors),
+///         }
+///     }
+/// }
+/// ```
+pub trait Validate {
+    /// Validate the value.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+/// Validation with [`garde`], available with `garde` feature,
+/// where the context is created with [`Default`],
+/// and each failure of the report is in the errors.
+///
+/// ## Example
+///
+/// ```no_run
+/// use garde::Validate;
+/// use jder_axum::extract::{Json, valid::Valid};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct CreateUser {
+///     #[garde(length(min = 3))]
+///     name: String,
+/// }
+///
+/// async fn route(
+///     Valid(Json(payload)): Valid<Json<CreateUser>>
+/// ) {
+///     // ...
+/// }
+/// ```
+#[cfg(feature = "garde")]
+impl<T> Validate for T
+where
+    T: garde::Validate,
+    T::Context: Default,
+{
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        garde::Validate::validate(self).map_err(ValidationErrors::from)
+    }
+}
+
+/// Extractor holding a value to be validated by [`Valid`].
+pub trait HasValidate {
+    /// Type of the value to be validated.
+    type Validate: Validate;
+
+    /// Get the value to be validated.
+    fn get_validate(&self) -> &Self::Validate;
+}
+
+#[cfg(feature = "json")]
+impl<T: Validate> HasValidate for crate::extract::Json<T> {
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(feature = "form")]
+impl<T: Validate> HasValidate for crate::extract::Form<T> {
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(feature = "query")]
+impl<T: Validate> HasValidate for crate::extract::Query<T> {
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Validate> HasValidate for Path<T> {
+    type Validate = T;
+
+    fn get_validate(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Extractor that validates the value of another extractor
+/// with [`Validate`] after extraction.
+///
+/// The rejection of the inner extractor is returned as is,
+/// and the validation failure is rejected with
+/// `422 Unprocessable Entity` status,
+/// listing each violated field in `path`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::{Json, valid::{Valid, Validate}},
+///     response::json::ValidationErrors,
+/// };
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct CreateUser {
+///     name: String,
+/// }
+///
+/// impl Validate for CreateUser {
+///     fn validate(&self) -> Result<(), ValidationErrors> {
+///         let mut errors: ValidationErrors = ValidationErrors::new();
+///
+///         if self.name.is_empty() {
+///             errors.add(["name"], "required", "Name is required");
+///         }
+///
+///         match errors.is_empty() {
+///             | true => Ok(()),
+///             | false => Err(errors),
+///         }
+///     }
+/// }
+///
+/// async fn route(
+///     Valid(Json(payload)): Valid<Json<CreateUser>>
+/// ) {
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Valid<E>(pub E);
+
+impl<E> Valid<E> {
+    /// Get the inner extractor.
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+/// Create a validation failure response.
+fn create_validation_rejection_fn(errors: ValidationErrors) -> Response {
+    CreateJsonResponse::rejection()
+        .status(StatusCode::UNPROCESSABLE_ENTITY)
+        .validation_errors(errors)
+        .create()
+}
+
+/// Validate the value of an extractor.
+fn validate_fn<E: HasValidate>(
+    extractor: E
+) -> Result<Valid<E>, ValidationErrors> {
+    extractor.get_validate().validate().map(|()| Valid(extractor))
+}
+
+impl<E, S> FromRequest<S> for Valid<E>
+where
+    E: FromRequest<S> + HasValidate,
+    E::Rejection: IntoResponse,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(
+        req: Request,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.valid", async move {
+            match E::from_request(req, state).await {
+                | Ok(extractor) => validate_fn(extractor)
+                    .map_err(create_validation_rejection_fn),
+                | Err(rej) => Err(rej.into_response()),
+            }
+        })
+    }
+}
+
+impl<E, S> FromRequestParts<S> for Valid<E>
+where
+    E: FromRequestParts<S> + HasValidate,
+    E::Rejection: IntoResponse,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.valid", async move {
+            match E::from_request_parts(parts, state).await {
+                | Ok(extractor) => validate_fn(extractor)
+                    .map_err(create_validation_rejection_fn),
+                | Err(rej) => Err(rej.into_response()),
+            }
+        })
+    }
+}
+
+/// Validation between the path parameters and an extracted body,
+/// used by the [`CrossValid`] extractor.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::valid::CrossValidate,
+///     response::json::ValidationErrors,
+/// };
+///
+/// struct UpdateUser {
+///     id: u32,
+/// }
+///
+/// impl CrossValidate<u32> for UpdateUser {
+///     fn cross_validate(&self, id: &u32) -> Result<(), ValidationErrors> {
+///         let mut errors: ValidationErrors = ValidationErrors::new();
+///
+///         if self.id != *id {
+///             errors.add_cross(
+///                 ["path", "id"],
+///                 ["json", "id"],
+///                 "mismatch",
+///                 "ID does not match",
+///             );
+///         }
+///
+///         match errors.is_empty() {
+///             | true => Ok(()),
+///             | false => Err(errors),
+///         }
+///     }
+/// }
+/// ```
+pub trait CrossValidate<P> {
+    /// Validate the value against the path parameters.
+    fn cross_validate(
+        &self,
+        path: &P,
+    ) -> Result<(), ValidationErrors>;
+}
+
+/// Extractor holding a body to be validated by [`CrossValid`].
+pub trait HasCrossValidate<P> {
+    /// Validate the body against the path parameters.
+    fn cross_validate(
+        &self,
+        path: &P,
+    ) -> Result<(), ValidationErrors>;
+}
+
+#[cfg(feature = "json")]
+impl<P, T: CrossValidate<P>> HasCrossValidate<P> for crate::extract::Json<T> {
+    fn cross_validate(
+        &self,
+        path: &P,
+    ) -> Result<(), ValidationErrors> {
+        self.0.cross_validate(path)
+    }
+}
+
+#[cfg(feature = "form")]
+impl<P, T: CrossValidate<P>> HasCrossValidate<P> for crate::extract::Form<T> {
+    fn cross_validate(
+        &self,
+        path: &P,
+    ) -> Result<(), ValidationErrors> {
+        self.0.cross_validate(path)
+    }
+}
+
+/// Extractor for the path parameters and a body,
+/// validated against each other with [`CrossValidate`]
+/// after both are extracted.
+///
+/// The rejections of the inner extractors are returned as is,
+/// and the validation failure is rejected with
+/// `422 Unprocessable Entity` status.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::{
+///         Json, Path,
+///         valid::{CrossValid, CrossValidate},
+///     },
+///     response::json::ValidationErrors,
+/// };
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct UpdateUser {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// impl CrossValidate<u32> for UpdateUser {
+///     fn cross_validate(&self, id: &u32) -> Result<(), ValidationErrors> {
+///         let mut errors: ValidationErrors = ValidationErrors::new();
+///
+///         if self.id != *id {
+///             errors.add_cross(
+///                 ["path", "id"],
+///                 ["json", "id"],
+///                 "mismatch",
+///                 "ID does not match",
+///             );
+///         }
+///
+///         match errors.is_empty() {
+///             | true => Ok(()),
+///             | false => Err(errors),
+///         }
+///     }
+/// }
+///
+/// async fn route(
+///     CrossValid(Path(id), Json(payload)): CrossValid<u32, Json<UpdateUser>>
+/// ) {
+///     // ...
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CrossValid<P, B>(pub Path<P>, pub B);
+
+impl<P, B, S> FromRequest<S> for CrossValid<P, B>
+where
+    P: DeserializeOwned + Send,
+    B: FromRequest<S> + HasCrossValidate<P>,
+    B::Rejection: IntoResponse,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(
+        req: Request,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        trace_extract!("extract.cross_valid", async move {
+            let (mut parts, body) = req.into_parts();
+
+            let path: Path<P> =
+                Path::<P>::from_request_parts(&mut parts, state).await?;
+
+            let body: B =
+                match B::from_request(Request::from_parts(parts, body), state)
+                    .await
+                {
+                    | Ok(body) => body,
+                    | Err(rej) => return Err(rej.into_response()),
+                };
+
+            match body.cross_validate(&path.0) {
+                | Ok(()) => Ok(Self(path, body)),
+                | Err(errors) => Err(create_validation_rejection_fn(errors)),
+            }
+        })
+    }
+}
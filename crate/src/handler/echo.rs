@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+
+use axum::{body::Bytes, extract::OriginalUri};
+use axum_core::extract::{FromRequest, FromRequestParts, Request};
+use http::{HeaderMap, header, request::Parts};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    extract::{
+        Form, Json, Query,
+        body::{create_body_read_rejection_fn, is_body_too_large},
+    },
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    },
+};
+
+/// Headers with the values replaced by [`ECHO_REDACTED_VALUE`].
+pub const ECHO_REDACTED_HEADERS: [&str; 6] = [
+    "authorization",
+    "cookie",
+    "proxy-authorization",
+    "set-cookie",
+    "x-api-key",
+    "x-csrf-token",
+];
+
+/// Value of the redacted headers.
+pub const ECHO_REDACTED_VALUE: &str = "[redacted]";
+
+/// Data of an [`echo`] response.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EchoData {
+    /// Method of the request.
+    pub method: String,
+    /// Path of the request, including the prefix of nested routers.
+    pub path: String,
+    /// Headers of the request,
+    /// where the values of repeated headers are joined by `, `.
+    pub headers: BTreeMap<String, String>,
+    /// Query parameters of the request.
+    pub query: Map<String, Value>,
+    /// Body of the request, parsed by the content type:
+    /// - JSON: parsed with [`Json`]
+    /// - `application/x-www-form-urlencoded`: parsed with [`Form`]
+    /// - others: text, or `null` if empty or not UTF-8
+    pub body: Value,
+}
+
+/// Get the headers with the sensitive values redacted.
+fn sanitize_headers_fn(headers: &HeaderMap) -> BTreeMap<String, String> {
+    let mut sanitized: BTreeMap<String, String> = BTreeMap::new();
+
+    for (name, value) in headers {
+        let value: String = match ECHO_REDACTED_HEADERS.contains(&name.as_str())
+        {
+            | true => ECHO_REDACTED_VALUE.to_string(),
+            | false => String::from_utf8_lossy(value.as_bytes()).to_string(),
+        };
+
+        sanitized
+            .entry(name.as_str().to_string())
+            .and_modify(|values| {
+                if values != ECHO_REDACTED_VALUE {
+                    values.push_str(", ");
+                    values.push_str(&value);
+                }
+            })
+            .or_insert(value);
+    }
+
+    sanitized
+}
+
+/// Get the media type of the request without parameters.
+fn get_media_type_fn(parts: &Parts) -> String {
+    parts
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+/// Parse the body of the request by the media type,
+/// or get the rejection of the extractor.
+async fn parse_body_fn(
+    req: Request,
+    media_type: &str,
+) -> Result<Value, Response> {
+    if media_type == "application/json" || media_type.ends_with("+json") {
+        let Json(body) = Json::<Value>::from_request(req, &()).await?;
+
+        return Ok(body);
+    }
+
+    if media_type == "application/x-www-form-urlencoded" {
+        let Form(body) =
+            Form::<Map<String, Value>>::from_request(req, &()).await?;
+
+        return Ok(Value::Object(body));
+    }
+
+    let bytes: Bytes = match Bytes::from_request(req, &()).await {
+        | Ok(bytes) => bytes,
+        | Err(rej) if is_body_too_large(&rej) => {
+            return Err(CreateJsonResponse::rejection()
+                .status(rej.status())
+                .add_error(
+                    JsonResponseError::from(ResponseError::TooLarge)
+                        .path(["request", "body"]),
+                )
+                .create());
+        },
+        | Err(rej) => return Err(create_body_read_rejection_fn(rej)),
+    };
+
+    match String::from_utf8(bytes.to_vec()) {
+        | Ok(text) if !text.is_empty() => Ok(Value::String(text)),
+        | _ => Ok(Value::Null),
+    }
+}
+
+/// Handler responding with the method, path, headers, query
+/// and body of the request in a success JSON response,
+/// available with `echo` feature.
+///
+/// The query and the body are parsed with the extractors of the crate,
+/// so that the rejections are the same as the ones of the routes,
+/// and the values of sensitive headers,
+/// such as [`ECHO_REDACTED_HEADERS`], are redacted.
+///
+/// It is intended for debugging clients,
+/// and should not be mounted in production.
+///
+/// ```jsonc
+/// // Status: 200
+/// {
+///     "success": true,
+///     "data": {
+///         "method": "POST",
+///         "path": "/echo",
+///         "headers": {
+///             "authorization": "[redacted]",
+///             "content-type": "application/json"
+///         },
+///         "query": {
+///             "page": "1"
+///         },
+///         "body": {
+///             "name": "Name"
+///         }
+///     },
+///     "errors": []
+/// }
+/// ```
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::any};
+/// use jder_axum::handler::echo;
+///
+/// let mut router: Router = Router::new();
+///
+/// if cfg!(debug_assertions) {
+///     router = router.route("/echo", any(echo));
+/// }
+/// ```
+pub async fn echo(req: Request) -> Response {
+    let (mut parts, body) = req.into_parts();
+
+    let Query(query) =
+        match Query::<Map<String, Value>>::from_request_parts(&mut parts, &())
+            .await
+        {
+            | Ok(query) => query,
+            | Err(res) => return res,
+        };
+
+    let media_type: String = get_media_type_fn(&parts);
+
+    let mut data: EchoData = EchoData {
+        method: parts.method.to_string(),
+        path: match parts.extensions.get::<OriginalUri>() {
+            | Some(OriginalUri(uri)) => uri.path().to_string(),
+            | None => parts.uri.path().to_string(),
+        },
+        headers: sanitize_headers_fn(&parts.headers),
+        query,
+        body: Value::Null,
+    };
+
+    data.body = match parse_body_fn(
+        Request::from_parts(parts, body),
+        &media_type,
+    )
+    .await
+    {
+        | Ok(body) => body,
+        | Err(res) => return res,
+    };
+
+    CreateJsonResponse::success::<EchoData>().data(data).create()
+}
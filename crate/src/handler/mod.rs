@@ -0,0 +1,72 @@
+#[cfg(feature = "echo")]
+pub(crate) mod echo;
+
+use axum::Router;
+
+use crate::response::{
+    Response,
+    json::{CreateJsonResponse, ResponseError},
+};
+
+#[cfg(feature = "echo")]
+pub use crate::handler::echo::{
+    ECHO_REDACTED_HEADERS, ECHO_REDACTED_VALUE, EchoData, echo,
+};
+
+/// Handler responding with a
+/// [`NotFound`](ResponseError::NotFound) failure JSON response
+/// and `404 Not Found` status,
+/// for unmatched routes.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::handler::not_found;
+///
+/// let router: Router = Router::new().fallback(not_found);
+/// ```
+pub async fn not_found() -> Response {
+    CreateJsonResponse::failure_from(ResponseError::NotFound).create()
+}
+
+/// Handler responding with a
+/// [`MethodNotAllowed`](ResponseError::MethodNotAllowed) failure JSON response
+/// and `405 Method Not Allowed` status,
+/// for unmatched methods.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::handler::method_not_allowed;
+///
+/// async fn route() {}
+///
+/// let router: Router =
+///     Router::new().route("/", get(route).fallback(method_not_allowed));
+/// ```
+pub async fn method_not_allowed() -> Response {
+    CreateJsonResponse::failure_from(ResponseError::MethodNotAllowed).create()
+}
+
+/// Set [`not_found`] and [`method_not_allowed`] as the fallbacks of a router,
+/// so that unmatched routes and methods
+/// are responded with failure JSON responses instead of empty bodies.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::handler::json_fallback;
+///
+/// async fn route() {}
+///
+/// let router: Router = json_fallback(Router::new().route("/", get(route)));
+/// ```
+pub fn json_fallback<S>(router: Router<S>) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    router.fallback(not_found).method_not_allowed_fallback(method_not_allowed)
+}
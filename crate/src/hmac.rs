@@ -0,0 +1,54 @@
+use ::hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Output size of HMAC-SHA256 in bytes.
+#[cfg(any(feature = "pagination_cursor", feature = "webhook"))]
+pub(crate) const HMAC_SHA256_OUTPUT_SIZE: usize = 32;
+
+/// Sign a message with HMAC-SHA256.
+#[cfg(any(feature = "pagination_cursor", feature = "webhook"))]
+pub(crate) fn sign_sha256(
+    key: &[u8],
+    message: &[u8],
+) -> [u8; HMAC_SHA256_OUTPUT_SIZE] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key)
+        .expect("HMAC accepts keys of any length");
+
+    mac.update(message);
+
+    mac.finalize().into_bytes().into()
+}
+
+/// Verify the HMAC-SHA256 signature of a message in constant time.
+pub(crate) fn verify_sha256(
+    key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(key) else {
+        return false;
+    };
+
+    mac.update(message);
+
+    mac.verify_slice(signature).is_ok()
+}
+
+/// Encode bytes as lowercase hex.
+#[cfg(feature = "webhook")]
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decode lowercase or uppercase hex into bytes.
+#[cfg(feature = "webhook")]
+pub(crate) fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashMap, sync::Arc, task::Context, time::Duration};
+
+use axum::extract::MatchedPath;
+use http::{HeaderValue, Request, header};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::Response as Res;
+
+/// Registry of the response TTLs per route pattern.
+///
+/// The route patterns are the same as the ones used in the router,
+/// such as `/users/{id}`, including the prefixes of nested routers.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use jder_axum::layers::cache_ttl::CacheTtlRegistry;
+///
+/// let registry: CacheTtlRegistry = CacheTtlRegistry::new()
+///     .route("/products", Duration::from_secs(60))
+///     .route("/products/{id}", Duration::from_secs(300));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CacheTtlRegistry {
+    routes: HashMap<String, Duration>,
+    private: bool,
+}
+
+impl CacheTtlRegistry {
+    /// Create a new empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the TTL of a route pattern.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::layers::cache_ttl::CacheTtlRegistry;
+    ///
+    /// CacheTtlRegistry::new().route("/products", Duration::from_secs(60));
+    /// ```
+    pub fn route<P: Into<String>>(
+        mut self,
+        pattern: P,
+        ttl: Duration,
+    ) -> Self {
+        self.routes.insert(pattern.into(), ttl);
+
+        self
+    }
+
+    /// Mark the responses as private,
+    /// so that only the client is allowed to cache them.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::cache_ttl::CacheTtlRegistry;
+    ///
+    /// CacheTtlRegistry::new().private(true);
+    /// ```
+    pub fn private(
+        mut self,
+        private: bool,
+    ) -> Self {
+        self.private = private;
+
+        self
+    }
+
+    /// Get the `Cache-Control` header value of a route pattern.
+    pub(crate) fn cache_control(
+        &self,
+        pattern: &str,
+    ) -> Option<HeaderValue> {
+        let ttl: &Duration = self.routes.get(pattern)?;
+
+        let visibility: &str = if self.private { "private" } else { "public" };
+
+        HeaderValue::try_from(format!(
+            "{}, max-age={}",
+            visibility,
+            ttl.as_secs()
+        ))
+        .ok()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CacheTtlService<S> {
+    inner: S,
+    registry: Arc<CacheTtlRegistry>,
+}
+
+impl<B, S> Service<Request<B>> for CacheTtlService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let cache_control: Option<HeaderValue> = req
+            .extensions()
+            .get::<MatchedPath>()
+            .and_then(|path| self.registry.cache_control(path.as_str()));
+
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let mut res: Res = fut.await?;
+
+            let cache_control: HeaderValue = match cache_control {
+                | Some(value) if is_cacheable(&res) => value,
+                | _ => return Ok(res),
+            };
+
+            res.headers_mut().insert(header::CACHE_CONTROL, cache_control);
+
+            Ok(res)
+        })
+    }
+}
+
+/// Check if the response is a success JSON response
+/// without `Cache-Control` header set by the handler.
+fn is_cacheable(res: &Res) -> bool {
+    res.status().is_success()
+        && !res.headers().contains_key(header::CACHE_CONTROL)
+        && res
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("application/json"))
+}
+
+/// Layer for stamping `Cache-Control` headers
+/// onto success JSON responses by route pattern.
+///
+/// The TTL of a route is looked up from the [`CacheTtlRegistry`],
+/// and the following header will be set:
+///
+/// ```text
+/// Cache-Control: public, max-age=60
+/// ```
+///
+/// Failure responses, non-JSON responses,
+/// and responses with `Cache-Control` header set by the handler
+/// are not changed.
+///
+/// The layer must be added with
+/// [`Router::layer`](axum::Router::layer)
+/// or [`Router::route_layer`](axum::Router::route_layer)
+/// for the matched route pattern to be available.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use axum::{Router, routing::get};
+/// use jder_axum::{
+///     layers::{CacheTtl, cache_ttl::CacheTtlRegistry},
+///     response::{Response, json::CreateJsonResponse},
+/// };
+///
+/// async fn route() -> Response {
+///     CreateJsonResponse::dataless().create()
+/// }
+///
+/// let registry: CacheTtlRegistry = CacheTtlRegistry::new()
+///     .route("/products", Duration::from_secs(60));
+///
+/// let router: Router = Router::new()
+///     .route("/products", get(route))
+///     .layer(CacheTtl::new(registry));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CacheTtl {
+    registry: Arc<CacheTtlRegistry>,
+}
+
+impl CacheTtl {
+    /// Create a new `CacheTtl` layer with a registry.
+    pub fn new(registry: CacheTtlRegistry) -> Self {
+        Self { registry: Arc::new(registry) }
+    }
+}
+
+impl<S> Layer<S> for CacheTtl {
+    type Service = CacheTtlService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        CacheTtlService { inner, registry: self.registry.clone() }
+    }
+}
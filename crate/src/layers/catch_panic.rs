@@ -0,0 +1,171 @@
+use std::any::Any;
+
+use axum_core::body::Body;
+use http::StatusCode;
+use tower_http::catch_panic::{CatchPanic as _CatchPanic, ResponseForPanic};
+use tower_layer::Layer;
+
+use crate::response::{
+    Response as Res,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+};
+
+/// Maximum length of the panic message in characters.
+pub const PANIC_MESSAGE_MAX_LENGTH: usize = 200;
+
+/// Get the sanitized message of a panic payload,
+/// with the first line only, without control characters
+/// and truncated to [`PANIC_MESSAGE_MAX_LENGTH`].
+fn get_panic_message_fn(payload: &(dyn Any + Send)) -> Option<String> {
+    let message: &str = match payload.downcast_ref::<&str>() {
+        | Some(message) => message,
+        | None => payload.downcast_ref::<String>()?.as_str(),
+    };
+
+    let message: String = message
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(PANIC_MESSAGE_MAX_LENGTH)
+        .collect();
+
+    match message.trim() {
+        | "" => None,
+        | message => Some(message.to_string()),
+    }
+}
+
+/// Create a failure response of a panic.
+fn create_panic_response_fn(
+    payload: Box<dyn Any + Send>,
+    debug: bool,
+) -> Res {
+    #[cfg(feature = "tracing")]
+    tracing::error!(
+        message = ?get_panic_message_fn(payload.as_ref()),
+        "panic in handler"
+    );
+
+    let mut error: JsonResponseError =
+        JsonResponseError::from(ResponseError::Server);
+
+    if debug {
+        if let Some(message) = get_panic_message_fn(payload.as_ref()) {
+            error = error.message(message);
+        }
+    }
+
+    CreateJsonResponse::failure_with(StatusCode::INTERNAL_SERVER_ERROR)
+        .add_error(error)
+        .create()
+}
+
+/// Handler creating the failure responses of panics
+/// for the [`CatchPanic`] layer.
+#[derive(Debug, Clone, Copy)]
+pub struct PanicHandler {
+    debug: bool,
+}
+
+impl ResponseForPanic for PanicHandler {
+    type ResponseBody = Body;
+
+    fn response_for_panic(
+        &mut self,
+        err: Box<dyn Any + Send + 'static>,
+    ) -> Res {
+        create_panic_response_fn(err, self.debug)
+    }
+}
+
+/// Service of the [`CatchPanic`] layer.
+pub type CatchPanicService<S> = _CatchPanic<S, PanicHandler>;
+
+/// Layer for catching panics in handlers,
+/// available with `catch_panic` feature.
+///
+/// The panics are caught by the `CatchPanic` middleware of `tower-http`,
+/// responding with the failure response below.
+///
+/// Following error will be returned if a handler panics,
+/// where the sanitized panic message replaces the message
+/// when [`debug`](CatchPanic::debug) is enabled:
+///
+/// ```jsonc
+/// // Status: 500
+/// {
+///     "success": false,
+///     "data": null,
+///     "errors": [
+///         {
+///             "code": "server",
+///             "path": [],
+///             "message": "Internal server error"
+///         }
+///     ]
+/// }
+/// ```
+///
+/// With `tracing` feature, the panic is logged as an error.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::CatchPanic;
+///
+/// let router: Router = Router::new()
+///     .layer(CatchPanic::new().debug(cfg!(debug_assertions)));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CatchPanic {
+    debug: bool,
+}
+
+impl CatchPanic {
+    /// Create a new `CatchPanic` layer
+    /// without the panic message in the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::CatchPanic;
+    ///
+    /// CatchPanic::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to include the sanitized panic message
+    /// in the response, such as for development.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::CatchPanic;
+    ///
+    /// CatchPanic::new().debug(true);
+    /// ```
+    pub fn debug(
+        mut self,
+        debug: bool,
+    ) -> Self {
+        self.debug = debug;
+
+        self
+    }
+}
+
+impl<S> Layer<S> for CatchPanic {
+    type Service = CatchPanicService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        _CatchPanic::custom(inner, PanicHandler { debug: self.debug })
+    }
+}
@@ -0,0 +1,374 @@
+use std::{io, pin::Pin, task::Context};
+
+use async_compression::{
+    Level,
+    tokio::bufread::{BrotliEncoder, GzipEncoder, ZlibEncoder, ZstdEncoder},
+};
+use axum_core::body::Body;
+use futures_util::TryStreamExt as _;
+use http::{HeaderMap, HeaderValue, Method, Request, StatusCode, header};
+use http_body::Body as _;
+use tokio::io::AsyncRead;
+use tokio_util::io::{ReaderStream, StreamReader};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::Response as Res;
+
+/// Default minimum body size in bytes to compress.
+pub const COMPRESSION_THRESHOLD_DEFAULT: usize = 1024;
+
+/// Content encoding of a response body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContentEncoding {
+    Br,
+    Zstd,
+    Gzip,
+    Deflate,
+}
+
+impl ContentEncoding {
+    /// Encodings in the preferred order with the same quality.
+    const PREFERRED: [Self; 4] =
+        [Self::Br, Self::Zstd, Self::Gzip, Self::Deflate];
+
+    /// Get the encoding of a coding in the `accept-encoding` header.
+    fn from_coding(coding: &str) -> Option<Self> {
+        match coding {
+            | c if c.eq_ignore_ascii_case("br") => Some(Self::Br),
+            | c if c.eq_ignore_ascii_case("zstd") => Some(Self::Zstd),
+            | c if c.eq_ignore_ascii_case("gzip")
+                || c.eq_ignore_ascii_case("x-gzip") =>
+            {
+                Some(Self::Gzip)
+            },
+            | c if c.eq_ignore_ascii_case("deflate") => Some(Self::Deflate),
+            | _ => None,
+        }
+    }
+
+    /// Get the value of the `content-encoding` header.
+    fn as_str(&self) -> &'static str {
+        match self {
+            | Self::Br => "br",
+            | Self::Zstd => "zstd",
+            | Self::Gzip => "gzip",
+            | Self::Deflate => "deflate",
+        }
+    }
+
+    /// Compress the body as a stream.
+    fn compress(
+        &self,
+        body: Body,
+    ) -> Body {
+        let reader = StreamReader::new(
+            body.into_data_stream().map_err(io::Error::other),
+        );
+
+        let encoder: Pin<Box<dyn AsyncRead + Send>> = match self {
+            // the default quality of brotli is too slow for responses
+            | Self::Br => {
+                Box::pin(BrotliEncoder::with_quality(reader, Level::Precise(4)))
+            },
+            | Self::Zstd => Box::pin(ZstdEncoder::new(reader)),
+            | Self::Gzip => Box::pin(GzipEncoder::new(reader)),
+            | Self::Deflate => Box::pin(ZlibEncoder::new(reader)),
+        };
+
+        Body::from_stream(ReaderStream::new(encoder))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CompressionService<S> {
+    inner: S,
+    threshold: usize,
+}
+
+impl<B, S> Service<Request<B>> for CompressionService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let encoding: Option<ContentEncoding> = match req.method() {
+            | &Method::HEAD => None,
+            | _ => get_encoding(req.headers()),
+        };
+
+        let threshold: usize = self.threshold;
+
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let mut res: Res = fut.await?;
+
+            if !is_compressible(&res) {
+                return Ok(res);
+            }
+
+            // the body depends on the `accept-encoding` header
+            add_vary(res.headers_mut());
+
+            let encoding: ContentEncoding = match encoding {
+                | Some(encoding) => encoding,
+                | None => return Ok(res),
+            };
+
+            match res.body().size_hint().exact() {
+                | Some(size) if size as usize >= threshold => {},
+                | _ => return Ok(res),
+            }
+
+            let (mut parts, body) = res.into_parts();
+
+            parts.headers.remove(header::CONTENT_LENGTH);
+            parts.headers.insert(
+                header::CONTENT_ENCODING,
+                HeaderValue::from_static(encoding.as_str()),
+            );
+
+            weaken_etag(&mut parts.headers);
+
+            Ok(Res::from_parts(parts, encoding.compress(body)))
+        })
+    }
+}
+
+/// Get the preferred content encoding of the `accept-encoding` header,
+/// where `br`, `zstd`, `gzip` and `deflate` are preferred in order
+/// with the same quality.
+fn get_encoding(headers: &HeaderMap) -> Option<ContentEncoding> {
+    let mut qualities: Vec<(ContentEncoding, f32)> = Vec::new();
+    let mut any: Option<f32> = None;
+
+    for value in headers.get_all(header::ACCEPT_ENCODING) {
+        let value: &str = match value.to_str() {
+            | Ok(value) => value,
+            | Err(_) => continue,
+        };
+
+        for item in value.split(',') {
+            let mut params = item.split(';').map(str::trim);
+
+            let coding: &str = params.next().unwrap_or_default();
+
+            let quality: f32 = params
+                .find_map(|param| param.strip_prefix("q="))
+                .and_then(|quality| quality.parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            match (coding, ContentEncoding::from_coding(coding)) {
+                | (_, Some(encoding)) => qualities.push((encoding, quality)),
+                | ("*", None) => any = Some(quality),
+                | _ => {},
+            }
+        }
+    }
+
+    let mut preferred: Option<(ContentEncoding, f32)> = None;
+
+    for encoding in ContentEncoding::PREFERRED {
+        let quality: f32 = qualities
+            .iter()
+            .find(|(e, _)| *e == encoding)
+            .map(|(_, quality)| *quality)
+            .or(any)
+            .unwrap_or(0.0);
+
+        let is_preferred: bool = match preferred {
+            | Some((_, q)) => quality > q,
+            | None => quality > 0.0,
+        };
+
+        if is_preferred {
+            preferred = Some((encoding, quality));
+        }
+    }
+
+    preferred.map(|(encoding, _)| encoding)
+}
+
+/// Check if the response is a JSON response to be compressed,
+/// with a known size and without an encoding.
+fn is_compressible(res: &Res) -> bool {
+    if matches!(res.status(), StatusCode::NO_CONTENT | StatusCode::NOT_MODIFIED)
+        || res.headers().contains_key(header::CONTENT_ENCODING)
+        || res.body().size_hint().exact().is_none()
+    {
+        return false;
+    }
+
+    let is_no_transform: bool = res
+        .headers()
+        .get_all(header::CACHE_CONTROL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|directive| directive.trim().eq_ignore_ascii_case("no-transform"));
+
+    if is_no_transform {
+        return false;
+    }
+
+    res.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(str::trim)
+        .is_some_and(|essence| {
+            essence.eq_ignore_ascii_case("application/json")
+                || essence.to_ascii_lowercase().ends_with("+json")
+        })
+}
+
+/// Weaken a strong `etag` header,
+/// as the compressed body is not the same bytes as the original one.
+fn weaken_etag(headers: &mut HeaderMap) {
+    let Some(etag) = headers.get(header::ETAG) else {
+        return;
+    };
+
+    if etag.as_bytes().starts_with(b"W/") {
+        return;
+    }
+
+    let weak: Vec<u8> = [b"W/", etag.as_bytes()].concat();
+
+    if let Ok(weak) = HeaderValue::from_bytes(&weak) {
+        headers.insert(header::ETAG, weak);
+    }
+}
+
+/// Add `accept-encoding` into the `vary` header if not set.
+fn add_vary(headers: &mut HeaderMap) {
+    let is_set: bool = headers
+        .get_all(header::VARY)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|name| {
+            name == "*" || name.eq_ignore_ascii_case("accept-encoding")
+        });
+
+    if !is_set {
+        headers
+            .append(header::VARY, HeaderValue::from_static("accept-encoding"));
+    }
+}
+
+/// Layer for compressing JSON responses,
+/// available with `compression` feature.
+///
+/// Bodies of JSON responses, such as the ones created by the JSON builders,
+/// are compressed as a stream with `br`, `zstd`, `gzip` or `deflate`
+/// when allowed by the `accept-encoding` header of the request
+/// and the size of the body reaches the threshold,
+/// [`COMPRESSION_THRESHOLD_DEFAULT`] by default.
+///
+/// The `content-encoding` header is set on compressed responses,
+/// where a strong `etag` header is weakened,
+/// and `accept-encoding` is added into the `vary` header
+/// of all JSON responses under the layer.
+///
+/// Streaming responses without a known size,
+/// responses with an encoding,
+/// and responses with `no-transform` in `cache-control`
+/// are kept as is.
+///
+/// ## Examples
+///
+/// Create a layer with default threshold:
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::Compression;
+///
+/// let router: Router = Router::new().layer(Compression::new());
+/// ```
+///
+/// Create a layer with custom threshold:
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::Compression;
+///
+/// let router: Router = Router::new().layer(Compression::new().threshold(256));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Compression {
+    threshold: usize,
+}
+
+impl Compression {
+    /// Create a new `Compression` layer
+    /// with [`COMPRESSION_THRESHOLD_DEFAULT`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::Compression;
+    ///
+    /// Compression::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { threshold: COMPRESSION_THRESHOLD_DEFAULT }
+    }
+
+    /// Set the minimum body size in bytes to compress.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::Compression;
+    ///
+    /// Compression::new().threshold(256);
+    /// ```
+    pub fn threshold(
+        mut self,
+        threshold: usize,
+    ) -> Self {
+        self.threshold = threshold;
+
+        self
+    }
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for Compression {
+    type Service = CompressionService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        CompressionService { inner, threshold: self.threshold }
+    }
+}
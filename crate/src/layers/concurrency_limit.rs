@@ -0,0 +1,363 @@
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use axum_core::body::Body;
+use bytes::Bytes;
+use http::{HeaderName, Request, StatusCode, request::Parts};
+use http_body::{Frame, SizeHint};
+use serde::Serialize;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{
+    Response as Res,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+};
+
+/// Key of the concurrency usage in meta.
+pub const CONCURRENCY_LIMIT_META_KEY: &str = "concurrency_limit";
+
+/// Provider of the key that requests are limited by,
+/// used by the [`ConcurrencyLimit`] layer,
+/// such as an API key or a client fingerprint.
+///
+/// Functions and closures taking the request parts
+/// and returning an optional key are providers,
+/// and requests without a key share a fallback key.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::http::request::Parts;
+/// use jder_axum::layers::concurrency_limit::ConcurrencyKey;
+///
+/// struct TenantKey;
+///
+/// impl ConcurrencyKey for TenantKey {
+///     fn key(
+///         &self,
+///         parts: &Parts,
+///     ) -> Option<String> {
+///         parts.uri.host().map(ToString::to_string)
+///     }
+/// }
+/// ```
+pub trait ConcurrencyKey: Send + Sync + 'static {
+    /// Get the key of a request.
+    fn key(
+        &self,
+        parts: &Parts,
+    ) -> Option<String>;
+}
+
+impl<F> ConcurrencyKey for F
+where
+    F: Fn(&Parts) -> Option<String> + Send + Sync + 'static,
+{
+    fn key(
+        &self,
+        parts: &Parts,
+    ) -> Option<String> {
+        self(parts)
+    }
+}
+
+/// Concurrency usage of a key in the meta of rejections.
+#[derive(Debug, Clone, Copy, Serialize)]
+struct ConcurrencyUsage {
+    /// Maximum concurrent requests of the key.
+    limit: usize,
+    /// Requests of the key in flight.
+    in_flight: usize,
+}
+
+/// Requests in flight by key,
+/// with `None` as the fallback key of requests without a key.
+type InFlight = Arc<Mutex<HashMap<Option<String>, usize>>>;
+
+/// Permit of a request in flight,
+/// released when the response body is dropped.
+struct ConcurrencyPermit {
+    in_flight: InFlight,
+    key: Option<String>,
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        if let Ok(mut in_flight) = self.in_flight.lock() {
+            if let Some(count) = in_flight.get_mut(&self.key) {
+                *count -= 1;
+
+                if *count == 0 {
+                    in_flight.remove(&self.key);
+                }
+            }
+        }
+    }
+}
+
+/// Response body holding the permit of its request.
+struct PermitBody {
+    inner: Body,
+    _permit: ConcurrencyPermit,
+}
+
+impl http_body::Body for PermitBody {
+    type Data = Bytes;
+    type Error = axum_core::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        Pin::new(&mut self.inner).poll_frame(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// Try to acquire a permit of a key,
+/// returns the requests in flight if the limit is reached.
+fn acquire_fn(
+    in_flight: &InFlight,
+    key: Option<String>,
+    limit: usize,
+) -> Result<ConcurrencyPermit, usize> {
+    let mut map = match in_flight.lock() {
+        | Ok(map) => map,
+        | Err(poisoned) => poisoned.into_inner(),
+    };
+
+    let count: &mut usize = map.entry(key.clone()).or_default();
+
+    if *count >= limit {
+        let count: usize = *count;
+
+        if count == 0 {
+            map.remove(&key);
+        }
+
+        return Err(count);
+    }
+
+    *count += 1;
+
+    Ok(ConcurrencyPermit { in_flight: in_flight.clone(), key })
+}
+
+/// Create a too many requests failure response with the usage in meta.
+fn create_concurrency_rejection_fn(usage: ConcurrencyUsage) -> Res {
+    CreateJsonResponse::failure()
+        .status(StatusCode::TOO_MANY_REQUESTS)
+        .add_error(
+            JsonResponseError::from(ResponseError::TooManyRequests)
+                .message("Too many concurrent requests"),
+        )
+        .meta(serde_json::json!({ CONCURRENCY_LIMIT_META_KEY: usage }))
+        .create()
+}
+
+#[derive(Clone)]
+pub struct ConcurrencyLimitService<S> {
+    inner: S,
+    limit: usize,
+    fallback_limit: usize,
+    provider: Arc<dyn ConcurrencyKey>,
+    in_flight: InFlight,
+}
+
+impl<B, S> Service<Request<B>> for ConcurrencyLimitService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let (parts, body) = req.into_parts();
+
+        let key: Option<String> = self.provider.key(&parts);
+
+        let limit: usize = match key {
+            | Some(_) => self.limit,
+            | None => self.fallback_limit,
+        };
+
+        let permit: ConcurrencyPermit =
+            match acquire_fn(&self.in_flight, key, limit) {
+                | Ok(permit) => permit,
+                | Err(in_flight) => {
+                    let usage: ConcurrencyUsage =
+                        ConcurrencyUsage { limit, in_flight };
+
+                    return Box::pin(async move {
+                        Ok(create_concurrency_rejection_fn(usage))
+                    });
+                },
+            };
+
+        let fut: S::Future = self.inner.call(Request::from_parts(parts, body));
+
+        Box::pin(async move {
+            let (parts, body) = fut.await?.into_parts();
+
+            let body: Body =
+                Body::new(PermitBody { inner: body, _permit: permit });
+
+            Ok(Res::from_parts(parts, body))
+        })
+    }
+}
+
+/// Layer for limiting concurrent requests by key,
+/// such as an API key, for fairness between tenants.
+///
+/// Requests over the limit of a key are rejected
+/// with `429 Too Many Requests` failure responses,
+/// and the usage of the key in the
+/// [`concurrency_limit`](CONCURRENCY_LIMIT_META_KEY) field of meta:
+///
+/// ```jsonc
+/// {
+///     "success": false,
+///     "data": null,
+///     "errors": [
+///         {
+///             "code": "too_many_requests",
+///             "path": [],
+///             "message": "Too many concurrent requests"
+///         }
+///     ],
+///     "meta": {
+///         "concurrency_limit": {
+///             "limit": 2,
+///             "in_flight": 2
+///         }
+///     }
+/// }
+/// ```
+///
+/// A request is in flight until its response body is dropped,
+/// so streaming bodies are counted while being sent.
+///
+/// Requests without a key share a fallback key,
+/// limited by [`fallback_limit`](ConcurrencyLimit::fallback_limit),
+/// the same limit as other keys by default.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::layers::ConcurrencyLimit;
+///
+/// async fn route() {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .layer(ConcurrencyLimit::header("x-api-key", 4));
+/// ```
+#[derive(Clone)]
+pub struct ConcurrencyLimit {
+    limit: usize,
+    fallback_limit: usize,
+    provider: Arc<dyn ConcurrencyKey>,
+    in_flight: InFlight,
+}
+
+impl ConcurrencyLimit {
+    /// Create a new `ConcurrencyLimit` layer
+    /// with the maximum concurrent requests of each key and the provider.
+    pub fn new<P: ConcurrencyKey>(
+        limit: usize,
+        provider: P,
+    ) -> Self {
+        Self {
+            limit,
+            fallback_limit: limit,
+            provider: Arc::new(provider),
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Create a new `ConcurrencyLimit` layer
+    /// with the maximum concurrent requests of each value of a header,
+    /// such as `X-Api-Key`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the header name is invalid or not lowercase.
+    pub fn header(
+        name: &'static str,
+        limit: usize,
+    ) -> Self {
+        let name: HeaderName = HeaderName::from_static(name);
+
+        Self::new(limit, move |parts: &Parts| {
+            parts
+                .headers
+                .get(&name)
+                .and_then(|value| value.to_str().ok())
+                .map(ToString::to_string)
+        })
+    }
+
+    /// Set the maximum concurrent requests of the fallback key,
+    /// shared by the requests without a key.
+    ///
+    /// Set to `0` to reject requests without a key.
+    pub fn fallback_limit(
+        mut self,
+        limit: usize,
+    ) -> Self {
+        self.fallback_limit = limit;
+
+        self
+    }
+}
+
+impl<S> Layer<S> for ConcurrencyLimit {
+    type Service = ConcurrencyLimitService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        ConcurrencyLimitService {
+            inner,
+            limit: self.limit,
+            fallback_limit: self.fallback_limit,
+            provider: self.provider.clone(),
+            in_flight: self.in_flight.clone(),
+        }
+    }
+}
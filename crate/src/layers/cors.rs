@@ -0,0 +1,581 @@
+use std::{collections::HashSet, sync::Arc, task::Context, time::Duration};
+
+use axum_core::body::Body;
+use http::{
+    HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode, header,
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{
+    Response as Res,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+};
+
+/// Default duration of caching preflight responses.
+pub const CORS_MAX_AGE_DEFAULT: Duration = Duration::from_secs(600);
+
+/// Default allowed methods.
+pub const CORS_ALLOW_METHODS_DEFAULT: [Method; 6] = [
+    Method::GET,
+    Method::HEAD,
+    Method::POST,
+    Method::PUT,
+    Method::PATCH,
+    Method::DELETE,
+];
+
+/// Default allowed request headers.
+pub const CORS_ALLOW_HEADERS_DEFAULT: [&str; 4] =
+    ["accept", "authorization", "content-type", "x-request-id"];
+
+/// Default exposed response headers.
+pub const CORS_EXPOSE_HEADERS_DEFAULT: [&str; 2] =
+    ["retry-after", "x-request-id"];
+
+/// Allowed origins.
+#[derive(Debug, Clone)]
+enum CorsOrigins {
+    Any,
+    List(HashSet<HeaderValue>),
+}
+
+/// Configuration of the [`Cors`] layer.
+#[derive(Debug, Clone)]
+struct CorsConfig {
+    origins: CorsOrigins,
+    credentials: bool,
+    methods: Vec<Method>,
+    allow_headers: HashSet<HeaderName>,
+    expose_headers: Vec<HeaderName>,
+    max_age: Duration,
+}
+
+impl CorsConfig {
+    /// Check whether the origin is allowed.
+    fn is_allowed_origin(
+        &self,
+        origin: &HeaderValue,
+    ) -> bool {
+        match &self.origins {
+            | CorsOrigins::Any => true,
+            | CorsOrigins::List(origins) => origins.contains(origin),
+        }
+    }
+
+    /// Get the value of the `access-control-allow-origin` header.
+    fn allow_origin(
+        &self,
+        origin: &HeaderValue,
+    ) -> HeaderValue {
+        match &self.origins {
+            | CorsOrigins::Any => HeaderValue::from_static("*"),
+            | CorsOrigins::List(_) => origin.clone(),
+        }
+    }
+
+    /// Panic if any origin is allowed with credentials,
+    /// which browsers reject.
+    fn assert_credentials(&self) {
+        if self.credentials && matches!(self.origins, CorsOrigins::Any) {
+            panic!(
+                "Invalid CORS configuration: \
+                credentials cannot be allowed with any origin, \
+                use `allow_origins` instead"
+            );
+        }
+    }
+
+    /// Set the headers shared by preflight and actual responses.
+    fn set_origin_headers(
+        &self,
+        headers: &mut HeaderMap,
+        origin: &HeaderValue,
+    ) {
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_ORIGIN,
+            self.allow_origin(origin),
+        );
+
+        if self.credentials {
+            headers.insert(
+                header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+                HeaderValue::from_static("true"),
+            );
+        }
+    }
+}
+
+/// Append `origin` to the `vary` header,
+/// as the response differs by the origin of the request.
+fn append_vary_fn(headers: &mut HeaderMap) {
+    headers.append(header::VARY, HeaderValue::from_static("origin"));
+}
+
+/// Join the values into a header value separated by commas.
+fn join_fn<I, V>(values: I) -> Option<HeaderValue>
+where
+    I: IntoIterator<Item = V>,
+    V: AsRef<str>,
+{
+    let joined: Vec<String> =
+        values.into_iter().map(|value| value.as_ref().to_string()).collect();
+
+    HeaderValue::from_str(&joined.join(", ")).ok()
+}
+
+/// Create a forbidden failure response of a preflight request,
+/// with the header of the failure in path.
+fn create_preflight_rejection_fn(
+    name: HeaderName,
+    message: &str,
+) -> Res {
+    CreateJsonResponse::failure()
+        .status(StatusCode::FORBIDDEN)
+        .add_error(
+            JsonResponseError::from(ResponseError::Forbidden)
+                .path(["header", name.as_str()])
+                .message(message),
+        )
+        .create()
+}
+
+/// Create a response of a preflight request.
+fn create_preflight_response_fn(
+    config: &CorsConfig,
+    headers: &HeaderMap,
+    origin: &HeaderValue,
+) -> Res {
+    if !config.is_allowed_origin(origin) {
+        return create_preflight_rejection_fn(
+            header::ORIGIN,
+            "Origin is not allowed",
+        );
+    }
+
+    let method: Option<Method> = headers
+        .get(header::ACCESS_CONTROL_REQUEST_METHOD)
+        .and_then(|value| Method::from_bytes(value.as_bytes()).ok());
+
+    if !method.is_some_and(|method| config.methods.contains(&method)) {
+        return create_preflight_rejection_fn(
+            header::ACCESS_CONTROL_REQUEST_METHOD,
+            "Method is not allowed",
+        );
+    }
+
+    let requested: Vec<&str> = headers
+        .get_all(header::ACCESS_CONTROL_REQUEST_HEADERS)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    let is_allowed_headers: bool = requested.iter().all(|name| {
+        HeaderName::try_from(*name)
+            .is_ok_and(|name| config.allow_headers.contains(&name))
+    });
+
+    if !is_allowed_headers {
+        return create_preflight_rejection_fn(
+            header::ACCESS_CONTROL_REQUEST_HEADERS,
+            "Headers are not allowed",
+        );
+    }
+
+    let mut res: Res = Res::new(Body::empty());
+
+    *res.status_mut() = StatusCode::NO_CONTENT;
+
+    let res_headers: &mut HeaderMap = res.headers_mut();
+
+    config.set_origin_headers(res_headers, origin);
+
+    if let Some(methods) = join_fn(config.methods.iter().map(Method::as_str)) {
+        res_headers.insert(header::ACCESS_CONTROL_ALLOW_METHODS, methods);
+    }
+
+    if let Some(names) = join_fn(requested) {
+        res_headers.insert(header::ACCESS_CONTROL_ALLOW_HEADERS, names);
+    }
+
+    res_headers.insert(
+        header::ACCESS_CONTROL_MAX_AGE,
+        HeaderValue::from(config.max_age.as_secs()),
+    );
+
+    res
+}
+
+#[derive(Debug, Clone)]
+pub struct CorsService<S> {
+    inner: S,
+    config: Arc<CorsConfig>,
+}
+
+impl<B, S> Service<Request<B>> for CorsService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let origin: Option<HeaderValue> =
+            req.headers().get(header::ORIGIN).cloned();
+
+        let config: Arc<CorsConfig> = self.config.clone();
+
+        // preflight request
+        let is_preflight: bool = req.method() == Method::OPTIONS
+            && req
+                .headers()
+                .contains_key(header::ACCESS_CONTROL_REQUEST_METHOD);
+
+        if let Some(origin) = &origin {
+            if is_preflight {
+                let mut res: Res = create_preflight_response_fn(
+                    &config,
+                    req.headers(),
+                    origin,
+                );
+
+                append_vary_fn(res.headers_mut());
+
+                return Box::pin(async move { Ok(res) });
+            }
+        }
+
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let mut res: Res = fut.await?;
+
+            let headers: &mut HeaderMap = res.headers_mut();
+
+            // shared caches must not reuse the response across origins,
+            // including requests without origin or from disallowed origins
+            append_vary_fn(headers);
+
+            // disallowed origins are blocked by the browser
+            let Some(origin) =
+                origin.filter(|origin| config.is_allowed_origin(origin))
+            else {
+                return Ok(res);
+            };
+
+            config.set_origin_headers(headers, &origin);
+
+            if !config.expose_headers.is_empty() {
+                if let Some(names) = join_fn(
+                    config.expose_headers.iter().map(HeaderName::as_str),
+                ) {
+                    headers
+                        .insert(header::ACCESS_CONTROL_EXPOSE_HEADERS, names);
+                }
+            }
+
+            Ok(res)
+        })
+    }
+}
+
+/// Layer for Cross-Origin Resource Sharing (CORS) of JSON APIs,
+/// available with `cors` feature.
+///
+/// Defaults:
+/// - origins: none, set with [`allow_origins`](Cors::allow_origins)
+///   or [`allow_any_origin`](Cors::allow_any_origin)
+/// - credentials: not allowed
+/// - methods: [`CORS_ALLOW_METHODS_DEFAULT`]
+/// - request headers: [`CORS_ALLOW_HEADERS_DEFAULT`]
+/// - exposed headers: [`CORS_EXPOSE_HEADERS_DEFAULT`]
+/// - max age: [`CORS_MAX_AGE_DEFAULT`]
+///
+/// Preflight requests are responded by the layer,
+/// and rejected preflight requests are
+/// `403 Forbidden` failure responses
+/// with the rejected header in path:
+///
+/// ```jsonc
+/// // Status: 403
+/// {
+///     "success": false,
+///     "data": null,
+///     "errors": [
+///         {
+///             "code": "forbidden",
+///             "path": [
+///                 "header",
+///                 "origin"
+///             ],
+///             "message": "Origin is not allowed"
+///         }
+///     ]
+/// }
+/// ```
+///
+/// Actual requests from disallowed origins are passed through
+/// without CORS headers, so that browsers block the responses.
+///
+/// Every response has `origin` in the `vary` header,
+/// so that shared caches do not reuse responses across origins.
+///
+/// ## Panics
+///
+/// Allowing any origin with credentials panics,
+/// as browsers reject the wildcard origin with credentials.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::Cors;
+///
+/// let router: Router = Router::new().layer(
+///     Cors::new()
+///         .allow_origins(["https://example.com"])
+///         .allow_credentials(true),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cors {
+    config: CorsConfig,
+}
+
+impl Cors {
+    /// Create a new `Cors` layer with the defaults.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::Cors;
+    ///
+    /// Cors::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            config: CorsConfig {
+                origins: CorsOrigins::List(HashSet::new()),
+                credentials: false,
+                methods: CORS_ALLOW_METHODS_DEFAULT.to_vec(),
+                allow_headers: CORS_ALLOW_HEADERS_DEFAULT
+                    .into_iter()
+                    .map(HeaderName::from_static)
+                    .collect(),
+                expose_headers: CORS_EXPOSE_HEADERS_DEFAULT
+                    .into_iter()
+                    .map(HeaderName::from_static)
+                    .collect(),
+                max_age: CORS_MAX_AGE_DEFAULT,
+            },
+        }
+    }
+
+    /// Allow the origins, such as `https://example.com`,
+    /// adding to the origins allowed before.
+    ///
+    /// Invalid origins are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::Cors;
+    ///
+    /// Cors::new().allow_origins(["https://example.com"]);
+    /// ```
+    pub fn allow_origins<I, O>(
+        mut self,
+        origins: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = O>,
+        O: AsRef<str>,
+    {
+        let values = origins
+            .into_iter()
+            .filter_map(|origin| HeaderValue::from_str(origin.as_ref()).ok());
+
+        match &mut self.config.origins {
+            | CorsOrigins::Any => {},
+            | CorsOrigins::List(list) => list.extend(values),
+        }
+
+        self
+    }
+
+    /// Allow any origin.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if credentials are allowed.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::Cors;
+    ///
+    /// Cors::new().allow_any_origin();
+    /// ```
+    pub fn allow_any_origin(mut self) -> Self {
+        self.config.origins = CorsOrigins::Any;
+        self.config.assert_credentials();
+
+        self
+    }
+
+    /// Set whether to allow credentials, such as cookies.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any origin is allowed.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::Cors;
+    ///
+    /// Cors::new().allow_credentials(true);
+    /// ```
+    pub fn allow_credentials(
+        mut self,
+        credentials: bool,
+    ) -> Self {
+        self.config.credentials = credentials;
+        self.config.assert_credentials();
+
+        self
+    }
+
+    /// Set the allowed methods, replacing the defaults.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Method;
+    /// use jder_axum::layers::Cors;
+    ///
+    /// Cors::new().allow_methods([Method::GET, Method::POST]);
+    /// ```
+    pub fn allow_methods<I: IntoIterator<Item = Method>>(
+        mut self,
+        methods: I,
+    ) -> Self {
+        self.config.methods = methods.into_iter().collect();
+
+        self
+    }
+
+    /// Allow the request headers, adding to the defaults.
+    ///
+    /// Invalid header names are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::Cors;
+    ///
+    /// Cors::new().allow_headers(["x-api-key"]);
+    /// ```
+    pub fn allow_headers<I, N>(
+        mut self,
+        names: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        self.config.allow_headers.extend(
+            names
+                .into_iter()
+                .filter_map(|name| HeaderName::try_from(name.as_ref()).ok()),
+        );
+
+        self
+    }
+
+    /// Expose the response headers, adding to the defaults.
+    ///
+    /// Invalid header names are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::Cors;
+    ///
+    /// Cors::new().expose_headers(["etag"]);
+    /// ```
+    pub fn expose_headers<I, N>(
+        mut self,
+        names: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        self.config.expose_headers.extend(
+            names
+                .into_iter()
+                .filter_map(|name| HeaderName::try_from(name.as_ref()).ok()),
+        );
+
+        self
+    }
+
+    /// Set the duration of caching preflight responses.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::layers::Cors;
+    ///
+    /// Cors::new().max_age(Duration::from_secs(3600));
+    /// ```
+    pub fn max_age(
+        mut self,
+        max_age: Duration,
+    ) -> Self {
+        self.config.max_age = max_age;
+
+        self
+    }
+}
+
+impl Default for Cors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for Cors {
+    type Service = CorsService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        CorsService { inner, config: Arc::new(self.config.clone()) }
+    }
+}
@@ -0,0 +1,280 @@
+use std::{sync::Arc, task::Context};
+
+use axum_core::body::Body;
+use bytes::Bytes;
+use http::{Request, StatusCode, header};
+use http_body_util::BodyExt as _;
+use serde_json::{Map, Value};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{
+    Response as Res,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+};
+
+/// Default key of the legacy errors.
+pub const ERROR_SHADOW_KEY_DEFAULT: &str = "legacy";
+
+/// Errors of a failure JSON response,
+/// set by the JSON response builders,
+/// so the errors are read regardless of the format of the envelope.
+#[derive(Debug, Clone)]
+pub(crate) struct FailureErrors(pub(crate) Vec<JsonResponseError>);
+
+/// Serializer for the legacy error format.
+type ErrorShadowSerializer =
+    Arc<dyn Fn(&[JsonResponseError], StatusCode) -> Value + Send + Sync>;
+
+#[derive(Clone)]
+pub struct ErrorShadowService<S> {
+    inner: S,
+    key: Arc<str>,
+    serializer: ErrorShadowSerializer,
+    enabled: bool,
+}
+
+impl<B, S> Service<Request<B>> for ErrorShadowService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let fut: S::Future = self.inner.call(req);
+
+        let key: Arc<str> = self.key.clone();
+        let serializer: ErrorShadowSerializer = self.serializer.clone();
+        let enabled: bool = self.enabled;
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            if !enabled
+                || !(res.status().is_client_error()
+                    || res.status().is_server_error())
+                || !is_json(&res)
+            {
+                return Ok(res);
+            }
+
+            let (mut parts, body) = res.into_parts();
+
+            let errors: Vec<JsonResponseError> =
+                match parts.extensions.remove::<FailureErrors>() {
+                    | Some(FailureErrors(errors)) => errors,
+                    | None => return Ok(Res::from_parts(parts, body)),
+                };
+
+            let bytes: Bytes = match body.collect().await {
+                | Ok(collected) => collected.to_bytes(),
+                | Err(_) => {
+                    return Ok(CreateJsonResponse::failure()
+                        .status(StatusCode::INTERNAL_SERVER_ERROR)
+                        .add_error(
+                            JsonResponseError::from(ResponseError::Server)
+                                .message("Failed to read the response body"),
+                        )
+                        .create());
+                },
+            };
+
+            let bytes: Bytes = match shadow(
+                &bytes,
+                &key,
+                &serializer,
+                &errors,
+                parts.status,
+            ) {
+                | Some(shadowed) => {
+                    // body is changed
+                    parts.headers.remove(header::CONTENT_LENGTH);
+
+                    shadowed
+                },
+                | None => bytes,
+            };
+
+            Ok(Res::from_parts(parts, Body::from(bytes)))
+        })
+    }
+}
+
+/// Check if the response is a JSON response.
+fn is_json(res: &Res) -> bool {
+    res.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+/// Add the legacy errors into a failure response body.
+///
+/// Returns `None` if the body is not a JSON object.
+fn shadow(
+    bytes: &[u8],
+    key: &str,
+    serializer: &ErrorShadowSerializer,
+    errors: &[JsonResponseError],
+    status: StatusCode,
+) -> Option<Bytes> {
+    let mut body: Map<String, Value> = match serde_json::from_slice(bytes) {
+        | Ok(Value::Object(body)) => body,
+        | _ => return None,
+    };
+
+    body.insert(key.to_string(), serializer(errors, status));
+
+    serde_json::to_vec(&body).ok().map(Bytes::from)
+}
+
+/// Layer for shadowing failure responses with a legacy error format
+/// during contract migrations.
+///
+/// Failure JSON responses from the routes under the layer
+/// will carry both the errors and the legacy format under a distinct key,
+/// [`ERROR_SHADOW_KEY_DEFAULT`] by default.
+///
+/// The errors are taken from the JSON response builders,
+/// so the layer works with any [`ResponseFormat`](crate::response::json::ResponseFormat)
+/// of the envelope, such as [`ErrorKeysFormat`](crate::response::json::ErrorKeysFormat),
+/// and failure responses created otherwise are left as is.
+///
+/// ```jsonc
+/// // Status: 400
+/// {
+///     "success": false,
+///     "data": null,
+///     "errors": [
+///         {
+///             "code": "parse",
+///             "path": [],
+///             "message": "Failed to parse the request"
+///         }
+///     ],
+///     "legacy": {
+///         "error": "Failed to parse the request"
+///     }
+/// }
+/// ```
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::ErrorShadow;
+/// use serde_json::json;
+///
+/// // flip to remove the legacy errors after migration
+/// let is_migrating: bool = true;
+///
+/// let app: Router = Router::new()
+///     .layer(
+///         ErrorShadow::new(|errors, status| {
+///             json!({
+///                 "status": status.as_u16(),
+///                 "error": errors
+///                     .first()
+///                     .and_then(|e| e.message.clone()),
+///             })
+///         })
+///         .enabled(is_migrating),
+///     );
+/// ```
+#[derive(Clone)]
+pub struct ErrorShadow {
+    key: Arc<str>,
+    serializer: ErrorShadowSerializer,
+    enabled: bool,
+}
+
+impl ErrorShadow {
+    /// Create a new `ErrorShadow` layer with a serializer
+    /// for the legacy error format.
+    pub fn new<F>(serializer: F) -> Self
+    where
+        F: Fn(&[JsonResponseError], StatusCode) -> Value
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            key: ERROR_SHADOW_KEY_DEFAULT.into(),
+            serializer: Arc::new(serializer),
+            enabled: true,
+        }
+    }
+
+    /// Set the key of the legacy errors.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::ErrorShadow;
+    /// use serde_json::Value;
+    ///
+    /// ErrorShadow::new(|_, _| Value::Null).key("error");
+    /// ```
+    pub fn key<K: Into<Arc<str>>>(
+        mut self,
+        key: K,
+    ) -> Self {
+        self.key = key.into();
+
+        self
+    }
+
+    /// Enable or disable the shadowing, enabled by default.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::ErrorShadow;
+    /// use serde_json::Value;
+    ///
+    /// ErrorShadow::new(|_, _| Value::Null).enabled(false);
+    /// ```
+    pub fn enabled(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.enabled = enabled;
+
+        self
+    }
+}
+
+impl<S> Layer<S> for ErrorShadow {
+    type Service = ErrorShadowService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        ErrorShadowService {
+            inner,
+            key: self.key.clone(),
+            serializer: self.serializer.clone(),
+            enabled: self.enabled,
+        }
+    }
+}
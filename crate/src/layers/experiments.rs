@@ -0,0 +1,192 @@
+use std::{sync::Arc, task::Context};
+
+use axum_core::body::Body;
+use bytes::Bytes;
+use http::{Request, header};
+use http_body_util::BodyExt as _;
+use serde_json::{Map, Value};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{
+    extract::experiments::{ExperimentAssignment, ExperimentProvider},
+    response::Response as Res,
+};
+
+/// Key of the experiments in meta.
+pub const EXPERIMENTS_META_KEY: &str = "experiments";
+
+#[derive(Clone)]
+pub struct ExperimentsService<S> {
+    inner: S,
+    provider: Arc<dyn ExperimentProvider>,
+}
+
+impl<B, S> Service<Request<B>> for ExperimentsService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let (mut parts, body) = req.into_parts();
+
+        let assignment: ExperimentAssignment =
+            ExperimentAssignment::new(self.provider.assign(&parts));
+
+        parts.extensions.insert(assignment.clone());
+
+        let fut: S::Future = self.inner.call(Request::from_parts(parts, body));
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            if assignment.is_empty() || !is_json(&res) {
+                return Ok(res);
+            }
+
+            let (mut parts, body) = res.into_parts();
+
+            let bytes: Bytes = match body.collect().await {
+                | Ok(collected) => collected.to_bytes(),
+                | Err(_) => return Ok(Res::from_parts(parts, Body::empty())),
+            };
+
+            let bytes: Bytes = match stamp(&bytes, &assignment) {
+                | Some(stamped) => {
+                    // body is changed
+                    parts.headers.remove(header::CONTENT_LENGTH);
+
+                    stamped
+                },
+                | None => bytes,
+            };
+
+            Ok(Res::from_parts(parts, Body::from(bytes)))
+        })
+    }
+}
+
+/// Check if the response is a JSON response.
+fn is_json(res: &Res) -> bool {
+    res.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+/// Add the assigned variants into the meta of a response body.
+///
+/// Returns `None` if the body is not a JSON response.
+fn stamp(
+    bytes: &[u8],
+    assignment: &ExperimentAssignment,
+) -> Option<Bytes> {
+    let mut body: Map<String, Value> = match serde_json::from_slice(bytes) {
+        | Ok(Value::Object(body)) => body,
+        | _ => return None,
+    };
+
+    if !body.get("success").is_some_and(Value::is_boolean) {
+        return None;
+    }
+
+    let variants: Value = Value::Object(
+        assignment
+            .iter()
+            .map(|(k, v)| (k.to_string(), Value::String(v.to_string())))
+            .collect(),
+    );
+
+    match body.entry("meta").or_insert_with(|| Value::Object(Map::new())) {
+        | Value::Object(meta) => {
+            meta.insert(EXPERIMENTS_META_KEY.to_string(), variants);
+        },
+        | _ => return None,
+    }
+
+    serde_json::to_vec(&body).ok().map(Bytes::from)
+}
+
+/// Layer for assigning experiment variants to requests,
+/// and stamping them into the meta of JSON responses.
+///
+/// The variants are assigned by an
+/// [`ExperimentProvider`](crate::extract::experiments::ExperimentProvider)
+/// for every request, which are available with the
+/// [`ExperimentAssignment`](crate::extract::experiments::ExperimentAssignment)
+/// extractor, and set into the [`experiments`](EXPERIMENTS_META_KEY)
+/// field of meta.
+///
+/// ```jsonc
+/// {
+///     "success": true,
+///     "data": null,
+///     "errors": [],
+///     "meta": {
+///         "experiments": {
+///             "checkout": "b"
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::collections::BTreeMap;
+///
+/// use axum::{Router, http::request::Parts, routing::get};
+/// use jder_axum::layers::Experiments;
+///
+/// async fn route() {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .layer(Experiments::new(|_: &Parts| {
+///         BTreeMap::from([("checkout".to_string(), "b".to_string())])
+///     }));
+/// ```
+#[derive(Clone)]
+pub struct Experiments {
+    provider: Arc<dyn ExperimentProvider>,
+}
+
+impl Experiments {
+    /// Create a new `Experiments` layer with the provider.
+    pub fn new<P: ExperimentProvider>(provider: P) -> Self {
+        Self { provider: Arc::new(provider) }
+    }
+}
+
+impl<S> Layer<S> for Experiments {
+    type Service = ExperimentsService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        ExperimentsService { inner, provider: self.provider.clone() }
+    }
+}
@@ -0,0 +1,277 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    task::Context,
+};
+
+use axum::extract::MatchedPath;
+use axum_core::body::Body;
+use bytes::Bytes;
+use http::{Request, StatusCode, header};
+use http_body_util::BodyExt as _;
+use serde::Deserialize;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{Response as Res, json::JsonResponseError};
+
+/// Maximum number of the failure keys counted,
+/// the counters are cleared when exceeded.
+pub const FAILURE_SAMPLING_KEYS_MAX: usize = 1024;
+
+/// Hook called with the sampled failures.
+type FailureSamplingHook = Arc<dyn Fn(&FailureSample) + Send + Sync>;
+
+/// Key of identical failures.
+type FailureKey = (String, String, Vec<String>);
+
+/// A sampled failure.
+#[derive(Debug, Clone)]
+pub struct FailureSample {
+    /// Route pattern of the request,
+    /// empty if the route is not matched.
+    pub route: String,
+    /// Status code of the response.
+    pub status: StatusCode,
+    /// Error of the response.
+    pub error: JsonResponseError,
+    /// Number of the occurrences of the identical failure so far.
+    pub count: u64,
+}
+
+/// Errors of a failure response body.
+#[derive(Deserialize)]
+struct FailureBody {
+    success: bool,
+    #[serde(default)]
+    errors: Vec<JsonResponseError>,
+}
+
+/// Counters of the identical failures.
+#[derive(Debug, Default)]
+struct FailureCounters {
+    counts: Mutex<HashMap<FailureKey, u64>>,
+}
+
+impl FailureCounters {
+    /// Increase the counter of a failure,
+    /// returns the number of the occurrences.
+    fn increase(
+        &self,
+        key: FailureKey,
+    ) -> u64 {
+        let Ok(mut counts) = self.counts.lock() else {
+            return 1;
+        };
+
+        // avoid unbounded growth from distinct failures
+        if counts.len() >= FAILURE_SAMPLING_KEYS_MAX
+            && !counts.contains_key(&key)
+        {
+            counts.clear();
+        }
+
+        let count: &mut u64 = counts.entry(key).or_insert(0);
+
+        *count += 1;
+
+        *count
+    }
+}
+
+#[derive(Clone)]
+pub struct FailureSamplingService<S> {
+    inner: S,
+    every: u64,
+    hook: FailureSamplingHook,
+    counters: Arc<FailureCounters>,
+}
+
+impl<B, S> Service<Request<B>> for FailureSamplingService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let route: String = req
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|path| path.as_str().to_string())
+            .unwrap_or_default();
+
+        let every: u64 = self.every;
+        let hook: FailureSamplingHook = self.hook.clone();
+        let counters: Arc<FailureCounters> = self.counters.clone();
+
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            if !(res.status().is_client_error()
+                || res.status().is_server_error())
+                || !is_json(&res)
+            {
+                return Ok(res);
+            }
+
+            let (parts, body) = res.into_parts();
+
+            let bytes: Bytes = match body.collect().await {
+                | Ok(collected) => collected.to_bytes(),
+                | Err(_) => return Ok(Res::from_parts(parts, Body::empty())),
+            };
+
+            let errors: Vec<JsonResponseError> =
+                match serde_json::from_slice::<FailureBody>(&bytes) {
+                    | Ok(body) if !body.success => body.errors,
+                    | _ => Vec::new(),
+                };
+
+            for error in errors {
+                let key: FailureKey =
+                    (route.clone(), error.code.clone(), error.path.clone());
+
+                let count: u64 = counters.increase(key);
+
+                // sample the first of every N occurrences
+                if (count - 1) % every == 0 {
+                    hook(&FailureSample {
+                        route: route.clone(),
+                        status: parts.status,
+                        error,
+                        count,
+                    });
+                }
+            }
+
+            Ok(Res::from_parts(parts, Body::from(bytes)))
+        })
+    }
+}
+
+/// Check if the response is a JSON response.
+fn is_json(res: &Res) -> bool {
+    res.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+/// Layer for sampling the failures of the routes,
+/// so that high-frequency identical failures
+/// with the same route, code and path
+/// only reach the hook every Nth occurrence.
+///
+/// The responses are always sent as is,
+/// only the logging or tracing in the hook is sampled.
+///
+/// The layer must be added with
+/// [`Router::layer`](axum::Router::layer)
+/// or [`Router::route_layer`](axum::Router::route_layer)
+/// for the matched route pattern to be available.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::FailureSampling;
+///
+/// let router: Router = Router::new().layer(FailureSampling::new(
+///     100,
+///     |sample| {
+///         eprintln!(
+///             "{} {} {} (x{})",
+///             sample.status, sample.route, sample.error.code, sample.count,
+///         );
+///     },
+/// ));
+/// ```
+#[derive(Clone)]
+pub struct FailureSampling {
+    every: u64,
+    hook: FailureSamplingHook,
+    counters: Arc<FailureCounters>,
+}
+
+impl FailureSampling {
+    /// Create a new `FailureSampling` layer
+    /// calling the hook every Nth identical failure,
+    /// where `0` is treated as `1`.
+    pub fn new<F>(
+        every: u64,
+        hook: F,
+    ) -> Self
+    where
+        F: Fn(&FailureSample) + Send + Sync + 'static,
+    {
+        Self {
+            every: every.max(1),
+            hook: Arc::new(hook),
+            counters: Arc::new(FailureCounters::default()),
+        }
+    }
+
+    /// Create a new `FailureSampling` layer
+    /// tracing every Nth identical failure as a warning,
+    /// available with `tracing` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::Router;
+    /// use jder_axum::layers::FailureSampling;
+    ///
+    /// let router: Router = Router::new().layer(FailureSampling::tracing(100));
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn tracing(every: u64) -> Self {
+        Self::new(every, |sample| {
+            tracing::warn!(
+                route = %sample.route,
+                status = %sample.status,
+                code = %sample.error.code,
+                path = ?sample.error.path,
+                message = ?sample.error.message,
+                count = sample.count,
+                "failure response"
+            );
+        })
+    }
+}
+
+impl<S> Layer<S> for FailureSampling {
+    type Service = FailureSamplingService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        FailureSamplingService {
+            inner,
+            every: self.every,
+            hook: self.hook.clone(),
+            counters: self.counters.clone(),
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::task::Context;
+
+use axum_core::body::Body;
+use bytes::Bytes;
+use http::{Request, header};
+use http_body_util::BodyExt as _;
+use serde_json::{Map, Value};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{
+    extract::feature_flags::{AppliedFeatureFlags, FeatureFlags},
+    response::Response as Res,
+};
+
+/// Key of the feature flags in meta.
+pub const FEATURE_FLAGS_META_KEY: &str = "feature_flags";
+
+#[derive(Clone)]
+pub struct FeatureFlagsEchoService<S> {
+    inner: S,
+}
+
+impl<B, S> Service<Request<B>> for FeatureFlagsEchoService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        mut req: Request<B>,
+    ) -> Self::Future {
+        let applied: AppliedFeatureFlags = AppliedFeatureFlags::default();
+
+        req.extensions_mut().insert(applied.clone());
+
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            let flags: Option<FeatureFlags> =
+                applied.0.lock().ok().and_then(|mut flags| flags.take());
+
+            let flags: FeatureFlags = match flags {
+                | Some(flags) if is_json(&res) => flags,
+                | _ => return Ok(res),
+            };
+
+            let (mut parts, body) = res.into_parts();
+
+            let bytes: Bytes = match body.collect().await {
+                | Ok(collected) => collected.to_bytes(),
+                | Err(_) => return Ok(Res::from_parts(parts, Body::empty())),
+            };
+
+            let bytes: Bytes = match echo(&bytes, &flags) {
+                | Some(echoed) => {
+                    // body is changed
+                    parts.headers.remove(header::CONTENT_LENGTH);
+
+                    echoed
+                },
+                | None => bytes,
+            };
+
+            Ok(Res::from_parts(parts, Body::from(bytes)))
+        })
+    }
+}
+
+/// Check if the response is a JSON response.
+fn is_json(res: &Res) -> bool {
+    res.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+/// Add the applied feature flags into the meta of a response body.
+///
+/// Returns `None` if the body is not a JSON response.
+fn echo(
+    bytes: &[u8],
+    flags: &FeatureFlags,
+) -> Option<Bytes> {
+    let mut body: Map<String, Value> = match serde_json::from_slice(bytes) {
+        | Ok(Value::Object(body)) => body,
+        | _ => return None,
+    };
+
+    if !body.get("success").is_some_and(Value::is_boolean) {
+        return None;
+    }
+
+    let flags: Value =
+        Value::Array(flags.iter().map(|f| Value::String(f.into())).collect());
+
+    match body.entry("meta").or_insert_with(|| Value::Object(Map::new())) {
+        | Value::Object(meta) => {
+            meta.insert(FEATURE_FLAGS_META_KEY.to_string(), flags);
+        },
+        | _ => return None,
+    }
+
+    serde_json::to_vec(&body).ok().map(Bytes::from)
+}
+
+/// Layer for echoing the applied feature flags
+/// into the meta of JSON responses.
+///
+/// The flags applied by the
+/// [`FeatureFlags`](crate::extract::feature_flags::FeatureFlags) extractor
+/// will be set into the [`feature_flags`](FEATURE_FLAGS_META_KEY)
+/// field of meta.
+///
+/// ```jsonc
+/// // X-Feature-Flags: new_checkout
+/// {
+///     "success": true,
+///     "data": null,
+///     "errors": [],
+///     "meta": {
+///         "feature_flags": ["new_checkout"]
+///     }
+/// }
+/// ```
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::{
+///     extract::feature_flags::{FeatureFlags, FeatureFlagsAllowList},
+///     layers::FeatureFlagsEcho,
+/// };
+///
+/// async fn route(flags: FeatureFlags) {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .with_state(FeatureFlagsAllowList::new(["new_checkout"]))
+///     .layer(FeatureFlagsEcho::new());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FeatureFlagsEcho;
+
+impl FeatureFlagsEcho {
+    /// Create a new `FeatureFlagsEcho` layer.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for FeatureFlagsEcho {
+    type Service = FeatureFlagsEchoService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        FeatureFlagsEchoService { inner }
+    }
+}
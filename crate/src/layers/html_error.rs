@@ -0,0 +1,259 @@
+use std::{fmt::Write as _, task::Context};
+
+use axum_core::body::Body;
+use bytes::Bytes;
+use http::{HeaderMap, HeaderValue, Request, StatusCode, header};
+use http_body_util::BodyExt as _;
+use serde_json::Value;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{
+    Response as Res,
+    json::{
+        JsonResponseError,
+        create::{base::CONTENT_TYPE_JSON, negotiate::preferred_media_type},
+    },
+};
+
+/// Content type of the HTML error page.
+pub const CONTENT_TYPE_HTML: &str = "text/html; charset=utf-8";
+
+/// Header name of the request ID shown in the HTML error page.
+pub const HTML_ERROR_REQUEST_ID_HEADER: &str = "x-request-id";
+
+#[derive(Debug, Clone)]
+pub struct HtmlErrorService<S> {
+    inner: S,
+}
+
+impl<B, S> Service<Request<B>> for HtmlErrorService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let is_html: bool = prefers_html(req.headers());
+
+        let request_id: Option<String> = get_request_id(req.headers());
+
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let mut res: Res = fut.await?;
+
+            if !(res.status().is_client_error()
+                || res.status().is_server_error())
+                || !is_json(&res)
+            {
+                return Ok(res);
+            }
+
+            // the body depends on the accept header
+            res.headers_mut()
+                .append(header::VARY, HeaderValue::from_static("accept"));
+
+            if !is_html {
+                return Ok(res);
+            }
+
+            let (mut parts, body) = res.into_parts();
+
+            let bytes: Bytes = match body.collect().await {
+                | Ok(collected) => collected.to_bytes(),
+                | Err(_) => return Ok(Res::from_parts(parts, Body::empty())),
+            };
+
+            let request_id: Option<String> =
+                request_id.or_else(|| get_request_id(&parts.headers));
+
+            match render(&bytes, parts.status, request_id.as_deref()) {
+                | Some(html) => {
+                    // body is changed
+                    parts.headers.remove(header::CONTENT_LENGTH);
+                    parts.headers.insert(
+                        header::CONTENT_TYPE,
+                        HeaderValue::from_static(CONTENT_TYPE_HTML),
+                    );
+
+                    Ok(Res::from_parts(parts, Body::from(html)))
+                },
+                | None => Ok(Res::from_parts(parts, Body::from(bytes))),
+            }
+        })
+    }
+}
+
+/// Check if the request prefers HTML over JSON.
+///
+/// JSON is preferred if both have the same quality.
+fn prefers_html(headers: &HeaderMap) -> bool {
+    preferred_media_type(headers, &[CONTENT_TYPE_JSON, "text/html"]) == Some(1)
+}
+
+/// Get the request ID from the headers.
+fn get_request_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(HTML_ERROR_REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Check if the response is a JSON response.
+fn is_json(res: &Res) -> bool {
+    res.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+/// Escape the special characters of HTML.
+fn escape(value: &str) -> String {
+    let mut escaped: String = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            | '&' => escaped.push_str("&amp;"),
+            | '<' => escaped.push_str("&lt;"),
+            | '>' => escaped.push_str("&gt;"),
+            | '"' => escaped.push_str("&quot;"),
+            | '\'' => escaped.push_str("&#39;"),
+            | _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Render a failure response body into an HTML error page.
+///
+/// Returns `None` if the body is not a failure response.
+fn render(
+    bytes: &[u8],
+    status: StatusCode,
+    request_id: Option<&str>,
+) -> Option<String> {
+    let body: Value = serde_json::from_slice(bytes).ok()?;
+
+    if body.get("success") != Some(&Value::Bool(false)) {
+        return None;
+    }
+
+    let errors: Vec<JsonResponseError> =
+        serde_json::from_value(body.get("errors")?.clone()).ok()?;
+
+    let title: String = escape(&format!(
+        "{} {}",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or("Error")
+    ));
+
+    let mut html: String = String::new();
+
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n");
+    html.push_str("<meta charset=\"utf-8\">\n");
+    let _ = writeln!(html, "<title>{title}</title>");
+    html.push_str("</head>\n<body>\n");
+    let _ = writeln!(html, "<h1>{title}</h1>");
+
+    if !errors.is_empty() {
+        html.push_str("<ul>\n");
+
+        for error in &errors {
+            let _ = write!(html, "<li><code>{}</code>", escape(&error.code));
+
+            if let Some(message) = &error.message {
+                let _ = write!(html, ": {}", escape(message));
+            }
+
+            html.push_str("</li>\n");
+        }
+
+        html.push_str("</ul>\n");
+    }
+
+    if let Some(request_id) = request_id {
+        let _ = writeln!(
+            html,
+            "<p>Request ID: <code>{}</code></p>",
+            escape(request_id)
+        );
+    }
+
+    html.push_str("</body>\n</html>\n");
+
+    Some(html)
+}
+
+/// Layer for rendering failure responses into HTML error pages
+/// for browser clients.
+///
+/// When the `Accept` header of the request prefers `text/html`
+/// over `application/json`,
+/// failure JSON responses from the routes under the layer
+/// are rendered through a built-in template
+/// with the status, the code and message of errors,
+/// and the request ID from the
+/// [`X-Request-Id`](HTML_ERROR_REQUEST_ID_HEADER) header
+/// of the request or response.
+///
+/// API clients and success responses are not changed,
+/// and `Vary: accept` is added to the failure responses.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::{
+///     layers::HtmlError,
+///     response::{Response, json::CreateJsonResponse},
+/// };
+///
+/// async fn route() -> Response {
+///     CreateJsonResponse::failure().create()
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .layer(HtmlError::new());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HtmlError;
+
+impl HtmlError {
+    /// Create a new `HtmlError` layer.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for HtmlError {
+    type Service = HtmlErrorService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        HtmlErrorService { inner }
+    }
+}
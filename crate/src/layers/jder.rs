@@ -0,0 +1,123 @@
+use std::task::Context;
+
+use http::Request;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{
+    Response as Res,
+    json::{RejectionConfig, rejection::RejectionScope},
+};
+
+#[derive(Clone)]
+pub struct JderService<S> {
+    inner: S,
+    rejection: Option<RejectionConfig>,
+}
+
+impl<B, S> Service<Request<B>> for JderService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let rejection: Option<RejectionConfig> = self
+            .rejection
+            .clone()
+            .or_else(|| req.extensions().get::<RejectionConfig>().cloned());
+
+        match rejection {
+            | Some(config) => {
+                Box::pin(RejectionScope::new(config, self.inner.call(req)))
+            },
+            | None => Box::pin(self.inner.call(req)),
+        }
+    }
+}
+
+/// Layer for configuring the extractors of this crate
+/// while handling the requests,
+/// available with `rejection_config` feature.
+///
+/// The [`RejectionConfig`] is taken from the builder,
+/// or from an [`Extension`](axum::Extension) added outside this layer,
+/// and applied to the rejections of the extractors,
+/// including the extractors in other layers inside this layer.
+///
+/// Rejections created in spawned tasks are not overridden.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, http::StatusCode, routing::post};
+/// use jder_axum::{
+///     extract::Json,
+///     layers::JderLayer,
+///     response::json::{RejectionConfig, ResponseError},
+/// };
+///
+/// async fn route(Json(body): Json<String>) {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", post(route))
+///     .layer(JderLayer::new().rejection(
+///         RejectionConfig::new()
+///             .status(ResponseError::Parse.as_code(), StatusCode::UNPROCESSABLE_ENTITY)
+///             .code(ResponseError::Parse.as_code(), "invalid_request"),
+///     ));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct JderLayer {
+    rejection: Option<RejectionConfig>,
+}
+
+impl JderLayer {
+    /// Create a new `JderLayer` layer
+    /// with the configuration from the extensions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the configuration of the rejections.
+    pub fn rejection(
+        mut self,
+        config: RejectionConfig,
+    ) -> Self {
+        self.rejection = Some(config);
+
+        self
+    }
+}
+
+impl<S> Layer<S> for JderLayer {
+    type Service = JderService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        JderService { inner, rejection: self.rejection.clone() }
+    }
+}
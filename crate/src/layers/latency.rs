@@ -0,0 +1,319 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    task::Context,
+    time::{Duration, Instant},
+};
+
+use axum::extract::{MatchedPath, State};
+use http::Request;
+use serde::Serialize;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{Response as Res, json::CreateJsonResponse};
+
+/// Number of the histogram buckets,
+/// with upper bounds from 1 microsecond doubling up to about 1 hour.
+const BUCKET_COUNT: usize = 32;
+
+/// Histogram of the latencies of a route.
+#[derive(Debug, Clone)]
+struct RouteHistogram {
+    buckets: [u64; BUCKET_COUNT],
+    count: u64,
+    max: Duration,
+}
+
+impl RouteHistogram {
+    fn new() -> Self {
+        Self { buckets: [0; BUCKET_COUNT], count: 0, max: Duration::ZERO }
+    }
+
+    /// Get the index of the bucket for a latency.
+    fn bucket_index(latency: Duration) -> usize {
+        let micros: u128 = latency.as_micros().max(1);
+
+        // the smallest `i` with `micros <= 2^i`
+        let index: u32 = u128::BITS - (micros - 1).leading_zeros();
+
+        (index as usize).min(BUCKET_COUNT - 1)
+    }
+
+    /// Get the upper bound of a bucket.
+    fn bucket_bound(index: usize) -> Duration {
+        Duration::from_micros(1 << index)
+    }
+
+    fn record(
+        &mut self,
+        latency: Duration,
+    ) {
+        self.buckets[Self::bucket_index(latency)] += 1;
+        self.count += 1;
+        self.max = self.max.max(latency);
+    }
+
+    /// Get the estimated latency of a percentile in `0.0..=1.0`,
+    /// which is the upper bound of the bucket,
+    /// capped by the maximum latency.
+    fn percentile(
+        &self,
+        percentile: f64,
+    ) -> Duration {
+        let target: u64 =
+            ((self.count as f64) * percentile).ceil().max(1.0) as u64;
+
+        let mut cumulative: u64 = 0;
+
+        for (index, count) in self.buckets.iter().enumerate() {
+            cumulative += count;
+
+            if cumulative >= target {
+                return Self::bucket_bound(index).min(self.max);
+            }
+        }
+
+        self.max
+    }
+}
+
+/// Latency summary of a route,
+/// with the latencies in milliseconds.
+///
+/// The percentiles are estimated from the histogram buckets,
+/// which are accurate within a factor of two.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct LatencySummary {
+    /// Route pattern.
+    pub route: String,
+    /// Number of the recorded requests.
+    pub count: u64,
+    /// 50th percentile latency.
+    pub p50: f64,
+    /// 90th percentile latency.
+    pub p90: f64,
+    /// 99th percentile latency.
+    pub p99: f64,
+    /// Maximum latency.
+    pub max: f64,
+}
+
+/// In-process latency histogram keyed by route pattern,
+/// populated by the [`Latency`] layer.
+///
+/// The histogram is shared between clones.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::layers::latency::{LatencyHistogram, LatencySummary};
+///
+/// let histogram: LatencyHistogram = LatencyHistogram::new();
+///
+/// let summaries: Vec<LatencySummary> = histogram.summaries();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LatencyHistogram {
+    routes: Arc<Mutex<BTreeMap<String, RouteHistogram>>>,
+}
+
+impl LatencyHistogram {
+    /// Create a new empty histogram.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a latency of a route pattern.
+    pub fn record(
+        &self,
+        route: &str,
+        latency: Duration,
+    ) {
+        let Ok(mut routes) = self.routes.lock() else {
+            return;
+        };
+
+        match routes.get_mut(route) {
+            | Some(histogram) => histogram.record(latency),
+            | None => {
+                let mut histogram: RouteHistogram = RouteHistogram::new();
+
+                histogram.record(latency);
+
+                routes.insert(route.to_string(), histogram);
+            },
+        }
+    }
+
+    /// Get the latency summaries of the routes,
+    /// sorted by route pattern.
+    pub fn summaries(&self) -> Vec<LatencySummary> {
+        let Ok(routes) = self.routes.lock() else {
+            return Vec::new();
+        };
+
+        routes
+            .iter()
+            .map(|(route, histogram)| LatencySummary {
+                route: route.clone(),
+                count: histogram.count,
+                p50: as_millis(histogram.percentile(0.5)),
+                p90: as_millis(histogram.percentile(0.9)),
+                p99: as_millis(histogram.percentile(0.99)),
+                max: as_millis(histogram.max),
+            })
+            .collect()
+    }
+
+    /// Clear the recorded latencies.
+    pub fn clear(&self) {
+        if let Ok(mut routes) = self.routes.lock() {
+            routes.clear();
+        }
+    }
+}
+
+/// Convert a duration into milliseconds.
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Route for serving the latency summaries
+/// of the [`LatencyHistogram`] from the router state,
+/// as a list of [`LatencySummary`] in data.
+///
+/// The route is intended for debugging,
+/// and should not be exposed publicly.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::layers::{
+///     Latency,
+///     latency::{LatencyHistogram, latency_summary},
+/// };
+///
+/// let histogram: LatencyHistogram = LatencyHistogram::new();
+///
+/// let router: Router = Router::new()
+///     .route("/debug/latency", get(latency_summary))
+///     .layer(Latency::new(histogram.clone()))
+///     .with_state(histogram);
+/// ```
+pub async fn latency_summary(State(histogram): State<LatencyHistogram>) -> Res {
+    CreateJsonResponse::success::<Vec<LatencySummary>>()
+        .data(histogram.summaries())
+        .create()
+}
+
+#[derive(Debug, Clone)]
+pub struct LatencyService<S> {
+    inner: S,
+    histogram: LatencyHistogram,
+}
+
+impl<B, S> Service<Request<B>> for LatencyService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        // unmatched requests are not recorded
+        let route: Option<String> = req
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|path| path.as_str().to_string());
+
+        let histogram: LatencyHistogram = self.histogram.clone();
+
+        let start: Instant = Instant::now();
+
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            if let Some(route) = route {
+                histogram.record(&route, start.elapsed());
+            }
+
+            Ok(res)
+        })
+    }
+}
+
+/// Layer for recording the latencies of routes
+/// into a [`LatencyHistogram`] by route pattern.
+///
+/// The latency is measured until the response is created,
+/// without the time of sending the body.
+///
+/// The layer must be added with
+/// [`Router::layer`](axum::Router::layer)
+/// or [`Router::route_layer`](axum::Router::route_layer)
+/// for the matched route pattern to be available.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::{
+///     layers::{Latency, latency::LatencyHistogram},
+///     response::{Response, json::CreateJsonResponse},
+/// };
+///
+/// async fn route() -> Response {
+///     CreateJsonResponse::dataless().create()
+/// }
+///
+/// let histogram: LatencyHistogram = LatencyHistogram::new();
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .layer(Latency::new(histogram));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Latency {
+    histogram: LatencyHistogram,
+}
+
+impl Latency {
+    /// Create a new `Latency` layer with a histogram.
+    pub fn new(histogram: LatencyHistogram) -> Self {
+        Self { histogram }
+    }
+}
+
+impl<S> Layer<S> for Latency {
+    type Service = LatencyService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        LatencyService { inner, histogram: self.histogram.clone() }
+    }
+}
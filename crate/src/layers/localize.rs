@@ -0,0 +1,116 @@
+use std::task::Context;
+
+use http::{Request, header};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{
+    extract::locale::Locale,
+    response::{
+        Response as Res,
+        json::{MessageCatalog, i18n::LocaleScope},
+    },
+};
+
+#[derive(Clone)]
+pub struct LocalizeService<S> {
+    inner: S,
+    catalog: MessageCatalog,
+}
+
+impl<B, S> Service<Request<B>> for LocalizeService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        mut req: Request<B>,
+    ) -> Self::Future {
+        let tag: String = self.catalog.negotiate(
+            req.headers()
+                .get(header::ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default(),
+        );
+
+        let locale: Locale = Locale::new(tag, self.catalog.clone());
+
+        req.extensions_mut().insert(locale.clone());
+
+        Box::pin(LocaleScope::new(locale, self.inner.call(req)))
+    }
+}
+
+/// Layer for negotiating the locale of the requests
+/// with the `Accept-Language` header and a [`MessageCatalog`],
+/// available with `i18n` feature.
+///
+/// The locale is available with the
+/// [`Locale`](crate::extract::Locale) extractor,
+/// and the messages of rejections from the extractors of this crate
+/// are looked up from the catalog by error code,
+/// keeping the original messages if not found.
+///
+/// Rejections created in spawned tasks are not localized.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::post};
+/// use jder_axum::{
+///     extract::Json,
+///     layers::Localize,
+///     response::json::{MessageCatalog, ResponseError},
+/// };
+///
+/// async fn route(Json(body): Json<String>) {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", post(route))
+///     .layer(Localize::new(
+///         MessageCatalog::new()
+///             .message("fr", ResponseError::Parse.as_code(), "Requête invalide"),
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Localize {
+    catalog: MessageCatalog,
+}
+
+impl Localize {
+    /// Create a new `Localize` layer with a catalog.
+    pub fn new(catalog: MessageCatalog) -> Self {
+        Self { catalog }
+    }
+}
+
+impl<S> Layer<S> for Localize {
+    type Service = LocalizeService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        LocalizeService { inner, catalog: self.catalog.clone() }
+    }
+}
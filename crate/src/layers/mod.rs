@@ -1,15 +1,159 @@
+/// Cache TTL layer,
+/// available with `cache_ttl` feature.
+#[cfg(feature = "cache_ttl")]
+pub mod cache_ttl;
+
+/// Catch panic layer,
+/// available with `catch_panic` feature.
+#[cfg(feature = "catch_panic")]
+pub mod catch_panic;
+
+/// Compression layer,
+/// available with `compression` feature.
+#[cfg(feature = "compression")]
+pub mod compression;
+
+/// Concurrency limit layer,
+/// available with `concurrency_limit` feature.
+#[cfg(feature = "concurrency_limit")]
+pub mod concurrency_limit;
+
+/// CORS layer,
+/// available with `cors` feature.
+#[cfg(feature = "cors")]
+pub mod cors;
+
+/// Error shadow layer,
+/// available with `error_shadow` feature.
+#[cfg(feature = "error_shadow")]
+pub mod error_shadow;
+
+/// Experiments layer,
+/// available with `experiments` feature.
+#[cfg(feature = "experiments")]
+pub mod experiments;
+
+/// Failure sampling layer,
+/// available with `failure_sampling` feature.
+#[cfg(feature = "failure_sampling")]
+pub mod failure_sampling;
+
+/// Feature flags echo layer,
+/// available with `feature_flags` feature.
+#[cfg(feature = "feature_flags")]
+pub mod feature_flags;
+
+/// HTML error page layer,
+/// available with `html_error` feature.
+#[cfg(feature = "html_error")]
+pub mod html_error;
+
+/// Jder layer,
+/// available with `rejection_config` feature.
+#[cfg(feature = "rejection_config")]
+pub mod jder;
+
+/// Latency histogram layer,
+/// available with `latency` feature.
+#[cfg(feature = "latency")]
+pub mod latency;
+
+/// Localize layer,
+/// available with `i18n` feature.
+#[cfg(feature = "i18n")]
+pub mod localize;
+
+/// Payload sampling layer,
+/// available with `payload_sampling` feature.
+#[cfg(feature = "payload_sampling")]
+pub mod payload_sampling;
+
+/// Rate limit layer,
+/// available with `rate_limit` feature.
+#[cfg(feature = "rate_limit")]
+pub mod rate_limit;
+
 /// Request body limit layer,
 /// available with `request_body_limit` feature.
 #[cfg(feature = "request_body_limit")]
 pub mod request_body_limit;
 
+/// Request ID layer,
+/// available with `request_id` feature.
+#[cfg(feature = "request_id")]
+pub mod request_id;
+
 /// Request time limit layer,
 /// available with `request_time_limit` feature.
 #[cfg(feature = "request_time_limit")]
 pub mod request_time_limit;
 
+/// Response cache layer,
+/// available with `response_cache` feature.
+#[cfg(feature = "response_cache")]
+pub mod response_cache;
+
+/// Upstream error layer,
+/// available with `upstream_error` feature.
+#[cfg(feature = "upstream_error")]
+pub mod upstream_error;
+
+#[cfg(feature = "cache_ttl")]
+pub use crate::layers::cache_ttl::CacheTtl;
+
+#[cfg(feature = "catch_panic")]
+pub use crate::layers::catch_panic::CatchPanic;
+
+#[cfg(feature = "compression")]
+pub use crate::layers::compression::Compression;
+
+#[cfg(feature = "concurrency_limit")]
+pub use crate::layers::concurrency_limit::ConcurrencyLimit;
+
+#[cfg(feature = "cors")]
+pub use crate::layers::cors::Cors;
+
+#[cfg(feature = "error_shadow")]
+pub use crate::layers::error_shadow::ErrorShadow;
+
+#[cfg(feature = "experiments")]
+pub use crate::layers::experiments::Experiments;
+
+#[cfg(feature = "failure_sampling")]
+pub use crate::layers::failure_sampling::FailureSampling;
+
+#[cfg(feature = "feature_flags")]
+pub use crate::layers::feature_flags::FeatureFlagsEcho;
+
+#[cfg(feature = "html_error")]
+pub use crate::layers::html_error::HtmlError;
+
+#[cfg(feature = "rejection_config")]
+pub use crate::layers::jder::JderLayer;
+
+#[cfg(feature = "latency")]
+pub use crate::layers::latency::Latency;
+
+#[cfg(feature = "i18n")]
+pub use crate::layers::localize::Localize;
+
+#[cfg(feature = "payload_sampling")]
+pub use crate::layers::payload_sampling::PayloadSampling;
+
+#[cfg(feature = "rate_limit")]
+pub use crate::layers::rate_limit::RateLimit;
+
 #[cfg(feature = "request_body_limit")]
 pub use crate::layers::request_body_limit::RequestBodyLimit;
 
+#[cfg(feature = "request_id")]
+pub use crate::layers::request_id::PropagateRequestId;
+
 #[cfg(feature = "request_time_limit")]
 pub use crate::layers::request_time_limit::RequestTimeLimit;
+
+#[cfg(feature = "response_cache")]
+pub use crate::layers::response_cache::ResponseCache;
+
+#[cfg(feature = "upstream_error")]
+pub use crate::layers::upstream_error::UpstreamError;
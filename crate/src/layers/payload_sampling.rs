@@ -0,0 +1,305 @@
+use std::{
+    cell::RefCell,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll},
+};
+
+use axum::extract::MatchedPath;
+use http::Request;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::Response as Res;
+
+/// A sampled success response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadSample {
+    /// Route pattern of the request,
+    /// empty if the route is not matched.
+    pub route: String,
+    /// Type name of the data, such as `alloc::vec::Vec<u8>`.
+    pub data_type: &'static str,
+    /// Size of the serialized body in bytes.
+    pub size: usize,
+    /// Number of the items if the data is an array.
+    pub items: Option<usize>,
+}
+
+/// Sink of the sampled responses,
+/// used by the [`PayloadSampling`] layer.
+///
+/// Functions and closures taking a sample are sinks.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use jder_axum::layers::payload_sampling::{PayloadSample, PayloadSampleSink};
+///
+/// struct MaxSize(AtomicUsize);
+///
+/// impl PayloadSampleSink for MaxSize {
+///     fn record(
+///         &self,
+///         sample: &PayloadSample,
+///     ) {
+///         self.0.fetch_max(sample.size, Ordering::Relaxed);
+///     }
+/// }
+/// ```
+pub trait PayloadSampleSink: Send + Sync + 'static {
+    /// Record a sample.
+    fn record(
+        &self,
+        sample: &PayloadSample,
+    );
+}
+
+impl<F> PayloadSampleSink for F
+where
+    F: Fn(&PayloadSample) + Send + Sync + 'static,
+{
+    fn record(
+        &self,
+        sample: &PayloadSample,
+    ) {
+        self(sample)
+    }
+}
+
+/// Sampler shared by the services of a layer.
+struct PayloadSampler {
+    rate: f64,
+    count: AtomicU64,
+    sink: Arc<dyn PayloadSampleSink>,
+}
+
+impl PayloadSampler {
+    /// Check whether the next response is sampled,
+    /// spreading the samples evenly by the rate.
+    fn next(&self) -> bool {
+        let count: u64 = self.count.fetch_add(1, Ordering::Relaxed) + 1;
+
+        (count as f64 * self.rate).floor()
+            > ((count - 1) as f64 * self.rate).floor()
+    }
+}
+
+/// Sampler of the request being handled with its route.
+#[derive(Clone)]
+pub(crate) struct PayloadSamplingContext {
+    route: String,
+    sampler: Arc<PayloadSampler>,
+}
+
+impl PayloadSamplingContext {
+    /// Check whether the response is sampled.
+    pub(crate) fn is_sampled(&self) -> bool {
+        self.sampler.next()
+    }
+
+    /// Record a sample of the response into the sink.
+    pub(crate) fn record(
+        &self,
+        data_type: &'static str,
+        size: usize,
+        items: Option<usize>,
+    ) {
+        self.sampler.sink.record(&PayloadSample {
+            route: self.route.clone(),
+            data_type,
+            size,
+            items,
+        });
+    }
+}
+
+thread_local! {
+    /// Sampling context of the request being handled on the current thread.
+    static CURRENT_PAYLOAD_SAMPLING: RefCell<Option<PayloadSamplingContext>> =
+        const { RefCell::new(None) };
+}
+
+/// Get the sampling context of the request being handled,
+/// set while the future of a [`PayloadSamplingScope`] is polled.
+pub(crate) fn current_payload_sampling_fn() -> Option<PayloadSamplingContext> {
+    CURRENT_PAYLOAD_SAMPLING.with(|context| context.borrow().clone())
+}
+
+/// Future with the sampling context of the request being handled,
+/// responses created when polling the future pick the context up.
+struct PayloadSamplingScope<F> {
+    context: Option<PayloadSamplingContext>,
+    fut: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for PayloadSamplingScope<F> {
+    type Output = F::Output;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let this: &mut Self = self.get_mut();
+
+        let prev: Option<PayloadSamplingContext> = CURRENT_PAYLOAD_SAMPLING
+            .with(|context| context.replace(this.context.take()));
+
+        let poll: Poll<F::Output> = this.fut.as_mut().poll(cx);
+
+        this.context =
+            CURRENT_PAYLOAD_SAMPLING.with(|context| context.replace(prev));
+
+        poll
+    }
+}
+
+#[derive(Clone)]
+pub struct PayloadSamplingService<S> {
+    inner: S,
+    sampler: Arc<PayloadSampler>,
+}
+
+impl<B, S> Service<Request<B>> for PayloadSamplingService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let route: String = req
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|path| path.as_str().to_string())
+            .unwrap_or_default();
+
+        let context: PayloadSamplingContext =
+            PayloadSamplingContext { route, sampler: self.sampler.clone() };
+
+        Box::pin(PayloadSamplingScope {
+            context: Some(context),
+            fut: Box::pin(self.inner.call(req)),
+        })
+    }
+}
+
+/// Layer for sampling the data of success JSON responses,
+/// recording the route, the type name of the data,
+/// the size of the body and the number of the items
+/// into a sink for payload analytics,
+/// such as the growth of the payloads of the routes.
+///
+/// A fraction of the responses, from `0.0` to `1.0`, are sampled,
+/// spread evenly over the responses.
+/// The responses are always sent as is.
+///
+/// Only the JSON responses created by the builders
+/// while the handlers are polled are sampled,
+/// responses created in spawned tasks
+/// and bodies spilled into temp files are not.
+///
+/// The layer must be added with
+/// [`Router::layer`](axum::Router::layer)
+/// or [`Router::route_layer`](axum::Router::route_layer)
+/// for the matched route pattern to be available.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::PayloadSampling;
+///
+/// let router: Router = Router::new().layer(PayloadSampling::new(
+///     0.01,
+///     |sample: &_| {
+///         eprintln!("{:?}", sample);
+///     },
+/// ));
+/// ```
+#[derive(Clone)]
+pub struct PayloadSampling {
+    sampler: Arc<PayloadSampler>,
+}
+
+impl PayloadSampling {
+    /// Create a new `PayloadSampling` layer
+    /// recording the fraction of the responses into the sink,
+    /// where the rate is clamped into `0.0..=1.0`.
+    pub fn new<K: PayloadSampleSink>(
+        rate: f64,
+        sink: K,
+    ) -> Self {
+        let rate: f64 = match rate.is_nan() {
+            | true => 0.0,
+            | false => rate.clamp(0.0, 1.0),
+        };
+
+        Self {
+            sampler: Arc::new(PayloadSampler {
+                rate,
+                count: AtomicU64::new(0),
+                sink: Arc::new(sink),
+            }),
+        }
+    }
+
+    /// Create a new `PayloadSampling` layer
+    /// tracing the fraction of the responses,
+    /// available with `tracing` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::Router;
+    /// use jder_axum::layers::PayloadSampling;
+    ///
+    /// let router: Router = Router::new().layer(PayloadSampling::tracing(0.01));
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn tracing(rate: f64) -> Self {
+        Self::new(rate, |sample: &PayloadSample| {
+            tracing::info!(
+                route = %sample.route,
+                data_type = sample.data_type,
+                size = sample.size,
+                items = ?sample.items,
+                "payload sample"
+            );
+        })
+    }
+}
+
+impl<S> Layer<S> for PayloadSampling {
+    type Service = PayloadSamplingService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        PayloadSamplingService { inner, sampler: self.sampler.clone() }
+    }
+}
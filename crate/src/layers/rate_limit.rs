@@ -0,0 +1,611 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    task::Context,
+    time::{Duration, Instant},
+};
+
+use http::{
+    HeaderMap, HeaderName, HeaderValue, Request, StatusCode, header,
+    request::Parts,
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{
+    Response as Res,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+};
+
+/// Header name of the maximum requests of a window.
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "ratelimit-limit";
+
+/// Header name of the remaining requests of the window.
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "ratelimit-remaining";
+
+/// Header name of the seconds until the quota is fully restored.
+pub const RATE_LIMIT_RESET_HEADER: &str = "ratelimit-reset";
+
+/// Key of the fallback quota shared by the requests without a key.
+pub const RATE_LIMIT_FALLBACK_KEY: &str = "";
+
+/// Default interval of removing fully restored buckets
+/// from the [`MemoryRateLimitStore`].
+pub const RATE_LIMIT_SWEEP_INTERVAL_DEFAULT: Duration = Duration::from_secs(60);
+
+/// Provider of the key that requests are limited by,
+/// used by the [`RateLimit`] layer,
+/// such as an API key or the client IP.
+///
+/// Functions and closures taking the request parts
+/// and returning an optional key are providers,
+/// and requests without a key or with an empty key
+/// share the [fallback key](RATE_LIMIT_FALLBACK_KEY).
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::http::request::Parts;
+/// use jder_axum::layers::rate_limit::RateLimitKey;
+///
+/// struct TenantKey;
+///
+/// impl RateLimitKey for TenantKey {
+///     fn key(
+///         &self,
+///         parts: &Parts,
+///     ) -> Option<String> {
+///         parts.uri.host().map(ToString::to_string)
+///     }
+/// }
+/// ```
+pub trait RateLimitKey: Send + Sync + 'static {
+    /// Get the key of a request.
+    fn key(
+        &self,
+        parts: &Parts,
+    ) -> Option<String>;
+}
+
+impl<F> RateLimitKey for F
+where
+    F: Fn(&Parts) -> Option<String> + Send + Sync + 'static,
+{
+    fn key(
+        &self,
+        parts: &Parts,
+    ) -> Option<String> {
+        self(parts)
+    }
+}
+
+/// Quota of the requests of a key,
+/// restored continuously over the window as a token bucket.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use jder_axum::layers::rate_limit::RateLimitQuota;
+///
+/// // 60 requests per minute, with bursts up to 60 requests
+/// let quota: RateLimitQuota = RateLimitQuota::new(60, Duration::from_secs(60));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitQuota {
+    /// Maximum requests of a window, also the maximum burst.
+    pub limit: u64,
+    /// Duration for fully restoring the quota.
+    pub window: Duration,
+}
+
+impl RateLimitQuota {
+    /// Create a new quota of the maximum requests of a window.
+    pub fn new(
+        limit: u64,
+        window: Duration,
+    ) -> Self {
+        Self { limit, window }
+    }
+}
+
+/// Decision of a store for a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    /// Whether the request is allowed.
+    pub allowed: bool,
+    /// Maximum requests of a window.
+    pub limit: u64,
+    /// Remaining requests after the request.
+    pub remaining: u64,
+    /// Duration until the quota is fully restored.
+    pub reset: Duration,
+    /// Duration until the next request is allowed,
+    /// zero if the request is allowed.
+    pub retry_after: Duration,
+}
+
+/// Store of the quotas of keys, used by the [`RateLimit`] layer,
+/// such as an in-memory map or a shared cache like Redis.
+///
+/// Stores shared between instances of a service
+/// limit requests across the instances.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use jder_axum::layers::rate_limit::{
+///     RateLimitDecision, RateLimitQuota, RateLimitStore,
+/// };
+///
+/// struct UnlimitedStore;
+///
+/// impl RateLimitStore for UnlimitedStore {
+///     fn acquire(
+///         &self,
+///         _: &str,
+///         quota: RateLimitQuota,
+///     ) -> impl Future<Output = RateLimitDecision> + Send {
+///         async move {
+///             RateLimitDecision {
+///                 allowed: true,
+///                 limit: quota.limit,
+///                 remaining: quota.limit,
+///                 reset: Duration::ZERO,
+///                 retry_after: Duration::ZERO,
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub trait RateLimitStore: Send + Sync + 'static {
+    /// Take a request from the quota of a key.
+    ///
+    /// Checking and taking must be atomic,
+    /// or concurrent requests may exceed the quota.
+    fn acquire(
+        &self,
+        key: &str,
+        quota: RateLimitQuota,
+    ) -> impl Future<Output = RateLimitDecision> + Send;
+}
+
+/// Token bucket of a key.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Token buckets of the [`MemoryRateLimitStore`].
+#[derive(Debug)]
+struct Buckets {
+    /// Token bucket of each key.
+    buckets: HashMap<String, Bucket>,
+    /// Time of the last removal of fully restored buckets.
+    swept: Instant,
+}
+
+/// In-memory [`RateLimitStore`] of a single instance,
+/// with a token bucket of each key.
+///
+/// Fully restored buckets are removed at most once per
+/// [`sweep_interval`](MemoryRateLimitStore::sweep_interval),
+/// instead of on every request.
+#[derive(Debug)]
+pub struct MemoryRateLimitStore {
+    buckets: Mutex<Buckets>,
+    sweep_interval: Duration,
+}
+
+impl Default for MemoryRateLimitStore {
+    fn default() -> Self {
+        Self {
+            buckets: Mutex::new(Buckets {
+                buckets: HashMap::new(),
+                swept: Instant::now(),
+            }),
+            sweep_interval: RATE_LIMIT_SWEEP_INTERVAL_DEFAULT,
+        }
+    }
+}
+
+impl MemoryRateLimitStore {
+    /// Create a new empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the interval of removing fully restored buckets,
+    /// [`RATE_LIMIT_SWEEP_INTERVAL_DEFAULT`] by default.
+    pub fn sweep_interval(
+        mut self,
+        interval: Duration,
+    ) -> Self {
+        self.sweep_interval = interval;
+
+        self
+    }
+
+    /// Take a request from the bucket of a key.
+    fn acquire_fn(
+        &self,
+        key: &str,
+        quota: RateLimitQuota,
+    ) -> RateLimitDecision {
+        let mut buckets = match self.buckets.lock() {
+            | Ok(buckets) => buckets,
+            | Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let now: Instant = Instant::now();
+
+        if now.duration_since(buckets.swept) >= self.sweep_interval {
+            buckets
+                .buckets
+                .retain(|_, bucket| now - bucket.updated < quota.window);
+            buckets.swept = now;
+        }
+
+        // no request is allowed without a quota
+        if quota.limit == 0 {
+            return RateLimitDecision {
+                allowed: false,
+                limit: 0,
+                remaining: 0,
+                reset: Duration::ZERO,
+                retry_after: quota.window,
+            };
+        }
+
+        let limit: f64 = quota.limit as f64;
+
+        // tokens restored per second
+        let rate: f64 = limit / quota.window.as_secs_f64().max(f64::EPSILON);
+
+        let bucket: &mut Bucket = buckets
+            .buckets
+            .entry(key.to_string())
+            .or_insert(Bucket { tokens: limit, updated: now });
+
+        bucket.tokens = (bucket.tokens
+            + (now - bucket.updated).as_secs_f64() * rate)
+            .min(limit);
+        bucket.updated = now;
+
+        let allowed: bool = bucket.tokens >= 1.0;
+
+        let retry_after: Duration = match allowed {
+            | true => {
+                bucket.tokens -= 1.0;
+
+                Duration::ZERO
+            },
+            | false => Duration::from_secs_f64((1.0 - bucket.tokens) / rate),
+        };
+
+        RateLimitDecision {
+            allowed,
+            limit: quota.limit,
+            remaining: bucket.tokens as u64,
+            reset: Duration::from_secs_f64((limit - bucket.tokens) / rate),
+            retry_after,
+        }
+    }
+}
+
+impl RateLimitStore for MemoryRateLimitStore {
+    fn acquire(
+        &self,
+        key: &str,
+        quota: RateLimitQuota,
+    ) -> impl Future<Output = RateLimitDecision> + Send {
+        let decision: RateLimitDecision = self.acquire_fn(key, quota);
+
+        async move { decision }
+    }
+}
+
+/// Get the seconds of a duration, rounded up.
+fn as_secs_ceil(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+/// Set the rate limit headers of a decision.
+fn set_headers_fn(
+    headers: &mut HeaderMap,
+    decision: &RateLimitDecision,
+) {
+    headers.insert(
+        HeaderName::from_static(RATE_LIMIT_LIMIT_HEADER),
+        HeaderValue::from(decision.limit),
+    );
+    headers.insert(
+        HeaderName::from_static(RATE_LIMIT_REMAINING_HEADER),
+        HeaderValue::from(decision.remaining),
+    );
+    headers.insert(
+        HeaderName::from_static(RATE_LIMIT_RESET_HEADER),
+        HeaderValue::from(as_secs_ceil(decision.reset)),
+    );
+}
+
+/// Create a too many requests failure response of a decision.
+fn create_rate_limit_rejection_fn(decision: &RateLimitDecision) -> Res {
+    let mut res: Res = CreateJsonResponse::failure()
+        .status(StatusCode::TOO_MANY_REQUESTS)
+        .header(header::RETRY_AFTER, as_secs_ceil(decision.retry_after).max(1))
+        .add_error(
+            JsonResponseError::from(ResponseError::TooManyRequests)
+                .message("Rate limit exceeded"),
+        )
+        .create();
+
+    set_headers_fn(res.headers_mut(), decision);
+
+    res
+}
+
+pub struct RateLimitService<S, T = MemoryRateLimitStore> {
+    inner: S,
+    quota: RateLimitQuota,
+    fallback_quota: RateLimitQuota,
+    provider: Arc<dyn RateLimitKey>,
+    store: Arc<T>,
+}
+
+impl<S: Clone, T> Clone for RateLimitService<S, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            quota: self.quota,
+            fallback_quota: self.fallback_quota,
+            provider: self.provider.clone(),
+            store: self.store.clone(),
+        }
+    }
+}
+
+impl<B, S, T> Service<Request<B>> for RateLimitService<S, T>
+where
+    S: Service<Request<B>, Response = Res> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    B: Send + 'static,
+    T: RateLimitStore,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let (parts, body) = req.into_parts();
+
+        let key: Option<String> =
+            self.provider.key(&parts).filter(|key| !key.is_empty());
+
+        let req: Request<B> = Request::from_parts(parts, body);
+
+        let (key, quota): (String, RateLimitQuota) = match key {
+            | Some(key) => (key, self.quota),
+            | None => {
+                (RATE_LIMIT_FALLBACK_KEY.to_string(), self.fallback_quota)
+            },
+        };
+        let store: Arc<T> = self.store.clone();
+
+        // take the ready service, leaving a clone for the next call
+        let clone: S = self.inner.clone();
+        let mut inner: S = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            let decision: RateLimitDecision = store.acquire(&key, quota).await;
+
+            if !decision.allowed {
+                return Ok(create_rate_limit_rejection_fn(&decision));
+            }
+
+            let mut res: Res = inner.call(req).await?;
+
+            set_headers_fn(res.headers_mut(), &decision);
+
+            Ok(res)
+        })
+    }
+}
+
+/// Layer for limiting the rate of requests by key,
+/// such as an API key or the client IP,
+/// available with `rate_limit` feature.
+///
+/// Each key has a [`RateLimitQuota`] restored continuously,
+/// and the quota is shared by the store,
+/// [`MemoryRateLimitStore`] by default.
+///
+/// Requests without a key share the
+/// [`fallback_quota`](RateLimit::fallback_quota),
+/// the same quota as other keys by default.
+///
+/// Responses of limited requests have the
+/// [`ratelimit-limit`](RATE_LIMIT_LIMIT_HEADER),
+/// [`ratelimit-remaining`](RATE_LIMIT_REMAINING_HEADER) and
+/// [`ratelimit-reset`](RATE_LIMIT_RESET_HEADER) headers,
+/// and requests over the quota are rejected
+/// with `429 Too Many Requests` failure responses
+/// and the `retry-after` header:
+///
+/// ```jsonc
+/// // Status: 429
+/// {
+///     "success": false,
+///     "data": null,
+///     "errors": [
+///         {
+///             "code": "too_many_requests",
+///             "path": [],
+///             "message": "Rate limit exceeded"
+///         }
+///     ]
+/// }
+/// ```
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use axum::{Router, routing::get};
+/// use jder_axum::layers::{RateLimit, rate_limit::RateLimitQuota};
+///
+/// async fn route() {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new().route("/", get(route)).layer(
+///     RateLimit::header(
+///         "x-api-key",
+///         RateLimitQuota::new(60, Duration::from_secs(60)),
+///     ),
+/// );
+/// ```
+pub struct RateLimit<T = MemoryRateLimitStore> {
+    quota: RateLimitQuota,
+    fallback_quota: RateLimitQuota,
+    provider: Arc<dyn RateLimitKey>,
+    store: Arc<T>,
+}
+
+impl<T> Clone for RateLimit<T> {
+    fn clone(&self) -> Self {
+        Self {
+            quota: self.quota,
+            fallback_quota: self.fallback_quota,
+            provider: self.provider.clone(),
+            store: self.store.clone(),
+        }
+    }
+}
+
+impl RateLimit {
+    /// Create a new `RateLimit` layer
+    /// with the quota of each key and the provider.
+    pub fn new<P: RateLimitKey>(
+        quota: RateLimitQuota,
+        provider: P,
+    ) -> Self {
+        Self {
+            quota,
+            fallback_quota: quota,
+            provider: Arc::new(provider),
+            store: Arc::new(MemoryRateLimitStore::new()),
+        }
+    }
+
+    /// Create a new `RateLimit` layer
+    /// with the quota of each value of a header,
+    /// such as `X-Api-Key`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the header name is invalid or not lowercase.
+    pub fn header(
+        name: &'static str,
+        quota: RateLimitQuota,
+    ) -> Self {
+        let name: HeaderName = HeaderName::from_static(name);
+
+        Self::new(quota, move |parts: &Parts| {
+            parts
+                .headers
+                .get(&name)
+                .and_then(|value| value.to_str().ok())
+                .map(ToString::to_string)
+        })
+    }
+
+    /// Create a new `RateLimit` layer
+    /// with the quota of each client IP,
+    /// available with `tokio` feature.
+    ///
+    /// The client IP is from the
+    /// [`ConnectInfo`](axum::extract::ConnectInfo) of the connection,
+    /// and requests without it share the fallback quota.
+    #[cfg(feature = "tokio")]
+    pub fn client_ip(quota: RateLimitQuota) -> Self {
+        use std::net::SocketAddr;
+
+        use axum::extract::ConnectInfo;
+
+        Self::new(quota, |parts: &Parts| {
+            parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip().to_string())
+        })
+    }
+}
+
+impl<T: RateLimitStore> RateLimit<T> {
+    /// Set the quota of the fallback key,
+    /// shared by the requests without a key.
+    ///
+    /// Set a quota with a limit of `0` to reject requests without a key.
+    pub fn fallback_quota(
+        mut self,
+        quota: RateLimitQuota,
+    ) -> Self {
+        self.fallback_quota = quota;
+
+        self
+    }
+
+    /// Set the store of the quotas,
+    /// such as a store shared between instances.
+    pub fn store<U: RateLimitStore>(
+        self,
+        store: U,
+    ) -> RateLimit<U> {
+        RateLimit {
+            quota: self.quota,
+            fallback_quota: self.fallback_quota,
+            provider: self.provider,
+            store: Arc::new(store),
+        }
+    }
+}
+
+impl<S, T> Layer<S> for RateLimit<T> {
+    type Service = RateLimitService<S, T>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        RateLimitService {
+            inner,
+            quota: self.quota,
+            fallback_quota: self.fallback_quota,
+            provider: self.provider.clone(),
+            store: self.store.clone(),
+        }
+    }
+}
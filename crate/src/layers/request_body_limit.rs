@@ -1,6 +1,7 @@
 use std::task::Context;
 
-use http::{Request, StatusCode};
+use axum::extract::DefaultBodyLimit;
+use http::{HeaderMap, Request, StatusCode, header};
 use http_body::Body;
 use http_body_util::{BodyExt as _, LengthLimitError, Limited};
 use tower_layer::Layer;
@@ -14,6 +15,44 @@ use crate::response::{
 /// Default maximum body size in bytes.
 pub const REQUEST_BODY_LIMIT_DEFAULT: usize = 10 * 1024 * 1024;
 
+/// Get the size of the body from the `content-length` header.
+fn get_content_length(headers: &HeaderMap) -> Option<usize> {
+    headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok())
+}
+
+/// Create a failure response of a body exceeding the limit,
+/// with the limit and the size of the body if known in message.
+fn create_too_large_response_fn(
+    limit: usize,
+    size: Option<usize>,
+) -> Res {
+    let message: String = match size {
+        | Some(size) => format!(
+            "{}, limit is {} bytes, got {} bytes",
+            ResponseError::TooLarge.as_message(),
+            limit,
+            size
+        ),
+        | None => format!(
+            "{}, limit is {} bytes",
+            ResponseError::TooLarge.as_message(),
+            limit
+        ),
+    };
+
+    CreateJsonResponse::failure()
+        .status(StatusCode::PAYLOAD_TOO_LARGE)
+        .add_error(
+            JsonResponseError::from(ResponseError::TooLarge)
+                .path(["request", "body"])
+                .message(message),
+        )
+        .create()
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct RequestBodyLimitService<S> {
     inner: S,
@@ -49,11 +88,24 @@ where
         &mut self,
         req: Request<B>,
     ) -> Self::Future {
+        let limit: usize = self.limit;
+
+        // reject early without reading the body
+        if let Some(size) = get_content_length(req.headers()) {
+            if size > limit {
+                let res: Res = create_too_large_response_fn(limit, Some(size));
+
+                return Box::pin(async move { Ok(res) });
+            }
+        }
+
         let (parts, body) = req.into_parts();
 
-        let limited_body: Limited<B> = Limited::new(body, self.limit);
+        let limited_body: Limited<B> = Limited::new(body, limit);
 
-        let mut inner: S = self.inner.clone();
+        // extractors share the limit instead of the default limit of axum
+        let mut inner: <DefaultBodyLimit as Layer<S>>::Service =
+            DefaultBodyLimit::max(limit).layer(self.inner.clone());
 
         Box::pin(async move {
             match limited_body.collect().await {
@@ -67,30 +119,14 @@ where
                 | Err(err)
                     if err.downcast_ref::<LengthLimitError>().is_some() =>
                 {
-                    let rer: ResponseError = ResponseError::TooLarge;
-
-                    let res: Res = CreateJsonResponse::failure()
-                        .status(StatusCode::PAYLOAD_TOO_LARGE)
-                        .add_error(
-                            JsonResponseError::new()
-                                .code(rer.as_code())
-                                .path(["request", "body"])
-                                .message(rer.as_message()),
-                        )
-                        .create();
-
-                    Ok(res)
+                    Ok(create_too_large_response_fn(limit, None))
                 },
                 | Err(_) => {
-                    let rer: ResponseError = ResponseError::Parse;
-
                     let res: Res = CreateJsonResponse::failure()
                         .status(StatusCode::BAD_REQUEST)
                         .add_error(
-                            JsonResponseError::new()
-                                .code(rer.as_code())
-                                .path(["request", "body"])
-                                .message(rer.as_message()),
+                            JsonResponseError::from(ResponseError::Parse)
+                                .path(["request", "body"]),
                         )
                         .create();
 
@@ -103,7 +139,13 @@ where
 
 /// Layer for configuring the request body limit.
 ///
-/// Following error will be returned if the request body exceeds the limit:
+/// Requests with a `content-length` header exceeding the limit
+/// are rejected without reading the body,
+/// and the extractors, such as `Json`, `Form` and `Multipart`,
+/// share the limit instead of the default limit of axum.
+///
+/// Following error will be returned if the request body exceeds the limit,
+/// with the limit and the size of the body if known in message:
 ///
 /// ```jsonc
 /// // Status: 413
@@ -117,7 +159,7 @@ where
 ///                 "request",
 ///                 "body"
 ///             ],
-///             "message": "Request body is too large"
+///             "message": "Request body is too large, limit is 1024 bytes, got 2048 bytes"
 ///         }
 ///     ]
 /// }
@@ -128,28 +170,20 @@ where
 /// Create a a layer with default limit:
 ///
 /// ```no_run
-/// use axum::{
-///     Router,
-///     extract::DefaultBodyLimit,
-/// };
+/// use axum::Router;
 /// use jder_axum::layers::RequestBodyLimit;
 ///
 /// let app: Router = Router::new()
-///     .layer(DefaultBodyLimit::disable())
 ///     .layer(RequestBodyLimit::new());
 /// ```
 ///
 /// Create a a layer with custom limit:
 ///
 /// ```no_run
-/// use axum::{
-///     Router,
-///     extract::DefaultBodyLimit,
-/// };
+/// use axum::Router;
 /// use jder_axum::layers::RequestBodyLimit;
 ///
 /// let app: Router = Router::new()
-///     .layer(DefaultBodyLimit::disable())
 ///     .layer(RequestBodyLimit::max(10 * 1024 * 1024));
 /// ```
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,299 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::Context,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use http::{HeaderName, HeaderValue, Request};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{
+    extract::request_id::{REQUEST_ID_HEADER, RequestId, RequestIdScope},
+    response::Response as Res,
+};
+
+/// Maximum length of the request IDs propagated from requests,
+/// longer IDs are replaced with generated IDs.
+pub const REQUEST_ID_MAX_LENGTH: usize = 128;
+
+/// Counter of the generated request IDs.
+static REQUEST_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Generator of the request IDs,
+/// used by the [`PropagateRequestId`] layer.
+///
+/// Functions and closures returning a string are generators.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// use jder_axum::layers::request_id::MakeRequestId;
+///
+/// struct SequentialId(AtomicU64);
+///
+/// impl MakeRequestId for SequentialId {
+///     fn make(&self) -> String {
+///         self.0.fetch_add(1, Ordering::Relaxed).to_string()
+///     }
+/// }
+/// ```
+pub trait MakeRequestId: Send + Sync + 'static {
+    /// Generate a request ID.
+    fn make(&self) -> String;
+}
+
+impl<F> MakeRequestId for F
+where
+    F: Fn() -> String + Send + Sync + 'static,
+{
+    fn make(&self) -> String {
+        self()
+    }
+}
+
+/// Generator of random UUID v4 request IDs.
+///
+/// The IDs are unique but not cryptographically secure,
+/// which should not be used as secrets.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidRequestId;
+
+impl UuidRequestId {
+    /// Get a random number with a randomly seeded hasher.
+    fn random_fn(seed: u64) -> u64 {
+        let mut hasher = RandomState::new().build_hasher();
+
+        hasher.write_u64(seed);
+
+        hasher.finish()
+    }
+}
+
+impl MakeRequestId for UuidRequestId {
+    fn make(&self) -> String {
+        let count: u64 = REQUEST_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        let nanos: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+
+        let high: u64 = Self::random_fn(count ^ nanos);
+        let low: u64 = Self::random_fn(high ^ count.rotate_left(32));
+
+        // version 4 and variant 1
+        let value: u128 = (((high as u128) << 64) | low as u128)
+            & !(0xf000 << 64)
+            & !(0xc << 60)
+            | (0x4000 << 64)
+            | (0x8 << 60);
+
+        format!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            (value >> 96) as u32,
+            (value >> 80) as u16,
+            (value >> 64) as u16,
+            (value >> 48) as u16,
+            value & 0xffff_ffff_ffff,
+        )
+    }
+}
+
+/// Get a request ID from a header value to propagate,
+/// returns `None` for empty, too long or non-visible values.
+fn get_propagated_fn(value: &HeaderValue) -> Option<String> {
+    let value: &str = value.to_str().ok()?;
+
+    match value.is_empty() || value.len() > REQUEST_ID_MAX_LENGTH {
+        | true => None,
+        | false => Some(value.to_string()),
+    }
+}
+
+#[derive(Clone)]
+pub struct PropagateRequestIdService<S> {
+    inner: S,
+    header: HeaderName,
+    maker: Arc<dyn MakeRequestId>,
+}
+
+impl<B, S> Service<Request<B>> for PropagateRequestIdService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        mut req: Request<B>,
+    ) -> Self::Future {
+        let propagated: Option<String> =
+            req.headers().get(&self.header).and_then(get_propagated_fn);
+
+        let id: String = match propagated {
+            | Some(id) => id,
+            | None => self.maker.make(),
+        };
+
+        let value: Option<HeaderValue> = HeaderValue::from_str(&id).ok();
+
+        if let Some(value) = &value {
+            req.headers_mut().insert(self.header.clone(), value.clone());
+        }
+
+        req.extensions_mut().insert(RequestId(id.clone()));
+
+        let header: HeaderName = self.header.clone();
+
+        let fut: RequestIdScope<S::Future> =
+            RequestIdScope::new(id, self.inner.call(req));
+
+        Box::pin(async move {
+            let mut res: Res = fut.await?;
+
+            if let Some(value) = value {
+                if !res.headers().contains_key(&header) {
+                    res.headers_mut().insert(header, value);
+                }
+            }
+
+            Ok(res)
+        })
+    }
+}
+
+/// Layer for generating or propagating request IDs.
+///
+/// The ID is taken from the [`x-request-id`](REQUEST_ID_HEADER) header
+/// of the request, or generated if absent or invalid,
+/// which is available with the
+/// [`RequestId`](crate::extract::request_id::RequestId) extractor,
+/// and set into the header of the response.
+///
+/// JSON responses created while handling the request
+/// pick the ID up into the
+/// [`request_id`](crate::extract::request_id::REQUEST_ID_META_KEY)
+/// field of meta, including the rejections from extractors:
+///
+/// ```jsonc
+/// // X-Request-Id: 0b6e7c1a-51c4-4a7e-9d3f-2c8f1e0a9b4d
+/// {
+///     "success": false,
+///     "data": null,
+///     "errors": [
+///         {
+///             "code": "parse",
+///             "path": [],
+///             "message": "..."
+///         }
+///     ],
+///     "meta": {
+///         "request_id": "0b6e7c1a-51c4-4a7e-9d3f-2c8f1e0a9b4d"
+///     }
+/// }
+/// ```
+///
+/// Responses created in spawned tasks do not pick the ID up,
+/// which can be set with the `request_id` function of the builders.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, routing::get};
+/// use jder_axum::{extract::RequestId, layers::PropagateRequestId};
+///
+/// async fn route(RequestId(id): RequestId) {
+///     // ...
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/", get(route))
+///     .layer(PropagateRequestId::new());
+/// ```
+#[derive(Clone)]
+pub struct PropagateRequestId {
+    header: HeaderName,
+    maker: Arc<dyn MakeRequestId>,
+}
+
+impl PropagateRequestId {
+    /// Create a new `PropagateRequestId` layer
+    /// with the [`x-request-id`](REQUEST_ID_HEADER) header
+    /// and UUID v4 request IDs.
+    pub fn new() -> Self {
+        Self {
+            header: HeaderName::from_static(REQUEST_ID_HEADER),
+            maker: Arc::new(UuidRequestId),
+        }
+    }
+
+    /// Set the header name of the request ID,
+    /// such as `x-correlation-id`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the header name is invalid or not lowercase.
+    pub fn header(
+        mut self,
+        name: &'static str,
+    ) -> Self {
+        self.header = HeaderName::from_static(name);
+
+        self
+    }
+
+    /// Set the generator of the request IDs.
+    pub fn maker<M: MakeRequestId>(
+        mut self,
+        maker: M,
+    ) -> Self {
+        self.maker = Arc::new(maker);
+
+        self
+    }
+}
+
+impl Default for PropagateRequestId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for PropagateRequestId {
+    type Service = PropagateRequestIdService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        PropagateRequestIdService {
+            inner,
+            header: self.header.clone(),
+            maker: self.maker.clone(),
+        }
+    }
+}
@@ -51,15 +51,11 @@ where
             match tokio::time::timeout(limit, fut).await {
                 | Ok(res) => res,
                 | Err(_) => {
-                    let rer: ResponseError = ResponseError::Timeout;
-
                     let res: Res = CreateJsonResponse::failure()
                         .status(StatusCode::GATEWAY_TIMEOUT)
-                        .add_error(
-                            JsonResponseError::new()
-                                .code(rer.as_code())
-                                .message(rer.as_message()),
-                        )
+                        .add_error(JsonResponseError::from(
+                            ResponseError::Timeout,
+                        ))
                         .create();
 
                     Ok(res)
@@ -69,7 +65,9 @@ where
     }
 }
 
-/// Layer for configuring the request time limit.
+/// Layer for configuring the request time limit,
+/// racing the inner service against the limit
+/// without a custom `HandleErrorLayer` conversion.
 ///
 /// Following error will be returned if the request time exceeds the limit:
 ///
@@ -81,7 +79,8 @@ where
 ///     "errors": [
 ///         {
 ///             "code": "timeout",
-///             "message" : "Gateway timeout"
+///             "path": [],
+///             "message": "Gateway timeout"
 ///         }
 ///     ]
 /// }
@@ -148,6 +147,23 @@ impl RequestTimeLimit {
     }
 }
 
+impl From<Duration> for RequestTimeLimit {
+    /// Create a new `RequestTimeLimit` layer with the limit.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::layers::RequestTimeLimit;
+    ///
+    /// RequestTimeLimit::from(Duration::from_secs(10)); // 10s
+    /// ```
+    fn from(limit: Duration) -> Self {
+        Self::max(limit)
+    }
+}
+
 impl Default for RequestTimeLimit {
     fn default() -> Self {
         Self::new()
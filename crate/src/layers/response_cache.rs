@@ -0,0 +1,601 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+    task::Context,
+    time::{Duration, Instant},
+};
+
+use axum_core::body::Body;
+use bytes::Bytes;
+use http::{
+    HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode, header,
+    request::Parts,
+};
+use http_body_util::BodyExt as _;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::Response as Res;
+
+/// Header name of the cache status of a response.
+pub const CACHE_STATUS_HEADER: &str = "cache-status";
+
+/// Name of the cache in the `Cache-Status` header.
+const CACHE_STATUS_NAME: &str = "jder";
+
+/// Request headers carrying the credentials of a client.
+const CREDENTIAL_HEADERS: [HeaderName; 2] =
+    [header::AUTHORIZATION, header::COOKIE];
+
+/// A cached response.
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    /// Status code of the response.
+    pub status: StatusCode,
+    /// Headers of the response.
+    pub headers: HeaderMap,
+    /// Body of the response.
+    pub body: Bytes,
+    /// Time the response was stored.
+    pub stored_at: Instant,
+}
+
+/// Store of the cached responses by key,
+/// used by the [`ResponseCache`] layer.
+///
+/// The store keeps the responses as is,
+/// and the layer decides whether an entry is fresh or stale.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::{collections::HashMap, sync::Mutex};
+///
+/// use jder_axum::layers::response_cache::{
+///     CachedResponse, ResponseCacheStore,
+/// };
+///
+/// struct Unbounded(Mutex<HashMap<String, CachedResponse>>);
+///
+/// impl ResponseCacheStore for Unbounded {
+///     fn get(
+///         &self,
+///         key: &str,
+///     ) -> Option<CachedResponse> {
+///         self.0.lock().unwrap().get(key).cloned()
+///     }
+///
+///     fn insert(
+///         &self,
+///         key: String,
+///         response: CachedResponse,
+///     ) {
+///         self.0.lock().unwrap().insert(key, response);
+///     }
+/// }
+/// ```
+pub trait ResponseCacheStore: Send + Sync + 'static {
+    /// Get the response of a key.
+    fn get(
+        &self,
+        key: &str,
+    ) -> Option<CachedResponse>;
+
+    /// Insert the response of a key, replacing the previous one.
+    fn insert(
+        &self,
+        key: String,
+        response: CachedResponse,
+    );
+}
+
+/// In-memory store of the cached responses,
+/// evicting the earliest stored response when full.
+#[derive(Debug)]
+pub struct MemoryResponseCacheStore {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+    max_entries: usize,
+}
+
+impl MemoryResponseCacheStore {
+    /// Create a new store with the maximum number of entries,
+    /// at least one entry.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            max_entries: max_entries.max(1),
+        }
+    }
+}
+
+impl Default for MemoryResponseCacheStore {
+    /// Create a new store of 1024 entries at most.
+    fn default() -> Self {
+        Self::new(1024)
+    }
+}
+
+impl ResponseCacheStore for MemoryResponseCacheStore {
+    fn get(
+        &self,
+        key: &str,
+    ) -> Option<CachedResponse> {
+        self.entries.lock().ok()?.get(key).cloned()
+    }
+
+    fn insert(
+        &self,
+        key: String,
+        response: CachedResponse,
+    ) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            let earliest: Option<String> = entries
+                .iter()
+                .min_by_key(|(_, response)| response.stored_at)
+                .map(|(key, _)| key.clone());
+
+            if let Some(earliest) = earliest {
+                entries.remove(&earliest);
+            }
+        }
+
+        entries.insert(key, response);
+    }
+}
+
+/// Cache shared by the services of a layer.
+struct ResponseCacher {
+    ttl: Duration,
+    stale: Duration,
+    vary: Vec<HeaderName>,
+    credentials: bool,
+    store: Arc<dyn ResponseCacheStore>,
+    /// Keys of the responses being refreshed in background.
+    refreshing: Mutex<HashSet<String>>,
+}
+
+impl ResponseCacher {
+    /// Check if the request bypasses the cache,
+    /// when it has credentials not allowed to be cached.
+    fn is_bypassed<B>(
+        &self,
+        req: &Request<B>,
+    ) -> bool {
+        !self.credentials
+            && CREDENTIAL_HEADERS
+                .iter()
+                .any(|name| req.headers().contains_key(name))
+    }
+
+    /// Get the key of a request,
+    /// with the method, the URI, the varying headers,
+    /// and the credential headers if credentials are cached.
+    fn key(
+        &self,
+        parts: &Parts,
+    ) -> String {
+        let mut key: String = format!("{} {}", parts.method, parts.uri);
+
+        let credentials: &[HeaderName] = match self.credentials {
+            | true => &CREDENTIAL_HEADERS,
+            | false => &[],
+        };
+
+        for name in self.vary.iter().chain(credentials) {
+            key.push('\n');
+            key.push_str(name.as_str());
+            key.push(':');
+
+            for value in parts.headers.get_all(name) {
+                key.push(' ');
+                key.push_str(&String::from_utf8_lossy(value.as_bytes()));
+            }
+        }
+
+        key
+    }
+
+    /// Mark a key as being refreshed,
+    /// returns `false` if it is already being refreshed.
+    fn start_refresh(
+        &self,
+        key: &str,
+    ) -> bool {
+        self.refreshing
+            .lock()
+            .is_ok_and(|mut refreshing| refreshing.insert(key.to_string()))
+    }
+
+    /// Unmark a key as being refreshed.
+    fn end_refresh(
+        &self,
+        key: &str,
+    ) {
+        if let Ok(mut refreshing) = self.refreshing.lock() {
+            refreshing.remove(key);
+        }
+    }
+
+    /// Store a response if it is cacheable,
+    /// returns the response with its body buffered
+    /// and whether it is stored.
+    async fn store(
+        &self,
+        key: String,
+        res: Res,
+    ) -> (Res, bool) {
+        if !is_cacheable(&res) {
+            return (res, false);
+        }
+
+        let (parts, body) = res.into_parts();
+
+        let body: Bytes = match body.collect().await {
+            | Ok(collected) => collected.to_bytes(),
+            | Err(_) => return (Res::from_parts(parts, Body::empty()), false),
+        };
+
+        self.store.insert(
+            key,
+            CachedResponse {
+                status: parts.status,
+                headers: parts.headers.clone(),
+                body: body.clone(),
+                stored_at: Instant::now(),
+            },
+        );
+
+        (Res::from_parts(parts, Body::from(body)), true)
+    }
+}
+
+/// Check if the response is a success JSON response
+/// allowed to be shared between clients.
+fn is_cacheable(res: &Res) -> bool {
+    let is_json: bool = res
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+
+    let is_shared: bool = res
+        .headers()
+        .get_all(header::CACHE_CONTROL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|directive| directive.trim().to_ascii_lowercase())
+        .all(|directive| {
+            !matches!(directive.as_str(), "no-store" | "no-cache" | "private")
+        });
+
+    res.status() == StatusCode::OK
+        && is_json
+        && is_shared
+        && !res.headers().contains_key(header::SET_COOKIE)
+}
+
+/// Create a response from a cached response,
+/// with the age and the cache status.
+fn create_cached_response_fn(
+    cached: CachedResponse,
+    age: Duration,
+    cache_status: String,
+) -> Res {
+    let mut res: Res = Res::new(Body::from(cached.body));
+
+    *res.status_mut() = cached.status;
+    *res.headers_mut() = cached.headers;
+
+    res.headers_mut().insert(header::AGE, HeaderValue::from(age.as_secs()));
+
+    set_cache_status_fn(&mut res, cache_status);
+
+    res
+}
+
+/// Set the `Cache-Status` header of a response.
+fn set_cache_status_fn(
+    res: &mut Res,
+    cache_status: String,
+) {
+    if let Ok(value) = HeaderValue::try_from(cache_status) {
+        res.headers_mut()
+            .insert(HeaderName::from_static(CACHE_STATUS_HEADER), value);
+    }
+}
+
+#[derive(Clone)]
+pub struct ResponseCacheService<S> {
+    inner: S,
+    cacher: Arc<ResponseCacher>,
+}
+
+impl<B, S> Service<Request<B>> for ResponseCacheService<S>
+where
+    S: Service<Request<B>, Response = Res> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    B: Default + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        if req.method() != Method::GET {
+            return Box::pin(self.inner.call(req));
+        }
+
+        let cacher: Arc<ResponseCacher> = self.cacher.clone();
+
+        // responses of clients with credentials are not shared
+        if cacher.is_bypassed(&req) {
+            let fut: S::Future = self.inner.call(req);
+
+            return Box::pin(async move {
+                let mut res: Res = fut.await?;
+
+                set_cache_status_fn(
+                    &mut res,
+                    format!("{CACHE_STATUS_NAME}; fwd=bypass"),
+                );
+
+                Ok(res)
+            });
+        }
+
+        let (parts, body) = req.into_parts();
+
+        let key: String = cacher.key(&parts);
+
+        let cached: Option<CachedResponse> = cacher.store.get(&key);
+
+        let age: Option<Duration> =
+            cached.as_ref().map(|cached| cached.stored_at.elapsed());
+
+        match (&cached, age) {
+            // fresh response
+            | (Some(cached), Some(age)) if age < cacher.ttl => {
+                let ttl: u64 = (cacher.ttl - age).as_secs();
+
+                let res: Res = create_cached_response_fn(
+                    cached.clone(),
+                    age,
+                    format!("{CACHE_STATUS_NAME}; hit; ttl={ttl}"),
+                );
+
+                return Box::pin(async move { Ok(res) });
+            },
+            // stale response, refreshed in background
+            | (Some(cached), Some(age)) if age < cacher.ttl + cacher.stale => {
+                if cacher.start_refresh(&key) {
+                    let mut inner: S = self.inner.clone();
+                    let req: Request<B> =
+                        Request::from_parts(parts.clone(), B::default());
+                    let cacher: Arc<ResponseCacher> = cacher.clone();
+                    let key: String = key.clone();
+
+                    tokio::spawn(async move {
+                        let is_ready: bool =
+                            std::future::poll_fn(|cx| inner.poll_ready(cx))
+                                .await
+                                .is_ok();
+
+                        let res: Option<Res> = match is_ready {
+                            | true => inner.call(req).await.ok(),
+                            | false => None,
+                        };
+
+                        if let Some(res) = res {
+                            cacher.store(key.clone(), res).await;
+                        }
+
+                        cacher.end_refresh(&key);
+                    });
+                }
+
+                let ttl: u64 = (age - cacher.ttl).as_secs();
+
+                let res: Res = create_cached_response_fn(
+                    cached.clone(),
+                    age,
+                    format!("{CACHE_STATUS_NAME}; hit; ttl=-{ttl}"),
+                );
+
+                return Box::pin(async move { Ok(res) });
+            },
+            | _ => {},
+        }
+
+        let fwd: &str = match cached {
+            | Some(_) => "stale",
+            | None => "miss",
+        };
+
+        let fut: S::Future = self.inner.call(Request::from_parts(parts, body));
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            let (mut res, is_stored): (Res, bool) =
+                cacher.store(key, res).await;
+
+            let cache_status: String = match is_stored {
+                | true => format!("{CACHE_STATUS_NAME}; fwd={fwd}; stored"),
+                | false => format!("{CACHE_STATUS_NAME}; fwd={fwd}"),
+            };
+
+            set_cache_status_fn(&mut res, cache_status);
+
+            Ok(res)
+        })
+    }
+}
+
+/// Layer for caching success JSON responses in process,
+/// serving stale responses while refreshing them in background,
+/// for read-heavy public routes.
+///
+/// Responses of `GET` requests are cached by the method, the URI,
+/// and the values of the varying request headers, such as `Accept-Language`.
+/// Only `200` JSON responses without `Set-Cookie` header
+/// and `Cache-Control` of `no-store`, `no-cache` or `private` are cached.
+///
+/// Requests with the `Authorization` or `Cookie` header bypass the cache,
+/// as their responses may be specific to the client,
+/// unless [`cache_credentials`](ResponseCache::cache_credentials) is set.
+///
+/// A cached response is fresh for the TTL,
+/// and then stale for the stale-while-revalidate duration,
+/// where it is still served,
+/// while a single request refreshes it in background.
+/// After that, the request waits for a new response.
+///
+/// The `Cache-Status` header is set to the responses, such as:
+///
+/// ```text
+/// Cache-Status: jder; hit; ttl=42
+/// Cache-Status: jder; hit; ttl=-3
+/// Cache-Status: jder; fwd=miss; stored
+/// Cache-Status: jder; fwd=bypass
+/// ```
+///
+/// and the `Age` header is set to the cached responses.
+///
+/// The bodies are cached as is, including the meta,
+/// so the layer should be added inside the layers setting the meta,
+/// such as the [`RequestId`](crate::layers::RequestId) layer.
+///
+/// The background refresh is spawned with Tokio.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use axum::{Router, http::header, routing::get};
+/// use jder_axum::{
+///     layers::ResponseCache,
+///     response::{Response, json::CreateJsonResponse},
+/// };
+///
+/// async fn route() -> Response {
+///     CreateJsonResponse::dataless().create()
+/// }
+///
+/// let router: Router = Router::new().route("/products", get(route)).layer(
+///     ResponseCache::new(Duration::from_secs(60))
+///         .stale_while_revalidate(Duration::from_secs(300))
+///         .vary(header::ACCEPT_LANGUAGE),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ResponseCache {
+    ttl: Duration,
+    stale: Duration,
+    vary: Vec<HeaderName>,
+    credentials: bool,
+    store: Arc<dyn ResponseCacheStore>,
+}
+
+impl ResponseCache {
+    /// Create a new `ResponseCache` layer
+    /// with the TTL of the fresh responses,
+    /// stored with a [`MemoryResponseCacheStore`] by default.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            stale: Duration::ZERO,
+            vary: Vec::new(),
+            credentials: false,
+            store: Arc::new(MemoryResponseCacheStore::default()),
+        }
+    }
+
+    /// Set the duration stale responses are served after the TTL,
+    /// while being refreshed in background.
+    pub fn stale_while_revalidate(
+        mut self,
+        stale: Duration,
+    ) -> Self {
+        self.stale = stale;
+
+        self
+    }
+
+    /// Add a request header the responses vary by.
+    pub fn vary(
+        mut self,
+        name: HeaderName,
+    ) -> Self {
+        self.vary.push(name);
+
+        self
+    }
+
+    /// Set whether to cache the responses of requests
+    /// with the `Authorization` or `Cookie` header,
+    /// which bypass the cache by default.
+    ///
+    /// The responses are cached by the values of the headers,
+    /// so that a response is only served to the same credentials.
+    pub fn cache_credentials(
+        mut self,
+        credentials: bool,
+    ) -> Self {
+        self.credentials = credentials;
+
+        self
+    }
+
+    /// Set the store of the cached responses.
+    pub fn store<T: ResponseCacheStore>(
+        mut self,
+        store: T,
+    ) -> Self {
+        self.store = Arc::new(store);
+
+        self
+    }
+}
+
+impl<S> Layer<S> for ResponseCache {
+    type Service = ResponseCacheService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        ResponseCacheService {
+            inner,
+            cacher: Arc::new(ResponseCacher {
+                ttl: self.ttl,
+                stale: self.stale,
+                vary: self.vary.clone(),
+                credentials: self.credentials,
+                store: self.store.clone(),
+                refreshing: Mutex::new(HashSet::new()),
+            }),
+        }
+    }
+}
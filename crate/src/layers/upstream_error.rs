@@ -0,0 +1,193 @@
+use std::task::Context;
+
+use http::{HeaderMap, HeaderValue, Request, StatusCode, header};
+use http_body::Body as _;
+use serde_json::{Map, Value};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::response::{
+    Response as Res,
+    json::{
+        CreateJsonResponse, JsonResponseError, ResponseError,
+        create::failure::CreateFailureJsonResponse,
+    },
+};
+
+/// Header name of the correlation ID of the request.
+pub const UPSTREAM_ERROR_REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Meta key of the correlation ID in the response.
+pub const UPSTREAM_ERROR_REQUEST_ID_META_KEY: &str = "request_id";
+
+#[derive(Debug, Clone)]
+pub struct UpstreamErrorService<S> {
+    inner: S,
+}
+
+impl<B, S> Service<Request<B>> for UpstreamErrorService<S>
+where
+    S: Service<Request<B>, Response = Res>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Self::Response, Self::Error>,
+                > + Send,
+        >,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        req: Request<B>,
+    ) -> Self::Future {
+        let request_id: Option<HeaderValue> = get_request_id(req.headers());
+
+        let fut: S::Future = self.inner.call(req);
+
+        Box::pin(async move {
+            let res: Res = fut.await?;
+
+            let error: ResponseError = match get_upstream_error(&res) {
+                | Some(error) => error,
+                | None => return Ok(res),
+            };
+
+            let (mut parts, _) = res.into_parts();
+
+            let request_id: Option<HeaderValue> =
+                request_id.or_else(|| get_request_id(&parts.headers));
+
+            let mut failure: CreateFailureJsonResponse<()> =
+                CreateJsonResponse::failure_with(parts.status)
+                    .add_error(JsonResponseError::from(error));
+
+            if let Some(id) =
+                request_id.as_ref().and_then(|id| id.to_str().ok())
+            {
+                let mut meta: Map<String, Value> = Map::new();
+
+                meta.insert(
+                    UPSTREAM_ERROR_REQUEST_ID_META_KEY.to_string(),
+                    Value::String(id.to_string()),
+                );
+
+                failure = failure
+                    .header(UPSTREAM_ERROR_REQUEST_ID_HEADER, id)
+                    .meta(meta);
+            }
+
+            let (created, body) = failure.create().into_parts();
+
+            // keep the headers of the upstream, such as `retry-after`
+            parts.headers.remove(header::CONTENT_LENGTH);
+            parts.headers.extend(created.headers);
+
+            Ok(Res::from_parts(parts, body))
+        })
+    }
+}
+
+/// Get the correlation ID from the headers.
+fn get_request_id(headers: &HeaderMap) -> Option<HeaderValue> {
+    headers.get(UPSTREAM_ERROR_REQUEST_ID_HEADER).cloned()
+}
+
+/// Get the error of an upstream failure with an empty body.
+fn get_upstream_error(res: &Res) -> Option<ResponseError> {
+    let error: ResponseError = match res.status() {
+        | StatusCode::BAD_GATEWAY => ResponseError::BadGateway,
+        | StatusCode::SERVICE_UNAVAILABLE => ResponseError::Unavailable,
+        | StatusCode::GATEWAY_TIMEOUT => ResponseError::Timeout,
+        | _ => return None,
+    };
+
+    match res.body().size_hint().exact() {
+        | Some(0) => Some(error),
+        | _ => None,
+    }
+}
+
+/// Layer for translating upstream failures into failure responses,
+/// available with `upstream_error` feature.
+///
+/// Responses of `502`, `503` and `504` with an empty body,
+/// such as the ones generated by a reverse proxy,
+/// are rewritten into failure responses with the same status
+/// and the headers of the upstream, such as `retry-after`:
+/// - `502`: [`BadGateway`](ResponseError::BadGateway) code
+/// - `503`: [`Unavailable`](ResponseError::Unavailable) code
+/// - `504`: [`Timeout`](ResponseError::Timeout) code
+///
+/// The correlation ID of the
+/// [`x-request-id`](UPSTREAM_ERROR_REQUEST_ID_HEADER) header
+/// of the request, or the response if not set,
+/// is set into the header and the
+/// [`request_id`](UPSTREAM_ERROR_REQUEST_ID_META_KEY) field of meta:
+///
+/// ```jsonc
+/// // Status: 502
+/// {
+///     "success": false,
+///     "data": null,
+///     "errors": [
+///         {
+///             "code": "bad_gateway",
+///             "path": [],
+///             "message": "Bad gateway"
+///         }
+///     ],
+///     "meta": {
+///         "request_id": "..."
+///     }
+/// }
+/// ```
+///
+/// Responses with a body, such as failure responses, are kept as is.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::layers::UpstreamError;
+///
+/// let router: Router = Router::new().layer(UpstreamError::new());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UpstreamError;
+
+impl UpstreamError {
+    /// Create a new `UpstreamError` layer.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::layers::UpstreamError;
+    ///
+    /// UpstreamError::new();
+    /// ```
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for UpstreamError {
+    type Service = UpstreamErrorService<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        UpstreamErrorService { inner }
+    }
+}
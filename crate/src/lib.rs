@@ -68,8 +68,30 @@
 //! }
 //! ```
 
+/// Re-exports for the derive macros.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use axum_core::response::IntoResponse;
+    pub use http::StatusCode;
+}
+
 pub mod extract;
 
+pub mod handler;
+
+#[cfg(any(
+    feature = "jwt",
+    feature = "pagination_cursor",
+    feature = "webhook"
+))]
+pub(crate) mod hmac;
+
 pub mod layers;
 
 pub mod response;
+
+/// Well-known endpoints module,
+/// available with `wellknown` feature.
+#[cfg(feature = "wellknown")]
+pub mod wellknown;
@@ -0,0 +1,661 @@
+mod tar;
+mod zip;
+
+use std::fmt::{self, Debug, Formatter};
+
+use axum_core::{BoxError, body::Body};
+use bytes::Bytes;
+use futures_util::{
+    Stream, StreamExt, TryStreamExt,
+    stream::{self, BoxStream},
+};
+use http::{
+    Error as HTTPError, HeaderName, HeaderValue, StatusCode, header,
+    response::Builder,
+};
+
+use crate::response::{
+    Response,
+    archive::{tar::TarGzEncoder, zip::ZipEncoder},
+    json::create::{JsonResponseState, base::create_json_response_fn},
+};
+
+/// Encoder assembling an archive entry by entry.
+pub(crate) trait ArchiveEncoder: Send {
+    /// Start an entry with its size if known, and get the bytes to send.
+    fn start_entry(
+        &mut self,
+        name: &str,
+        size: Option<u64>,
+    ) -> Result<Bytes, BoxError>;
+
+    /// Write a chunk of the entry, and get the bytes to send.
+    fn write_entry(
+        &mut self,
+        chunk: Bytes,
+    ) -> Result<Bytes, BoxError>;
+
+    /// End the entry, and get the bytes to send.
+    fn end_entry(&mut self) -> Result<Bytes, BoxError>;
+
+    /// Finish the archive, and get the bytes to send.
+    fn finish(&mut self) -> Result<Bytes, BoxError>;
+}
+
+/// Format of an archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// Zip archive with stored entries,
+    /// where entries without a known size or over 4 GiB are in zip64.
+    Zip,
+    /// Tar archive compressed with gzip,
+    /// where entries without a known size are buffered before being sent.
+    TarGz,
+}
+
+impl ArchiveFormat {
+    /// Get the content type of the format.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            | Self::Zip => "application/zip",
+            | Self::TarGz => "application/gzip",
+        }
+    }
+
+    /// Get the file extension of the format, such as `zip`.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            | Self::Zip => "zip",
+            | Self::TarGz => "tar.gz",
+        }
+    }
+
+    /// Create the encoder of the format.
+    fn encoder(&self) -> Box<dyn ArchiveEncoder> {
+        match self {
+            | Self::Zip => Box::new(ZipEncoder::new()),
+            | Self::TarGz => Box::new(TarGzEncoder::new()),
+        }
+    }
+}
+
+/// Entry of an archive with a name and a stream of content.
+///
+/// A tuple of a name and a stream can be converted into it.
+///
+/// An entry with a known size, such as from [`ArchiveEntry::sized`]
+/// or [`ArchiveEntry::bytes`], is streamed without being buffered,
+/// and the archive is aborted if the content does not match the size.
+///
+/// ## Example
+///
+/// ```no_run
+/// use futures_util::stream;
+/// use jder_axum::response::archive::ArchiveEntry;
+///
+/// let entry: ArchiveEntry = ArchiveEntry::new(
+///     "users/1.json",
+///     stream::iter([Ok::<_, std::io::Error>("{}")]),
+/// );
+/// ```
+pub struct ArchiveEntry {
+    /// Name of the entry.
+    name: String,
+    /// Size of the content if known.
+    size: Option<u64>,
+    /// Content of the entry.
+    body: BoxStream<'static, Result<Bytes, BoxError>>,
+}
+
+impl ArchiveEntry {
+    /// Create a new entry from a stream of content.
+    pub fn new<N, S, B, E>(
+        name: N,
+        body: S,
+    ) -> Self
+    where
+        N: Into<String>,
+        S: Stream<Item = Result<B, E>> + Send + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        Self {
+            name: name.into(),
+            size: None,
+            body: body.map_ok(Into::into).map_err(Into::into).boxed(),
+        }
+    }
+
+    /// Create a new entry from a stream of content with a known size.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::archive::ArchiveEntry;
+    ///
+    /// let entry: ArchiveEntry = ArchiveEntry::sized(
+    ///     "users/1.json",
+    ///     2,
+    ///     stream::iter([Ok::<_, std::io::Error>("{}")]),
+    /// );
+    /// ```
+    pub fn sized<N, S, B, E>(
+        name: N,
+        size: u64,
+        body: S,
+    ) -> Self
+    where
+        N: Into<String>,
+        S: Stream<Item = Result<B, E>> + Send + 'static,
+        B: Into<Bytes> + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        Self { size: Some(size), ..Self::new(name, body) }
+    }
+
+    /// Create a new entry from bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::archive::ArchiveEntry;
+    ///
+    /// let entry: ArchiveEntry = ArchiveEntry::bytes("readme.txt", "Hello");
+    /// ```
+    pub fn bytes<N, B>(
+        name: N,
+        bytes: B,
+    ) -> Self
+    where
+        N: Into<String>,
+        B: Into<Bytes>,
+    {
+        let bytes: Bytes = bytes.into();
+
+        Self::sized(
+            name,
+            bytes.len() as u64,
+            stream::iter([Ok::<Bytes, BoxError>(bytes)]),
+        )
+    }
+
+    /// Get the name of the entry.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the size of the entry if known.
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+}
+
+impl Debug for ArchiveEntry {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("ArchiveEntry")
+            .field("name", &self.name)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<N, S, B, E> From<(N, S)> for ArchiveEntry
+where
+    N: Into<String>,
+    S: Stream<Item = Result<B, E>> + Send + 'static,
+    B: Into<Bytes> + 'static,
+    E: Into<BoxError> + 'static,
+{
+    fn from((name, body): (N, S)) -> Self {
+        Self::new(name, body)
+    }
+}
+
+/// Check if an entry name is a relative path,
+/// without empty, `.` or `..` segments and backslashes,
+/// to prevent path traversal while extracting.
+fn is_entry_name_valid_fn(name: &str) -> bool {
+    !name.contains(['\\', '\0'])
+        && name
+            .trim_end_matches('/')
+            .split('/')
+            .all(|segment| !matches!(segment, "" | "." | ".."))
+}
+
+/// Check if a file name can be quoted in `Content-Disposition`.
+fn is_file_name_valid_fn(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| (0x20..0x7F).contains(&b) && b != b'"' && b != b'\\')
+}
+
+/// State of an archive being assembled.
+struct ArchiveStreamState {
+    /// Entries to be written.
+    entries: BoxStream<'static, ArchiveEntry>,
+    /// Encoder of the archive.
+    encoder: Box<dyn ArchiveEncoder>,
+    /// Content of the entry being written.
+    body: Option<BoxStream<'static, Result<Bytes, BoxError>>>,
+    /// Size left of the entry being written if known.
+    remaining: Option<u64>,
+    /// Whether the archive is finished or aborted.
+    is_done: bool,
+}
+
+/// Get the next chunk of an archive,
+/// where the archive is aborted on the first error.
+async fn next_archive_chunk_fn(
+    state: &mut ArchiveStreamState
+) -> Option<Result<Bytes, BoxError>> {
+    loop {
+        if state.is_done {
+            return None;
+        }
+
+        let result: Result<Bytes, BoxError> = match &mut state.body {
+            | Some(body) => match body.next().await {
+                | Some(Ok(chunk)) => match state.remaining {
+                    | Some(remaining) if chunk.len() as u64 > remaining => Err(
+                        BoxError::from("Archive entry is larger than its size"),
+                    ),
+                    | remaining => {
+                        state.remaining =
+                            remaining.map(|r| r - chunk.len() as u64);
+
+                        state.encoder.write_entry(chunk)
+                    },
+                },
+                | Some(Err(err)) => Err(err),
+                | None => match state.remaining {
+                    | Some(remaining) if remaining > 0 => Err(BoxError::from(
+                        "Archive entry is smaller than its size",
+                    )),
+                    | _ => {
+                        state.body = None;
+
+                        state.encoder.end_entry()
+                    },
+                },
+            },
+            | None => match state.entries.next().await {
+                | Some(entry) if is_entry_name_valid_fn(&entry.name) => {
+                    state.body = Some(entry.body);
+                    state.remaining = entry.size;
+
+                    state.encoder.start_entry(&entry.name, entry.size)
+                },
+                | Some(entry) => Err(BoxError::from(format!(
+                    "Invalid archive entry name `{}`",
+                    entry.name
+                ))),
+                | None => {
+                    state.is_done = true;
+
+                    state.encoder.finish()
+                },
+            },
+        };
+
+        match result {
+            | Ok(chunk) if chunk.is_empty() => continue,
+            | Ok(chunk) => return Some(Ok(chunk)),
+            | Err(err) => {
+                state.is_done = true;
+
+                return Some(Err(err));
+            },
+        }
+    }
+}
+
+/// Functions for creating an archive response.
+pub struct ArchiveResponseFunctions {
+    /// Internal state.
+    state: JsonResponseState<()>,
+    /// Format of the archive.
+    format: ArchiveFormat,
+    /// Entries of the archive.
+    entries: BoxStream<'static, ArchiveEntry>,
+    /// File name in `Content-Disposition`.
+    file_name: Option<String>,
+}
+
+impl ArchiveResponseFunctions {
+    /// Set the status code for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     archive::{ArchiveEntry, CreateArchiveResponse},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateArchiveResponse::zip(stream::empty::<ArchiveEntry>())
+    ///         .status(StatusCode::CREATED)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn status<C: Into<StatusCode>>(
+        mut self,
+        status: C,
+    ) -> Self {
+        self.state.status = status.into();
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     archive::{ArchiveEntry, CreateArchiveResponse},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateArchiveResponse::zip(stream::empty::<ArchiveEntry>())
+    ///         .header(header::CACHE_CONTROL, "no-store")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        let key: HeaderName = match <HeaderName as TryFrom<K>>::try_from(key) {
+            | Ok(k) => k,
+            | Err(_) => {
+                self.state.is_header_map_failed = true;
+                return self;
+            },
+        };
+
+        let value: HeaderValue =
+            match <HeaderValue as TryFrom<V>>::try_from(value) {
+                | Ok(v) => v,
+                | Err(_) => {
+                    self.state.is_header_map_failed = true;
+                    return self;
+                },
+            };
+
+        self.state.header_map.append(key, value);
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        for (key, value) in headers {
+            self = self.header(key, value);
+        }
+
+        self
+    }
+
+    /// Set the file name for downloading the archive,
+    /// with an `attachment` in `Content-Disposition` header.
+    ///
+    /// A file name with non-ASCII characters, quotes or backslashes
+    /// fails the response like an invalid header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     archive::{ArchiveEntry, CreateArchiveResponse},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateArchiveResponse::zip(stream::empty::<ArchiveEntry>())
+    ///         .file_name("export.zip")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn file_name<N: Into<String>>(
+        mut self,
+        name: N,
+    ) -> Self {
+        self.file_name = Some(name.into());
+
+        self
+    }
+
+    /// Finish the response creation.
+    ///
+    /// The archive is assembled while the body is being sent.
+    /// If an entry fails to be read or written,
+    /// such as an invalid entry name, the body will be aborted.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     archive::{ArchiveEntry, CreateArchiveResponse},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateArchiveResponse::zip(stream::iter([
+    ///         ArchiveEntry::bytes("readme.txt", "Hello"),
+    ///     ]))
+    ///     .create()
+    /// }
+    /// ```
+    pub fn create(mut self) -> Response {
+        if let Some(name) = self.file_name.take() {
+            match is_file_name_valid_fn(&name) {
+                | true => {
+                    self = self.header(
+                        header::CONTENT_DISPOSITION,
+                        format!("attachment; filename=\"{}\"", name),
+                    );
+                },
+                | false => self.state.is_header_map_failed = true,
+            }
+        }
+
+        // header map error
+        if self.state.is_header_map_failed {
+            return create_json_response_fn(self.state);
+        }
+
+        let state: ArchiveStreamState = ArchiveStreamState {
+            entries: self.entries,
+            encoder: self.format.encoder(),
+            body: None,
+            remaining: None,
+            is_done: false,
+        };
+
+        let chunks = stream::unfold(state, |mut state| async move {
+            next_archive_chunk_fn(&mut state).await.map(|chunk| (chunk, state))
+        });
+
+        // create response builder
+        let mut builder: Builder = Response::builder()
+            .status(self.state.status)
+            .version(self.state.version)
+            .header(header::CONTENT_TYPE, self.format.content_type());
+
+        // push headers, where repeated values come without the name
+        let mut name: Option<HeaderName> = None;
+
+        for (header, value) in self.state.header_map {
+            if header.is_some() {
+                name = header;
+            }
+
+            if let Some(name) = &name {
+                builder = builder.header(name, value);
+            }
+        }
+
+        match builder.body(Body::from_stream(chunks)) {
+            | Ok(res) => res,
+            | Err(_) => {
+                let mut state: JsonResponseState<()> =
+                    JsonResponseState::success();
+
+                state.is_header_map_failed = true;
+
+                create_json_response_fn(state)
+            },
+        }
+    }
+}
+
+/// Create an archive response streamed from entries for a route,
+/// available with `archive` feature.
+///
+/// The archive is assembled on the fly while the body is being sent,
+/// and failures before sending, such as invalid headers,
+/// are failure responses.
+///
+/// ## Example
+///
+/// ```no_run
+/// use futures_util::{StreamExt, stream};
+/// use jder_axum::response::{
+///     Response,
+///     archive::{ArchiveEntry, CreateArchiveResponse},
+/// };
+///
+/// async fn route() -> Response {
+///     let entries = stream::iter(1..=3).map(|id| {
+///         ArchiveEntry::bytes(format!("users/{id}.json"), "{}")
+///     });
+///
+///     CreateArchiveResponse::zip(entries).file_name("users.zip").create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateArchiveResponse;
+
+impl CreateArchiveResponse {
+    /// Create an archive response of a format from a stream of entries.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     archive::{ArchiveEntry, ArchiveFormat, CreateArchiveResponse},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateArchiveResponse::with_format(
+    ///         ArchiveFormat::TarGz,
+    ///         stream::iter([ArchiveEntry::bytes("readme.txt", "Hello")]),
+    ///     )
+    ///     .create()
+    /// }
+    /// ```
+    pub fn with_format<S, E>(
+        format: ArchiveFormat,
+        entries: S,
+    ) -> ArchiveResponseFunctions
+    where
+        S: Stream<Item = E> + Send + 'static,
+        E: Into<ArchiveEntry> + 'static,
+    {
+        ArchiveResponseFunctions {
+            state: JsonResponseState::success(),
+            format,
+            entries: entries.map(Into::into).boxed(),
+            file_name: None,
+        }
+    }
+
+    /// Create a zip archive response from a stream of entries.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     archive::{ArchiveEntry, CreateArchiveResponse},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateArchiveResponse::zip(stream::iter([
+    ///         ArchiveEntry::bytes("readme.txt", "Hello"),
+    ///     ]))
+    ///     .create()
+    /// }
+    /// ```
+    pub fn zip<S, E>(entries: S) -> ArchiveResponseFunctions
+    where
+        S: Stream<Item = E> + Send + 'static,
+        E: Into<ArchiveEntry> + 'static,
+    {
+        Self::with_format(ArchiveFormat::Zip, entries)
+    }
+
+    /// Create a tar.gz archive response from a stream of entries.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     archive::{ArchiveEntry, CreateArchiveResponse},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateArchiveResponse::tar_gz(stream::iter([
+    ///         ArchiveEntry::bytes("readme.txt", "Hello"),
+    ///     ]))
+    ///     .create()
+    /// }
+    /// ```
+    pub fn tar_gz<S, E>(entries: S) -> ArchiveResponseFunctions
+    where
+        S: Stream<Item = E> + Send + 'static,
+        E: Into<ArchiveEntry> + 'static,
+    {
+        Self::with_format(ArchiveFormat::TarGz, entries)
+    }
+}
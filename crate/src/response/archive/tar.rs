@@ -0,0 +1,211 @@
+use std::io::Write as _;
+
+use axum_core::BoxError;
+use bytes::{BufMut, Bytes, BytesMut, buf::Writer};
+use flate2::{Compression, write::GzEncoder};
+
+use crate::response::archive::ArchiveEncoder;
+
+/// Size of a tar block.
+const BLOCK_SIZE: usize = 512;
+
+/// Write a NUL-terminated octal number into a tar header field.
+fn put_octal_fn(
+    field: &mut [u8],
+    value: u64,
+) {
+    let width: usize = field.len() - 1;
+
+    let digits: String = format!("{:0width$o}", value, width = width);
+
+    field[..width].copy_from_slice(&digits.as_bytes()[digits.len() - width..]);
+}
+
+/// Write a size into a tar header field,
+/// in base-256 if it exceeds the octal digits, such as over 8 GiB.
+fn put_size_fn(
+    field: &mut [u8],
+    size: u64,
+) {
+    if size < 1 << (3 * (field.len() - 1)) {
+        return put_octal_fn(field, size);
+    }
+
+    let start: usize = field.len() - 8;
+
+    field.fill(0);
+    field[0] = 0x80;
+    field[start..].copy_from_slice(&size.to_be_bytes());
+}
+
+/// Split an entry name into the name and the prefix of a ustar header.
+fn split_name_fn(name: &str) -> Result<(&str, &str), BoxError> {
+    if name.len() <= 100 {
+        return Ok(("", name));
+    }
+
+    name.char_indices()
+        .filter(|(_, c)| *c == '/')
+        .map(|(i, _)| (&name[..i], &name[i + 1..]))
+        .find(|(prefix, name)| prefix.len() <= 155 && name.len() <= 100)
+        .ok_or_else(|| BoxError::from("Archive entry name is too long"))
+}
+
+/// Create a ustar header of a regular file.
+fn create_tar_header_fn(
+    name: &str,
+    size: u64,
+) -> Result<[u8; BLOCK_SIZE], BoxError> {
+    let (prefix, name) = split_name_fn(name)?;
+
+    let mut header: [u8; BLOCK_SIZE] = [0; BLOCK_SIZE];
+
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    put_octal_fn(&mut header[100..108], 0o644);
+    put_octal_fn(&mut header[108..116], 0);
+    put_octal_fn(&mut header[116..124], 0);
+    put_size_fn(&mut header[124..136], size);
+    put_octal_fn(&mut header[136..148], 0);
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    header[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+
+    // checksum with the field as spaces
+    header[148..156].fill(b' ');
+
+    let checksum: u32 = header.iter().map(|byte| *byte as u32).sum();
+
+    put_octal_fn(&mut header[148..155], checksum as u64);
+
+    Ok(header)
+}
+
+/// Entry of a tar archive being written.
+enum TarEntry {
+    /// Entry with a known size, written while being read.
+    Sized {
+        /// Size of the content.
+        size: u64,
+    },
+    /// Entry without a known size, buffered before being written,
+    /// as the size comes before the content.
+    Buffered {
+        /// Name of the entry.
+        name: String,
+        /// Content of the entry.
+        content: BytesMut,
+    },
+}
+
+/// Encoder of a tar archive compressed with gzip.
+pub(crate) struct TarGzEncoder {
+    /// Gzip encoder writing into the bytes to send.
+    gzip: GzEncoder<Writer<BytesMut>>,
+    /// Entry being written.
+    current: Option<TarEntry>,
+}
+
+impl TarGzEncoder {
+    /// Create a new tar.gz encoder.
+    pub(crate) fn new() -> Self {
+        Self {
+            gzip: GzEncoder::new(
+                BytesMut::new().writer(),
+                Compression::default(),
+            ),
+            current: None,
+        }
+    }
+
+    /// Write bytes of the tar stream into the gzip encoder.
+    fn write(
+        &mut self,
+        data: &[u8],
+    ) -> Result<(), BoxError> {
+        self.gzip.write_all(data)?;
+
+        Ok(())
+    }
+
+    /// Take the compressed bytes to send.
+    fn take(&mut self) -> Bytes {
+        self.gzip.get_mut().get_mut().split().freeze()
+    }
+
+    /// Write the padding of an entry to the block size.
+    fn write_padding(
+        &mut self,
+        size: u64,
+    ) -> Result<(), BoxError> {
+        let padding: usize =
+            (BLOCK_SIZE - (size % BLOCK_SIZE as u64) as usize) % BLOCK_SIZE;
+
+        self.write(&[0; BLOCK_SIZE][..padding])
+    }
+}
+
+impl ArchiveEncoder for TarGzEncoder {
+    fn start_entry(
+        &mut self,
+        name: &str,
+        size: Option<u64>,
+    ) -> Result<Bytes, BoxError> {
+        split_name_fn(name)?;
+
+        let entry: TarEntry = match size {
+            | Some(size) => {
+                self.write(&create_tar_header_fn(name, size)?)?;
+
+                TarEntry::Sized { size }
+            },
+            | None => TarEntry::Buffered {
+                name: name.to_string(),
+                content: BytesMut::new(),
+            },
+        };
+
+        self.current = Some(entry);
+
+        Ok(self.take())
+    }
+
+    fn write_entry(
+        &mut self,
+        chunk: Bytes,
+    ) -> Result<Bytes, BoxError> {
+        match &mut self.current {
+            | Some(TarEntry::Buffered { content, .. }) => {
+                content.put_slice(&chunk);
+            },
+            | _ => self.write(&chunk)?,
+        }
+
+        Ok(self.take())
+    }
+
+    fn end_entry(&mut self) -> Result<Bytes, BoxError> {
+        match self.current.take() {
+            | Some(TarEntry::Sized { size }) => self.write_padding(size)?,
+            | Some(TarEntry::Buffered { name, content }) => {
+                let size: u64 = content.len() as u64;
+
+                self.write(&create_tar_header_fn(&name, size)?)?;
+                self.write(&content)?;
+                self.write_padding(size)?;
+            },
+            | None => {},
+        }
+
+        Ok(self.take())
+    }
+
+    fn finish(&mut self) -> Result<Bytes, BoxError> {
+        // end of archive
+        self.write(&[0; BLOCK_SIZE * 2])?;
+
+        self.gzip.try_finish()?;
+
+        Ok(self.take())
+    }
+}
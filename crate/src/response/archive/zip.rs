@@ -0,0 +1,294 @@
+use axum_core::BoxError;
+use bytes::{BufMut, Bytes, BytesMut};
+use crc32fast::Hasher;
+
+use crate::response::archive::ArchiveEncoder;
+
+/// Signature of a local file header.
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4B50;
+
+/// Signature of a data descriptor.
+const DATA_DESCRIPTOR_SIGNATURE: u32 = 0x0807_4B50;
+
+/// Signature of a central directory header.
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4B50;
+
+/// Signature of the zip64 end of central directory record.
+const ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0606_4B50;
+
+/// Signature of the zip64 end of central directory locator.
+const ZIP64_END_OF_CENTRAL_DIRECTORY_LOCATOR_SIGNATURE: u32 = 0x0706_4B50;
+
+/// Signature of the end of central directory record.
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4B50;
+
+/// Version needed to extract, 2.0 for stored entries.
+const VERSION: u16 = 20;
+
+/// Version needed to extract, 4.5 for zip64 entries.
+const VERSION_ZIP64: u16 = 45;
+
+/// Header ID of the zip64 extended information extra field.
+const ZIP64_EXTRA_ID: u16 = 0x0001;
+
+/// Value of a field stored in zip64 instead.
+const ZIP64_MARKER: u32 = u32::MAX;
+
+/// General purpose flags,
+/// sizes in data descriptor and UTF-8 names.
+const FLAGS: u16 = 0x0808;
+
+/// Modification date of 1980-01-01 in MS-DOS format.
+const DOS_DATE: u16 = 0x0021;
+
+/// Check if a size or an offset must be stored in zip64.
+fn is_zip64_fn(value: u64) -> bool {
+    value >= ZIP64_MARKER as u64
+}
+
+/// Entry of a zip archive.
+struct ZipEntry {
+    /// Name of the entry.
+    name: String,
+    /// Offset of the local file header.
+    offset: u64,
+    /// Checksum of the content.
+    crc: Hasher,
+    /// Size of the content.
+    size: u64,
+    /// Whether the sizes are stored in zip64,
+    /// for an entry without a known size or a large entry.
+    is_zip64: bool,
+}
+
+/// Encoder of a zip archive with stored entries,
+/// where the sizes and checksums follow the content
+/// in data descriptors,
+/// and zip64 records are written for large archives.
+pub(crate) struct ZipEncoder {
+    /// Bytes written so far.
+    offset: u64,
+    /// Entry being written.
+    current: Option<ZipEntry>,
+    /// Written entries for the central directory.
+    entries: Vec<ZipEntry>,
+}
+
+impl ZipEncoder {
+    /// Create a new zip encoder.
+    pub(crate) fn new() -> Self {
+        Self { offset: 0, current: None, entries: Vec::new() }
+    }
+}
+
+impl ArchiveEncoder for ZipEncoder {
+    fn start_entry(
+        &mut self,
+        name: &str,
+        size: Option<u64>,
+    ) -> Result<Bytes, BoxError> {
+        let name_len: u16 = u16::try_from(name.len())
+            .map_err(|_| BoxError::from("Archive entry name is too long"))?;
+
+        // the size of an entry is unknown until the content is written
+        let is_zip64: bool = size.is_none_or(is_zip64_fn);
+
+        let mut buf: BytesMut = BytesMut::with_capacity(50 + name.len());
+
+        buf.put_u32_le(LOCAL_HEADER_SIGNATURE);
+        buf.put_u16_le(match is_zip64 {
+            | true => VERSION_ZIP64,
+            | false => VERSION,
+        });
+        buf.put_u16_le(FLAGS);
+        // stored
+        buf.put_u16_le(0);
+        buf.put_u16_le(0);
+        buf.put_u16_le(DOS_DATE);
+        // checksum and sizes in data descriptor
+        buf.put_u32_le(0);
+
+        match is_zip64 {
+            | true => {
+                buf.put_u32_le(ZIP64_MARKER);
+                buf.put_u32_le(ZIP64_MARKER);
+                buf.put_u16_le(name_len);
+                buf.put_u16_le(20);
+                buf.put_slice(name.as_bytes());
+                buf.put_u16_le(ZIP64_EXTRA_ID);
+                buf.put_u16_le(16);
+                buf.put_u64_le(0);
+                buf.put_u64_le(0);
+            },
+            | false => {
+                buf.put_u32_le(0);
+                buf.put_u32_le(0);
+                buf.put_u16_le(name_len);
+                buf.put_u16_le(0);
+                buf.put_slice(name.as_bytes());
+            },
+        }
+
+        self.current = Some(ZipEntry {
+            name: name.to_string(),
+            offset: self.offset,
+            crc: Hasher::new(),
+            size: 0,
+            is_zip64,
+        });
+
+        self.offset += buf.len() as u64;
+
+        Ok(buf.freeze())
+    }
+
+    fn write_entry(
+        &mut self,
+        chunk: Bytes,
+    ) -> Result<Bytes, BoxError> {
+        if let Some(entry) = &mut self.current {
+            entry.crc.update(&chunk);
+            entry.size += chunk.len() as u64;
+        }
+
+        self.offset += chunk.len() as u64;
+
+        Ok(chunk)
+    }
+
+    fn end_entry(&mut self) -> Result<Bytes, BoxError> {
+        let entry: ZipEntry = match self.current.take() {
+            | Some(entry) => entry,
+            | None => return Ok(Bytes::new()),
+        };
+
+        let mut buf: BytesMut = BytesMut::with_capacity(24);
+
+        buf.put_u32_le(DATA_DESCRIPTOR_SIGNATURE);
+        buf.put_u32_le(entry.crc.clone().finalize());
+
+        match entry.is_zip64 {
+            | true => {
+                buf.put_u64_le(entry.size);
+                buf.put_u64_le(entry.size);
+            },
+            | false => {
+                buf.put_u32_le(entry.size as u32);
+                buf.put_u32_le(entry.size as u32);
+            },
+        }
+
+        self.offset += buf.len() as u64;
+
+        self.entries.push(entry);
+
+        Ok(buf.freeze())
+    }
+
+    fn finish(&mut self) -> Result<Bytes, BoxError> {
+        let offset: u64 = self.offset;
+
+        let mut buf: BytesMut = BytesMut::new();
+
+        for entry in &self.entries {
+            let is_size_zip64: bool = entry.is_zip64 || is_zip64_fn(entry.size);
+            let is_offset_zip64: bool = is_zip64_fn(entry.offset);
+
+            let extra_len: u16 = match (is_size_zip64, is_offset_zip64) {
+                | (true, true) => 24,
+                | (true, false) => 16,
+                | (false, true) => 8,
+                | (false, false) => 0,
+            };
+
+            let version: u16 = match extra_len {
+                | 0 => VERSION,
+                | _ => VERSION_ZIP64,
+            };
+
+            let size: u32 = match is_size_zip64 {
+                | true => ZIP64_MARKER,
+                | false => entry.size as u32,
+            };
+
+            buf.put_u32_le(CENTRAL_HEADER_SIGNATURE);
+            buf.put_u16_le(version);
+            buf.put_u16_le(version);
+            buf.put_u16_le(FLAGS);
+            buf.put_u16_le(0);
+            buf.put_u16_le(0);
+            buf.put_u16_le(DOS_DATE);
+            buf.put_u32_le(entry.crc.clone().finalize());
+            buf.put_u32_le(size);
+            buf.put_u32_le(size);
+            buf.put_u16_le(entry.name.len() as u16);
+            buf.put_u16_le(match extra_len {
+                | 0 => 0,
+                | len => len + 4,
+            });
+            // comment, disk and attributes
+            buf.put_u16_le(0);
+            buf.put_u16_le(0);
+            buf.put_u16_le(0);
+            buf.put_u32_le(0);
+            buf.put_u32_le(match is_offset_zip64 {
+                | true => ZIP64_MARKER,
+                | false => entry.offset as u32,
+            });
+            buf.put_slice(entry.name.as_bytes());
+
+            if extra_len > 0 {
+                buf.put_u16_le(ZIP64_EXTRA_ID);
+                buf.put_u16_le(extra_len);
+
+                if is_size_zip64 {
+                    buf.put_u64_le(entry.size);
+                    buf.put_u64_le(entry.size);
+                }
+
+                if is_offset_zip64 {
+                    buf.put_u64_le(entry.offset);
+                }
+            }
+        }
+
+        let size: u64 = buf.len() as u64;
+        let count: u64 = self.entries.len() as u64;
+
+        let is_zip64: bool = count >= u16::MAX as u64
+            || is_zip64_fn(size)
+            || is_zip64_fn(offset);
+
+        if is_zip64 {
+            let zip64_offset: u64 = offset + size;
+
+            buf.put_u32_le(ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+            // size of the rest of the record
+            buf.put_u64_le(44);
+            buf.put_u16_le(VERSION_ZIP64);
+            buf.put_u16_le(VERSION_ZIP64);
+            buf.put_u32_le(0);
+            buf.put_u32_le(0);
+            buf.put_u64_le(count);
+            buf.put_u64_le(count);
+            buf.put_u64_le(size);
+            buf.put_u64_le(offset);
+
+            buf.put_u32_le(ZIP64_END_OF_CENTRAL_DIRECTORY_LOCATOR_SIGNATURE);
+            buf.put_u32_le(0);
+            buf.put_u64_le(zip64_offset);
+            buf.put_u32_le(1);
+        }
+
+        buf.put_u32_le(END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        buf.put_u16_le(0);
+        buf.put_u16_le(0);
+        buf.put_u16_le(count.min(u16::MAX as u64) as u16);
+        buf.put_u16_le(count.min(u16::MAX as u64) as u16);
+        buf.put_u32_le(size.min(ZIP64_MARKER as u64) as u32);
+        buf.put_u32_le(offset.min(ZIP64_MARKER as u64) as u32);
+        buf.put_u16_le(0);
+
+        Ok(buf.freeze())
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "sse")]
+use futures_util::StreamExt as _;
+use futures_util::{Stream, stream};
+use tokio::sync::broadcast::{self, Receiver, Sender, error::RecvError};
+
+use crate::response::json::JsonResponse;
+#[cfg(feature = "sse")]
+use crate::response::sse::SseEvent;
+
+/// Default capacity of the events buffered for each subscriber.
+pub const BROADCAST_CAPACITY_DEFAULT: usize = 64;
+
+/// Filter of the events for a subscriber.
+type BroadcastFilter<D> = Arc<dyn Fn(&JsonResponse<D>) -> bool + Send + Sync>;
+
+/// Hub for publishing JSON response events to many subscribers,
+/// such as server-sent events or WebSocket connections,
+/// available with `broadcast` feature.
+///
+/// The hub is shared between clones.
+///
+/// A subscriber that falls behind by more than the capacity
+/// will skip the oldest events.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, extract::State, routing::{get, post}};
+/// use jder_axum::response::{
+///     Response,
+///     broadcast::BroadcastHub,
+///     json::{CreateJsonResponse, JsonResponse},
+///     sse::CreateSseResponse,
+/// };
+///
+/// async fn publish(State(hub): State<BroadcastHub<String>>) -> Response {
+///     hub.publish(JsonResponse::new().data("Hello".to_string()));
+///
+///     CreateJsonResponse::dataless().create()
+/// }
+///
+/// async fn subscribe(State(hub): State<BroadcastHub<String>>) -> Response {
+///     CreateSseResponse::stream(hub.subscribe().into_sse()).create()
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/publish", post(publish))
+///     .route("/subscribe", get(subscribe))
+///     .with_state(BroadcastHub::<String>::new());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BroadcastHub<D> {
+    sender: Sender<JsonResponse<D>>,
+}
+
+impl<D: Clone + Send + 'static> BroadcastHub<D> {
+    /// Create a new hub with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(BROADCAST_CAPACITY_DEFAULT)
+    }
+
+    /// Create a new hub with the capacity
+    /// of the events buffered for each subscriber.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::broadcast::BroadcastHub;
+    ///
+    /// let hub: BroadcastHub<String> = BroadcastHub::with_capacity(256);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+
+        Self { sender }
+    }
+
+    /// Publish an event to all subscribers,
+    /// returns the number of the subscribers.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     broadcast::BroadcastHub,
+    ///     json::JsonResponse,
+    /// };
+    ///
+    /// let hub: BroadcastHub<String> = BroadcastHub::new();
+    ///
+    /// hub.publish(JsonResponse::new().data("Hello".to_string()));
+    /// ```
+    pub fn publish(
+        &self,
+        event: JsonResponse<D>,
+    ) -> usize {
+        self.sender.send(event).unwrap_or(0)
+    }
+
+    /// Publish a success event with data to all subscribers,
+    /// returns the number of the subscribers.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::broadcast::BroadcastHub;
+    ///
+    /// let hub: BroadcastHub<String> = BroadcastHub::new();
+    ///
+    /// hub.publish_data("Hello".to_string());
+    /// ```
+    pub fn publish_data(
+        &self,
+        data: D,
+    ) -> usize {
+        self.publish(JsonResponse::new().data(data))
+    }
+
+    /// Get the number of the subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    /// Subscribe to all events published after the subscription.
+    pub fn subscribe(&self) -> BroadcastSubscription<D> {
+        BroadcastSubscription::new(self.sender.subscribe(), None)
+    }
+
+    /// Subscribe to the events matching a filter,
+    /// such as events for a specific user of the connection.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::broadcast::{
+    ///     BroadcastHub,
+    ///     BroadcastSubscription,
+    /// };
+    ///
+    /// let hub: BroadcastHub<String> = BroadcastHub::new();
+    ///
+    /// let subscription: BroadcastSubscription<String> =
+    ///     hub.subscribe_with(|event| event.success);
+    /// ```
+    pub fn subscribe_with<F>(
+        &self,
+        filter: F,
+    ) -> BroadcastSubscription<D>
+    where
+        F: Fn(&JsonResponse<D>) -> bool + Send + Sync + 'static,
+    {
+        BroadcastSubscription::new(
+            self.sender.subscribe(),
+            Some(Arc::new(filter)),
+        )
+    }
+}
+
+impl<D: Clone + Send + 'static> Default for BroadcastHub<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Subscription to a [`BroadcastHub`],
+/// as a stream of JSON response events.
+///
+/// The stream ends when all hubs are dropped.
+pub struct BroadcastSubscription<D> {
+    stream: Pin<Box<dyn Stream<Item = JsonResponse<D>> + Send>>,
+}
+
+impl<D: Clone + Send + 'static> BroadcastSubscription<D> {
+    fn new(
+        receiver: Receiver<JsonResponse<D>>,
+        filter: Option<BroadcastFilter<D>>,
+    ) -> Self {
+        let stream = stream::unfold(receiver, move |mut receiver| {
+            let filter: Option<BroadcastFilter<D>> = filter.clone();
+
+            async move {
+                loop {
+                    match receiver.recv().await {
+                        | Ok(event) => {
+                            if filter.as_ref().is_none_or(|f| f(&event)) {
+                                return Some((event, receiver));
+                            }
+                        },
+                        // skip the oldest events
+                        | Err(RecvError::Lagged(_)) => continue,
+                        | Err(RecvError::Closed) => return None,
+                    }
+                }
+            }
+        });
+
+        Self { stream: Box::pin(stream) }
+    }
+
+    /// Convert the subscription into a stream of server-sent events,
+    /// available with `sse` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::extract::State;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     broadcast::BroadcastHub,
+    ///     sse::CreateSseResponse,
+    /// };
+    ///
+    /// async fn route(State(hub): State<BroadcastHub<String>>) -> Response {
+    ///     CreateSseResponse::stream(hub.subscribe().into_sse()).create()
+    /// }
+    /// ```
+    #[cfg(feature = "sse")]
+    pub fn into_sse(self) -> impl Stream<Item = SseEvent<D>> + Send + 'static {
+        self.map(SseEvent::new)
+    }
+}
+
+impl<D> Stream for BroadcastSubscription<D> {
+    type Item = JsonResponse<D>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
@@ -0,0 +1,196 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+/// Directives of a `Cache-Control` response header.
+///
+/// Set it with the `cache_control` function of the JSON response builders,
+/// or set the directives one by one with the functions of the builders,
+/// such as `cache_max_age` and `no_store`.
+///
+/// Durations are sent in whole seconds,
+/// and `public` and `private` replace each other.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use jder_axum::response::{
+///     Response,
+///     cache_control::CacheControl,
+///     json::CreateJsonResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     // public, max-age=60, stale-while-revalidate=30
+///     CreateJsonResponse::dataless()
+///         .cache_control(
+///             CacheControl::new()
+///                 .public()
+///                 .max_age(Duration::from_secs(60))
+///                 .stale_while_revalidate(Duration::from_secs(30)),
+///         )
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheControl {
+    public: bool,
+    private: bool,
+    no_cache: bool,
+    no_store: bool,
+    no_transform: bool,
+    must_revalidate: bool,
+    immutable: bool,
+    max_age: Option<u64>,
+    s_maxage: Option<u64>,
+    stale_while_revalidate: Option<u64>,
+    stale_if_error: Option<u64>,
+}
+
+impl CacheControl {
+    /// Create a new `Cache-Control` header without directives.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether no directive is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Set the `public` directive, removing `private`.
+    pub fn public(mut self) -> Self {
+        self.public = true;
+        self.private = false;
+
+        self
+    }
+
+    /// Set the `private` directive, removing `public`.
+    pub fn private(mut self) -> Self {
+        self.private = true;
+        self.public = false;
+
+        self
+    }
+
+    /// Set the `no-cache` directive.
+    pub fn no_cache(mut self) -> Self {
+        self.no_cache = true;
+
+        self
+    }
+
+    /// Set the `no-store` directive.
+    pub fn no_store(mut self) -> Self {
+        self.no_store = true;
+
+        self
+    }
+
+    /// Set the `no-transform` directive.
+    pub fn no_transform(mut self) -> Self {
+        self.no_transform = true;
+
+        self
+    }
+
+    /// Set the `must-revalidate` directive.
+    pub fn must_revalidate(mut self) -> Self {
+        self.must_revalidate = true;
+
+        self
+    }
+
+    /// Set the `immutable` directive.
+    pub fn immutable(mut self) -> Self {
+        self.immutable = true;
+
+        self
+    }
+
+    /// Set the `max-age` directive.
+    pub fn max_age(
+        mut self,
+        max_age: Duration,
+    ) -> Self {
+        self.max_age = Some(max_age.as_secs());
+
+        self
+    }
+
+    /// Set the `s-maxage` directive for shared caches.
+    pub fn s_maxage(
+        mut self,
+        s_maxage: Duration,
+    ) -> Self {
+        self.s_maxage = Some(s_maxage.as_secs());
+
+        self
+    }
+
+    /// Set the `stale-while-revalidate` directive.
+    pub fn stale_while_revalidate(
+        mut self,
+        duration: Duration,
+    ) -> Self {
+        self.stale_while_revalidate = Some(duration.as_secs());
+
+        self
+    }
+
+    /// Set the `stale-if-error` directive.
+    pub fn stale_if_error(
+        mut self,
+        duration: Duration,
+    ) -> Self {
+        self.stale_if_error = Some(duration.as_secs());
+
+        self
+    }
+}
+
+impl Display for CacheControl {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        let flags: [(bool, &str); 7] = [
+            (self.public, "public"),
+            (self.private, "private"),
+            (self.no_cache, "no-cache"),
+            (self.no_store, "no-store"),
+            (self.no_transform, "no-transform"),
+            (self.must_revalidate, "must-revalidate"),
+            (self.immutable, "immutable"),
+        ];
+
+        let durations: [(Option<u64>, &str); 4] = [
+            (self.max_age, "max-age"),
+            (self.s_maxage, "s-maxage"),
+            (self.stale_while_revalidate, "stale-while-revalidate"),
+            (self.stale_if_error, "stale-if-error"),
+        ];
+
+        let directives = flags
+            .into_iter()
+            .filter(|(is_set, _)| *is_set)
+            .map(|(_, name)| name.to_string())
+            .chain(durations.into_iter().filter_map(|(seconds, name)| {
+                seconds.map(|seconds| format!("{name}={seconds}"))
+            }));
+
+        for (index, directive) in directives.enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{directive}")?;
+        }
+
+        Ok(())
+    }
+}
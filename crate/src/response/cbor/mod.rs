@@ -0,0 +1,194 @@
+use http::StatusCode;
+
+use crate::response::json::{
+    CreateFailureJsonResponse, CreateJsonResponse, CreateSuccessJsonResponse,
+    HasData, create::ResponseEncoding,
+};
+
+/// CBOR content type.
+pub const CONTENT_TYPE_CBOR: &str = "application/cbor";
+
+/// Create a CBOR response for a route.
+///
+/// The response has the same structure as a JSON response,
+/// but the body is encoded as CBOR (RFC 8949)
+/// with `application/cbor` content type.
+///
+/// The builders are shared with
+/// [`CreateJsonResponse`](crate::response::json::CreateJsonResponse),
+/// except that problem details are always encoded as JSON.
+///
+/// ## Examples
+///
+/// A success CBOR response without data:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     cbor::CreateCborResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateCborResponse::dataless().create()
+/// }
+/// ```
+///
+/// A success CBOR response:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     cbor::CreateCborResponse,
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Default, Serialize)]
+/// struct ResponseData {
+///    name: String,
+/// }
+///
+/// async fn route() -> Response {
+///     CreateCborResponse::success::<ResponseData>()
+///         .data(ResponseData { name: "Name".to_string() })
+///         .create()
+/// }
+/// ```
+///
+/// A failure CBOR response:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     cbor::CreateCborResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateCborResponse::failure().create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateCborResponse;
+
+impl CreateCborResponse {
+    /// Create a success CBOR response without data.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cbor::CreateCborResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCborResponse::dataless().create()
+    /// }
+    /// ```
+    pub fn dataless() -> CreateSuccessJsonResponse<(), HasData> {
+        let mut res: CreateSuccessJsonResponse<(), HasData> =
+            CreateJsonResponse::dataless();
+
+        res.state.encoding = ResponseEncoding::Cbor;
+
+        res
+    }
+
+    /// Create a success CBOR response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cbor::CreateCborResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCborResponse::success::<String>()
+    ///         .data("Name".to_string())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn success<D>() -> CreateSuccessJsonResponse<D> {
+        let mut res: CreateSuccessJsonResponse<D> =
+            CreateJsonResponse::success();
+
+        res.state.encoding = ResponseEncoding::Cbor;
+
+        res
+    }
+
+    /// Create a success CBOR response with a status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cbor::CreateCborResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCborResponse::success_with::<String>(StatusCode::CREATED)
+    ///         .data("Name".to_string())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn success_with<D>(status: StatusCode) -> CreateSuccessJsonResponse<D> {
+        let mut res: CreateSuccessJsonResponse<D> =
+            CreateJsonResponse::success_with(status);
+
+        res.state.encoding = ResponseEncoding::Cbor;
+
+        res
+    }
+
+    /// Create a failure CBOR response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cbor::CreateCborResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCborResponse::failure().create()
+    /// }
+    /// ```
+    pub fn failure() -> CreateFailureJsonResponse<()> {
+        let mut res: CreateFailureJsonResponse<()> =
+            CreateJsonResponse::failure();
+
+        res.state.encoding = ResponseEncoding::Cbor;
+
+        res
+    }
+
+    /// Create a failure CBOR response with a status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cbor::CreateCborResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCborResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn failure_with(status: StatusCode) -> CreateFailureJsonResponse<()> {
+        let mut res: CreateFailureJsonResponse<()> =
+            CreateJsonResponse::failure_with(status);
+
+        res.state.encoding = ResponseEncoding::Cbor;
+
+        res
+    }
+}
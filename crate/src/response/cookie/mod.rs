@@ -0,0 +1,271 @@
+use std::fmt::{self, Display, Formatter};
+
+/// `SameSite` attribute of a cookie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameSite {
+    /// Sent with same-site requests only.
+    Strict,
+    /// Sent with same-site requests and top-level navigations.
+    Lax,
+    /// Sent with all requests, requires `Secure`.
+    None,
+}
+
+impl SameSite {
+    /// Get the attribute value as `&str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            | Self::Strict => "Strict",
+            | Self::Lax => "Lax",
+            | Self::None => "None",
+        }
+    }
+}
+
+/// Cookie set by a response with a `Set-Cookie` header.
+///
+/// Set it with the `cookie` function of the JSON response builders,
+/// and an invalid cookie, such as a name with spaces,
+/// fails the response like an invalid header.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     cookie::{ResponseCookie, SameSite},
+///     json::CreateJsonResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateJsonResponse::dataless()
+///         .cookie(
+///             ResponseCookie::new("session", "abc")
+///                 .path("/")
+///                 .max_age(3600)
+///                 .secure(true)
+///                 .http_only(true)
+///                 .same_site(SameSite::Lax),
+///         )
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseCookie {
+    name: String,
+    value: String,
+    max_age: Option<i64>,
+    domain: Option<String>,
+    path: Option<String>,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SameSite>,
+}
+
+impl ResponseCookie {
+    /// Create a new cookie with the name and value.
+    pub fn new<N, V>(
+        name: N,
+        value: V,
+    ) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            max_age: None,
+            domain: None,
+            path: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        }
+    }
+
+    /// Create a cookie that removes the cookie of the name
+    /// at the root path.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::cookie::ResponseCookie;
+    ///
+    /// // session=; Max-Age=0; Path=/
+    /// let cookie: ResponseCookie = ResponseCookie::removal("session");
+    /// ```
+    pub fn removal<N: Into<String>>(name: N) -> Self {
+        Self::new(name, "").max_age(0).path("/")
+    }
+
+    /// Get the name of the cookie.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the value of the cookie.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Set the `Max-Age` attribute in seconds.
+    pub fn max_age(
+        mut self,
+        seconds: i64,
+    ) -> Self {
+        self.max_age = Some(seconds);
+
+        self
+    }
+
+    /// Set the `Domain` attribute.
+    pub fn domain<D: Into<String>>(
+        mut self,
+        domain: D,
+    ) -> Self {
+        self.domain = Some(domain.into());
+
+        self
+    }
+
+    /// Set the `Path` attribute.
+    pub fn path<P: Into<String>>(
+        mut self,
+        path: P,
+    ) -> Self {
+        self.path = Some(path.into());
+
+        self
+    }
+
+    /// Set the `Secure` attribute.
+    pub fn secure(
+        mut self,
+        secure: bool,
+    ) -> Self {
+        self.secure = secure;
+
+        self
+    }
+
+    /// Set the `HttpOnly` attribute.
+    pub fn http_only(
+        mut self,
+        http_only: bool,
+    ) -> Self {
+        self.http_only = http_only;
+
+        self
+    }
+
+    /// Set the `SameSite` attribute.
+    pub fn same_site(
+        mut self,
+        same_site: SameSite,
+    ) -> Self {
+        self.same_site = Some(same_site);
+
+        self
+    }
+
+    /// Check if the cookie can be sent as a `Set-Cookie` header.
+    ///
+    /// The name must be a token,
+    /// the value must not contain spaces, quotes, commas,
+    /// semicolons or backslashes,
+    /// and the domain and path must not contain semicolons
+    /// or control characters.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::cookie::ResponseCookie;
+    ///
+    /// // true
+    /// ResponseCookie::new("session", "abc").is_valid();
+    /// // false
+    /// ResponseCookie::new("my session", "abc").is_valid();
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        is_token_fn(&self.name)
+            && self.value.bytes().all(is_cookie_octet_fn)
+            && self.domain.as_deref().is_none_or(is_attribute_value_fn)
+            && self.path.as_deref().is_none_or(is_attribute_value_fn)
+    }
+}
+
+impl Display for ResponseCookie {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)?;
+
+        if let Some(max_age) = self.max_age {
+            write!(f, "; Max-Age={max_age}")?;
+        }
+
+        if let Some(domain) = &self.domain {
+            write!(f, "; Domain={domain}")?;
+        }
+
+        if let Some(path) = &self.path {
+            write!(f, "; Path={path}")?;
+        }
+
+        if self.secure {
+            write!(f, "; Secure")?;
+        }
+
+        if self.http_only {
+            write!(f, "; HttpOnly")?;
+        }
+
+        if let Some(same_site) = &self.same_site {
+            write!(f, "; SameSite={}", same_site.as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "extra_cookie")]
+impl From<axum_extra::extract::cookie::Cookie<'_>> for ResponseCookie {
+    fn from(cookie: axum_extra::extract::cookie::Cookie<'_>) -> Self {
+        use axum_extra::extract::cookie::SameSite as _SameSite;
+
+        Self {
+            name: cookie.name().to_string(),
+            value: cookie.value().to_string(),
+            max_age: cookie.max_age().map(|max_age| max_age.whole_seconds()),
+            domain: cookie.domain().map(ToString::to_string),
+            path: cookie.path().map(ToString::to_string),
+            secure: cookie.secure().unwrap_or_default(),
+            http_only: cookie.http_only().unwrap_or_default(),
+            same_site: cookie.same_site().map(|same_site| match same_site {
+                | _SameSite::Strict => SameSite::Strict,
+                | _SameSite::Lax => SameSite::Lax,
+                | _SameSite::None => SameSite::None,
+            }),
+        }
+    }
+}
+
+/// Check if a string is a token of RFC 7230.
+fn is_token_fn(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes().all(|b| {
+            b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b)
+        })
+}
+
+/// Check if a byte is a cookie octet of RFC 6265.
+fn is_cookie_octet_fn(b: u8) -> bool {
+    matches!(b, 0x21 | 0x23..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E)
+}
+
+/// Check if a string is a valid attribute value of RFC 6265.
+fn is_attribute_value_fn(s: &str) -> bool {
+    s.bytes().all(|b| b != b';' && !b.is_ascii_control())
+}
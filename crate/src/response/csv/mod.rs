@@ -0,0 +1,392 @@
+use std::{cell::RefCell, io};
+
+use axum_core::{BoxError, body::Body};
+use bytes::{Bytes, BytesMut};
+use csv::{Terminator, Writer, WriterBuilder};
+use futures_util::{StreamExt, stream};
+use http::{
+    Error as HTTPError, HeaderName, HeaderValue, StatusCode, Version, header,
+    response::Builder,
+};
+use serde::Serialize;
+
+use crate::response::{
+    Response,
+    json::{
+        CreateJsonResponse, JsonResponseError, ResponseError,
+        create::{JsonResponseState, base::create_json_response_fn},
+    },
+};
+
+/// CSV content type.
+const CONTENT_TYPE_CSV: &str = "text/csv; charset=utf-8";
+
+/// Reusable buffer of the written rows,
+/// taken through the shared reference of the writer.
+#[derive(Default)]
+struct RowBuffer(RefCell<BytesMut>);
+
+impl io::Write for RowBuffer {
+    fn write(
+        &mut self,
+        buf: &[u8],
+    ) -> io::Result<usize> {
+        self.0.get_mut().extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writer of the rows.
+type CsvWriter = Writer<RowBuffer>;
+
+/// Create a writer of rows ended by CRLF.
+fn create_writer_fn() -> CsvWriter {
+    WriterBuilder::new()
+        .terminator(Terminator::CRLF)
+        .from_writer(RowBuffer::default())
+}
+
+/// Serialize a record into the writer,
+/// returns the written rows.
+fn write_record_fn<T: Serialize>(
+    writer: &mut CsvWriter,
+    record: &T,
+) -> Result<Bytes, BoxError> {
+    writer.serialize(record)?;
+    writer.flush()?;
+
+    Ok(writer.get_ref().0.borrow_mut().split().freeze())
+}
+
+/// Check if a file name can be quoted in `Content-Disposition`.
+fn is_file_name_valid_fn(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| (0x20..0x7F).contains(&b) && b != b'"' && b != b'\\')
+}
+
+/// Create a failure response of a record failing to serialize,
+/// with `csv` in path.
+fn create_csv_failure_fn() -> Response {
+    CreateJsonResponse::failure()
+        .status(StatusCode::INTERNAL_SERVER_ERROR)
+        .add_error(
+            JsonResponseError::from(ResponseError::Server)
+                .path(["csv"])
+                .message("Failed to serialize record"),
+        )
+        .create()
+}
+
+/// Functions for creating a CSV response.
+pub struct CsvResponseFunctions<I> {
+    /// Internal state.
+    state: JsonResponseState<()>,
+    /// Records of the rows.
+    records: I,
+    /// File name of the download.
+    file_name: Option<String>,
+}
+
+impl<I, T> CsvResponseFunctions<I>
+where
+    I: Iterator<Item = T> + Send + 'static,
+    T: Serialize,
+{
+    /// Set the status code for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{Response, csv::CreateCsvResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCsvResponse::iter(vec![(1, "a"), (2, "b")])
+    ///         .status(StatusCode::PARTIAL_CONTENT)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn status<C: Into<StatusCode>>(
+        mut self,
+        status: C,
+    ) -> Self {
+        self.state.status = status.into();
+
+        self
+    }
+
+    /// Set the HTTP version for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Version;
+    /// use jder_axum::response::{Response, csv::CreateCsvResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCsvResponse::iter(vec![(1, "a"), (2, "b")])
+    ///         .version(Version::HTTP_2)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn version<V: Into<Version>>(
+        mut self,
+        version: V,
+    ) -> Self {
+        self.state.version = version.into();
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use jder_axum::response::{Response, csv::CreateCsvResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCsvResponse::iter(vec![(1, "a"), (2, "b")])
+    ///         .header(header::CACHE_CONTROL, "no-store")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        let key: HeaderName = match <HeaderName as TryFrom<K>>::try_from(key) {
+            | Ok(k) => k,
+            | Err(_) => {
+                self.state.is_header_map_failed = true;
+                return self;
+            },
+        };
+
+        let value: HeaderValue =
+            match <HeaderValue as TryFrom<V>>::try_from(value) {
+                | Ok(v) => v,
+                | Err(_) => {
+                    self.state.is_header_map_failed = true;
+                    return self;
+                },
+            };
+
+        self.state.header_map.try_append(key, value).unwrap();
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        for (key, value) in headers {
+            self = self.header(key, value);
+        }
+
+        self
+    }
+
+    /// Set the file name of the download
+    /// with a `Content-Disposition: attachment` header.
+    ///
+    /// The name must be printable ASCII without `"` and `\`,
+    /// or the response fails like an invalid header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, csv::CreateCsvResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCsvResponse::iter(vec![(1, "a"), (2, "b")])
+    ///         .file_name("report.csv")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn file_name<N: Into<String>>(
+        mut self,
+        name: N,
+    ) -> Self {
+        self.file_name = Some(name.into());
+
+        self
+    }
+
+    /// Finish the response creation.
+    ///
+    /// The first record is serialized before the response is sent,
+    /// with its field names as the header row if it is a struct,
+    /// and a failure response is created if it fails to serialize.
+    /// The other records are serialized into rows
+    /// while the body is being sent,
+    /// and the body will be aborted if a record fails to serialize
+    /// or has a different number of fields than the first record.
+    ///
+    /// No records make an empty body.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, csv::CreateCsvResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCsvResponse::iter(vec![(1, "a"), (2, "b")]).create()
+    /// }
+    /// ```
+    pub fn create(mut self) -> Response {
+        if let Some(name) = self.file_name.take() {
+            match is_file_name_valid_fn(&name) {
+                | true => {
+                    self = self.header(
+                        header::CONTENT_DISPOSITION,
+                        format!("attachment; filename=\"{}\"", name),
+                    );
+                },
+                | false => self.state.is_header_map_failed = true,
+            }
+        }
+
+        // header map error
+        if self.state.is_header_map_failed {
+            return create_json_response_fn(self.state);
+        }
+
+        let mut writer: CsvWriter = create_writer_fn();
+
+        let head: Bytes = match self.records.next() {
+            | Some(record) => match write_record_fn(&mut writer, &record) {
+                | Ok(head) => head,
+                | Err(_) => return create_csv_failure_fn(),
+            },
+            | None => Bytes::new(),
+        };
+
+        let rows = stream::iter(self.records)
+            .map(move |record| write_record_fn(&mut writer, &record));
+
+        let chunks = stream::once(async move { Ok::<Bytes, BoxError>(head) })
+            .chain(rows);
+
+        // create response builder
+        let mut builder: Builder = Response::builder()
+            .status(self.state.status)
+            .version(self.state.version)
+            .header(header::CONTENT_TYPE, CONTENT_TYPE_CSV);
+
+        // push headers, where repeated values come without the name
+        let mut name: Option<HeaderName> = None;
+
+        for (header, value) in self.state.header_map {
+            if header.is_some() {
+                name = header;
+            }
+
+            if let Some(name) = &name {
+                builder = builder.header(name, value);
+            }
+        }
+
+        match builder.body(Body::from_stream(chunks)) {
+            | Ok(res) => res,
+            | Err(_) => {
+                let mut state: JsonResponseState<()> =
+                    JsonResponseState::success();
+
+                state.is_header_map_failed = true;
+
+                create_json_response_fn(state)
+            },
+        }
+    }
+}
+
+/// Create a CSV response for a route,
+/// available with `csv` feature.
+///
+/// Each record is serialized into a row by the `csv` crate,
+/// where the fields of a struct are the columns
+/// with the names of the first record as the header row,
+/// and `None` is an empty field.
+/// Maps and nested sequences or structs are not supported.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::{Response, csv::CreateCsvResponse};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Row {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// async fn route() -> Response {
+///     let rows: Vec<Row> = vec![
+///         Row { id: 1, name: "Alice".to_string() },
+///         Row { id: 2, name: "Bob".to_string() },
+///     ];
+///
+///     // id,name
+///     // 1,Alice
+///     // 2,Bob
+///     CreateCsvResponse::iter(rows).file_name("users.csv").create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateCsvResponse;
+
+impl CreateCsvResponse {
+    /// Create a CSV response from an iterator of records.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, csv::CreateCsvResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateCsvResponse::iter(vec![(1, "a"), (2, "b")]).create()
+    /// }
+    /// ```
+    pub fn iter<I>(records: I) -> CsvResponseFunctions<I::IntoIter>
+    where
+        I: IntoIterator,
+        I::IntoIter: Send + 'static,
+    {
+        CsvResponseFunctions {
+            state: JsonResponseState::success(),
+            records: records.into_iter(),
+            file_name: None,
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    hash::Hash,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::OnceCell;
+
+/// Cached data with the time it was computed.
+type CacheCell<D> = Arc<OnceCell<(Arc<D>, Instant)>>;
+
+/// Provider of expensive response data,
+/// cached for a time-to-live by key,
+/// available with `data_cache` feature.
+///
+/// Concurrent requests for the same key share a single computation,
+/// and the following requests reuse the cached data until it expires.
+/// Failed computations are not cached.
+///
+/// The data is shared with an [`Arc`],
+/// which can be set with the `data_arc` function of the success builder.
+///
+/// The cache is shared between clones.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::{sync::Arc, time::Duration};
+///
+/// use axum::{Router, extract::State, routing::get};
+/// use jder_axum::response::{
+///     Response,
+///     data_cache::CachedDataProvider,
+///     json::CreateJsonResponse,
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Stats {
+///     users: u64,
+/// }
+///
+/// async fn count_users() -> u64 {
+///     42
+/// }
+///
+/// async fn route(
+///     State(provider): State<CachedDataProvider<Stats>>,
+/// ) -> Response {
+///     let stats: Arc<Stats> = provider
+///         .get("stats".to_string(), || async {
+///             Stats { users: count_users().await }
+///         })
+///         .await;
+///
+///     CreateJsonResponse::success::<Stats>().data_arc(stats).create()
+/// }
+///
+/// let router: Router = Router::new()
+///     .route("/stats", get(route))
+///     .with_state(CachedDataProvider::<Stats>::new(Duration::from_secs(30)));
+/// ```
+pub struct CachedDataProvider<D, K = String> {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<K, CacheCell<D>>>>,
+}
+
+impl<D, K> CachedDataProvider<D, K>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Create a new provider with the time-to-live of the cached data.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::data_cache::CachedDataProvider;
+    ///
+    /// let provider: CachedDataProvider<Vec<String>> =
+    ///     CachedDataProvider::new(Duration::from_secs(60));
+    /// ```
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl, entries: Arc::new(Mutex::new(HashMap::new())) }
+    }
+
+    /// Get the time-to-live of the cached data.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Get the cell of the key,
+    /// replacing the cell of expired data.
+    fn cell_fn(
+        &self,
+        key: K,
+    ) -> CacheCell<D> {
+        let mut entries: MutexGuard<'_, HashMap<K, CacheCell<D>>> =
+            self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(cell) = entries.get(&key) {
+            if !self.is_expired(cell) {
+                return cell.clone();
+            }
+        }
+
+        entries.retain(|_, cell| !self.is_expired(cell));
+
+        let cell: CacheCell<D> = Arc::new(OnceCell::new());
+
+        entries.insert(key, cell.clone());
+
+        cell
+    }
+
+    /// Check whether the data of the cell is expired,
+    /// where a computation in flight is never expired.
+    fn is_expired(
+        &self,
+        cell: &CacheCell<D>,
+    ) -> bool {
+        cell.get().is_some_and(|(_, at)| at.elapsed() >= self.ttl)
+    }
+
+    /// Get the cached data of the key,
+    /// or compute it with the function.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use jder_axum::response::data_cache::CachedDataProvider;
+    ///
+    /// async fn names(provider: CachedDataProvider<Vec<String>>) {
+    ///     let names: Arc<Vec<String>> = provider
+    ///         .get("names".to_string(), || async { vec!["Name".to_string()] })
+    ///         .await;
+    /// }
+    /// ```
+    pub async fn get<F, Fut>(
+        &self,
+        key: K,
+        compute: F,
+    ) -> Arc<D>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = D>,
+    {
+        let cell: CacheCell<D> = self.cell_fn(key);
+
+        let (data, _) = cell
+            .get_or_init(|| async {
+                (Arc::new(compute().await), Instant::now())
+            })
+            .await;
+
+        data.clone()
+    }
+
+    /// Get the cached data of the key,
+    /// or compute it with the fallible function.
+    ///
+    /// Errors are returned to the caller without being cached,
+    /// and a waiting request computes the data again.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use jder_axum::response::{
+    ///     data_cache::CachedDataProvider,
+    ///     json::JsonResponseError,
+    /// };
+    ///
+    /// async fn names(
+    ///     provider: CachedDataProvider<Vec<String>>,
+    /// ) -> Result<Arc<Vec<String>>, JsonResponseError> {
+    ///     provider
+    ///         .try_get("names".to_string(), || async {
+    ///             Ok(vec!["Name".to_string()])
+    ///         })
+    ///         .await
+    /// }
+    /// ```
+    pub async fn try_get<F, Fut, E>(
+        &self,
+        key: K,
+        compute: F,
+    ) -> Result<Arc<D>, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<D, E>>,
+    {
+        let cell: CacheCell<D> = self.cell_fn(key);
+
+        let (data, _) = cell
+            .get_or_try_init(|| async {
+                compute().await.map(|data| (Arc::new(data), Instant::now()))
+            })
+            .await?;
+
+        Ok(data.clone())
+    }
+
+    /// Remove the cached data of the key,
+    /// so that the next request computes it again.
+    pub fn invalidate(
+        &self,
+        key: &K,
+    ) {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner).remove(key);
+    }
+
+    /// Remove all cached data.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+}
+
+impl<D, K> Clone for CachedDataProvider<D, K> {
+    fn clone(&self) -> Self {
+        Self { ttl: self.ttl, entries: self.entries.clone() }
+    }
+}
+
+impl<D, K> fmt::Debug for CachedDataProvider<D, K> {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("CachedDataProvider").field("ttl", &self.ttl).finish()
+    }
+}
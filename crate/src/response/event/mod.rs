@@ -0,0 +1,13 @@
+pub mod sender;
+pub mod webhook;
+
+pub use crate::response::event::webhook::{
+    SignedWebhookEvent, WEBHOOK_SIGNATURE_HEADER,
+    WEBHOOK_SIGNATURE_TOLERANCE_DEFAULT, WebhookEvent, verify_signature,
+};
+
+pub use crate::response::event::sender::{
+    WEBHOOK_BACKOFF_DEFAULT, WEBHOOK_BACKOFF_MAX_DEFAULT,
+    WEBHOOK_DELIVERY_ERROR_CODE, WEBHOOK_RETRIES_DEFAULT, WebhookDelivery,
+    WebhookSender, WebhookTransport,
+};
@@ -0,0 +1,309 @@
+use std::{future::Future, time::Duration};
+
+use http::{HeaderMap, HeaderValue, StatusCode, header};
+use serde::Serialize;
+
+use crate::response::{
+    event::webhook::{
+        SignedWebhookEvent, WEBHOOK_SIGNATURE_HEADER, WebhookEvent,
+    },
+    json::JsonResponseError,
+};
+
+/// Error code for failed webhook deliveries.
+pub const WEBHOOK_DELIVERY_ERROR_CODE: &str = "delivery";
+
+/// Default maximum number of retries.
+pub const WEBHOOK_RETRIES_DEFAULT: usize = 3;
+
+/// Default initial backoff in milliseconds.
+pub const WEBHOOK_BACKOFF_DEFAULT: u64 = 500;
+
+/// Default maximum backoff in milliseconds.
+pub const WEBHOOK_BACKOFF_MAX_DEFAULT: u64 = 30_000;
+
+/// Transport for sending webhook events.
+///
+/// Implemented for `reqwest::Client` with `reqwest` feature,
+/// or implement this trait with any HTTP client, such as `hyper`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::future::Future;
+///
+/// use axum::http::{HeaderMap, StatusCode};
+/// use jder_axum::response::event::WebhookTransport;
+///
+/// struct Transport;
+///
+/// impl WebhookTransport for Transport {
+///     fn post(
+///         &self,
+///         url: &str,
+///         headers: HeaderMap,
+///         body: String,
+///     ) -> impl Future<Output = Result<StatusCode, String>> + Send {
+///         async move {
+///             // send the request with an HTTP client
+///             Ok(StatusCode::OK)
+///         }
+///     }
+/// }
+/// ```
+pub trait WebhookTransport: Send + Sync {
+    /// Send a `POST` request,
+    /// returns the status code of the response,
+    /// or an error message if the request cannot be sent.
+    fn post(
+        &self,
+        url: &str,
+        headers: HeaderMap,
+        body: String,
+    ) -> impl Future<Output = Result<StatusCode, String>> + Send;
+}
+
+/// Transport with a `reqwest` client,
+/// available with `reqwest` feature.
+///
+/// TLS is not enabled by this crate,
+/// enable a TLS feature of `reqwest`, such as `rustls-tls`,
+/// for `https` endpoints.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::event::WebhookSender;
+///
+/// let sender: WebhookSender<reqwest::Client> =
+///     WebhookSender::new(reqwest::Client::new(), "secret");
+/// ```
+#[cfg(feature = "reqwest")]
+impl WebhookTransport for reqwest::Client {
+    fn post(
+        &self,
+        url: &str,
+        headers: HeaderMap,
+        body: String,
+    ) -> impl Future<Output = Result<StatusCode, String>> + Send {
+        let request: reqwest::RequestBuilder =
+            reqwest::Client::post(self, url).headers(headers).body(body);
+
+        async move {
+            request
+                .send()
+                .await
+                .map(|res| res.status())
+                .map_err(|err| err.to_string())
+        }
+    }
+}
+
+/// Result of a successful webhook delivery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookDelivery {
+    /// Status code of the response.
+    pub status: StatusCode,
+    /// Number of attempts made.
+    pub attempts: usize,
+}
+
+/// Sender for webhook events,
+/// with retries and exponential backoff.
+///
+/// Errors for each failed attempt are reported as [`JsonResponseError`]
+/// with [`WEBHOOK_DELIVERY_ERROR_CODE`].
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::{future::Future, time::Duration};
+///
+/// use axum::http::{HeaderMap, StatusCode};
+/// use jder_axum::response::event::{
+///     WebhookEvent,
+///     WebhookSender,
+///     WebhookTransport,
+/// };
+///
+/// struct Transport;
+///
+/// impl WebhookTransport for Transport {
+///     fn post(
+///         &self,
+///         url: &str,
+///         headers: HeaderMap,
+///         body: String,
+///     ) -> impl Future<Output = Result<StatusCode, String>> + Send {
+///         async move { Ok(StatusCode::OK) }
+///     }
+/// }
+///
+/// async fn send() {
+///     let sender: WebhookSender<Transport> =
+///         WebhookSender::new(Transport, "secret")
+///             .max_retries(5)
+///             .backoff(Duration::from_secs(1));
+///
+///     let event: WebhookEvent<String> = WebhookEvent::new()
+///         .id("evt_1")
+///         .event_type("user.created")
+///         .data("payload".to_string());
+///
+///     let result = sender.send("https://example.com/webhook", &event).await;
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookSender<T> {
+    transport: T,
+    key: Vec<u8>,
+    max_retries: usize,
+    backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl<T: WebhookTransport> WebhookSender<T> {
+    /// Create a new webhook sender with a transport and a signing key.
+    pub fn new<K: Into<Vec<u8>>>(
+        transport: T,
+        key: K,
+    ) -> Self {
+        Self {
+            transport,
+            key: key.into(),
+            max_retries: WEBHOOK_RETRIES_DEFAULT,
+            backoff: Duration::from_millis(WEBHOOK_BACKOFF_DEFAULT),
+            max_backoff: Duration::from_millis(WEBHOOK_BACKOFF_MAX_DEFAULT),
+        }
+    }
+
+    /// Set the maximum number of retries,
+    /// [`WEBHOOK_RETRIES_DEFAULT`] by default.
+    pub fn max_retries(
+        mut self,
+        retries: usize,
+    ) -> Self {
+        self.max_retries = retries;
+
+        self
+    }
+
+    /// Set the initial backoff, doubled after each failed attempt,
+    /// [`WEBHOOK_BACKOFF_DEFAULT`] milliseconds by default.
+    pub fn backoff(
+        mut self,
+        backoff: Duration,
+    ) -> Self {
+        self.backoff = backoff;
+
+        self
+    }
+
+    /// Set the maximum backoff,
+    /// [`WEBHOOK_BACKOFF_MAX_DEFAULT`] milliseconds by default.
+    pub fn max_backoff(
+        mut self,
+        backoff: Duration,
+    ) -> Self {
+        self.max_backoff = backoff;
+
+        self
+    }
+
+    /// Sign and send a webhook event.
+    ///
+    /// Requests failed with a network error, `408`, `429` or `5xx`
+    /// will be retried, other failures will be returned immediately.
+    pub async fn send<D: Serialize>(
+        &self,
+        url: &str,
+        event: &WebhookEvent<D>,
+    ) -> Result<WebhookDelivery, Vec<JsonResponseError>> {
+        let signed: SignedWebhookEvent =
+            event.sign(&self.key).map_err(|e| vec![e])?;
+
+        let headers: HeaderMap = match create_headers(&signed) {
+            | Some(headers) => headers,
+            | None => {
+                return Err(vec![create_error(
+                    0,
+                    "Failed to create header map",
+                )]);
+            },
+        };
+
+        let mut errors: Vec<JsonResponseError> = Vec::new();
+        let mut backoff: Duration = self.backoff;
+
+        for attempt in 1..=self.max_retries + 1 {
+            let retryable: bool = match self
+                .transport
+                .post(url, headers.clone(), signed.body.clone())
+                .await
+            {
+                | Ok(status) if status.is_success() => {
+                    return Ok(WebhookDelivery { status, attempts: attempt });
+                },
+                | Ok(status) => {
+                    errors.push(create_error(
+                        attempt,
+                        &format!("Unexpected status code {}", status.as_u16()),
+                    ));
+
+                    status == StatusCode::REQUEST_TIMEOUT
+                        || status == StatusCode::TOO_MANY_REQUESTS
+                        || status.is_server_error()
+                },
+                | Err(message) => {
+                    errors.push(create_error(attempt, &message));
+
+                    true
+                },
+            };
+
+            if !retryable {
+                break;
+            }
+
+            if attempt <= self.max_retries {
+                tokio::time::sleep(backoff).await;
+
+                backoff = (backoff * 2).min(self.max_backoff);
+            }
+        }
+
+        Err(errors)
+    }
+}
+
+/// Create headers for a signed webhook event.
+fn create_headers(signed: &SignedWebhookEvent) -> Option<HeaderMap> {
+    let mut headers: HeaderMap = HeaderMap::new();
+
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+
+    headers.insert(
+        WEBHOOK_SIGNATURE_HEADER,
+        HeaderValue::from_str(&signed.signature).ok()?,
+    );
+
+    Some(headers)
+}
+
+/// Create a delivery error for an attempt.
+fn create_error(
+    attempt: usize,
+    message: &str,
+) -> JsonResponseError {
+    JsonResponseError::new()
+        .code(WEBHOOK_DELIVERY_ERROR_CODE)
+        .path([
+            "webhook".to_string(),
+            "attempt".to_string(),
+            attempt.to_string(),
+        ])
+        .message(message)
+}
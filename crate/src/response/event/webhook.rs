@@ -0,0 +1,283 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    hmac::{from_hex, sign_sha256, to_hex, verify_sha256},
+    response::json::{JsonResponseError, ResponseError},
+};
+
+/// Header name of webhook signatures.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-webhook-signature";
+
+/// Default tolerance of webhook signature timestamps in seconds.
+pub const WEBHOOK_SIGNATURE_TOLERANCE_DEFAULT: u64 = 300;
+
+/// Webhook event.
+///
+/// For API documentation generation with utoipa,
+/// `ToSchema` derive is available with the `utoipa` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::event::WebhookEvent;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct UserCreated {
+///     id: usize,
+/// }
+///
+/// let event: WebhookEvent<UserCreated> = WebhookEvent::new()
+///     .id("evt_1")
+///     .event_type("user.created")
+///     .data(UserCreated { id: 1 });
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct WebhookEvent<D = ()> {
+    /// Unique identifier of the event.
+    pub id: String,
+    /// Type of the event.
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// Time of the event in seconds since Unix epoch.
+    pub timestamp: u64,
+    /// Payload of the event.
+    pub data: Option<D>,
+}
+
+impl<D> WebhookEvent<D> {
+    /// Create a new webhook event with current timestamp.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::event::WebhookEvent;
+    ///
+    /// let event: WebhookEvent = WebhookEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            id: String::new(),
+            event_type: String::new(),
+            timestamp: now(),
+            data: None,
+        }
+    }
+
+    /// Set the identifier of the event.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::event::WebhookEvent;
+    ///
+    /// let event: WebhookEvent = WebhookEvent::new().id("evt_1");
+    /// ```
+    pub fn id<I: Into<String>>(
+        mut self,
+        id: I,
+    ) -> Self {
+        self.id = id.into();
+
+        self
+    }
+
+    /// Set the type of the event.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::event::WebhookEvent;
+    ///
+    /// let event: WebhookEvent = WebhookEvent::new()
+    ///     .event_type("user.created");
+    /// ```
+    pub fn event_type<T: Into<String>>(
+        mut self,
+        event_type: T,
+    ) -> Self {
+        self.event_type = event_type.into();
+
+        self
+    }
+
+    /// Set the time of the event in seconds since Unix epoch.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::event::WebhookEvent;
+    ///
+    /// let event: WebhookEvent = WebhookEvent::new()
+    ///     .timestamp(1700000000);
+    /// ```
+    pub fn timestamp(
+        mut self,
+        timestamp: u64,
+    ) -> Self {
+        self.timestamp = timestamp;
+
+        self
+    }
+
+    /// Set the payload of the event.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::event::WebhookEvent;
+    ///
+    /// let event: WebhookEvent<String> = WebhookEvent::new()
+    ///     .data("payload".to_string());
+    /// ```
+    pub fn data(
+        mut self,
+        data: D,
+    ) -> Self {
+        self.data = Some(data);
+
+        self
+    }
+}
+
+impl<D: Serialize> WebhookEvent<D> {
+    /// Serialize and sign the event with HMAC-SHA256.
+    ///
+    /// The signature is in the format of `t=<timestamp>,v1=<hex>`,
+    /// where the signed content is `<timestamp>.<body>`,
+    /// the same as Stripe webhook signatures.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::event::{
+    ///     SignedWebhookEvent,
+    ///     WebhookEvent,
+    /// };
+    ///
+    /// let signed: SignedWebhookEvent = WebhookEvent::<()>::new()
+    ///     .id("evt_1")
+    ///     .event_type("user.created")
+    ///     .sign(b"secret")
+    ///     .unwrap();
+    /// ```
+    pub fn sign(
+        &self,
+        key: &[u8],
+    ) -> Result<SignedWebhookEvent, JsonResponseError> {
+        let body: String = serde_json::to_string(self).map_err(|err| {
+            JsonResponseError::from(ResponseError::Server)
+                .path(["webhook", "body"])
+                .message(err.to_string())
+        })?;
+
+        let signature: String = create_signature(&body, self.timestamp, key);
+
+        Ok(SignedWebhookEvent { body, signature })
+    }
+}
+
+impl Default for WebhookEvent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serialized and signed webhook event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedWebhookEvent {
+    /// Serialized event.
+    pub body: String,
+    /// Signature of the event,
+    /// sent with [`WEBHOOK_SIGNATURE_HEADER`].
+    pub signature: String,
+}
+
+/// Verify the signature of a received webhook event.
+///
+/// Signatures with timestamp older than the tolerance will be rejected.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use jder_axum::response::event::verify_signature;
+///
+/// async fn route(body: String) {
+///     let signature: &str = "t=1700000000,v1=...";
+///
+///     let result = verify_signature(
+///         &body,
+///         signature,
+///         b"secret",
+///         Duration::from_secs(300),
+///     );
+/// }
+/// ```
+pub fn verify_signature(
+    body: &str,
+    signature: &str,
+    key: &[u8],
+    tolerance: Duration,
+) -> Result<(), JsonResponseError> {
+    let error = |message: &str| {
+        JsonResponseError::from(ResponseError::Parse)
+            .path(["header", WEBHOOK_SIGNATURE_HEADER])
+            .message(message)
+    };
+
+    let mut timestamp: Option<u64> = None;
+    let mut expected: Option<Vec<u8>> = None;
+
+    for part in signature.split(',') {
+        match part.trim().split_once('=') {
+            | Some(("t", value)) => timestamp = value.parse().ok(),
+            | Some(("v1", value)) => expected = from_hex(value),
+            | _ => {},
+        }
+    }
+
+    let (timestamp, expected) = match (timestamp, expected) {
+        | (Some(timestamp), Some(expected)) => (timestamp, expected),
+        | _ => return Err(error("Malformed signature")),
+    };
+
+    if now().abs_diff(timestamp) > tolerance.as_secs() {
+        return Err(error("Signature timestamp is outside the tolerance"));
+    }
+
+    let content: String = format!("{}.{}", timestamp, body);
+
+    if !verify_sha256(key, content.as_bytes(), &expected) {
+        return Err(error("Invalid signature"));
+    }
+
+    Ok(())
+}
+
+/// Create the signature of a serialized event.
+fn create_signature(
+    body: &str,
+    timestamp: u64,
+    key: &[u8],
+) -> String {
+    let content: String = format!("{}.{}", timestamp, body);
+
+    format!(
+        "t={},v1={}",
+        timestamp,
+        to_hex(&sign_sha256(key, content.as_bytes()))
+    )
+}
+
+/// Current time in seconds since Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
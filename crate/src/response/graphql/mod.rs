@@ -0,0 +1,341 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+use crate::response::json::{
+    JsonResponse, JsonResponseError, ResponseError, ResponseFormat,
+    ResponseFormatParts,
+};
+
+/// Location of a GraphQL error in the request document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphQLLocation {
+    /// Line of the location, starting from `1`.
+    pub line: usize,
+    /// Column of the location, starting from `1`.
+    pub column: usize,
+}
+
+/// GraphQL error of an execution result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphQLError {
+    /// Detail of the error.
+    pub message: String,
+    /// Locations of the error in the request document.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<GraphQLLocation>,
+    /// Path of the response field where the error occurred,
+    /// with field names and list indices.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<Value>,
+    /// Additional information of the error, such as `code`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Map<String, Value>>,
+}
+
+/// GraphQL execution result following the GraphQL specification,
+/// available with `graphql` feature.
+///
+/// Execution results of GraphQL servers can be converted
+/// through their serialized form,
+/// or from `async_graphql::Response` with `async_graphql` feature,
+/// and converted into a [`JsonResponse`]:
+/// - `data`: data of the result
+/// - `errors`: errors of the result,
+///   failed when there is any error,
+///   with `extensions.code` as `code`
+///   and the path under `graphql`, such as `["graphql", "user", "0"]`,
+///   or the first location, such as `["graphql", "3:5"]`,
+///   when the error has no path
+/// - `extensions`: meta of the response
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     graphql::GraphQLResponse,
+///     json::JsonResponse,
+/// };
+/// use serde_json::{Value, json};
+///
+/// async fn route() -> JsonResponse<Value> {
+///     // from `serde_json::to_value(async_graphql_response)`
+///     let result: Value = json!({
+///         "data": { "user": null },
+///         "errors": [{ "message": "User not found", "path": ["user"] }],
+///     });
+///
+///     let result: GraphQLResponse = serde_json::from_value(result).unwrap();
+///
+///     JsonResponse::from(result)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphQLResponse<D = Value> {
+    /// Data of the execution result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<D>,
+    /// Errors of the execution result.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GraphQLError>,
+    /// Additional information of the execution result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Map<String, Value>>,
+}
+
+impl From<GraphQLError> for JsonResponseError {
+    fn from(error: GraphQLError) -> Self {
+        let code: String = error
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("code"))
+            .and_then(Value::as_str)
+            .unwrap_or(ResponseError::Unknown.as_code())
+            .to_string();
+
+        let mut path: Vec<String> = vec!["graphql".to_string()];
+
+        if !error.path.is_empty() {
+            path.extend(error.path.iter().map(|segment| match segment {
+                | Value::String(segment) => segment.clone(),
+                | segment => segment.to_string(),
+            }));
+        } else if let Some(location) = error.locations.first() {
+            path.push(format!("{}:{}", location.line, location.column));
+        }
+
+        JsonResponseError::new().code(code).path(path).message(error.message)
+    }
+}
+
+impl<D> From<GraphQLResponse<D>> for JsonResponse<D> {
+    fn from(response: GraphQLResponse<D>) -> Self {
+        let mut json: JsonResponse<D> = JsonResponse::new()
+            .success(response.errors.is_empty())
+            .errors(response.errors);
+
+        json.data = response.data;
+        json.meta = response.extensions;
+
+        json
+    }
+}
+
+/// Convert a GraphQL value of async-graphql into a JSON value.
+#[cfg(feature = "async_graphql")]
+fn from_async_graphql_value_fn(value: async_graphql::Value) -> Value {
+    value.into_json().unwrap_or(Value::Null)
+}
+
+/// Error of an async-graphql execution result,
+/// available with `async_graphql` feature.
+#[cfg(feature = "async_graphql")]
+impl From<async_graphql::ServerError> for GraphQLError {
+    fn from(error: async_graphql::ServerError) -> Self {
+        let extensions: Option<Map<String, Value>> = error
+            .extensions
+            .and_then(|extensions| serde_json::to_value(extensions).ok())
+            .and_then(|extensions| match extensions {
+                | Value::Object(extensions) => Some(extensions),
+                | _ => None,
+            });
+
+        Self {
+            message: error.message,
+            locations: error
+                .locations
+                .into_iter()
+                .map(|pos| GraphQLLocation {
+                    line: pos.line,
+                    column: pos.column,
+                })
+                .collect(),
+            path: error
+                .path
+                .into_iter()
+                .map(|segment| match segment {
+                    | async_graphql::PathSegment::Field(field) => {
+                        Value::String(field)
+                    },
+                    | async_graphql::PathSegment::Index(index) => {
+                        Value::from(index)
+                    },
+                })
+                .collect(),
+            extensions,
+        }
+    }
+}
+
+/// Execution result of async-graphql,
+/// available with `async_graphql` feature,
+/// where `null` data is `None`.
+#[cfg(feature = "async_graphql")]
+impl From<async_graphql::Response> for GraphQLResponse {
+    fn from(response: async_graphql::Response) -> Self {
+        let data: Option<Value> =
+            match from_async_graphql_value_fn(response.data) {
+                | Value::Null => None,
+                | data => Some(data),
+            };
+
+        let extensions: Map<String, Value> = response
+            .extensions
+            .into_iter()
+            .map(|(key, value)| (key, from_async_graphql_value_fn(value)))
+            .collect();
+
+        Self {
+            data,
+            errors: response.errors.into_iter().map(Into::into).collect(),
+            extensions: match extensions.is_empty() {
+                | true => None,
+                | false => Some(extensions),
+            },
+        }
+    }
+}
+
+/// Execution result of async-graphql,
+/// available with `async_graphql` feature,
+/// converted through [`GraphQLResponse`].
+///
+/// ## Example
+///
+/// ```no_run
+/// use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+/// use jder_axum::response::json::JsonResponse;
+/// use serde_json::Value;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn name(&self) -> &str {
+///         "Name"
+///     }
+/// }
+///
+/// async fn route() -> JsonResponse<Value> {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///
+///     JsonResponse::from(schema.execute("{ name }").await)
+/// }
+/// ```
+#[cfg(feature = "async_graphql")]
+impl From<async_graphql::Response> for JsonResponse<Value> {
+    fn from(response: async_graphql::Response) -> Self {
+        GraphQLResponse::from(response).into()
+    }
+}
+
+/// Format of a GraphQL response body,
+/// available with `graphql` feature.
+///
+/// The response is mapped into a GraphQL response body,
+/// so that GraphQL endpoints share the status and headers
+/// of the response builder:
+/// - `data`: data of the response, or `null` if not set
+/// - `errors`: errors of a failure response,
+///   with `code` in `extensions`,
+///   and `path` without the leading `graphql` segment,
+///   or `locations` for a `line:column` segment
+/// - `extensions`: meta of the response if set
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     graphql::GraphQLFormat,
+///     json::CreateJsonResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateJsonResponse::with_format(GraphQLFormat)
+///         .success::<String>()
+///         .data("Name".to_string())
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GraphQLFormat;
+
+impl ResponseFormat for GraphQLFormat {
+    fn format(
+        &self,
+        parts: ResponseFormatParts,
+    ) -> Value {
+        let mut body: Map<String, Value> = Map::new();
+
+        body.insert("data".to_string(), parts.data.unwrap_or(Value::Null));
+
+        if !parts.success {
+            let errors: Vec<Value> =
+                parts.errors.iter().map(create_error_object).collect();
+
+            body.insert("errors".to_string(), Value::Array(errors));
+        }
+
+        if let Some(meta) = parts.meta {
+            body.insert("extensions".to_string(), Value::Object(meta));
+        }
+
+        Value::Object(body)
+    }
+}
+
+/// Parse a `line:column` path segment into a location.
+fn parse_location_fn(segment: &str) -> Option<GraphQLLocation> {
+    let (line, column) = segment.split_once(':')?;
+
+    Some(GraphQLLocation {
+        line: line.parse().ok()?,
+        column: column.parse().ok()?,
+    })
+}
+
+/// Create a GraphQL error object from an error.
+fn create_error_object(error: &JsonResponseError) -> Value {
+    let segments: &[String] = match error.path.split_first() {
+        | Some((first, rest)) if first == "graphql" => rest,
+        | _ => &error.path,
+    };
+
+    let mut object: Map<String, Value> = Map::new();
+
+    object.insert(
+        "message".to_string(),
+        Value::String(error.message.clone().unwrap_or(error.code.clone())),
+    );
+
+    let location: Option<GraphQLLocation> = match segments {
+        | [segment] => parse_location_fn(segment),
+        | _ => None,
+    };
+
+    match location {
+        | Some(location) => {
+            object.insert("locations".to_string(), json!([location]));
+        },
+        | None if !segments.is_empty() => {
+            let path: Vec<Value> = segments
+                .iter()
+                .map(|segment| match segment.parse::<u64>() {
+                    | Ok(index) => Value::from(index),
+                    | Err(_) => Value::String(segment.clone()),
+                })
+                .collect();
+
+            object.insert("path".to_string(), Value::Array(path));
+        },
+        | None => {},
+    }
+
+    let mut extensions: Map<String, Value> = Map::new();
+
+    extensions.insert("code".to_string(), Value::String(error.code.clone()));
+
+    object.insert("extensions".to_string(), Value::Object(extensions));
+
+    Value::Object(object)
+}
@@ -0,0 +1,528 @@
+use std::sync::Arc;
+
+use axum_core::{BoxError, body::Body};
+use bytes::Bytes;
+use http::{
+    Error as HTTPError, HeaderMap, HeaderName, HeaderValue, StatusCode, header,
+    response::Builder,
+};
+
+use crate::response::{
+    Response,
+    json::{
+        CreateJsonResponse, JsonResponseError, ResponseError,
+        create::{
+            JsonResponseState, base::create_json_response_fn,
+            negotiate::preferred_media_type,
+        },
+    },
+};
+
+/// Format of an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    /// PNG image.
+    Png,
+    /// JPEG image.
+    Jpeg,
+    /// GIF image.
+    Gif,
+    /// WebP image.
+    Webp,
+    /// AVIF image.
+    Avif,
+    /// BMP image.
+    Bmp,
+}
+
+impl ImageFormat {
+    /// Get the content type of the format, such as `image/png`.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            | Self::Png => "image/png",
+            | Self::Jpeg => "image/jpeg",
+            | Self::Gif => "image/gif",
+            | Self::Webp => "image/webp",
+            | Self::Avif => "image/avif",
+            | Self::Bmp => "image/bmp",
+        }
+    }
+
+    /// Detect the format of an image from its signature,
+    /// returns `None` if the image is not recognized.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::image::ImageFormat;
+    ///
+    /// let format: Option<ImageFormat> =
+    ///     ImageFormat::detect(b"\x89PNG\r\n\x1a\n");
+    /// ```
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            return Some(Self::Png);
+        }
+
+        if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            return Some(Self::Jpeg);
+        }
+
+        if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            return Some(Self::Gif);
+        }
+
+        if bytes.len() >= 12
+            && &bytes[..4] == b"RIFF"
+            && &bytes[8..12] == b"WEBP"
+        {
+            return Some(Self::Webp);
+        }
+
+        if bytes.len() >= 12
+            && &bytes[4..8] == b"ftyp"
+            && matches!(&bytes[8..12], b"avif" | b"avis")
+        {
+            return Some(Self::Avif);
+        }
+
+        if bytes.starts_with(b"BM") {
+            return Some(Self::Bmp);
+        }
+
+        None
+    }
+}
+
+/// Transcoder of images for negotiating the format
+/// from the `Accept` header,
+/// used by [`create_negotiated`](ImageResponseFunctions::create_negotiated).
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::BoxError;
+/// use bytes::Bytes;
+/// use jder_axum::response::image::{ImageFormat, ImageTranscoder};
+///
+/// struct WebpTranscoder;
+///
+/// impl ImageTranscoder for WebpTranscoder {
+///     fn formats(&self) -> &[ImageFormat] {
+///         &[ImageFormat::Webp]
+///     }
+///
+///     fn transcode(
+///         &self,
+///         image: Bytes,
+///         from: ImageFormat,
+///         to: ImageFormat,
+///     ) -> Result<Bytes, BoxError> {
+///         // ...
+///         Ok(image)
+///     }
+/// }
+/// ```
+pub trait ImageTranscoder: Send + Sync {
+    /// Get the formats that images can be transcoded into.
+    fn formats(&self) -> &[ImageFormat];
+
+    /// Transcode an image into another format.
+    fn transcode(
+        &self,
+        image: Bytes,
+        from: ImageFormat,
+        to: ImageFormat,
+    ) -> Result<Bytes, BoxError>;
+}
+
+impl<T: ImageTranscoder + ?Sized> ImageTranscoder for Arc<T> {
+    fn formats(&self) -> &[ImageFormat] {
+        (**self).formats()
+    }
+
+    fn transcode(
+        &self,
+        image: Bytes,
+        from: ImageFormat,
+        to: ImageFormat,
+    ) -> Result<Bytes, BoxError> {
+        (**self).transcode(image, from, to)
+    }
+}
+
+/// Create a failure response of an image,
+/// with `image` in path.
+fn create_image_failure_fn(
+    status: StatusCode,
+    code: ResponseError,
+    message: &str,
+) -> Response {
+    CreateJsonResponse::failure()
+        .status(status)
+        .add_error(
+            JsonResponseError::from(code).path(["image"]).message(message),
+        )
+        .create()
+}
+
+/// Functions for creating an image response.
+pub struct ImageResponseFunctions {
+    /// Internal state.
+    state: JsonResponseState<()>,
+    /// Source image.
+    source: Option<Bytes>,
+    /// Transcoder for negotiating the format.
+    transcoder: Option<Arc<dyn ImageTranscoder>>,
+}
+
+impl ImageResponseFunctions {
+    /// Set the status code for the response.
+    pub fn status<C: Into<StatusCode>>(
+        mut self,
+        status: C,
+    ) -> Self {
+        self.state.status = status.into();
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use jder_axum::response::{Response, image::CreateImageResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateImageResponse::bytes(std::fs::read("logo.png").unwrap())
+    ///         .header(header::CONTENT_DISPOSITION, "inline")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        let key: HeaderName = match <HeaderName as TryFrom<K>>::try_from(key) {
+            | Ok(k) => k,
+            | Err(_) => {
+                self.state.is_header_map_failed = true;
+                return self;
+            },
+        };
+
+        let value: HeaderValue =
+            match <HeaderValue as TryFrom<V>>::try_from(value) {
+                | Ok(v) => v,
+                | Err(_) => {
+                    self.state.is_header_map_failed = true;
+                    return self;
+                },
+            };
+
+        self.state.header_map.append(key, value);
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        for (key, value) in headers {
+            self = self.header(key, value);
+        }
+
+        self
+    }
+
+    /// Set the public cache lifetime in seconds
+    /// with `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, image::CreateImageResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateImageResponse::bytes(std::fs::read("logo.png").unwrap())
+    ///         .max_age(86400)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn max_age(
+        self,
+        seconds: u64,
+    ) -> Self {
+        self.header(
+            header::CACHE_CONTROL,
+            format!("public, max-age={}", seconds),
+        )
+    }
+
+    /// Set the transcoder for negotiating the format
+    /// with [`create_negotiated`](Self::create_negotiated).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use axum::http::HeaderMap;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     image::{CreateImageResponse, ImageTranscoder},
+    /// };
+    ///
+    /// async fn route(
+    ///     headers: HeaderMap,
+    ///     transcoder: Arc<dyn ImageTranscoder>,
+    /// ) -> Response {
+    ///     CreateImageResponse::bytes(std::fs::read("logo.png").unwrap())
+    ///         .transcoder(transcoder)
+    ///         .create_negotiated(&headers)
+    /// }
+    /// ```
+    pub fn transcoder<T: ImageTranscoder + 'static>(
+        mut self,
+        transcoder: T,
+    ) -> Self {
+        self.transcoder = Some(Arc::new(transcoder));
+
+        self
+    }
+
+    /// Create the response of the image in a format,
+    /// transcoded if the format is not the source format.
+    fn create_in(
+        self,
+        format: Option<ImageFormat>,
+    ) -> Response {
+        // header map error
+        if self.state.is_header_map_failed {
+            return create_json_response_fn(self.state);
+        }
+
+        let source: Bytes = match self.source {
+            | Some(source) => source,
+            | None => {
+                return create_image_failure_fn(
+                    StatusCode::NOT_FOUND,
+                    ResponseError::NotFound,
+                    "Image not found",
+                );
+            },
+        };
+
+        let from: ImageFormat = match ImageFormat::detect(&source) {
+            | Some(from) => from,
+            | None => {
+                return create_image_failure_fn(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    ResponseError::Server,
+                    "Unrecognized image source",
+                );
+            },
+        };
+
+        let (format, body): (ImageFormat, Bytes) =
+            match (format, &self.transcoder) {
+                | (Some(to), Some(transcoder)) if to != from => {
+                    match transcoder.transcode(source, from, to) {
+                        | Ok(body) => (to, body),
+                        | Err(_) => {
+                            return create_image_failure_fn(
+                                StatusCode::INTERNAL_SERVER_ERROR,
+                                ResponseError::Server,
+                                "Failed to transcode image",
+                            );
+                        },
+                    }
+                },
+                | _ => (from, source),
+            };
+
+        // create response builder
+        let mut builder: Builder = Response::builder()
+            .status(self.state.status)
+            .version(self.state.version)
+            .header(header::CONTENT_TYPE, format.content_type());
+
+        // push headers, where repeated values come without the name
+        let mut name: Option<HeaderName> = None;
+
+        for (header, value) in self.state.header_map {
+            if header.is_some() {
+                name = header;
+            }
+
+            if let Some(name) = &name {
+                builder = builder.header(name, value);
+            }
+        }
+
+        match builder.body(Body::from(body)) {
+            | Ok(res) => res,
+            | Err(_) => {
+                let mut state: JsonResponseState<()> =
+                    JsonResponseState::success();
+
+                state.is_header_map_failed = true;
+
+                create_json_response_fn(state)
+            },
+        }
+    }
+
+    /// Finish the response creation with the source format.
+    ///
+    /// Missing sources are `404 Not Found` failure responses,
+    /// and unrecognized sources are `500 Internal Server Error`
+    /// failure responses.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, image::CreateImageResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateImageResponse::bytes(std::fs::read("logo.png").unwrap()).create()
+    /// }
+    /// ```
+    pub fn create(self) -> Response {
+        self.create_in(None)
+    }
+
+    /// Finish the response creation with the format
+    /// negotiated from the `Accept` header of the request,
+    /// falling back to the source format.
+    ///
+    /// The source format is preferred if multiple formats
+    /// have the same quality, and the other formats
+    /// are the formats of the [`transcoder`](Self::transcoder),
+    /// with `Vary: Accept` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::HeaderMap;
+    /// use jder_axum::response::{Response, image::CreateImageResponse};
+    ///
+    /// async fn route(headers: HeaderMap) -> Response {
+    ///     CreateImageResponse::bytes(std::fs::read("logo.png").unwrap())
+    ///         .create_negotiated(&headers)
+    /// }
+    /// ```
+    pub fn create_negotiated(
+        mut self,
+        headers: &HeaderMap,
+    ) -> Response {
+        let from: Option<ImageFormat> =
+            self.source.as_deref().and_then(ImageFormat::detect);
+
+        let (from, transcoder) = match (from, &self.transcoder) {
+            | (Some(from), Some(transcoder)) => (from, transcoder.clone()),
+            | _ => return self.create(),
+        };
+
+        let formats: Vec<ImageFormat> = std::iter::once(from)
+            .chain(transcoder.formats().iter().copied().filter(|f| *f != from))
+            .collect();
+
+        let media_types: Vec<&str> =
+            formats.iter().map(|format| format.content_type()).collect();
+
+        let format: Option<ImageFormat> =
+            preferred_media_type(headers, &media_types).map(|i| formats[i]);
+
+        self = self.header(header::VARY, "accept");
+
+        self.create_in(format)
+    }
+}
+
+/// Create an image response for a route,
+/// available with `image` feature.
+///
+/// The content type is detected from the image,
+/// and the format can be negotiated from the `Accept` header
+/// with an [`ImageTranscoder`].
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::http::HeaderMap;
+/// use jder_axum::response::{Response, image::CreateImageResponse};
+///
+/// async fn route(headers: HeaderMap) -> Response {
+///     let image: Option<Vec<u8>> = std::fs::read("logo.png").ok();
+///
+///     CreateImageResponse::optional(image)
+///         .max_age(3600)
+///         .create_negotiated(&headers)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateImageResponse;
+
+impl CreateImageResponse {
+    /// Create an image response from bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, image::CreateImageResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateImageResponse::bytes(std::fs::read("logo.png").unwrap()).create()
+    /// }
+    /// ```
+    pub fn bytes<B: Into<Bytes>>(image: B) -> ImageResponseFunctions {
+        Self::optional(Some(image))
+    }
+
+    /// Create an image response from optional bytes,
+    /// where a missing image is a `404 Not Found` failure response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, image::CreateImageResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateImageResponse::optional(std::fs::read("logo.png").ok())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn optional<B: Into<Bytes>>(
+        image: Option<B>
+    ) -> ImageResponseFunctions {
+        ImageResponseFunctions {
+            state: JsonResponseState::success(),
+            source: image.map(Into::into),
+            transcoder: None,
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    io::Error as IoError,
+    num::{ParseFloatError, ParseIntError},
+    str::Utf8Error,
+    string::FromUtf8Error,
+};
+
+use axum_core::response::IntoResponse;
+use http::StatusCode;
+
+use crate::response::{
+    Response,
+    json::{
+        config::JsonResponseConfig,
+        create::CreateJsonResponse,
+        error::{CustomError, ErrorCode, JsonResponseError, ResponseError},
+        response::JsonResponse,
+    },
+};
+
+/// Result type for routes,
+/// so that errors can be propagated with `?` operator.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::{ApiResult, JsonResponse};
+///
+/// async fn route() -> ApiResult<u32> {
+///     let value: u32 = "42".parse()?;
+///
+///     Ok(JsonResponse::new().data(value))
+/// }
+/// ```
+pub type ApiResult<D = ()> = Result<JsonResponse<D>, ApiError>;
+
+/// Error for routes,
+/// responded as a failure JSON response.
+///
+/// Without a status code,
+/// the status is derived from the [`ResponseError`] or [`CustomError`]
+/// it is converted from,
+/// or the default failure status,
+/// see [`JsonResponseConfig`](crate::response::json::JsonResponseConfig).
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::http::StatusCode;
+/// use jder_axum::response::json::{
+///     ApiError,
+///     ApiResult,
+///     JsonResponse,
+///     JsonResponseError,
+/// };
+///
+/// async fn route() -> ApiResult<String> {
+///     Err(ApiError::new()
+///         .status(StatusCode::NOT_FOUND)
+///         .add_error(JsonResponseError::new().code("not_found")))
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ApiError {
+    /// Status code of the response.
+    pub status: Option<StatusCode>,
+    /// A list of errors for the response.
+    pub errors: Vec<JsonResponseError>,
+}
+
+impl ApiError {
+    /// Create a new API error.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::ApiError;
+    ///
+    /// let error: ApiError = ApiError::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the status code for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::ApiError;
+    ///
+    /// let error: ApiError = ApiError::new()
+    ///     .status(StatusCode::NOT_FOUND);
+    /// ```
+    pub fn status<S: Into<StatusCode>>(
+        mut self,
+        status: S,
+    ) -> Self {
+        self.status = Some(status.into());
+
+        self
+    }
+
+    /// Add an error for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::{
+    ///     ApiError,
+    ///     JsonResponseError,
+    /// };
+    ///
+    /// let error: ApiError = ApiError::new()
+    ///     .add_error(JsonResponseError::new().code("not_found"));
+    /// ```
+    pub fn add_error<E: Into<JsonResponseError>>(
+        mut self,
+        error: E,
+    ) -> Self {
+        self.errors.push(error.into());
+
+        self
+    }
+
+    /// Create an API error from an error code,
+    /// with the suggested status code of the error if any,
+    /// such as a custom error defined by a downstream crate.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::{ApiError, CustomError};
+    ///
+    /// const ORDER_LOCKED: CustomError =
+    ///     CustomError::new("order_locked", "Order is locked").status(409);
+    ///
+    /// let error: ApiError = ApiError::from_code(ORDER_LOCKED);
+    /// ```
+    pub fn from_code<E: ErrorCode>(error: E) -> Self {
+        let status: Option<StatusCode> =
+            error.status().and_then(|status| StatusCode::from_u16(status).ok());
+
+        Self { status, errors: vec![JsonResponseError::from(error)] }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status: StatusCode = self
+            .status
+            .unwrap_or_else(|| JsonResponseConfig::get().failure_status);
+
+        CreateJsonResponse::failure_with(status).errors(self.errors).create()
+    }
+}
+
+impl From<JsonResponseError> for ApiError {
+    fn from(error: JsonResponseError) -> Self {
+        Self::new().add_error(error)
+    }
+}
+
+impl From<ResponseError> for ApiError {
+    fn from(error: ResponseError) -> Self {
+        let api_error: ApiError = Self::from_code(error);
+
+        match error {
+            | ResponseError::BodyRead => {
+                api_error.status(JsonResponseConfig::get().body_read_status)
+            },
+            | _ => api_error,
+        }
+    }
+}
+
+impl From<CustomError> for ApiError {
+    fn from(error: CustomError) -> Self {
+        Self::from_code(error)
+    }
+}
+
+/// Create a parse error with a message.
+fn create_parse_error_fn<E: ToString>(error: E) -> ApiError {
+    ApiError::new().status(StatusCode::BAD_REQUEST).add_error(
+        JsonResponseError::from(ResponseError::Parse)
+            .message(error.to_string()),
+    )
+}
+
+impl From<IoError> for ApiError {
+    fn from(_: IoError) -> Self {
+        // hide the detail as it may contain internal information
+        Self::from(ResponseError::Server)
+    }
+}
+
+impl From<serde_json::Error> for ApiError {
+    fn from(error: serde_json::Error) -> Self {
+        create_parse_error_fn(error)
+    }
+}
+
+impl From<ParseIntError> for ApiError {
+    fn from(error: ParseIntError) -> Self {
+        create_parse_error_fn(error)
+    }
+}
+
+impl From<ParseFloatError> for ApiError {
+    fn from(error: ParseFloatError) -> Self {
+        create_parse_error_fn(error)
+    }
+}
+
+impl From<Utf8Error> for ApiError {
+    fn from(error: Utf8Error) -> Self {
+        create_parse_error_fn(error)
+    }
+}
+
+impl From<FromUtf8Error> for ApiError {
+    fn from(error: FromUtf8Error) -> Self {
+        create_parse_error_fn(error)
+    }
+}
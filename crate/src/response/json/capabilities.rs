@@ -0,0 +1,171 @@
+use axum_core::response::IntoResponse;
+use http::{HeaderName, Method, header};
+use serde::{Deserialize, Serialize};
+
+use crate::response::{Response, json::CreateJsonResponse};
+
+/// Header name of the media types accepted by `PATCH`.
+pub const ACCEPT_PATCH_HEADER: &str = "accept-patch";
+
+/// Header name of the media types accepted by `POST`.
+pub const ACCEPT_POST_HEADER: &str = "accept-post";
+
+/// Capabilities of a resource for `OPTIONS` responses,
+/// enabling clients to discover the supported methods
+/// and media types of the resource.
+///
+/// The response carries the `Allow`, `Accept-Patch` and `Accept-Post`
+/// headers, and the capabilities in data:
+///
+/// ```jsonc
+/// // Allow: GET, PATCH, POST
+/// // Accept-Patch: application/merge-patch+json
+/// // Accept-Post: application/json
+/// {
+///     "success": true,
+///     "data": {
+///         "methods": ["GET", "PATCH", "POST"],
+///         "accept_patch": ["application/merge-patch+json"],
+///         "accept_post": ["application/json"]
+///     },
+///     "errors": []
+/// }
+/// ```
+///
+/// Headers with no media types are omitted.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, http::Method, routing::options};
+/// use jder_axum::response::json::Capabilities;
+///
+/// async fn route() -> Capabilities {
+///     Capabilities::new()
+///         .methods([Method::GET, Method::PATCH, Method::POST])
+///         .accept_patch(["application/merge-patch+json"])
+///         .accept_post(["application/json"])
+/// }
+///
+/// let router: Router = Router::new().route("/users", options(route));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Methods allowed for the resource.
+    pub methods: Vec<String>,
+    /// Media types accepted by `PATCH`.
+    pub accept_patch: Vec<String>,
+    /// Media types accepted by `POST`.
+    pub accept_post: Vec<String>,
+}
+
+impl Capabilities {
+    /// Create new empty capabilities.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the methods allowed for the resource.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Method;
+    /// use jder_axum::response::json::Capabilities;
+    ///
+    /// Capabilities::new().methods([Method::GET, Method::DELETE]);
+    /// ```
+    pub fn methods<I: IntoIterator<Item = Method>>(
+        mut self,
+        methods: I,
+    ) -> Self {
+        for method in methods {
+            let method: String = method.to_string();
+
+            if !self.methods.contains(&method) {
+                self.methods.push(method);
+            }
+        }
+
+        self
+    }
+
+    /// Add the media types accepted by `PATCH`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::Capabilities;
+    ///
+    /// Capabilities::new().accept_patch(["application/json-patch+json"]);
+    /// ```
+    pub fn accept_patch<I, S>(
+        mut self,
+        media_types: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.accept_patch.extend(media_types.into_iter().map(Into::into));
+
+        self
+    }
+
+    /// Add the media types accepted by `POST`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::Capabilities;
+    ///
+    /// Capabilities::new().accept_post(["application/json"]);
+    /// ```
+    pub fn accept_post<I, S>(
+        mut self,
+        media_types: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.accept_post.extend(media_types.into_iter().map(Into::into));
+
+        self
+    }
+
+    /// Create the `OPTIONS` response of the capabilities.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Method;
+    /// use jder_axum::response::{Response, json::Capabilities};
+    ///
+    /// async fn route() -> Response {
+    ///     Capabilities::new().methods([Method::GET]).create()
+    /// }
+    /// ```
+    pub fn create(self) -> Response {
+        let headers: Vec<(HeaderName, String)> = [
+            (header::ALLOW, &self.methods),
+            (HeaderName::from_static(ACCEPT_PATCH_HEADER), &self.accept_patch),
+            (HeaderName::from_static(ACCEPT_POST_HEADER), &self.accept_post),
+        ]
+        .into_iter()
+        .filter(|(_, values)| !values.is_empty())
+        .map(|(name, values)| (name, values.join(", ")))
+        .collect();
+
+        CreateJsonResponse::success::<Self>()
+            .headers(headers)
+            .data(self)
+            .create()
+    }
+}
+
+impl IntoResponse for Capabilities {
+    fn into_response(self) -> Response {
+        self.create()
+    }
+}
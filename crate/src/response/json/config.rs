@@ -0,0 +1,583 @@
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+};
+#[cfg(feature = "messages_watch")]
+use std::{
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use arc_swap::ArcSwapOption;
+use http::StatusCode;
+
+#[cfg(feature = "decompression")]
+use crate::extract::decompression::DECOMPRESSED_BODY_LIMIT_DEFAULT;
+use crate::response::json::{
+    format::{ResponseFormat, ResponseFormatRef},
+    header_policy::HeaderPolicy,
+};
+
+/// Default ellipsis appended to truncated error messages.
+pub const MESSAGE_ELLIPSIS_DEFAULT: &str = "...";
+
+/// Global configuration,
+/// read without locking by every response.
+static CONFIG: ArcSwapOption<JsonResponseConfig> = ArcSwapOption::const_empty();
+
+/// Lock of the global configuration writers,
+/// so concurrent updates are not lost.
+static CONFIG_WRITE: Mutex<()> = Mutex::new(());
+
+/// Default configuration shared when no configuration is set.
+static CONFIG_DEFAULT: LazyLock<Arc<JsonResponseConfig>> =
+    LazyLock::new(|| Arc::new(JsonResponseConfig::new()));
+
+/// Configuration for JSON responses.
+///
+/// The configuration is applied globally with [`set`](Self::set),
+/// and used by every JSON response created afterwards,
+/// including the rejections from extractors and layers.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::JsonResponseConfig;
+///
+/// JsonResponseConfig::new()
+///     .message_max_length(256)
+///     .set();
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonResponseConfig {
+    pub(crate) message_max_length: Option<usize>,
+    pub(crate) message_ellipsis: String,
+    pub(crate) format: Option<ResponseFormatRef>,
+    pub(crate) body_read_status: StatusCode,
+    pub(crate) success_status: StatusCode,
+    pub(crate) failure_status: StatusCode,
+    pub(crate) messages: Arc<HashMap<String, String>>,
+    pub(crate) header_policy: Option<HeaderPolicy>,
+    #[cfg(feature = "decompression")]
+    pub(crate) decompressed_body_limit: usize,
+}
+
+impl JsonResponseConfig {
+    /// Create a new JSON response configuration.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// let config: JsonResponseConfig = JsonResponseConfig::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            message_max_length: None,
+            message_ellipsis: MESSAGE_ELLIPSIS_DEFAULT.to_string(),
+            format: None,
+            body_read_status: StatusCode::BAD_REQUEST,
+            success_status: StatusCode::OK,
+            failure_status: StatusCode::BAD_REQUEST,
+            messages: Arc::new(HashMap::new()),
+            header_policy: None,
+            #[cfg(feature = "decompression")]
+            decompressed_body_limit: DECOMPRESSED_BODY_LIMIT_DEFAULT,
+        }
+    }
+
+    /// Get the current global configuration,
+    /// shared without cloning the configuration itself.
+    ///
+    /// Returns the default configuration if no configuration is set.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// let config: Arc<JsonResponseConfig> = JsonResponseConfig::get();
+    /// ```
+    pub fn get() -> Arc<Self> {
+        CONFIG.load_full().unwrap_or_else(|| CONFIG_DEFAULT.clone())
+    }
+
+    /// Set the maximum length of error messages in characters.
+    ///
+    /// Messages longer than the limit will be truncated
+    /// with the ellipsis appended within the limit.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// let config: JsonResponseConfig = JsonResponseConfig::new()
+    ///     .message_max_length(256);
+    /// ```
+    pub fn message_max_length(
+        mut self,
+        length: usize,
+    ) -> Self {
+        self.message_max_length = Some(length);
+
+        self
+    }
+
+    /// Set the ellipsis appended to truncated error messages,
+    /// [`MESSAGE_ELLIPSIS_DEFAULT`] by default.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// let config: JsonResponseConfig = JsonResponseConfig::new()
+    ///     .message_max_length(256)
+    ///     .message_ellipsis("…");
+    /// ```
+    pub fn message_ellipsis<E: Into<String>>(
+        mut self,
+        ellipsis: E,
+    ) -> Self {
+        self.message_ellipsis = ellipsis.into();
+
+        self
+    }
+
+    /// Set the format of the serialized envelope.
+    ///
+    /// The format of a response set with
+    /// [`CreateJsonResponse::with_format`](crate::response::json::CreateJsonResponse::with_format)
+    /// takes precedence over this.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::{
+    ///     JsonResponseConfig,
+    ///     ResponseFormat,
+    ///     ResponseFormatParts,
+    /// };
+    /// use serde_json::{Value, json};
+    ///
+    /// struct MyFormat;
+    ///
+    /// impl ResponseFormat for MyFormat {
+    ///     fn format(&self, parts: ResponseFormatParts) -> Value {
+    ///         json!({ "ok": parts.success, "result": parts.data })
+    ///     }
+    /// }
+    ///
+    /// JsonResponseConfig::new().format(MyFormat).set();
+    /// ```
+    pub fn format<F: ResponseFormat>(
+        mut self,
+        format: F,
+    ) -> Self {
+        self.format = Some(ResponseFormatRef::new(format));
+
+        self
+    }
+
+    /// Set the status code of failure responses
+    /// when the request body cannot be read,
+    /// such as the client is disconnected,
+    /// `400 Bad Request` by default.
+    ///
+    /// The errors have [`BodyRead`](crate::response::json::ResponseError::BodyRead) code,
+    /// to be separated from the [`Parse`](crate::response::json::ResponseError::Parse) errors
+    /// of malformed payloads.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new()
+    ///     .body_read_status(StatusCode::REQUEST_TIMEOUT)
+    ///     .set();
+    /// ```
+    pub fn body_read_status<S: Into<StatusCode>>(
+        mut self,
+        status: S,
+    ) -> Self {
+        self.body_read_status = status.into();
+
+        self
+    }
+
+    /// Set the default status code of success responses,
+    /// `200 OK` by default.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new()
+    ///     .success_status(StatusCode::OK)
+    ///     .set();
+    /// ```
+    pub fn success_status<S: Into<StatusCode>>(
+        mut self,
+        status: S,
+    ) -> Self {
+        self.success_status = status.into();
+
+        self
+    }
+
+    /// Set the default status code of failure responses,
+    /// `400 Bad Request` by default.
+    ///
+    /// Failure responses from extractors and layers
+    /// keep their own status codes.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new()
+    ///     .failure_status(StatusCode::UNPROCESSABLE_ENTITY)
+    ///     .set();
+    /// ```
+    pub fn failure_status<S: Into<StatusCode>>(
+        mut self,
+        status: S,
+    ) -> Self {
+        self.failure_status = status.into();
+
+        self
+    }
+
+    /// Set the message of errors with a code,
+    /// replacing the messages from handlers, extractors and layers,
+    /// such as user-facing text for the rejections.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::{JsonResponseConfig, ResponseError};
+    ///
+    /// JsonResponseConfig::new()
+    ///     .message(ResponseError::Parse.as_code(), "Invalid request")
+    ///     .set();
+    /// ```
+    pub fn message<C: Into<String>, M: Into<String>>(
+        mut self,
+        code: C,
+        message: M,
+    ) -> Self {
+        Arc::make_mut(&mut self.messages).insert(code.into(), message.into());
+
+        self
+    }
+
+    /// Set the policy of the headers set on JSON responses,
+    /// such as dropping the `server` header or the hop-by-hop headers.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::{HeaderPolicy, JsonResponseConfig};
+    ///
+    /// JsonResponseConfig::new()
+    ///     .header_policy(HeaderPolicy::new().deny(["server"]))
+    ///     .set();
+    /// ```
+    pub fn header_policy(
+        mut self,
+        policy: HeaderPolicy,
+    ) -> Self {
+        self.header_policy = Some(policy);
+
+        self
+    }
+
+    /// Set the maximum size of decompressed request bodies in bytes,
+    /// [`DECOMPRESSED_BODY_LIMIT_DEFAULT`] by default,
+    /// available with `decompression` feature.
+    ///
+    /// `Json`, `Form` and `Multipart` extractors decompress
+    /// request bodies with `gzip` or `deflate` content encoding,
+    /// `br` with `decompression_br` feature
+    /// and `zstd` with `decompression_zstd` feature,
+    /// as a stream while reading the body,
+    /// and reject bodies decompressed beyond the limit
+    /// with `413 Payload Too Large` failure responses
+    /// of [`Encoding`](crate::response::json::ResponseError::Encoding) code.
+    ///
+    /// The limit replaces
+    /// [`DefaultBodyLimit`](axum::extract::DefaultBodyLimit)
+    /// for the decompressed body,
+    /// while the encoded body is still limited by `DefaultBodyLimit`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new()
+    ///     .decompressed_body_limit(16 * 1024 * 1024)
+    ///     .set();
+    /// ```
+    #[cfg(feature = "decompression")]
+    pub fn decompressed_body_limit(
+        mut self,
+        bytes: usize,
+    ) -> Self {
+        self.decompressed_body_limit = bytes;
+
+        self
+    }
+
+    /// Set the messages of errors by code,
+    /// replacing all messages set before.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new()
+    ///     .messages([
+    ///         ("parse", "Invalid request"),
+    ///         ("server", "Something went wrong"),
+    ///     ])
+    ///     .set();
+    /// ```
+    pub fn messages<I, C, M>(
+        mut self,
+        messages: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (C, M)>,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.messages = Arc::new(
+            messages
+                .into_iter()
+                .map(|(code, message)| (code.into(), message.into()))
+                .collect(),
+        );
+
+        self
+    }
+
+    /// Apply the configuration globally.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::new()
+    ///     .message_max_length(256)
+    ///     .set();
+    /// ```
+    pub fn set(self) {
+        let _write =
+            CONFIG_WRITE.lock().unwrap_or_else(PoisonError::into_inner);
+
+        CONFIG.store(Some(Arc::new(self)));
+    }
+
+    /// Update the global configuration in place,
+    /// such as changing the messages at runtime
+    /// without redeploying services.
+    ///
+    /// Responses created afterwards use the updated configuration,
+    /// while responses being created keep the previous one.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::update(|config| {
+    ///     config.message("server", "Please try again later")
+    /// });
+    /// ```
+    pub fn update<F: FnOnce(Self) -> Self>(f: F) {
+        let _write =
+            CONFIG_WRITE.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let current: Self = Self::get().as_ref().clone();
+
+        CONFIG.store(Some(Arc::new(f(current))));
+    }
+
+    /// Reload the messages of the global configuration
+    /// from a JSON file of messages by code:
+    ///
+    /// ```jsonc
+    /// {
+    ///     "parse": "Invalid request",
+    ///     "server": "Something went wrong"
+    /// }
+    /// ```
+    ///
+    /// The global configuration is kept
+    /// if the file cannot be read or parsed,
+    /// which can be called when the file changes,
+    /// such as from a signal handler,
+    /// or see [`watch_messages`](Self::watch_messages).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// if let Err(err) = JsonResponseConfig::reload_messages("messages.json") {
+    ///     eprintln!("failed to reload messages: {err}");
+    /// }
+    /// ```
+    pub fn reload_messages<P: AsRef<Path>>(path: P) -> std::io::Result<()> {
+        let content: String = std::fs::read_to_string(path)?;
+
+        let messages: HashMap<String, String> =
+            serde_json::from_str(&content).map_err(std::io::Error::other)?;
+
+        Self::update(|config| config.messages(messages));
+
+        Ok(())
+    }
+
+    /// Watch a JSON file of messages by code,
+    /// reloading the messages of the global configuration
+    /// with [`reload_messages`](Self::reload_messages)
+    /// when the modified time of the file changes,
+    /// available with `messages_watch` feature.
+    ///
+    /// The file is checked once per interval,
+    /// and loaded on the first check if it exists.
+    /// The global configuration is kept
+    /// if the file is missing or cannot be parsed.
+    ///
+    /// The watch stops when the returned task is aborted.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// # async fn run() {
+    /// let watch = JsonResponseConfig::watch_messages(
+    ///     "messages.json",
+    ///     Duration::from_secs(5),
+    /// );
+    ///
+    /// // stop watching
+    /// watch.abort();
+    /// # }
+    /// ```
+    #[cfg(feature = "messages_watch")]
+    pub fn watch_messages<P: Into<PathBuf>>(
+        path: P,
+        interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let path: PathBuf = path.into();
+
+        tokio::spawn(async move {
+            let mut ticker: tokio::time::Interval =
+                tokio::time::interval(interval);
+
+            let mut modified: Option<SystemTime> = None;
+
+            loop {
+                ticker.tick().await;
+
+                let Ok(current) =
+                    std::fs::metadata(&path).and_then(|meta| meta.modified())
+                else {
+                    continue;
+                };
+
+                if modified == Some(current) {
+                    continue;
+                }
+
+                modified = Some(current);
+
+                let result: std::io::Result<()> = Self::reload_messages(&path);
+
+                #[cfg(feature = "tracing")]
+                if let Err(err) = result {
+                    tracing::warn!(
+                        path = %path.display(),
+                        error = %err,
+                        "failed to reload messages"
+                    );
+                }
+
+                #[cfg(not(feature = "tracing"))]
+                let _ = result;
+            }
+        })
+    }
+
+    /// Reset the global configuration to default.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponseConfig;
+    ///
+    /// JsonResponseConfig::reset();
+    /// ```
+    pub fn reset() {
+        let _write =
+            CONFIG_WRITE.lock().unwrap_or_else(PoisonError::into_inner);
+
+        CONFIG.store(None);
+    }
+}
+
+impl Default for JsonResponseConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get the prefix of a message with a length in characters.
+fn take_chars_fn(
+    message: &str,
+    length: usize,
+) -> &str {
+    match message.char_indices().nth(length) {
+        | Some((index, _)) => &message[..index],
+        | None => message,
+    }
+}
+
+/// Truncate a message to the maximum length in characters,
+/// including the ellipsis appended,
+/// or without the ellipsis if it is longer than the maximum length.
+///
+/// Returns `None` if the message is not truncated.
+pub(crate) fn truncate_message(
+    message: &str,
+    max_length: usize,
+    ellipsis: &str,
+) -> Option<String> {
+    message.chars().nth(max_length)?;
+
+    match max_length.checked_sub(ellipsis.chars().count()) {
+        | Some(length) => {
+            Some(format!("{}{}", take_chars_fn(message, length), ellipsis))
+        },
+        | None => Some(take_chars_fn(message, max_length).to_string()),
+    }
+}
@@ -1,89 +1,994 @@
+use std::sync::Arc;
+
+use axum::body::Bytes;
 use axum_core::{body::Body, response::Response};
-use http::{HeaderMap, HeaderValue, StatusCode, header, response::Builder};
+use http::{
+    HeaderMap, HeaderName, HeaderValue, StatusCode, Version, header,
+    response::Builder,
+};
 use serde::Serialize;
+use serde_json::Value;
 
-use crate::response::json::{
-    create::JsonResponseState,
-    error::{FAILURE_RESPONSE_DEFAULT, JsonResponseError, ResponseError},
-    response::JsonResponse,
+#[cfg(feature = "etag")]
+use crate::extract::etag::{IfNoneMatch, create_etag_fn};
+#[cfg(feature = "request_id")]
+use crate::extract::request_id::{
+    REQUEST_ID_HEADER, REQUEST_ID_META_KEY, current_request_id_fn,
+};
+#[cfg(feature = "schema_version")]
+use crate::extract::schema_version::{SCHEMA_VERSION_HEADER, SchemaVersion};
+#[cfg(feature = "error_shadow")]
+use crate::layers::error_shadow::FailureErrors;
+#[cfg(feature = "payload_sampling")]
+use crate::layers::payload_sampling::{
+    PayloadSamplingContext, current_payload_sampling_fn,
+};
+#[cfg(feature = "etag")]
+use crate::response::json::create::ETagMode;
+#[cfg(feature = "incident_id")]
+use crate::response::json::incident::{
+    INCIDENT_ID_HEADER, INCIDENT_ID_META_KEY, IncidentId,
+};
+#[cfg(feature = "rejection_config")]
+use crate::response::json::rejection::apply_rejection_config_fn;
+#[cfg(all(feature = "schema_check", debug_assertions))]
+use crate::response::json::schema::check_data_fn;
+#[cfg(feature = "spill")]
+use crate::response::json::spill::SpillWriter;
+#[cfg(feature = "jsonapi")]
+use crate::response::jsonapi::{
+    CONTENT_TYPE_JSONAPI, FAILURE_RESPONSE_JSONAPI_DEFAULT, JsonApiFormat,
+};
+use crate::response::{
+    cache_control::CacheControl,
+    json::{
+        config::{JsonResponseConfig, truncate_message},
+        create::{JsonResponseState, ResponseEncoding},
+        error::{FAILURE_RESPONSE_DEFAULT, JsonResponseError, ResponseError},
+        format::{ResponseFormatParts, ResponseFormatRef},
+        header_policy::HeaderPolicy,
+        problem::{CONTENT_TYPE_PROBLEM_JSON, ProblemDetails},
+        response::JsonResponse,
+    },
+};
+#[cfg(feature = "cbor")]
+use crate::response::{
+    cbor::CONTENT_TYPE_CBOR, json::error::FAILURE_RESPONSE_CBOR_DEFAULT,
+};
+#[cfg(feature = "msgpack")]
+use crate::response::{
+    json::error::FAILURE_RESPONSE_MSGPACK_DEFAULT,
+    msgpack::CONTENT_TYPE_MSGPACK,
+};
+#[cfg(feature = "xml")]
+use crate::response::{
+    json::error::FAILURE_RESPONSE_XML_DEFAULT,
+    xml::{CONTENT_TYPE_XML, XML_DECLARATION, XML_ROOT},
+};
+#[cfg(feature = "i18n")]
+use crate::{
+    extract::locale::Locale,
+    response::json::i18n::{localize_errors_fn, take_locale_fn},
 };
 
 /// JSON content type.
-const CONTENT_TYPE_JSON: &str = "application/json";
+pub(crate) const CONTENT_TYPE_JSON: &str = "application/json";
 
-pub fn create_json_response_fn<D: Serialize>(
-    state: JsonResponseState<D>
+/// Default capacity reserved for the response body in bytes.
+pub(crate) const BODY_CAPACITY_DEFAULT: usize = 128;
+
+/// Truncate the messages of errors that exceed the maximum length.
+fn truncate_errors(
+    errors: Vec<JsonResponseError>,
+    max_length: usize,
+    ellipsis: &str,
+) -> Vec<JsonResponseError> {
+    errors
+        .into_iter()
+        .map(|mut error| {
+            if let Some(message) = &error.message {
+                if let Some(truncated) =
+                    truncate_message(message, max_length, ellipsis)
+                {
+                    // keep the full message in logs
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        code = %error.code,
+                        path = ?error.path,
+                        message = %message,
+                        "error message truncated"
+                    );
+
+                    error.message = Some(truncated);
+                }
+            }
+
+            error
+        })
+        .collect()
+}
+
+/// Get the content type of an encoding.
+fn content_type_fn(encoding: ResponseEncoding) -> &'static str {
+    match encoding {
+        | ResponseEncoding::Json => CONTENT_TYPE_JSON,
+        #[cfg(feature = "jsonapi")]
+        | ResponseEncoding::JsonApi => CONTENT_TYPE_JSONAPI,
+        #[cfg(feature = "cbor")]
+        | ResponseEncoding::Cbor => CONTENT_TYPE_CBOR,
+        #[cfg(feature = "msgpack")]
+        | ResponseEncoding::Msgpack => CONTENT_TYPE_MSGPACK,
+        #[cfg(feature = "xml")]
+        | ResponseEncoding::Xml => CONTENT_TYPE_XML,
+    }
+}
+
+/// Encode a value as JSON into a buffer with the capacity reserved.
+fn encode_json_fn<T: Serialize>(
+    value: &T,
+    capacity: usize,
+) -> Option<Vec<u8>> {
+    let mut buf: Vec<u8> = Vec::with_capacity(capacity);
+
+    serde_json::to_writer(&mut buf, value).ok()?;
+
+    Some(buf)
+}
+
+/// Encode a value as CBOR into a buffer with the capacity reserved.
+#[cfg(feature = "cbor")]
+fn encode_cbor_fn<T: Serialize>(
+    value: &T,
+    capacity: usize,
+) -> Option<Vec<u8>> {
+    let mut buf: Vec<u8> = Vec::with_capacity(capacity);
+
+    ciborium::into_writer(value, &mut buf).ok()?;
+
+    Some(buf)
+}
+
+/// Encode a value as MessagePack into a buffer with the capacity reserved,
+/// with the struct fields as map keys.
+#[cfg(feature = "msgpack")]
+fn encode_msgpack_fn<T: Serialize>(
+    value: &T,
+    capacity: usize,
+) -> Option<Vec<u8>> {
+    let mut buf: Vec<u8> = Vec::with_capacity(capacity);
+
+    rmp_serde::encode::write_named(&mut buf, value).ok()?;
+
+    Some(buf)
+}
+
+/// Encode a value as an XML document with a `response` root element
+/// into a buffer with the capacity reserved.
+#[cfg(feature = "xml")]
+fn encode_xml_fn<T: Serialize>(
+    value: &T,
+    capacity: usize,
+) -> Option<Vec<u8>> {
+    let mut buf: String = String::with_capacity(capacity);
+
+    buf.push_str(XML_DECLARATION);
+
+    quick_xml::se::to_writer_with_root(&mut buf, XML_ROOT, value).ok()?;
+
+    Some(buf.into_bytes())
+}
+
+/// Encode a value with an encoding,
+/// with the capacity reserved for the output.
+fn encode_fn<T: Serialize>(
+    value: &T,
+    encoding: ResponseEncoding,
+    capacity: usize,
+) -> Option<Vec<u8>> {
+    match encoding {
+        | ResponseEncoding::Json => encode_json_fn(value, capacity),
+        #[cfg(feature = "jsonapi")]
+        | ResponseEncoding::JsonApi => encode_json_fn(value, capacity),
+        #[cfg(feature = "cbor")]
+        | ResponseEncoding::Cbor => encode_cbor_fn(value, capacity),
+        #[cfg(feature = "msgpack")]
+        | ResponseEncoding::Msgpack => encode_msgpack_fn(value, capacity),
+        #[cfg(feature = "xml")]
+        | ResponseEncoding::Xml => encode_xml_fn(value, capacity),
+    }
+}
+
+/// Serialize a JSON response, with the format if any.
+fn serialize_fn<D: Serialize>(
+    res: JsonResponse<D>,
+    format: Option<&ResponseFormatRef>,
+    encoding: ResponseEncoding,
+    capacity: usize,
+) -> Option<Vec<u8>> {
+    // JSON:API document takes precedence over the format
+    #[cfg(feature = "jsonapi")]
+    let jsonapi: Option<ResponseFormatRef> = (encoding
+        == ResponseEncoding::JsonApi)
+        .then(|| ResponseFormatRef::new(JsonApiFormat));
+
+    #[cfg(feature = "jsonapi")]
+    let format: Option<&ResponseFormatRef> = jsonapi.as_ref().or(format);
+
+    let format: &ResponseFormatRef = match format {
+        | Some(format) => format,
+        | None => return encode_fn(&res, encoding, capacity),
+    };
+
+    let data: Option<Value> = match res.data {
+        | Some(data) => Some(serde_json::to_value(data).ok()?),
+        | None => None,
+    };
+
+    let value: Value = format.0.format(ResponseFormatParts {
+        success: res.success,
+        data,
+        errors: res.errors,
+        meta: res.meta,
+    });
+
+    encode_fn(&value, encoding, capacity)
+}
+
+/// Create a failure response for an invalid response state.
+fn create_state_failure_fn(
+    status: StatusCode,
+    error: JsonResponseError,
+    format: Option<&ResponseFormatRef>,
+    encoding: ResponseEncoding,
+    server_error: Response,
 ) -> Response {
-    // a server error that supposed to be always work
-    let server_error: Response = Response::builder()
+    // create error
+    let res: JsonResponse = JsonResponse::new().success(false).errors([error]);
+
+    // parse body
+    let body: Vec<u8> =
+        match serialize_fn(res, format, encoding, BODY_CAPACITY_DEFAULT) {
+            | Some(body) => body,
+            | None => return server_error,
+        };
+
+    match Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, content_type_fn(encoding))
+        .body(Body::from(Bytes::from(body)))
+    {
+        | Ok(res) => res,
+        | Err(_) => server_error,
+    }
+}
+
+/// Create a server error that supposed to be always work.
+fn create_server_error_fn(encoding: ResponseEncoding) -> Response {
+    let body: Body = match encoding {
+        | ResponseEncoding::Json => Body::from(FAILURE_RESPONSE_DEFAULT),
+        #[cfg(feature = "jsonapi")]
+        | ResponseEncoding::JsonApi => {
+            Body::from(FAILURE_RESPONSE_JSONAPI_DEFAULT)
+        },
+        #[cfg(feature = "cbor")]
+        | ResponseEncoding::Cbor => Body::from(FAILURE_RESPONSE_CBOR_DEFAULT),
+        #[cfg(feature = "msgpack")]
+        | ResponseEncoding::Msgpack => {
+            Body::from(FAILURE_RESPONSE_MSGPACK_DEFAULT)
+        },
+        #[cfg(feature = "xml")]
+        | ResponseEncoding::Xml => Body::from(FAILURE_RESPONSE_XML_DEFAULT),
+    };
+
+    Response::builder()
         .status(StatusCode::INTERNAL_SERVER_ERROR)
-        .header(header::CONTENT_TYPE, CONTENT_TYPE_JSON)
-        .body(Body::from(FAILURE_RESPONSE_DEFAULT.to_string()))
-        .unwrap();
+        .header(header::CONTENT_TYPE, content_type_fn(encoding))
+        .body(body)
+        .unwrap()
+}
 
+/// Create a failure response if the response state is invalid.
+fn check_state_fn<D>(
+    state: &JsonResponseState<D>,
+    format: Option<&ResponseFormatRef>,
+) -> Option<Response> {
     // header map error
     if state.is_header_map_failed {
-        // create error
-        let res: JsonResponse<D> = JsonResponse::new().success(false).errors([
+        return Some(create_state_failure_fn(
+            StatusCode::BAD_REQUEST,
             JsonResponseError::new()
                 .code(ResponseError::Parse.to_code())
                 .path(["response", "header_map"])
                 .message("Failed to create header map."),
-        ]);
+            format,
+            state.encoding,
+            create_server_error_fn(state.encoding),
+        ));
+    }
 
-        // parse body
-        let body: String = match serde_json::to_string(&res) {
-            | Ok(body) => body,
-            | Err(_) => return server_error,
-        };
+    // meta error
+    if state.is_meta_failed {
+        return Some(create_state_failure_fn(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            JsonResponseError::new()
+                .code(ResponseError::Server.to_code())
+                .path(["response", "meta"])
+                .message("Failed to serialize meta."),
+            format,
+            state.encoding,
+            create_server_error_fn(state.encoding),
+        ));
+    }
 
-        return match Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .header(header::CONTENT_TYPE, CONTENT_TYPE_JSON)
-            .body(Body::from(body))
-        {
-            | Ok(res) => res,
-            | Err(_) => server_error,
-        };
+    None
+}
+
+/// Set the `Cache-Control` header of the state,
+/// replacing the header set by hand.
+fn apply_cache_control_fn<D>(state: &mut JsonResponseState<D>) {
+    let cache_control: CacheControl = match state.cache_control.take() {
+        | Some(cache_control) if !cache_control.is_empty() => cache_control,
+        | _ => return,
+    };
+
+    // directives are always valid header values
+    if let Ok(value) = HeaderValue::from_str(&cache_control.to_string()) {
+        state.header_map.insert(header::CACHE_CONTROL, value);
     }
+}
 
-    // create response builder
+/// Apply the global header policy to the headers of the state,
+/// and create a failure response if a header is rejected.
+fn apply_header_policy_fn<D>(
+    state: &mut JsonResponseState<D>,
+    config: &JsonResponseConfig,
+    format: Option<&ResponseFormatRef>,
+) -> Option<Response> {
+    let policy: &HeaderPolicy = config.header_policy.as_ref()?;
+
+    match policy.apply(&mut state.header_map) {
+        | Ok(()) => None,
+        | Err(name) => Some(create_state_failure_fn(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            JsonResponseError::from(ResponseError::Server)
+                .path(["response", "header", name.as_str()])
+                .message("Header is not allowed"),
+            format,
+            state.encoding,
+            create_server_error_fn(state.encoding),
+        )),
+    }
+}
+
+/// Create a response builder with status, version and headers,
+/// and the content type if any.
+fn create_builder_fn(
+    status: StatusCode,
+    version: Version,
+    mut header_map: HeaderMap,
+    content_type: Option<&'static str>,
+) -> Builder {
     let mut builder: Builder =
-        Response::builder().status(state.status).version(state.version);
+        Response::builder().status(status).version(version);
 
     // set content type
-    let mut header_map: HeaderMap = state.header_map;
+    if let Some(content_type) = content_type {
+        header_map.append(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(content_type),
+        );
+    }
 
-    header_map.append(
-        header::CONTENT_TYPE,
-        match HeaderValue::from_str(CONTENT_TYPE_JSON) {
-            | Ok(value) => value,
-            | Err(_) => return server_error,
-        },
-    );
+    // push headers, where repeated values of a header,
+    // such as `Set-Cookie`, come without the name
+    let mut name: Option<HeaderName> = None;
 
-    // push headers
     for (header, value) in header_map {
-        if let Some(header) = header {
-            builder = builder.header(header, value);
+        if header.is_some() {
+            name = header;
+        }
+
+        if let Some(name) = &name {
+            builder = builder.header(name, value);
         }
     }
 
+    builder
+}
+
+/// Replace error messages with the global messages by code,
+/// and truncate error messages with the response or global maximum length.
+fn create_errors_fn(
+    mut errors: Vec<JsonResponseError>,
+    message_max_length: Option<usize>,
+    config: &JsonResponseConfig,
+) -> Vec<JsonResponseError> {
+    if !config.messages.is_empty() {
+        for error in errors.iter_mut() {
+            if let Some(message) = config.messages.get(&error.code) {
+                error.message = Some(message.clone());
+            }
+        }
+    }
+
+    match message_max_length.or(config.message_max_length) {
+        | Some(max_length) => {
+            truncate_errors(errors, max_length, &config.message_ellipsis)
+        },
+        | None => errors,
+    }
+}
+
+/// Set the request ID of the state or the request being handled
+/// into the meta and the header.
+#[cfg(feature = "request_id")]
+fn apply_request_id_fn<D>(
+    mut state: JsonResponseState<D>
+) -> JsonResponseState<D> {
+    let id: String =
+        match state.request_id.take().or_else(current_request_id_fn) {
+            | Some(id) => id,
+            | None => return state,
+        };
+
+    if !state.header_map.contains_key(REQUEST_ID_HEADER) {
+        if let Ok(value) = HeaderValue::from_str(&id) {
+            state
+                .header_map
+                .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+        }
+    }
+
+    if !state.is_meta_failed {
+        state
+            .meta
+            .get_or_insert_with(serde_json::Map::new)
+            .entry(REQUEST_ID_META_KEY)
+            .or_insert(Value::String(id));
+    }
+
+    state
+}
+
+/// Set an incident ID into the meta and the header
+/// of a failure response with a server error status,
+/// reusing the ID in the meta if any.
+#[cfg(feature = "incident_id")]
+fn apply_incident_id_fn<D>(
+    mut state: JsonResponseState<D>
+) -> JsonResponseState<D> {
+    if state.success || !state.status.is_server_error() {
+        return state;
+    }
+
+    let id: String = match state
+        .meta
+        .as_ref()
+        .and_then(|meta| meta.get(INCIDENT_ID_META_KEY))
+        .and_then(Value::as_str)
+    {
+        | Some(id) => id.to_string(),
+        | None => IncidentId::new().to_string(),
+    };
+
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        state
+            .header_map
+            .insert(HeaderName::from_static(INCIDENT_ID_HEADER), value);
+    }
+
+    // correlate the logs with the reports of users
+    #[cfg(feature = "tracing")]
+    tracing::error!(
+        incident_id = %id,
+        status = %state.status,
+        codes = ?state.errors.iter().map(|e| &e.code).collect::<Vec<_>>(),
+        "server error response"
+    );
+
+    if !state.is_meta_failed {
+        state
+            .meta
+            .get_or_insert_with(serde_json::Map::new)
+            .insert(INCIDENT_ID_META_KEY.to_string(), Value::String(id));
+    }
+
+    state
+}
+
+/// Take the pinned schema version of the state,
+/// and set the version into the header.
+#[cfg(feature = "schema_version")]
+fn take_schema_version_fn<D>(
+    mut state: JsonResponseState<D>
+) -> (JsonResponseState<D>, Option<SchemaVersion>) {
+    let version: Option<SchemaVersion> = state.schema_version.take();
+
+    if let Some(version) = &version {
+        state.header_map.insert(
+            HeaderName::from_static(SCHEMA_VERSION_HEADER),
+            HeaderValue::from(version.version()),
+        );
+    }
+
+    (state, version)
+}
+
+/// Take the entity tag and the `if-none-match` header of the state,
+/// only used for success statuses.
+#[cfg(feature = "etag")]
+fn take_etag_fn<D>(state: &mut JsonResponseState<D>) -> Option<ETag> {
+    let mode: Option<ETagMode> = state.etag.take();
+    let if_none_match: Option<IfNoneMatch> = state.if_none_match.take();
+
+    match state.status.is_success() {
+        | true => mode.map(|mode| ETag { mode, if_none_match }),
+        | false => None,
+    }
+}
+
+/// Create a `304 Not Modified` response with an empty body,
+/// keeping the headers except the content type.
+#[cfg(feature = "etag")]
+fn create_not_modified_fn(
+    mut builder: Builder,
+    tag: HeaderValue,
+    server_error: Response,
+) -> Response {
+    if let Some(headers) = builder.headers_mut() {
+        headers.remove(header::CONTENT_TYPE);
+    }
+
+    match builder
+        .status(StatusCode::NOT_MODIFIED)
+        .header(header::ETAG, tag)
+        .body(Body::empty())
+    {
+        | Ok(res) => res,
+        | Err(_) => server_error,
+    }
+}
+
+/// Create a sample of the success data
+/// if the response is sampled by the layer.
+#[cfg(feature = "payload_sampling")]
+fn create_payload_sample_fn<D: Serialize>(
+    state: &JsonResponseState<D>
+) -> Option<PayloadSampleDraft> {
+    let data: &D = state.data.as_ref().filter(|_| state.success)?;
+
+    let context: PayloadSamplingContext = current_payload_sampling_fn()?;
+
+    if !context.is_sampled() {
+        return None;
+    }
+
+    let items: Option<usize> = serde_json::to_value(data)
+        .ok()
+        .and_then(|data| data.as_array().map(Vec::len));
+
+    Some(PayloadSampleDraft {
+        context,
+        data_type: std::any::type_name::<D>(),
+        items,
+    })
+}
+
+pub fn create_json_response_fn<D: Serialize>(
+    state: JsonResponseState<D>
+) -> Response {
+    let config: Arc<JsonResponseConfig> = JsonResponseConfig::get();
+
+    let format: Option<ResponseFormatRef> =
+        state.format.clone().or(config.format.clone());
+
+    // invalid state
+    if let Some(res) = check_state_fn(&state, format.as_ref()) {
+        return res;
+    }
+
+    // rejection overrides
+    #[cfg(feature = "rejection_config")]
+    let state: JsonResponseState<D> = apply_rejection_config_fn(state);
+
+    // locale
+    #[cfg(feature = "i18n")]
+    let (state, locale): (JsonResponseState<D>, Option<Locale>) =
+        take_locale_fn(state);
+
+    // request id
+    #[cfg(feature = "request_id")]
+    let state: JsonResponseState<D> = apply_request_id_fn(state);
+
+    // incident id
+    #[cfg(feature = "incident_id")]
+    let state: JsonResponseState<D> = apply_incident_id_fn(state);
+
+    // schema version
+    #[cfg(feature = "schema_version")]
+    let (state, schema_version): (
+        JsonResponseState<D>,
+        Option<SchemaVersion>,
+    ) = take_schema_version_fn(state);
+
+    // header policy
+    let mut state: JsonResponseState<D> = state;
+
+    apply_cache_control_fn(&mut state);
+
+    if let Some(res) =
+        apply_header_policy_fn(&mut state, &config, format.as_ref())
+    {
+        return res;
+    }
+
+    // entity tag
+    #[cfg(feature = "etag")]
+    let etag: Option<ETag> = take_etag_fn(&mut state);
+
+    // payload sample
+    #[cfg(feature = "payload_sampling")]
+    let sample: Option<PayloadSampleDraft> = create_payload_sample_fn(&state);
+
+    let server_error: Response = create_server_error_fn(state.encoding);
+
+    // create response builder
+    let builder: Builder = create_builder_fn(
+        state.status,
+        state.version,
+        state.header_map,
+        Some(content_type_fn(state.encoding)),
+    );
+
+    // truncate error messages
+    let errors: Vec<JsonResponseError> =
+        create_errors_fn(state.errors, state.message_max_length, &config);
+
+    // localize error messages
+    #[cfg(feature = "i18n")]
+    let errors: Vec<JsonResponseError> =
+        localize_errors_fn(errors, locale.as_ref());
+
+    // validate data against its schema in debug builds
+    #[cfg(all(feature = "schema_check", debug_assertions))]
+    if let Some(data) = &state.data {
+        check_data_fn(data);
+    }
+
     // create response
     let res: JsonResponse<D> = JsonResponse {
         success: state.success,
         data: state.data,
-        errors: state.errors,
+        errors,
+        meta: state.meta,
+        status: None,
     };
 
+    // errors for the `ErrorShadow` layer
+    #[cfg(feature = "error_shadow")]
+    let failure_errors: Option<FailureErrors> =
+        (!res.success).then(|| FailureErrors(res.errors.clone()));
+
     // parse body
-    let body: String = match serde_json::to_string(&res) {
-        | Ok(body) => body,
-        | Err(_) => return server_error,
+    let body: BodyOptions = BodyOptions {
+        format,
+        encoding: state.encoding,
+        capacity: state.size_hint.unwrap_or(BODY_CAPACITY_DEFAULT),
+        #[cfg(feature = "spill")]
+        spill_threshold: state.spill_threshold,
+        #[cfg(feature = "etag")]
+        etag,
+        #[cfg(feature = "payload_sampling")]
+        sample,
     };
 
+    // migrate data to the pinned schema version
+    #[cfg(feature = "schema_version")]
+    if let Some(version) = schema_version.filter(|v| !v.is_current()) {
+        let data: Option<Value> = match res.data.map(serde_json::to_value) {
+            | Some(Ok(data)) => Some(version.migrate(data)),
+            | Some(Err(_)) => return server_error,
+            | None => None,
+        };
+
+        let res: JsonResponse<Value> = JsonResponse {
+            success: res.success,
+            data,
+            errors: res.errors,
+            meta: res.meta,
+            status: None,
+        };
+
+        let res: Response = create_body_fn(res, builder, body, server_error);
+
+        #[cfg(feature = "error_shadow")]
+        let res: Response = attach_failure_errors_fn(res, failure_errors);
+
+        return res;
+    }
+
+    let res: Response = create_body_fn(res, builder, body, server_error);
+
+    #[cfg(feature = "error_shadow")]
+    let res: Response = attach_failure_errors_fn(res, failure_errors);
+
+    res
+}
+
+/// Attach the errors of a failure response for the `ErrorShadow` layer.
+#[cfg(feature = "error_shadow")]
+fn attach_failure_errors_fn(
+    mut res: Response,
+    errors: Option<FailureErrors>,
+) -> Response {
+    if let Some(errors) = errors {
+        res.extensions_mut().insert(errors);
+    }
+
+    res
+}
+
+/// Options for serializing the body of a response.
+struct BodyOptions {
+    format: Option<ResponseFormatRef>,
+    encoding: ResponseEncoding,
+    capacity: usize,
+    #[cfg(feature = "spill")]
+    spill_threshold: Option<usize>,
+    #[cfg(feature = "etag")]
+    etag: Option<ETag>,
+    #[cfg(feature = "payload_sampling")]
+    sample: Option<PayloadSampleDraft>,
+}
+
+/// Sample of a response waiting for the size of the body.
+#[cfg(feature = "payload_sampling")]
+struct PayloadSampleDraft {
+    context: PayloadSamplingContext,
+    data_type: &'static str,
+    items: Option<usize>,
+}
+
+#[cfg(feature = "spill")]
+impl BodyOptions {
+    /// Check whether the body is plain JSON to be spilled,
+    /// except tagged bodies hashed in memory.
+    fn is_spillable(&self) -> bool {
+        #[cfg(feature = "etag")]
+        if self.etag.is_some() {
+            return false;
+        }
+
+        self.format.is_none() && self.encoding == ResponseEncoding::Json
+    }
+}
+
+/// Entity tag of a response with the `if-none-match` header of the request.
+#[cfg(feature = "etag")]
+struct ETag {
+    mode: ETagMode,
+    if_none_match: Option<IfNoneMatch>,
+}
+
+#[cfg(feature = "etag")]
+impl ETag {
+    /// Check whether the entity tag matches the `if-none-match` header.
+    fn is_not_modified(
+        &self,
+        tag: &str,
+    ) -> bool {
+        self.if_none_match
+            .as_ref()
+            .is_some_and(|if_none_match| if_none_match.matches(tag))
+    }
+}
+
+/// Serialize a JSON response into the body of the response builder.
+fn create_body_fn<D: Serialize>(
+    res: JsonResponse<D>,
+    builder: Builder,
+    options: BodyOptions,
+    server_error: Response,
+) -> Response {
+    // skip serialization when the given entity tag is not modified
+    #[cfg(feature = "etag")]
+    if let Some(etag) = &options.etag {
+        if let ETagMode::Value(tag) = &etag.mode {
+            if etag.is_not_modified(tag) {
+                return match HeaderValue::from_str(tag) {
+                    | Ok(tag) => {
+                        create_not_modified_fn(builder, tag, server_error)
+                    },
+                    | Err(_) => server_error,
+                };
+            }
+        }
+    }
+
+    // spill plain JSON beyond the threshold into a temp file
+    #[cfg(feature = "spill")]
+    if let Some(threshold) = options.spill_threshold {
+        if options.is_spillable() {
+            let mut writer: SpillWriter =
+                SpillWriter::new(threshold, options.capacity);
+
+            let body: Body = match serde_json::to_writer(&mut writer, &res)
+                .map_err(std::io::Error::from)
+                .and_then(|()| writer.into_body())
+            {
+                | Ok(body) => body,
+                | Err(_) => return server_error,
+            };
+
+            return match builder.body(body) {
+                | Ok(res) => res,
+                | Err(_) => server_error,
+            };
+        }
+    }
+
+    let body: Vec<u8> = match serialize_fn(
+        res,
+        options.format.as_ref(),
+        options.encoding,
+        options.capacity,
+    ) {
+        | Some(body) => body,
+        | None => return server_error,
+    };
+
+    // record payload sample
+    #[cfg(feature = "payload_sampling")]
+    if let Some(sample) = options.sample {
+        sample.context.record(sample.data_type, body.len(), sample.items);
+    }
+
+    // set entity tag
+    #[cfg(feature = "etag")]
+    let builder: Builder = match options.etag {
+        | Some(etag) => {
+            let tag: String = match &etag.mode {
+                | ETagMode::Auto => create_etag_fn(&body),
+                | ETagMode::Value(tag) => tag.clone(),
+            };
+
+            let value: HeaderValue = match HeaderValue::from_str(&tag) {
+                | Ok(value) => value,
+                | Err(_) => return server_error,
+            };
+
+            if etag.is_not_modified(&tag) {
+                return create_not_modified_fn(builder, value, server_error);
+            }
+
+            builder.header(header::ETAG, value)
+        },
+        | None => builder,
+    };
+
+    // result
+    match builder.body(Body::from(Bytes::from(body))) {
+        | Ok(res) => res,
+        | Err(_) => server_error,
+    }
+}
+
+/// Create a problem details (RFC 9457) response from a failure state.
+pub fn create_problem_details_fn<D>(
+    mut state: JsonResponseState<D>
+) -> Response {
+    let config: Arc<JsonResponseConfig> = JsonResponseConfig::get();
+
+    // problem details are always JSON
+    state.encoding = ResponseEncoding::Json;
+
+    let format: Option<ResponseFormatRef> =
+        state.format.clone().or(config.format.clone());
+
+    // invalid state
+    if let Some(res) = check_state_fn(&state, format.as_ref()) {
+        return res;
+    }
+
+    // rejection overrides
+    #[cfg(feature = "rejection_config")]
+    let state: JsonResponseState<D> = apply_rejection_config_fn(state);
+
+    // locale
+    #[cfg(feature = "i18n")]
+    let (state, locale): (JsonResponseState<D>, Option<Locale>) =
+        take_locale_fn(state);
+
+    // request id
+    #[cfg(feature = "request_id")]
+    let state: JsonResponseState<D> = apply_request_id_fn(state);
+
+    // incident id
+    #[cfg(feature = "incident_id")]
+    let state: JsonResponseState<D> = apply_incident_id_fn(state);
+
+    // header policy
+    let mut state: JsonResponseState<D> = state;
+
+    apply_cache_control_fn(&mut state);
+
+    if let Some(res) =
+        apply_header_policy_fn(&mut state, &config, format.as_ref())
+    {
+        return res;
+    }
+
+    let server_error: Response = create_server_error_fn(state.encoding);
+
+    // create response builder
+    let builder: Builder = create_builder_fn(
+        state.status,
+        state.version,
+        state.header_map,
+        Some(CONTENT_TYPE_PROBLEM_JSON),
+    );
+
+    // truncate error messages
+    let errors: Vec<JsonResponseError> =
+        create_errors_fn(state.errors, state.message_max_length, &config);
+
+    // localize error messages
+    #[cfg(feature = "i18n")]
+    let errors: Vec<JsonResponseError> =
+        localize_errors_fn(errors, locale.as_ref());
+
+    // create problem details
+    let mut problem: ProblemDetails = ProblemDetails::new(state.status, errors);
+
+    problem.meta = state.meta;
+
+    // parse body
+    let capacity: usize = state.size_hint.unwrap_or(BODY_CAPACITY_DEFAULT);
+
+    let body: Vec<u8> = match encode_json_fn(&problem, capacity) {
+        | Some(body) => body,
+        | None => return server_error,
+    };
+
+    // result
+    match builder.body(Body::from(Bytes::from(body))) {
+        | Ok(res) => res,
+        | Err(_) => server_error,
+    }
+}
+
+/// Create a response without a body from a state,
+/// such as a redirect.
+pub fn create_empty_response_fn<D>(
+    mut state: JsonResponseState<D>
+) -> Response {
+    let config: Arc<JsonResponseConfig> = JsonResponseConfig::get();
+
+    let format: Option<ResponseFormatRef> =
+        state.format.clone().or(config.format.clone());
+
+    // invalid state
+    if let Some(res) = check_state_fn(&state, format.as_ref()) {
+        return res;
+    }
+
+    // header policy
+    apply_cache_control_fn(&mut state);
+
+    if let Some(res) =
+        apply_header_policy_fn(&mut state, &config, format.as_ref())
+    {
+        return res;
+    }
+
+    let server_error: Response = create_server_error_fn(state.encoding);
+
+    // create response builder
+    let builder: Builder =
+        create_builder_fn(state.status, state.version, state.header_map, None);
+
     // result
-    match builder.body(Body::from(body)) {
+    match builder.body(Body::empty()) {
         | Ok(res) => res,
         | Err(_) => server_error,
     }
@@ -1,12 +1,26 @@
-use http::{Error as HTTPError, HeaderName, HeaderValue, StatusCode, Version};
+use std::time::Duration;
+
+use http::{
+    Error as HTTPError, HeaderMap, HeaderName, HeaderValue, StatusCode,
+    Version, header,
+};
 use serde::Serialize;
+use serde_json::{Map, Value};
 
+#[cfg(feature = "i18n")]
+use crate::extract::locale::Locale;
 use crate::response::{
     Response,
+    cache_control::CacheControl,
+    cookie::ResponseCookie,
     json::{
-        create::JsonResponseState, create::base::create_json_response_fn,
+        create::JsonResponseState,
+        create::base::{create_json_response_fn, create_problem_details_fn},
+        create::negotiate::negotiate_encoding_fn,
         error::JsonResponseError,
+        validation::IntoValidationErrors,
     },
+    security_headers::SecurityHeaders,
 };
 
 /// Functions for creating an failure response.
@@ -178,6 +192,451 @@ impl<D: Serialize> CreateFailureJsonResponse<D> {
         self
     }
 
+    /// Add a cookie with a `Set-Cookie` header.
+    ///
+    /// An invalid cookie fails the response like an invalid header,
+    /// see [`is_valid`](ResponseCookie::is_valid).
+    ///
+    /// With `extra_cookie` feature,
+    /// a [`Cookie`](crate::extract::extra::Cookie) can be set as well.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cookie::ResponseCookie,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .cookie(ResponseCookie::new("session", "abc").http_only(true))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn cookie<C: Into<ResponseCookie>>(
+        mut self,
+        cookie: C,
+    ) -> Self {
+        let cookie: ResponseCookie = cookie.into();
+
+        if !cookie.is_valid() {
+            self.state.is_header_map_failed = true;
+
+            return self;
+        }
+
+        self.header(header::SET_COOKIE, cookie.to_string())
+    }
+
+    /// Add cookies with `Set-Cookie` headers.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cookie::ResponseCookie,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .cookies([
+    ///             ResponseCookie::new("theme", "dark"),
+    ///             ResponseCookie::new("lang", "en"),
+    ///         ])
+    ///         .create()
+    /// }
+    /// ```
+    pub fn cookies<C: Into<ResponseCookie>>(
+        mut self,
+        cookies: impl IntoIterator<Item = C>,
+    ) -> Self {
+        for cookie in cookies {
+            self = self.cookie(cookie);
+        }
+
+        self
+    }
+
+    /// Remove a cookie at the root path with a `Set-Cookie` header
+    /// that expires the cookie.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .remove_cookie("session")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn remove_cookie<N: Into<String>>(
+        self,
+        name: N,
+    ) -> Self {
+        self.cookie(ResponseCookie::removal(name))
+    }
+
+    /// Set the `Cache-Control` header of the response,
+    /// replacing the directives set before.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cache_control::CacheControl,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .cache_control(
+    ///             CacheControl::new().private().max_age(Duration::from_secs(60)),
+    ///         )
+    ///         .create()
+    /// }
+    /// ```
+    pub fn cache_control(
+        mut self,
+        cache_control: CacheControl,
+    ) -> Self {
+        self.state.cache_control = Some(cache_control);
+
+        self
+    }
+
+    /// Update the directives of the `Cache-Control` header.
+    fn update_cache_control(
+        mut self,
+        update: impl FnOnce(CacheControl) -> CacheControl,
+    ) -> Self {
+        let cache_control: CacheControl =
+            self.state.cache_control.take().unwrap_or_default();
+
+        self.state.cache_control = Some(update(cache_control));
+
+        self
+    }
+
+    /// Set the `max-age` directive of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     // cache-control: max-age=60
+    ///     CreateJsonResponse::failure()
+    ///         .cache_max_age(Duration::from_secs(60))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn cache_max_age(
+        self,
+        max_age: Duration,
+    ) -> Self {
+        self.update_cache_control(|cache_control| {
+            cache_control.max_age(max_age)
+        })
+    }
+
+    /// Set the `stale-while-revalidate` directive
+    /// of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     // cache-control: max-age=60, stale-while-revalidate=30
+    ///     CreateJsonResponse::failure()
+    ///         .cache_max_age(Duration::from_secs(60))
+    ///         .stale_while_revalidate(Duration::from_secs(30))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn stale_while_revalidate(
+        self,
+        duration: Duration,
+    ) -> Self {
+        self.update_cache_control(|cache_control| {
+            cache_control.stale_while_revalidate(duration)
+        })
+    }
+
+    /// Set the `no-cache` directive of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure().no_cache().create()
+    /// }
+    /// ```
+    pub fn no_cache(self) -> Self {
+        self.update_cache_control(CacheControl::no_cache)
+    }
+
+    /// Set the `no-store` directive of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure().no_store().create()
+    /// }
+    /// ```
+    pub fn no_store(self) -> Self {
+        self.update_cache_control(CacheControl::no_store)
+    }
+
+    /// Set the `public` directive of the `Cache-Control` header,
+    /// removing `private`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure().public().create()
+    /// }
+    /// ```
+    pub fn public(self) -> Self {
+        self.update_cache_control(CacheControl::public)
+    }
+
+    /// Set the `private` directive of the `Cache-Control` header,
+    /// removing `public`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure().private().create()
+    /// }
+    /// ```
+    pub fn private(self) -> Self {
+        self.update_cache_control(CacheControl::private)
+    }
+
+    /// Set the `must-revalidate` directive of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure().must_revalidate().create()
+    /// }
+    /// ```
+    pub fn must_revalidate(self) -> Self {
+        self.update_cache_control(CacheControl::must_revalidate)
+    }
+
+    /// Set the security headers of the response,
+    /// from a [`SecurityPreset`](crate::response::security_headers::SecurityPreset)
+    /// or [`SecurityHeaders`],
+    /// replacing the headers set before.
+    ///
+    /// Invalid headers, such as a malformed `Content-Security-Policy`,
+    /// fail the response like an invalid header,
+    /// see [`is_valid`](SecurityHeaders::is_valid).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    ///     security_headers::SecurityPreset,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .security_headers(SecurityPreset::Api)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn security_headers<H: Into<SecurityHeaders>>(
+        mut self,
+        headers: H,
+    ) -> Self {
+        let headers: SecurityHeaders = headers.into();
+
+        match headers.to_headers() {
+            | Some(headers) => {
+                for (name, value) in headers {
+                    self.state.header_map.insert(name, value);
+                }
+            },
+            | None => self.state.is_header_map_failed = true,
+        }
+
+        self
+    }
+
+    /// Set the capacity reserved for the serialized body in bytes,
+    /// which avoids reallocations for large bodies.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .size_hint(64 * 1024)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn size_hint(
+        mut self,
+        bytes: usize,
+    ) -> Self {
+        self.state.size_hint = Some(bytes);
+
+        self
+    }
+
+    /// Set the request ID for the response,
+    /// available with `request_id` feature.
+    ///
+    /// The ID is set into the
+    /// [`request_id`](crate::extract::request_id::REQUEST_ID_META_KEY)
+    /// field of meta and the
+    /// [`x-request-id`](crate::extract::request_id::REQUEST_ID_HEADER) header,
+    /// taking precedence over the ID picked up from the
+    /// [`PropagateRequestId`](crate::layers::request_id::PropagateRequestId) layer.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     extract::RequestId,
+    ///     response::{Response, json::CreateJsonResponse},
+    /// };
+    ///
+    /// async fn route(RequestId(id): RequestId) -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .request_id(id)
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "request_id")]
+    pub fn request_id<I: Into<String>>(
+        mut self,
+        id: I,
+    ) -> Self {
+        self.state.request_id = Some(id.into());
+
+        self
+    }
+
+    /// Localize the messages of errors with a locale,
+    /// looked up from the catalog by error code,
+    /// keeping the original messages if not found,
+    /// available with `i18n` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     extract::Locale,
+    ///     response::{
+    ///         Response,
+    ///         json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    ///     },
+    /// };
+    ///
+    /// async fn route(locale: Locale) -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .add_error(
+    ///             JsonResponseError::new()
+    ///                 .code(ResponseError::NotFound.as_code())
+    ///                 .message(ResponseError::NotFound.as_message()),
+    ///         )
+    ///         .localized(&locale)
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "i18n")]
+    pub fn localized(
+        mut self,
+        locale: &Locale,
+    ) -> Self {
+        self.state.locale = Some(locale.clone());
+
+        self
+    }
+
+    /// Set the meta for the response.
+    ///
+    /// The meta must be serialized into a JSON object,
+    /// otherwise a failure response will be returned.
+    ///
+    /// The meta is merged into the meta set before,
+    /// such as the `pagination` field,
+    /// where the fields of the same name are replaced.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct ResponseMeta {
+    ///     request_id: String,
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .meta(ResponseMeta { request_id: "abc".to_string() })
+    ///         .create()
+    /// }
+    /// ```
+    pub fn meta<M: Serialize>(
+        mut self,
+        meta: M,
+    ) -> Self {
+        match serde_json::to_value(meta) {
+            | Ok(Value::Object(meta)) => {
+                self.state.meta.get_or_insert_with(Map::new).extend(meta)
+            },
+            | _ => self.state.is_meta_failed = true,
+        }
+
+        self
+    }
+
     /// Finish the response creation.
     ///
     /// ## Example
@@ -196,6 +655,75 @@ impl<D: Serialize> CreateFailureJsonResponse<D> {
         create_json_response_fn(self.state)
     }
 
+    /// Finish the response creation with the media type
+    /// negotiated from the `Accept` header of the request,
+    /// falling back to JSON.
+    ///
+    /// The media types available with features:
+    /// - `application/json`
+    /// - `application/vnd.api+json` with `jsonapi` feature,
+    ///   see [`JsonApiFormat`](crate::response::jsonapi::JsonApiFormat)
+    /// - `application/msgpack` with `msgpack` feature
+    /// - `application/cbor` with `cbor` feature
+    /// - `application/xml` and `text/xml` with `xml` feature
+    ///
+    /// JSON is preferred if multiple media types have the same quality.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::HeaderMap;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route(headers: HeaderMap) -> Response {
+    ///     CreateJsonResponse::failure().create_negotiated(&headers)
+    /// }
+    /// ```
+    pub fn create_negotiated(
+        mut self,
+        headers: &HeaderMap,
+    ) -> Response {
+        self.state.encoding = negotiate_encoding_fn(headers);
+
+        self.create()
+    }
+
+    /// Finish the response creation as problem details (RFC 9457),
+    /// with `application/problem+json` content type.
+    ///
+    /// See [`ProblemDetails`](crate::response::json::ProblemDetails)
+    /// for how the fields are mapped.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         CreateJsonResponse,
+    ///         JsonResponseError,
+    ///         ResponseError,
+    ///     },
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure()
+    ///         .add_error(
+    ///             JsonResponseError::new()
+    ///                 .code(ResponseError::Parse.as_code())
+    ///                 .path(["body", "name"])
+    ///                 .message("Name is required"),
+    ///         )
+    ///         .problem_details()
+    /// }
+    /// ```
+    pub fn problem_details(self) -> Response {
+        create_problem_details_fn(self.state)
+    }
+
     /// Finish the response creation.
     #[deprecated = "Use `create` instead"]
     pub fn send(self) -> Response {
@@ -301,6 +829,10 @@ impl<D: Serialize> CreateFailureJsonResponse<D> {
 
     /// Add an error to the response.
     ///
+    /// The error can be any type convertible into [`JsonResponseError`],
+    /// such as [`ResponseError`](crate::response::json::ResponseError)
+    /// or [`serde_json::Error`].
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -323,11 +855,81 @@ impl<D: Serialize> CreateFailureJsonResponse<D> {
     ///         .create()
     /// }
     /// ```
-    pub fn add_error(
+    pub fn add_error<E: Into<JsonResponseError>>(
         mut self,
-        error: JsonResponseError,
+        error: E,
     ) -> Self {
-        self.state.errors.push(error);
+        self.state.errors.push(error.into());
+
+        self
+    }
+
+    /// Add the errors of validation failures to the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         CreateJsonResponse,
+    ///         ValidationErrors,
+    ///     },
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     let mut errors: ValidationErrors = ValidationErrors::new();
+    ///
+    ///     errors.add(["json", "name"], "length", "Name is too short");
+    ///
+    ///     CreateJsonResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY)
+    ///         .validation_errors(errors)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn validation_errors<E: IntoValidationErrors>(
+        mut self,
+        errors: E,
+    ) -> Self {
+        self.state.errors.extend(errors.into_validation_errors());
+
+        self
+    }
+
+    /// Set the maximum length of error messages in characters,
+    /// overriding the global configuration.
+    ///
+    /// Messages longer than the limit will be truncated
+    /// with the ellipsis appended within the limit.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         JsonResponseError,
+    ///         CreateJsonResponse,
+    ///     },
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     let error: JsonResponseError = JsonResponseError::new()
+    ///         .code("parse")
+    ///         .message("A very long message");
+    ///
+    ///     CreateJsonResponse::failure()
+    ///         .add_error(error)
+    ///         .message_max_length(6)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn message_max_length(
+        mut self,
+        length: usize,
+    ) -> Self {
+        self.state.message_max_length = Some(length);
 
         self
     }
@@ -1,16 +1,74 @@
 pub mod base;
 pub mod failure;
+pub(crate) mod negotiate;
+#[cfg(feature = "json_stream")]
+pub mod stream;
 pub mod success;
 
-use http::{HeaderMap, StatusCode, Version};
+use std::{marker::PhantomData, sync::Arc};
 
-use crate::response::json::{
-    create::{
-        failure::CreateFailureJsonResponse, success::CreateSuccessJsonResponse,
+#[cfg(feature = "json_stream")]
+use futures_util::Stream;
+use http::{HeaderMap, StatusCode, Version, header};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+#[cfg(feature = "etag")]
+use crate::extract::etag::IfNoneMatch;
+#[cfg(feature = "i18n")]
+use crate::extract::locale::Locale;
+#[cfg(feature = "schema_version")]
+use crate::extract::schema_version::SchemaVersion;
+#[cfg(feature = "json_stream")]
+use crate::response::json::create::stream::CreateStreamJsonResponse;
+use crate::response::{
+    cache_control::CacheControl,
+    json::{
+        config::JsonResponseConfig,
+        create::{
+            failure::CreateFailureJsonResponse,
+            success::{CreateSuccessJsonResponse, HasData, NoData},
+        },
+        error::{JsonResponseError, ResponseError},
+        format::{ResponseFormat, ResponseFormatRef},
+        job::{JOB_RETRY_AFTER_DEFAULT, JobStatus},
     },
-    error::JsonResponseError,
 };
 
+/// Encoding of the response body.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResponseEncoding {
+    /// JSON with `application/json` content type.
+    #[default]
+    Json,
+    /// JSON:API document with `application/vnd.api+json` content type,
+    /// available with `jsonapi` feature.
+    #[cfg(feature = "jsonapi")]
+    JsonApi,
+    /// CBOR with `application/cbor` content type,
+    /// available with `cbor` feature.
+    #[cfg(feature = "cbor")]
+    Cbor,
+    /// MessagePack with `application/msgpack` content type,
+    /// available with `msgpack` feature.
+    #[cfg(feature = "msgpack")]
+    Msgpack,
+    /// XML with `application/xml` content type,
+    /// available with `xml` feature.
+    #[cfg(feature = "xml")]
+    Xml,
+}
+
+/// Entity tag of the response.
+#[cfg(feature = "etag")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ETagMode {
+    /// Hash of the serialized body.
+    Auto,
+    /// Quoted entity tag.
+    Value(String),
+}
+
 /// Internal state.
 #[derive(Debug, Clone)]
 pub struct JsonResponseState<D> {
@@ -18,9 +76,30 @@ pub struct JsonResponseState<D> {
     pub version: Version,
     pub header_map: HeaderMap,
     pub is_header_map_failed: bool,
+    pub cache_control: Option<CacheControl>,
     pub success: bool,
     pub data: Option<D>,
     pub errors: Vec<JsonResponseError>,
+    pub message_max_length: Option<usize>,
+    pub size_hint: Option<usize>,
+    #[cfg(feature = "spill")]
+    pub spill_threshold: Option<usize>,
+    #[cfg(feature = "request_id")]
+    pub request_id: Option<String>,
+    #[cfg(feature = "schema_version")]
+    pub schema_version: Option<SchemaVersion>,
+    #[cfg(feature = "etag")]
+    pub etag: Option<ETagMode>,
+    #[cfg(feature = "etag")]
+    pub if_none_match: Option<IfNoneMatch>,
+    #[cfg(any(feature = "i18n", feature = "rejection_config"))]
+    pub is_rejection: bool,
+    #[cfg(feature = "i18n")]
+    pub locale: Option<Locale>,
+    pub meta: Option<Map<String, Value>>,
+    pub is_meta_failed: bool,
+    pub format: Option<ResponseFormatRef>,
+    pub encoding: ResponseEncoding,
 }
 
 impl<D> JsonResponseState<D> {
@@ -31,9 +110,30 @@ impl<D> JsonResponseState<D> {
             version: Version::HTTP_11,
             header_map: HeaderMap::new(),
             is_header_map_failed: false,
+            cache_control: None,
             success: true,
             data: None,
             errors: Vec::new(),
+            message_max_length: None,
+            size_hint: None,
+            #[cfg(feature = "spill")]
+            spill_threshold: None,
+            #[cfg(feature = "request_id")]
+            request_id: None,
+            #[cfg(feature = "schema_version")]
+            schema_version: None,
+            #[cfg(feature = "etag")]
+            etag: None,
+            #[cfg(feature = "etag")]
+            if_none_match: None,
+            #[cfg(any(feature = "i18n", feature = "rejection_config"))]
+            is_rejection: false,
+            #[cfg(feature = "i18n")]
+            locale: None,
+            meta: None,
+            is_meta_failed: false,
+            format: None,
+            encoding: ResponseEncoding::Json,
         }
     }
 
@@ -44,9 +144,67 @@ impl<D> JsonResponseState<D> {
             version: Version::HTTP_11,
             header_map: HeaderMap::new(),
             is_header_map_failed: false,
+            cache_control: None,
             success: false,
             data: None,
             errors: Vec::new(),
+            message_max_length: None,
+            size_hint: None,
+            #[cfg(feature = "spill")]
+            spill_threshold: None,
+            #[cfg(feature = "request_id")]
+            request_id: None,
+            #[cfg(feature = "schema_version")]
+            schema_version: None,
+            #[cfg(feature = "etag")]
+            etag: None,
+            #[cfg(feature = "etag")]
+            if_none_match: None,
+            #[cfg(any(feature = "i18n", feature = "rejection_config"))]
+            is_rejection: false,
+            #[cfg(feature = "i18n")]
+            locale: None,
+            meta: None,
+            is_meta_failed: false,
+            format: None,
+            encoding: ResponseEncoding::Json,
+        }
+    }
+
+    /// Replace the data of the state, keeping the others.
+    pub(crate) fn with_data<T>(
+        self,
+        data: Option<T>,
+    ) -> JsonResponseState<T> {
+        JsonResponseState {
+            status: self.status,
+            version: self.version,
+            header_map: self.header_map,
+            is_header_map_failed: self.is_header_map_failed,
+            cache_control: self.cache_control,
+            success: self.success,
+            data,
+            errors: self.errors,
+            message_max_length: self.message_max_length,
+            size_hint: self.size_hint,
+            #[cfg(feature = "spill")]
+            spill_threshold: self.spill_threshold,
+            #[cfg(feature = "request_id")]
+            request_id: self.request_id,
+            #[cfg(feature = "schema_version")]
+            schema_version: self.schema_version,
+            #[cfg(feature = "etag")]
+            etag: self.etag,
+            #[cfg(feature = "etag")]
+            if_none_match: self.if_none_match,
+            #[cfg(any(feature = "i18n", feature = "rejection_config"))]
+            is_rejection: self.is_rejection,
+            #[cfg(feature = "i18n")]
+            locale: self.locale,
+            meta: self.meta,
+            is_meta_failed: self.is_meta_failed,
+            format: self.format,
+            encoding: self.encoding,
         }
     }
 }
@@ -119,8 +277,12 @@ impl CreateJsonResponse {
     ///     CreateJsonResponse::dataless().create()
     /// }
     /// ```
-    pub fn dataless() -> CreateSuccessJsonResponse<()> {
-        CreateSuccessJsonResponse { state: JsonResponseState::success() }
+    pub fn dataless() -> CreateSuccessJsonResponse<(), HasData> {
+        let mut state: JsonResponseState<()> = JsonResponseState::success();
+
+        state.status = JsonResponseConfig::get().success_status;
+
+        CreateSuccessJsonResponse { state, marker: PhantomData }
     }
 
     /// Create a success JSON response.
@@ -146,7 +308,104 @@ impl CreateJsonResponse {
     /// }
     /// ```
     pub fn success<D>() -> CreateSuccessJsonResponse<D> {
-        CreateSuccessJsonResponse { state: JsonResponseState::success() }
+        Self::success_with(JsonResponseConfig::get().success_status)
+    }
+
+    /// Create a success JSON response
+    /// with the data required to be set before `create`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::strict_success::<String>()
+    ///         .data("Name".to_string())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn strict_success<D>() -> CreateSuccessJsonResponse<D, NoData> {
+        let mut state: JsonResponseState<D> = JsonResponseState::success();
+
+        state.status = JsonResponseConfig::get().success_status;
+
+        CreateSuccessJsonResponse { state, marker: PhantomData }
+    }
+
+    /// Create a success JSON response with a status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::success_with::<String>(StatusCode::CREATED)
+    ///         .data("Name".to_string())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn success_with<D>(status: StatusCode) -> CreateSuccessJsonResponse<D> {
+        let mut state: JsonResponseState<D> = JsonResponseState::success();
+
+        state.status = status;
+
+        CreateSuccessJsonResponse { state, marker: PhantomData }
+    }
+
+    /// Create a success JSON response
+    /// with the data converted from a value,
+    /// such as an entity into a response data.
+    ///
+    /// With `derive` feature, the conversions can be derived with
+    /// [`IntoResponseData`](crate::response::json::IntoResponseData).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// struct User {
+    ///     name: String,
+    ///     password: String,
+    /// }
+    ///
+    /// #[derive(Serialize)]
+    /// struct UserData {
+    ///     name: String,
+    /// }
+    ///
+    /// impl From<User> for UserData {
+    ///     fn from(user: User) -> Self {
+    ///         Self { name: user.name }
+    ///     }
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     let user: User = User {
+    ///         name: "Name".to_string(),
+    ///         password: "secret".to_string(),
+    ///     };
+    ///
+    ///     CreateJsonResponse::respond_with::<UserData>(user).create()
+    /// }
+    /// ```
+    pub fn respond_with<D>(
+        value: impl Into<D>
+    ) -> CreateSuccessJsonResponse<D, HasData> {
+        Self::success::<D>().data(value.into())
     }
 
     /// Create a failure JSON response.
@@ -164,6 +423,344 @@ impl CreateJsonResponse {
     /// }
     /// ```
     pub fn failure() -> CreateFailureJsonResponse<()> {
-        CreateFailureJsonResponse { state: JsonResponseState::failure() }
+        Self::failure_with(JsonResponseConfig::get().failure_status)
+    }
+
+    /// Create a failure JSON response with a status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn failure_with(status: StatusCode) -> CreateFailureJsonResponse<()> {
+        let mut state: JsonResponseState<()> = JsonResponseState::failure();
+
+        state.status = status;
+
+        CreateFailureJsonResponse { state }
+    }
+
+    /// Create a failure JSON response from a response error,
+    /// with the suggested status code and the error with its default message.
+    ///
+    /// The status of [`BodyRead`](ResponseError::BodyRead)
+    /// and errors without a suggested status code
+    /// follow the [`JsonResponseConfig`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{CreateJsonResponse, ResponseError},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::failure_from(ResponseError::Forbidden).create()
+    /// }
+    /// ```
+    pub fn failure_from(error: ResponseError) -> CreateFailureJsonResponse<()> {
+        let config: Arc<JsonResponseConfig> = JsonResponseConfig::get();
+
+        let status: StatusCode = match error {
+            | ResponseError::BodyRead => config.body_read_status,
+            | _ => error.as_status().unwrap_or(config.failure_status),
+        };
+
+        Self::failure_with(status).add_error(JsonResponseError::from(error))
+    }
+
+    /// Create a failure JSON response for a rejection of an extractor,
+    /// overridden by the [`RejectionConfig`] with `rejection_config` feature
+    /// and localized by the [`Localize`] layer with `i18n` feature.
+    ///
+    /// [`RejectionConfig`]: crate::response::json::RejectionConfig
+    /// [`Localize`]: crate::layers::Localize
+    pub(crate) fn rejection() -> CreateFailureJsonResponse<()> {
+        let state: JsonResponseState<()> = JsonResponseState {
+            status: JsonResponseConfig::get().failure_status,
+            #[cfg(any(feature = "i18n", feature = "rejection_config"))]
+            is_rejection: true,
+            ..JsonResponseState::failure()
+        };
+
+        CreateFailureJsonResponse { state }
+    }
+
+    /// Create a conflict failure JSON response
+    /// with the current representation of the resource.
+    ///
+    /// The response has `409 Conflict` status,
+    /// the current representation in `data`,
+    /// and a [`Conflict`](ResponseError::Conflict) error,
+    /// so that clients can merge their changes with the current state.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Document {
+    ///     version: usize,
+    ///     content: String,
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     let current: Document = Document {
+    ///         version: 2,
+    ///         content: "Updated".to_string(),
+    ///     };
+    ///
+    ///     CreateJsonResponse::conflict_with_current(current).create()
+    /// }
+    /// ```
+    pub fn conflict_with_current<C: Serialize>(
+        current: C
+    ) -> CreateFailureJsonResponse<C> {
+        let mut state: JsonResponseState<C> = JsonResponseState::failure();
+
+        state.status = StatusCode::CONFLICT;
+        state.data = Some(current);
+        state.errors.push(JsonResponseError::from(ResponseError::Conflict));
+
+        CreateFailureJsonResponse { state }
+    }
+
+    /// Create a success JSON response with the status of a job,
+    /// for polling asynchronous tasks.
+    ///
+    /// Queued and running jobs have `202 Accepted` status
+    /// with the `Retry-After` header,
+    /// and finished jobs have `200 OK` status,
+    /// where the errors of failed jobs are in the job status.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{CreateJsonResponse, JobStatus},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::job(
+    ///         JobStatus::running("job_1").progress(40).retry_after(5),
+    ///     )
+    ///     .create()
+    /// }
+    /// ```
+    pub fn job(
+        status: JobStatus
+    ) -> CreateSuccessJsonResponse<JobStatus, HasData> {
+        if !status.state.is_in_progress() {
+            return Self::success_with::<JobStatus>(StatusCode::OK)
+                .data(status);
+        }
+
+        let retry_after: u64 =
+            status.retry_after.unwrap_or(JOB_RETRY_AFTER_DEFAULT);
+
+        Self::success_with::<JobStatus>(StatusCode::ACCEPTED)
+            .header(header::RETRY_AFTER, retry_after.to_string())
+            .data(status)
+    }
+
+    /// Create JSON responses with a format of the serialized envelope,
+    /// which takes precedence over the global format.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         CreateJsonResponse,
+    ///         ResponseFormat,
+    ///         ResponseFormatParts,
+    ///     },
+    /// };
+    /// use serde_json::{Value, json};
+    ///
+    /// struct MyFormat;
+    ///
+    /// impl ResponseFormat for MyFormat {
+    ///     fn format(&self, parts: ResponseFormatParts) -> Value {
+    ///         json!({ "ok": parts.success, "result": parts.data })
+    ///     }
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::with_format(MyFormat).dataless().create()
+    /// }
+    /// ```
+    pub fn with_format<F: ResponseFormat>(
+        format: F
+    ) -> CreateFormattedJsonResponse {
+        CreateFormattedJsonResponse { format: ResponseFormatRef::new(format) }
+    }
+
+    /// Create a streaming success JSON response,
+    /// available with `json_stream` feature.
+    ///
+    /// The data items from the stream are serialized into
+    /// the `data` array incrementally instead of being buffered,
+    /// which is useful for large payloads.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::stream(stream::iter(vec![1, 2, 3])).create()
+    /// }
+    /// ```
+    #[cfg(feature = "json_stream")]
+    pub fn stream<S, T>(stream: S) -> CreateStreamJsonResponse<S>
+    where
+        S: Stream<Item = T> + Send + 'static,
+        T: Serialize,
+    {
+        CreateStreamJsonResponse { state: JsonResponseState::success(), stream }
+    }
+}
+
+/// Create a JSON response with a format of the serialized envelope.
+///
+/// Created with [`CreateJsonResponse::with_format`].
+#[derive(Debug, Clone)]
+pub struct CreateFormattedJsonResponse {
+    format: ResponseFormatRef,
+}
+
+impl CreateFormattedJsonResponse {
+    /// Create a success JSON response without data.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         CreateJsonResponse,
+    ///         ResponseFormat,
+    ///         ResponseFormatParts,
+    ///     },
+    /// };
+    /// use serde_json::{Value, json};
+    ///
+    /// struct MyFormat;
+    ///
+    /// impl ResponseFormat for MyFormat {
+    ///     fn format(&self, parts: ResponseFormatParts) -> Value {
+    ///         json!({ "ok": parts.success })
+    ///     }
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::with_format(MyFormat).dataless().create()
+    /// }
+    /// ```
+    pub fn dataless(&self) -> CreateSuccessJsonResponse<(), HasData> {
+        let mut res: CreateSuccessJsonResponse<(), HasData> =
+            CreateJsonResponse::dataless();
+
+        res.state.format = Some(self.format.clone());
+
+        res
+    }
+
+    /// Create a success JSON response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         CreateJsonResponse,
+    ///         ResponseFormat,
+    ///         ResponseFormatParts,
+    ///     },
+    /// };
+    /// use serde_json::{Value, json};
+    ///
+    /// struct MyFormat;
+    ///
+    /// impl ResponseFormat for MyFormat {
+    ///     fn format(&self, parts: ResponseFormatParts) -> Value {
+    ///         json!({ "ok": parts.success, "result": parts.data })
+    ///     }
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::with_format(MyFormat)
+    ///         .success::<String>()
+    ///         .data("Name".to_string())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn success<D>(&self) -> CreateSuccessJsonResponse<D> {
+        let mut res: CreateSuccessJsonResponse<D> =
+            CreateJsonResponse::success();
+
+        res.state.format = Some(self.format.clone());
+
+        res
+    }
+
+    /// Create a failure JSON response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         CreateJsonResponse,
+    ///         ResponseFormat,
+    ///         ResponseFormatParts,
+    ///     },
+    /// };
+    /// use serde_json::{Value, json};
+    ///
+    /// struct MyFormat;
+    ///
+    /// impl ResponseFormat for MyFormat {
+    ///     fn format(&self, parts: ResponseFormatParts) -> Value {
+    ///         json!({ "ok": parts.success, "error": parts.errors.first() })
+    ///     }
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::with_format(MyFormat).failure().create()
+    /// }
+    /// ```
+    pub fn failure(&self) -> CreateFailureJsonResponse<()> {
+        let mut res: CreateFailureJsonResponse<()> =
+            CreateJsonResponse::failure();
+
+        res.state.format = Some(self.format.clone());
+
+        res
     }
 }
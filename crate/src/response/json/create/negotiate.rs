@@ -0,0 +1,155 @@
+use http::{HeaderMap, header};
+
+#[cfg(feature = "cbor")]
+use crate::response::cbor::CONTENT_TYPE_CBOR;
+use crate::response::json::create::{
+    ResponseEncoding, base::CONTENT_TYPE_JSON,
+};
+#[cfg(feature = "jsonapi")]
+use crate::response::jsonapi::CONTENT_TYPE_JSONAPI;
+#[cfg(feature = "msgpack")]
+use crate::response::msgpack::CONTENT_TYPE_MSGPACK;
+#[cfg(feature = "xml")]
+use crate::response::xml::CONTENT_TYPE_XML;
+
+/// Media range of an `Accept` header.
+struct MediaRange<'a> {
+    /// Main type, such as `application` or `*`.
+    main: &'a str,
+    /// Sub type, such as `json` or `*`.
+    sub: &'a str,
+    /// Quality of the media range.
+    quality: f32,
+}
+
+impl MediaRange<'_> {
+    /// Get the specificity if the media range matches the media type.
+    fn specificity(
+        &self,
+        main: &str,
+        sub: &str,
+    ) -> Option<u8> {
+        match (self.main, self.sub) {
+            | ("*", "*") => Some(0),
+            | (m, "*") if m.eq_ignore_ascii_case(main) => Some(1),
+            | (m, s)
+                if m.eq_ignore_ascii_case(main)
+                    && s.eq_ignore_ascii_case(sub) =>
+            {
+                Some(2)
+            },
+            | _ => None,
+        }
+    }
+}
+
+/// Parse media ranges from an `Accept` header value.
+fn parse_media_ranges(value: &str) -> Vec<MediaRange<'_>> {
+    value
+        .split(',')
+        .filter_map(|range| {
+            let mut params = range.split(';');
+
+            let (main, sub) =
+                params.next().unwrap_or_default().trim().split_once('/')?;
+
+            let quality: f32 = params
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(Some(1.0))?;
+
+            Some(MediaRange { main: main.trim(), sub: sub.trim(), quality })
+        })
+        .collect()
+}
+
+/// Get the quality of a media type with the most specific media range.
+fn media_type_quality(
+    ranges: &[MediaRange<'_>],
+    media_type: &str,
+) -> f32 {
+    let (main, sub) = media_type.split_once('/').unwrap_or((media_type, ""));
+
+    let mut matched: Option<(u8, f32)> = None;
+
+    for range in ranges {
+        if let Some(specificity) = range.specificity(main, sub) {
+            if matched.is_none_or(|(s, _)| specificity > s) {
+                matched = Some((specificity, range.quality));
+            }
+        }
+    }
+
+    matched.map(|(_, q)| q).unwrap_or(0.0)
+}
+
+/// Get the index of the preferred media type from the `Accept` header.
+///
+/// Media types with the same quality are preferred in order.
+/// Returns `None` if the header is missing
+/// or none of the media types is acceptable.
+pub(crate) fn preferred_media_type(
+    headers: &HeaderMap,
+    media_types: &[&str],
+) -> Option<usize> {
+    let value: String = headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<&str>>()
+        .join(",");
+
+    if value.trim().is_empty() {
+        return None;
+    }
+
+    let ranges: Vec<MediaRange<'_>> = parse_media_ranges(&value);
+
+    let mut preferred: Option<(usize, f32)> = None;
+
+    for (i, media_type) in media_types.iter().enumerate() {
+        let quality: f32 = media_type_quality(&ranges, media_type);
+
+        if quality <= 0.0 {
+            continue;
+        }
+
+        if preferred.is_none_or(|(_, q)| quality > q) {
+            preferred = Some((i, quality));
+        }
+    }
+
+    preferred.map(|(i, _)| i)
+}
+
+/// Get the response encoding from the `Accept` header,
+/// falling back to JSON.
+///
+/// JSON is preferred if multiple encodings have the same quality.
+pub(crate) fn negotiate_encoding_fn(headers: &HeaderMap) -> ResponseEncoding {
+    let encodings: &[(&str, ResponseEncoding)] = &[
+        (CONTENT_TYPE_JSON, ResponseEncoding::Json),
+        #[cfg(feature = "jsonapi")]
+        (CONTENT_TYPE_JSONAPI, ResponseEncoding::JsonApi),
+        #[cfg(feature = "msgpack")]
+        (CONTENT_TYPE_MSGPACK, ResponseEncoding::Msgpack),
+        #[cfg(feature = "msgpack")]
+        ("application/x-msgpack", ResponseEncoding::Msgpack),
+        #[cfg(feature = "msgpack")]
+        ("application/vnd.msgpack", ResponseEncoding::Msgpack),
+        #[cfg(feature = "cbor")]
+        (CONTENT_TYPE_CBOR, ResponseEncoding::Cbor),
+        #[cfg(feature = "xml")]
+        (CONTENT_TYPE_XML, ResponseEncoding::Xml),
+        #[cfg(feature = "xml")]
+        ("text/xml", ResponseEncoding::Xml),
+    ];
+
+    let media_types: Vec<&str> =
+        encodings.iter().map(|(media_type, _)| *media_type).collect();
+
+    match preferred_media_type(headers, &media_types) {
+        | Some(i) => encodings[i].1,
+        | None => ResponseEncoding::Json,
+    }
+}
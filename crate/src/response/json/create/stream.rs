@@ -0,0 +1,322 @@
+use axum_core::{BoxError, body::Body};
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt, stream};
+use http::{
+    Error as HTTPError, HeaderName, HeaderValue, StatusCode, Version, header,
+    response::Builder,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::response::{
+    Response,
+    json::create::{
+        JsonResponseState,
+        base::{CONTENT_TYPE_JSON, create_json_response_fn},
+    },
+};
+
+/// Functions for creating a streaming success response,
+/// available with `json_stream` feature.
+pub struct CreateStreamJsonResponse<S> {
+    /// Internal state.
+    pub(crate) state: JsonResponseState<()>,
+    /// Stream of data items.
+    pub(crate) stream: S,
+}
+
+impl<S, T> CreateStreamJsonResponse<S>
+where
+    S: Stream<Item = T> + Send + 'static,
+    T: Serialize,
+{
+    /// Set the status code for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::stream(stream::iter(vec![1, 2, 3]))
+    ///         .status(StatusCode::PARTIAL_CONTENT)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn status<C: Into<StatusCode>>(
+        mut self,
+        status: C,
+    ) -> Self {
+        self.state.status = status.into();
+
+        self
+    }
+
+    /// Set the HTTP version for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Version;
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::stream(stream::iter(vec![1, 2, 3]))
+    ///         .version(Version::HTTP_2)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn version<V: Into<Version>>(
+        mut self,
+        version: V,
+    ) -> Self {
+        self.state.version = version.into();
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::stream(stream::iter(vec![1, 2, 3]))
+    ///         .header(
+    ///             header::CONTENT_DISPOSITION,
+    ///             "attachment; filename=\"export.json\""
+    ///         )
+    ///         .create()
+    /// }
+    /// ```
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        let key: HeaderName = match <HeaderName as TryFrom<K>>::try_from(key) {
+            | Ok(k) => k,
+            | Err(_) => {
+                self.state.is_header_map_failed = true;
+                return self;
+            },
+        };
+
+        let value: HeaderValue =
+            match <HeaderValue as TryFrom<V>>::try_from(value) {
+                | Ok(v) => v,
+                | Err(_) => {
+                    self.state.is_header_map_failed = true;
+                    return self;
+                },
+            };
+
+        self.state.header_map.try_append(key, value).unwrap();
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::{
+    ///     header,
+    ///     HeaderName,
+    /// };
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     let headers: Vec<(HeaderName, &str)> = vec![
+    ///         (
+    ///             header::ACCESS_CONTROL_ALLOW_ORIGIN,
+    ///             "*"
+    ///         ),
+    ///     ];
+    ///
+    ///     CreateJsonResponse::stream(stream::iter(vec![1, 2, 3]))
+    ///         .headers(headers)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        for (key, value) in headers {
+            self = self.header(key, value);
+        }
+
+        self
+    }
+
+    /// Set the meta for the response.
+    ///
+    /// The meta must be serialized into a JSON object,
+    /// otherwise a failure response will be returned.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct ResponseMeta {
+    ///     request_id: String,
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::stream(stream::iter(vec![1, 2, 3]))
+    ///         .meta(ResponseMeta { request_id: "abc".to_string() })
+    ///         .create()
+    /// }
+    /// ```
+    pub fn meta<M: Serialize>(
+        mut self,
+        meta: M,
+    ) -> Self {
+        match serde_json::to_value(meta) {
+            | Ok(Value::Object(meta)) => self.state.meta = Some(meta),
+            | _ => self.state.is_meta_failed = true,
+        }
+
+        self
+    }
+
+    /// Finish the response creation.
+    ///
+    /// The data items are serialized into a JSON array one by one
+    /// while the body is being sent.
+    /// If an item fails to serialize, the body will be aborted.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::stream(stream::iter(vec![1, 2, 3])).create()
+    /// }
+    /// ```
+    pub fn create(self) -> Response {
+        // header map or meta error
+        if self.state.is_header_map_failed || self.state.is_meta_failed {
+            return create_json_response_fn(self.state);
+        }
+
+        // the tail of the envelope
+        let mut tail: String = "],\"errors\":[]".to_string();
+
+        if let Some(meta) = self.state.meta {
+            match serde_json::to_string(&meta) {
+                | Ok(meta) => {
+                    tail.push_str(",\"meta\":");
+                    tail.push_str(&meta);
+                },
+                | Err(_) => {
+                    let mut state: JsonResponseState<()> =
+                        JsonResponseState::success();
+
+                    state.is_meta_failed = true;
+
+                    return create_json_response_fn(state);
+                },
+            }
+        }
+
+        tail.push('}');
+
+        let head = stream::once(async {
+            Ok::<Bytes, BoxError>(Bytes::from_static(
+                b"{\"success\":true,\"data\":[",
+            ))
+        });
+
+        let items = self.stream.enumerate().map(|(index, item)| {
+            let mut buf: Vec<u8> = Vec::new();
+
+            if index > 0 {
+                buf.push(b',');
+            }
+
+            serde_json::to_writer(&mut buf, &item)
+                .map(|_| Bytes::from(buf))
+                .map_err(BoxError::from)
+        });
+
+        let tail = stream::once(async move { Ok(Bytes::from(tail)) });
+
+        // create response builder
+        let mut builder: Builder = Response::builder()
+            .status(self.state.status)
+            .version(self.state.version)
+            .header(header::CONTENT_TYPE, CONTENT_TYPE_JSON);
+
+        // push headers
+        for (header, value) in self.state.header_map {
+            if let Some(header) = header {
+                builder = builder.header(header, value);
+            }
+        }
+
+        match builder.body(Body::from_stream(head.chain(items).chain(tail))) {
+            | Ok(res) => res,
+            | Err(_) => {
+                let mut state: JsonResponseState<()> =
+                    JsonResponseState::success();
+
+                state.is_header_map_failed = true;
+
+                create_json_response_fn(state)
+            },
+        }
+    }
+}
@@ -1,21 +1,77 @@
-use http::{Error as HTTPError, HeaderName, HeaderValue, StatusCode, Version};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+
+use http::{
+    Error as HTTPError, HeaderMap, HeaderName, HeaderValue, StatusCode,
+    Version, header,
+};
 use serde::Serialize;
+use serde_json::{Map, Value};
 
+#[cfg(feature = "schema_version")]
+use crate::extract::schema_version::SchemaVersion;
 use crate::response::{
     Response,
-    json::{create::JsonResponseState, create::base::create_json_response_fn},
+    cache_control::CacheControl,
+    cookie::ResponseCookie,
+    json::{
+        config::JsonResponseConfig, create::JsonResponseState,
+        create::base::create_json_response_fn,
+        create::negotiate::negotiate_encoding_fn, error::JsonResponseError,
+        pagination::Pagination,
+    },
+    security_headers::SecurityHeaders,
 };
+#[cfg(feature = "etag")]
+use crate::{
+    extract::etag::{IfNoneMatch, quote_etag_fn},
+    response::json::create::ETagMode,
+};
+
+/// Builder state of a success response with data set,
+/// or without data required.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HasData;
+
+/// Builder state of a success response waiting for data,
+/// created with
+/// [`CreateJsonResponse::strict_success`](crate::response::json::CreateJsonResponse::strict_success).
+///
+/// ## Example
+///
+/// ```compile_fail
+/// use jder_axum::response::{
+///     Response,
+///     json::CreateJsonResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     // `create` is not available before the data is set
+///     CreateJsonResponse::strict_success::<String>().create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoData;
 
 /// Functions for creating a success response.
+///
+/// Builders in [`NoData`] state, such as
+/// [`CreateJsonResponse::strict_success`](crate::response::json::CreateJsonResponse::strict_success),
+/// only provide `create` after the data is set.
 #[derive(Debug, Clone)]
-pub struct CreateSuccessJsonResponse<D> {
+pub struct CreateSuccessJsonResponse<D, B = HasData> {
     /// Internal state.
     pub(crate) state: JsonResponseState<D>,
+    /// Builder state.
+    pub(crate) marker: PhantomData<B>,
 }
 
-impl<D: Serialize> CreateSuccessJsonResponse<D> {
+impl<D: Serialize, B> CreateSuccessJsonResponse<D, B> {
     /// Set the status code for the response.
     ///
+    /// The status of a failed
+    /// [`data_result`](CreateSuccessJsonResponse::data_result)
+    /// is kept.
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -35,7 +91,9 @@ impl<D: Serialize> CreateSuccessJsonResponse<D> {
         mut self,
         status: S,
     ) -> Self {
-        self.state.status = status.into();
+        if self.state.success {
+            self.state.status = status.into();
+        }
 
         self
     }
@@ -176,6 +234,575 @@ impl<D: Serialize> CreateSuccessJsonResponse<D> {
         self
     }
 
+    /// Add a cookie with a `Set-Cookie` header.
+    ///
+    /// An invalid cookie fails the response like an invalid header,
+    /// see [`is_valid`](ResponseCookie::is_valid).
+    ///
+    /// With `extra_cookie` feature,
+    /// a [`Cookie`](crate::extract::extra::Cookie) can be set as well.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cookie::ResponseCookie,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .cookie(ResponseCookie::new("session", "abc").http_only(true))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn cookie<C: Into<ResponseCookie>>(
+        mut self,
+        cookie: C,
+    ) -> Self {
+        let cookie: ResponseCookie = cookie.into();
+
+        if !cookie.is_valid() {
+            self.state.is_header_map_failed = true;
+
+            return self;
+        }
+
+        self.header(header::SET_COOKIE, cookie.to_string())
+    }
+
+    /// Add cookies with `Set-Cookie` headers.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cookie::ResponseCookie,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .cookies([
+    ///             ResponseCookie::new("theme", "dark"),
+    ///             ResponseCookie::new("lang", "en"),
+    ///         ])
+    ///         .create()
+    /// }
+    /// ```
+    pub fn cookies<C: Into<ResponseCookie>>(
+        mut self,
+        cookies: impl IntoIterator<Item = C>,
+    ) -> Self {
+        for cookie in cookies {
+            self = self.cookie(cookie);
+        }
+
+        self
+    }
+
+    /// Remove a cookie at the root path with a `Set-Cookie` header
+    /// that expires the cookie.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .remove_cookie("session")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn remove_cookie<N: Into<String>>(
+        self,
+        name: N,
+    ) -> Self {
+        self.cookie(ResponseCookie::removal(name))
+    }
+
+    /// Set the `Cache-Control` header of the response,
+    /// replacing the directives set before.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cache_control::CacheControl,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .cache_control(
+    ///             CacheControl::new().private().max_age(Duration::from_secs(60)),
+    ///         )
+    ///         .create()
+    /// }
+    /// ```
+    pub fn cache_control(
+        mut self,
+        cache_control: CacheControl,
+    ) -> Self {
+        self.state.cache_control = Some(cache_control);
+
+        self
+    }
+
+    /// Update the directives of the `Cache-Control` header.
+    fn update_cache_control(
+        mut self,
+        update: impl FnOnce(CacheControl) -> CacheControl,
+    ) -> Self {
+        let cache_control: CacheControl =
+            self.state.cache_control.take().unwrap_or_default();
+
+        self.state.cache_control = Some(update(cache_control));
+
+        self
+    }
+
+    /// Set the `max-age` directive of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     // cache-control: max-age=60
+    ///     CreateJsonResponse::dataless()
+    ///         .cache_max_age(Duration::from_secs(60))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn cache_max_age(
+        self,
+        max_age: Duration,
+    ) -> Self {
+        self.update_cache_control(|cache_control| {
+            cache_control.max_age(max_age)
+        })
+    }
+
+    /// Set the `stale-while-revalidate` directive
+    /// of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     // cache-control: max-age=60, stale-while-revalidate=30
+    ///     CreateJsonResponse::dataless()
+    ///         .cache_max_age(Duration::from_secs(60))
+    ///         .stale_while_revalidate(Duration::from_secs(30))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn stale_while_revalidate(
+        self,
+        duration: Duration,
+    ) -> Self {
+        self.update_cache_control(|cache_control| {
+            cache_control.stale_while_revalidate(duration)
+        })
+    }
+
+    /// Set the `no-cache` directive of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless().no_cache().create()
+    /// }
+    /// ```
+    pub fn no_cache(self) -> Self {
+        self.update_cache_control(CacheControl::no_cache)
+    }
+
+    /// Set the `no-store` directive of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless().no_store().create()
+    /// }
+    /// ```
+    pub fn no_store(self) -> Self {
+        self.update_cache_control(CacheControl::no_store)
+    }
+
+    /// Set the `public` directive of the `Cache-Control` header,
+    /// removing `private`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless().public().create()
+    /// }
+    /// ```
+    pub fn public(self) -> Self {
+        self.update_cache_control(CacheControl::public)
+    }
+
+    /// Set the `private` directive of the `Cache-Control` header,
+    /// removing `public`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless().private().create()
+    /// }
+    /// ```
+    pub fn private(self) -> Self {
+        self.update_cache_control(CacheControl::private)
+    }
+
+    /// Set the `must-revalidate` directive of the `Cache-Control` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless().must_revalidate().create()
+    /// }
+    /// ```
+    pub fn must_revalidate(self) -> Self {
+        self.update_cache_control(CacheControl::must_revalidate)
+    }
+
+    /// Set the security headers of the response,
+    /// from a [`SecurityPreset`](crate::response::security_headers::SecurityPreset)
+    /// or [`SecurityHeaders`],
+    /// replacing the headers set before.
+    ///
+    /// Invalid headers, such as a malformed `Content-Security-Policy`,
+    /// fail the response like an invalid header,
+    /// see [`is_valid`](SecurityHeaders::is_valid).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    ///     security_headers::SecurityPreset,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .security_headers(SecurityPreset::Api)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn security_headers<H: Into<SecurityHeaders>>(
+        mut self,
+        headers: H,
+    ) -> Self {
+        let headers: SecurityHeaders = headers.into();
+
+        match headers.to_headers() {
+            | Some(headers) => {
+                for (name, value) in headers {
+                    self.state.header_map.insert(name, value);
+                }
+            },
+            | None => self.state.is_header_map_failed = true,
+        }
+
+        self
+    }
+
+    /// Set the capacity reserved for the serialized body in bytes,
+    /// which avoids reallocations for large bodies.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .size_hint(64 * 1024)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn size_hint(
+        mut self,
+        bytes: usize,
+    ) -> Self {
+        self.state.size_hint = Some(bytes);
+
+        self
+    }
+
+    /// Set the memory threshold of the serialized body in bytes,
+    /// available with `spill` feature.
+    ///
+    /// Serialization beyond the threshold continues into a temp file,
+    /// which is then streamed as the body and removed afterwards,
+    /// protecting the memory on unexpectedly large responses.
+    ///
+    /// Only applied to plain JSON responses without a format.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::success::<Vec<u64>>()
+    ///         .spill_threshold(1024 * 1024)
+    ///         .data((0..1_000_000).collect())
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "spill")]
+    pub fn spill_threshold(
+        mut self,
+        bytes: usize,
+    ) -> Self {
+        self.state.spill_threshold = Some(bytes);
+
+        self
+    }
+
+    /// Set the request ID for the response,
+    /// available with `request_id` feature.
+    ///
+    /// The ID is set into the
+    /// [`request_id`](crate::extract::request_id::REQUEST_ID_META_KEY)
+    /// field of meta and the
+    /// [`x-request-id`](crate::extract::request_id::REQUEST_ID_HEADER) header,
+    /// taking precedence over the ID picked up from the
+    /// [`PropagateRequestId`](crate::layers::request_id::PropagateRequestId) layer.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     extract::RequestId,
+    ///     response::{Response, json::CreateJsonResponse},
+    /// };
+    ///
+    /// async fn route(RequestId(id): RequestId) -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .request_id(id)
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "request_id")]
+    pub fn request_id<I: Into<String>>(
+        mut self,
+        id: I,
+    ) -> Self {
+        self.state.request_id = Some(id.into());
+
+        self
+    }
+
+    /// Set the schema version pinned by the client for the response,
+    /// available with `schema_version` feature.
+    ///
+    /// The data is migrated to the pinned version
+    /// with the registered
+    /// [`SchemaMigrations`](crate::extract::schema_version::SchemaMigrations)
+    /// before sending, and the version is set into the
+    /// [`x-schema-version`](crate::extract::schema_version::SCHEMA_VERSION_HEADER)
+    /// header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     extract::SchemaVersion,
+    ///     response::{Response, json::CreateJsonResponse},
+    /// };
+    ///
+    /// async fn route(version: SchemaVersion) -> Response {
+    ///     CreateJsonResponse::success::<String>()
+    ///         .data("Name".to_string())
+    ///         .schema_version(&version)
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "schema_version")]
+    pub fn schema_version(
+        mut self,
+        version: &SchemaVersion,
+    ) -> Self {
+        self.state.schema_version = Some(version.clone());
+
+        self
+    }
+
+    /// Set the entity tag of the response
+    /// from the hash of the serialized body,
+    /// available with `etag` feature.
+    ///
+    /// The entity tag is set into the `etag` header
+    /// of success responses.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::success::<String>()
+    ///         .data("Name".to_string())
+    ///         .etag_auto()
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "etag")]
+    pub fn etag_auto(mut self) -> Self {
+        self.state.etag = Some(ETagMode::Auto);
+
+        self
+    }
+
+    /// Set the entity tag of the response,
+    /// such as the version of a resource,
+    /// available with `etag` feature.
+    ///
+    /// The tag is quoted if not quoted, such as `v1` into `"v1"`,
+    /// and weak tags such as `W/"v1"` are kept.
+    ///
+    /// The body is not serialized
+    /// when the tag matches the `if-none-match` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, json::CreateJsonResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::success::<String>()
+    ///         .data("Name".to_string())
+    ///         .etag("v1")
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "etag")]
+    pub fn etag<T: AsRef<str>>(
+        mut self,
+        tag: T,
+    ) -> Self {
+        self.state.etag = Some(ETagMode::Value(quote_etag_fn(tag.as_ref())));
+
+        self
+    }
+
+    /// Set the `if-none-match` header of the request,
+    /// available with `etag` feature.
+    ///
+    /// A `304 Not Modified` response with an empty body is sent
+    /// when the entity tag of the success response matches.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{
+    ///     extract::IfNoneMatch,
+    ///     response::{Response, json::CreateJsonResponse},
+    /// };
+    ///
+    /// async fn route(if_none_match: IfNoneMatch) -> Response {
+    ///     CreateJsonResponse::success::<String>()
+    ///         .data("Name".to_string())
+    ///         .etag_auto()
+    ///         .if_none_match(&if_none_match)
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "etag")]
+    pub fn if_none_match(
+        mut self,
+        if_none_match: &IfNoneMatch,
+    ) -> Self {
+        self.state.if_none_match = Some(if_none_match.clone());
+
+        self
+    }
+
+    /// Set the meta for the response.
+    ///
+    /// The meta must be serialized into a JSON object,
+    /// otherwise a failure response will be returned.
+    ///
+    /// The meta is merged into the meta set before,
+    /// such as the `pagination` field,
+    /// where the fields of the same name are replaced.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct ResponseMeta {
+    ///     request_id: String,
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::dataless()
+    ///         .meta(ResponseMeta { request_id: "abc".to_string() })
+    ///         .create()
+    /// }
+    /// ```
+    pub fn meta<M: Serialize>(
+        mut self,
+        meta: M,
+    ) -> Self {
+        match serde_json::to_value(meta) {
+            | Ok(Value::Object(meta)) => {
+                self.state.meta.get_or_insert_with(Map::new).extend(meta)
+            },
+            | _ => self.state.is_meta_failed = true,
+        }
+
+        self
+    }
+}
+
+impl<D: Serialize> CreateSuccessJsonResponse<D, HasData> {
     /// Finish the response creation.
     ///
     /// ## Example
@@ -194,6 +821,42 @@ impl<D: Serialize> CreateSuccessJsonResponse<D> {
         create_json_response_fn(self.state)
     }
 
+    /// Finish the response creation with the media type
+    /// negotiated from the `Accept` header of the request,
+    /// falling back to JSON.
+    ///
+    /// The media types available with features:
+    /// - `application/json`
+    /// - `application/vnd.api+json` with `jsonapi` feature,
+    ///   see [`JsonApiFormat`](crate::response::jsonapi::JsonApiFormat)
+    /// - `application/msgpack` with `msgpack` feature
+    /// - `application/cbor` with `cbor` feature
+    /// - `application/xml` and `text/xml` with `xml` feature
+    ///
+    /// JSON is preferred if multiple media types have the same quality.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::HeaderMap;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse,
+    /// };
+    ///
+    /// async fn route(headers: HeaderMap) -> Response {
+    ///     CreateJsonResponse::dataless().create_negotiated(&headers)
+    /// }
+    /// ```
+    pub fn create_negotiated(
+        mut self,
+        headers: &HeaderMap,
+    ) -> Response {
+        self.state.encoding = negotiate_encoding_fn(headers);
+
+        self.create()
+    }
+
     /// Finish the response creation.
     #[deprecated = "Use `create` instead"]
     pub fn send(self) -> Response {
@@ -201,7 +864,7 @@ impl<D: Serialize> CreateSuccessJsonResponse<D> {
     }
 }
 
-impl<D> CreateSuccessJsonResponse<D> {
+impl<D, B> CreateSuccessJsonResponse<D, B> {
     /// Set the data for the response.
     ///
     /// ## Example
@@ -227,9 +890,205 @@ impl<D> CreateSuccessJsonResponse<D> {
     pub fn data(
         mut self,
         data: D,
-    ) -> Self {
+    ) -> CreateSuccessJsonResponse<D, HasData> {
         self.state.data = Some(data);
 
-        self
+        CreateSuccessJsonResponse { state: self.state, marker: PhantomData }
+    }
+
+    /// Set the default value of the data for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Default, Serialize)]
+    /// struct ResponseData {
+    ///    names: Vec<String>,
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::success::<ResponseData>()
+    ///         .data_default()
+    ///         .create()
+    /// }
+    /// ```
+    pub fn data_default(self) -> CreateSuccessJsonResponse<D, HasData>
+    where
+        D: Default,
+    {
+        self.data(D::default())
+    }
+
+    /// Set the data from a result for the response.
+    ///
+    /// If the result is an error,
+    /// the response is switched into a failure response
+    /// with the error and the default failure status,
+    /// see [`data_result_with`](CreateSuccessJsonResponse::data_result_with)
+    /// for setting the failure status.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{CreateJsonResponse, JsonResponseError},
+    /// };
+    ///
+    /// fn find_name(id: u32) -> Result<String, JsonResponseError> {
+    ///     match id {
+    ///         | 1 => Ok("Name".to_string()),
+    ///         | _ => Err(JsonResponseError::new().code("not_found")),
+    ///     }
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::success::<String>()
+    ///         .data_result(find_name(1))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn data_result<E: Into<JsonResponseError>>(
+        self,
+        result: Result<D, E>,
+    ) -> CreateSuccessJsonResponse<D, HasData> {
+        self.data_result_with(result, JsonResponseConfig::get().failure_status)
+    }
+
+    /// Set the data from a result for the response,
+    /// with the status code for the failure response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{CreateJsonResponse, JsonResponseError},
+    /// };
+    ///
+    /// fn find_name(id: u32) -> Result<String, JsonResponseError> {
+    ///     match id {
+    ///         | 1 => Ok("Name".to_string()),
+    ///         | _ => Err(JsonResponseError::new().code("not_found")),
+    ///     }
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     CreateJsonResponse::success::<String>()
+    ///         .data_result_with(find_name(2), StatusCode::NOT_FOUND)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn data_result_with<E: Into<JsonResponseError>>(
+        mut self,
+        result: Result<D, E>,
+        failure_status: StatusCode,
+    ) -> CreateSuccessJsonResponse<D, HasData> {
+        match result {
+            | Ok(data) => self.state.data = Some(data),
+            | Err(err) => {
+                self.state.success = false;
+                self.state.status = failure_status;
+                self.state.data = None;
+                self.state.errors.push(err.into());
+            },
+        }
+
+        CreateSuccessJsonResponse { state: self.state, marker: PhantomData }
+    }
+
+    /// Set the shared data for the response.
+    ///
+    /// The data is serialized through the [`Arc`],
+    /// so that the same data can be sent to many requests
+    /// without being cloned.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use axum::extract::State;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::CreateJsonResponse
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct ResponseData {
+    ///    names: Vec<String>,
+    /// }
+    ///
+    /// async fn route(State(data): State<Arc<ResponseData>>) -> Response {
+    ///     CreateJsonResponse::success::<ResponseData>()
+    ///         .data_arc(data)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn data_arc(
+        self,
+        data: Arc<D>,
+    ) -> CreateSuccessJsonResponse<Arc<D>, HasData> {
+        CreateSuccessJsonResponse {
+            state: self.state.with_data(Some(data)),
+            marker: PhantomData,
+        }
+    }
+
+    /// Set the data with pagination for the response.
+    ///
+    /// The pagination will be set into the `pagination` field of meta.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     json::{
+    ///         CreateJsonResponse,
+    ///         pagination::Pagination,
+    ///     },
+    /// };
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///    name: String,
+    /// }
+    ///
+    /// async fn route() -> Response {
+    ///     let users: Vec<User> = vec![User { name: "Name".to_string() }];
+    ///
+    ///     CreateJsonResponse::success::<Vec<User>>()
+    ///         .paginated(users, Pagination::new(1, 20).total(1))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn paginated(
+        mut self,
+        data: D,
+        pagination: Pagination,
+    ) -> CreateSuccessJsonResponse<D, HasData> {
+        self.state.data = Some(data);
+
+        match serde_json::to_value(pagination) {
+            | Ok(pagination) => {
+                self.state
+                    .meta
+                    .get_or_insert_with(Map::new)
+                    .insert("pagination".to_string(), pagination);
+            },
+            | Err(_) => self.state.is_meta_failed = true,
+        }
+
+        CreateSuccessJsonResponse { state: self.state, marker: PhantomData }
     }
 }
@@ -0,0 +1,227 @@
+use std::{fmt, sync::Arc};
+
+use serde_json::{Map, Value};
+
+use crate::response::json::error::JsonResponseError;
+
+/// Parts of a JSON response to be formatted.
+#[derive(Debug, Clone)]
+pub struct ResponseFormatParts {
+    /// Indicates whether the response is successful or not.
+    pub success: bool,
+    /// Serialized data of the response.
+    pub data: Option<Value>,
+    /// A list of errors of the response.
+    pub errors: Vec<JsonResponseError>,
+    /// Additional information of the response.
+    pub meta: Option<Map<String, Value>>,
+}
+
+/// Format of the serialized JSON response envelope.
+///
+/// Implement this trait to control the field names and structure
+/// of the envelope, and apply it globally with
+/// [`JsonResponseConfig::format`](crate::response::json::JsonResponseConfig::format),
+/// or per response with
+/// [`CreateJsonResponse::with_format`](crate::response::json::CreateJsonResponse::with_format).
+///
+/// Streaming responses are not affected by the format.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::{
+///     ResponseFormat,
+///     ResponseFormatParts,
+/// };
+/// use serde_json::{Value, json};
+///
+/// struct MyFormat;
+///
+/// impl ResponseFormat for MyFormat {
+///     fn format(&self, parts: ResponseFormatParts) -> Value {
+///         json!({
+///             "ok": parts.success,
+///             "result": parts.data,
+///             "error": parts.errors.first(),
+///         })
+///     }
+/// }
+/// ```
+pub trait ResponseFormat: Send + Sync + 'static {
+    /// Format the parts into the serialized envelope.
+    fn format(
+        &self,
+        parts: ResponseFormatParts,
+    ) -> Value;
+}
+
+/// Shared reference to a response format.
+#[derive(Clone)]
+pub(crate) struct ResponseFormatRef(pub(crate) Arc<dyn ResponseFormat>);
+
+impl ResponseFormatRef {
+    /// Create a shared reference from a response format.
+    pub(crate) fn new<F: ResponseFormat>(format: F) -> Self {
+        Self(Arc::new(format))
+    }
+}
+
+impl fmt::Debug for ResponseFormatRef {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str("ResponseFormat")
+    }
+}
+
+/// Format of the default envelope
+/// with the error array key and the error object field names renamed,
+/// for clients expecting slightly different error field names.
+///
+/// Apply it globally with
+/// [`JsonResponseConfig::format`](crate::response::json::JsonResponseConfig::format),
+/// or per response with
+/// [`CreateJsonResponse::with_format`](crate::response::json::CreateJsonResponse::with_format):
+///
+/// ```jsonc
+/// // Status: 400
+/// {
+///     "success": false,
+///     "data": null,
+///     "issues": [
+///         {
+///             "code": "parse",
+///             "pointer": ["json", "name"],
+///             "detail": "Invalid name"
+///         }
+///     ]
+/// }
+/// ```
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::{ErrorKeysFormat, JsonResponseConfig};
+///
+/// JsonResponseConfig::new()
+///     .format(
+///         ErrorKeysFormat::new()
+///             .errors("issues")
+///             .path("pointer")
+///             .message("detail"),
+///     )
+///     .set();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ErrorKeysFormat {
+    errors: String,
+    code: String,
+    path: String,
+    message: String,
+}
+
+impl ErrorKeysFormat {
+    /// Create a new format with the default names.
+    pub fn new() -> Self {
+        Self {
+            errors: "errors".to_string(),
+            code: "code".to_string(),
+            path: "path".to_string(),
+            message: "message".to_string(),
+        }
+    }
+
+    /// Set the key of the error array, `errors` by default.
+    pub fn errors<K: Into<String>>(
+        mut self,
+        key: K,
+    ) -> Self {
+        self.errors = key.into();
+
+        self
+    }
+
+    /// Set the field name of the error code, `code` by default.
+    pub fn code<K: Into<String>>(
+        mut self,
+        key: K,
+    ) -> Self {
+        self.code = key.into();
+
+        self
+    }
+
+    /// Set the field name of the error path, `path` by default.
+    pub fn path<K: Into<String>>(
+        mut self,
+        key: K,
+    ) -> Self {
+        self.path = key.into();
+
+        self
+    }
+
+    /// Set the field name of the error message, `message` by default.
+    pub fn message<K: Into<String>>(
+        mut self,
+        key: K,
+    ) -> Self {
+        self.message = key.into();
+
+        self
+    }
+
+    /// Create an error object with the field names.
+    fn create_error_object(
+        &self,
+        error: JsonResponseError,
+    ) -> Value {
+        let mut object: Map<String, Value> = Map::new();
+
+        object.insert(self.code.clone(), Value::String(error.code));
+        object.insert(
+            self.path.clone(),
+            Value::Array(error.path.into_iter().map(Value::String).collect()),
+        );
+        object.insert(
+            self.message.clone(),
+            error.message.map(Value::String).unwrap_or(Value::Null),
+        );
+
+        Value::Object(object)
+    }
+}
+
+impl Default for ErrorKeysFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResponseFormat for ErrorKeysFormat {
+    fn format(
+        &self,
+        parts: ResponseFormatParts,
+    ) -> Value {
+        let mut body: Map<String, Value> = Map::new();
+
+        body.insert("success".to_string(), Value::Bool(parts.success));
+        body.insert("data".to_string(), parts.data.unwrap_or(Value::Null));
+
+        let errors: Vec<Value> = parts
+            .errors
+            .into_iter()
+            .map(|error| self.create_error_object(error))
+            .collect();
+
+        body.insert(self.errors.clone(), Value::Array(errors));
+
+        if let Some(meta) = parts.meta {
+            body.insert("meta".to_string(), Value::Object(meta));
+        }
+
+        Value::Object(body)
+    }
+}
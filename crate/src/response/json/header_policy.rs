@@ -0,0 +1,208 @@
+use std::{collections::HashSet, sync::Arc};
+
+use http::{HeaderMap, HeaderName};
+
+/// Hop-by-hop headers, which must not be forwarded by proxies,
+/// denied with [`deny_hop_by_hop`](HeaderPolicy::deny_hop_by_hop).
+pub const HOP_BY_HOP_HEADERS: [&str; 8] = [
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// Policy of the headers set on JSON responses,
+/// applied globally with
+/// [`JsonResponseConfig::header_policy`](crate::response::json::JsonResponseConfig::header_policy).
+///
+/// Headers not in the allow-list, if set, or in the deny-list
+/// are dropped from the response when the response is created,
+/// or the response is replaced with a
+/// `500 Internal Server Error` failure response
+/// with `["response", "header", "<name>"]` in path
+/// when [`reject`](Self::reject) is enabled.
+///
+/// The content type of the response is always kept.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::{HeaderPolicy, JsonResponseConfig};
+///
+/// JsonResponseConfig::new()
+///     .header_policy(
+///         HeaderPolicy::new()
+///             .deny(["server", "x-debug-sql"])
+///             .deny_hop_by_hop(),
+///     )
+///     .set();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HeaderPolicy {
+    allow: Option<Arc<HashSet<HeaderName>>>,
+    deny: Arc<HashSet<HeaderName>>,
+    reject: bool,
+}
+
+impl HeaderPolicy {
+    /// Create a new policy allowing all headers.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::HeaderPolicy;
+    ///
+    /// let policy: HeaderPolicy = HeaderPolicy::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow only the headers with the names,
+    /// adding to the allow-list set before.
+    ///
+    /// Invalid header names are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::HeaderPolicy;
+    ///
+    /// let policy: HeaderPolicy = HeaderPolicy::new()
+    ///     .allow(["cache-control", "etag", "x-request-id"]);
+    /// ```
+    pub fn allow<I, N>(
+        mut self,
+        names: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        let allow: &mut Arc<HashSet<HeaderName>> =
+            self.allow.get_or_insert_with(Default::default);
+
+        Arc::make_mut(allow).extend(parse_names_fn(names));
+
+        self
+    }
+
+    /// Deny the headers with the names,
+    /// taking precedence over the allow-list.
+    ///
+    /// Invalid header names are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::HeaderPolicy;
+    ///
+    /// let policy: HeaderPolicy = HeaderPolicy::new()
+    ///     .deny(["server", "x-powered-by"]);
+    /// ```
+    pub fn deny<I, N>(
+        mut self,
+        names: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        Arc::make_mut(&mut self.deny).extend(parse_names_fn(names));
+
+        self
+    }
+
+    /// Deny the [`HOP_BY_HOP_HEADERS`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::HeaderPolicy;
+    ///
+    /// let policy: HeaderPolicy = HeaderPolicy::new().deny_hop_by_hop();
+    /// ```
+    pub fn deny_hop_by_hop(self) -> Self {
+        self.deny(HOP_BY_HOP_HEADERS)
+    }
+
+    /// Set whether to reject the response with a failure response
+    /// instead of dropping the headers, such as for development.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::HeaderPolicy;
+    ///
+    /// let policy: HeaderPolicy = HeaderPolicy::new()
+    ///     .deny(["server"])
+    ///     .reject(cfg!(debug_assertions));
+    /// ```
+    pub fn reject(
+        mut self,
+        reject: bool,
+    ) -> Self {
+        self.reject = reject;
+
+        self
+    }
+
+    /// Check whether the header is allowed.
+    fn is_allowed(
+        &self,
+        name: &HeaderName,
+    ) -> bool {
+        if self.deny.contains(name) {
+            return false;
+        }
+
+        match &self.allow {
+            | Some(allow) => allow.contains(name),
+            | None => true,
+        }
+    }
+
+    /// Apply the policy to the headers.
+    ///
+    /// Returns the name of the first header not allowed
+    /// if [`reject`](Self::reject) is enabled.
+    pub(crate) fn apply(
+        &self,
+        header_map: &mut HeaderMap,
+    ) -> Result<(), HeaderName> {
+        let denied: Vec<HeaderName> = header_map
+            .keys()
+            .filter(|name| !self.is_allowed(name))
+            .cloned()
+            .collect();
+
+        if self.reject {
+            if let Some(name) = denied.into_iter().next() {
+                return Err(name);
+            }
+
+            return Ok(());
+        }
+
+        for name in denied {
+            header_map.remove(name);
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse the header names, ignoring invalid names.
+fn parse_names_fn<I, N>(names: I) -> impl Iterator<Item = HeaderName>
+where
+    I: IntoIterator<Item = N>,
+    N: AsRef<str>,
+{
+    names
+        .into_iter()
+        .filter_map(|name| HeaderName::try_from(name.as_ref()).ok())
+}
@@ -0,0 +1,262 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use crate::{
+    extract::locale::Locale,
+    response::json::{create::JsonResponseState, error::JsonResponseError},
+};
+
+/// Default locale of the message catalogs.
+pub const LOCALE_DEFAULT: &str = "en";
+
+thread_local! {
+    /// Locale of the request being handled on the current thread.
+    static CURRENT_LOCALE: RefCell<Option<Locale>> =
+        const { RefCell::new(None) };
+}
+
+/// Catalog of the error messages by locale and error code,
+/// available with `i18n` feature.
+///
+/// Locales are language tags compared case-insensitively,
+/// such as `fr` or `zh-Hant`,
+/// and requests are negotiated to the locales of the catalog
+/// with the `Accept-Language` header,
+/// see [`Localize`](crate::layers::Localize).
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::{MessageCatalog, ResponseError};
+///
+/// let catalog: MessageCatalog = MessageCatalog::new()
+///     .message("fr", ResponseError::Parse.as_code(), "Requête invalide")
+///     .messages("de", [
+///         (ResponseError::Parse.as_code(), "Ungültige Anfrage"),
+///         (ResponseError::NotFound.as_code(), "Ressource nicht gefunden"),
+///     ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MessageCatalog {
+    /// Locale used when no locale is accepted.
+    default_locale: String,
+    /// Messages by locale and error code.
+    messages: Arc<HashMap<String, HashMap<String, String>>>,
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self {
+            default_locale: LOCALE_DEFAULT.to_string(),
+            messages: Arc::new(HashMap::new()),
+        }
+    }
+}
+
+impl MessageCatalog {
+    /// Create a new empty catalog
+    /// with the [`en`](LOCALE_DEFAULT) default locale.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the locale used when no locale of the catalog is accepted.
+    pub fn default_locale<L: Into<String>>(
+        mut self,
+        locale: L,
+    ) -> Self {
+        self.default_locale = locale.into().to_ascii_lowercase();
+
+        self
+    }
+
+    /// Set the message of an error code in a locale.
+    pub fn message<L, C, M>(
+        mut self,
+        locale: L,
+        code: C,
+        message: M,
+    ) -> Self
+    where
+        L: Into<String>,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        Arc::make_mut(&mut self.messages)
+            .entry(locale.into().to_ascii_lowercase())
+            .or_default()
+            .insert(code.into(), message.into());
+
+        self
+    }
+
+    /// Set the messages of error codes in a locale,
+    /// keeping the messages set before.
+    pub fn messages<L, I, C, M>(
+        mut self,
+        locale: L,
+        messages: I,
+    ) -> Self
+    where
+        L: Into<String>,
+        I: IntoIterator<Item = (C, M)>,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        Arc::make_mut(&mut self.messages)
+            .entry(locale.into().to_ascii_lowercase())
+            .or_default()
+            .extend(
+                messages
+                    .into_iter()
+                    .map(|(code, message)| (code.into(), message.into())),
+            );
+
+        self
+    }
+
+    /// Get the message of an error code in a locale.
+    pub fn get(
+        &self,
+        locale: &str,
+        code: &str,
+    ) -> Option<&str> {
+        self.messages
+            .get(&locale.to_ascii_lowercase())
+            .and_then(|messages| messages.get(code))
+            .map(String::as_str)
+    }
+
+    /// Get the locale of the catalog for a language tag,
+    /// matching the tag or its primary language,
+    /// such as `fr` for `fr-CA`.
+    fn match_fn(
+        &self,
+        tag: &str,
+    ) -> Option<String> {
+        if self.messages.contains_key(tag) {
+            return Some(tag.to_string());
+        }
+
+        let primary: &str = tag.split('-').next()?;
+
+        match self.messages.contains_key(primary) {
+            | true => Some(primary.to_string()),
+            | false => None,
+        }
+    }
+
+    /// Negotiate the locale of the catalog
+    /// with the value of an `Accept-Language` header,
+    /// such as `fr-CH, fr;q=0.9, en;q=0.8`.
+    ///
+    /// Returns the default locale if no locale of the catalog is accepted.
+    pub fn negotiate(
+        &self,
+        accept_language: &str,
+    ) -> String {
+        let mut tags: Vec<(String, f32)> = accept_language
+            .split(',')
+            .filter_map(|range| {
+                let mut params = range.split(';').map(str::trim);
+
+                let tag: String = params.next()?.to_ascii_lowercase();
+
+                let q: f32 = params
+                    .find_map(|param| param.strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.parse::<f32>().ok())?;
+
+                match tag.is_empty() || q <= 0.0 {
+                    | true => None,
+                    | false => Some((tag, q)),
+                }
+            })
+            .collect();
+
+        // stable for the same quality
+        tags.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        tags.iter()
+            .find_map(|(tag, _)| match tag.as_str() {
+                | "*" => Some(self.default_locale.clone()),
+                | tag => self.match_fn(tag),
+            })
+            .unwrap_or_else(|| self.default_locale.clone())
+    }
+}
+
+/// Future with the locale of the request being handled,
+/// responses created when polling the future pick the locale up.
+pub(crate) struct LocaleScope<F> {
+    locale: Option<Locale>,
+    fut: Pin<Box<F>>,
+}
+
+impl<F> LocaleScope<F> {
+    /// Create a new scope of a locale.
+    pub(crate) fn new(
+        locale: Locale,
+        fut: F,
+    ) -> Self {
+        Self { locale: Some(locale), fut: Box::pin(fut) }
+    }
+}
+
+impl<F: Future> Future for LocaleScope<F> {
+    type Output = F::Output;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let this: &mut Self = self.get_mut();
+
+        let prev: Option<Locale> =
+            CURRENT_LOCALE.with(|l| l.replace(this.locale.take()));
+
+        let poll: Poll<F::Output> = this.fut.as_mut().poll(cx);
+
+        this.locale = CURRENT_LOCALE.with(|l| l.replace(prev));
+
+        poll
+    }
+}
+
+/// Take the locale of a state,
+/// or the locale of the request being handled for rejections.
+pub(crate) fn take_locale_fn<D>(
+    mut state: JsonResponseState<D>
+) -> (JsonResponseState<D>, Option<Locale>) {
+    let locale: Option<Locale> = match state.locale.take() {
+        | Some(locale) => Some(locale),
+        | None if state.is_rejection => {
+            CURRENT_LOCALE.with(|l| l.borrow().clone())
+        },
+        | None => None,
+    };
+
+    (state, locale)
+}
+
+/// Replace the messages of errors with the messages of a locale by code.
+pub(crate) fn localize_errors_fn(
+    mut errors: Vec<JsonResponseError>,
+    locale: Option<&Locale>,
+) -> Vec<JsonResponseError> {
+    let Some(locale) = locale else {
+        return errors;
+    };
+
+    for error in errors.iter_mut() {
+        if let Some(message) = locale.message(&error.code) {
+            error.message = Some(message.to_string());
+        }
+    }
+
+    errors
+}
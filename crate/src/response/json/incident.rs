@@ -0,0 +1,189 @@
+use std::{
+    collections::hash_map::RandomState,
+    fmt::{self, Display, Formatter},
+    hash::{BuildHasher, Hasher},
+    str::FromStr,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Header name of the incident ID.
+pub const INCIDENT_ID_HEADER: &str = "x-incident-id";
+
+/// Key of the incident ID in meta.
+pub const INCIDENT_ID_META_KEY: &str = "incident_id";
+
+/// Crockford's base32 alphabet of ULIDs.
+const INCIDENT_ID_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Length of an incident ID.
+const INCIDENT_ID_LENGTH: usize = 26;
+
+/// Counter of the generated incident IDs.
+static INCIDENT_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Get a random number with a randomly seeded hasher.
+fn random_fn(seed: u64) -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+
+    hasher.write_u64(seed);
+
+    hasher.finish()
+}
+
+/// Get the value of a base32 character,
+/// where `I` and `L` are read as `1`, and `O` as `0`.
+fn decode_char_fn(c: u8) -> Option<u128> {
+    let c: u8 = match c.to_ascii_uppercase() {
+        | b'I' | b'L' => b'1',
+        | b'O' => b'0',
+        | c => c,
+    };
+
+    INCIDENT_ID_ALPHABET.iter().position(|a| *a == c).map(|value| value as u128)
+}
+
+/// Incident ID of a server error response,
+/// available with `incident_id` feature.
+///
+/// Incident IDs are ULIDs,
+/// 26 characters of Crockford's base32 sorted by the time created,
+/// such as `01ARZ3NDEKTSV4RRFFQ69G5FAV`.
+///
+/// An ID is generated for every failure response
+/// with a `5xx` status code created by the JSON builders,
+/// and set into the `incident_id` of meta,
+/// the `x-incident-id` header and the tracing event of the response,
+/// so that a report from a user can be looked up in the logs.
+///
+/// ```jsonc
+/// // Status: 500
+/// {
+///     "success": false,
+///     "errors": [
+///         {
+///             "code": "server",
+///             "message": "Internal server error"
+///         }
+///     ],
+///     "meta": {
+///         "incident_id": "01ARZ3NDEKTSV4RRFFQ69G5FAV"
+///     }
+/// }
+/// ```
+///
+/// ## Example
+///
+/// Parse an ID reported by a user,
+/// case-insensitive and with dashes and spaces ignored:
+///
+/// ```no_run
+/// use std::time::SystemTime;
+///
+/// use jder_axum::response::json::IncidentId;
+///
+/// let id: IncidentId = "01arz3nd-ektsv4rr-ffq69g5fav".parse().unwrap();
+///
+/// // 01ARZ3NDEKTSV4RRFFQ69G5FAV
+/// println!("{}", id);
+///
+/// // when the incident happened
+/// let time: SystemTime = id.time();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IncidentId(u128);
+
+impl IncidentId {
+    /// Generate a new incident ID with the current time.
+    pub fn new() -> Self {
+        let count: u64 = INCIDENT_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        let duration: Duration =
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+
+        let millis: u128 = duration.as_millis() & 0xffff_ffff_ffff;
+
+        let high: u64 = random_fn(count ^ duration.as_nanos() as u64);
+        let low: u64 = random_fn(high ^ count.rotate_left(32));
+
+        // 80 bits of randomness
+        let random: u128 =
+            (((high as u128) << 64) | low as u128) & ((1 << 80) - 1);
+
+        Self((millis << 80) | random)
+    }
+
+    /// Get the time the incident ID was created, in milliseconds.
+    pub fn time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis((self.0 >> 80) as u64)
+    }
+}
+
+impl Default for IncidentId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for IncidentId {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        let mut buf: [u8; INCIDENT_ID_LENGTH] = [0; INCIDENT_ID_LENGTH];
+
+        for (index, c) in buf.iter_mut().enumerate() {
+            let shift: usize = (INCIDENT_ID_LENGTH - 1 - index) * 5;
+
+            *c = INCIDENT_ID_ALPHABET[((self.0 >> shift) & 0x1f) as usize];
+        }
+
+        // the alphabet is ASCII
+        f.write_str(std::str::from_utf8(&buf).map_err(|_| fmt::Error)?)
+    }
+}
+
+/// Error of parsing an invalid incident ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncidentIdError;
+
+impl Display for IncidentIdError {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str("invalid incident ID")
+    }
+}
+
+impl std::error::Error for IncidentIdError {}
+
+impl FromStr for IncidentId {
+    type Err = IncidentIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut value: u128 = 0;
+        let mut length: usize = 0;
+
+        for c in s.trim().bytes().filter(|c| !matches!(c, b'-' | b' ')) {
+            let digit: u128 = decode_char_fn(c).ok_or(IncidentIdError)?;
+
+            // the first character holds 3 bits only
+            if length == 0 && digit > 7 {
+                return Err(IncidentIdError);
+            }
+
+            value = (value << 5) | digit;
+            length += 1;
+
+            if length > INCIDENT_ID_LENGTH {
+                return Err(IncidentIdError);
+            }
+        }
+
+        match length == INCIDENT_ID_LENGTH {
+            | true => Ok(Self(value)),
+            | false => Err(IncidentIdError),
+        }
+    }
+}
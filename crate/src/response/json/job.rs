@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+
+use crate::response::json::error::JsonResponseError;
+
+/// Default `Retry-After` of in-progress jobs in seconds.
+pub const JOB_RETRY_AFTER_DEFAULT: u64 = 1;
+
+/// State of an asynchronous job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    /// Waiting to be run.
+    Queued,
+    /// Being run.
+    Running,
+    /// Finished successfully.
+    Succeeded,
+    /// Finished with errors.
+    Failed,
+}
+
+impl JobState {
+    /// Check if the job is queued or running.
+    pub fn is_in_progress(&self) -> bool {
+        matches!(self, Self::Queued | Self::Running)
+    }
+}
+
+/// Status of an asynchronous job for polling,
+/// created with [`job`](crate::response::json::CreateJsonResponse::job).
+///
+/// ```jsonc
+/// // 202 Accepted
+/// // Retry-After: 1
+/// {
+///     "success": true,
+///     "data": {
+///         "id": "job_1",
+///         "state": "running",
+///         "progress": 40,
+///         "result_url": null,
+///         "errors": []
+///     },
+///     "errors": []
+/// }
+/// ```
+///
+/// For API documentation generation with utoipa,
+/// `ToSchema` derive is available with the `utoipa` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::JobStatus;
+///
+/// let status: JobStatus = JobStatus::running("job_1").progress(40);
+///
+/// let status: JobStatus =
+///     JobStatus::succeeded("job_1").result_url("/reports/1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct JobStatus {
+    /// Identifier of the job.
+    pub id: String,
+    /// State of the job.
+    pub state: JobState,
+    /// Progress of the job in percent, from `0` to `100`.
+    pub progress: Option<u8>,
+    /// Link to the result of the job when succeeded.
+    pub result_url: Option<String>,
+    /// A list of errors of the job when failed.
+    pub errors: Vec<JsonResponseError>,
+    /// Seconds to wait before polling again when in progress,
+    /// sent as the `Retry-After` header.
+    ///
+    /// Not included in the response body.
+    #[serde(skip)]
+    pub retry_after: Option<u64>,
+}
+
+impl JobStatus {
+    /// Create a job status with the id and state.
+    pub fn new<I: Into<String>>(
+        id: I,
+        state: JobState,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            state,
+            progress: None,
+            result_url: None,
+            errors: Vec::new(),
+            retry_after: None,
+        }
+    }
+
+    /// Create a queued job status.
+    pub fn queued<I: Into<String>>(id: I) -> Self {
+        Self::new(id, JobState::Queued)
+    }
+
+    /// Create a running job status.
+    pub fn running<I: Into<String>>(id: I) -> Self {
+        Self::new(id, JobState::Running)
+    }
+
+    /// Create a succeeded job status with progress of `100`.
+    pub fn succeeded<I: Into<String>>(id: I) -> Self {
+        Self::new(id, JobState::Succeeded).progress(100)
+    }
+
+    /// Create a failed job status.
+    pub fn failed<I: Into<String>>(id: I) -> Self {
+        Self::new(id, JobState::Failed)
+    }
+
+    /// Set the progress in percent, capped at `100`.
+    pub fn progress(
+        mut self,
+        progress: u8,
+    ) -> Self {
+        self.progress = Some(progress.min(100));
+
+        self
+    }
+
+    /// Set the link to the result.
+    pub fn result_url<U: Into<String>>(
+        mut self,
+        url: U,
+    ) -> Self {
+        self.result_url = Some(url.into());
+
+        self
+    }
+
+    /// Add an error of the job.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::{JobStatus, JsonResponseError};
+    ///
+    /// let status: JobStatus = JobStatus::failed("job_1").add_error(
+    ///     JsonResponseError::new().code("timeout").message("Job timed out"),
+    /// );
+    /// ```
+    pub fn add_error<E: Into<JsonResponseError>>(
+        mut self,
+        error: E,
+    ) -> Self {
+        self.errors.push(error.into());
+
+        self
+    }
+
+    /// Set the seconds to wait before polling again,
+    /// [`JOB_RETRY_AFTER_DEFAULT`] when not set.
+    pub fn retry_after(
+        mut self,
+        seconds: u64,
+    ) -> Self {
+        self.retry_after = Some(seconds);
+
+        self
+    }
+}
@@ -1,13 +1,88 @@
+pub(crate) mod api;
+pub(crate) mod capabilities;
+pub(crate) mod config;
 pub(crate) mod create;
 pub(crate) mod error;
+pub(crate) mod format;
+pub(crate) mod header_policy;
+#[cfg(feature = "i18n")]
+pub(crate) mod i18n;
+#[cfg(feature = "incident_id")]
+pub(crate) mod incident;
+pub(crate) mod job;
+pub mod pagination;
+pub(crate) mod problem;
+#[cfg(feature = "rejection_config")]
+pub(crate) mod rejection;
 pub(crate) mod response;
+#[cfg(feature = "schema_check")]
+pub(crate) mod schema;
+#[cfg(feature = "spill")]
+pub(crate) mod spill;
+pub(crate) mod validation;
 
-pub use crate::response::json::error::{JsonResponseError, ResponseError};
+pub use crate::response::json::api::{ApiError, ApiResult};
+
+pub use crate::response::json::capabilities::{
+    ACCEPT_PATCH_HEADER, ACCEPT_POST_HEADER, Capabilities,
+};
+
+#[cfg(feature = "derive")]
+pub use jder_axum_derive::{IntoResponseData, JsonError, jder_handler};
+
+pub use crate::response::json::config::{
+    JsonResponseConfig, MESSAGE_ELLIPSIS_DEFAULT,
+};
+
+pub use crate::response::json::error::{
+    CustomError, ErrorCode, JsonResponseError, ResponseError,
+};
+
+pub use crate::response::json::format::{
+    ErrorKeysFormat, ResponseFormat, ResponseFormatParts,
+};
+
+pub use crate::response::json::header_policy::{
+    HOP_BY_HOP_HEADERS, HeaderPolicy,
+};
+
+#[cfg(feature = "i18n")]
+pub use crate::response::json::i18n::{LOCALE_DEFAULT, MessageCatalog};
+
+#[cfg(feature = "incident_id")]
+pub use crate::response::json::incident::{
+    INCIDENT_ID_HEADER, INCIDENT_ID_META_KEY, IncidentId, IncidentIdError,
+};
+
+pub use crate::response::json::job::{
+    JOB_RETRY_AFTER_DEFAULT, JobState, JobStatus,
+};
+
+pub use crate::response::json::problem::{
+    CONTENT_TYPE_PROBLEM_JSON, PROBLEM_TYPE_DEFAULT, ProblemDetails,
+};
+
+#[cfg(feature = "rejection_config")]
+pub use crate::response::json::rejection::RejectionConfig;
 
 pub use crate::response::json::response::JsonResponse;
 
-pub use crate::response::json::create::success::CreateSuccessJsonResponse;
+#[cfg(feature = "schema_check")]
+pub use crate::response::json::schema::DataSchema;
+
+pub use crate::response::json::validation::{
+    IntoValidationErrors, ValidationErrors,
+};
+
+pub use crate::response::json::create::success::{
+    CreateSuccessJsonResponse, HasData, NoData,
+};
 
 pub use crate::response::json::create::failure::CreateFailureJsonResponse;
 
-pub use crate::response::json::create::CreateJsonResponse;
+#[cfg(feature = "json_stream")]
+pub use crate::response::json::create::stream::CreateStreamJsonResponse;
+
+pub use crate::response::json::create::{
+    CreateFormattedJsonResponse, CreateJsonResponse,
+};
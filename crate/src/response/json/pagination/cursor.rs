@@ -0,0 +1,337 @@
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::{
+    hmac::{HMAC_SHA256_OUTPUT_SIZE, sign_sha256, verify_sha256},
+    response::json::error::{JsonResponseError, ResponseError},
+};
+
+/// Default version of page cursors.
+pub const PAGE_CURSOR_VERSION_DEFAULT: u8 = 1;
+
+/// Page cursor error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageCursorError {
+    /// The cursor is not a valid token.
+    Malformed,
+    /// The signature of the cursor does not match.
+    InvalidSignature,
+    /// The version of the cursor is not supported.
+    InvalidVersion,
+    /// The cursor is expired.
+    Expired,
+}
+
+impl PageCursorError {
+    /// Get the error message as `&str`.
+    pub fn as_message(&self) -> &str {
+        match self {
+            | Self::Malformed => "Malformed cursor",
+            | Self::InvalidSignature => "Invalid cursor signature",
+            | Self::InvalidVersion => "Unsupported cursor version",
+            | Self::Expired => "Cursor is expired",
+        }
+    }
+}
+
+impl fmt::Display for PageCursorError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str(self.as_message())
+    }
+}
+
+impl std::error::Error for PageCursorError {}
+
+impl From<PageCursorError> for JsonResponseError {
+    fn from(error: PageCursorError) -> Self {
+        JsonResponseError::from(ResponseError::Parse)
+            .path(["query", "cursor"])
+            .message(error.as_message())
+    }
+}
+
+/// Key for signing and verifying page cursors,
+/// obtained from the router state by
+/// [`PaginationCursor`](crate::extract::pagination::PaginationCursor).
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::pagination::PageCursorKey;
+///
+/// let key: PageCursorKey = PageCursorKey::new("secret");
+/// ```
+#[derive(Clone)]
+pub struct PageCursorKey {
+    key: Arc<[u8]>,
+}
+
+impl PageCursorKey {
+    /// Create a key from bytes.
+    pub fn new<K: Into<Vec<u8>>>(key: K) -> Self {
+        Self { key: key.into().into() }
+    }
+
+    /// Get the key as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.key
+    }
+}
+
+impl fmt::Debug for PageCursorKey {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("PageCursorKey").finish_non_exhaustive()
+    }
+}
+
+/// Serialized payload of a page cursor.
+#[derive(Serialize, Deserialize)]
+struct PageCursorPayload<T> {
+    /// Version.
+    v: u8,
+    /// Expiry in seconds since Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    e: Option<u64>,
+    /// Data.
+    d: T,
+}
+
+/// Opaque cursor for cursor-based pagination,
+/// available with `pagination_cursor` feature.
+///
+/// The cursor is encoded as a URL-safe base64 token,
+/// signed with HMAC-SHA256 to detect tampering,
+/// and can be versioned and expired.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use jder_axum::response::json::pagination::PageCursor;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct LastSeen {
+///     id: usize,
+/// }
+///
+/// let key: &[u8] = b"secret";
+///
+/// let token: String = PageCursor::new(LastSeen { id: 10 })
+///     .expires_in(Duration::from_secs(3600))
+///     .encode(key);
+///
+/// let cursor: PageCursor<LastSeen> =
+///     PageCursor::decode(&token, key).unwrap();
+///
+/// assert_eq!(cursor.data.id, 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PageCursor<T> {
+    /// Version of the cursor.
+    pub version: u8,
+    /// Expiry of the cursor.
+    pub expires_at: Option<SystemTime>,
+    /// Data of the cursor.
+    pub data: T,
+}
+
+impl<T> PageCursor<T> {
+    /// Create a new page cursor with
+    /// [`PAGE_CURSOR_VERSION_DEFAULT`] and no expiry.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::PageCursor;
+    ///
+    /// let cursor: PageCursor<usize> = PageCursor::new(10);
+    /// ```
+    pub fn new(data: T) -> Self {
+        Self { version: PAGE_CURSOR_VERSION_DEFAULT, expires_at: None, data }
+    }
+
+    /// Set the version of the cursor.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::PageCursor;
+    ///
+    /// let cursor: PageCursor<usize> = PageCursor::new(10).version(2);
+    /// ```
+    pub fn version(
+        mut self,
+        version: u8,
+    ) -> Self {
+        self.version = version;
+
+        self
+    }
+
+    /// Set the expiry of the cursor.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::SystemTime;
+    ///
+    /// use jder_axum::response::json::pagination::PageCursor;
+    ///
+    /// let cursor: PageCursor<usize> =
+    ///     PageCursor::new(10).expires_at(SystemTime::now());
+    /// ```
+    pub fn expires_at(
+        mut self,
+        time: SystemTime,
+    ) -> Self {
+        self.expires_at = Some(time);
+
+        self
+    }
+
+    /// Set the expiry of the cursor from now.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::json::pagination::PageCursor;
+    ///
+    /// let cursor: PageCursor<usize> =
+    ///     PageCursor::new(10).expires_in(Duration::from_secs(3600));
+    /// ```
+    pub fn expires_in(
+        self,
+        duration: Duration,
+    ) -> Self {
+        self.expires_at(SystemTime::now() + duration)
+    }
+}
+
+impl<T: Serialize> PageCursor<T> {
+    /// Encode the cursor into a signed token.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::PageCursor;
+    ///
+    /// let token: String = PageCursor::new(10).encode(b"secret");
+    /// ```
+    pub fn encode(
+        &self,
+        key: &[u8],
+    ) -> String {
+        let payload: PageCursorPayload<&T> = PageCursorPayload {
+            v: self.version,
+            e: self.expires_at.map(to_timestamp),
+            d: &self.data,
+        };
+
+        // serializing a `Serialize` value into bytes is not supposed to fail,
+        // fallback to an empty payload which will never be decoded
+        let payload: Vec<u8> = serde_json::to_vec(&payload).unwrap_or_default();
+
+        let signature: [u8; HMAC_SHA256_OUTPUT_SIZE] =
+            sign_sha256(key, &payload);
+
+        format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(payload),
+            URL_SAFE_NO_PAD.encode(signature)
+        )
+    }
+}
+
+impl<T: DeserializeOwned> PageCursor<T> {
+    /// Decode a signed token with [`PAGE_CURSOR_VERSION_DEFAULT`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::PageCursor;
+    ///
+    /// let token: String = PageCursor::new(10).encode(b"secret");
+    ///
+    /// let cursor: PageCursor<usize> =
+    ///     PageCursor::decode(&token, b"secret").unwrap();
+    /// ```
+    pub fn decode(
+        token: &str,
+        key: &[u8],
+    ) -> Result<Self, PageCursorError> {
+        Self::decode_version(token, key, PAGE_CURSOR_VERSION_DEFAULT)
+    }
+
+    /// Decode a signed token with a specific version.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::PageCursor;
+    ///
+    /// let token: String = PageCursor::new(10).version(2).encode(b"secret");
+    ///
+    /// let cursor: PageCursor<usize> =
+    ///     PageCursor::decode_version(&token, b"secret", 2).unwrap();
+    /// ```
+    pub fn decode_version(
+        token: &str,
+        key: &[u8],
+        version: u8,
+    ) -> Result<Self, PageCursorError> {
+        let (payload, signature) =
+            token.split_once('.').ok_or(PageCursorError::Malformed)?;
+
+        let payload: Vec<u8> = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| PageCursorError::Malformed)?;
+
+        let signature: Vec<u8> = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| PageCursorError::Malformed)?;
+
+        if !verify_sha256(key, &payload, &signature) {
+            return Err(PageCursorError::InvalidSignature);
+        }
+
+        let payload: PageCursorPayload<T> = serde_json::from_slice(&payload)
+            .map_err(|_| PageCursorError::Malformed)?;
+
+        if payload.v != version {
+            return Err(PageCursorError::InvalidVersion);
+        }
+
+        if let Some(expiry) = payload.e {
+            if to_timestamp(SystemTime::now()) >= expiry {
+                return Err(PageCursorError::Expired);
+            }
+        }
+
+        Ok(Self {
+            version: payload.v,
+            expires_at: payload.e.map(|e| UNIX_EPOCH + Duration::from_secs(e)),
+            data: payload.d,
+        })
+    }
+}
+
+/// Convert a time into seconds since Unix epoch.
+fn to_timestamp(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
@@ -0,0 +1,13 @@
+/// Page cursor module,
+/// available with `pagination_cursor` feature.
+#[cfg(feature = "pagination_cursor")]
+pub mod cursor;
+
+pub mod page;
+
+pub use crate::response::json::pagination::page::Pagination;
+
+#[cfg(feature = "pagination_cursor")]
+pub use crate::response::json::pagination::cursor::{
+    PAGE_CURSOR_VERSION_DEFAULT, PageCursor, PageCursorError, PageCursorKey,
+};
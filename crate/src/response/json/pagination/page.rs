@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "pagination_cursor")]
+use crate::response::json::pagination::cursor::{PageCursor, PageCursorKey};
+
+/// Pagination information for list responses.
+///
+/// Set into the `pagination` field of meta with
+/// [`paginated`](crate::response::json::CreateSuccessJsonResponse::paginated).
+///
+/// For API documentation generation with utoipa,
+/// `ToSchema` derive is available with the `utoipa` feature.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::pagination::Pagination;
+///
+/// let pagination: Pagination = Pagination::new(1, 20)
+///     .total(95)
+///     .next_cursor("abc");
+///
+/// assert_eq!(pagination.total_pages, Some(5));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct Pagination {
+    /// Current page.
+    pub page: u64,
+    /// Number of items per page.
+    pub per_page: u64,
+    /// Total number of items.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    /// Total number of pages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<u64>,
+    /// Cursor of the next page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// Cursor of the previous page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_cursor: Option<String>,
+}
+
+impl Pagination {
+    /// Create a new pagination.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::Pagination;
+    ///
+    /// let pagination: Pagination = Pagination::new(1, 20);
+    /// ```
+    pub fn new(
+        page: u64,
+        per_page: u64,
+    ) -> Self {
+        Self { page, per_page, ..Default::default() }
+    }
+
+    /// Set the total number of items,
+    /// the total number of pages will be calculated.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::Pagination;
+    ///
+    /// let pagination: Pagination = Pagination::new(1, 20).total(95);
+    /// ```
+    pub fn total(
+        mut self,
+        total: u64,
+    ) -> Self {
+        self.total = Some(total);
+        self.total_pages = Some(match self.per_page {
+            | 0 => 0,
+            | per_page => total.div_ceil(per_page),
+        });
+
+        self
+    }
+
+    /// Set the cursor of the next page.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::Pagination;
+    ///
+    /// let pagination: Pagination = Pagination::new(1, 20)
+    ///     .next_cursor("abc");
+    /// ```
+    pub fn next_cursor<C: Into<String>>(
+        mut self,
+        cursor: C,
+    ) -> Self {
+        self.next_cursor = Some(cursor.into());
+
+        self
+    }
+
+    /// Set the cursor of the previous page.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::Pagination;
+    ///
+    /// let pagination: Pagination = Pagination::new(2, 20)
+    ///     .prev_cursor("abc");
+    /// ```
+    pub fn prev_cursor<C: Into<String>>(
+        mut self,
+        cursor: C,
+    ) -> Self {
+        self.prev_cursor = Some(cursor.into());
+
+        self
+    }
+
+    /// Set the cursor of the next page
+    /// from a page cursor signed with a key,
+    /// available with `pagination_cursor` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::{
+    ///     PageCursor,
+    ///     PageCursorKey,
+    ///     Pagination,
+    /// };
+    ///
+    /// let key: PageCursorKey = PageCursorKey::new("secret");
+    ///
+    /// let pagination: Pagination = Pagination::new(1, 20)
+    ///     .next_page_cursor(&PageCursor::new(20), &key);
+    /// ```
+    #[cfg(feature = "pagination_cursor")]
+    pub fn next_page_cursor<T: Serialize>(
+        self,
+        cursor: &PageCursor<T>,
+        key: &PageCursorKey,
+    ) -> Self {
+        self.next_cursor(cursor.encode(key.as_bytes()))
+    }
+
+    /// Set the cursor of the previous page
+    /// from a page cursor signed with a key,
+    /// available with `pagination_cursor` feature.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::pagination::{
+    ///     PageCursor,
+    ///     PageCursorKey,
+    ///     Pagination,
+    /// };
+    ///
+    /// let key: PageCursorKey = PageCursorKey::new("secret");
+    ///
+    /// let pagination: Pagination = Pagination::new(2, 20)
+    ///     .prev_page_cursor(&PageCursor::new(0), &key);
+    /// ```
+    #[cfg(feature = "pagination_cursor")]
+    pub fn prev_page_cursor<T: Serialize>(
+        self,
+        cursor: &PageCursor<T>,
+        key: &PageCursorKey,
+    ) -> Self {
+        self.prev_cursor(cursor.encode(key.as_bytes()))
+    }
+}
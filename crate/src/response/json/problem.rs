@@ -0,0 +1,97 @@
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::response::json::error::JsonResponseError;
+
+/// Problem details content type.
+pub const CONTENT_TYPE_PROBLEM_JSON: &str = "application/problem+json";
+
+/// Default problem type.
+pub const PROBLEM_TYPE_DEFAULT: &str = "about:blank";
+
+/// Problem details (RFC 9457) for failure responses.
+///
+/// The fields are mapped from the failure response:
+/// - `type`: [`PROBLEM_TYPE_DEFAULT`]
+/// - `title`: reason phrase of the status code
+/// - `status`: status code
+/// - `detail`: message of the first error
+/// - `instance`: path of the first error as a JSON pointer fragment,
+///   such as `#/body/name`
+/// - `errors`: all errors as an extension member
+///
+/// For API documentation generation with utoipa,
+/// `ToSchema` derive is available with the `utoipa` feature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ProblemDetails {
+    /// URI reference identifying the problem type.
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    /// Short summary of the problem type.
+    pub title: String,
+    /// Status code of the response.
+    pub status: u16,
+    /// Explanation specific to this occurrence of the problem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// URI reference identifying this occurrence of the problem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// A list of errors of the problem.
+    pub errors: Vec<JsonResponseError>,
+    /// Additional information of the problem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
+    pub meta: Option<Map<String, Value>>,
+}
+
+impl ProblemDetails {
+    /// Create problem details from a status code and errors.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::{
+    ///     JsonResponseError,
+    ///     ProblemDetails,
+    /// };
+    ///
+    /// let problem: ProblemDetails = ProblemDetails::new(
+    ///     StatusCode::BAD_REQUEST,
+    ///     vec![JsonResponseError::new().code("parse")],
+    /// );
+    /// ```
+    pub fn new(
+        status: StatusCode,
+        errors: Vec<JsonResponseError>,
+    ) -> Self {
+        let first: Option<&JsonResponseError> = errors.first();
+
+        let detail: Option<String> = first.and_then(|e| e.message.clone());
+
+        let instance: Option<String> = first
+            .filter(|e| !e.path.is_empty())
+            .map(|e| format!("#/{}", to_pointer(&e.path)));
+
+        Self {
+            problem_type: PROBLEM_TYPE_DEFAULT.to_string(),
+            title: status.canonical_reason().unwrap_or_default().to_string(),
+            status: status.as_u16(),
+            detail,
+            instance,
+            errors,
+            meta: None,
+        }
+    }
+}
+
+/// Join path segments into a JSON pointer, escaping `~` and `/`.
+pub(crate) fn to_pointer(path: &[String]) -> String {
+    path.iter()
+        .map(|segment| segment.replace('~', "~0").replace('/', "~1"))
+        .collect::<Vec<String>>()
+        .join("/")
+}
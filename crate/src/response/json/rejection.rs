@@ -0,0 +1,200 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use http::StatusCode;
+
+use crate::response::json::{
+    create::JsonResponseState, error::JsonResponseError,
+};
+
+thread_local! {
+    /// Rejection configuration of the request being handled
+    /// on the current thread.
+    static CURRENT_REJECTION_CONFIG: RefCell<Option<RejectionConfig>> =
+        const { RefCell::new(None) };
+}
+
+/// Overrides of the rejections with an error code.
+#[derive(Debug, Clone, Default)]
+struct RejectionRule {
+    status: Option<StatusCode>,
+    code: Option<String>,
+    message: Option<String>,
+}
+
+/// Configuration of the rejections from the extractors of this crate,
+/// applied by the [`JderLayer`](crate::layers::JderLayer),
+/// available with `rejection_config` feature.
+///
+/// The status, the error code and the message of rejections
+/// are overridden by the original error code,
+/// such as [`Parse`](crate::response::json::ResponseError::Parse),
+/// while failure responses from handlers are kept.
+///
+/// Message templates can include the original fields:
+/// - `{code}`: the original error code
+/// - `{path}`: the path joined with `.`, such as `json.user.age`
+/// - `{message}`: the original message
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::http::StatusCode;
+/// use jder_axum::response::json::{RejectionConfig, ResponseError};
+///
+/// let config: RejectionConfig = RejectionConfig::new()
+///     .status(ResponseError::Parse.as_code(), StatusCode::UNPROCESSABLE_ENTITY)
+///     .code(ResponseError::Parse.as_code(), "invalid_request")
+///     .message(ResponseError::Parse.as_code(), "Invalid `{path}`: {message}");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RejectionConfig {
+    rules: Arc<HashMap<String, RejectionRule>>,
+}
+
+impl RejectionConfig {
+    /// Create a new configuration without overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the rule of an error code to override.
+    fn rule_fn<C: Into<String>>(
+        &mut self,
+        code: C,
+    ) -> &mut RejectionRule {
+        Arc::make_mut(&mut self.rules).entry(code.into()).or_default()
+    }
+
+    /// Set the status of rejections with an error code.
+    pub fn status<C: Into<String>, S: Into<StatusCode>>(
+        mut self,
+        code: C,
+        status: S,
+    ) -> Self {
+        self.rule_fn(code).status = Some(status.into());
+
+        self
+    }
+
+    /// Replace the error code of rejections with an error code.
+    pub fn code<C: Into<String>, T: Into<String>>(
+        mut self,
+        code: C,
+        to: T,
+    ) -> Self {
+        self.rule_fn(code).code = Some(to.into());
+
+        self
+    }
+
+    /// Set the message template of rejections with an error code.
+    pub fn message<C: Into<String>, M: Into<String>>(
+        mut self,
+        code: C,
+        template: M,
+    ) -> Self {
+        self.rule_fn(code).message = Some(template.into());
+
+        self
+    }
+
+    /// Apply the overrides to an error,
+    /// returns the overridden status if any.
+    fn apply_fn(
+        &self,
+        error: &mut JsonResponseError,
+    ) -> Option<StatusCode> {
+        let rule: &RejectionRule = self.rules.get(&error.code)?;
+
+        if let Some(template) = &rule.message {
+            let message: String = template
+                .replace("{code}", &error.code)
+                .replace("{path}", &error.path.join("."))
+                .replace("{message}", error.message.as_deref().unwrap_or(""));
+
+            error.message = Some(message);
+        }
+
+        if let Some(code) = &rule.code {
+            error.code = code.clone();
+        }
+
+        rule.status
+    }
+}
+
+/// Future with the rejection configuration of the request being handled,
+/// rejections created when polling the future pick the configuration up.
+pub(crate) struct RejectionScope<F> {
+    config: Option<RejectionConfig>,
+    fut: Pin<Box<F>>,
+}
+
+impl<F> RejectionScope<F> {
+    /// Create a new scope of a rejection configuration.
+    pub(crate) fn new(
+        config: RejectionConfig,
+        fut: F,
+    ) -> Self {
+        Self { config: Some(config), fut: Box::pin(fut) }
+    }
+}
+
+impl<F: Future> Future for RejectionScope<F> {
+    type Output = F::Output;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let this: &mut Self = self.get_mut();
+
+        let prev: Option<RejectionConfig> =
+            CURRENT_REJECTION_CONFIG.with(|c| c.replace(this.config.take()));
+
+        let poll: Poll<F::Output> = this.fut.as_mut().poll(cx);
+
+        this.config = CURRENT_REJECTION_CONFIG.with(|c| c.replace(prev));
+
+        poll
+    }
+}
+
+/// Apply the rejection configuration of the request being handled
+/// to a rejection state,
+/// with the status of the first overridden error.
+pub(crate) fn apply_rejection_config_fn<D>(
+    mut state: JsonResponseState<D>
+) -> JsonResponseState<D> {
+    if !state.is_rejection {
+        return state;
+    }
+
+    CURRENT_REJECTION_CONFIG.with(|config| {
+        let config = config.borrow();
+
+        let Some(config) = config.as_ref() else {
+            return;
+        };
+
+        let mut status: Option<StatusCode> = None;
+
+        for error in state.errors.iter_mut() {
+            let overridden: Option<StatusCode> = config.apply_fn(error);
+
+            status = status.or(overridden);
+        }
+
+        if let Some(status) = status {
+            state.status = status;
+        }
+    });
+
+    state
+}
@@ -1,13 +1,47 @@
+use std::sync::Arc;
+
+use axum_core::response::IntoResponse;
+use http::StatusCode;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
-use crate::response::json::error::JsonResponseError;
+use crate::response::{
+    Response,
+    json::{
+        config::JsonResponseConfig,
+        create::{JsonResponseState, base::create_json_response_fn},
+        error::JsonResponseError,
+    },
+};
 
 /// JSON response.
 ///
+/// The response can be returned from a route directly,
+/// see [`status`](JsonResponse::status) for the status code.
+///
 /// For API documentation generation with utoipa,
 /// `ToSchema` derive is available with the `utoipa` feature.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// For TypeScript type generation with ts-rs,
+/// `TS` derive is available with the `ts_export` feature,
+/// where the data type should implement `TS`.
+///
+/// It can be converted from and into
+/// [`jder_core::JsonResponse`], the envelope without the status code,
+/// for sharing the types with client applications.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::JsonResponse;
+///
+/// async fn route() -> JsonResponse<String> {
+///     JsonResponse::new().data("Name".to_string())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "ts_export", derive(ts_rs::TS))]
 pub struct JsonResponse<D = ()> {
     /// Indicates whether the response is successful or not.
     pub success: bool,
@@ -15,6 +49,23 @@ pub struct JsonResponse<D = ()> {
     pub data: Option<D>,
     /// A list of errors for the response when `success` is `false`.
     pub errors: Vec<JsonResponseError>,
+    /// Additional information for the response,
+    /// such as pagination, request id or timing.
+    ///
+    /// Omitted from the response when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
+    #[cfg_attr(
+        feature = "ts_export",
+        ts(optional, type = "Record<string, unknown>")
+    )]
+    pub meta: Option<Map<String, Value>>,
+    /// Status code when returned from a route,
+    /// derived from `success` when not set.
+    ///
+    /// Not included in the response body.
+    #[serde(skip)]
+    pub status: Option<StatusCode>,
 }
 
 impl<D> JsonResponse<D> {
@@ -28,7 +79,50 @@ impl<D> JsonResponse<D> {
     /// let response: JsonResponse = JsonResponse::new();
     /// ```
     pub fn new() -> Self {
-        Self { success: true, data: None, errors: vec![] }
+        Self {
+            success: true,
+            data: None,
+            errors: vec![],
+            meta: None,
+            status: None,
+        }
+    }
+
+    /// Create a success JSON response with data,
+    /// such as the expected response in tests.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponse;
+    ///
+    /// let response: JsonResponse<String> =
+    ///     JsonResponse::success_with("Name".to_string());
+    ///
+    /// assert_eq!(response, JsonResponse::new().data("Name".to_string()));
+    /// ```
+    pub fn success_with(data: D) -> Self {
+        Self::new().data(data)
+    }
+
+    /// Create a failure JSON response with errors,
+    /// such as the expected response in tests.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::{JsonResponse, JsonResponseError};
+    ///
+    /// let response: JsonResponse = JsonResponse::failure_with([
+    ///     JsonResponseError::new().code("parse"),
+    /// ]);
+    /// ```
+    pub fn failure_with<V, E>(errors: V) -> Self
+    where
+        V: IntoIterator<Item = E>,
+        E: Into<JsonResponseError>,
+    {
+        Self::new().success(false).errors(errors)
     }
 
     /// Create a JSON response from another JSON response.
@@ -45,7 +139,13 @@ impl<D> JsonResponse<D> {
     pub fn from<R: Into<JsonResponse<D>>>(response: R) -> Self {
         let res: JsonResponse<D> = response.into();
 
-        Self { success: res.success, data: res.data, errors: res.errors }
+        Self {
+            success: res.success,
+            data: res.data,
+            errors: res.errors,
+            meta: res.meta,
+            status: res.status,
+        }
     }
 
     /// Set the success status for the response.
@@ -139,6 +239,54 @@ impl<D> JsonResponse<D> {
 
         self
     }
+
+    /// Set the status code for the response when returned from a route.
+    ///
+    /// Without the status code,
+    /// the default success or failure status is used,
+    /// see [`JsonResponseConfig`](crate::response::json::JsonResponseConfig).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::json::JsonResponse;
+    ///
+    /// let response: JsonResponse = JsonResponse::new()
+    ///     .status(StatusCode::CREATED);
+    /// ```
+    pub fn status<S: Into<StatusCode>>(
+        mut self,
+        status: S,
+    ) -> Self {
+        self.status = Some(status.into());
+
+        self
+    }
+
+    /// Set the meta for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::JsonResponse;
+    /// use serde_json::{Map, Value};
+    ///
+    /// let mut meta: Map<String, Value> = Map::new();
+    ///
+    /// meta.insert("request_id".to_string(), Value::from("abc"));
+    ///
+    /// let response: JsonResponse = JsonResponse::new()
+    ///     .meta(meta);
+    /// ```
+    pub fn meta(
+        mut self,
+        meta: Map<String, Value>,
+    ) -> Self {
+        self.meta = Some(meta);
+
+        self
+    }
 }
 
 impl Default for JsonResponse {
@@ -146,3 +294,49 @@ impl Default for JsonResponse {
         Self::new()
     }
 }
+
+impl<D> From<jder_core::JsonResponse<D>> for JsonResponse<D> {
+    fn from(response: jder_core::JsonResponse<D>) -> Self {
+        Self {
+            success: response.success,
+            data: response.data,
+            errors: response.errors,
+            meta: response.meta,
+            status: None,
+        }
+    }
+}
+
+impl<D> From<JsonResponse<D>> for jder_core::JsonResponse<D> {
+    fn from(response: JsonResponse<D>) -> Self {
+        Self {
+            success: response.success,
+            data: response.data,
+            errors: response.errors,
+            meta: response.meta,
+        }
+    }
+}
+
+impl<D: Serialize> IntoResponse for JsonResponse<D> {
+    fn into_response(self) -> Response {
+        let mut state: JsonResponseState<D> = if self.success {
+            JsonResponseState::success()
+        } else {
+            JsonResponseState::failure()
+        };
+
+        let config: Arc<JsonResponseConfig> = JsonResponseConfig::get();
+
+        state.status = match self.status {
+            | Some(status) => status,
+            | None if self.success => config.success_status,
+            | None => config.failure_status,
+        };
+        state.data = self.data;
+        state.errors = self.errors;
+        state.meta = self.meta;
+
+        create_json_response_fn(state)
+    }
+}
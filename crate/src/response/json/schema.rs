@@ -0,0 +1,325 @@
+use std::{
+    any::type_name,
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Registered schemas by data type name.
+static SCHEMAS: RwLock<Option<HashMap<&'static str, DataSchema>>> =
+    RwLock::new(None);
+
+/// JSON Schema of the data of a type, available with `schema_check` feature.
+///
+/// In debug builds, the data of every JSON response
+/// of a registered type is serialized and validated against the schema,
+/// so accidental changes to the response shapes are caught
+/// before they reach the clients.
+/// Release builds skip the validation.
+///
+/// On contract drift, the validation panics by default,
+/// or logs an error with `tracing` feature if not strict.
+///
+/// A subset of JSON Schema is supported:
+/// `type`, `enum`, `const`, `properties`, `required`,
+/// `additionalProperties`, `items` and `anyOf`,
+/// other keywords are ignored.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::DataSchema;
+/// use serde::Serialize;
+/// use serde_json::json;
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// DataSchema::new(json!({
+///     "type": "object",
+///     "properties": {
+///         "id": { "type": "integer" },
+///         "name": { "type": "string" },
+///     },
+///     "required": ["id", "name"],
+///     "additionalProperties": false,
+/// }))
+/// .register::<User>();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DataSchema {
+    /// JSON Schema of the data.
+    schema: Arc<Value>,
+    /// Whether to panic on contract drift.
+    strict: bool,
+}
+
+impl DataSchema {
+    /// Create a new strict schema.
+    pub fn new(schema: Value) -> Self {
+        Self { schema: Arc::new(schema), strict: true }
+    }
+
+    /// Set whether to panic on contract drift,
+    /// or log an error with `tracing` feature instead.
+    ///
+    /// Defaults to `true`.
+    pub fn strict(
+        mut self,
+        strict: bool,
+    ) -> Self {
+        self.strict = strict;
+
+        self
+    }
+
+    /// Register the schema for the data of a type,
+    /// replacing the schema registered before.
+    pub fn register<T: ?Sized>(self) {
+        if let Ok(mut schemas) = SCHEMAS.write() {
+            schemas
+                .get_or_insert_with(HashMap::new)
+                .insert(type_name::<T>(), self);
+        }
+    }
+
+    /// Remove the schema registered for the data of a type.
+    pub fn unregister<T: ?Sized>() {
+        if let Ok(mut schemas) = SCHEMAS.write() {
+            if let Some(schemas) = schemas.as_mut() {
+                schemas.remove(type_name::<T>());
+            }
+        }
+    }
+
+    /// Get the schema registered for the data of a type.
+    pub fn get<T: ?Sized>() -> Option<Self> {
+        match SCHEMAS.read() {
+            | Ok(schemas) => schemas.as_ref()?.get(type_name::<T>()).cloned(),
+            | Err(_) => None,
+        }
+    }
+
+    /// Validate data against the schema,
+    /// returning the violations if any.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::DataSchema;
+    /// use serde_json::json;
+    ///
+    /// let schema: DataSchema = DataSchema::new(json!({ "type": "string" }));
+    ///
+    /// assert!(schema.validate(&1).is_err());
+    /// ```
+    pub fn validate<T: Serialize + ?Sized>(
+        &self,
+        data: &T,
+    ) -> Result<(), Vec<String>> {
+        let value: Value = match serde_json::to_value(data) {
+            | Ok(value) => value,
+            | Err(err) => return Err(vec![format!("data: {err}")]),
+        };
+
+        let mut violations: Vec<String> = Vec::new();
+
+        validate_fn(&self.schema, &value, "data", &mut violations);
+
+        match violations.is_empty() {
+            | true => Ok(()),
+            | false => Err(violations),
+        }
+    }
+}
+
+/// Get the JSON Schema type name of a value.
+fn type_of_fn(value: &Value) -> &'static str {
+    match value {
+        | Value::Null => "null",
+        | Value::Bool(_) => "boolean",
+        | Value::Number(_) => "number",
+        | Value::String(_) => "string",
+        | Value::Array(_) => "array",
+        | Value::Object(_) => "object",
+    }
+}
+
+/// Check whether a value is of a JSON Schema type.
+fn is_type_fn(
+    value: &Value,
+    ty: &str,
+) -> bool {
+    match ty {
+        | "integer" => match value {
+            | Value::Number(n) => {
+                n.is_i64()
+                    || n.is_u64()
+                    || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+            },
+            | _ => false,
+        },
+        | ty => type_of_fn(value) == ty,
+    }
+}
+
+/// Validate a value against a schema at a path,
+/// pushing the violations found.
+fn validate_fn(
+    schema: &Value,
+    value: &Value,
+    path: &str,
+    violations: &mut Vec<String>,
+) {
+    let schema: &Map<String, Value> = match schema {
+        | Value::Object(schema) => schema,
+        | Value::Bool(false) => {
+            violations.push(format!("`{path}`: not allowed"));
+            return;
+        },
+        | _ => return,
+    };
+
+    // type
+    if let Some(ty) = schema.get("type") {
+        let types: Vec<&str> = match ty {
+            | Value::String(ty) => vec![ty.as_str()],
+            | Value::Array(types) => {
+                types.iter().filter_map(Value::as_str).collect()
+            },
+            | _ => Vec::new(),
+        };
+
+        if !types.is_empty() && !types.iter().any(|ty| is_type_fn(value, ty)) {
+            violations.push(format!(
+                "`{path}`: expected type `{}`, found `{}`",
+                types.join("` or `"),
+                type_of_fn(value)
+            ));
+
+            return;
+        }
+    }
+
+    // enum
+    if let Some(Value::Array(values)) = schema.get("enum") {
+        if !values.contains(value) {
+            violations.push(format!("`{path}`: value `{value}` not in enum"));
+        }
+    }
+
+    // const
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            violations.push(format!(
+                "`{path}`: expected `{expected}`, found `{value}`"
+            ));
+        }
+    }
+
+    // any of
+    if let Some(Value::Array(schemas)) = schema.get("anyOf") {
+        let is_matched: bool = schemas.iter().any(|schema| {
+            let mut nested: Vec<String> = Vec::new();
+
+            validate_fn(schema, value, path, &mut nested);
+
+            nested.is_empty()
+        });
+
+        if !is_matched {
+            violations.push(format!("`{path}`: no schema of `anyOf` matched"));
+        }
+    }
+
+    match value {
+        | Value::Object(object) => {
+            validate_object_fn(schema, object, path, violations)
+        },
+        | Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_fn(
+                        item_schema,
+                        item,
+                        &format!("{path}[{index}]"),
+                        violations,
+                    );
+                }
+            }
+        },
+        | _ => {},
+    }
+}
+
+/// Validate an object against the object keywords of a schema.
+fn validate_object_fn(
+    schema: &Map<String, Value>,
+    object: &Map<String, Value>,
+    path: &str,
+    violations: &mut Vec<String>,
+) {
+    let properties: Option<&Map<String, Value>> =
+        schema.get("properties").and_then(Value::as_object);
+
+    // required
+    if let Some(Value::Array(required)) = schema.get("required") {
+        for key in required.iter().filter_map(Value::as_str) {
+            if !object.contains_key(key) {
+                violations.push(format!("`{path}.{key}`: missing"));
+            }
+        }
+    }
+
+    for (key, field) in object {
+        let field_path: String = format!("{path}.{key}");
+
+        match properties.and_then(|properties| properties.get(key)) {
+            | Some(field_schema) => {
+                validate_fn(field_schema, field, &field_path, violations)
+            },
+            | None => match schema.get("additionalProperties") {
+                | Some(Value::Bool(false)) => {
+                    violations.push(format!("`{field_path}`: unexpected"));
+                },
+                | Some(additional) => {
+                    validate_fn(additional, field, &field_path, violations)
+                },
+                | None => {},
+            },
+        }
+    }
+}
+
+/// Validate the data of a response against the schema registered
+/// for its type, panicking or logging on contract drift.
+#[cfg(debug_assertions)]
+pub(crate) fn check_data_fn<D: Serialize>(data: &D) {
+    let Some(schema) = DataSchema::get::<D>() else {
+        return;
+    };
+
+    let Err(violations) = schema.validate(data) else {
+        return;
+    };
+
+    if schema.strict {
+        panic!(
+            "response data of `{}` does not match its schema: {}",
+            type_name::<D>(),
+            violations.join(", ")
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::error!(
+        data = type_name::<D>(),
+        violations = ?violations,
+        "response data does not match its schema"
+    );
+}
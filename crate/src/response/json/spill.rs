@@ -0,0 +1,141 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Seek, SeekFrom, Write},
+    path::PathBuf,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use axum::body::Bytes;
+use axum_core::body::Body;
+use bytes::BytesMut;
+use futures_util::stream;
+use tokio::io::AsyncReadExt as _;
+
+/// Size of the chunks streamed from a spilled file in bytes.
+const SPILL_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Counter of the spilled files of the process.
+static SPILL_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Temp file removed on drop.
+struct SpillFile {
+    path: PathBuf,
+}
+
+impl SpillFile {
+    /// Create a new temp file for reading and writing.
+    fn create() -> io::Result<(Self, File)> {
+        let path: PathBuf = std::env::temp_dir().join(format!(
+            "jder_axum-{}-{}.json",
+            std::process::id(),
+            SPILL_COUNTER.fetch_add(1, Ordering::Relaxed),
+        ));
+
+        let file: File = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+
+        Ok((Self { path }, file))
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Writer that buffers in memory up to a threshold,
+/// and continues into a temp file beyond the threshold.
+pub(crate) struct SpillWriter {
+    threshold: usize,
+    buf: Vec<u8>,
+    file: Option<(SpillFile, io::BufWriter<File>)>,
+}
+
+impl SpillWriter {
+    /// Create a new writer with the memory threshold in bytes,
+    /// and the capacity reserved for the memory buffer.
+    pub(crate) fn new(
+        threshold: usize,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            threshold,
+            buf: Vec::with_capacity(capacity.min(threshold)),
+            file: None,
+        }
+    }
+
+    /// Create the body from the written bytes,
+    /// streamed from the temp file if spilled.
+    pub(crate) fn into_body(self) -> io::Result<Body> {
+        let (spill, writer) = match self.file {
+            | Some(file) => file,
+            | None => return Ok(Body::from(Bytes::from(self.buf))),
+        };
+
+        let mut file: File =
+            writer.into_inner().map_err(|err| err.into_error())?;
+
+        file.seek(SeekFrom::Start(0))?;
+
+        let file: tokio::fs::File = tokio::fs::File::from_std(file);
+
+        // keep the temp file until the stream is dropped
+        let stream =
+            stream::try_unfold((file, spill), |(mut file, spill)| async move {
+                let mut chunk: BytesMut =
+                    BytesMut::with_capacity(SPILL_CHUNK_SIZE);
+
+                match file.read_buf(&mut chunk).await? {
+                    | 0 => Ok::<_, io::Error>(None),
+                    | _ => Ok(Some((chunk.freeze(), (file, spill)))),
+                }
+            });
+
+        Ok(Body::from_stream(stream))
+    }
+}
+
+impl Write for SpillWriter {
+    fn write(
+        &mut self,
+        bytes: &[u8],
+    ) -> io::Result<usize> {
+        if let Some((_, writer)) = &mut self.file {
+            return writer.write(bytes);
+        }
+
+        if self.buf.len() + bytes.len() <= self.threshold {
+            self.buf.extend_from_slice(bytes);
+
+            return Ok(bytes.len());
+        }
+
+        // move the buffer into a temp file
+        let (spill, file) = SpillFile::create()?;
+
+        let mut writer: io::BufWriter<File> =
+            io::BufWriter::with_capacity(SPILL_CHUNK_SIZE, file);
+
+        writer.write_all(&self.buf)?;
+
+        self.buf = Vec::new();
+
+        let written: usize = writer.write(bytes)?;
+
+        self.file = Some((spill, writer));
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.file {
+            | Some((_, writer)) => writer.flush(),
+            | None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use http::StatusCode;
+
+use crate::response::json::{
+    api::ApiError,
+    error::{JsonResponseError, ResponseError},
+};
+
+/// Conversion of validation failures into a list of errors,
+/// used by the
+/// [`validation_errors`](crate::response::json::CreateFailureJsonResponse::validation_errors)
+/// function of failure response.
+///
+/// The trait can be implemented for the errors of validation libraries,
+/// through a wrapper type,
+/// with the field path in `path`,
+/// the rule in `code` and the detail in `message`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::{IntoValidationErrors, JsonResponseError};
+///
+/// struct FieldErrors(Vec<(String, String)>);
+///
+/// impl IntoValidationErrors for FieldErrors {
+///     fn into_validation_errors(self) -> Vec<JsonResponseError> {
+///         self.0
+///             .into_iter()
+///             .map(|(field, rule)| {
+///                 JsonResponseError::new().code(rule).path(["json", &field])
+///             })
+///             .collect()
+///     }
+/// }
+/// ```
+pub trait IntoValidationErrors {
+    /// Convert into a list of errors.
+    fn into_validation_errors(self) -> Vec<JsonResponseError>;
+}
+
+impl IntoValidationErrors for Vec<JsonResponseError> {
+    fn into_validation_errors(self) -> Vec<JsonResponseError> {
+        self
+    }
+}
+
+impl IntoValidationErrors for JsonResponseError {
+    fn into_validation_errors(self) -> Vec<JsonResponseError> {
+        vec![self]
+    }
+}
+
+/// A list of validation failures of fields.
+///
+/// Converted into an [`ApiError`]
+/// with `422 Unprocessable Entity` status.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::ValidationErrors;
+///
+/// let mut errors: ValidationErrors = ValidationErrors::new();
+///
+/// errors.add(["json", "name"], "length", "Name is too short");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ValidationErrors {
+    errors: Vec<JsonResponseError>,
+}
+
+impl ValidationErrors {
+    /// Create a new empty list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a failure of a field.
+    ///
+    /// Empty codes are replaced with
+    /// [`Validation`](ResponseError::Validation) code.
+    pub fn add<P, S, C, M>(
+        &mut self,
+        path: P,
+        code: C,
+        message: M,
+    ) -> &mut Self
+    where
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        let code: String = code.into();
+
+        let code: String = match code.is_empty() {
+            | true => ResponseError::Validation.to_code(),
+            | false => code,
+        };
+
+        self.errors.push(
+            JsonResponseError::new().code(code).path(path).message(message),
+        );
+
+        self
+    }
+
+    /// Add a failure referencing two fields,
+    /// such as a mismatch between a path parameter and a body field,
+    /// as an error for each field.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::json::ValidationErrors;
+    ///
+    /// let mut errors: ValidationErrors = ValidationErrors::new();
+    ///
+    /// errors.add_cross(
+    ///     ["path", "id"],
+    ///     ["json", "id"],
+    ///     "mismatch",
+    ///     "ID does not match",
+    /// );
+    /// ```
+    pub fn add_cross<P1, S1, P2, S2, C, M>(
+        &mut self,
+        first: P1,
+        second: P2,
+        code: C,
+        message: M,
+    ) -> &mut Self
+    where
+        P1: IntoIterator<Item = S1>,
+        S1: Into<String>,
+        P2: IntoIterator<Item = S2>,
+        S2: Into<String>,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        let code: String = code.into();
+        let message: String = message.into();
+
+        self.add(first, code.clone(), message.clone())
+            .add(second, code, message)
+    }
+
+    /// Check if there is no failure.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Get the number of failures.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Get an iterator over the failures.
+    pub fn iter(&self) -> impl Iterator<Item = &JsonResponseError> {
+        self.errors.iter()
+    }
+}
+
+impl IntoValidationErrors for ValidationErrors {
+    fn into_validation_errors(self) -> Vec<JsonResponseError> {
+        self.errors
+    }
+}
+
+/// Failures of [`garde`] validation,
+/// available with `garde` feature,
+/// with the field path in `path`,
+/// such as `["items", "0", "name"]`,
+/// [`Validation`](ResponseError::Validation) code
+/// and the detail in `message`.
+#[cfg(feature = "garde")]
+impl IntoValidationErrors for garde::Report {
+    fn into_validation_errors(self) -> Vec<JsonResponseError> {
+        ValidationErrors::from(self).errors
+    }
+}
+
+/// Failures of [`validator`] validation,
+/// available with `validator` feature,
+/// with the field path in `path`,
+/// such as `["items", "0", "name"]` for nested structs and lists,
+/// the rule in `code` and the custom message in `message` if any.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::http::StatusCode;
+/// use jder_axum::response::{Response, json::CreateJsonResponse};
+/// use validator::Validate;
+///
+/// #[derive(Validate)]
+/// struct CreateUser {
+///     #[validate(length(min = 3))]
+///     name: String,
+/// }
+///
+/// fn respond(user: CreateUser) -> Response {
+///     match user.validate() {
+///         | Ok(()) => CreateJsonResponse::dataless().create(),
+///         | Err(errors) => {
+///             CreateJsonResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY)
+///                 .validation_errors(errors)
+///                 .create()
+///         },
+///     }
+/// }
+/// ```
+#[cfg(feature = "validator")]
+impl IntoValidationErrors for validator::ValidationErrors {
+    fn into_validation_errors(self) -> Vec<JsonResponseError> {
+        ValidationErrors::from(self).errors
+    }
+}
+
+/// Add the failures of [`validator`] validation under a path,
+/// in the order of field names.
+#[cfg(feature = "validator")]
+fn push_validator_errors_fn(
+    errors: &mut Vec<JsonResponseError>,
+    path: &[String],
+    source: validator::ValidationErrors,
+) {
+    use validator::ValidationErrorsKind;
+
+    let mut fields: Vec<_> = source.into_errors().into_iter().collect();
+
+    fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    for (field, kind) in fields {
+        let mut path: Vec<String> = path.to_vec();
+
+        path.push(field.into_owned());
+
+        match kind {
+            | ValidationErrorsKind::Field(fails) => {
+                for fail in fails {
+                    let error: JsonResponseError = JsonResponseError::new()
+                        .code(fail.code)
+                        .path(path.clone());
+
+                    errors.push(match fail.message {
+                        | Some(message) => error.message(message),
+                        | None => error,
+                    });
+                }
+            },
+            | ValidationErrorsKind::Struct(source) => {
+                push_validator_errors_fn(errors, &path, *source);
+            },
+            | ValidationErrorsKind::List(items) => {
+                for (index, source) in items {
+                    let mut path: Vec<String> = path.clone();
+
+                    path.push(index.to_string());
+
+                    push_validator_errors_fn(errors, &path, *source);
+                }
+            },
+        }
+    }
+}
+
+#[cfg(feature = "validator")]
+impl From<validator::ValidationErrors> for ValidationErrors {
+    fn from(source: validator::ValidationErrors) -> Self {
+        let mut errors: Vec<JsonResponseError> = Vec::new();
+
+        push_validator_errors_fn(&mut errors, &[], source);
+
+        Self { errors }
+    }
+}
+
+#[cfg(feature = "garde")]
+impl From<garde::Report> for ValidationErrors {
+    fn from(report: garde::Report) -> Self {
+        let mut errors: ValidationErrors = ValidationErrors::new();
+
+        for (path, error) in report.iter() {
+            // components are stored from the innermost field
+            let path: Vec<String> = path
+                .__iter()
+                .rev()
+                .filter(|(_, component)| !component.is_empty())
+                .map(|(_, component)| component.to_string())
+                .collect();
+
+            errors.add(path, "", error.message());
+        }
+
+        errors
+    }
+}
+
+impl From<ValidationErrors> for ApiError {
+    fn from(errors: ValidationErrors) -> Self {
+        Self {
+            status: Some(StatusCode::UNPROCESSABLE_ENTITY),
+            errors: errors.errors,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use serde_json::{Map, Value};
+
+use crate::response::json::{
+    JsonResponseError, ResponseFormat, ResponseFormatParts, problem::to_pointer,
+};
+
+/// JSON:API content type.
+pub const CONTENT_TYPE_JSONAPI: &str = "application/vnd.api+json";
+
+/// Default JSON:API failure response in case of an internal server error.
+pub const FAILURE_RESPONSE_JSONAPI_DEFAULT: &str = "{\"errors\":[{\"code\":\"server\",\"detail\":\"Internal server error.\"}]}";
+
+/// Format of a JSON:API document.
+///
+/// The response is mapped into a JSON:API document:
+/// - `data`: data of a success response, or `null` if not set
+/// - `errors`: errors of a failure response,
+///   with `message` as `detail` and `path` as `source.pointer`
+/// - `meta`: meta of the response if set
+///
+/// Use
+/// [`create_negotiated`](crate::response::json::CreateSuccessJsonResponse::create_negotiated)
+/// to respond with a JSON:API document
+/// when the client accepts `application/vnd.api+json`,
+/// or apply it directly with
+/// [`CreateJsonResponse::with_format`](crate::response::json::CreateJsonResponse::with_format).
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     json::CreateJsonResponse,
+///     jsonapi::JsonApiFormat,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateJsonResponse::with_format(JsonApiFormat)
+///         .success::<String>()
+///         .data("Name".to_string())
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonApiFormat;
+
+impl ResponseFormat for JsonApiFormat {
+    fn format(
+        &self,
+        parts: ResponseFormatParts,
+    ) -> Value {
+        let mut document: Map<String, Value> = Map::new();
+
+        if parts.success {
+            document
+                .insert("data".to_string(), parts.data.unwrap_or(Value::Null));
+        } else {
+            let errors: Vec<Value> =
+                parts.errors.iter().map(create_error_object).collect();
+
+            document.insert("errors".to_string(), Value::Array(errors));
+        }
+
+        if let Some(meta) = parts.meta {
+            document.insert("meta".to_string(), Value::Object(meta));
+        }
+
+        Value::Object(document)
+    }
+}
+
+/// Create a JSON:API error object from an error.
+fn create_error_object(error: &JsonResponseError) -> Value {
+    let mut object: Map<String, Value> = Map::new();
+
+    object.insert("code".to_string(), Value::String(error.code.clone()));
+
+    if let Some(message) = &error.message {
+        object.insert("detail".to_string(), Value::String(message.clone()));
+    }
+
+    if !error.path.is_empty() {
+        let mut source: Map<String, Value> = Map::new();
+
+        source.insert(
+            "pointer".to_string(),
+            Value::String(format!("/{}", to_pointer(&error.path))),
+        );
+
+        object.insert("source".to_string(), Value::Object(source));
+    }
+
+    Value::Object(object)
+}
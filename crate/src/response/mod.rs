@@ -1,6 +1,90 @@
+/// Archive response module,
+/// available with `archive` feature.
+#[cfg(feature = "archive")]
+pub mod archive;
+
+/// Broadcast module,
+/// available with `broadcast` feature.
+#[cfg(feature = "broadcast")]
+pub mod broadcast;
+
+pub mod cache_control;
+
+/// CBOR response module,
+/// available with `cbor` feature.
+#[cfg(feature = "cbor")]
+pub mod cbor;
+
+/// CSV response module,
+/// available with `csv` feature.
+#[cfg(feature = "csv")]
+pub mod csv;
+
+/// Cached data provider module,
+/// available with `data_cache` feature.
+#[cfg(feature = "data_cache")]
+pub mod data_cache;
+
+/// Event module,
+/// available with `webhook` feature.
+#[cfg(feature = "webhook")]
+pub mod event;
+
+pub mod cookie;
+
+/// GraphQL response module,
+/// available with `graphql` feature.
+#[cfg(feature = "graphql")]
+pub mod graphql;
+
 pub mod header;
+
+/// Image response module,
+/// available with `image` feature.
+#[cfg(feature = "image")]
+pub mod image;
+
 pub mod json;
 
+/// JSON:API response module,
+/// available with `jsonapi` feature.
+#[cfg(feature = "jsonapi")]
+pub mod jsonapi;
+
+/// MessagePack response module,
+/// available with `msgpack` feature.
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+
+/// NDJSON response module,
+/// available with `ndjson` feature.
+#[cfg(feature = "ndjson")]
+pub mod ndjson;
+
+pub mod redirect;
+
+pub mod security_headers;
+
+/// Shutdown signal module for streaming responses,
+/// available with `shutdown` feature.
+#[cfg(feature = "shutdown")]
+pub mod shutdown;
+
+/// Sitemap and robots response module,
+/// available with `sitemap` feature.
+#[cfg(feature = "sitemap")]
+pub mod sitemap;
+
+/// Server-sent events response module,
+/// available with `sse` feature.
+#[cfg(feature = "sse")]
+pub mod sse;
+
+/// XML response module,
+/// available with `xml` feature.
+#[cfg(feature = "xml")]
+pub mod xml;
+
 use axum_core::body::Body;
 use http::{
     Error as HTTPError, HeaderMap, HeaderName, HeaderValue,
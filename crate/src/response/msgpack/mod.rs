@@ -0,0 +1,195 @@
+use http::StatusCode;
+
+use crate::response::json::{
+    CreateFailureJsonResponse, CreateJsonResponse, CreateSuccessJsonResponse,
+    HasData, create::ResponseEncoding,
+};
+
+/// MessagePack content type.
+pub const CONTENT_TYPE_MSGPACK: &str = "application/msgpack";
+
+/// Create a MessagePack response for a route.
+///
+/// The response has the same structure as a JSON response,
+/// but the body is encoded as MessagePack
+/// with `application/msgpack` content type,
+/// and structs are encoded as maps of field names.
+///
+/// The builders are shared with
+/// [`CreateJsonResponse`](crate::response::json::CreateJsonResponse),
+/// except that problem details are always encoded as JSON.
+///
+/// ## Examples
+///
+/// A success MessagePack response without data:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     msgpack::CreateMsgpackResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateMsgpackResponse::dataless().create()
+/// }
+/// ```
+///
+/// A success MessagePack response:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     msgpack::CreateMsgpackResponse,
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Default, Serialize)]
+/// struct ResponseData {
+///    name: String,
+/// }
+///
+/// async fn route() -> Response {
+///     CreateMsgpackResponse::success::<ResponseData>()
+///         .data(ResponseData { name: "Name".to_string() })
+///         .create()
+/// }
+/// ```
+///
+/// A failure MessagePack response:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     msgpack::CreateMsgpackResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateMsgpackResponse::failure().create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateMsgpackResponse;
+
+impl CreateMsgpackResponse {
+    /// Create a success MessagePack response without data.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     msgpack::CreateMsgpackResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateMsgpackResponse::dataless().create()
+    /// }
+    /// ```
+    pub fn dataless() -> CreateSuccessJsonResponse<(), HasData> {
+        let mut res: CreateSuccessJsonResponse<(), HasData> =
+            CreateJsonResponse::dataless();
+
+        res.state.encoding = ResponseEncoding::Msgpack;
+
+        res
+    }
+
+    /// Create a success MessagePack response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     msgpack::CreateMsgpackResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateMsgpackResponse::success::<String>()
+    ///         .data("Name".to_string())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn success<D>() -> CreateSuccessJsonResponse<D> {
+        let mut res: CreateSuccessJsonResponse<D> =
+            CreateJsonResponse::success();
+
+        res.state.encoding = ResponseEncoding::Msgpack;
+
+        res
+    }
+
+    /// Create a success MessagePack response with a status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     msgpack::CreateMsgpackResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateMsgpackResponse::success_with::<String>(StatusCode::CREATED)
+    ///         .data("Name".to_string())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn success_with<D>(status: StatusCode) -> CreateSuccessJsonResponse<D> {
+        let mut res: CreateSuccessJsonResponse<D> =
+            CreateJsonResponse::success_with(status);
+
+        res.state.encoding = ResponseEncoding::Msgpack;
+
+        res
+    }
+
+    /// Create a failure MessagePack response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     msgpack::CreateMsgpackResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateMsgpackResponse::failure().create()
+    /// }
+    /// ```
+    pub fn failure() -> CreateFailureJsonResponse<()> {
+        let mut res: CreateFailureJsonResponse<()> =
+            CreateJsonResponse::failure();
+
+        res.state.encoding = ResponseEncoding::Msgpack;
+
+        res
+    }
+
+    /// Create a failure MessagePack response with a status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     msgpack::CreateMsgpackResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateMsgpackResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn failure_with(status: StatusCode) -> CreateFailureJsonResponse<()> {
+        let mut res: CreateFailureJsonResponse<()> =
+            CreateJsonResponse::failure_with(status);
+
+        res.state.encoding = ResponseEncoding::Msgpack;
+
+        res
+    }
+}
@@ -0,0 +1,381 @@
+use axum_core::{BoxError, body::Body};
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt, stream};
+use http::{
+    Error as HTTPError, HeaderName, HeaderValue, StatusCode, Version, header,
+    response::Builder,
+};
+use serde::Serialize;
+
+#[cfg(feature = "shutdown")]
+use crate::response::shutdown::{
+    Shutdown, ShutdownStream, create_draining_response_fn,
+};
+use crate::response::{
+    Response,
+    json::create::{JsonResponseState, base::create_json_response_fn},
+};
+
+/// NDJSON content type.
+const CONTENT_TYPE_NDJSON: &str = "application/x-ndjson";
+
+/// Functions for creating an NDJSON response.
+pub struct NdjsonResponseFunctions<S> {
+    /// Internal state.
+    state: JsonResponseState<()>,
+    /// Stream of items.
+    stream: S,
+    /// Shutdown signal terminating the stream.
+    #[cfg(feature = "shutdown")]
+    shutdown: Option<Shutdown>,
+}
+
+impl<S, T> NdjsonResponseFunctions<S>
+where
+    S: Stream<Item = T> + Send + 'static,
+    T: Serialize,
+{
+    /// Set the status code for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     ndjson::CreateNdjsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateNdjsonResponse::iter(vec![1, 2, 3])
+    ///         .status(StatusCode::PARTIAL_CONTENT)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn status<C: Into<StatusCode>>(
+        mut self,
+        status: C,
+    ) -> Self {
+        self.state.status = status.into();
+
+        self
+    }
+
+    /// Set the HTTP version for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Version;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     ndjson::CreateNdjsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateNdjsonResponse::iter(vec![1, 2, 3])
+    ///         .version(Version::HTTP_2)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn version<V: Into<Version>>(
+        mut self,
+        version: V,
+    ) -> Self {
+        self.state.version = version.into();
+
+        self
+    }
+
+    /// Set the shutdown signal terminating the stream,
+    /// available with `shutdown` feature.
+    ///
+    /// When the signal is triggered,
+    /// a final failure line of
+    /// [`Draining`](crate::response::json::ResponseError::Draining) code
+    /// is sent and the stream ends.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::extract::State;
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     ndjson::CreateNdjsonResponse,
+    ///     shutdown::Shutdown,
+    /// };
+    ///
+    /// async fn route(State(shutdown): State<Shutdown>) -> Response {
+    ///     CreateNdjsonResponse::stream(stream::pending::<usize>())
+    ///         .shutdown(shutdown)
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "shutdown")]
+    pub fn shutdown(
+        mut self,
+        shutdown: Shutdown,
+    ) -> Self {
+        self.shutdown = Some(shutdown);
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     ndjson::CreateNdjsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateNdjsonResponse::iter(vec![1, 2, 3])
+    ///         .header(header::CACHE_CONTROL, "no-cache")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        let key: HeaderName = match <HeaderName as TryFrom<K>>::try_from(key) {
+            | Ok(k) => k,
+            | Err(_) => {
+                self.state.is_header_map_failed = true;
+                return self;
+            },
+        };
+
+        let value: HeaderValue =
+            match <HeaderValue as TryFrom<V>>::try_from(value) {
+                | Ok(v) => v,
+                | Err(_) => {
+                    self.state.is_header_map_failed = true;
+                    return self;
+                },
+            };
+
+        self.state.header_map.try_append(key, value).unwrap();
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::{
+    ///     header,
+    ///     HeaderName,
+    /// };
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     ndjson::CreateNdjsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     let headers: Vec<(HeaderName, &str)> = vec![
+    ///         (
+    ///             header::CACHE_CONTROL,
+    ///             "no-cache"
+    ///         ),
+    ///     ];
+    ///
+    ///     CreateNdjsonResponse::iter(vec![1, 2, 3])
+    ///         .headers(headers)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        for (key, value) in headers {
+            self = self.header(key, value);
+        }
+
+        self
+    }
+
+    /// Finish the response creation.
+    ///
+    /// Each item is serialized into a line while the body is being sent.
+    /// If an item fails to serialize, the body will be aborted.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     ndjson::CreateNdjsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateNdjsonResponse::iter(vec![1, 2, 3]).create()
+    /// }
+    /// ```
+    pub fn create(self) -> Response {
+        // header map error
+        if self.state.is_header_map_failed {
+            return create_json_response_fn(self.state);
+        }
+
+        let lines = self.stream.map(|item| {
+            let mut buf: Vec<u8> = Vec::new();
+
+            serde_json::to_writer(&mut buf, &item).map_err(BoxError::from)?;
+
+            buf.push(b'\n');
+
+            Ok::<Bytes, BoxError>(Bytes::from(buf))
+        });
+
+        #[cfg(feature = "shutdown")]
+        let lines = ShutdownStream::new(lines, self.shutdown.as_ref(), || {
+            let mut buf: Vec<u8> =
+                serde_json::to_vec(&create_draining_response_fn::<()>())
+                    .map_err(BoxError::from)?;
+
+            buf.push(b'\n');
+
+            Ok(Bytes::from(buf))
+        });
+
+        // create response builder
+        let mut builder: Builder = Response::builder()
+            .status(self.state.status)
+            .version(self.state.version)
+            .header(header::CONTENT_TYPE, CONTENT_TYPE_NDJSON);
+
+        // push headers
+        for (header, value) in self.state.header_map {
+            if let Some(header) = header {
+                builder = builder.header(header, value);
+            }
+        }
+
+        match builder.body(Body::from_stream(lines)) {
+            | Ok(res) => res,
+            | Err(_) => {
+                let mut state: JsonResponseState<()> =
+                    JsonResponseState::success();
+
+                state.is_header_map_failed = true;
+
+                create_json_response_fn(state)
+            },
+        }
+    }
+}
+
+/// Create an NDJSON (newline-delimited JSON) response for a route,
+/// available with `ndjson` feature.
+///
+/// ## Examples
+///
+/// An NDJSON response from an iterator:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     ndjson::CreateNdjsonResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateNdjsonResponse::iter(vec![1, 2, 3]).create()
+/// }
+/// ```
+///
+/// An NDJSON response from a stream:
+///
+/// ```no_run
+/// use futures_util::stream;
+/// use jder_axum::response::{
+///     Response,
+///     ndjson::CreateNdjsonResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateNdjsonResponse::stream(stream::iter(vec![1, 2, 3])).create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateNdjsonResponse;
+
+impl CreateNdjsonResponse {
+    /// Create an NDJSON response from an iterator.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     ndjson::CreateNdjsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateNdjsonResponse::iter(vec![1, 2, 3]).create()
+    /// }
+    /// ```
+    pub fn iter<I>(
+        iter: I
+    ) -> NdjsonResponseFunctions<stream::Iter<I::IntoIter>>
+    where
+        I: IntoIterator,
+        I::IntoIter: Send + 'static,
+    {
+        Self::stream(stream::iter(iter))
+    }
+
+    /// Create an NDJSON response from a stream.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     ndjson::CreateNdjsonResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateNdjsonResponse::stream(stream::iter(vec![1, 2, 3])).create()
+    /// }
+    /// ```
+    pub fn stream<S>(stream: S) -> NdjsonResponseFunctions<S>
+    where
+        S: Stream + Send + 'static,
+    {
+        NdjsonResponseFunctions {
+            state: JsonResponseState::success(),
+            stream,
+            #[cfg(feature = "shutdown")]
+            shutdown: None,
+        }
+    }
+}
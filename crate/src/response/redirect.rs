@@ -0,0 +1,263 @@
+use http::{
+    Error as HTTPError, HeaderName, HeaderValue, StatusCode, Version, header,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::response::{
+    Response,
+    cookie::ResponseCookie,
+    json::{
+        CreateJsonResponse, CreateSuccessJsonResponse,
+        create::base::create_empty_response_fn,
+    },
+};
+
+/// Data of a redirect response with a JSON body.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedirectData {
+    /// Status code of the redirect.
+    pub status: u16,
+    /// URI redirected to.
+    pub location: String,
+}
+
+/// Functions for creating a redirect response.
+#[derive(Debug, Clone)]
+pub struct CreateRedirectResponse {
+    builder: CreateSuccessJsonResponse<RedirectData>,
+    is_json_body: bool,
+}
+
+impl CreateRedirectResponse {
+    /// Create a redirect to the URI with the status code.
+    fn new(
+        status: StatusCode,
+        uri: String,
+    ) -> Self {
+        let data: RedirectData =
+            RedirectData { status: status.as_u16(), location: uri.clone() };
+
+        Self {
+            builder: CreateJsonResponse::success::<RedirectData>()
+                .status(status)
+                .header(header::LOCATION, uri)
+                .data(data),
+            is_json_body: false,
+        }
+    }
+
+    /// Set the HTTP version of the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::Version;
+    /// use jder_axum::response::{Response, redirect::CreateRedirect};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateRedirect::temporary("/login")
+    ///         .version(Version::HTTP_2)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn version<V: Into<Version>>(
+        mut self,
+        version: V,
+    ) -> Self {
+        self.builder = self.builder.version(version);
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use jder_axum::response::{Response, redirect::CreateRedirect};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateRedirect::see_other("/orders/1")
+    ///         .header(header::CACHE_CONTROL, "no-store")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        self.builder = self.builder.header(key, value);
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        self.builder = self.builder.headers(headers);
+
+        self
+    }
+
+    /// Add a cookie with a `Set-Cookie` header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     cookie::ResponseCookie,
+    ///     redirect::CreateRedirect,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateRedirect::see_other("/")
+    ///         .cookie(ResponseCookie::new("session", "abc").http_only(true))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn cookie<C: Into<ResponseCookie>>(
+        mut self,
+        cookie: C,
+    ) -> Self {
+        self.builder = self.builder.cookie(cookie);
+
+        self
+    }
+
+    /// Add cookies with `Set-Cookie` headers.
+    pub fn cookies<C: Into<ResponseCookie>>(
+        mut self,
+        cookies: impl IntoIterator<Item = C>,
+    ) -> Self {
+        self.builder = self.builder.cookies(cookies);
+
+        self
+    }
+
+    /// Remove a cookie at the root path with a `Set-Cookie` header
+    /// that expires the cookie.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, redirect::CreateRedirect};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateRedirect::see_other("/login")
+    ///         .remove_cookie("session")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn remove_cookie<N: Into<String>>(
+        mut self,
+        name: N,
+    ) -> Self {
+        self.builder = self.builder.remove_cookie(name);
+
+        self
+    }
+
+    /// Include a JSON body explaining the redirect,
+    /// for clients not following redirects.
+    ///
+    /// ```jsonc
+    /// // Status: 307
+    /// // Location: /login
+    /// {
+    ///     "success": true,
+    ///     "data": {
+    ///         "status": 307,
+    ///         "location": "/login"
+    ///     },
+    ///     "errors": []
+    /// }
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, redirect::CreateRedirect};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateRedirect::temporary("/login").with_json_body().create()
+    /// }
+    /// ```
+    pub fn with_json_body(mut self) -> Self {
+        self.is_json_body = true;
+
+        self
+    }
+
+    /// Finish the response creation.
+    ///
+    /// An invalid URI fails the response like an invalid header.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, redirect::CreateRedirect};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateRedirect::permanent("/new").create()
+    /// }
+    /// ```
+    pub fn create(self) -> Response {
+        match self.is_json_body {
+            | true => self.builder.create(),
+            | false => create_empty_response_fn(self.builder.state),
+        }
+    }
+}
+
+/// Create a redirect response for a route,
+/// sharing the builder functions of the JSON responses,
+/// with an empty body by default.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::{Response, redirect::CreateRedirect};
+///
+/// async fn route() -> Response {
+///     CreateRedirect::temporary("/login").create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateRedirect;
+
+impl CreateRedirect {
+    /// Create a redirect with HTTP 307 status code,
+    /// keeping the method and the body of the request.
+    pub fn temporary<U: Into<String>>(uri: U) -> CreateRedirectResponse {
+        CreateRedirectResponse::new(StatusCode::TEMPORARY_REDIRECT, uri.into())
+    }
+
+    /// Create a redirect with HTTP 308 status code,
+    /// keeping the method and the body of the request.
+    pub fn permanent<U: Into<String>>(uri: U) -> CreateRedirectResponse {
+        CreateRedirectResponse::new(StatusCode::PERMANENT_REDIRECT, uri.into())
+    }
+
+    /// Create a redirect with HTTP 303 status code,
+    /// changing the method to `GET`, such as after a form submission.
+    pub fn see_other<U: Into<String>>(uri: U) -> CreateRedirectResponse {
+        CreateRedirectResponse::new(StatusCode::SEE_OTHER, uri.into())
+    }
+}
@@ -0,0 +1,254 @@
+use std::{collections::HashSet, time::Duration};
+
+use http::{HeaderName, HeaderValue, header};
+
+/// Directives of the `Content-Security-Policy` header
+/// accepted by [`SecurityHeaders::is_valid`].
+pub const CSP_DIRECTIVES: [&str; 27] = [
+    "base-uri",
+    "block-all-mixed-content",
+    "child-src",
+    "connect-src",
+    "default-src",
+    "fenced-frame-src",
+    "font-src",
+    "form-action",
+    "frame-ancestors",
+    "frame-src",
+    "img-src",
+    "manifest-src",
+    "media-src",
+    "object-src",
+    "report-to",
+    "report-uri",
+    "require-trusted-types-for",
+    "sandbox",
+    "script-src",
+    "script-src-attr",
+    "script-src-elem",
+    "style-src",
+    "style-src-attr",
+    "style-src-elem",
+    "trusted-types",
+    "upgrade-insecure-requests",
+    "worker-src",
+];
+
+/// `Content-Security-Policy` of API responses,
+/// which never load any resource or get framed.
+pub const CSP_API: &str = "default-src 'none'; frame-ancestors 'none'";
+
+/// Preset of security headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SecurityPreset {
+    /// Headers for API responses:
+    /// - `X-Content-Type-Options: nosniff`
+    /// - `X-Frame-Options: DENY`
+    /// - `Referrer-Policy: no-referrer`
+    /// - `Content-Security-Policy: default-src 'none'; frame-ancestors 'none'`
+    Api,
+}
+
+/// Security headers of a response.
+///
+/// Set it with the `security_headers` function of the JSON response builders,
+/// from a [`SecurityPreset`] or customized from one,
+/// and invalid headers, such as a malformed `Content-Security-Policy`,
+/// fail the response like an invalid header.
+///
+/// `Strict-Transport-Security` is not set by the presets,
+/// as it should only be sent over HTTPS.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use jder_axum::response::{
+///     Response,
+///     json::CreateJsonResponse,
+///     security_headers::{SecurityHeaders, SecurityPreset},
+/// };
+///
+/// async fn route() -> Response {
+///     CreateJsonResponse::dataless()
+///         .security_headers(
+///             SecurityHeaders::from(SecurityPreset::Api)
+///                 .hsts(Duration::from_secs(31536000), true),
+///         )
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityHeaders {
+    content_type_options: bool,
+    frame_options: Option<String>,
+    referrer_policy: Option<String>,
+    content_security_policy: Option<String>,
+    hsts: Option<(u64, bool)>,
+}
+
+impl SecurityHeaders {
+    /// Create security headers from a preset.
+    pub fn new(preset: SecurityPreset) -> Self {
+        match preset {
+            | SecurityPreset::Api => Self {
+                content_type_options: true,
+                frame_options: Some("DENY".to_string()),
+                referrer_policy: Some("no-referrer".to_string()),
+                content_security_policy: Some(CSP_API.to_string()),
+                hsts: None,
+            },
+        }
+    }
+
+    /// Set the `Content-Security-Policy` header.
+    ///
+    /// The policy is checked with [`is_valid`](SecurityHeaders::is_valid).
+    pub fn content_security_policy<P: Into<String>>(
+        mut self,
+        policy: P,
+    ) -> Self {
+        self.content_security_policy = Some(policy.into());
+
+        self
+    }
+
+    /// Set the `Referrer-Policy` header.
+    pub fn referrer_policy<P: Into<String>>(
+        mut self,
+        policy: P,
+    ) -> Self {
+        self.referrer_policy = Some(policy.into());
+
+        self
+    }
+
+    /// Set the `X-Frame-Options` header, such as `SAMEORIGIN`.
+    pub fn frame_options<O: Into<String>>(
+        mut self,
+        options: O,
+    ) -> Self {
+        self.frame_options = Some(options.into());
+
+        self
+    }
+
+    /// Set the `Strict-Transport-Security` header
+    /// with the `max-age` and `includeSubDomains` directives.
+    pub fn hsts(
+        mut self,
+        max_age: Duration,
+        include_subdomains: bool,
+    ) -> Self {
+        self.hsts = Some((max_age.as_secs(), include_subdomains));
+
+        self
+    }
+
+    /// Check if the headers can be sent.
+    ///
+    /// The `Content-Security-Policy` must have at least one directive,
+    /// where the directives are known, such as [`CSP_DIRECTIVES`],
+    /// not repeated, and separated by `;` without `,`,
+    /// which would split the policy into multiple policies.
+    ///
+    /// All values must be visible ASCII.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::security_headers::{
+    ///     SecurityHeaders, SecurityPreset,
+    /// };
+    ///
+    /// // true
+    /// SecurityHeaders::new(SecurityPreset::Api)
+    ///     .content_security_policy("default-src 'self'; img-src *")
+    ///     .is_valid();
+    /// // false
+    /// SecurityHeaders::new(SecurityPreset::Api)
+    ///     .content_security_policy("default-src 'self', img-src *")
+    ///     .is_valid();
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.to_headers().is_some()
+    }
+
+    /// Get the headers to send,
+    /// returns `None` if any header is invalid.
+    pub(crate) fn to_headers(&self) -> Option<Vec<(HeaderName, HeaderValue)>> {
+        if let Some(policy) = &self.content_security_policy {
+            if !is_valid_csp_fn(policy) {
+                return None;
+            }
+        }
+
+        let hsts: Option<String> =
+            self.hsts.map(|(max_age, include_subdomains)| {
+                match include_subdomains {
+                    | true => format!("max-age={max_age}; includeSubDomains"),
+                    | false => format!("max-age={max_age}"),
+                }
+            });
+
+        let headers: [(HeaderName, Option<&str>); 5] = [
+            (
+                header::X_CONTENT_TYPE_OPTIONS,
+                self.content_type_options.then_some("nosniff"),
+            ),
+            (header::X_FRAME_OPTIONS, self.frame_options.as_deref()),
+            (header::REFERRER_POLICY, self.referrer_policy.as_deref()),
+            (
+                header::CONTENT_SECURITY_POLICY,
+                self.content_security_policy.as_deref(),
+            ),
+            (header::STRICT_TRANSPORT_SECURITY, hsts.as_deref()),
+        ];
+
+        headers
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .map(|(name, value)| {
+                HeaderValue::from_str(value).ok().map(|value| (name, value))
+            })
+            .collect()
+    }
+}
+
+impl From<SecurityPreset> for SecurityHeaders {
+    fn from(preset: SecurityPreset) -> Self {
+        Self::new(preset)
+    }
+}
+
+/// Check if a `Content-Security-Policy` is well-formed.
+fn is_valid_csp_fn(policy: &str) -> bool {
+    if !policy.bytes().all(|b| b.is_ascii_graphic() || b == b' ')
+        || policy.contains(',')
+    {
+        return false;
+    }
+
+    let mut names: HashSet<String> = HashSet::new();
+
+    for directive in policy.split(';').map(str::trim) {
+        // allow a trailing `;`
+        if directive.is_empty() {
+            continue;
+        }
+
+        let name: String = directive
+            .split_ascii_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if !CSP_DIRECTIVES.contains(&name.as_str()) || !names.insert(name) {
+            return false;
+        }
+    }
+
+    !names.is_empty()
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{
+        Arc, Mutex, MutexGuard,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    task::{Context, Poll, Waker},
+};
+
+#[cfg(any(feature = "ndjson", feature = "sse"))]
+use crate::response::json::{JsonResponse, JsonResponseError, ResponseError};
+
+/// Internal state.
+#[derive(Debug, Default)]
+struct ShutdownState {
+    triggered: AtomicBool,
+    next_id: AtomicU64,
+    wakers: Mutex<HashMap<u64, Waker>>,
+}
+
+impl ShutdownState {
+    /// Lock the wakers of the waiting futures.
+    fn wakers_fn(&self) -> MutexGuard<'_, HashMap<u64, Waker>> {
+        match self.wakers.lock() {
+            | Ok(wakers) => wakers,
+            | Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+/// Signal of the server shutdown,
+/// observed by streaming responses to terminate gracefully,
+/// available with `shutdown` feature.
+///
+/// The signal is shared by cloning, such as in the router state,
+/// and triggered once when the server starts draining.
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::{Router, extract::State, routing::get};
+/// use futures_util::stream;
+/// use jder_axum::response::{
+///     Response,
+///     sse::{CreateSseResponse, SseEvent},
+///     shutdown::Shutdown,
+/// };
+///
+/// async fn route(State(shutdown): State<Shutdown>) -> Response {
+///     CreateSseResponse::stream(stream::pending::<SseEvent<usize>>())
+///         .shutdown(shutdown)
+///         .create()
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let shutdown: Shutdown = Shutdown::new();
+///
+///     let router: Router = Router::new()
+///         .route("/events", get(route))
+///         .with_state(shutdown.clone());
+///
+///     let trigger: Shutdown = shutdown.clone();
+///
+///     tokio::spawn(async move {
+///         tokio::signal::ctrl_c().await.unwrap();
+///         trigger.trigger();
+///     });
+///
+///     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000")
+///         .await
+///         .unwrap();
+///
+///     axum::serve(listener, router)
+///         .with_graceful_shutdown(shutdown.triggered())
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Shutdown {
+    state: Arc<ShutdownState>,
+}
+
+impl Shutdown {
+    /// Create a new signal not triggered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trigger the signal,
+    /// waking the streams and the futures waiting for it.
+    pub fn trigger(&self) {
+        self.state.triggered.store(true, Ordering::Release);
+
+        let wakers: HashMap<u64, Waker> =
+            std::mem::take(&mut *self.state.wakers_fn());
+
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    /// Check if the signal is triggered.
+    pub fn is_triggered(&self) -> bool {
+        self.state.triggered.load(Ordering::Acquire)
+    }
+
+    /// Get a future completed when the signal is triggered,
+    /// such as for the graceful shutdown of the server.
+    pub fn triggered(&self) -> ShutdownFuture {
+        ShutdownFuture {
+            shutdown: self.clone(),
+            id: self.state.next_id.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+/// Future completed when a [`Shutdown`] signal is triggered.
+#[derive(Debug)]
+pub struct ShutdownFuture {
+    shutdown: Shutdown,
+    id: u64,
+}
+
+impl Future for ShutdownFuture {
+    type Output = ();
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        if self.shutdown.is_triggered() {
+            return Poll::Ready(());
+        }
+
+        self.shutdown.state.wakers_fn().insert(self.id, cx.waker().clone());
+
+        // triggered while registering
+        match self.shutdown.is_triggered() {
+            | true => Poll::Ready(()),
+            | false => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for ShutdownFuture {
+    fn drop(&mut self) {
+        self.shutdown.state.wakers_fn().remove(&self.id);
+    }
+}
+
+/// Stream terminated with a final item when a [`Shutdown`] is triggered,
+/// or passing the items through without a signal.
+#[cfg(any(feature = "ndjson", feature = "sse"))]
+pub(crate) struct ShutdownStream<S, T> {
+    stream: Pin<Box<S>>,
+    signal: Option<ShutdownFuture>,
+    last: Option<fn() -> T>,
+}
+
+#[cfg(any(feature = "ndjson", feature = "sse"))]
+impl<S, T> ShutdownStream<S, T> {
+    /// Create a new stream with the final item sent on a shutdown.
+    pub(crate) fn new(
+        stream: S,
+        shutdown: Option<&Shutdown>,
+        last: fn() -> T,
+    ) -> Self {
+        Self {
+            stream: Box::pin(stream),
+            signal: shutdown.map(Shutdown::triggered),
+            last: Some(last),
+        }
+    }
+}
+
+#[cfg(any(feature = "ndjson", feature = "sse"))]
+impl<S: futures_util::Stream<Item = T>, T> futures_util::Stream
+    for ShutdownStream<S, T>
+{
+    type Item = T;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this: &mut Self = self.get_mut();
+
+        if this.last.is_none() {
+            return Poll::Ready(None);
+        }
+
+        if let Some(signal) = &mut this.signal {
+            if Pin::new(signal).poll(cx).is_ready() {
+                return Poll::Ready(this.last.take().map(|last| last()));
+            }
+        }
+
+        match this.stream.as_mut().poll_next(cx) {
+            | Poll::Ready(None) => {
+                this.last = None;
+
+                Poll::Ready(None)
+            },
+            | poll => poll,
+        }
+    }
+}
+
+/// Create the final frame of a stream terminated by a shutdown.
+///
+/// ```jsonc
+/// {
+///     "success": false,
+///     "data": null,
+///     "errors": [
+///         {
+///             "code": "draining",
+///             "path": [],
+///             "message": "Server is draining"
+///         }
+///     ]
+/// }
+/// ```
+#[cfg(any(feature = "ndjson", feature = "sse"))]
+pub(crate) fn create_draining_response_fn<D>() -> JsonResponse<D> {
+    JsonResponse {
+        success: false,
+        data: None,
+        errors: vec![JsonResponseError::from(ResponseError::Draining)],
+        meta: None,
+        status: None,
+    }
+}
@@ -0,0 +1,576 @@
+use axum_core::body::Body;
+use http::{
+    Error as HTTPError, HeaderName, HeaderValue, StatusCode, header,
+    response::Builder,
+};
+
+use crate::response::{
+    Response,
+    json::{
+        CreateJsonResponse, JsonResponseError, ResponseError,
+        create::{JsonResponseState, base::create_json_response_fn},
+    },
+};
+
+/// Content type of a sitemap.
+pub const CONTENT_TYPE_SITEMAP: &str = "application/xml; charset=utf-8";
+
+/// Content type of a robots file.
+pub const CONTENT_TYPE_ROBOTS: &str = "text/plain; charset=utf-8";
+
+/// Maximum number of URLs in a sitemap.
+pub const SITEMAP_URL_LIMIT: usize = 50_000;
+
+/// Create a failure response of a generation error,
+/// such as `["sitemap", "0", "loc"]` in path.
+fn create_generation_failure_fn<P, S>(
+    path: P,
+    message: &str,
+) -> Response
+where
+    P: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    CreateJsonResponse::failure_with(StatusCode::INTERNAL_SERVER_ERROR)
+        .add_error(
+            JsonResponseError::from(ResponseError::Server)
+                .path(path)
+                .message(message),
+        )
+        .create()
+}
+
+/// Create a text response with the state and the content type.
+fn create_text_response_fn(
+    state: JsonResponseState<()>,
+    content_type: &str,
+    body: String,
+) -> Response {
+    // header map error
+    if state.is_header_map_failed {
+        return create_json_response_fn(state);
+    }
+
+    // create response builder
+    let mut builder: Builder = Response::builder()
+        .status(state.status)
+        .version(state.version)
+        .header(header::CONTENT_TYPE, content_type);
+
+    // push headers, where repeated values come without the name
+    let mut name: Option<HeaderName> = None;
+
+    for (header, value) in state.header_map {
+        if header.is_some() {
+            name = header;
+        }
+
+        if let Some(name) = &name {
+            builder = builder.header(name, value);
+        }
+    }
+
+    match builder.body(Body::from(body)) {
+        | Ok(res) => res,
+        | Err(_) => {
+            let mut state: JsonResponseState<()> = JsonResponseState::success();
+
+            state.is_header_map_failed = true;
+
+            create_json_response_fn(state)
+        },
+    }
+}
+
+/// Append a header to the state,
+/// or mark the header map as failed for an invalid header.
+fn append_header_fn<K, V>(
+    state: &mut JsonResponseState<()>,
+    key: K,
+    value: V,
+) where
+    HeaderName: TryFrom<K>,
+    <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+    HeaderValue: TryFrom<V>,
+    <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+{
+    let key: HeaderName = match <HeaderName as TryFrom<K>>::try_from(key) {
+        | Ok(k) => k,
+        | Err(_) => {
+            state.is_header_map_failed = true;
+            return;
+        },
+    };
+
+    let value: HeaderValue = match <HeaderValue as TryFrom<V>>::try_from(value)
+    {
+        | Ok(v) => v,
+        | Err(_) => {
+            state.is_header_map_failed = true;
+            return;
+        },
+    };
+
+    state.header_map.append(key, value);
+}
+
+/// Escape a value for XML content.
+fn escape_xml_fn(value: &str) -> String {
+    let mut escaped: String = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            | '&' => escaped.push_str("&amp;"),
+            | '<' => escaped.push_str("&lt;"),
+            | '>' => escaped.push_str("&gt;"),
+            | '"' => escaped.push_str("&quot;"),
+            | '\'' => escaped.push_str("&apos;"),
+            | c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// URL entry of a sitemap.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::sitemap::SitemapEntry;
+///
+/// let entry: SitemapEntry = SitemapEntry::new("https://example.com/")
+///     .lastmod("2026-01-01")
+///     .priority(0.8);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapEntry {
+    /// Absolute URL of the page.
+    pub loc: String,
+    /// Last modification date of the page in W3C datetime format,
+    /// such as `2026-01-01`.
+    pub lastmod: Option<String>,
+    /// Priority of the page between `0.0` and `1.0`.
+    pub priority: Option<f32>,
+}
+
+impl SitemapEntry {
+    /// Create a new entry with an absolute URL.
+    pub fn new<L: Into<String>>(loc: L) -> Self {
+        Self { loc: loc.into(), lastmod: None, priority: None }
+    }
+
+    /// Set the last modification date of the page
+    /// in W3C datetime format, such as `2026-01-01`.
+    pub fn lastmod<M: Into<String>>(
+        mut self,
+        lastmod: M,
+    ) -> Self {
+        self.lastmod = Some(lastmod.into());
+
+        self
+    }
+
+    /// Set the priority of the page between `0.0` and `1.0`.
+    pub fn priority(
+        mut self,
+        priority: f32,
+    ) -> Self {
+        self.priority = Some(priority);
+
+        self
+    }
+}
+
+impl<L: Into<String>> From<L> for SitemapEntry {
+    fn from(loc: L) -> Self {
+        Self::new(loc)
+    }
+}
+
+/// Functions for creating a sitemap response.
+#[derive(Debug, Clone)]
+pub struct SitemapResponseFunctions {
+    /// Internal state.
+    state: JsonResponseState<()>,
+    /// URL entries of the sitemap.
+    entries: Vec<SitemapEntry>,
+}
+
+impl SitemapResponseFunctions {
+    /// Set the status code for the response.
+    pub fn status<C: Into<StatusCode>>(
+        mut self,
+        status: C,
+    ) -> Self {
+        self.state.status = status.into();
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use jder_axum::response::{Response, sitemap::CreateSitemapResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateSitemapResponse::entries(["https://example.com/"])
+    ///         .header(header::CACHE_CONTROL, "public, max-age=3600")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        append_header_fn(&mut self.state, key, value);
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        for (key, value) in headers {
+            self = self.header(key, value);
+        }
+
+        self
+    }
+
+    /// Finish the response creation.
+    ///
+    /// Entries without an absolute `http` or `https` URL,
+    /// with a priority out of range,
+    /// or more than [`SITEMAP_URL_LIMIT`] entries
+    /// are `500 Internal Server Error` failure responses.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, sitemap::CreateSitemapResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateSitemapResponse::entries(["https://example.com/"]).create()
+    /// }
+    /// ```
+    pub fn create(self) -> Response {
+        if self.entries.len() > SITEMAP_URL_LIMIT {
+            return create_generation_failure_fn(
+                ["sitemap"],
+                "Too many URLs in sitemap",
+            );
+        }
+
+        let mut body: String = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+        );
+
+        for (i, entry) in self.entries.iter().enumerate() {
+            let index: String = i.to_string();
+
+            if !entry.loc.starts_with("http://")
+                && !entry.loc.starts_with("https://")
+            {
+                return create_generation_failure_fn(
+                    ["sitemap", &index, "loc"],
+                    "URL must be absolute",
+                );
+            }
+
+            body.push_str("<url><loc>");
+            body.push_str(&escape_xml_fn(&entry.loc));
+            body.push_str("</loc>");
+
+            if let Some(lastmod) = &entry.lastmod {
+                body.push_str("<lastmod>");
+                body.push_str(&escape_xml_fn(lastmod));
+                body.push_str("</lastmod>");
+            }
+
+            if let Some(priority) = entry.priority {
+                if !(0.0..=1.0).contains(&priority) {
+                    return create_generation_failure_fn(
+                        ["sitemap", &index, "priority"],
+                        "Priority must be between 0.0 and 1.0",
+                    );
+                }
+
+                body.push_str(&format!("<priority>{}</priority>", priority));
+            }
+
+            body.push_str("</url>\n");
+        }
+
+        body.push_str("</urlset>\n");
+
+        create_text_response_fn(self.state, CONTENT_TYPE_SITEMAP, body)
+    }
+}
+
+/// Create a sitemap response for a route,
+/// available with `sitemap` feature.
+///
+/// Generation errors, such as a relative URL,
+/// are failure JSON responses.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     sitemap::{CreateSitemapResponse, SitemapEntry},
+/// };
+///
+/// async fn route() -> Response {
+///     CreateSitemapResponse::entries([
+///         SitemapEntry::new("https://example.com/").priority(1.0),
+///         SitemapEntry::new("https://example.com/about")
+///             .lastmod("2026-01-01"),
+///     ])
+///     .create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateSitemapResponse;
+
+impl CreateSitemapResponse {
+    /// Create a sitemap response from URL entries.
+    pub fn entries<E: Into<SitemapEntry>>(
+        entries: impl IntoIterator<Item = E>
+    ) -> SitemapResponseFunctions {
+        SitemapResponseFunctions {
+            state: JsonResponseState::success(),
+            entries: entries.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Functions for creating a robots response.
+#[derive(Debug, Clone)]
+pub struct RobotsResponseFunctions {
+    /// Internal state.
+    state: JsonResponseState<()>,
+    /// Directives of the robots file.
+    directives: Vec<(&'static str, String)>,
+}
+
+impl RobotsResponseFunctions {
+    /// Add a directive to the robots file.
+    fn directive_fn<V: Into<String>>(
+        mut self,
+        name: &'static str,
+        value: V,
+    ) -> Self {
+        self.directives.push((name, value.into()));
+
+        self
+    }
+
+    /// Start a group of rules for a user agent, such as `*`.
+    pub fn user_agent<V: Into<String>>(
+        self,
+        user_agent: V,
+    ) -> Self {
+        self.directive_fn("User-agent", user_agent)
+    }
+
+    /// Allow a path for the current user agent group.
+    pub fn allow<V: Into<String>>(
+        self,
+        path: V,
+    ) -> Self {
+        self.directive_fn("Allow", path)
+    }
+
+    /// Disallow a path for the current user agent group.
+    pub fn disallow<V: Into<String>>(
+        self,
+        path: V,
+    ) -> Self {
+        self.directive_fn("Disallow", path)
+    }
+
+    /// Set the crawl delay in seconds for the current user agent group.
+    pub fn crawl_delay(
+        self,
+        seconds: u64,
+    ) -> Self {
+        self.directive_fn("Crawl-delay", seconds.to_string())
+    }
+
+    /// Add the absolute URL of a sitemap.
+    pub fn sitemap<V: Into<String>>(
+        self,
+        url: V,
+    ) -> Self {
+        self.directive_fn("Sitemap", url)
+    }
+
+    /// Set the status code for the response.
+    pub fn status<C: Into<StatusCode>>(
+        mut self,
+        status: C,
+    ) -> Self {
+        self.state.status = status.into();
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        append_header_fn(&mut self.state, key, value);
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        for (key, value) in headers {
+            self = self.header(key, value);
+        }
+
+        self
+    }
+
+    /// Finish the response creation.
+    ///
+    /// Rules before any user agent and values with line breaks
+    /// are `500 Internal Server Error` failure responses.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{Response, sitemap::CreateRobotsResponse};
+    ///
+    /// async fn route() -> Response {
+    ///     CreateRobotsResponse::rules().user_agent("*").allow("/").create()
+    /// }
+    /// ```
+    pub fn create(self) -> Response {
+        let mut body: String = String::new();
+
+        let mut has_user_agent: bool = false;
+
+        for (i, (name, value)) in self.directives.iter().enumerate() {
+            let index: String = i.to_string();
+
+            if value.contains(['\r', '\n']) {
+                return create_generation_failure_fn(
+                    ["robots", &index],
+                    "Value must not contain line breaks",
+                );
+            }
+
+            match *name {
+                | "User-agent" => {
+                    // separate groups with a blank line
+                    if has_user_agent
+                        && !matches!(
+                            self.directives.get(i.wrapping_sub(1)),
+                            Some(("User-agent", _))
+                        )
+                    {
+                        body.push('\n');
+                    }
+
+                    has_user_agent = true;
+                },
+                | "Sitemap" => {},
+                | _ if !has_user_agent => {
+                    return create_generation_failure_fn(
+                        ["robots", &index],
+                        "Rule must follow a user agent",
+                    );
+                },
+                | _ => {},
+            }
+
+            body.push_str(&format!("{}: {}\n", name, value));
+        }
+
+        create_text_response_fn(self.state, CONTENT_TYPE_ROBOTS, body)
+    }
+}
+
+/// Create a robots response for a route,
+/// available with `sitemap` feature.
+///
+/// Generation errors, such as a rule before any user agent,
+/// are failure JSON responses.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::{Response, sitemap::CreateRobotsResponse};
+///
+/// async fn route() -> Response {
+///     CreateRobotsResponse::rules()
+///         .user_agent("*")
+///         .allow("/")
+///         .disallow("/admin")
+///         .sitemap("https://example.com/sitemap.xml")
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateRobotsResponse;
+
+impl CreateRobotsResponse {
+    /// Create a robots response to add directives to.
+    pub fn rules() -> RobotsResponseFunctions {
+        RobotsResponseFunctions {
+            state: JsonResponseState::success(),
+            directives: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use crate::response::json::{JsonResponse, JsonResponseError};
+
+/// A server-sent event carrying a JSON response frame.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use jder_axum::response::sse::SseEvent;
+///
+/// let event: SseEvent<usize> = SseEvent::success(1)
+///     .event("count")
+///     .id("1")
+///     .retry(Duration::from_secs(3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SseEvent<D = ()> {
+    /// JSON response of the event.
+    pub(crate) response: JsonResponse<D>,
+    /// Name of the event.
+    pub(crate) event: Option<String>,
+    /// Identifier of the event.
+    pub(crate) id: Option<String>,
+    /// Reconnection time of the event.
+    pub(crate) retry: Option<Duration>,
+}
+
+impl<D> SseEvent<D> {
+    /// Create a new event from a JSON response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     json::JsonResponse,
+    ///     sse::SseEvent,
+    /// };
+    ///
+    /// let event: SseEvent = SseEvent::new(JsonResponse::new());
+    /// ```
+    pub fn new(response: JsonResponse<D>) -> Self {
+        Self { response, event: None, id: None, retry: None }
+    }
+
+    /// Create a new success event with data.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::sse::SseEvent;
+    ///
+    /// let event: SseEvent<String> = SseEvent::success("data".to_string());
+    /// ```
+    pub fn success(data: D) -> Self {
+        Self::new(JsonResponse {
+            success: true,
+            data: Some(data),
+            errors: Vec::new(),
+            meta: None,
+            status: None,
+        })
+    }
+
+    /// Create a new failure event with errors.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     json::JsonResponseError,
+    ///     sse::SseEvent,
+    /// };
+    ///
+    /// let event: SseEvent = SseEvent::failure([
+    ///     JsonResponseError::new().code("timeout"),
+    /// ]);
+    /// ```
+    pub fn failure<E: IntoIterator<Item = JsonResponseError>>(
+        errors: E
+    ) -> Self {
+        Self::new(JsonResponse {
+            success: false,
+            data: None,
+            errors: errors.into_iter().collect(),
+            meta: None,
+            status: None,
+        })
+    }
+
+    /// Set the name of the event.
+    ///
+    /// The name must not contain line breaks,
+    /// otherwise a failure frame will be sent instead.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::sse::SseEvent;
+    ///
+    /// let event: SseEvent<usize> = SseEvent::success(1).event("count");
+    /// ```
+    pub fn event<E: Into<String>>(
+        mut self,
+        event: E,
+    ) -> Self {
+        self.event = Some(event.into());
+
+        self
+    }
+
+    /// Set the identifier of the event.
+    ///
+    /// The identifier must not contain line breaks or null characters,
+    /// otherwise a failure frame will be sent instead.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::sse::SseEvent;
+    ///
+    /// let event: SseEvent<usize> = SseEvent::success(1).id("1");
+    /// ```
+    pub fn id<I: Into<String>>(
+        mut self,
+        id: I,
+    ) -> Self {
+        self.id = Some(id.into());
+
+        self
+    }
+
+    /// Set the reconnection time of the event.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use jder_axum::response::sse::SseEvent;
+    ///
+    /// let event: SseEvent<usize> =
+    ///     SseEvent::success(1).retry(Duration::from_secs(3));
+    /// ```
+    pub fn retry(
+        mut self,
+        retry: Duration,
+    ) -> Self {
+        self.retry = Some(retry);
+
+        self
+    }
+}
+
+impl<D> From<JsonResponse<D>> for SseEvent<D> {
+    fn from(response: JsonResponse<D>) -> Self {
+        Self::new(response)
+    }
+}
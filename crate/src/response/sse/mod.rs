@@ -0,0 +1,463 @@
+pub(crate) mod event;
+
+pub use crate::response::sse::event::SseEvent;
+
+use std::{convert::Infallible, time::Duration};
+
+use axum::response::{
+    IntoResponse,
+    sse::{Event, KeepAlive, Sse},
+};
+use futures_util::{Stream, StreamExt};
+use http::{Error as HTTPError, HeaderName, HeaderValue};
+use serde::Serialize;
+
+#[cfg(feature = "shutdown")]
+use crate::response::shutdown::{
+    Shutdown, ShutdownStream, create_draining_response_fn,
+};
+use crate::response::{
+    Response,
+    json::{
+        JsonResponse, JsonResponseError, ResponseError,
+        create::{JsonResponseState, base::create_json_response_fn},
+    },
+};
+
+/// Functions for creating a server-sent events response.
+pub struct SseResponseFunctions<S> {
+    /// Internal state.
+    state: JsonResponseState<()>,
+    /// Stream of events.
+    stream: S,
+    /// Default name of the events.
+    event: Option<String>,
+    /// Reconnection time sent with the first event.
+    retry: Option<Duration>,
+    /// Keep-alive of the stream.
+    keep_alive: Option<KeepAlive>,
+    /// Shutdown signal terminating the stream.
+    #[cfg(feature = "shutdown")]
+    shutdown: Option<Shutdown>,
+}
+
+impl<S, D> SseResponseFunctions<S>
+where
+    S: Stream<Item = SseEvent<D>> + Send + 'static,
+    D: Serialize + 'static,
+{
+    /// Set the default name of the events,
+    /// used when an event has no name.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     sse::{CreateSseResponse, SseEvent},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateSseResponse::stream(stream::iter(vec![SseEvent::success(1)]))
+    ///         .event("count")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn event<E: Into<String>>(
+        mut self,
+        event: E,
+    ) -> Self {
+        self.event = Some(event.into());
+
+        self
+    }
+
+    /// Set the reconnection time, sent with the first event.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     sse::{CreateSseResponse, SseEvent},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateSseResponse::stream(stream::iter(vec![SseEvent::success(1)]))
+    ///         .retry(Duration::from_secs(3))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn retry(
+        mut self,
+        retry: Duration,
+    ) -> Self {
+        self.retry = Some(retry);
+
+        self
+    }
+
+    /// Set the keep-alive of the stream.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use axum::response::sse::KeepAlive;
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     sse::{CreateSseResponse, SseEvent},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateSseResponse::stream(stream::iter(vec![SseEvent::success(1)]))
+    ///         .keep_alive(KeepAlive::new().interval(Duration::from_secs(15)))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn keep_alive(
+        mut self,
+        keep_alive: KeepAlive,
+    ) -> Self {
+        self.keep_alive = Some(keep_alive);
+
+        self
+    }
+
+    /// Set the shutdown signal terminating the stream,
+    /// available with `shutdown` feature.
+    ///
+    /// When the signal is triggered,
+    /// a final failure frame of
+    /// [`Draining`](crate::response::json::ResponseError::Draining) code
+    /// is sent and the stream ends,
+    /// so that clients can reconnect to another instance.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::extract::State;
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     shutdown::Shutdown,
+    ///     sse::{CreateSseResponse, SseEvent},
+    /// };
+    ///
+    /// async fn route(State(shutdown): State<Shutdown>) -> Response {
+    ///     CreateSseResponse::stream(stream::pending::<SseEvent<usize>>())
+    ///         .shutdown(shutdown)
+    ///         .create()
+    /// }
+    /// ```
+    #[cfg(feature = "shutdown")]
+    pub fn shutdown(
+        mut self,
+        shutdown: Shutdown,
+    ) -> Self {
+        self.shutdown = Some(shutdown);
+
+        self
+    }
+
+    /// Set a header for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::header;
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     sse::{CreateSseResponse, SseEvent},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateSseResponse::stream(stream::iter(vec![SseEvent::success(1)]))
+    ///         .header(header::CACHE_CONTROL, "no-cache")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn header<K, V>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        let key: HeaderName = match <HeaderName as TryFrom<K>>::try_from(key) {
+            | Ok(k) => k,
+            | Err(_) => {
+                self.state.is_header_map_failed = true;
+                return self;
+            },
+        };
+
+        let value: HeaderValue =
+            match <HeaderValue as TryFrom<V>>::try_from(value) {
+                | Ok(v) => v,
+                | Err(_) => {
+                    self.state.is_header_map_failed = true;
+                    return self;
+                },
+            };
+
+        self.state.header_map.try_append(key, value).unwrap();
+
+        self
+    }
+
+    /// Set multiple headers for the response.
+    ///
+    /// For validation on key value, see
+    /// [`get_header_from_key_value`](crate::response::header::get_header_from_key_value).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::{
+    ///     header,
+    ///     HeaderName,
+    /// };
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     sse::{CreateSseResponse, SseEvent},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     let headers: Vec<(HeaderName, &str)> = vec![
+    ///         (
+    ///             header::CACHE_CONTROL,
+    ///             "no-cache"
+    ///         ),
+    ///     ];
+    ///
+    ///     CreateSseResponse::stream(stream::iter(vec![SseEvent::success(1)]))
+    ///         .headers(headers)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn headers<K, V>(
+        mut self,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<HTTPError>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<HTTPError>,
+    {
+        for (key, value) in headers {
+            self = self.header(key, value);
+        }
+
+        self
+    }
+
+    /// Finish the response creation.
+    ///
+    /// Each event is sent as a JSON response frame in the `data` field.
+    /// If an event fails to be created, a failure frame will be sent instead.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     sse::{CreateSseResponse, SseEvent},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateSseResponse::stream(stream::iter(vec![SseEvent::success(1)]))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn create(self) -> Response {
+        // header map error
+        if self.state.is_header_map_failed {
+            return create_json_response_fn(self.state);
+        }
+
+        let default_event: Option<String> = self.event;
+        let retry: Option<Duration> = self.retry;
+
+        #[cfg(feature = "shutdown")]
+        let stream =
+            ShutdownStream::new(self.stream, self.shutdown.as_ref(), || {
+                SseEvent::new(create_draining_response_fn())
+            });
+        #[cfg(not(feature = "shutdown"))]
+        let stream = self.stream;
+
+        let events = stream.enumerate().map(move |(index, mut event)| {
+            if event.event.is_none() {
+                event.event = default_event.clone();
+            }
+
+            if index == 0 && event.retry.is_none() {
+                event.retry = retry;
+            }
+
+            Ok::<Event, Infallible>(create_event(event))
+        });
+
+        let mut res: Response = match self.keep_alive {
+            | Some(keep_alive) => {
+                Sse::new(events).keep_alive(keep_alive).into_response()
+            },
+            | None => Sse::new(events).into_response(),
+        };
+
+        // push headers
+        for (header, value) in self.state.header_map {
+            if let Some(header) = header {
+                res.headers_mut().append(header, value);
+            }
+        }
+
+        res
+    }
+}
+
+/// Create an SSE event from a JSON response frame,
+/// or a failure frame if the event is invalid.
+fn create_event<D: Serialize>(event: SseEvent<D>) -> Event {
+    let is_invalid = |value: &Option<String>, null: bool| {
+        value.as_deref().is_some_and(|value| {
+            value.contains(['\n', '\r']) || (null && value.contains('\0'))
+        })
+    };
+
+    if is_invalid(&event.event, false) {
+        return create_failure_event("event");
+    }
+
+    if is_invalid(&event.id, true) {
+        return create_failure_event("id");
+    }
+
+    let data: String = match serde_json::to_string(&event.response) {
+        | Ok(data) => data,
+        | Err(_) => return create_failure_event("data"),
+    };
+
+    let mut frame: Event = Event::default().data(data);
+
+    if let Some(name) = event.event {
+        frame = frame.event(name);
+    }
+
+    if let Some(id) = event.id {
+        frame = frame.id(id);
+    }
+
+    if let Some(retry) = event.retry {
+        frame = frame.retry(retry);
+    }
+
+    frame
+}
+
+/// Create a failure frame for an invalid field of an event.
+fn create_failure_event(field: &str) -> Event {
+    let response: JsonResponse = JsonResponse {
+        success: false,
+        data: None,
+        errors: vec![
+            JsonResponseError::from(ResponseError::Server)
+                .path(["response", "sse", field])
+                .message(ResponseError::Server.as_message()),
+        ],
+        meta: None,
+        status: None,
+    };
+
+    Event::default().data(serde_json::to_string(&response).unwrap_or_default())
+}
+
+/// Create a server-sent events response for a route,
+/// available with `sse` feature.
+///
+/// Each event carries a JSON response frame in the `data` field,
+/// keeping the same shape as the other JSON responses.
+///
+/// ```text
+/// event: count
+/// data: {"success":true,"data":1,"errors":[]}
+///
+/// ```
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use axum::response::sse::KeepAlive;
+/// use futures_util::stream;
+/// use jder_axum::response::{
+///     Response,
+///     sse::{CreateSseResponse, SseEvent},
+/// };
+///
+/// async fn route() -> Response {
+///     let events: Vec<SseEvent<usize>> = (1..=3)
+///         .map(|i| SseEvent::success(i).id(i.to_string()))
+///         .collect();
+///
+///     CreateSseResponse::stream(stream::iter(events))
+///         .event("count")
+///         .keep_alive(KeepAlive::default())
+///         .create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateSseResponse;
+
+impl CreateSseResponse {
+    /// Create a server-sent events response from a stream of events.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use futures_util::stream;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     sse::{CreateSseResponse, SseEvent},
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateSseResponse::stream(stream::iter(vec![SseEvent::success(1)]))
+    ///         .create()
+    /// }
+    /// ```
+    pub fn stream<S, D>(stream: S) -> SseResponseFunctions<S>
+    where
+        S: Stream<Item = SseEvent<D>> + Send + 'static,
+        D: Serialize + 'static,
+    {
+        SseResponseFunctions {
+            state: JsonResponseState::success(),
+            stream,
+            event: None,
+            retry: None,
+            keep_alive: None,
+            #[cfg(feature = "shutdown")]
+            shutdown: None,
+        }
+    }
+}
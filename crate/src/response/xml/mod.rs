@@ -0,0 +1,224 @@
+use http::StatusCode;
+
+use crate::response::json::{
+    CreateFailureJsonResponse, CreateJsonResponse, CreateSuccessJsonResponse,
+    HasData, create::ResponseEncoding,
+};
+
+/// XML content type.
+pub const CONTENT_TYPE_XML: &str = "application/xml";
+
+/// XML declaration written before the root element.
+pub(crate) const XML_DECLARATION: &str =
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>";
+
+/// Name of the root element.
+pub(crate) const XML_ROOT: &str = "response";
+
+/// Create an XML response for a route.
+///
+/// The response has the same structure as a JSON response,
+/// but the body is encoded as XML
+/// with `application/xml` content type,
+/// for clients requiring XML with the same error semantics.
+///
+/// The body is serialized by `quick_xml::se` with a `response` root element,
+/// where fields of structs and maps are child elements named by the keys,
+/// items of sequences are repeated elements named by the field,
+/// and `None` values are empty elements:
+///
+/// ```xml
+/// <?xml version="1.0" encoding="UTF-8"?>
+/// <response>
+///     <success>false</success>
+///     <data/>
+///     <errors>
+///         <code>parse</code>
+///         <path>json</path>
+///         <path>name</path>
+///         <message>Invalid name</message>
+///     </errors>
+/// </response>
+/// ```
+///
+/// Keys which are not valid element names
+/// fail the response with a server error.
+///
+/// The builders are shared with
+/// [`CreateJsonResponse`](crate::response::json::CreateJsonResponse),
+/// except that problem details are always encoded as JSON.
+///
+/// ## Examples
+///
+/// A success XML response without data:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     xml::CreateXmlResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateXmlResponse::dataless().create()
+/// }
+/// ```
+///
+/// A success XML response:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     xml::CreateXmlResponse,
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Default, Serialize)]
+/// struct ResponseData {
+///    name: String,
+/// }
+///
+/// async fn route() -> Response {
+///     CreateXmlResponse::success::<ResponseData>()
+///         .data(ResponseData { name: "Name".to_string() })
+///         .create()
+/// }
+/// ```
+///
+/// A failure XML response:
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     xml::CreateXmlResponse,
+/// };
+///
+/// async fn route() -> Response {
+///     CreateXmlResponse::failure().create()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CreateXmlResponse;
+
+impl CreateXmlResponse {
+    /// Create a success XML response without data.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     xml::CreateXmlResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateXmlResponse::dataless().create()
+    /// }
+    /// ```
+    pub fn dataless() -> CreateSuccessJsonResponse<(), HasData> {
+        let mut res: CreateSuccessJsonResponse<(), HasData> =
+            CreateJsonResponse::dataless();
+
+        res.state.encoding = ResponseEncoding::Xml;
+
+        res
+    }
+
+    /// Create a success XML response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     xml::CreateXmlResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateXmlResponse::success::<String>()
+    ///         .data("Name".to_string())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn success<D>() -> CreateSuccessJsonResponse<D> {
+        let mut res: CreateSuccessJsonResponse<D> =
+            CreateJsonResponse::success();
+
+        res.state.encoding = ResponseEncoding::Xml;
+
+        res
+    }
+
+    /// Create a success XML response with a status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     xml::CreateXmlResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateXmlResponse::success_with::<String>(StatusCode::CREATED)
+    ///         .data("Name".to_string())
+    ///         .create()
+    /// }
+    /// ```
+    pub fn success_with<D>(status: StatusCode) -> CreateSuccessJsonResponse<D> {
+        let mut res: CreateSuccessJsonResponse<D> =
+            CreateJsonResponse::success_with(status);
+
+        res.state.encoding = ResponseEncoding::Xml;
+
+        res
+    }
+
+    /// Create a failure XML response.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     xml::CreateXmlResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateXmlResponse::failure().create()
+    /// }
+    /// ```
+    pub fn failure() -> CreateFailureJsonResponse<()> {
+        let mut res: CreateFailureJsonResponse<()> =
+            CreateJsonResponse::failure();
+
+        res.state.encoding = ResponseEncoding::Xml;
+
+        res
+    }
+
+    /// Create a failure XML response with a status code.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use axum::http::StatusCode;
+    /// use jder_axum::response::{
+    ///     Response,
+    ///     xml::CreateXmlResponse,
+    /// };
+    ///
+    /// async fn route() -> Response {
+    ///     CreateXmlResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY)
+    ///         .create()
+    /// }
+    /// ```
+    pub fn failure_with(status: StatusCode) -> CreateFailureJsonResponse<()> {
+        let mut res: CreateFailureJsonResponse<()> =
+            CreateJsonResponse::failure_with(status);
+
+        res.state.encoding = ResponseEncoding::Xml;
+
+        res
+    }
+}
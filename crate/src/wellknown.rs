@@ -0,0 +1,385 @@
+use std::sync::Arc;
+
+use axum::{Router, routing::get};
+use http::{HeaderValue, StatusCode, header};
+use serde_json::Value;
+
+use crate::response::{
+    CreateResponse, Response,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+};
+
+/// Content type of a `security.txt` document.
+pub const CONTENT_TYPE_SECURITY_TXT: &str = "text/plain; charset=utf-8";
+
+/// Content type of an OpenID configuration document.
+pub const CONTENT_TYPE_OPENID_CONFIGURATION: &str = "application/json";
+
+/// Create a failure response of a generation error,
+/// such as `["wellknown", "security.txt", "contact"]` in path.
+fn create_generation_failure_fn(
+    document: &str,
+    field: &str,
+    message: &str,
+) -> Response {
+    CreateJsonResponse::failure_with(StatusCode::INTERNAL_SERVER_ERROR)
+        .add_error(
+            JsonResponseError::from(ResponseError::Server)
+                .path(["wellknown", document, field])
+                .message(message),
+        )
+        .create()
+}
+
+/// Fields of a `security.txt` document, following RFC 9116.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::wellknown::SecurityTxt;
+///
+/// let security_txt: SecurityTxt = SecurityTxt::new()
+///     .contact("mailto:security@example.com")
+///     .expires("2027-01-01T00:00:00Z")
+///     .preferred_languages(["en", "ja"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecurityTxt {
+    /// Contacts for reporting vulnerabilities, required.
+    pub contact: Vec<String>,
+    /// Expiry date and time in RFC 3339 format, required.
+    pub expires: Option<String>,
+    /// URLs of the keys for encrypted communication.
+    pub encryption: Vec<String>,
+    /// URLs of the acknowledgments pages.
+    pub acknowledgments: Vec<String>,
+    /// Preferred languages of the reports.
+    pub preferred_languages: Vec<String>,
+    /// Canonical URLs of the document.
+    pub canonical: Vec<String>,
+    /// URLs of the vulnerability disclosure policies.
+    pub policy: Vec<String>,
+    /// URLs of the security-related job positions.
+    pub hiring: Vec<String>,
+}
+
+impl SecurityTxt {
+    /// Create a new document without fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a contact, such as `mailto:security@example.com`.
+    pub fn contact<V: Into<String>>(
+        mut self,
+        contact: V,
+    ) -> Self {
+        self.contact.push(contact.into());
+
+        self
+    }
+
+    /// Set the expiry date and time in RFC 3339 format,
+    /// such as `2027-01-01T00:00:00Z`.
+    pub fn expires<V: Into<String>>(
+        mut self,
+        expires: V,
+    ) -> Self {
+        self.expires = Some(expires.into());
+
+        self
+    }
+
+    /// Add a URL of a key for encrypted communication.
+    pub fn encryption<V: Into<String>>(
+        mut self,
+        url: V,
+    ) -> Self {
+        self.encryption.push(url.into());
+
+        self
+    }
+
+    /// Add a URL of an acknowledgments page.
+    pub fn acknowledgments<V: Into<String>>(
+        mut self,
+        url: V,
+    ) -> Self {
+        self.acknowledgments.push(url.into());
+
+        self
+    }
+
+    /// Set the preferred languages of the reports, such as `en`.
+    pub fn preferred_languages<V: Into<String>>(
+        mut self,
+        languages: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.preferred_languages =
+            languages.into_iter().map(Into::into).collect();
+
+        self
+    }
+
+    /// Add a canonical URL of the document.
+    pub fn canonical<V: Into<String>>(
+        mut self,
+        url: V,
+    ) -> Self {
+        self.canonical.push(url.into());
+
+        self
+    }
+
+    /// Add a URL of a vulnerability disclosure policy.
+    pub fn policy<V: Into<String>>(
+        mut self,
+        url: V,
+    ) -> Self {
+        self.policy.push(url.into());
+
+        self
+    }
+
+    /// Add a URL of a security-related job position.
+    pub fn hiring<V: Into<String>>(
+        mut self,
+        url: V,
+    ) -> Self {
+        self.hiring.push(url.into());
+
+        self
+    }
+
+    /// Render the document,
+    /// or get the field and the message of a generation error.
+    fn render_fn(&self) -> Result<String, (&'static str, &'static str)> {
+        if self.contact.is_empty() {
+            return Err(("contact", "Missing contact"));
+        }
+
+        let expires: &String = match &self.expires {
+            | Some(expires) => expires,
+            | None => return Err(("expires", "Missing expiry")),
+        };
+
+        let languages: String = self.preferred_languages.join(", ");
+
+        let fields: [(&'static str, &str, &[String]); 8] = [
+            ("contact", "Contact", &self.contact),
+            ("expires", "Expires", std::slice::from_ref(expires)),
+            ("encryption", "Encryption", &self.encryption),
+            ("acknowledgments", "Acknowledgments", &self.acknowledgments),
+            (
+                "preferred_languages",
+                "Preferred-Languages",
+                std::slice::from_ref(&languages),
+            ),
+            ("canonical", "Canonical", &self.canonical),
+            ("policy", "Policy", &self.policy),
+            ("hiring", "Hiring", &self.hiring),
+        ];
+
+        let mut body: String = String::new();
+
+        for (field, name, values) in fields {
+            for value in values.iter().filter(|value| !value.is_empty()) {
+                if value.contains(['\r', '\n']) {
+                    return Err((field, "Value must not contain line breaks"));
+                }
+
+                body.push_str(&format!("{}: {}\n", name, value));
+            }
+        }
+
+        Ok(body)
+    }
+
+    /// Create a `security.txt` response,
+    /// where generation errors, such as a missing contact,
+    /// are `500 Internal Server Error` failure responses.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use jder_axum::{response::Response, wellknown::SecurityTxt};
+    ///
+    /// async fn route() -> Response {
+    ///     SecurityTxt::new()
+    ///         .contact("mailto:security@example.com")
+    ///         .expires("2027-01-01T00:00:00Z")
+    ///         .create()
+    /// }
+    /// ```
+    pub fn create(&self) -> Response {
+        match self.render_fn() {
+            | Ok(body) => CreateResponse::success::<String>()
+                .header(header::CONTENT_TYPE, CONTENT_TYPE_SECURITY_TXT)
+                .body(body)
+                .create(),
+            | Err((field, message)) => {
+                create_generation_failure_fn("security.txt", field, message)
+            },
+        }
+    }
+}
+
+/// Create a response redirecting to the page for changing passwords,
+/// where an invalid URL is a `500 Internal Server Error` failure response.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{response::Response, wellknown::create_change_password};
+///
+/// async fn route() -> Response {
+///     create_change_password("/account/password")
+/// }
+/// ```
+pub fn create_change_password(url: &str) -> Response {
+    match HeaderValue::try_from(url) {
+        | Ok(location) => CreateResponse::success::<String>()
+            .status(StatusCode::FOUND)
+            .header(header::LOCATION, location)
+            .create(),
+        | Err(_) => create_generation_failure_fn(
+            "change-password",
+            "url",
+            "Invalid URL",
+        ),
+    }
+}
+
+/// Create a response of an OpenID configuration document as is.
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     response::Response,
+///     wellknown::create_openid_configuration,
+/// };
+/// use serde_json::json;
+///
+/// async fn route() -> Response {
+///     create_openid_configuration(&json!({
+///         "issuer": "https://example.com",
+///     }))
+/// }
+/// ```
+pub fn create_openid_configuration(document: &Value) -> Response {
+    CreateResponse::success::<String>()
+        .header(header::CONTENT_TYPE, CONTENT_TYPE_OPENID_CONFIGURATION)
+        .body(document.to_string())
+        .create()
+}
+
+/// Well-known documents under `/.well-known/`,
+/// available with `wellknown` feature.
+///
+/// Only the configured documents are routed:
+/// - `/.well-known/security.txt`: [`SecurityTxt`]
+/// - `/.well-known/change-password`: redirect to the page for changing passwords
+/// - `/.well-known/openid-configuration`: OpenID configuration document as is
+///
+/// ## Example
+///
+/// ```no_run
+/// use axum::Router;
+/// use jder_axum::wellknown::{SecurityTxt, WellKnown};
+///
+/// let router: Router = Router::new().merge(
+///     WellKnown::new()
+///         .security_txt(
+///             SecurityTxt::new()
+///                 .contact("mailto:security@example.com")
+///                 .expires("2027-01-01T00:00:00Z"),
+///         )
+///         .change_password("/account/password")
+///         .router(),
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WellKnown {
+    security_txt: Option<SecurityTxt>,
+    change_password: Option<String>,
+    openid_configuration: Option<Value>,
+}
+
+impl WellKnown {
+    /// Create a new configuration without documents.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `security.txt` document.
+    pub fn security_txt(
+        mut self,
+        security_txt: SecurityTxt,
+    ) -> Self {
+        self.security_txt = Some(security_txt);
+
+        self
+    }
+
+    /// Set the URL of the page for changing passwords.
+    pub fn change_password<V: Into<String>>(
+        mut self,
+        url: V,
+    ) -> Self {
+        self.change_password = Some(url.into());
+
+        self
+    }
+
+    /// Set the OpenID configuration document,
+    /// such as the document of an identity provider.
+    pub fn openid_configuration(
+        mut self,
+        document: Value,
+    ) -> Self {
+        self.openid_configuration = Some(document);
+
+        self
+    }
+
+    /// Create a router of the configured documents.
+    pub fn router<S>(self) -> Router<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let mut router: Router<S> = Router::new();
+
+        if let Some(security_txt) = self.security_txt {
+            let security_txt: Arc<SecurityTxt> = Arc::new(security_txt);
+
+            router = router.route(
+                "/.well-known/security.txt",
+                get(move || async move { security_txt.create() }),
+            );
+        }
+
+        if let Some(url) = self.change_password {
+            let url: Arc<str> = Arc::from(url);
+
+            router = router.route(
+                "/.well-known/change-password",
+                get(move || async move { create_change_password(&url) }),
+            );
+        }
+
+        if let Some(document) = self.openid_configuration {
+            let document: Arc<Value> = Arc::new(document);
+
+            router =
+                router.route(
+                    "/.well-known/openid-configuration",
+                    get(move || async move {
+                        create_openid_configuration(&document)
+                    }),
+                );
+        }
+
+        router
+    }
+}
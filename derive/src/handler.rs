@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Error, ItemFn, LitInt, Result, ReturnType, Type, meta::ParseNestedMeta,
+    spanned::Spanned as _,
+};
+
+/// Options of a handler.
+#[derive(Default)]
+pub(crate) struct HandlerOptions {
+    /// Status code of the success response.
+    status: Option<u16>,
+}
+
+impl HandlerOptions {
+    /// Parse an option of the attribute.
+    pub(crate) fn parse(
+        &mut self,
+        meta: ParseNestedMeta,
+    ) -> Result<()> {
+        if meta.path.is_ident("status") {
+            let lit: LitInt = meta.value()?.parse::<LitInt>()?;
+
+            let value: u16 = lit.base10_parse::<u16>()?;
+
+            if !(100..1000).contains(&value) {
+                return Err(Error::new(
+                    lit.span(),
+                    "status code must be in 100..1000",
+                ));
+            }
+
+            self.status = Some(value);
+
+            return Ok(());
+        }
+
+        Err(meta.error("expected `status = 200`"))
+    }
+}
+
+/// Expand the `jder_handler` attribute.
+pub(crate) fn expand(
+    options: HandlerOptions,
+    mut item: ItemFn,
+) -> Result<TokenStream> {
+    if item.sig.asyncness.is_none() {
+        return Err(Error::new(
+            item.sig.fn_token.span(),
+            "`jder_handler` only supports async functions",
+        ));
+    }
+
+    let ty: Type = match &item.sig.output {
+        | ReturnType::Type(_, ty) => (**ty).clone(),
+        | ReturnType::Default => {
+            return Err(Error::new(
+                item.sig.span(),
+                "`jder_handler` expects a `Result` return type",
+            ));
+        },
+    };
+
+    let status: TokenStream = match options.status {
+        | Some(status) => quote! {
+            .status(
+                ::jder_axum::__private::StatusCode::from_u16(#status)
+                    .expect("status code is checked by the macro"),
+            )
+        },
+        | None => quote!(),
+    };
+
+    let block = &item.block;
+
+    item.sig.output = syn::parse_quote!(-> ::jder_axum::response::Response);
+
+    item.block = syn::parse_quote!({
+        let result: #ty = async move {
+            let result: #ty = #block;
+
+            result
+        }
+        .await;
+
+        ::jder_axum::__private::IntoResponse::into_response(
+            result
+                .map(|data| {
+                    ::jder_axum::response::json::JsonResponse::new()
+                        .data(data)
+                        #status
+                })
+                .map_err(::jder_axum::response::json::ApiError::from),
+        )
+    });
+
+    Ok(quote!(#item))
+}
@@ -0,0 +1,266 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Data, DeriveInput, Error, Fields, Ident, LitInt, LitStr, Result, Variant,
+    spanned::Spanned as _,
+};
+
+/// Attribute name of the macro.
+const ATTR: &str = "json";
+
+/// Options of a variant.
+struct VariantOptions {
+    /// Error code.
+    code: LitStr,
+    /// Status code.
+    status: Option<u16>,
+    /// Error message with the format of [`format!`].
+    message: Option<LitStr>,
+}
+
+/// Convert a variant name into snake case.
+fn to_snake_case(name: &str) -> String {
+    let mut snake: String = String::with_capacity(name.len() + 4);
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+
+    snake
+}
+
+/// Rewrite the positional arguments of tuple fields,
+/// such as `{0}`, into the bindings, such as `{_0}`.
+fn rewrite_message(message: &LitStr) -> LitStr {
+    let value: String = message.value();
+
+    let mut rewritten: String = String::with_capacity(value.len());
+
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        rewritten.push(c);
+
+        if c != '{' {
+            continue;
+        }
+
+        // escaped brace
+        if chars.peek() == Some(&'{') {
+            rewritten.push(chars.next().expect("peeked"));
+            continue;
+        }
+
+        if chars.peek().is_some_and(char::is_ascii_digit) {
+            rewritten.push('_');
+        }
+    }
+
+    LitStr::new(&rewritten, message.span())
+}
+
+/// Parse the options of a variant.
+fn parse_variant_options(variant: &Variant) -> Result<VariantOptions> {
+    let mut code: Option<LitStr> = None;
+    let mut status: Option<u16> = None;
+    let mut message: Option<LitStr> = None;
+
+    for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident(ATTR)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("code") {
+                code = Some(meta.value()?.parse::<LitStr>()?);
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("status") {
+                let lit: LitInt = meta.value()?.parse::<LitInt>()?;
+
+                let value: u16 = lit.base10_parse::<u16>()?;
+
+                if !(100..1000).contains(&value) {
+                    return Err(Error::new(
+                        lit.span(),
+                        "status code must be in 100..1000",
+                    ));
+                }
+
+                status = Some(value);
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("message") {
+                message = Some(meta.value()?.parse::<LitStr>()?);
+
+                return Ok(());
+            }
+
+            Err(meta.error(
+                "expected `code = \"...\"`, `status = 400` or `message = \"...\"`",
+            ))
+        })?;
+    }
+
+    let code: LitStr = code.unwrap_or_else(|| {
+        LitStr::new(
+            &to_snake_case(&variant.ident.to_string()),
+            variant.ident.span(),
+        )
+    });
+
+    Ok(VariantOptions { code, status, message })
+}
+
+/// Create the pattern of a variant binding all fields.
+fn create_binding_pattern(
+    name: &Ident,
+    variant: &Variant,
+) -> TokenStream {
+    let ident: &Ident = &variant.ident;
+
+    match &variant.fields {
+        | Fields::Named(fields) => {
+            let names = fields.named.iter().map(|f| &f.ident);
+
+            quote!(#name::#ident { #(#names),* })
+        },
+        | Fields::Unnamed(fields) => {
+            let names = (0..fields.unnamed.len())
+                .map(|i| format_ident!("_{}", i, span = Span::call_site()));
+
+            quote!(#name::#ident(#(#names),*))
+        },
+        | Fields::Unit => quote!(#name::#ident),
+    }
+}
+
+/// Create the pattern of a variant ignoring all fields.
+fn create_wildcard_pattern(
+    name: &Ident,
+    variant: &Variant,
+) -> TokenStream {
+    let ident: &Ident = &variant.ident;
+
+    match &variant.fields {
+        | Fields::Named(_) => quote!(#name::#ident { .. }),
+        | Fields::Unnamed(_) => quote!(#name::#ident(..)),
+        | Fields::Unit => quote!(#name::#ident),
+    }
+}
+
+/// Expand the `JsonError` derive.
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let variants = match &input.data {
+        | Data::Enum(data) => &data.variants,
+        | _ => {
+            return Err(Error::new(
+                input.span(),
+                "`JsonError` only supports enums",
+            ));
+        },
+    };
+
+    let mut error_arms: Vec<TokenStream> = Vec::new();
+    let mut status_arms: Vec<TokenStream> = Vec::new();
+
+    for variant in variants {
+        let options: VariantOptions = parse_variant_options(variant)?;
+
+        let binding: TokenStream =
+            create_binding_pattern(&input.ident, variant);
+        let wildcard: TokenStream =
+            create_wildcard_pattern(&input.ident, variant);
+
+        let code: &LitStr = &options.code;
+
+        let message: TokenStream = match &options.message {
+            | Some(message) => {
+                let message: LitStr = rewrite_message(message);
+
+                quote!(.message(::std::format!(#message)))
+            },
+            | None => quote!(),
+        };
+
+        error_arms.push(quote! {
+            #binding => ::jder_axum::response::json::JsonResponseError::new()
+                .code(#code)
+                #message
+        });
+
+        let status: TokenStream = match options.status {
+            | Some(status) => quote! {
+                ::core::option::Option::Some(
+                    ::jder_axum::__private::StatusCode::from_u16(#status)
+                        .expect("status code is checked by the macro"),
+                )
+            },
+            | None => quote!(::core::option::Option::None),
+        };
+
+        status_arms.push(quote!(#wildcard => #status));
+    }
+
+    let ident: &Ident = &input.ident;
+
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#ident #ty_generics>
+            for ::jder_axum::response::json::JsonResponseError
+            #where_clause
+        {
+            #[allow(unused_variables)]
+            fn from(error: #ident #ty_generics) -> Self {
+                match error {
+                    #(#error_arms,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#ident #ty_generics>
+            for ::jder_axum::response::json::ApiError
+            #where_clause
+        {
+            fn from(error: #ident #ty_generics) -> Self {
+                let status: ::core::option::Option<
+                    ::jder_axum::__private::StatusCode,
+                > = match &error {
+                    #(#status_arms,)*
+                };
+
+                let api_error: ::jder_axum::response::json::ApiError =
+                    ::jder_axum::response::json::ApiError::new()
+                        .add_error(error);
+
+                match status {
+                    | ::core::option::Option::Some(status) => {
+                        api_error.status(status)
+                    },
+                    | ::core::option::Option::None => api_error,
+                }
+            }
+        }
+
+        impl #impl_generics ::jder_axum::__private::IntoResponse
+            for #ident #ty_generics
+            #where_clause
+        {
+            fn into_response(self) -> ::jder_axum::response::Response {
+                ::jder_axum::__private::IntoResponse::into_response(
+                    ::jder_axum::response::json::ApiError::from(self),
+                )
+            }
+        }
+    })
+}
@@ -0,0 +1,194 @@
+//! # JDER axum derive
+//!
+//! Derive macros for [jder_axum](https://docs.rs/jder_axum),
+//! re-exported by `jder_axum` with the `derive` feature.
+
+mod handler;
+mod json_error;
+mod response_data;
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, ItemFn, parse_macro_input};
+
+/// Derive `From` conversions from entities into a response data.
+///
+/// Each field of the response data is converted from the field
+/// with the same name of the entity with [`Into`],
+/// and each field of the entity can only be used once.
+///
+/// Struct attributes:
+/// - `#[response_data(from = Type)]`: entity to convert from,
+///   can be repeated for multiple entities
+///
+/// Field attributes:
+/// - `#[response_data(field = name)]`: field name of the entity
+/// - `#[response_data(with = path)]`: function for converting the field
+/// - `#[response_data(skip)]`: use the default value
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::{
+///     Response,
+///     json::{CreateJsonResponse, IntoResponseData},
+/// };
+/// use serde::Serialize;
+///
+/// struct User {
+///     id: u32,
+///     name: String,
+///     password: String,
+/// }
+///
+/// fn to_display_name(name: String) -> String {
+///     name.to_uppercase()
+/// }
+///
+/// #[derive(Serialize, IntoResponseData)]
+/// #[response_data(from = User)]
+/// struct UserData {
+///     id: u64,
+///     #[response_data(field = name, with = to_display_name)]
+///     display_name: String,
+///     #[response_data(skip)]
+///     tags: Vec<String>,
+/// }
+///
+/// async fn route() -> Response {
+///     let user: User = User {
+///         id: 1,
+///         name: "Name".to_string(),
+///         password: "secret".to_string(),
+///     };
+///
+///     CreateJsonResponse::respond_with::<UserData>(user).create()
+/// }
+/// ```
+#[proc_macro_derive(IntoResponseData, attributes(response_data))]
+pub fn derive_into_response_data(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+
+    response_data::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive conversions from a domain error enum
+/// into failure JSON responses.
+///
+/// The following implementations are generated:
+/// - `From<T>` for `JsonResponseError`
+/// - `From<T>` for `ApiError`, so that `?` can be used with `ApiResult`
+/// - `IntoResponse` with the failure JSON response
+///
+/// Variant attributes:
+/// - `#[json(code = "...")]`: error code,
+///   variant name in snake case by default
+/// - `#[json(status = 400)]`: status code,
+///   default failure status by default
+/// - `#[json(message = "...")]`: error message,
+///   fields can be used with the format of [`format!`],
+///   such as `{id}` for named fields and `{0}` for tuple fields
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::response::json::{ApiResult, JsonError, JsonResponse};
+///
+/// #[derive(JsonError)]
+/// enum UserError {
+///     #[json(code = "auth", status = 401, message = "Unauthorized")]
+///     Unauthorized,
+///     #[json(status = 404, message = "User {id} not found")]
+///     NotFound { id: u32 },
+///     #[json(code = "invalid_name", message = "Invalid name: {0}")]
+///     InvalidName(String),
+/// }
+///
+/// fn find_user(id: u32) -> Result<String, UserError> {
+///     Err(UserError::NotFound { id })
+/// }
+///
+/// async fn route() -> ApiResult<String> {
+///     let name: String = find_user(1)?;
+///
+///     Ok(JsonResponse::new().data(name))
+/// }
+/// ```
+#[proc_macro_derive(JsonError, attributes(json))]
+pub fn derive_json_error(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+
+    json_error::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Wrap the return value of a handler into a JSON response,
+/// so that a handler can return a `Result` of the data.
+///
+/// The handler must be an async function returning `Result<D, E>`,
+/// where `D` is the data of the success response,
+/// and `E` can be converted into `ApiError`,
+/// such as an error enum deriving [`JsonError`](macro@JsonError),
+/// responded as the failure response with its status code and errors.
+/// The `?` operator can be used as in a handler returning `ApiResult`.
+///
+/// Attribute options:
+/// - `#[jder_handler(status = 201)]`: status code of the success response,
+///   default success status by default
+///
+/// ## Example
+///
+/// ```no_run
+/// use jder_axum::{
+///     extract::Path,
+///     response::json::{JsonError, jder_handler},
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     id: u32,
+/// }
+///
+/// #[derive(JsonError)]
+/// enum UserError {
+///     #[json(status = 404, message = "User {id} not found")]
+///     NotFound { id: u32 },
+/// }
+///
+/// fn find_user(id: u32) -> Result<User, UserError> {
+///     Err(UserError::NotFound { id })
+/// }
+///
+/// #[jder_handler]
+/// async fn get_user(Path(id): Path<u32>) -> Result<User, UserError> {
+///     let user: User = find_user(id)?;
+///
+///     Ok(user)
+/// }
+///
+/// #[jder_handler(status = 201)]
+/// async fn create_user() -> Result<User, UserError> {
+///     Ok(User { id: 1 })
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn jder_handler(
+    attr: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    let mut options: handler::HandlerOptions =
+        handler::HandlerOptions::default();
+
+    let parser = syn::meta::parser(|meta| options.parse(meta));
+
+    parse_macro_input!(attr with parser);
+
+    let item: ItemFn = parse_macro_input!(item as ItemFn);
+
+    handler::expand(options, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,146 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Error, Expr, Fields, Ident, Path, Result, Type,
+    spanned::Spanned as _,
+};
+
+/// Attribute name of the macro.
+const ATTR: &str = "response_data";
+
+/// Options of a field.
+#[derive(Default)]
+struct FieldOptions {
+    /// Source field name of the entity.
+    field: Option<Ident>,
+    /// Conversion function of the field.
+    with: Option<Path>,
+    /// Use the default value instead of the entity.
+    skip: bool,
+}
+
+/// Parse the entity types from the struct attributes.
+fn parse_entities(input: &DeriveInput) -> Result<Vec<Type>> {
+    let mut entities: Vec<Type> = Vec::new();
+
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident(ATTR)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("from") {
+                entities.push(meta.value()?.parse::<Type>()?);
+
+                return Ok(());
+            }
+
+            Err(meta.error("expected `from = Type`"))
+        })?;
+    }
+
+    if entities.is_empty() {
+        return Err(Error::new(
+            input.ident.span(),
+            "expected at least one `#[response_data(from = Type)]` attribute",
+        ));
+    }
+
+    Ok(entities)
+}
+
+/// Parse the options of a field.
+fn parse_field_options(field: &syn::Field) -> Result<FieldOptions> {
+    let mut options: FieldOptions = FieldOptions::default();
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident(ATTR)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                options.skip = true;
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("field") {
+                options.field = Some(meta.value()?.parse::<Ident>()?);
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("with") {
+                options.with = Some(meta.value()?.parse::<Path>()?);
+
+                return Ok(());
+            }
+
+            Err(meta.error("expected `skip`, `field = name` or `with = path`"))
+        })?;
+    }
+
+    Ok(options)
+}
+
+/// Expand the `IntoResponseData` derive.
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        | Data::Struct(data) => match &data.fields {
+            | Fields::Named(fields) => &fields.named,
+            | _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "`IntoResponseData` only supports structs with named fields",
+                ));
+            },
+        },
+        | _ => {
+            return Err(Error::new(
+                input.span(),
+                "`IntoResponseData` only supports structs",
+            ));
+        },
+    };
+
+    let entities: Vec<Type> = parse_entities(&input)?;
+
+    let mut inits: Vec<TokenStream> = Vec::new();
+
+    for field in fields {
+        let name: &Ident = field.ident.as_ref().expect("named field");
+
+        let options: FieldOptions = parse_field_options(field)?;
+
+        let value: Expr = match options {
+            | FieldOptions { skip: true, .. } => {
+                syn::parse_quote!(::core::default::Default::default())
+            },
+            | FieldOptions { field, with, .. } => {
+                let source: &Ident = field.as_ref().unwrap_or(name);
+
+                match with {
+                    | Some(with) => syn::parse_quote!(#with(value.#source)),
+                    | None => syn::parse_quote!(
+                        ::core::convert::Into::into(value.#source)
+                    ),
+                }
+            },
+        };
+
+        inits.push(quote!(#name: #value));
+    }
+
+    let ident: &Ident = &input.ident;
+
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    let impls = entities.iter().map(|entity| {
+        quote! {
+            impl #impl_generics ::core::convert::From<#entity>
+                for #ident #ty_generics #where_clause
+            {
+                #[allow(unused_variables)]
+                fn from(value: #entity) -> Self {
+                    Self { #(#inits),* }
+                }
+            }
+        }
+    });
+
+    Ok(quote!(#(#impls)*))
+}
@@ -0,0 +1,29 @@
+use axum::{extract::Path, http::StatusCode};
+use jder_axum::response::json::{
+    ApiError, ApiResult, CustomError, JsonResponse, JsonResponseError,
+    ResponseError,
+};
+
+const ORDER_LOCKED: CustomError =
+    CustomError::new("order_locked", "Order is locked").status(423);
+
+#[axum::debug_handler]
+pub async fn route_api_result(Path(value): Path<String>) -> ApiResult<u32> {
+    let value: u32 = value.parse()?;
+
+    if value == 0 {
+        return Err(ResponseError::Conflict.into());
+    }
+
+    if value == 423 {
+        return Err(ORDER_LOCKED.into());
+    }
+
+    if value == 404 {
+        return Err(ApiError::new()
+            .status(StatusCode::NOT_FOUND)
+            .add_error(JsonResponseError::new().code("not_found")));
+    }
+
+    Ok(JsonResponse::new().data(value))
+}
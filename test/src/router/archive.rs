@@ -0,0 +1,45 @@
+use axum::{Router, routing::get};
+use bytes::Bytes;
+use futures_util::stream;
+use jder_axum::response::{
+    Response,
+    archive::{ArchiveEntry, CreateArchiveResponse},
+};
+
+fn create_entries() -> Vec<ArchiveEntry> {
+    vec![
+        ArchiveEntry::bytes("readme.txt", "Hello"),
+        ArchiveEntry::new(
+            "users/1.json",
+            stream::iter([
+                Ok::<Bytes, std::io::Error>(Bytes::from("{\"id\":")),
+                Ok(Bytes::from("1}")),
+            ]),
+        ),
+    ]
+}
+
+async fn route_archive_zip() -> Response {
+    CreateArchiveResponse::zip(stream::iter(create_entries()))
+        .file_name("export.zip")
+        .create()
+}
+
+async fn route_archive_tar_gz() -> Response {
+    CreateArchiveResponse::tar_gz(stream::iter(create_entries()))
+        .file_name("export.tar.gz")
+        .create()
+}
+
+async fn route_archive_invalid() -> Response {
+    CreateArchiveResponse::zip(stream::iter(create_entries()))
+        .file_name("\"export\".zip")
+        .create()
+}
+
+pub fn router_archive() -> Router {
+    Router::new()
+        .route("/zip", get(route_archive_zip))
+        .route("/tar_gz", get(route_archive_tar_gz))
+        .route("/invalid", get(route_archive_invalid))
+}
@@ -0,0 +1,68 @@
+use axum::{Extension, Router, routing::post};
+use jder_axum::{
+    extract::auth::{BasicAuth, BearerToken, Jwt, JwtConfig, StateJwt},
+    response::{Response, json::CreateJsonResponse},
+};
+use serde::Deserialize;
+
+const RSA_PUBLIC_KEY: &str = "\
+-----BEGIN PUBLIC KEY-----
+MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEAxedRQCupOYNQIU+ODtX+
+oVqaI7sJl7RwOiP5WZfEOpF6v52AcGocUFfjsgphSaeCcUhXUBuH1KlwAqZqmWNR
+DG1FZnhCRWALQseKL5fExOkNwGb1DTjLzQecbN1PLlrFU3Vi0q03YHbv+4XXckmo
+AujE6wWiSQZG9WjCPZobXj5kxvdcWCuLhx7NV1f6Qn55kVDmzqu6TE9b8G4wsy6u
++oWkSjPZmfblvADwfq8icxuIz4yXrtk4srln6yKorM5aw5+/Iuuoqo9K8USPe7My
+lM163ncZ7xMQUB39sRSGE8wNENamG6qnvW42kFipExNL8sTMzAXCFqb32esfMGfA
+wwIDAQAB
+-----END PUBLIC KEY-----
+";
+
+#[derive(Deserialize)]
+pub struct RouteAuthJwtClaims {
+    pub sub: String,
+}
+
+async fn route_auth_bearer(BearerToken(token): BearerToken) -> Response {
+    CreateJsonResponse::success::<String>().data(token).create()
+}
+
+async fn route_auth_basic(auth: BasicAuth) -> Response {
+    CreateJsonResponse::success::<(String, String)>()
+        .data(auth.into_parts())
+        .create()
+}
+
+async fn route_auth_jwt(Jwt(claims): Jwt<RouteAuthJwtClaims>) -> Response {
+    CreateJsonResponse::success::<String>().data(claims.sub).create()
+}
+
+async fn route_auth_jwt_state(
+    StateJwt(claims): StateJwt<RouteAuthJwtClaims>
+) -> Response {
+    CreateJsonResponse::success::<String>().data(claims.sub).create()
+}
+
+pub fn router_auth() -> Router {
+    Router::new()
+        .route("/jwt", post(route_auth_jwt))
+        .layer(Extension(JwtConfig::hs256("secret").issuer("jder")))
+        .route(
+            "/jwt/rs256",
+            post(route_auth_jwt).layer(Extension(
+                JwtConfig::rs256_pem(RSA_PUBLIC_KEY).unwrap().issuer("jder"),
+            )),
+        )
+        .route(
+            "/jwt/unsigned",
+            post(route_auth_jwt)
+                .layer(Extension(JwtConfig::rs256(|_: &[u8], _: &[u8]| true))),
+        )
+        .route(
+            "/jwt/state",
+            post(route_auth_jwt_state)
+                .with_state(JwtConfig::hs256("secret").issuer("jder")),
+        )
+        .route("/jwt/missing_config", post(route_auth_jwt))
+        .route("/bearer", post(route_auth_bearer))
+        .route("/basic", post(route_auth_basic))
+}
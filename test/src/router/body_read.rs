@@ -0,0 +1,42 @@
+use std::io::{Error, ErrorKind};
+
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    extract::Request,
+    middleware::map_request,
+    routing::post,
+};
+use futures_util::stream;
+use jder_axum::{
+    extract::{Form, Json},
+    response::{Response, json::CreateJsonResponse},
+};
+use serde_json::Value;
+
+async fn route_json(Json(_): Json<Value>) -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+async fn route_form(Form(_): Form<Vec<(String, String)>>) -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+/// Replace the body with a stream that fails like a disconnected client.
+async fn disconnect(req: Request) -> Request {
+    let (parts, _) = req.into_parts();
+
+    let body: Body = Body::from_stream(stream::iter([
+        Ok(Bytes::from_static(b"{")),
+        Err(Error::new(ErrorKind::ConnectionReset, "connection reset")),
+    ]));
+
+    Request::from_parts(parts, body)
+}
+
+pub fn router_body_read() -> Router {
+    Router::new()
+        .route("/json", post(route_json))
+        .route("/form", post(route_form))
+        .layer(map_request(disconnect))
+}
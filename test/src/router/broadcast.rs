@@ -0,0 +1,27 @@
+use jder_axum::response::{
+    Response,
+    broadcast::{BroadcastHub, BroadcastSubscription},
+    json::{JsonResponse, JsonResponseError},
+    sse::CreateSseResponse,
+};
+
+#[axum::debug_handler]
+pub async fn route_broadcast() -> Response {
+    let hub: BroadcastHub<usize> = BroadcastHub::new();
+
+    let subscription: BroadcastSubscription<usize> =
+        hub.subscribe_with(|event| event.data.is_none_or(|data| data % 2 == 0));
+
+    hub.publish_data(1);
+    hub.publish_data(2);
+    hub.publish(
+        JsonResponse::new()
+            .success(false)
+            .errors([JsonResponseError::new().code("done")]),
+    );
+
+    // end the stream after the published events
+    drop(hub);
+
+    CreateSseResponse::stream(subscription.into_sse()).create()
+}
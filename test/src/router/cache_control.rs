@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use axum::{Router, routing::get};
+use jder_axum::response::{
+    Response, cache_control::CacheControl, json::CreateJsonResponse,
+};
+
+async fn route_cache_control_success() -> Response {
+    CreateJsonResponse::dataless()
+        .header("cache-control", "no-cache")
+        .public()
+        .cache_max_age(Duration::from_secs(60))
+        .stale_while_revalidate(Duration::from_secs(30))
+        .create()
+}
+
+async fn route_cache_control_failure() -> Response {
+    CreateJsonResponse::failure().private().no_store().create()
+}
+
+async fn route_cache_control_replace() -> Response {
+    CreateJsonResponse::dataless()
+        .no_store()
+        .cache_control(
+            CacheControl::new()
+                .private()
+                .public()
+                .max_age(Duration::from_millis(1500))
+                .must_revalidate(),
+        )
+        .create()
+}
+
+pub fn router_cache_control() -> Router {
+    Router::new()
+        .route("/success", get(route_cache_control_success))
+        .route("/failure", get(route_cache_control_failure))
+        .route("/replace", get(route_cache_control_replace))
+}
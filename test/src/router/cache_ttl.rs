@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use axum::{Router, http::header, routing::post};
+use jder_axum::{
+    layers::{CacheTtl, cache_ttl::CacheTtlRegistry},
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_cache_ttl() -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+async fn route_cache_ttl_failure() -> Response {
+    CreateJsonResponse::failure().create()
+}
+
+async fn route_cache_ttl_override() -> Response {
+    CreateJsonResponse::dataless()
+        .header(header::CACHE_CONTROL, "no-store")
+        .create()
+}
+
+pub fn router_cache_ttl() -> Router {
+    let registry: CacheTtlRegistry = CacheTtlRegistry::new()
+        .route("/cache_ttl/{id}", Duration::from_secs(60))
+        .route("/cache_ttl/failure", Duration::from_secs(60))
+        .route("/cache_ttl/override", Duration::from_secs(60));
+
+    Router::new()
+        .route("/{id}", post(route_cache_ttl))
+        .route("/failure", post(route_cache_ttl_failure))
+        .route("/override", post(route_cache_ttl_override))
+        .route("/", post(route_cache_ttl))
+        .layer(CacheTtl::new(registry))
+}
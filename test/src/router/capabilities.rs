@@ -0,0 +1,9 @@
+use axum::http::Method;
+use jder_axum::response::json::Capabilities;
+
+#[axum::debug_handler]
+pub async fn route_capabilities() -> Capabilities {
+    Capabilities::new()
+        .methods([Method::GET, Method::PATCH, Method::POST, Method::GET])
+        .accept_patch(["application/merge-patch+json"])
+}
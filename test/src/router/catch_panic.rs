@@ -0,0 +1,28 @@
+use axum::{Router, routing::post};
+use jder_axum::{
+    layers::CatchPanic,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_ok() -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+async fn route_panic() -> Response {
+    panic!("Failed to load\nsecret details");
+}
+
+pub fn router_catch_panic() -> Router {
+    Router::new()
+        .merge(
+            Router::new()
+                .route("/ok", post(route_ok))
+                .route("/panic", post(route_panic))
+                .layer(CatchPanic::new()),
+        )
+        .merge(
+            Router::new()
+                .route("/debug", post(route_panic))
+                .layer(CatchPanic::new().debug(true)),
+        )
+}
@@ -0,0 +1,43 @@
+use jder_axum::response::{Response, cbor::CreateCborResponse};
+use serde::{Serialize, Serializer, ser::Error};
+
+#[derive(Serialize)]
+pub struct RouteCborData {
+    pub id: u32,
+    pub name: String,
+    pub offset: i64,
+}
+
+#[axum::debug_handler]
+pub async fn route_cbor() -> Response {
+    CreateCborResponse::success::<RouteCborData>()
+        .data(RouteCborData {
+            id: 1000,
+            name: "Name".to_string(),
+            offset: -500,
+        })
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_cbor_failure() -> Response {
+    CreateCborResponse::failure().create()
+}
+
+pub struct RouteCborInvalidData;
+
+impl Serialize for RouteCborInvalidData {
+    fn serialize<S: Serializer>(
+        &self,
+        _serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Err(S::Error::custom("invalid"))
+    }
+}
+
+#[axum::debug_handler]
+pub async fn route_cbor_invalid() -> Response {
+    CreateCborResponse::success::<RouteCborInvalidData>()
+        .data(RouteCborInvalidData)
+        .create()
+}
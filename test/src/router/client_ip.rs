@@ -0,0 +1,48 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+use axum::{Extension, Router, routing::post};
+use jder_axum::{
+    extract::extra::{ClientIp, ClientIpConfig, ClientIpSource},
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_client_ip(ClientIp(ip): ClientIp) -> Response {
+    CreateJsonResponse::success::<String>().data(ip.to_string()).create()
+}
+
+pub fn router_client_ip() -> Router {
+    Router::new()
+        .route("/", post(route_client_ip))
+        .route(
+            "/hops",
+            post(route_client_ip)
+                .layer(Extension(ClientIpConfig::new().trusted_hops(2))),
+        )
+        .route(
+            "/forwarded",
+            post(route_client_ip).layer(Extension(
+                ClientIpConfig::new()
+                    .source(ClientIpSource::Forwarded)
+                    .trusted_hops(2),
+            )),
+        )
+        .route(
+            "/real_ip",
+            post(route_client_ip).layer(Extension(
+                ClientIpConfig::new()
+                    .source(ClientIpSource::XRealIp)
+                    .trusted_proxy(IpAddr::V4(Ipv4Addr::LOCALHOST), 8),
+            )),
+        )
+        .route(
+            "/proxy",
+            post(route_client_ip).layer(Extension(
+                ClientIpConfig::new()
+                    .trusted_proxy(IpAddr::V4(Ipv4Addr::LOCALHOST), 8)
+                    .trusted_proxy(
+                        IpAddr::V4(Ipv4Addr::new(198, 51, 100, 0)),
+                        24,
+                    ),
+            )),
+        )
+}
@@ -0,0 +1,67 @@
+use axum::{
+    Router,
+    http::header,
+    routing::{get, post},
+};
+use jder_axum::{
+    extract::Json,
+    layers::Compression,
+    response::{
+        CreateResponse, Response,
+        json::{CreateJsonResponse, JsonResponse},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteCompressionData {
+    pub names: Vec<String>,
+}
+
+async fn route_compression_large() -> Response {
+    CreateJsonResponse::success::<RouteCompressionData>()
+        .data(RouteCompressionData {
+            names: (0..100).map(|i| format!("Name {i}")).collect(),
+        })
+        .create()
+}
+
+async fn route_compression_etag() -> Response {
+    CreateJsonResponse::success::<RouteCompressionData>()
+        .header(header::ETAG, "\"names\"")
+        .data(RouteCompressionData {
+            names: (0..100).map(|i| format!("Name {i}")).collect(),
+        })
+        .create()
+}
+
+async fn route_compression_small() -> Response {
+    CreateJsonResponse::success::<RouteCompressionData>()
+        .data(RouteCompressionData { names: vec!["Name".to_string()] })
+        .create()
+}
+
+async fn route_compression_text() -> Response {
+    CreateResponse::success()
+        .header("content-type", "text/plain")
+        .body("Name ".repeat(500))
+        .create()
+}
+
+async fn route_compression_echo(
+    Json(res): Json<JsonResponse<RouteCompressionData>>
+) -> Response {
+    CreateJsonResponse::success::<usize>()
+        .data(res.data.map(|data| data.names.len()).unwrap_or_default())
+        .create()
+}
+
+pub fn router_compression() -> Router {
+    Router::new()
+        .route("/large", get(route_compression_large))
+        .route("/etag", get(route_compression_etag))
+        .route("/small", get(route_compression_small))
+        .route("/text", get(route_compression_text))
+        .layer(Compression::new().threshold(512))
+        .route("/echo", post(route_compression_echo))
+}
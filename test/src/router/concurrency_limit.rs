@@ -0,0 +1,30 @@
+use std::{convert::Infallible, time::Duration};
+
+use axum::{Router, body::Body, routing::post};
+use bytes::Bytes;
+use futures_util::stream;
+use jder_axum::{
+    layers::ConcurrencyLimit,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_concurrency_limit() -> Response {
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    CreateJsonResponse::dataless().create()
+}
+
+async fn route_concurrency_limit_stream() -> Response {
+    Response::new(Body::from_stream(stream::once(async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        Ok::<Bytes, Infallible>(Bytes::from_static(b"done"))
+    })))
+}
+
+pub fn router_concurrency_limit() -> Router {
+    Router::new()
+        .route("/", post(route_concurrency_limit))
+        .route("/stream", post(route_concurrency_limit_stream))
+        .layer(ConcurrencyLimit::header("x-api-key", 1))
+}
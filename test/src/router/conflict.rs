@@ -0,0 +1,18 @@
+use jder_axum::response::{Response, json::CreateJsonResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteConflictResponseData {
+    pub version: usize,
+    pub content: String,
+}
+
+#[axum::debug_handler]
+pub async fn route_conflict() -> Response {
+    CreateJsonResponse::conflict_with_current(RouteConflictResponseData {
+        version: 2,
+        content: "Updated".to_string(),
+    })
+    .header("etag", "\"2\"")
+    .create()
+}
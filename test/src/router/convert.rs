@@ -0,0 +1,21 @@
+use std::io::{Error as IoError, ErrorKind};
+
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, ResponseError},
+};
+use serde_json::Value;
+
+#[axum::debug_handler]
+pub async fn route_convert() -> Response {
+    let json_error: serde_json::Error =
+        serde_json::from_str::<Value>("{").unwrap_err();
+
+    let io_error: IoError = IoError::new(ErrorKind::NotFound, "/etc/secret");
+
+    CreateJsonResponse::failure()
+        .add_error(ResponseError::Timeout)
+        .add_error(json_error)
+        .add_error(io_error)
+        .create()
+}
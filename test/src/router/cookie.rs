@@ -0,0 +1,36 @@
+use jder_axum::{
+    extract::extra::{Cookie, CookieJar},
+    response::{
+        Response,
+        cookie::{ResponseCookie, SameSite},
+        json::{ApiError, CreateJsonResponse},
+    },
+};
+
+#[axum::debug_handler]
+pub async fn route_cookie(jar: CookieJar) -> Result<Response, ApiError> {
+    let session: &Cookie = jar.get_required("session")?;
+
+    Ok(CreateJsonResponse::success::<String>()
+        .cookie(
+            Cookie::build(("visited", "true"))
+                .path("/")
+                .http_only(true)
+                .build(),
+        )
+        .cookies([ResponseCookie::new("theme", "dark")
+            .max_age(3600)
+            .path("/")
+            .secure(true)
+            .same_site(SameSite::Lax)])
+        .remove_cookie("session")
+        .data(session.value().to_string())
+        .create())
+}
+
+#[axum::debug_handler]
+pub async fn route_cookie_invalid() -> Response {
+    CreateJsonResponse::dataless()
+        .cookie(ResponseCookie::new("invalid name", "value"))
+        .create()
+}
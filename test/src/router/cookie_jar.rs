@@ -0,0 +1,49 @@
+use axum::{Router, routing::get};
+use jder_axum::{
+    extract::extra::{Cookie, Key, PrivateCookieJar, SignedCookieJar},
+    response::{
+        Response,
+        json::{ApiError, CreateJsonResponse},
+    },
+};
+
+async fn route_signed_set(jar: SignedCookieJar) -> (SignedCookieJar, Response) {
+    (
+        jar.add(Cookie::new("session", "abc")),
+        CreateJsonResponse::dataless().create(),
+    )
+}
+
+async fn route_signed(jar: SignedCookieJar) -> Result<Response, ApiError> {
+    let session: Cookie = jar.get_required("session")?;
+
+    Ok(CreateJsonResponse::success::<String>()
+        .data(session.value().to_string())
+        .create())
+}
+
+async fn route_private_set(
+    jar: PrivateCookieJar
+) -> (PrivateCookieJar, Response) {
+    (
+        jar.add(Cookie::new("session", "abc")),
+        CreateJsonResponse::dataless().create(),
+    )
+}
+
+async fn route_private(jar: PrivateCookieJar) -> Result<Response, ApiError> {
+    let session: Cookie = jar.get_required("session")?;
+
+    Ok(CreateJsonResponse::success::<String>()
+        .data(session.value().to_string())
+        .create())
+}
+
+pub fn router_cookie_jar() -> Router {
+    Router::new()
+        .route("/signed", get(route_signed))
+        .route("/signed/set", get(route_signed_set))
+        .route("/private", get(route_private))
+        .route("/private/set", get(route_private_set))
+        .with_state(Key::from(&[0u8; 64]))
+}
@@ -0,0 +1,26 @@
+use axum::{Router, routing::post};
+use jder_axum::{
+    layers::Cors,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_cors() -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+pub fn router_cors() -> Router {
+    Router::new()
+        .merge(
+            Router::new().route("/", post(route_cors)).layer(
+                Cors::new()
+                    .allow_origins(["https://example.com"])
+                    .allow_credentials(true)
+                    .expose_headers(["etag"]),
+            ),
+        )
+        .merge(
+            Router::new()
+                .route("/any", post(route_cors))
+                .layer(Cors::new().allow_any_origin()),
+        )
+}
@@ -0,0 +1,52 @@
+use jder_axum::{
+    extract::{
+        Json, Path,
+        valid::{CrossValid, CrossValidate},
+    },
+    response::{
+        Response,
+        json::{CreateJsonResponse, ValidationErrors},
+    },
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct RouteCrossValidData {
+    id: u32,
+    name: String,
+}
+
+impl CrossValidate<u32> for RouteCrossValidData {
+    fn cross_validate(
+        &self,
+        id: &u32,
+    ) -> Result<(), ValidationErrors> {
+        let mut errors: ValidationErrors = ValidationErrors::new();
+
+        if self.id != *id {
+            errors.add_cross(
+                ["path", "id"],
+                ["json", "id"],
+                "mismatch",
+                "ID does not match",
+            );
+        }
+
+        match errors.is_empty() {
+            | true => Ok(()),
+            | false => Err(errors),
+        }
+    }
+}
+
+#[axum::debug_handler]
+pub async fn route_cross_valid(
+    CrossValid(Path(id), Json(data)): CrossValid<
+        u32,
+        Json<RouteCrossValidData>,
+    >
+) -> Response {
+    CreateJsonResponse::success::<String>()
+        .data(format!("{id}:{}", data.name))
+        .create()
+}
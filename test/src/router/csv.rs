@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use axum::{Router, routing::get};
+use jder_axum::response::{Response, csv::CreateCsvResponse};
+use serde::{Serialize, Serializer};
+
+#[derive(Serialize)]
+pub struct RouteCsvRow {
+    pub name: String,
+    pub id: usize,
+    pub note: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct RouteCsvNestedRow {
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
+pub struct RouteCsvBroken;
+
+impl Serialize for RouteCsvBroken {
+    fn serialize<S: Serializer>(
+        &self,
+        _: S,
+    ) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom("broken"))
+    }
+}
+
+async fn route_csv() -> Response {
+    let rows: Vec<RouteCsvRow> = vec![
+        RouteCsvRow { name: "Alice".to_string(), id: 1, note: None },
+        RouteCsvRow {
+            name: "Bob".to_string(),
+            id: 2,
+            note: Some("says \"hi\", twice".to_string()),
+        },
+    ];
+
+    CreateCsvResponse::iter(rows).file_name("users.csv").create()
+}
+
+async fn route_csv_tuple() -> Response {
+    CreateCsvResponse::iter(vec![(1, "a"), (2, "b\nc")]).create()
+}
+
+async fn route_csv_map() -> Response {
+    let row: BTreeMap<&str, usize> = BTreeMap::from([("x", 1), ("y", 2)]);
+
+    CreateCsvResponse::iter(vec![row]).create()
+}
+
+async fn route_csv_empty() -> Response {
+    CreateCsvResponse::iter(Vec::<RouteCsvRow>::new()).create()
+}
+
+async fn route_csv_broken() -> Response {
+    CreateCsvResponse::iter(vec![RouteCsvBroken]).create()
+}
+
+async fn route_csv_nested() -> Response {
+    CreateCsvResponse::iter(vec![RouteCsvNestedRow {
+        name: "Alice".to_string(),
+        tags: vec!["a".to_string()],
+    }])
+    .create()
+}
+
+async fn route_csv_file_name() -> Response {
+    CreateCsvResponse::iter(vec![1]).file_name("a\"b.csv").create()
+}
+
+pub fn router_csv() -> Router {
+    Router::new()
+        .route("/", get(route_csv))
+        .route("/tuple", get(route_csv_tuple))
+        .route("/map", get(route_csv_map))
+        .route("/empty", get(route_csv_empty))
+        .route("/broken", get(route_csv_broken))
+        .route("/nested", get(route_csv_nested))
+        .route("/file_name", get(route_csv_file_name))
+}
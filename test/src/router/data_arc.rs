@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use axum::{Router, extract::State, routing::post};
+use jder_axum::response::{Response, json::CreateJsonResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteDataArcData {
+    pub names: Vec<String>,
+}
+
+async fn route_data_arc(State(data): State<Arc<RouteDataArcData>>) -> Response {
+    CreateJsonResponse::success::<RouteDataArcData>().data_arc(data).create()
+}
+
+pub fn router_data_arc() -> Router {
+    let data: Arc<RouteDataArcData> = Arc::new(RouteDataArcData {
+        names: vec!["a".to_string(), "b".to_string()],
+    });
+
+    Router::new().route("/", post(route_data_arc)).with_state(data)
+}
@@ -0,0 +1,69 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use axum::{
+    Router,
+    extract::{Path, State},
+    routing::post,
+};
+use jder_axum::response::{
+    Response, data_cache::CachedDataProvider, json::CreateJsonResponse,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteDataCacheData {
+    pub key: String,
+    pub computed: usize,
+}
+
+#[derive(Clone)]
+struct RouteDataCacheState {
+    provider: CachedDataProvider<RouteDataCacheData>,
+    computed: Arc<AtomicUsize>,
+}
+
+async fn route_data_cache(
+    State(state): State<RouteDataCacheState>,
+    Path(key): Path<String>,
+) -> Response {
+    let data: Arc<RouteDataCacheData> = state
+        .provider
+        .get(key.clone(), || async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            RouteDataCacheData {
+                key,
+                computed: state.computed.fetch_add(1, Ordering::SeqCst) + 1,
+            }
+        })
+        .await;
+
+    CreateJsonResponse::success::<RouteDataCacheData>().data_arc(data).create()
+}
+
+async fn route_data_cache_invalidate(
+    State(state): State<RouteDataCacheState>,
+    Path(key): Path<String>,
+) -> Response {
+    state.provider.invalidate(&key);
+
+    CreateJsonResponse::dataless().create()
+}
+
+pub fn router_data_cache() -> Router {
+    let state: RouteDataCacheState = RouteDataCacheState {
+        provider: CachedDataProvider::new(Duration::from_secs(60)),
+        computed: Arc::new(AtomicUsize::new(0)),
+    };
+
+    Router::new()
+        .route("/{key}", post(route_data_cache))
+        .route("/{key}/invalidate", post(route_data_cache_invalidate))
+        .with_state(state)
+}
@@ -0,0 +1,15 @@
+use jder_axum::response::{Response, json::CreateJsonResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct RouteDataDefaultData {
+    pub names: Vec<String>,
+    pub count: usize,
+}
+
+#[axum::debug_handler]
+pub async fn route_data_default() -> Response {
+    CreateJsonResponse::success::<RouteDataDefaultData>()
+        .data_default()
+        .create()
+}
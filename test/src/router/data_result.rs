@@ -0,0 +1,32 @@
+use axum::{Router, extract::Path, http::StatusCode, routing::post};
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponseError},
+};
+
+fn find_name(id: u32) -> Result<String, JsonResponseError> {
+    match id {
+        | 1 => Ok("Name".to_string()),
+        | _ => Err(JsonResponseError::new()
+            .code("not_found")
+            .message("Name not found")),
+    }
+}
+
+async fn route_data_result(Path(id): Path<u32>) -> Response {
+    CreateJsonResponse::success::<String>().data_result(find_name(id)).create()
+}
+
+async fn route_data_result_with(Path(id): Path<u32>) -> Response {
+    CreateJsonResponse::success::<String>()
+        .status(StatusCode::CREATED)
+        .data_result_with(find_name(id), StatusCode::NOT_FOUND)
+        .status(StatusCode::CREATED)
+        .create()
+}
+
+pub fn router_data_result() -> Router {
+    Router::new()
+        .route("/{id}", post(route_data_result))
+        .route("/with/{id}", post(route_data_result_with))
+}
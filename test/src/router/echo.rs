@@ -0,0 +1,6 @@
+use axum::{Router, routing::any};
+use jder_axum::handler::echo;
+
+pub fn router_echo() -> Router {
+    Router::new().route("/", any(echo))
+}
@@ -0,0 +1,47 @@
+use axum::{Router, routing::post};
+use jder_axum::{
+    layers::ErrorShadow,
+    response::{
+        Response,
+        json::{CreateJsonResponse, ErrorKeysFormat, JsonResponseError},
+    },
+};
+use serde_json::json;
+
+async fn route_ok() -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+async fn route_failure() -> Response {
+    CreateJsonResponse::failure()
+        .add_error(JsonResponseError::new().code("parse").message("Invalid"))
+        .create()
+}
+
+async fn route_failure_error_keys() -> Response {
+    CreateJsonResponse::with_format(ErrorKeysFormat::new().errors("issues"))
+        .failure()
+        .add_error(JsonResponseError::new().code("parse").message("Invalid"))
+        .create()
+}
+
+pub fn router_error_shadow() -> Router {
+    Router::new()
+        .merge(
+            Router::new()
+                .route("/ok", post(route_ok))
+                .route("/failure", post(route_failure))
+                .route("/failure/error_keys", post(route_failure_error_keys))
+                .layer(ErrorShadow::new(|errors, status| {
+                    json!({
+                        "status": status.as_u16(),
+                        "error": errors.first().and_then(|e| e.message.clone()),
+                    })
+                })),
+        )
+        .merge(
+            Router::new()
+                .route("/disabled", post(route_failure))
+                .layer(ErrorShadow::new(|_, _| json!({})).enabled(false)),
+        )
+}
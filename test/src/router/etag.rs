@@ -0,0 +1,37 @@
+use axum::{Router, http::StatusCode, routing::get};
+use jder_axum::{
+    extract::IfNoneMatch,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_etag_auto(if_none_match: IfNoneMatch) -> Response {
+    CreateJsonResponse::success::<String>()
+        .header("cache-control", "no-cache")
+        .data("Name".to_string())
+        .etag_auto()
+        .if_none_match(&if_none_match)
+        .create()
+}
+
+async fn route_etag_value(if_none_match: IfNoneMatch) -> Response {
+    CreateJsonResponse::success::<String>()
+        .data("Name".to_string())
+        .etag("v1")
+        .if_none_match(&if_none_match)
+        .create()
+}
+
+async fn route_etag_failure(if_none_match: IfNoneMatch) -> Response {
+    CreateJsonResponse::success::<String>()
+        .status(StatusCode::NOT_FOUND)
+        .etag("v1")
+        .if_none_match(&if_none_match)
+        .create()
+}
+
+pub fn router_etag() -> Router {
+    Router::new()
+        .route("/auto", get(route_etag_auto))
+        .route("/value", get(route_etag_value))
+        .route("/failure", get(route_etag_failure))
+}
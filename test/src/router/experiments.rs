@@ -0,0 +1,30 @@
+use std::collections::BTreeMap;
+
+use axum::{Router, http::request::Parts, routing::post};
+use jder_axum::{
+    extract::experiments::ExperimentAssignment,
+    layers::Experiments,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_experiments(assignment: ExperimentAssignment) -> Response {
+    CreateJsonResponse::success::<bool>()
+        .data(assignment.is_variant("checkout", "b"))
+        .create()
+}
+
+fn assign_experiments(parts: &Parts) -> BTreeMap<String, String> {
+    let variant: &str = match parts.headers.contains_key("x-beta") {
+        | true => "b",
+        | false => "a",
+    };
+
+    BTreeMap::from([("checkout".to_string(), variant.to_string())])
+}
+
+pub fn router_experiments() -> Router {
+    Router::new()
+        .route("/", post(route_experiments))
+        .layer(Experiments::new(assign_experiments))
+        .route("/missing", post(route_experiments))
+}
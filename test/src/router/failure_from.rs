@@ -0,0 +1,9 @@
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, ResponseError},
+};
+
+#[axum::debug_handler]
+pub async fn route_failure_from() -> Response {
+    CreateJsonResponse::failure_from(ResponseError::Forbidden).create()
+}
@@ -0,0 +1,44 @@
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    Router,
+    extract::State,
+    routing::{get, post},
+};
+use jder_axum::{
+    layers::FailureSampling,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError},
+    },
+};
+
+type Samples = Arc<Mutex<Vec<u64>>>;
+
+async fn route_failure_sampling() -> Response {
+    CreateJsonResponse::failure()
+        .add_error(JsonResponseError::new().code("bad").path(["json", "name"]))
+        .create()
+}
+
+async fn route_failure_sampling_samples(
+    State(samples): State<Samples>
+) -> Response {
+    let samples: Vec<u64> = samples.lock().unwrap().clone();
+
+    CreateJsonResponse::success::<Vec<u64>>().data(samples).create()
+}
+
+pub fn router_failure_sampling() -> Router {
+    let samples: Samples = Arc::new(Mutex::new(Vec::new()));
+
+    let hook_samples: Samples = samples.clone();
+
+    Router::new()
+        .route("/{id}", post(route_failure_sampling))
+        .layer(FailureSampling::new(3, move |sample| {
+            hook_samples.lock().unwrap().push(sample.count);
+        }))
+        .route("/samples", get(route_failure_sampling_samples))
+        .with_state(samples)
+}
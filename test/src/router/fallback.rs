@@ -0,0 +1,13 @@
+use axum::{Router, routing::get};
+use jder_axum::{
+    handler::json_fallback,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_fallback() -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+pub fn router_fallback() -> Router {
+    json_fallback(Router::new().route("/", get(route_fallback)))
+}
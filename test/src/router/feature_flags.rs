@@ -0,0 +1,19 @@
+use axum::{Router, routing::post};
+use jder_axum::{
+    extract::feature_flags::{FeatureFlags, FeatureFlagsAllowList},
+    layers::FeatureFlagsEcho,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_feature_flags(flags: FeatureFlags) -> Response {
+    CreateJsonResponse::success::<bool>()
+        .data(flags.is_enabled("new_checkout"))
+        .create()
+}
+
+pub fn router_feature_flags() -> Router {
+    Router::new()
+        .route("/", post(route_feature_flags))
+        .with_state(FeatureFlagsAllowList::new(["dark_mode", "new_checkout"]))
+        .layer(FeatureFlagsEcho::new())
+}
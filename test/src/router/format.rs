@@ -0,0 +1,64 @@
+use jder_axum::response::{
+    Response,
+    json::{
+        CreateJsonResponse, ErrorKeysFormat, JsonResponseError, ResponseFormat,
+        ResponseFormatParts,
+    },
+};
+use serde_json::{Value, json};
+
+pub struct RouteFormat;
+
+impl ResponseFormat for RouteFormat {
+    fn format(
+        &self,
+        parts: ResponseFormatParts,
+    ) -> Value {
+        match parts.success {
+            | true => json!({ "ok": true, "result": parts.data }),
+            | false => json!({ "ok": false, "error": parts.errors.first() }),
+        }
+    }
+}
+
+#[axum::debug_handler]
+pub async fn route_format() -> Response {
+    CreateJsonResponse::with_format(RouteFormat)
+        .success::<String>()
+        .data("Name".to_string())
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_format_failure() -> Response {
+    CreateJsonResponse::with_format(RouteFormat)
+        .failure()
+        .add_error(JsonResponseError::new().code("custom"))
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_format_header() -> Response {
+    CreateJsonResponse::with_format(RouteFormat)
+        .dataless()
+        .header("invalid header", "value")
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_format_error_keys() -> Response {
+    CreateJsonResponse::with_format(
+        ErrorKeysFormat::new()
+            .errors("issues")
+            .path("pointer")
+            .message("detail"),
+    )
+    .failure()
+    .add_error(
+        JsonResponseError::new()
+            .code("custom")
+            .path(["json", "name"])
+            .message("Invalid name"),
+    )
+    .create()
+}
@@ -0,0 +1,93 @@
+use async_graphql::{
+    EmptyMutation, EmptySubscription, Error, ErrorExtensions, Object, Schema,
+};
+use axum::http::StatusCode;
+use jder_axum::response::{
+    Response,
+    graphql::{GraphQLFormat, GraphQLResponse},
+    json::{CreateJsonResponse, JsonResponse, JsonResponseError},
+};
+use serde_json::{Value, json};
+
+#[axum::debug_handler]
+pub async fn route_graphql() -> JsonResponse<Value> {
+    let result: Value = json!({
+        "data": { "user": null },
+        "errors": [
+            {
+                "message": "User not found",
+                "locations": [{ "line": 2, "column": 3 }],
+                "path": ["user", 0],
+                "extensions": { "code": "not_found" },
+            },
+            {
+                "message": "Unknown field",
+                "locations": [{ "line": 4, "column": 5 }],
+            },
+        ],
+        "extensions": { "cost": 1 },
+    });
+
+    let result: GraphQLResponse = serde_json::from_value(result).unwrap();
+
+    JsonResponse::from(result)
+}
+
+#[axum::debug_handler]
+pub async fn route_graphql_format() -> Response {
+    CreateJsonResponse::with_format(GraphQLFormat)
+        .failure()
+        .status(StatusCode::OK)
+        .header("x-graphql", "1")
+        .add_errors([
+            JsonResponseError::new()
+                .code("not_found")
+                .path(["graphql", "user", "0"])
+                .message("User not found"),
+            JsonResponseError::new()
+                .code("unknown")
+                .path(["graphql", "4:5"])
+                .message("Unknown field"),
+        ])
+        .create()
+}
+
+struct RouteGraphQLUser {
+    id: u32,
+    is_hidden: bool,
+}
+
+#[Object]
+impl RouteGraphQLUser {
+    async fn id(&self) -> u32 {
+        self.id
+    }
+
+    async fn name(&self) -> Result<&str, Error> {
+        match self.is_hidden {
+            | true => Err(Error::new("User is hidden")
+                .extend_with(|_, ext| ext.set("code", "forbidden"))),
+            | false => Ok("Name"),
+        }
+    }
+}
+
+struct RouteGraphQLQuery;
+
+#[Object]
+impl RouteGraphQLQuery {
+    async fn users(&self) -> Vec<Option<RouteGraphQLUser>> {
+        vec![
+            Some(RouteGraphQLUser { id: 1, is_hidden: false }),
+            Some(RouteGraphQLUser { id: 2, is_hidden: true }),
+        ]
+    }
+}
+
+#[axum::debug_handler]
+pub async fn route_graphql_async_graphql() -> JsonResponse<Value> {
+    let schema =
+        Schema::new(RouteGraphQLQuery, EmptyMutation, EmptySubscription);
+
+    JsonResponse::from(schema.execute("{ users { id name } }").await)
+}
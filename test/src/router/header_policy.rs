@@ -0,0 +1,8 @@
+use jder_axum::response::{Response, json::CreateJsonResponse};
+
+pub async fn route_header_policy() -> Response {
+    CreateJsonResponse::dataless()
+        .header("x-header-policy-denied", "denied")
+        .header("x-header-policy-kept", "kept")
+        .create()
+}
@@ -0,0 +1,27 @@
+use axum::{Router, routing::post};
+use jder_axum::{
+    layers::HtmlError,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JsonResponseError},
+    },
+};
+
+async fn route_ok() -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+async fn route_failure() -> Response {
+    CreateJsonResponse::failure()
+        .add_error(
+            JsonResponseError::new().code("parse").message("Invalid <title>"),
+        )
+        .create()
+}
+
+pub fn router_html_error() -> Router {
+    Router::new()
+        .route("/ok", post(route_ok))
+        .route("/failure", post(route_failure))
+        .layer(HtmlError::new())
+}
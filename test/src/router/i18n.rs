@@ -0,0 +1,66 @@
+use axum::{Router, routing::post};
+use jder_axum::{
+    extract::{Json, Locale},
+    layers::Localize,
+    response::{
+        Response,
+        json::{
+            CreateJsonResponse, JsonResponseError, MessageCatalog,
+            ResponseError,
+        },
+    },
+};
+async fn route_i18n(
+    locale: Locale,
+    Json(_): Json<String>,
+) -> Response {
+    CreateJsonResponse::success::<String>()
+        .data(locale.tag().to_string())
+        .create()
+}
+
+async fn route_i18n_localized(locale: Locale) -> Response {
+    CreateJsonResponse::failure()
+        .add_error(
+            JsonResponseError::new()
+                .code(ResponseError::NotFound.as_code())
+                .message(ResponseError::NotFound.as_message()),
+        )
+        .localized(&locale)
+        .create()
+}
+
+async fn route_i18n_unlocalized() -> Response {
+    CreateJsonResponse::failure()
+        .add_error(
+            JsonResponseError::new()
+                .code(ResponseError::NotFound.as_code())
+                .message(ResponseError::NotFound.as_message()),
+        )
+        .create()
+}
+
+pub fn router_i18n() -> Router {
+    Router::new()
+        .route("/", post(route_i18n))
+        .route("/localized", post(route_i18n_localized))
+        .route("/unlocalized", post(route_i18n_unlocalized))
+        .layer(Localize::new(
+            MessageCatalog::new()
+                .message(
+                    "fr",
+                    ResponseError::Parse.as_code(),
+                    "Requête invalide",
+                )
+                .messages(
+                    "de",
+                    [(ResponseError::NotFound.as_code(), "Nicht gefunden")],
+                )
+                .message(
+                    "fr",
+                    ResponseError::NotFound.as_code(),
+                    "Introuvable",
+                ),
+        ))
+        .route("/missing_config", post(route_i18n_localized))
+}
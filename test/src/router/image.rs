@@ -0,0 +1,49 @@
+use axum::{BoxError, Router, http::HeaderMap, routing::get};
+use bytes::Bytes;
+use jder_axum::response::{
+    Response,
+    image::{CreateImageResponse, ImageFormat, ImageTranscoder},
+};
+
+pub const PNG: &[u8] = b"\x89PNG\r\n\x1a\nimage";
+
+pub const WEBP: &[u8] = b"RIFF\0\0\0\0WEBPimage";
+
+struct WebpTranscoder;
+
+impl ImageTranscoder for WebpTranscoder {
+    fn formats(&self) -> &[ImageFormat] {
+        &[ImageFormat::Webp]
+    }
+
+    fn transcode(
+        &self,
+        _: Bytes,
+        _: ImageFormat,
+        _: ImageFormat,
+    ) -> Result<Bytes, BoxError> {
+        Ok(Bytes::from_static(WEBP))
+    }
+}
+
+async fn route_image(headers: HeaderMap) -> Response {
+    CreateImageResponse::bytes(PNG)
+        .max_age(3600)
+        .transcoder(WebpTranscoder)
+        .create_negotiated(&headers)
+}
+
+async fn route_image_missing() -> Response {
+    CreateImageResponse::optional(None::<Bytes>).create()
+}
+
+async fn route_image_corrupt() -> Response {
+    CreateImageResponse::bytes("not an image").create()
+}
+
+pub fn router_image() -> Router {
+    Router::new()
+        .route("/", get(route_image))
+        .route("/missing", get(route_image_missing))
+        .route("/corrupt", get(route_image_corrupt))
+}
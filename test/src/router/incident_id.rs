@@ -0,0 +1,33 @@
+use axum::{Router, http::StatusCode, routing::get};
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+};
+
+async fn route_incident_id_server() -> Response {
+    CreateJsonResponse::failure()
+        .status(StatusCode::INTERNAL_SERVER_ERROR)
+        .add_error(JsonResponseError::from(ResponseError::Server))
+        .create()
+}
+
+async fn route_incident_id_client() -> Response {
+    CreateJsonResponse::failure()
+        .status(StatusCode::NOT_FOUND)
+        .add_error(JsonResponseError::from(ResponseError::NotFound))
+        .create()
+}
+
+async fn route_incident_id_problem() -> Response {
+    CreateJsonResponse::failure()
+        .status(StatusCode::SERVICE_UNAVAILABLE)
+        .add_error(JsonResponseError::from(ResponseError::Unavailable))
+        .problem_details()
+}
+
+pub fn router_incident_id() -> Router {
+    Router::new()
+        .route("/server", get(route_incident_id_server))
+        .route("/client", get(route_incident_id_client))
+        .route("/problem", get(route_incident_id_problem))
+}
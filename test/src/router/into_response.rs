@@ -0,0 +1,19 @@
+use axum::http::StatusCode;
+use jder_axum::response::json::{JsonResponse, JsonResponseError};
+
+#[axum::debug_handler]
+pub async fn route_into_response_success() -> JsonResponse<String> {
+    JsonResponse::new().data("Name".to_string())
+}
+
+#[axum::debug_handler]
+pub async fn route_into_response_failure() -> JsonResponse {
+    JsonResponse::new()
+        .success(false)
+        .errors([JsonResponseError::new().code("parse")])
+}
+
+#[axum::debug_handler]
+pub async fn route_into_response_status() -> JsonResponse<String> {
+    JsonResponse::new().data("Name".to_string()).status(StatusCode::CREATED)
+}
@@ -0,0 +1,60 @@
+use axum::{
+    Router,
+    extract::Path,
+    routing::{get, post},
+};
+use jder_axum::response::json::{ApiError, JsonError, jder_handler};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct RouteJderHandlerUser {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(JsonError)]
+pub enum RouteJderHandlerError {
+    #[json(status = 404, message = "User {id} not found")]
+    NotFound { id: u32 },
+}
+
+fn find_user(id: u32) -> Result<RouteJderHandlerUser, RouteJderHandlerError> {
+    match id {
+        | 1 => Ok(RouteJderHandlerUser { id, name: "Name".to_string() }),
+        | _ => Err(RouteJderHandlerError::NotFound { id }),
+    }
+}
+
+#[jder_handler]
+#[axum::debug_handler]
+async fn route_jder_handler_get(
+    Path(id): Path<u32>
+) -> Result<RouteJderHandlerUser, RouteJderHandlerError> {
+    let user: RouteJderHandlerUser = find_user(id)?;
+
+    Ok(user)
+}
+
+#[jder_handler(status = 201)]
+async fn route_jder_handler_create()
+-> Result<RouteJderHandlerUser, RouteJderHandlerError> {
+    Ok(RouteJderHandlerUser { id: 2, name: "New".to_string() })
+}
+
+#[jder_handler]
+async fn route_jder_handler_parse(
+    Path(value): Path<String>
+) -> Result<u32, ApiError> {
+    if value.is_empty() {
+        return Ok(0);
+    }
+
+    Ok(value.parse::<u32>()?)
+}
+
+pub fn router_jder_handler() -> Router {
+    Router::new()
+        .route("/users", post(route_jder_handler_create))
+        .route("/users/{id}", get(route_jder_handler_get))
+        .route("/parse/{value}", get(route_jder_handler_parse))
+}
@@ -0,0 +1,19 @@
+use jder_axum::{
+    extract::Path,
+    response::{
+        Response,
+        json::{CreateJsonResponse, JobStatus, JsonResponseError},
+    },
+};
+
+pub async fn route_job(Path(state): Path<String>) -> Response {
+    let status: JobStatus = match state.as_str() {
+        | "queued" => JobStatus::queued("job_1"),
+        | "running" => JobStatus::running("job_1").progress(40).retry_after(5),
+        | "succeeded" => JobStatus::succeeded("job_1").result_url("/result/1"),
+        | _ => JobStatus::failed("job_1")
+            .add_error(JsonResponseError::new().code("timeout")),
+    };
+
+    CreateJsonResponse::job(status).create()
+}
@@ -1,4 +1,5 @@
 pub mod optional;
+pub mod stream;
 
 use jder_axum::{
     extract::Json,
@@ -0,0 +1,20 @@
+use futures_util::stream;
+use jder_axum::response::{Response, json::CreateJsonResponse};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct RouteJsonStreamResponseMeta {
+    pub total: usize,
+}
+
+#[axum::debug_handler]
+pub async fn route_json_stream() -> Response {
+    CreateJsonResponse::stream(stream::iter(vec![1, 2, 3]))
+        .meta(RouteJsonStreamResponseMeta { total: 3 })
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_json_stream_empty() -> Response {
+    CreateJsonResponse::stream(stream::iter(Vec::<usize>::new())).create()
+}
@@ -0,0 +1,33 @@
+use axum::extract::Path;
+use jder_axum::response::json::{ApiResult, JsonError, JsonResponse};
+
+#[derive(JsonError)]
+pub enum RouteJsonError {
+    #[json(code = "auth", status = 401, message = "Unauthorized")]
+    Unauthorized,
+    #[json(status = 404, message = "User {id} not found")]
+    NotFound { id: u32 },
+    #[json(code = "invalid_name", message = "Invalid name: {0} {{0}}")]
+    InvalidName(String),
+}
+
+fn find_user(id: u32) -> Result<String, RouteJsonError> {
+    match id {
+        | 0 => Err(RouteJsonError::Unauthorized),
+        | 1 => Ok("Name".to_string()),
+        | 2 => Err(RouteJsonError::InvalidName("x".to_string())),
+        | _ => Err(RouteJsonError::NotFound { id }),
+    }
+}
+
+#[axum::debug_handler]
+pub async fn route_json_error(Path(id): Path<u32>) -> ApiResult<String> {
+    let name: String = find_user(id)?;
+
+    Ok(JsonResponse::new().data(name))
+}
+
+#[axum::debug_handler]
+pub async fn route_json_error_direct() -> RouteJsonError {
+    RouteJsonError::Unauthorized
+}
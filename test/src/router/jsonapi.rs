@@ -0,0 +1,30 @@
+use axum::http::HeaderMap;
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct RouteJsonApiData {
+    pub id: String,
+}
+
+#[axum::debug_handler]
+pub async fn route_jsonapi(headers: HeaderMap) -> Response {
+    CreateJsonResponse::success::<RouteJsonApiData>()
+        .data(RouteJsonApiData { id: "1".to_string() })
+        .create_negotiated(&headers)
+}
+
+#[axum::debug_handler]
+pub async fn route_jsonapi_failure(headers: HeaderMap) -> Response {
+    CreateJsonResponse::failure()
+        .add_error(
+            JsonResponseError::new()
+                .code(ResponseError::Parse.as_code())
+                .path(["body", "name"])
+                .message("Invalid name"),
+        )
+        .create_negotiated(&headers)
+}
@@ -0,0 +1,25 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+use jder_axum::{
+    layers::{
+        Latency,
+        latency::{LatencyHistogram, latency_summary},
+    },
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_latency() -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+pub fn router_latency() -> Router {
+    let histogram: LatencyHistogram = LatencyHistogram::new();
+
+    Router::new()
+        .route("/{id}", post(route_latency))
+        .layer(Latency::new(histogram.clone()))
+        .route("/summary", get(latency_summary))
+        .with_state(histogram)
+}
@@ -0,0 +1,19 @@
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponseError},
+};
+
+#[axum::debug_handler]
+pub async fn route_message_max_length() -> Response {
+    CreateJsonResponse::failure()
+        .add_error(JsonResponseError::new().message("Hello, World!"))
+        .message_max_length(5)
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_message_max_length_global() -> Response {
+    CreateJsonResponse::failure()
+        .add_error(JsonResponseError::new().message("a".repeat(300)))
+        .create()
+}
@@ -0,0 +1,13 @@
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponseError},
+};
+
+pub async fn route_messages() -> Response {
+    CreateJsonResponse::failure()
+        .add_error(
+            JsonResponseError::new().code("messages").message("Hello, World!"),
+        )
+        .add_error(JsonResponseError::new().code("other").message("Other"))
+        .create()
+}
@@ -0,0 +1,19 @@
+use jder_axum::response::{Response, json::CreateJsonResponse};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct RouteMetaResponseMeta {
+    pub request_id: String,
+}
+
+#[axum::debug_handler]
+pub async fn route_meta() -> Response {
+    CreateJsonResponse::dataless()
+        .meta(RouteMetaResponseMeta { request_id: "abc".to_string() })
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_meta_invalid() -> Response {
+    CreateJsonResponse::dataless().meta("abc").create()
+}
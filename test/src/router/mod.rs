@@ -1,43 +1,223 @@
+pub mod api_result;
+pub mod archive;
+pub mod auth;
+pub mod body_read;
+pub mod broadcast;
+pub mod cache_control;
+pub mod cache_ttl;
+pub mod capabilities;
+pub mod catch_panic;
+pub mod cbor;
+pub mod client_ip;
+pub mod compression;
+pub mod concurrency_limit;
+pub mod conflict;
 pub mod connect_info;
+pub mod convert;
+pub mod cookie;
+pub mod cookie_jar;
+pub mod cors;
+pub mod cross_valid;
+pub mod csv;
+pub mod data_arc;
+pub mod data_cache;
+pub mod data_default;
+pub mod data_result;
+pub mod echo;
+pub mod error_shadow;
+pub mod etag;
+pub mod experiments;
+pub mod failure_from;
+pub mod failure_sampling;
+pub mod fallback;
+pub mod feature_flags;
 pub mod form;
+pub mod format;
+pub mod graphql;
+pub mod header_policy;
 pub mod host;
+pub mod html_error;
+pub mod i18n;
+pub mod image;
+pub mod incident_id;
+pub mod into_response;
+pub mod jder_handler;
+pub mod job;
 pub mod json;
+pub mod json_error;
+pub mod jsonapi;
+pub mod latency;
 pub mod matched_path;
+pub mod message_max_length;
+pub mod messages;
+pub mod meta;
+pub mod msgpack;
 pub mod multipart;
+pub mod ndjson;
+pub mod negotiate;
 pub mod nested_path;
+pub mod nonce;
+pub mod page_cursor;
+pub mod pagination;
 pub mod path;
+pub mod payload_sampling;
+pub mod problem_details;
 pub mod query;
+pub mod rate_limit;
+pub mod redirect;
+pub mod rejection_config;
 pub mod request_body_limit;
+pub mod request_context;
+pub mod request_id;
 pub mod request_time_limit;
+pub mod response_cache;
+pub mod response_data;
+pub mod schema_check;
+pub mod schema_version;
 pub mod scheme;
+pub mod security_headers;
+pub mod sitemap;
+pub mod size_hint;
+pub mod spill;
+pub mod sse;
+pub mod status;
+pub mod strict_success;
 pub mod typed_header;
+pub mod upstream_error;
+pub mod valid;
+pub mod validation;
+pub mod wellknown;
+pub mod xml;
 
 use std::net::SocketAddr;
 
+use archive::router_archive;
+use auth::router_auth;
 use axum::{
     Router,
     extract::{DefaultBodyLimit, connect_info::IntoMakeServiceWithConnectInfo},
-    routing::{get, post},
+    routing::{get, options, post},
 };
 use axum_test::TestServer;
+use body_read::router_body_read;
+use cache_control::router_cache_control;
+use cache_ttl::router_cache_ttl;
+use catch_panic::router_catch_panic;
+use client_ip::router_client_ip;
+use compression::router_compression;
+use concurrency_limit::router_concurrency_limit;
+use cookie_jar::router_cookie_jar;
+use cors::router_cors;
+use csv::router_csv;
+use data_arc::router_data_arc;
+use data_cache::router_data_cache;
+use data_result::router_data_result;
+use echo::router_echo;
+use error_shadow::router_error_shadow;
+use etag::router_etag;
+use experiments::router_experiments;
+use failure_sampling::router_failure_sampling;
+use fallback::router_fallback;
+use feature_flags::router_feature_flags;
+use html_error::router_html_error;
+use i18n::router_i18n;
+use image::router_image;
+use incident_id::router_incident_id;
 use jder_axum::response::{Response, json::CreateJsonResponse};
+use jder_handler::router_jder_handler;
+use latency::router_latency;
+use multipart::inspect::router_multipart_inspect;
+use nonce::router_nonce;
+use page_cursor::router_page_cursor;
+use payload_sampling::router_payload_sampling;
+use rate_limit::router_rate_limit;
+use redirect::router_redirect;
+use rejection_config::router_rejection_config;
 use request_body_limit::router_request_body_limit;
+use request_id::router_request_id;
 use request_time_limit::router_request_time_limit;
+use response_cache::router_response_cache;
+use schema_check::router_schema_check;
+use schema_version::router_schema_version;
+use security_headers::router_security_headers;
+use upstream_error::router_upstream_error;
+use wellknown::{router_wellknown, router_wellknown_invalid};
+use xml::router_xml;
 
+use crate::router::api_result::route_api_result;
+use crate::router::broadcast::route_broadcast;
+use crate::router::capabilities::route_capabilities;
+use crate::router::cbor::{route_cbor, route_cbor_failure, route_cbor_invalid};
+use crate::router::conflict::route_conflict;
 use crate::router::connect_info::route_connect_info;
+use crate::router::convert::route_convert;
+use crate::router::cookie::{route_cookie, route_cookie_invalid};
+use crate::router::cross_valid::route_cross_valid;
+use crate::router::data_default::route_data_default;
+use crate::router::failure_from::route_failure_from;
 use crate::router::form::route_form;
+use crate::router::format::{
+    route_format, route_format_error_keys, route_format_failure,
+    route_format_header,
+};
+use crate::router::graphql::{
+    route_graphql, route_graphql_async_graphql, route_graphql_format,
+};
+use crate::router::header_policy::route_header_policy;
 use crate::router::host::route_host;
-use crate::router::json::{optional::route_json_optional, route_json};
+use crate::router::into_response::{
+    route_into_response_failure, route_into_response_status,
+    route_into_response_success,
+};
+use crate::router::job::route_job;
+use crate::router::json::{
+    optional::route_json_optional,
+    route_json,
+    stream::{route_json_stream, route_json_stream_empty},
+};
+use crate::router::json_error::{route_json_error, route_json_error_direct};
+use crate::router::jsonapi::{route_jsonapi, route_jsonapi_failure};
 use crate::router::matched_path::route_matched_path;
+use crate::router::message_max_length::{
+    route_message_max_length, route_message_max_length_global,
+};
+use crate::router::messages::route_messages;
+use crate::router::meta::{route_meta, route_meta_invalid};
+use crate::router::msgpack::{route_msgpack, route_msgpack_invalid};
 use crate::router::multipart::file::route_multipart_file;
-use crate::router::multipart::route_multipart;
+use crate::router::multipart::spool::route_multipart_spool;
+use crate::router::multipart::{route_multipart, route_multipart_base};
+use crate::router::ndjson::{
+    route_ndjson, route_ndjson_shutdown, route_ndjson_stream,
+};
+use crate::router::negotiate::route_negotiate;
 use crate::router::nested_path::route_nested_path;
+use crate::router::pagination::{route_pagination, route_pagination_meta};
 use crate::router::path::route_path;
-use crate::router::query::route_query;
+use crate::router::problem_details::{
+    route_problem_details, route_problem_details_empty,
+};
+use crate::router::query::{route_query, route_query_qs, route_query_strict};
+use crate::router::request_context::route_request_context;
+use crate::router::response_data::{
+    route_response_data, route_response_data_admin,
+};
 use crate::router::scheme::route_scheme;
+use crate::router::sitemap::{
+    route_robots, route_robots_invalid, route_sitemap, route_sitemap_invalid,
+};
+use crate::router::size_hint::route_size_hint;
+use crate::router::spill::route_spill;
+use crate::router::sse::{route_sse, route_sse_invalid, route_sse_shutdown};
+use crate::router::status::{route_status_failure, route_status_success};
+use crate::router::strict_success::route_strict_success;
 use crate::router::typed_header::{
     optional::route_typed_header_optional, route_typed_header,
 };
+use crate::router::valid::{route_valid, route_valid_garde, route_valid_query};
+use crate::router::validation::{
+    route_validation, route_validation_api, route_validation_validator,
+};
 
 pub async fn route_index() -> Response {
     CreateJsonResponse::dataless().create()
@@ -47,26 +227,144 @@ pub async fn route_index() -> Response {
 pub fn create_router() -> IntoMakeServiceWithConnectInfo<Router, SocketAddr> {
     Router::new()
         .route("/", get(route_index))
+        .route("/api_result/{value}", post(route_api_result))
+        .route("/broadcast", post(route_broadcast))
+        .route("/capabilities", options(route_capabilities))
+        .route("/cbor", post(route_cbor))
+        .route("/cbor/failure", post(route_cbor_failure))
+        .route("/cbor/invalid", post(route_cbor_invalid))
+        .route("/conflict", post(route_conflict))
         .route("/connect_info", post(route_connect_info))
+        .route("/convert", post(route_convert))
+        .route("/cookie", get(route_cookie))
+        .route("/cookie/invalid", get(route_cookie_invalid))
+        .route("/cross_valid/{id}", post(route_cross_valid))
+        .route("/data_default", post(route_data_default))
         .route("/host", post(route_host))
+        .route("/failure_from", post(route_failure_from))
         .route("/form", post(route_form))
+        .route("/format", post(route_format))
+        .route("/format/failure", post(route_format_failure))
+        .route("/format/header", post(route_format_header))
+        .route("/format/error_keys", post(route_format_error_keys))
+        .route("/graphql", post(route_graphql))
+        .route("/graphql/async_graphql", post(route_graphql_async_graphql))
+        .route("/graphql/format", post(route_graphql_format))
+        .route("/header_policy", post(route_header_policy))
+        .route("/into_response/success", post(route_into_response_success))
+        .route("/into_response/failure", post(route_into_response_failure))
+        .route("/into_response/status", post(route_into_response_status))
+        .route("/job/{state}", get(route_job))
         .route("/json", post(route_json))
         .route("/json/optional", post(route_json_optional))
+        .route("/json/stream", post(route_json_stream))
+        .route("/json/stream/empty", post(route_json_stream_empty))
+        .route("/json_error/{id}", post(route_json_error))
+        .route("/json_error/direct", post(route_json_error_direct))
+        .route("/jsonapi", post(route_jsonapi))
+        .route("/jsonapi/failure", post(route_jsonapi_failure))
         .route("/matched_path", post(route_matched_path))
+        .route("/message_max_length", post(route_message_max_length))
+        .route(
+            "/message_max_length/global",
+            post(route_message_max_length_global),
+        )
+        .route("/messages", post(route_messages))
+        .route("/meta", post(route_meta))
+        .route("/meta/invalid", post(route_meta_invalid))
+        .route("/msgpack", post(route_msgpack))
+        .route("/msgpack/invalid", post(route_msgpack_invalid))
         .route("/multipart", post(route_multipart))
+        .route("/multipart/base", post(route_multipart_base))
         .route("/multipart/file", post(route_multipart_file))
+        .route("/multipart/spool", post(route_multipart_spool))
+        .route("/ndjson", post(route_ndjson))
+        .route("/ndjson/shutdown", post(route_ndjson_shutdown))
+        .route("/ndjson/stream", post(route_ndjson_stream))
+        .route("/negotiate", post(route_negotiate))
         .route("/nested_path", post(route_nested_path))
         .nest(
             "/{id}",
             Router::new().route("/nested_path", post(route_nested_path)),
         )
+        .route("/pagination", post(route_pagination))
+        .route("/pagination/meta", post(route_pagination_meta))
         .route("/path/{id}/{name}", post(route_path))
+        .route("/problem_details", post(route_problem_details))
+        .route("/problem_details/empty", post(route_problem_details_empty))
         .route("/query", post(route_query))
+        .route("/query/qs", post(route_query_qs))
+        .route("/query/strict", post(route_query_strict))
+        .route("/request_context", post(route_request_context))
+        .route("/response_data", post(route_response_data))
+        .route("/response_data/admin", post(route_response_data_admin))
+        .route("/robots.txt", get(route_robots))
+        .route("/robots/invalid", get(route_robots_invalid))
         .route("/scheme", post(route_scheme))
+        .route("/sitemap.xml", get(route_sitemap))
+        .route("/sitemap/invalid", get(route_sitemap_invalid))
+        .route("/size_hint", post(route_size_hint))
+        .route("/spill/{count}", post(route_spill))
+        .route("/sse", post(route_sse))
+        .route("/sse/invalid", post(route_sse_invalid))
+        .route("/sse/shutdown", post(route_sse_shutdown))
+        .route("/status/success", post(route_status_success))
+        .route("/status/failure", post(route_status_failure))
+        .route("/strict_success", post(route_strict_success))
         .route("/typed_header", post(route_typed_header))
         .route("/typed_header/optional", post(route_typed_header_optional))
+        .route("/valid", post(route_valid))
+        .route("/valid/garde", post(route_valid_garde))
+        .route("/valid/query", post(route_valid_query))
+        .route("/validation", post(route_validation))
+        .route("/validation/api", post(route_validation_api))
+        .route("/validation/validator", post(route_validation_validator))
+        .nest("/archive", router_archive())
+        .nest("/auth", router_auth())
+        .nest("/body_read", router_body_read())
+        .nest("/cache_control", router_cache_control())
+        .nest("/cache_ttl", router_cache_ttl())
+        .nest("/client_ip", router_client_ip())
+        .nest("/catch_panic", router_catch_panic())
+        .nest("/compression", router_compression())
+        .nest("/concurrency_limit", router_concurrency_limit())
+        .nest("/cookie_jar", router_cookie_jar())
+        .nest("/cors", router_cors())
+        .nest("/csv", router_csv())
+        .nest("/data_arc", router_data_arc())
+        .nest("/data_cache", router_data_cache())
+        .nest("/data_result", router_data_result())
+        .nest("/echo", router_echo())
+        .nest("/etag", router_etag())
+        .nest("/error_shadow", router_error_shadow())
+        .nest("/experiments", router_experiments())
+        .nest("/failure_sampling", router_failure_sampling())
+        .nest("/fallback", router_fallback())
+        .nest("/feature_flags", router_feature_flags())
+        .nest("/html_error", router_html_error())
+        .nest("/i18n", router_i18n())
+        .nest("/image", router_image())
+        .nest("/incident_id", router_incident_id())
+        .nest("/jder_handler", router_jder_handler())
+        .nest("/latency", router_latency())
+        .nest("/multipart/inspect", router_multipart_inspect())
+        .nest("/nonce", router_nonce())
+        .nest("/page_cursor", router_page_cursor())
+        .nest("/payload_sampling", router_payload_sampling())
+        .nest("/rate_limit", router_rate_limit())
+        .nest("/redirect", router_redirect())
+        .nest("/rejection_config", router_rejection_config())
         .nest("/request_body_limit", router_request_body_limit())
+        .nest("/request_id", router_request_id())
         .nest("/request_time_limit", router_request_time_limit())
+        .nest("/response_cache", router_response_cache())
+        .nest("/schema_check", router_schema_check())
+        .nest("/schema_version", router_schema_version())
+        .nest("/security_headers", router_security_headers())
+        .nest("/upstream_error", router_upstream_error())
+        .nest("/wellknown", router_wellknown())
+        .nest("/wellknown/invalid", router_wellknown_invalid())
+        .nest("/xml", router_xml())
         .layer(DefaultBodyLimit::disable())
         .into_make_service_with_connect_info::<SocketAddr>()
 }
@@ -0,0 +1,38 @@
+use jder_axum::response::{Response, msgpack::CreateMsgpackResponse};
+use serde::{Serialize, Serializer, ser::Error};
+
+#[derive(Serialize)]
+pub struct RouteMsgpackData {
+    pub id: u32,
+    pub name: String,
+    pub offset: i64,
+}
+
+#[axum::debug_handler]
+pub async fn route_msgpack() -> Response {
+    CreateMsgpackResponse::success::<RouteMsgpackData>()
+        .data(RouteMsgpackData {
+            id: 1000,
+            name: "Name".to_string(),
+            offset: -500,
+        })
+        .create()
+}
+
+pub struct RouteMsgpackInvalidData;
+
+impl Serialize for RouteMsgpackInvalidData {
+    fn serialize<S: Serializer>(
+        &self,
+        _serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Err(S::Error::custom("invalid"))
+    }
+}
+
+#[axum::debug_handler]
+pub async fn route_msgpack_invalid() -> Response {
+    CreateMsgpackResponse::success::<RouteMsgpackInvalidData>()
+        .data(RouteMsgpackInvalidData)
+        .create()
+}
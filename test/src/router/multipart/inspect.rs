@@ -0,0 +1,46 @@
+use axum::{Router, routing::post};
+use axum_typed_multipart::TryFromMultipart;
+use jder_axum::{
+    extract::multipart::{
+        ContentInspector, ContentPart, ContentRejection, InspectedMultipart,
+    },
+    response::{Response, json::CreateJsonResponse},
+};
+
+/// Inspector that rejects content containing `virus`.
+#[derive(Clone)]
+struct Scanner;
+
+impl ContentInspector for Scanner {
+    async fn inspect(
+        &self,
+        part: &mut ContentPart<'_>,
+    ) -> Result<(), ContentRejection> {
+        let mut content: Vec<u8> = Vec::new();
+
+        while let Some(chunk) = part.chunk().await {
+            content.extend_from_slice(&chunk);
+        }
+
+        if content.windows(5).any(|w| w == b"virus") {
+            return Err(ContentRejection::new().message("Virus found"));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(TryFromMultipart)]
+struct RouteMultipartInspectData {
+    file: String,
+}
+
+async fn route_multipart_inspect(
+    data: InspectedMultipart<RouteMultipartInspectData, Scanner>
+) -> Response {
+    CreateJsonResponse::success::<String>().data(data.file.clone()).create()
+}
+
+pub fn router_multipart_inspect() -> Router {
+    Router::new().route("/", post(route_multipart_inspect)).with_state(Scanner)
+}
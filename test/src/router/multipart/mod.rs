@@ -1,9 +1,14 @@
 pub mod file;
+pub mod inspect;
+pub mod spool;
 
 use axum_typed_multipart::TryFromMultipart;
 use jder_axum::{
-    extract::multipart::TypedMultipart,
-    response::{Response, json::CreateJsonResponse},
+    extract::{Multipart, multipart::TypedMultipart},
+    response::{
+        Response,
+        json::{ApiResult, CreateJsonResponse, JsonResponse},
+    },
 };
 use serde::{Deserialize, Serialize};
 
@@ -29,3 +34,16 @@ pub async fn route_multipart(
         })
         .create()
 }
+
+#[axum::debug_handler]
+pub async fn route_multipart_base(
+    mut multipart: Multipart
+) -> ApiResult<Vec<String>> {
+    let mut names: Vec<String> = Vec::new();
+
+    while let Some(field) = multipart.next_field().await? {
+        names.push(field.name().unwrap_or_default().to_string());
+    }
+
+    Ok(JsonResponse::new().data(names))
+}
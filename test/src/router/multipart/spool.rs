@@ -0,0 +1,37 @@
+use jder_axum::{
+    extract::{
+        Multipart,
+        multipart::{MultipartFile, MultipartFileOptions},
+    },
+    response::json::{ApiResult, JsonResponse},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RouteMultipartSpoolResponseData {
+    pub name: Option<String>,
+    pub size: u64,
+    pub content: String,
+}
+
+#[axum::debug_handler]
+pub async fn route_multipart_spool(
+    mut multipart: Multipart
+) -> ApiResult<Vec<RouteMultipartSpoolResponseData>> {
+    let options: MultipartFileOptions =
+        MultipartFileOptions::new().dir(std::env::temp_dir()).max_size(16);
+
+    let mut files: Vec<RouteMultipartSpoolResponseData> = Vec::new();
+
+    while let Some(field) = multipart.next_field().await? {
+        let file: MultipartFile = options.spool(field).await?;
+
+        files.push(RouteMultipartSpoolResponseData {
+            name: file.name().map(ToString::to_string),
+            size: file.size(),
+            content: tokio::fs::read_to_string(file.path()).await?,
+        });
+    }
+
+    Ok(JsonResponse::new().data(files))
+}
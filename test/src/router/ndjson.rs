@@ -0,0 +1,37 @@
+use futures_util::{StreamExt, stream};
+use jder_axum::response::{
+    Response, ndjson::CreateNdjsonResponse, shutdown::Shutdown,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct RouteNdjsonItem {
+    pub id: usize,
+}
+
+#[axum::debug_handler]
+pub async fn route_ndjson() -> Response {
+    CreateNdjsonResponse::iter((1..=3).map(|id| RouteNdjsonItem { id }))
+        .header("x-total", "3")
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_ndjson_stream() -> Response {
+    CreateNdjsonResponse::stream(stream::iter(vec!["a", "b"])).create()
+}
+
+#[axum::debug_handler]
+pub async fn route_ndjson_shutdown() -> Response {
+    let shutdown: Shutdown = Shutdown::new();
+    let trigger: Shutdown = shutdown.clone();
+
+    let items = stream::iter(vec![1, 2])
+        .chain(stream::once(async move {
+            trigger.trigger();
+            3
+        }))
+        .chain(stream::pending());
+
+    CreateNdjsonResponse::stream(items).shutdown(shutdown).create()
+}
@@ -0,0 +1,9 @@
+use axum::http::HeaderMap;
+use jder_axum::response::{Response, json::CreateJsonResponse};
+
+#[axum::debug_handler]
+pub async fn route_negotiate(headers: HeaderMap) -> Response {
+    CreateJsonResponse::success::<String>()
+        .data("Name".to_string())
+        .create_negotiated(&headers)
+}
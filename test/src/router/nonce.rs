@@ -0,0 +1,16 @@
+use axum::{Extension, Router, routing::post};
+use jder_axum::{
+    extract::nonce::{MemoryNonceStore, Nonce, NonceConfig},
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_nonce(Nonce(nonce): Nonce) -> Response {
+    CreateJsonResponse::success::<String>().data(nonce).create()
+}
+
+pub fn router_nonce() -> Router {
+    Router::new()
+        .route("/", post(route_nonce))
+        .layer(Extension(NonceConfig::new(MemoryNonceStore::new())))
+        .route("/missing_config", post(route_nonce))
+}
@@ -0,0 +1,34 @@
+use axum::{Router, extract::State, routing::post};
+use jder_axum::{
+    extract::pagination::PaginationCursor,
+    response::{
+        Response,
+        json::{
+            CreateJsonResponse,
+            pagination::{PageCursor, PageCursorKey, Pagination},
+        },
+    },
+};
+
+const PER_PAGE: u64 = 3;
+
+async fn route_page_cursor(
+    State(key): State<PageCursorKey>,
+    PaginationCursor(cursor): PaginationCursor<u64>,
+) -> Response {
+    let offset: u64 = cursor.map(|cursor| cursor.data).unwrap_or(0);
+
+    CreateJsonResponse::success::<Vec<u64>>()
+        .paginated(
+            (offset..offset + PER_PAGE).collect(),
+            Pagination::new(offset / PER_PAGE + 1, PER_PAGE)
+                .next_page_cursor(&PageCursor::new(offset + PER_PAGE), &key),
+        )
+        .create()
+}
+
+pub fn router_page_cursor() -> Router {
+    Router::new()
+        .route("/", post(route_page_cursor))
+        .with_state(PageCursorKey::new("secret"))
+}
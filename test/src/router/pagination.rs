@@ -0,0 +1,21 @@
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, pagination::Pagination},
+};
+
+#[axum::debug_handler]
+pub async fn route_pagination() -> Response {
+    CreateJsonResponse::success::<Vec<usize>>()
+        .paginated(
+            vec![1, 2, 3],
+            Pagination::new(1, 3).total(10).next_cursor("next"),
+        )
+        .create()
+}
+
+pub async fn route_pagination_meta() -> Response {
+    CreateJsonResponse::success::<Vec<usize>>()
+        .paginated(vec![1, 2, 3], Pagination::new(1, 3))
+        .meta(serde_json::json!({ "cached": true }))
+        .create()
+}
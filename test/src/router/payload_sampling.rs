@@ -0,0 +1,74 @@
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    Router,
+    extract::State,
+    routing::{get, post},
+};
+use jder_axum::{
+    layers::{PayloadSampling, payload_sampling::PayloadSample},
+    response::{Response, json::CreateJsonResponse},
+};
+
+type Samples = Arc<Mutex<Vec<PayloadSample>>>;
+
+async fn route_payload_sampling_list() -> Response {
+    CreateJsonResponse::success::<Vec<u32>>().data(vec![1, 2, 3]).create()
+}
+
+async fn route_payload_sampling_half() -> Response {
+    CreateJsonResponse::success::<String>().data("Name".to_string()).create()
+}
+
+async fn route_payload_sampling_failure() -> Response {
+    CreateJsonResponse::failure().create()
+}
+
+async fn route_payload_sampling_samples(
+    State(samples): State<Samples>
+) -> Response {
+    let samples: Vec<(String, String, usize, Option<usize>)> = samples
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|sample| {
+            (
+                sample.route.clone(),
+                sample.data_type.to_string(),
+                sample.size,
+                sample.items,
+            )
+        })
+        .collect();
+
+    CreateJsonResponse::success::<Vec<(String, String, usize, Option<usize>)>>()
+        .data(samples)
+        .create()
+}
+
+pub fn router_payload_sampling() -> Router {
+    let list_samples: Samples = Arc::new(Mutex::new(Vec::new()));
+    let half_samples: Samples = Arc::new(Mutex::new(Vec::new()));
+
+    let list_sink: Samples = list_samples.clone();
+    let half_sink: Samples = half_samples.clone();
+
+    let list: Router = Router::new()
+        .route("/list", post(route_payload_sampling_list))
+        .route("/failure", post(route_payload_sampling_failure))
+        .layer(PayloadSampling::new(1.0, move |sample: &PayloadSample| {
+            list_sink.lock().unwrap().push(sample.clone());
+        }))
+        .route("/list/samples", get(route_payload_sampling_samples))
+        .with_state(list_samples);
+
+    let half: Router = Router::new()
+        .route("/half", post(route_payload_sampling_half))
+        .layer(PayloadSampling::new(0.5, move |sample: &PayloadSample| {
+            half_sink.lock().unwrap().push(sample.clone());
+        }))
+        .route("/half/samples", get(route_payload_sampling_samples))
+        .with_state(half_samples);
+
+    list.merge(half)
+}
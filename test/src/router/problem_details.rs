@@ -0,0 +1,24 @@
+use axum::http::StatusCode;
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+};
+
+#[axum::debug_handler]
+pub async fn route_problem_details() -> Response {
+    CreateJsonResponse::failure()
+        .status(StatusCode::UNPROCESSABLE_ENTITY)
+        .add_error(
+            JsonResponseError::new()
+                .code(ResponseError::Parse.as_code())
+                .path(["body", "a/b"])
+                .message("Invalid value"),
+        )
+        .add_error(JsonResponseError::new().code("other"))
+        .problem_details()
+}
+
+#[axum::debug_handler]
+pub async fn route_problem_details_empty() -> Response {
+    CreateJsonResponse::failure().problem_details()
+}
@@ -1,5 +1,8 @@
 use jder_axum::{
-    extract::query::{Query, empty_as_none},
+    extract::{
+        QsQuery,
+        query::{Query, StrictQuery, empty_as_none},
+    },
     response::{Response, json::CreateJsonResponse},
 };
 use serde::{Deserialize, Serialize};
@@ -29,3 +32,36 @@ pub async fn route_query(Query(query): Query<RouteQueryData>) -> Response {
         })
         .create()
 }
+
+pub async fn route_query_strict(
+    StrictQuery(query): StrictQuery<RouteQueryData>
+) -> Response {
+    CreateJsonResponse::success::<RouteQueryResponseData>()
+        .data(RouteQueryResponseData {
+            num: query.num,
+            empty: query.empty,
+            none: query.none,
+        })
+        .create()
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteQueryQsFilter {
+    pub status: Option<String>,
+    #[serde(default)]
+    pub assignee: Vec<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteQueryQsData {
+    #[serde(default)]
+    pub tag: Vec<String>,
+    pub page: Option<usize>,
+    pub filter: Option<RouteQueryQsFilter>,
+}
+
+pub async fn route_query_qs(
+    QsQuery(query): QsQuery<RouteQueryQsData>
+) -> Response {
+    CreateJsonResponse::success::<RouteQueryQsData>().data(query).create()
+}
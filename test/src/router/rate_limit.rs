@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use axum::{Router, routing::post};
+use jder_axum::{
+    layers::{RateLimit, rate_limit::RateLimitQuota},
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_rate_limit() -> Response {
+    CreateJsonResponse::dataless().create()
+}
+
+pub fn router_rate_limit() -> Router {
+    Router::new()
+        .merge(Router::new().route("/", post(route_rate_limit)).layer(
+            RateLimit::header(
+                "x-api-key",
+                RateLimitQuota::new(2, Duration::from_secs(60)),
+            ),
+        ))
+        .merge(
+            Router::new().route("/reject", post(route_rate_limit)).layer(
+                RateLimit::header(
+                    "x-api-key",
+                    RateLimitQuota::new(2, Duration::from_secs(60)),
+                )
+                .fallback_quota(RateLimitQuota::new(
+                    0,
+                    Duration::from_secs(60),
+                )),
+            ),
+        )
+        .merge(Router::new().route("/client_ip", post(route_rate_limit)).layer(
+            RateLimit::client_ip(RateLimitQuota::new(
+                1,
+                Duration::from_secs(60),
+            )),
+        ))
+}
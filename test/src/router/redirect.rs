@@ -0,0 +1,32 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+use jder_axum::response::{Response, redirect::CreateRedirect};
+
+async fn route_redirect_temporary() -> Response {
+    CreateRedirect::temporary("/login").create()
+}
+
+async fn route_redirect_permanent() -> Response {
+    CreateRedirect::permanent("/new")
+        .header("x-redirect", "permanent")
+        .with_json_body()
+        .create()
+}
+
+async fn route_redirect_see_other() -> Response {
+    CreateRedirect::see_other("/orders/1").remove_cookie("draft").create()
+}
+
+async fn route_redirect_invalid() -> Response {
+    CreateRedirect::temporary("/\n").create()
+}
+
+pub fn router_redirect() -> Router {
+    Router::new()
+        .route("/temporary", get(route_redirect_temporary))
+        .route("/permanent", get(route_redirect_permanent))
+        .route("/see_other", post(route_redirect_see_other))
+        .route("/invalid", get(route_redirect_invalid))
+}
@@ -0,0 +1,55 @@
+use axum::{Extension, Router, http::StatusCode, routing::post};
+use jder_axum::{
+    extract::Json,
+    layers::JderLayer,
+    response::{
+        Response,
+        json::{
+            CreateJsonResponse, JsonResponseError, RejectionConfig,
+            ResponseError,
+        },
+    },
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct RouteRejectionConfigData {
+    pub name: String,
+}
+
+async fn route_rejection_config(
+    Json(body): Json<RouteRejectionConfigData>
+) -> Response {
+    CreateJsonResponse::success::<String>().data(body.name).create()
+}
+
+async fn route_rejection_config_handler() -> Response {
+    CreateJsonResponse::failure()
+        .add_error(
+            JsonResponseError::new()
+                .code(ResponseError::Parse.as_code())
+                .message("Handler failure"),
+        )
+        .create()
+}
+
+fn create_config_fn() -> RejectionConfig {
+    RejectionConfig::new()
+        .status(ResponseError::Parse.as_code(), StatusCode::BAD_REQUEST)
+        .code(ResponseError::Parse.as_code(), "invalid_request")
+        .message(ResponseError::Parse.as_code(), "Invalid `{path}` ({code})")
+}
+
+pub fn router_rejection_config() -> Router {
+    let extension: Router = Router::new()
+        .route("/extension", post(route_rejection_config))
+        .layer(JderLayer::new())
+        .layer(Extension(create_config_fn()));
+
+    Router::new()
+        .route("/", post(route_rejection_config))
+        .route("/handler", post(route_rejection_config_handler))
+        .layer(JderLayer::new().rejection(create_config_fn()))
+        .merge(extension)
+        .route("/missing_config", post(route_rejection_config))
+}
@@ -1,5 +1,6 @@
-use axum::{Router, routing::post};
+use axum::{Router, extract::DefaultBodyLimit, routing::post};
 use jder_axum::{
+    extract::Json,
     layers::RequestBodyLimit,
     response::{Response, json::CreateJsonResponse},
 };
@@ -8,6 +9,10 @@ async fn route_root() -> Response {
     CreateJsonResponse::dataless().create()
 }
 
+async fn route_json(Json(value): Json<String>) -> Response {
+    CreateJsonResponse::success::<usize>().data(value.len()).create()
+}
+
 pub fn router_request_body_limit() -> Router {
     Router::new()
         .merge(
@@ -20,4 +25,15 @@ pub fn router_request_body_limit() -> Router {
                 .route("/10mb", post(route_root))
                 .layer(RequestBodyLimit::max(10 * 1024 * 1024)),
         )
+        .merge(
+            Router::new()
+                .route("/json", post(route_json))
+                .layer(RequestBodyLimit::max(1024 * 1024))
+                .layer(DefaultBodyLimit::max(1024)),
+        )
+        .merge(
+            Router::new()
+                .route("/default", post(route_json))
+                .layer(DefaultBodyLimit::max(1024)),
+        )
 }
@@ -0,0 +1,22 @@
+use jder_axum::{
+    extract::RequestContext,
+    response::{Response, json::CreateJsonResponse},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RouteRequestContextResponseData {
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+    pub currency: Option<String>,
+}
+
+pub async fn route_request_context(ctx: RequestContext) -> Response {
+    CreateJsonResponse::success::<RouteRequestContextResponseData>()
+        .data(RouteRequestContextResponseData {
+            locale: ctx.locale,
+            timezone: ctx.timezone,
+            currency: ctx.currency,
+        })
+        .create()
+}
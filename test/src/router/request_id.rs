@@ -0,0 +1,27 @@
+use axum::{Router, routing::post};
+use jder_axum::{
+    extract::{Json, RequestId},
+    layers::PropagateRequestId,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_request_id(RequestId(id): RequestId) -> Response {
+    CreateJsonResponse::success::<String>().data(id).create()
+}
+
+async fn route_request_id_json(Json(value): Json<u64>) -> Response {
+    CreateJsonResponse::success::<u64>().data(value).create()
+}
+
+async fn route_request_id_custom() -> Response {
+    CreateJsonResponse::dataless().request_id("custom").create()
+}
+
+pub fn router_request_id() -> Router {
+    Router::new()
+        .route("/", post(route_request_id))
+        .route("/json", post(route_request_id_json))
+        .layer(PropagateRequestId::new())
+        .route("/custom", post(route_request_id_custom))
+        .route("/missing", post(route_request_id))
+}
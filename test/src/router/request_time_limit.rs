@@ -16,10 +16,16 @@ async fn route_timeout() -> Response {
 }
 
 pub fn router_request_time_limit() -> Router {
-    Router::new().merge(
-        Router::new()
-            .route("/ok", post(route_ok))
-            .route("/timeout", post(route_timeout))
-            .layer(RequestTimeLimit::max(Duration::from_secs(1))),
-    )
+    Router::new()
+        .merge(
+            Router::new()
+                .route("/ok", post(route_ok))
+                .route("/timeout", post(route_timeout))
+                .layer(RequestTimeLimit::max(Duration::from_secs(1))),
+        )
+        .merge(
+            Router::new()
+                .route("/from", post(route_timeout))
+                .layer(RequestTimeLimit::from(Duration::from_millis(500))),
+        )
 }
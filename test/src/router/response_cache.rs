@@ -0,0 +1,66 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use axum::{Router, extract::State, http::header, routing::get};
+use jder_axum::{
+    layers::ResponseCache,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_response_cache(
+    State(count): State<Arc<AtomicUsize>>
+) -> Response {
+    let count: usize = count.fetch_add(1, Ordering::SeqCst) + 1;
+
+    CreateJsonResponse::success::<usize>().data(count).create()
+}
+
+async fn route_response_cache_no_store(
+    State(count): State<Arc<AtomicUsize>>
+) -> Response {
+    let count: usize = count.fetch_add(1, Ordering::SeqCst) + 1;
+
+    CreateJsonResponse::success::<usize>()
+        .header(header::CACHE_CONTROL, "no-store")
+        .data(count)
+        .create()
+}
+
+fn create_router(cache: ResponseCache) -> Router {
+    Router::new()
+        .route("/", get(route_response_cache).post(route_response_cache))
+        .route("/no_store", get(route_response_cache_no_store))
+        .layer(cache)
+        .with_state(Arc::new(AtomicUsize::new(0)))
+}
+
+pub fn router_response_cache() -> Router {
+    Router::new()
+        .nest(
+            "/fresh",
+            create_router(
+                ResponseCache::new(Duration::from_secs(60))
+                    .vary(header::ACCEPT_LANGUAGE),
+            ),
+        )
+        .nest(
+            "/stale",
+            create_router(
+                ResponseCache::new(Duration::ZERO)
+                    .stale_while_revalidate(Duration::from_secs(60)),
+            ),
+        )
+        .nest("/expired", create_router(ResponseCache::new(Duration::ZERO)))
+        .nest(
+            "/credentials",
+            create_router(
+                ResponseCache::new(Duration::from_secs(60))
+                    .cache_credentials(true),
+            ),
+        )
+}
@@ -0,0 +1,45 @@
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, IntoResponseData},
+};
+use serde::{Deserialize, Serialize};
+
+pub struct RouteResponseDataUser {
+    pub id: u32,
+    pub name: String,
+}
+
+pub struct RouteResponseDataAdmin {
+    pub id: u32,
+    pub name: String,
+}
+
+fn to_display_name(name: String) -> String {
+    name.to_uppercase()
+}
+
+#[derive(Serialize, Deserialize, IntoResponseData)]
+#[response_data(from = RouteResponseDataUser, from = RouteResponseDataAdmin)]
+pub struct RouteResponseData {
+    pub id: u64,
+    #[response_data(field = name, with = to_display_name)]
+    pub display_name: String,
+    #[response_data(skip)]
+    pub tags: Vec<String>,
+}
+
+#[axum::debug_handler]
+pub async fn route_response_data() -> Response {
+    let user: RouteResponseDataUser =
+        RouteResponseDataUser { id: 1, name: "Name".to_string() };
+
+    CreateJsonResponse::respond_with::<RouteResponseData>(user).create()
+}
+
+#[axum::debug_handler]
+pub async fn route_response_data_admin() -> Response {
+    let admin: RouteResponseDataAdmin =
+        RouteResponseDataAdmin { id: 2, name: "Admin".to_string() };
+
+    CreateJsonResponse::respond_with::<RouteResponseData>(admin).create()
+}
@@ -0,0 +1,54 @@
+use axum::{Router, routing::post};
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, DataSchema},
+};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Serialize)]
+pub struct RouteSchemaCheckUser {
+    pub id: u64,
+    pub name: String,
+}
+
+#[derive(Serialize)]
+pub struct RouteSchemaCheckDrift {
+    pub id: String,
+}
+
+async fn route_schema_check() -> Response {
+    CreateJsonResponse::success()
+        .data(RouteSchemaCheckUser { id: 1, name: "jder".to_string() })
+        .create()
+}
+
+async fn route_schema_check_drift() -> Response {
+    CreateJsonResponse::success()
+        .data(RouteSchemaCheckDrift { id: "1".to_string() })
+        .create()
+}
+
+pub fn router_schema_check() -> Router {
+    DataSchema::new(json!({
+        "type": "object",
+        "properties": {
+            "id": { "type": "integer" },
+            "name": { "type": "string" },
+        },
+        "required": ["id", "name"],
+        "additionalProperties": false,
+    }))
+    .register::<RouteSchemaCheckUser>();
+
+    DataSchema::new(json!({
+        "type": "object",
+        "properties": { "id": { "type": "integer" } },
+    }))
+    .strict(false)
+    .register::<RouteSchemaCheckDrift>();
+
+    Router::new()
+        .route("/", post(route_schema_check))
+        .route("/drift", post(route_schema_check_drift))
+}
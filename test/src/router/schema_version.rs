@@ -0,0 +1,50 @@
+use axum::{Extension, Router, routing::get};
+use jder_axum::{
+    extract::{SchemaVersion, schema_version::SchemaMigrations},
+    response::{Response, json::CreateJsonResponse},
+};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Serialize)]
+struct User {
+    name: String,
+    email: String,
+}
+
+async fn route_schema_version(version: SchemaVersion) -> Response {
+    CreateJsonResponse::success()
+        .data(User {
+            name: "Name".to_string(),
+            email: "name@example.com".to_string(),
+        })
+        .schema_version(&version)
+        .create()
+}
+
+pub fn router_schema_version() -> Router {
+    Router::new()
+        .route("/", get(route_schema_version))
+        .layer(Extension(
+            SchemaMigrations::new(3)
+                // from version 3 to version 2, `email` was not exposed
+                .downgrade(2, |mut data: Value| {
+                    if let Some(data) = data.as_object_mut() {
+                        data.remove("email");
+                    }
+
+                    data
+                })
+                // from version 2 to version 1, `name` was `full_name`
+                .downgrade(1, |mut data: Value| {
+                    if let Some(data) = data.as_object_mut() {
+                        if let Some(name) = data.remove("name") {
+                            data.insert("full_name".to_string(), name);
+                        }
+                    }
+
+                    data
+                }),
+        ))
+        .route("/missing", get(route_schema_version))
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use axum::{Router, routing::get};
+use jder_axum::response::{
+    Response,
+    json::CreateJsonResponse,
+    security_headers::{SecurityHeaders, SecurityPreset},
+};
+
+async fn route_security_headers_api() -> Response {
+    CreateJsonResponse::dataless()
+        .header("x-frame-options", "SAMEORIGIN")
+        .security_headers(SecurityPreset::Api)
+        .create()
+}
+
+async fn route_security_headers_custom() -> Response {
+    CreateJsonResponse::failure()
+        .security_headers(
+            SecurityHeaders::new(SecurityPreset::Api)
+                .content_security_policy("default-src 'self'; img-src *;")
+                .hsts(Duration::from_secs(31536000), true),
+        )
+        .create()
+}
+
+async fn route_security_headers_invalid() -> Response {
+    CreateJsonResponse::dataless()
+        .security_headers(
+            SecurityHeaders::new(SecurityPreset::Api)
+                .content_security_policy("default-src 'self', img-src *"),
+        )
+        .create()
+}
+
+pub fn router_security_headers() -> Router {
+    Router::new()
+        .route("/api", get(route_security_headers_api))
+        .route("/custom", get(route_security_headers_custom))
+        .route("/invalid", get(route_security_headers_invalid))
+}
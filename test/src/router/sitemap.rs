@@ -0,0 +1,36 @@
+use jder_axum::response::{
+    Response,
+    sitemap::{CreateRobotsResponse, CreateSitemapResponse, SitemapEntry},
+};
+
+#[axum::debug_handler]
+pub async fn route_sitemap() -> Response {
+    CreateSitemapResponse::entries([
+        SitemapEntry::new("https://example.com/").priority(1.0),
+        SitemapEntry::new("https://example.com/search?q=a&b")
+            .lastmod("2026-01-01"),
+    ])
+    .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_sitemap_invalid() -> Response {
+    CreateSitemapResponse::entries(["https://example.com/", "/about"]).create()
+}
+
+#[axum::debug_handler]
+pub async fn route_robots() -> Response {
+    CreateRobotsResponse::rules()
+        .user_agent("*")
+        .allow("/")
+        .disallow("/admin")
+        .user_agent("bot")
+        .disallow("/")
+        .sitemap("https://example.com/sitemap.xml")
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_robots_invalid() -> Response {
+    CreateRobotsResponse::rules().disallow("/admin").create()
+}
@@ -0,0 +1,9 @@
+use jder_axum::response::{Response, json::CreateJsonResponse};
+
+#[axum::debug_handler]
+pub async fn route_size_hint() -> Response {
+    CreateJsonResponse::success::<Vec<usize>>()
+        .data((0..1000).collect())
+        .size_hint(0)
+        .create()
+}
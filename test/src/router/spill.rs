@@ -0,0 +1,10 @@
+use axum::extract::Path;
+use jder_axum::response::{Response, json::CreateJsonResponse};
+
+#[axum::debug_handler]
+pub async fn route_spill(Path(count): Path<u64>) -> Response {
+    CreateJsonResponse::success::<Vec<u64>>()
+        .spill_threshold(1024)
+        .data((0..count).collect())
+        .create()
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use futures_util::{StreamExt, stream};
+use jder_axum::response::{
+    Response,
+    json::JsonResponseError,
+    shutdown::Shutdown,
+    sse::{CreateSseResponse, SseEvent},
+};
+
+#[axum::debug_handler]
+pub async fn route_sse() -> Response {
+    let events: Vec<SseEvent<usize>> = vec![
+        SseEvent::success(1).id("1"),
+        SseEvent::success(2).event("last").id("2"),
+        SseEvent::failure([JsonResponseError::new().code("done")]),
+    ];
+
+    CreateSseResponse::stream(stream::iter(events))
+        .event("count")
+        .retry(Duration::from_secs(3))
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_sse_invalid() -> Response {
+    CreateSseResponse::stream(stream::iter(vec![
+        SseEvent::success(1).id("line\nbreak"),
+    ]))
+    .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_sse_shutdown() -> Response {
+    let shutdown: Shutdown = Shutdown::new();
+    let trigger: Shutdown = shutdown.clone();
+
+    let events = stream::iter(vec![SseEvent::success(1)])
+        .chain(stream::once(async move {
+            trigger.trigger();
+            SseEvent::success(2)
+        }))
+        .chain(stream::pending());
+
+    CreateSseResponse::stream(events).event("count").shutdown(shutdown).create()
+}
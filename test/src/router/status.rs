@@ -0,0 +1,14 @@
+use axum::http::StatusCode;
+use jder_axum::response::{Response, json::CreateJsonResponse};
+
+#[axum::debug_handler]
+pub async fn route_status_success() -> Response {
+    CreateJsonResponse::success_with::<String>(StatusCode::CREATED)
+        .data("Name".to_string())
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_status_failure() -> Response {
+    CreateJsonResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY).create()
+}
@@ -0,0 +1,8 @@
+use jder_axum::response::{Response, json::CreateJsonResponse};
+
+#[axum::debug_handler]
+pub async fn route_strict_success() -> Response {
+    CreateJsonResponse::strict_success::<String>()
+        .data("Name".to_string())
+        .create()
+}
@@ -0,0 +1,49 @@
+use axum::{Router, body::Body, http::StatusCode, routing::get};
+use jder_axum::{
+    layers::UpstreamError,
+    response::{Response, json::CreateJsonResponse},
+};
+
+async fn route_bad_gateway() -> Response {
+    Response::builder()
+        .status(StatusCode::BAD_GATEWAY)
+        .body(Body::empty())
+        .unwrap()
+}
+
+async fn route_unavailable() -> Response {
+    Response::builder()
+        .status(StatusCode::SERVICE_UNAVAILABLE)
+        .header("retry-after", "120")
+        .body(Body::empty())
+        .unwrap()
+}
+
+async fn route_timeout() -> Response {
+    Response::builder()
+        .status(StatusCode::GATEWAY_TIMEOUT)
+        .header("x-request-id", "upstream")
+        .body(Body::empty())
+        .unwrap()
+}
+
+async fn route_body() -> Response {
+    Response::builder()
+        .status(StatusCode::BAD_GATEWAY)
+        .body(Body::from("Bad gateway"))
+        .unwrap()
+}
+
+async fn route_failure() -> Response {
+    CreateJsonResponse::failure_with(StatusCode::SERVICE_UNAVAILABLE).create()
+}
+
+pub fn router_upstream_error() -> Router {
+    Router::new()
+        .route("/bad_gateway", get(route_bad_gateway))
+        .route("/unavailable", get(route_unavailable))
+        .route("/timeout", get(route_timeout))
+        .route("/body", get(route_body))
+        .route("/failure", get(route_failure))
+        .layer(UpstreamError::new())
+}
@@ -0,0 +1,71 @@
+use jder_axum::{
+    extract::{
+        Json, Query,
+        valid::{Valid, Validate},
+    },
+    response::{
+        Response,
+        json::{CreateJsonResponse, ValidationErrors},
+    },
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, garde::Validate)]
+pub struct RouteValidGardeItem {
+    #[garde(length(min = 1))]
+    name: String,
+}
+
+#[derive(Deserialize, garde::Validate)]
+pub struct RouteValidGardeData {
+    #[garde(length(min = 3))]
+    name: String,
+    #[garde(dive)]
+    items: Vec<RouteValidGardeItem>,
+}
+
+#[derive(Deserialize)]
+pub struct RouteValidData {
+    name: String,
+    age: u8,
+}
+
+impl Validate for RouteValidData {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors: ValidationErrors = ValidationErrors::new();
+
+        if self.name.len() < 3 {
+            errors.add(["name"], "length", "Name is too short");
+        }
+
+        if self.age < 18 {
+            errors.add(["age"], "range", "Age must be at least 18");
+        }
+
+        match errors.is_empty() {
+            | true => Ok(()),
+            | false => Err(errors),
+        }
+    }
+}
+
+#[axum::debug_handler]
+pub async fn route_valid(
+    Valid(Json(data)): Valid<Json<RouteValidData>>
+) -> Response {
+    CreateJsonResponse::success::<String>().data(data.name).create()
+}
+
+#[axum::debug_handler]
+pub async fn route_valid_query(
+    Valid(Query(data)): Valid<Query<RouteValidData>>
+) -> Response {
+    CreateJsonResponse::success::<u8>().data(data.age).create()
+}
+
+#[axum::debug_handler]
+pub async fn route_valid_garde(
+    Valid(Json(data)): Valid<Json<RouteValidGardeData>>
+) -> Response {
+    CreateJsonResponse::success::<usize>().data(data.items.len()).create()
+}
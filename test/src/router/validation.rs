@@ -0,0 +1,70 @@
+use axum::http::StatusCode;
+use jder_axum::{
+    extract::Json,
+    response::{
+        Response,
+        json::{ApiResult, CreateJsonResponse, ValidationErrors},
+    },
+};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+pub struct RouteValidationAddress {
+    #[validate(length(min = 1, message = "City is required"))]
+    city: String,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct RouteValidationItem {
+    #[validate(range(min = 1))]
+    quantity: u32,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct RouteValidationData {
+    #[validate(length(min = 3, message = "Name is too short"))]
+    name: String,
+    #[validate(nested)]
+    address: RouteValidationAddress,
+    #[validate(nested)]
+    items: Vec<RouteValidationItem>,
+}
+
+fn create_validation_errors() -> ValidationErrors {
+    let mut errors: ValidationErrors = ValidationErrors::new();
+
+    errors.add(["json", "name"], "length", "Name is too short").add(
+        ["json", "age"],
+        "",
+        "Age is required",
+    );
+
+    errors
+}
+
+#[axum::debug_handler]
+pub async fn route_validation() -> Response {
+    CreateJsonResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY)
+        .validation_errors(create_validation_errors())
+        .create()
+}
+
+#[axum::debug_handler]
+pub async fn route_validation_api() -> ApiResult {
+    Err(create_validation_errors().into())
+}
+
+#[axum::debug_handler]
+pub async fn route_validation_validator(
+    Json(data): Json<RouteValidationData>
+) -> Response {
+    match data.validate() {
+        | Ok(()) => CreateJsonResponse::dataless().create(),
+        | Err(errors) => {
+            CreateJsonResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY)
+                .validation_errors(errors)
+                .create()
+        },
+    }
+}
@@ -0,0 +1,22 @@
+use axum::Router;
+use jder_axum::wellknown::{SecurityTxt, WellKnown};
+use serde_json::json;
+
+pub fn router_wellknown() -> Router {
+    WellKnown::new()
+        .security_txt(
+            SecurityTxt::new()
+                .contact("mailto:security@example.com")
+                .expires("2027-01-01T00:00:00Z")
+                .preferred_languages(["en", "ja"]),
+        )
+        .change_password("/account/password")
+        .openid_configuration(json!({ "issuer": "https://example.com" }))
+        .router()
+}
+
+pub fn router_wellknown_invalid() -> Router {
+    WellKnown::new()
+        .security_txt(SecurityTxt::new().expires("2027-01-01T00:00:00Z"))
+        .router()
+}
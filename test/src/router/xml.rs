@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use axum::{
+    Router,
+    http::{HeaderMap, StatusCode},
+    routing::get,
+};
+use jder_axum::response::{
+    Response,
+    json::{CreateJsonResponse, JsonResponseError, ResponseError},
+    xml::CreateXmlResponse,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct RouteXmlData {
+    pub id: u32,
+    pub name: String,
+    pub tags: Vec<String>,
+    pub note: Option<String>,
+}
+
+async fn route_xml() -> Response {
+    CreateXmlResponse::success::<RouteXmlData>()
+        .data(RouteXmlData {
+            id: 1,
+            name: "<Tom & Jerry>".to_string(),
+            tags: vec!["a".to_string(), "b".to_string()],
+            note: None,
+        })
+        .create()
+}
+
+async fn route_xml_failure() -> Response {
+    CreateXmlResponse::failure_with(StatusCode::UNPROCESSABLE_ENTITY)
+        .add_error(
+            JsonResponseError::from(ResponseError::Parse)
+                .path(["json", "name"])
+                .message("Invalid name"),
+        )
+        .create()
+}
+
+async fn route_xml_invalid() -> Response {
+    let data: HashMap<String, u32> = HashMap::from([("a b".to_string(), 1)]);
+
+    CreateXmlResponse::success::<HashMap<String, u32>>().data(data).create()
+}
+
+async fn route_xml_negotiated(headers: HeaderMap) -> Response {
+    CreateJsonResponse::success::<u32>().data(1).create_negotiated(&headers)
+}
+
+pub fn router_xml() -> Router {
+    Router::new()
+        .route("/", get(route_xml))
+        .route("/failure", get(route_xml_failure))
+        .route("/invalid", get(route_xml_invalid))
+        .route("/negotiated", get(route_xml_negotiated))
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/api_result/42").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: JsonResponse<u32> = res.json::<JsonResponse<u32>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_parse() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/api_result/abc").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, "parse");
+    }
+
+    #[tokio::test]
+    async fn test_response_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/api_result/0").await;
+
+        assert_eq!(res.status_code(), StatusCode::CONFLICT);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, "conflict");
+    }
+
+    #[tokio::test]
+    async fn test_status() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/api_result/404").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, "not_found");
+    }
+
+    #[tokio::test]
+    async fn test_custom_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/api_result/423").await;
+
+        assert_eq!(res.status_code(), StatusCode::LOCKED);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, "order_locked");
+        assert_eq!(res.errors[0].message, Some("Order is locked".to_string()));
+    }
+}
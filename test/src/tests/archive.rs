@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test {
+    use std::io::Read;
+
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use flate2::read::GzDecoder;
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_zip() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/archive/zip").await;
+
+        res.assert_status_ok();
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/zip");
+        assert_eq!(
+            res.header(header::CONTENT_DISPOSITION),
+            "attachment; filename=\"export.zip\""
+        );
+
+        let body: Vec<u8> = res.into_bytes().to_vec();
+
+        // local file header
+        assert_eq!(&body[..4], b"PK\x03\x04");
+
+        // end of central directory with 2 entries
+        let end: &[u8] = &body[body.len() - 22..];
+
+        assert_eq!(&end[..4], b"PK\x05\x06");
+        assert_eq!(&end[10..12], &2u16.to_le_bytes());
+
+        // checksum of "Hello" in data descriptor
+        let descriptor: usize = 30 + "readme.txt".len() + "Hello".len();
+
+        assert_eq!(&body[descriptor..descriptor + 4], b"PK\x07\x08");
+        assert_eq!(
+            &body[descriptor + 4..descriptor + 8],
+            &0xF7D1_8982u32.to_le_bytes()
+        );
+
+        // zip64 local file header of the entry without a known size
+        let local: usize = descriptor + 16;
+
+        assert_eq!(&body[local..local + 4], b"PK\x03\x04");
+        assert_eq!(&body[local + 4..local + 6], &45u16.to_le_bytes());
+        assert_eq!(&body[local + 22..local + 26], &u32::MAX.to_le_bytes());
+        assert_eq!(&body[local + 28..local + 30], &20u16.to_le_bytes());
+
+        // zip64 data descriptor with 8 bytes of sizes
+        let descriptor: usize =
+            local + 30 + "users/1.json".len() + 20 + "{\"id\":1}".len();
+
+        assert_eq!(&body[descriptor..descriptor + 4], b"PK\x07\x08");
+        assert_eq!(&body[descriptor + 8..descriptor + 16], &8u64.to_le_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_tar_gz() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/archive/tar_gz").await;
+
+        res.assert_status_ok();
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/gzip");
+
+        let mut tar: Vec<u8> = Vec::new();
+
+        GzDecoder::new(&res.into_bytes()[..]).read_to_end(&mut tar).unwrap();
+
+        // 2 entries with a block of content each and the end of archive
+        assert_eq!(tar.len(), 512 * 6);
+
+        // sized entry
+        assert_eq!(&tar[..10], b"readme.txt");
+        assert_eq!(&tar[124..135], b"00000000005");
+        assert_eq!(&tar[257..263], b"ustar\0");
+        assert_eq!(&tar[512..517], b"Hello");
+
+        // buffered entry
+        assert_eq!(&tar[1024..1036], b"users/1.json");
+        assert_eq!(&tar[1024 + 124..1024 + 135], b"00000000010");
+        assert_eq!(&tar[1536..1544], b"{\"id\":1}");
+
+        // end of archive
+        assert!(tar[2048..].iter().all(|byte| *byte == 0));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_file_name() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/archive/invalid").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse<()> = res.json::<JsonResponse<()>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["response", "header_map"]);
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/body_read/json")
+            .add_header(header::CONTENT_TYPE, "application/json")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::BodyRead.as_code());
+    }
+
+    #[tokio::test]
+    async fn test_form() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse = server
+            .post("/body_read/form")
+            .add_header(
+                header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .await
+            .json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::BodyRead.as_code());
+    }
+
+    #[tokio::test]
+    async fn test_parse() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse = server
+            .post("/json")
+            .add_header(header::CONTENT_TYPE, "application/json")
+            .text("{")
+            .await
+            .json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+    }
+}
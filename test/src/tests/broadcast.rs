@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod test {
+    use axum_test::{TestResponse, TestServer};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/broadcast").await;
+
+        let frames: Vec<String> = res
+            .text()
+            .split("\n\n")
+            .filter(|frame| !frame.is_empty())
+            .map(|frame| frame.to_string())
+            .collect();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(
+            frames[0],
+            "data: {\"success\":true,\"data\":2,\"errors\":[]}"
+        );
+        assert!(frames[1].contains("\"code\":\"done\""));
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod test {
+    use axum::http::header;
+    use axum_test::{TestResponse, TestServer};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_success() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/cache_control/success").await;
+
+        assert_eq!(
+            res.header(header::CACHE_CONTROL),
+            "public, max-age=60, stale-while-revalidate=30"
+        );
+        assert_eq!(
+            res.headers().get_all(header::CACHE_CONTROL).iter().count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/cache_control/failure").await;
+
+        assert_eq!(res.header(header::CACHE_CONTROL), "private, no-store");
+    }
+
+    #[tokio::test]
+    async fn test_replace() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/cache_control/replace").await;
+
+        assert_eq!(
+            res.header(header::CACHE_CONTROL),
+            "public, must-revalidate, max-age=1"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/cache_ttl/1").await;
+
+        assert_eq!(
+            res.header(header::CACHE_CONTROL).to_str().unwrap(),
+            "public, max-age=60"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unregistered() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/cache_ttl").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.maybe_header(header::CACHE_CONTROL), None);
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/cache_ttl/failure").await;
+
+        assert_eq!(res.maybe_header(header::CACHE_CONTROL), None);
+    }
+
+    #[tokio::test]
+    async fn test_override() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/cache_ttl/override").await;
+
+        assert_eq!(
+            res.header(header::CACHE_CONTROL).to_str().unwrap(),
+            "no-store"
+        );
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        ACCEPT_PATCH_HEADER, ACCEPT_POST_HEADER, Capabilities, JsonResponse,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.method(Method::OPTIONS, "/capabilities").await;
+
+        assert_eq!(res.header(header::ALLOW), "GET, PATCH, POST");
+        assert_eq!(
+            res.header(ACCEPT_PATCH_HEADER),
+            "application/merge-patch+json"
+        );
+        assert_eq!(res.maybe_header(ACCEPT_POST_HEADER), None);
+
+        let res: JsonResponse<Capabilities> =
+            res.json::<JsonResponse<Capabilities>>();
+
+        let data: Capabilities = res.data.unwrap();
+
+        assert_eq!(data.methods, vec!["GET", "PATCH", "POST"]);
+        assert_eq!(data.accept_patch, vec!["application/merge-patch+json"]);
+        assert_eq!(data.accept_post.len(), 0);
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/catch_panic/ok").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_panic() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/catch_panic/panic").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+        assert_eq!(
+            res.errors[0].message,
+            Some(ResponseError::Server.to_message())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_debug() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/catch_panic/debug").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+        assert_eq!(res.errors[0].message, Some("Failed to load".to_string()));
+    }
+}
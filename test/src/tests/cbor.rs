@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/cbor").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/cbor"
+        );
+
+        let expected: &[u8] = b"\xa3gsuccess\xf5ddata\xa3bid\x19\x03\xe8dnamedNamefoffset\x39\x01\xf3ferrors\x80";
+
+        assert_eq!(res.as_bytes().as_ref(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/cbor/failure").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let expected: &[u8] = b"\xa3gsuccess\xf4ddata\xf6ferrors\x80";
+
+        assert_eq!(res.as_bytes().as_ref(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/cbor/invalid").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/cbor"
+        );
+
+        let expected: &[u8] = b"\xa3gsuccess\xf4ddata\xf6ferrors\x81\xa3dcodefserverdpath\x80gmessagevInternal server error.";
+
+        assert_eq!(res.as_bytes().as_ref(), expected);
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    async fn post_client_ip(
+        path: &str,
+        headers: &[(&'static str, &'static str)],
+    ) -> TestResponse {
+        let server: TestServer = create_server();
+
+        let mut req = server.post(&format!("/client_ip{path}"));
+
+        for (name, value) in headers {
+            req = req.add_header(*name, *value);
+        }
+
+        req.await
+    }
+
+    fn assert_client_ip(
+        res: TestResponse,
+        ip: &str,
+    ) {
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: JsonResponse<String> = res.json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data.as_deref(), Some(ip));
+    }
+
+    #[tokio::test]
+    async fn test_untrusted() {
+        let res: TestResponse =
+            post_client_ip("", &[("x-forwarded-for", "203.0.113.1")]).await;
+
+        assert_client_ip(res, "127.0.0.1");
+    }
+
+    #[tokio::test]
+    async fn test_hops() {
+        let res: TestResponse = post_client_ip(
+            "/hops",
+            &[("x-forwarded-for", "192.0.2.1, 203.0.113.1, 198.51.100.1")],
+        )
+        .await;
+
+        assert_client_ip(res, "203.0.113.1");
+    }
+
+    #[tokio::test]
+    async fn test_hops_all_trusted() {
+        let res: TestResponse =
+            post_client_ip("/hops", &[("x-forwarded-for", "203.0.113.1")])
+                .await;
+
+        assert_client_ip(res, "203.0.113.1");
+    }
+
+    #[tokio::test]
+    async fn test_proxy() {
+        let res: TestResponse = post_client_ip(
+            "/proxy",
+            &[
+                ("x-forwarded-for", "192.0.2.1, 203.0.113.1"),
+                ("x-forwarded-for", "198.51.100.7"),
+            ],
+        )
+        .await;
+
+        assert_client_ip(res, "203.0.113.1");
+    }
+
+    #[tokio::test]
+    async fn test_forwarded() {
+        let res: TestResponse = post_client_ip(
+            "/forwarded",
+            &[
+                (
+                    header::FORWARDED.as_str(),
+                    "for=\"[2001:db8::1]:8080\";proto=https, for=198.51.100.1",
+                ),
+                ("x-forwarded-for", "192.0.2.1"),
+            ],
+        )
+        .await;
+
+        assert_client_ip(res, "2001:db8::1");
+    }
+
+    #[tokio::test]
+    async fn test_real_ip() {
+        let res: TestResponse =
+            post_client_ip("/real_ip", &[("x-real-ip", "203.0.113.1")]).await;
+
+        assert_client_ip(res, "203.0.113.1");
+    }
+
+    #[tokio::test]
+    async fn test_spoofed_header() {
+        // proxy only appends `X-Forwarded-For`
+        let res: TestResponse = post_client_ip(
+            "/hops",
+            &[
+                (header::FORWARDED.as_str(), "for=192.0.2.66"),
+                ("x-real-ip", "192.0.2.66"),
+                ("x-forwarded-for", "203.0.113.1"),
+            ],
+        )
+        .await;
+
+        assert_client_ip(res, "203.0.113.1");
+
+        // proxy only sets `X-Real-IP`
+        let res: TestResponse = post_client_ip(
+            "/real_ip",
+            &[("x-forwarded-for", "192.0.2.66"), ("x-real-ip", "203.0.113.1")],
+        )
+        .await;
+
+        assert_client_ip(res, "203.0.113.1");
+    }
+
+    #[tokio::test]
+    async fn test_unknown() {
+        let res: TestResponse =
+            post_client_ip("/proxy", &[("x-forwarded-for", "unknown")]).await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse<String> = res.json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::{compression::RouteCompressionData, create_server};
+
+    async fn get_encoded(
+        server: &TestServer,
+        path: &str,
+        accept: &str,
+    ) -> TestResponse {
+        server
+            .get(path)
+            .add_header(header::ACCEPT_ENCODING, accept.to_string())
+            .await
+    }
+
+    async fn assert_round_trip(
+        server: &TestServer,
+        res: TestResponse,
+        encoding: &str,
+    ) {
+        let res: JsonResponse<usize> = server
+            .post("/compression/echo")
+            .add_header(header::CONTENT_ENCODING, encoding.to_string())
+            .content_type("application/json")
+            .bytes(res.into_bytes())
+            .await
+            .json::<JsonResponse<usize>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(100));
+    }
+
+    #[tokio::test]
+    async fn test_gzip() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            get_encoded(&server, "/compression/large", "gzip, deflate").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::CONTENT_ENCODING), "gzip");
+        assert_eq!(res.header(header::VARY), "accept-encoding");
+
+        assert_round_trip(&server, res, "gzip").await;
+    }
+
+    #[tokio::test]
+    async fn test_deflate() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            get_encoded(&server, "/compression/large", "gzip;q=0.5, deflate")
+                .await;
+
+        assert_eq!(res.header(header::CONTENT_ENCODING), "deflate");
+
+        assert_round_trip(&server, res, "deflate").await;
+    }
+
+    #[tokio::test]
+    async fn test_br() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            get_encoded(&server, "/compression/large", "gzip, br, zstd").await;
+
+        assert_eq!(res.header(header::CONTENT_ENCODING), "br");
+
+        assert_round_trip(&server, res, "br").await;
+    }
+
+    #[tokio::test]
+    async fn test_zstd() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            get_encoded(&server, "/compression/large", "br;q=0.5, zstd").await;
+
+        assert_eq!(res.header(header::CONTENT_ENCODING), "zstd");
+
+        assert_round_trip(&server, res, "zstd").await;
+    }
+
+    #[tokio::test]
+    async fn test_any() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            get_encoded(&server, "/compression/large", "*, br;q=0").await;
+
+        assert_eq!(res.header(header::CONTENT_ENCODING), "zstd");
+
+        assert_round_trip(&server, res, "zstd").await;
+    }
+
+    #[tokio::test]
+    async fn test_etag() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            get_encoded(&server, "/compression/etag", "gzip").await;
+
+        assert_eq!(res.header(header::CONTENT_ENCODING), "gzip");
+        assert_eq!(res.header(header::ETAG), "W/\"names\"");
+
+        let res: TestResponse =
+            get_encoded(&server, "/compression/etag", "identity").await;
+
+        assert_eq!(res.header(header::ETAG), "\"names\"");
+    }
+
+    #[tokio::test]
+    async fn test_identity() {
+        let server: TestServer = create_server();
+
+        for accept in ["identity", "gzip;q=0, deflate;q=0", "compress"] {
+            let res: TestResponse =
+                get_encoded(&server, "/compression/large", accept).await;
+
+            assert_eq!(res.maybe_header(header::CONTENT_ENCODING), None);
+            assert_eq!(res.header(header::VARY), "accept-encoding");
+
+            let res: JsonResponse<RouteCompressionData> =
+                res.json::<JsonResponse<RouteCompressionData>>();
+
+            assert_eq!(res.data.unwrap().names.len(), 100);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_below_threshold() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            get_encoded(&server, "/compression/small", "gzip").await;
+
+        assert_eq!(res.maybe_header(header::CONTENT_ENCODING), None);
+        assert_eq!(res.header(header::VARY), "accept-encoding");
+
+        let res: JsonResponse<RouteCompressionData> =
+            res.json::<JsonResponse<RouteCompressionData>>();
+
+        assert_eq!(res.data.unwrap().names, vec!["Name"]);
+    }
+
+    #[tokio::test]
+    async fn test_not_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            get_encoded(&server, "/compression/text", "gzip").await;
+
+        assert_eq!(res.maybe_header(header::CONTENT_ENCODING), None);
+        assert_eq!(res.maybe_header(header::VARY), None);
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    async fn post(
+        server: &TestServer,
+        key: Option<&str>,
+    ) -> TestResponse {
+        match key {
+            | Some(key) => {
+                server
+                    .post("/concurrency_limit")
+                    .add_header("x-api-key", key)
+                    .await
+            },
+            | None => server.post("/concurrency_limit").await,
+        }
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let (a, b, c, d) = tokio::join!(
+            post(&server, Some("a")),
+            post(&server, Some("a")),
+            post(&server, Some("b")),
+            post(&server, None),
+        );
+
+        let mut statuses: Vec<StatusCode> =
+            vec![a.status_code(), b.status_code()];
+
+        statuses.sort();
+
+        assert_eq!(
+            statuses,
+            vec![StatusCode::OK, StatusCode::TOO_MANY_REQUESTS]
+        );
+        assert_eq!(c.status_code(), StatusCode::OK);
+        assert_eq!(d.status_code(), StatusCode::OK);
+
+        let rejected: TestResponse = match a.status_code() {
+            | StatusCode::OK => b,
+            | _ => a,
+        };
+
+        let res: JsonResponse<()> = rejected.json::<JsonResponse<()>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(
+            res.errors[0].code,
+            ResponseError::TooManyRequests.as_code()
+        );
+
+        let usage = &res.meta.unwrap()["concurrency_limit"];
+
+        assert_eq!(usage["limit"], 1);
+        assert_eq!(usage["in_flight"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_fallback() {
+        let server: TestServer = create_server();
+
+        let (a, b) = tokio::join!(post(&server, None), post(&server, None));
+
+        let mut statuses: Vec<StatusCode> =
+            vec![a.status_code(), b.status_code()];
+
+        statuses.sort();
+
+        assert_eq!(
+            statuses,
+            vec![StatusCode::OK, StatusCode::TOO_MANY_REQUESTS]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let server: TestServer = create_server();
+
+        let (a, b) = tokio::join!(
+            server
+                .post("/concurrency_limit/stream")
+                .add_header("x-api-key", "a"),
+            async {
+                tokio::time::sleep(Duration::from_millis(30)).await;
+
+                post(&server, Some("a")).await
+            },
+        );
+
+        assert_eq!(a.status_code(), StatusCode::OK);
+        assert_eq!(a.text(), "done");
+        assert_eq!(b.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn test_released() {
+        let server: TestServer = create_server();
+
+        post(&server, Some("a")).await.assert_status_ok();
+        post(&server, Some("a")).await.assert_status_ok();
+    }
+}
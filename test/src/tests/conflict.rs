@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::conflict::RouteConflictResponseData;
+    use crate::router::create_server;
+
+    type RouteResponse = JsonResponse<RouteConflictResponseData>;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/conflict").await;
+
+        assert_eq!(res.status_code(), StatusCode::CONFLICT);
+        assert_eq!(res.header("etag").to_str().unwrap(), "\"2\"");
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, false);
+
+        let data: RouteConflictResponseData = res.data.unwrap();
+
+        assert_eq!(data.version, 2);
+        assert_eq!(data.content, "Updated");
+        assert_eq!(res.errors.len(), 1);
+        assert_eq!(res.errors[0].code, ResponseError::Conflict.as_code());
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/convert").await.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors.len(), 3);
+
+        assert_eq!(res.errors[0].code, "timeout");
+        assert_eq!(res.errors[0].message, Some("Gateway timeout".to_string()));
+
+        assert_eq!(res.errors[1].code, "parse");
+        assert!(res.errors[1].message.is_some());
+
+        assert_eq!(res.errors[2].code, "io");
+        assert_eq!(
+            res.errors[2].message,
+            Some("Input/output error".to_string())
+        );
+    }
+}
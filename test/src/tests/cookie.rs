@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    type RouteResponse = JsonResponse<String>;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/cookie")
+            .add_header(header::COOKIE, "session=abc")
+            .await;
+
+        let cookies: Vec<&str> = res
+            .headers()
+            .get_all(header::SET_COOKIE)
+            .iter()
+            .map(|value| value.to_str().unwrap())
+            .collect();
+
+        assert_eq!(cookies.len(), 3);
+        assert_eq!(cookies[0], "visited=true; Path=/; HttpOnly");
+        assert_eq!(
+            cookies[1],
+            "theme=dark; Max-Age=3600; Path=/; Secure; SameSite=Lax"
+        );
+        assert_eq!(cookies[2], "session=; Max-Age=0; Path=/");
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("abc".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_missing() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/cookie").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["cookie", "session"]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/cookie/invalid").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+        assert!(res.headers().get(header::SET_COOKIE).is_none());
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["response", "header_map"]);
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    type RouteResponse = JsonResponse<String>;
+
+    async fn get_cookie(
+        server: &TestServer,
+        path: &str,
+    ) -> String {
+        let res: TestResponse = server.get(path).await;
+
+        let cookie: String =
+            res.header(header::SET_COOKIE).to_str().unwrap().to_string();
+
+        cookie.split(';').next().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_signed() {
+        let server: TestServer = create_server();
+
+        let cookie: String =
+            get_cookie(&server, "/cookie_jar/signed/set").await;
+
+        assert!(cookie.starts_with("session="));
+        assert!(cookie.ends_with("abc"));
+
+        let res: RouteResponse = server
+            .get("/cookie_jar/signed")
+            .add_header(header::COOKIE, &cookie)
+            .await
+            .json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("abc".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_signed_tampered() {
+        let server: TestServer = create_server();
+
+        let cookie: String =
+            get_cookie(&server, "/cookie_jar/signed/set").await;
+
+        let cookie: String = cookie.replace("abc", "xyz");
+
+        let res: TestResponse = server
+            .get("/cookie_jar/signed")
+            .add_header(header::COOKIE, &cookie)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["cookie", "session"]);
+    }
+
+    #[tokio::test]
+    async fn test_private() {
+        let server: TestServer = create_server();
+
+        let cookie: String =
+            get_cookie(&server, "/cookie_jar/private/set").await;
+
+        assert!(cookie.starts_with("session="));
+        assert!(!cookie.contains("abc"));
+
+        let res: RouteResponse = server
+            .get("/cookie_jar/private")
+            .add_header(header::COOKIE, &cookie)
+            .await
+            .json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("abc".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_private_tampered() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/cookie_jar/private")
+            .add_header(header::COOKIE, "session=abc")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+    }
+}
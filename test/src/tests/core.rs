@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: jder_core::JsonResponse =
+            server.get("/").await.json::<jder_core::JsonResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.errors.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: jder_core::JsonResponse =
+            server.post("/form").await.json::<jder_core::JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(
+            res.errors[0].code,
+            jder_core::ResponseError::UnsupportedMediaType.as_code()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_convert() {
+        let res: jder_core::JsonResponse<String> =
+            jder_core::JsonResponse::new().data("Name".to_string());
+
+        let res: JsonResponse<String> = JsonResponse::from(res);
+
+        assert_eq!(res.status, None);
+        assert_eq!(res.data, Some("Name".to_string()));
+
+        let res: jder_core::JsonResponse<String> = res.into();
+
+        assert_eq!(res.data, Some("Name".to_string()));
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        layers::Cors,
+        response::json::{JsonResponse, ResponseError},
+    };
+
+    use crate::router::create_server;
+
+    const ORIGIN: &str = "https://example.com";
+
+    async fn preflight(
+        server: &TestServer,
+        origin: &str,
+        method: &str,
+        headers: &str,
+    ) -> TestResponse {
+        server
+            .method(Method::OPTIONS, "/cors")
+            .add_header(header::ORIGIN, origin)
+            .add_header(header::ACCESS_CONTROL_REQUEST_METHOD, method)
+            .add_header(header::ACCESS_CONTROL_REQUEST_HEADERS, headers)
+            .await
+    }
+
+    fn assert_forbidden(
+        res: TestResponse,
+        name: &str,
+    ) {
+        assert_eq!(res.status_code(), StatusCode::FORBIDDEN);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Forbidden.as_code());
+        assert_eq!(res.errors[0].path, ["header", name]);
+    }
+
+    #[tokio::test]
+    async fn test_preflight() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            preflight(&server, ORIGIN, "POST", "content-type, authorization")
+                .await;
+
+        assert_eq!(res.status_code(), StatusCode::NO_CONTENT);
+        assert_eq!(res.header(header::ACCESS_CONTROL_ALLOW_ORIGIN), ORIGIN);
+        assert_eq!(
+            res.header(header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            "true"
+        );
+        assert_eq!(
+            res.header(header::ACCESS_CONTROL_ALLOW_METHODS),
+            "GET, HEAD, POST, PUT, PATCH, DELETE"
+        );
+        assert_eq!(
+            res.header(header::ACCESS_CONTROL_ALLOW_HEADERS),
+            "content-type, authorization"
+        );
+        assert_eq!(res.header(header::ACCESS_CONTROL_MAX_AGE), "600");
+        assert_eq!(res.header(header::VARY), "origin");
+    }
+
+    #[tokio::test]
+    async fn test_preflight_rejected() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            preflight(&server, "https://other.com", "POST", "").await;
+
+        assert_forbidden(res, "origin");
+
+        let res: TestResponse = preflight(&server, ORIGIN, "TRACE", "").await;
+
+        assert_forbidden(res, "access-control-request-method");
+
+        let res: TestResponse =
+            preflight(&server, ORIGIN, "POST", "x-unknown").await;
+
+        assert_forbidden(res, "access-control-request-headers");
+    }
+
+    #[tokio::test]
+    async fn test_actual() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/cors").add_header(header::ORIGIN, ORIGIN).await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::ACCESS_CONTROL_ALLOW_ORIGIN), ORIGIN);
+        assert_eq!(
+            res.header(header::ACCESS_CONTROL_EXPOSE_HEADERS),
+            "retry-after, x-request-id, etag"
+        );
+
+        let res: TestResponse = server
+            .post("/cors")
+            .add_header(header::ORIGIN, "https://other.com")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.maybe_header(header::ACCESS_CONTROL_ALLOW_ORIGIN), None);
+        assert_eq!(res.header(header::VARY), "origin");
+
+        let res: TestResponse = server.post("/cors").await;
+
+        assert_eq!(res.maybe_header(header::ACCESS_CONTROL_ALLOW_ORIGIN), None);
+        assert_eq!(res.header(header::VARY), "origin");
+    }
+
+    #[tokio::test]
+    async fn test_preflight_rejected_vary() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            preflight(&server, "https://other.com", "POST", "").await;
+
+        assert_eq!(res.status_code(), StatusCode::FORBIDDEN);
+        assert_eq!(res.header(header::VARY), "origin");
+    }
+
+    #[test]
+    #[should_panic(expected = "credentials cannot be allowed with any origin")]
+    fn test_any_origin_credentials() {
+        Cors::new().allow_any_origin().allow_credentials(true);
+    }
+
+    #[test]
+    #[should_panic(expected = "credentials cannot be allowed with any origin")]
+    fn test_credentials_any_origin() {
+        Cors::new().allow_credentials(true).allow_any_origin();
+    }
+
+    #[tokio::test]
+    async fn test_any_origin() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/cors/any")
+            .add_header(header::ORIGIN, "https://other.com")
+            .await;
+
+        assert_eq!(res.header(header::ACCESS_CONTROL_ALLOW_ORIGIN), "*");
+        assert_eq!(
+            res.maybe_header(header::ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            None
+        );
+    }
+}
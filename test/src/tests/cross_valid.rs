@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+    use serde_json::json;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> = server
+            .post("/cross_valid/1")
+            .json(&json!({ "id": 1, "name": "Name" }))
+            .await
+            .json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("1:Name".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_mismatch() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/cross_valid/1")
+            .json(&json!({ "id": 2, "name": "Name" }))
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors.len(), 2);
+
+        assert_eq!(res.errors[0].code, "mismatch");
+        assert_eq!(res.errors[0].path, vec!["path", "id"]);
+
+        assert_eq!(res.errors[1].code, "mismatch");
+        assert_eq!(res.errors[1].path, vec!["json", "id"]);
+    }
+
+    #[tokio::test]
+    async fn test_path_rejection() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/cross_valid/abc")
+            .json(&json!({ "id": 1, "name": "Name" }))
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/csv").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "text/csv; charset=utf-8"
+        );
+        assert_eq!(
+            res.header(header::CONTENT_DISPOSITION).to_str().unwrap(),
+            "attachment; filename=\"users.csv\""
+        );
+        assert_eq!(
+            res.text(),
+            "name,id,note\r\nAlice,1,\r\nBob,2,\"says \"\"hi\"\", twice\"\r\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tuple() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/csv/tuple").await;
+
+        assert_eq!(res.text(), "1,a\r\n2,\"b\nc\"\r\n");
+    }
+
+    #[tokio::test]
+    async fn test_map() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/csv/map").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_empty() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/csv/empty").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.text(), "");
+    }
+
+    #[tokio::test]
+    async fn test_broken() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/csv/broken").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body: Value = res.json::<Value>();
+
+        assert_eq!(body["success"], false);
+        assert_eq!(body["errors"][0]["code"], "server");
+        assert_eq!(body["errors"][0]["path"][0], "csv");
+    }
+
+    #[tokio::test]
+    async fn test_nested() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/csv/nested").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_file_name() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/csv/file_name").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+    }
+}
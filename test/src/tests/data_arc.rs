@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::{create_server, data_arc::RouteDataArcData};
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        for _ in 0..2 {
+            let res: JsonResponse<RouteDataArcData> = server
+                .post("/data_arc")
+                .await
+                .json::<JsonResponse<RouteDataArcData>>();
+
+            assert_eq!(res.success, true);
+            assert_eq!(res.data.unwrap().names, vec!["a", "b"]);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::{create_server, data_cache::RouteDataCacheData};
+
+    #[tokio::test]
+    async fn test_single_flight() {
+        let server: TestServer = create_server();
+
+        let (a, b) = tokio::join!(
+            server.post("/data_cache/concurrent"),
+            server.post("/data_cache/concurrent"),
+        );
+
+        let a: JsonResponse<RouteDataCacheData> =
+            a.json::<JsonResponse<RouteDataCacheData>>();
+        let b: JsonResponse<RouteDataCacheData> =
+            b.json::<JsonResponse<RouteDataCacheData>>();
+
+        assert_eq!(a.data.unwrap().computed, b.data.unwrap().computed);
+    }
+
+    #[tokio::test]
+    async fn test_cached() {
+        let server: TestServer = create_server();
+
+        let first: RouteDataCacheData = server
+            .post("/data_cache/cached")
+            .await
+            .json::<JsonResponse<RouteDataCacheData>>()
+            .data
+            .unwrap();
+
+        let second: RouteDataCacheData = server
+            .post("/data_cache/cached")
+            .await
+            .json::<JsonResponse<RouteDataCacheData>>()
+            .data
+            .unwrap();
+
+        assert_eq!(first.key, "cached");
+        assert_eq!(first.computed, second.computed);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate() {
+        let server: TestServer = create_server();
+
+        let first: RouteDataCacheData = server
+            .post("/data_cache/invalidated")
+            .await
+            .json::<JsonResponse<RouteDataCacheData>>()
+            .data
+            .unwrap();
+
+        server.post("/data_cache/invalidated/invalidate").await;
+
+        let second: RouteDataCacheData = server
+            .post("/data_cache/invalidated")
+            .await
+            .json::<JsonResponse<RouteDataCacheData>>()
+            .data
+            .unwrap();
+
+        assert_ne!(first.computed, second.computed);
+    }
+}
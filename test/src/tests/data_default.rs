@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::{create_server, data_default::RouteDataDefaultData};
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<RouteDataDefaultData> = server
+            .post("/data_default")
+            .await
+            .json::<JsonResponse<RouteDataDefaultData>>();
+
+        assert_eq!(res.success, true);
+
+        let data: RouteDataDefaultData = res.data.unwrap();
+
+        assert_eq!(data.names.len(), 0);
+        assert_eq!(data.count, 0);
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> =
+            server.post("/data_result/1").await.json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("Name".to_string()));
+        assert_eq!(res.errors.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_err() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/data_result/2").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse<String> = res.json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.data, None);
+        assert_eq!(res.errors[0].code, "not_found");
+        assert_eq!(res.errors[0].message, Some("Name not found".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_with() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/data_result/with/1").await;
+
+        assert_eq!(res.status_code(), StatusCode::CREATED);
+
+        let res: TestResponse = server.post("/data_result/with/2").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let res: JsonResponse<String> = res.json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, "not_found");
+    }
+}
@@ -0,0 +1,306 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use bytes::Bytes;
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+    use crate::router::form::RouteFormResponseData;
+    use crate::router::json::RouteJsonResponseData;
+
+    const GZIP: [u8; 41] = [
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 171, 86, 202, 76, 81, 178, 50, 52, 50,
+        214, 81, 202, 75, 204, 77, 85, 178, 82, 242, 3, 81, 181, 0, 235, 208,
+        202, 184, 24, 0, 0, 0,
+    ];
+
+    const DEFLATE: [u8; 29] = [
+        120, 156, 171, 86, 202, 76, 81, 178, 50, 52, 50, 214, 81, 202, 75, 204,
+        77, 85, 178, 82, 242, 3, 81, 181, 0, 83, 82, 6, 234,
+    ];
+
+    const RAW_DEFLATE: [u8; 23] = [
+        171, 86, 202, 76, 81, 178, 50, 52, 50, 214, 81, 202, 75, 204, 77, 85,
+        178, 82, 242, 3, 81, 181, 0,
+    ];
+
+    const GZIP_DYNAMIC: [u8; 89] = [
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 45, 138, 177, 13, 128, 64, 12, 3, 119,
+        73, 77, 143, 244, 219, 216, 49, 5, 5, 44, 128, 216, 29, 251, 159, 20,
+        142, 117, 190, 167, 78, 213, 216, 183, 186, 113, 29, 53, 10, 236, 110,
+        248, 28, 196, 108, 74, 53, 100, 158, 83, 129, 204, 15, 89, 22, 241,
+        235, 30, 91, 84, 22, 78, 46, 146, 54, 91, 83, 17, 154, 168, 247, 3, 35,
+        94, 124, 209, 118, 0, 0, 0,
+    ];
+
+    const FORM_GZIP: [u8; 32] = [
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 203, 76, 177, 53, 84, 203, 75, 204,
+        77, 181, 245, 3, 18, 0, 11, 82, 7, 160, 14, 0, 0, 0,
+    ];
+
+    const GZIP_BOMB: [u8; 161] = [
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 237, 218, 33, 14, 130, 0, 24, 134,
+        225, 95, 212, 141, 98, 114, 179, 57, 177, 178, 65, 241, 0, 86, 131,
+        179, 153, 25, 21, 139, 73, 25, 20, 18, 197, 32, 7, 48, 192, 102, 112,
+        22, 179, 179, 64, 243, 12, 58, 154, 81, 198, 17, 112, 120, 11, 216,
+        251, 164, 247, 12, 223, 190, 201, 65, 149, 154, 210, 253, 102, 201, 32,
+        213, 164, 151, 174, 159, 155, 165, 241, 142, 130, 139, 228, 103, 123,
+        63, 186, 63, 230, 229, 194, 119, 118, 214, 204, 176, 86, 29, 1, 0, 0,
+        0, 0, 0, 45, 102, 250, 91, 237, 31, 159, 234, 165, 94, 1, 0, 0, 0, 0,
+        64, 219, 233, 69, 54, 173, 175, 0, 65, 92, 221, 198, 118, 159, 113, 4,
+        104, 170, 80, 78, 71, 207, 29, 74, 36, 63, 6, 172, 115, 245, 13, 36, 0,
+        0,
+    ];
+
+    const GZIP_CORRUPT: [u8; 41] = [
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 171, 86, 202, 76, 81, 178, 50, 52, 50,
+        214, 81, 202, 75, 204, 77, 85, 178, 82, 242, 3, 81, 181, 0, 234, 208,
+        202, 184, 24, 0, 0, 0,
+    ];
+
+    const BR: [u8; 28] = [
+        139, 11, 128, 123, 34, 105, 100, 34, 58, 49, 50, 51, 44, 34, 110, 97,
+        109, 101, 34, 58, 34, 78, 97, 109, 101, 34, 125, 3,
+    ];
+
+    const ZSTD: [u8; 37] = [
+        40, 181, 47, 253, 36, 24, 193, 0, 0, 123, 34, 105, 100, 34, 58, 49, 50,
+        51, 44, 34, 110, 97, 109, 101, 34, 58, 34, 78, 97, 109, 101, 34, 125,
+        203, 165, 37, 104,
+    ];
+
+    /// JSON string of 4096 `a`, larger than the body limit of the route.
+    const GZIP_LARGE: [u8; 41] = [
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 237, 193, 129, 12, 0, 0, 0, 2, 48,
+        151, 204, 242, 151, 200, 163, 253, 79, 1, 0, 0, 128, 123, 25, 147, 119,
+        12, 227, 2, 16, 0, 0,
+    ];
+
+    /// Multipart body with a `name` field and boundary `X`,
+    /// gzipped with a corrupt checksum.
+    const MULTIPART_GZIP_CORRUPT: [u8; 83] = [
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 211, 213, 141, 224, 229, 114, 206,
+        207, 43, 73, 205, 43, 209, 117, 201, 44, 46, 200, 47, 206, 44, 201,
+        204, 207, 179, 82, 72, 203, 47, 202, 213, 77, 73, 44, 73, 180, 86, 200,
+        75, 204, 77, 181, 85, 2, 145, 74, 188, 92, 188, 92, 101, 137, 57, 165,
+        169, 188, 92, 186, 186, 17, 186, 186, 188, 92, 0, 78, 96, 235, 174, 66,
+        0, 0, 0,
+    ];
+
+    /// Create a raw deflate stream of stored blocks.
+    fn create_stored_deflate(len: usize) -> Vec<u8> {
+        let mut body: Vec<u8> = Vec::new();
+        let mut remaining: usize = len;
+
+        while remaining > 0 {
+            let size: u16 = remaining.min(u16::MAX as usize) as u16;
+
+            remaining -= size as usize;
+
+            body.push(u8::from(remaining == 0));
+            body.extend_from_slice(&size.to_le_bytes());
+            body.extend_from_slice(&(!size).to_le_bytes());
+            body.extend(std::iter::repeat_n(b' ', size as usize));
+        }
+
+        body
+    }
+
+    async fn post_encoded(
+        path: &str,
+        encoding: &str,
+        content_type: &str,
+        body: &[u8],
+    ) -> TestResponse {
+        let server: TestServer = create_server();
+
+        server
+            .post(path)
+            .add_header(header::CONTENT_ENCODING, encoding.to_string())
+            .content_type(content_type)
+            .bytes(Bytes::copy_from_slice(body))
+            .await
+    }
+
+    fn assert_json_data(
+        res: TestResponse,
+        id: usize,
+        name: &str,
+    ) {
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: JsonResponse<RouteJsonResponseData> =
+            res.json::<JsonResponse<RouteJsonResponseData>>();
+
+        assert_eq!(res.success, true);
+
+        let data: RouteJsonResponseData = res.data.unwrap();
+
+        assert_eq!(data.id, Some(id));
+        assert_eq!(data.name.as_deref(), Some(name));
+    }
+
+    fn assert_encoding_error(
+        res: TestResponse,
+        status: StatusCode,
+    ) {
+        assert_eq!(res.status_code(), status);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Encoding.as_code());
+        assert_eq!(res.errors[0].path, vec!["header", "content-encoding"]);
+    }
+
+    #[tokio::test]
+    async fn test_gzip() {
+        let res: TestResponse =
+            post_encoded("/json", "gzip", "application/json", &GZIP).await;
+
+        assert_json_data(res, 123, "Name");
+    }
+
+    #[tokio::test]
+    async fn test_gzip_dynamic() {
+        let res: TestResponse =
+            post_encoded("/json", "x-gzip", "application/json", &GZIP_DYNAMIC)
+                .await;
+
+        assert_json_data(
+            res,
+            7,
+            "abcccaaaacaabacaaaadcaabccabaabcabadaaaabbadabaababacaabaaabacaadaacdbdbaabbcaabadbbbdabcdbaaabdacba",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deflate() {
+        let res: TestResponse =
+            post_encoded("/json", "deflate", "application/json", &DEFLATE)
+                .await;
+
+        assert_json_data(res, 123, "Name");
+    }
+
+    #[tokio::test]
+    async fn test_raw_deflate() {
+        let res: TestResponse =
+            post_encoded("/json", "Deflate", "application/json", &RAW_DEFLATE)
+                .await;
+
+        assert_json_data(res, 123, "Name");
+    }
+
+    #[tokio::test]
+    async fn test_form() {
+        let res: TestResponse = post_encoded(
+            "/form",
+            "gzip",
+            "application/x-www-form-urlencoded",
+            &FORM_GZIP,
+        )
+        .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: JsonResponse<RouteFormResponseData> =
+            res.json::<JsonResponse<RouteFormResponseData>>();
+
+        let data: RouteFormResponseData = res.data.unwrap();
+
+        assert_eq!(data.id, Some(1));
+        assert_eq!(data.name, Some("Name".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_unsupported() {
+        let res: TestResponse =
+            post_encoded("/json", "compress", "application/json", &GZIP).await;
+
+        assert_encoding_error(res, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn test_br() {
+        let res: TestResponse =
+            post_encoded("/json", "br", "application/json", &BR).await;
+
+        assert_json_data(res, 123, "Name");
+    }
+
+    #[tokio::test]
+    async fn test_zstd() {
+        let res: TestResponse =
+            post_encoded("/json", "zstd", "application/json", &ZSTD).await;
+
+        assert_json_data(res, 123, "Name");
+    }
+
+    #[tokio::test]
+    async fn test_over_default_body_limit() {
+        let res: TestResponse = post_encoded(
+            "/request_body_limit/default",
+            "gzip",
+            "application/json",
+            &GZIP_LARGE,
+        )
+        .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: JsonResponse<usize> = res.json::<JsonResponse<usize>>();
+
+        assert_eq!(res.data, Some(4096));
+    }
+
+    #[tokio::test]
+    async fn test_encoded_too_large() {
+        let res: TestResponse = post_encoded(
+            "/request_body_limit/default",
+            "deflate",
+            "application/json",
+            &create_stored_deflate(2048),
+        )
+        .await;
+
+        assert_eq!(res.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::TooLarge.as_code());
+    }
+
+    #[tokio::test]
+    async fn test_multipart_corrupt() {
+        let res: TestResponse = post_encoded(
+            "/multipart/base",
+            "gzip",
+            "multipart/form-data; boundary=X",
+            &MULTIPART_GZIP_CORRUPT,
+        )
+        .await;
+
+        assert_encoding_error(res, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_corrupt() {
+        let res: TestResponse =
+            post_encoded("/json", "gzip", "application/json", &GZIP_CORRUPT)
+                .await;
+
+        assert_encoding_error(res, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_bomb() {
+        let res: TestResponse =
+            post_encoded("/json", "gzip, gzip", "application/json", &GZIP_BOMB)
+                .await;
+
+        assert_encoding_error(res, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        handler::{ECHO_REDACTED_VALUE, EchoData},
+        response::json::{JsonResponse, ResponseError},
+    };
+    use serde_json::json;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_json() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<EchoData> = server
+            .post("/echo?page=1")
+            .add_header(header::AUTHORIZATION, "Bearer token")
+            .add_header("x-client", "test")
+            .json(&json!({ "name": "Name" }))
+            .await
+            .json::<JsonResponse<EchoData>>();
+
+        assert_eq!(res.success, true);
+
+        let data: EchoData = res.data.unwrap();
+
+        assert_eq!(data.method, "POST");
+        assert_eq!(data.path, "/echo");
+        assert_eq!(data.headers["authorization"], ECHO_REDACTED_VALUE);
+        assert_eq!(data.headers["x-client"], "test");
+        assert_eq!(data.query["page"], "1");
+        assert_eq!(data.body, json!({ "name": "Name" }));
+    }
+
+    #[tokio::test]
+    async fn test_form() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<EchoData> = server
+            .put("/echo")
+            .form(&[("id", "1"), ("name", "Name")])
+            .await
+            .json::<JsonResponse<EchoData>>();
+
+        let data: EchoData = res.data.unwrap();
+
+        assert_eq!(data.method, "PUT");
+        assert_eq!(data.body, json!({ "id": "1", "name": "Name" }));
+    }
+
+    #[tokio::test]
+    async fn test_text() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<EchoData> = server
+            .get("/echo")
+            .text("Hello")
+            .await
+            .json::<JsonResponse<EchoData>>();
+
+        let data: EchoData = res.data.unwrap();
+
+        assert_eq!(data.method, "GET");
+        assert_eq!(data.query.len(), 0);
+        assert_eq!(data.body, json!("Hello"));
+    }
+
+    #[tokio::test]
+    async fn test_json_rejection() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/echo")
+            .text("{")
+            .content_type("application/json")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+    }
+}
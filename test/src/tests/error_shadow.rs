@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: Value = server.post("/error_shadow/ok").await.json::<Value>();
+
+        assert_eq!(res["success"], true);
+        assert_eq!(res.get("legacy"), None);
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: Value =
+            server.post("/error_shadow/failure").await.json::<Value>();
+
+        assert_eq!(res["success"], false);
+        assert_eq!(res["errors"][0]["code"], "parse");
+        assert_eq!(res["legacy"]["status"], 400);
+        assert_eq!(res["legacy"]["error"], "Invalid");
+    }
+
+    #[tokio::test]
+    async fn test_failure_error_keys() {
+        let server: TestServer = create_server();
+
+        let res: Value = server
+            .post("/error_shadow/failure/error_keys")
+            .await
+            .json::<Value>();
+
+        assert_eq!(res["issues"][0]["code"], "parse");
+        assert_eq!(res["legacy"]["status"], 400);
+        assert_eq!(res["legacy"]["error"], "Invalid");
+    }
+
+    #[tokio::test]
+    async fn test_disabled() {
+        let server: TestServer = create_server();
+
+        let res: Value =
+            server.post("/error_shadow/disabled").await.json::<Value>();
+
+        assert_eq!(res["success"], false);
+        assert_eq!(res.get("legacy"), None);
+    }
+}
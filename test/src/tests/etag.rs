@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_auto() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/etag/auto").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let etag: String =
+            res.header(header::ETAG).to_str().unwrap().to_string();
+
+        assert_eq!(etag.len(), 42);
+        assert!(etag.starts_with('"') && etag.ends_with('"'));
+
+        let res: TestResponse = server
+            .get("/etag/auto")
+            .add_header(header::IF_NONE_MATCH, &etag)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.text(), "");
+        assert_eq!(res.header(header::ETAG), etag.as_str());
+        assert_eq!(res.header(header::CACHE_CONTROL), "no-cache");
+        assert!(res.maybe_header(header::CONTENT_TYPE).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_value() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/etag/value").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::ETAG), "\"v1\"");
+
+        let res: TestResponse = server
+            .get("/etag/value")
+            .add_header(header::IF_NONE_MATCH, "\"v0\", \"v1\"")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.text(), "");
+    }
+
+    #[tokio::test]
+    async fn test_not_matched() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/etag/value")
+            .add_header(header::IF_NONE_MATCH, "\"v0\"")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::ETAG), "\"v1\"");
+        assert_eq!(res.text(), r#"{"success":true,"data":"Name","errors":[]}"#);
+    }
+
+    #[tokio::test]
+    async fn test_weak() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/etag/value")
+            .add_header(header::IF_NONE_MATCH, "W/\"v1\"")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_MODIFIED);
+
+        let res: TestResponse = server
+            .get("/etag/value")
+            .add_header(header::IF_NONE_MATCH, "*")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/etag/failure")
+            .add_header(header::IF_NONE_MATCH, "\"v1\"")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+        assert!(res.maybe_header(header::ETAG).is_none());
+    }
+}
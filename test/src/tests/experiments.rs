@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<bool> = server
+            .post("/experiments")
+            .add_header("x-beta", "1")
+            .await
+            .json::<JsonResponse<bool>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(true));
+        assert_eq!(
+            Value::Object(res.meta.unwrap())["experiments"],
+            json!({ "checkout": "b" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<bool> =
+            server.post("/experiments").await.json::<JsonResponse<bool>>();
+
+        assert_eq!(res.data, Some(false));
+        assert_eq!(
+            Value::Object(res.meta.unwrap())["experiments"],
+            json!({ "checkout": "a" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_layer() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/experiments/missing").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+    }
+}
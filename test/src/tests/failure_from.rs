@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/failure_from").await;
+
+        assert_eq!(res.status_code(), StatusCode::FORBIDDEN);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors.len(), 1);
+        assert_eq!(res.errors[0].code, ResponseError::Forbidden.as_code());
+        assert_eq!(
+            res.errors[0].message,
+            Some(ResponseError::Forbidden.to_message())
+        );
+    }
+
+    #[test]
+    fn test_as_status() {
+        assert_eq!(
+            ResponseError::Forbidden.as_status(),
+            Some(StatusCode::FORBIDDEN)
+        );
+        assert_eq!(
+            ResponseError::NotFound.as_status(),
+            Some(StatusCode::NOT_FOUND)
+        );
+        assert_eq!(ResponseError::Unknown.as_status(), None);
+    }
+}
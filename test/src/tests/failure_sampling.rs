@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        for _ in 0..7 {
+            let res: TestResponse = server.post("/failure_sampling/1").await;
+
+            // responses are not affected by sampling
+            assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+            let res: JsonResponse = res.json::<JsonResponse>();
+
+            assert_eq!(res.errors[0].code, "bad");
+        }
+
+        let res: JsonResponse<Vec<u64>> = server
+            .get("/failure_sampling/samples")
+            .await
+            .json::<JsonResponse<Vec<u64>>>();
+
+        assert_eq!(res.data, Some(vec![1, 4, 7]));
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/fallback").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/fallback/missing").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::NotFound.as_code());
+    }
+
+    #[tokio::test]
+    async fn test_method_not_allowed() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.delete("/fallback").await;
+
+        assert_eq!(res.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(
+            res.errors[0].code,
+            ResponseError::MethodNotAllowed.as_code()
+        );
+    }
+}
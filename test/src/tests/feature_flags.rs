@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<bool> = server
+            .post("/feature_flags")
+            .add_header("x-feature-flags", "new_checkout, unknown,dark_mode")
+            .await
+            .json::<JsonResponse<bool>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(true));
+        assert_eq!(
+            Value::Object(res.meta.unwrap())["feature_flags"],
+            json!(["dark_mode", "new_checkout"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_empty() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<bool> =
+            server.post("/feature_flags").await.json::<JsonResponse<bool>>();
+
+        assert_eq!(res.data, Some(false));
+        assert_eq!(
+            Value::Object(res.meta.unwrap())["feature_flags"],
+            json!([])
+        );
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
-    use axum_test::TestServer;
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
     use jder_axum::response::json::{JsonResponse, ResponseError};
 
     use crate::router::create_server;
@@ -71,7 +72,45 @@ mod test {
         let res: RouteResponse =
             server.post("/form").await.json::<RouteResponse>();
 
+        // no content type
         assert_eq!(res.success, false);
+        assert_eq!(
+            res.errors[0].code,
+            ResponseError::UnsupportedMediaType.as_code()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_media_type() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/form").text("id=1").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(
+            res.errors[0].code,
+            ResponseError::UnsupportedMediaType.as_code()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_id() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/form")
+            .text("id=abc")
+            .content_type("application/x-www-form-urlencoded")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
         assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["form", "id"]);
     }
 }
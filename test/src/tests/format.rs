@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: Value = server.post("/format").await.json::<Value>();
+
+        assert_eq!(res, json!({ "ok": true, "result": "Name" }));
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/format/failure").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: Value = res.json::<Value>();
+
+        assert_eq!(res["ok"], false);
+        assert_eq!(res["error"]["code"], "custom");
+        assert_eq!(res.get("errors"), None);
+    }
+
+    #[tokio::test]
+    async fn test_header() {
+        let server: TestServer = create_server();
+
+        let res: Value = server.post("/format/header").await.json::<Value>();
+
+        assert_eq!(res["ok"], false);
+        assert_eq!(res["error"]["path"], json!(["response", "header_map"]));
+    }
+
+    #[tokio::test]
+    async fn test_error_keys() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/format/error_keys").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: Value = res.json::<Value>();
+
+        assert_eq!(
+            res,
+            json!({
+                "success": false,
+                "data": null,
+                "issues": [
+                    {
+                        "code": "custom",
+                        "pointer": ["json", "name"],
+                        "detail": "Invalid name"
+                    }
+                ]
+            })
+        );
+    }
+}
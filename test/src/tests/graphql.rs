@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/graphql").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.data, Some(json!({ "user": null })));
+        assert_eq!(res.errors.len(), 2);
+        assert_eq!(res.errors[0].code, "not_found");
+        assert_eq!(res.errors[0].path, vec!["graphql", "user", "0"]);
+        assert_eq!(res.errors[0].message, Some("User not found".to_string()));
+        assert_eq!(res.errors[1].code, ResponseError::Unknown.as_code());
+        assert_eq!(res.errors[1].path, vec!["graphql", "4:5"]);
+        assert_eq!(res.meta.unwrap()["cost"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_async_graphql() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/graphql/async_graphql").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(
+            res.data,
+            Some(json!({
+                "users": [{ "id": 1, "name": "Name" }, null],
+            }))
+        );
+        assert_eq!(res.errors.len(), 1);
+        assert_eq!(res.errors[0].code, "forbidden");
+        assert_eq!(res.errors[0].path, vec!["graphql", "users", "1", "name"]);
+        assert_eq!(res.errors[0].message, Some("User is hidden".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_format() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/graphql/format").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header("x-graphql").to_str().unwrap(), "1");
+        assert_eq!(
+            res.json::<Value>(),
+            json!({
+                "data": null,
+                "errors": [
+                    {
+                        "message": "User not found",
+                        "path": ["user", 0],
+                        "extensions": { "code": "not_found" },
+                    },
+                    {
+                        "message": "Unknown field",
+                        "locations": [{ "line": 4, "column": 5 }],
+                        "extensions": { "code": "unknown" },
+                    },
+                ],
+            })
+        );
+    }
+}
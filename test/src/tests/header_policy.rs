@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        HeaderPolicy, JsonResponse, JsonResponseConfig, ResponseError,
+    };
+    use tokio::sync::MutexGuard;
+
+    use crate::{router::create_server, tests::CONFIG_LOCK};
+
+    fn create_policy() -> HeaderPolicy {
+        HeaderPolicy::new().deny(["X-Header-Policy-Denied"])
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let _lock: MutexGuard<'_, ()> = CONFIG_LOCK.lock().await;
+
+        let server: TestServer = create_server();
+
+        // drop
+        JsonResponseConfig::update(|config| {
+            config.header_policy(create_policy())
+        });
+
+        let res: TestResponse = server.post("/header_policy").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.maybe_header("x-header-policy-denied"), None);
+        assert_eq!(res.header("x-header-policy-kept"), "kept");
+
+        // reject
+        JsonResponseConfig::update(|config| {
+            config.header_policy(create_policy().reject(true))
+        });
+
+        let res: TestResponse = server.post("/header_policy").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(res.maybe_header("x-header-policy-kept"), None);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+        assert_eq!(
+            res.errors[0].path,
+            ["response", "header", "x-header-policy-denied"]
+        );
+
+        JsonResponseConfig::update(|config| {
+            config.header_policy(HeaderPolicy::new())
+        });
+    }
+}
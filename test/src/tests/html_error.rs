@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{HeaderValue, header};
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    const ACCEPT_BROWSER: &str = "text/html,application/xhtml+xml,*/*;q=0.8";
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/html_error/ok")
+            .add_header(header::ACCEPT, ACCEPT_BROWSER)
+            .await;
+
+        assert_eq!(
+            res.maybe_header(header::CONTENT_TYPE),
+            Some(HeaderValue::from_static("application/json"))
+        );
+        assert_eq!(res.json::<Value>()["success"], true);
+    }
+
+    #[tokio::test]
+    async fn test_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/html_error/failure")
+            .add_header(header::ACCEPT, "application/json, text/html")
+            .await;
+
+        assert_eq!(
+            res.maybe_header(header::VARY),
+            Some(HeaderValue::from_static("accept"))
+        );
+
+        let res: Value = res.json::<Value>();
+
+        assert_eq!(res["success"], false);
+        assert_eq!(res["errors"][0]["code"], "parse");
+    }
+
+    #[tokio::test]
+    async fn test_html() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/html_error/failure")
+            .add_header(header::ACCEPT, ACCEPT_BROWSER)
+            .add_header("x-request-id", "req-1")
+            .await;
+
+        assert_eq!(res.status_code().as_u16(), 400);
+        assert_eq!(
+            res.maybe_header(header::CONTENT_TYPE),
+            Some(HeaderValue::from_static("text/html; charset=utf-8"))
+        );
+
+        let html: String = res.text();
+
+        assert!(html.contains("<title>400 Bad Request</title>"));
+        assert!(html.contains("<code>parse</code>: Invalid &lt;title&gt;"));
+        assert!(html.contains("<code>req-1</code>"));
+    }
+}
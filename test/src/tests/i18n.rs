@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+    use serde_json::json;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> = server
+            .post("/i18n")
+            .add_header(header::ACCEPT_LANGUAGE, "fr-CH, de;q=0.9, en;q=0.8")
+            .json(&json!("jder"))
+            .await
+            .json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("fr".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_quality() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> = server
+            .post("/i18n")
+            .add_header(header::ACCEPT_LANGUAGE, "fr;q=0.5, de")
+            .json(&json!("jder"))
+            .await
+            .json::<JsonResponse<String>>();
+
+        assert_eq!(res.data, Some("de".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_default() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> = server
+            .post("/i18n")
+            .add_header(header::ACCEPT_LANGUAGE, "ja")
+            .json(&json!("jder"))
+            .await
+            .json::<JsonResponse<String>>();
+
+        assert_eq!(res.data, Some("en".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_rejection() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse = server
+            .post("/i18n")
+            .add_header(header::ACCEPT_LANGUAGE, "fr")
+            .json(&json!(1))
+            .await
+            .json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].message, Some("Requête invalide".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_rejection_default() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/i18n").json(&json!(1)).await.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_ne!(res.errors[0].message, Some("Requête invalide".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_localized() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse = server
+            .post("/i18n/localized")
+            .add_header(header::ACCEPT_LANGUAGE, "de-AT")
+            .await
+            .json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].message, Some("Nicht gefunden".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_unlocalized() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse = server
+            .post("/i18n/unlocalized")
+            .add_header(header::ACCEPT_LANGUAGE, "de")
+            .await
+            .json::<JsonResponse>();
+
+        assert_eq!(
+            res.errors[0].message,
+            Some(ResponseError::NotFound.as_message().to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_config() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/i18n/missing_config").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+    use crate::router::image::{PNG, WEBP};
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/image").await;
+
+        res.assert_status_ok();
+        assert_eq!(res.header(header::CONTENT_TYPE), "image/png");
+        assert_eq!(res.header(header::CACHE_CONTROL), "public, max-age=3600");
+        assert_eq!(res.header(header::VARY), "accept");
+        assert_eq!(res.into_bytes().as_ref(), PNG);
+    }
+
+    #[tokio::test]
+    async fn test_negotiated() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/image")
+            .add_header(header::ACCEPT, "image/webp,image/*;q=0.8")
+            .await;
+
+        res.assert_status_ok();
+        assert_eq!(res.header(header::CONTENT_TYPE), "image/webp");
+        assert_eq!(res.into_bytes().as_ref(), WEBP);
+    }
+
+    #[tokio::test]
+    async fn test_unacceptable() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/image").add_header(header::ACCEPT, "image/avif").await;
+
+        res.assert_status_ok();
+        assert_eq!(res.header(header::CONTENT_TYPE), "image/png");
+    }
+
+    #[tokio::test]
+    async fn test_missing() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/image/missing").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let res: JsonResponse<()> = res.json::<JsonResponse<()>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::NotFound.as_code());
+        assert_eq!(res.errors[0].path, vec!["image"]);
+    }
+
+    #[tokio::test]
+    async fn test_corrupt() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/image/corrupt").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let res: JsonResponse<()> = res.json::<JsonResponse<()>>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime};
+
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        INCIDENT_ID_HEADER, INCIDENT_ID_META_KEY, IncidentId, IncidentIdError,
+    };
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_server_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/incident_id/server").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let header: String =
+            res.header(INCIDENT_ID_HEADER).to_str().unwrap().to_string();
+
+        let body: Value = res.json::<Value>();
+
+        assert_eq!(body["meta"][INCIDENT_ID_META_KEY], header.as_str());
+        assert_eq!(header.len(), 26);
+
+        let id: IncidentId = header.parse().unwrap();
+
+        assert_eq!(id.to_string(), header);
+
+        let elapsed: Duration =
+            SystemTime::now().duration_since(id.time()).unwrap();
+
+        assert!(elapsed < Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_unique() {
+        let server: TestServer = create_server();
+
+        let a: TestResponse = server.get("/incident_id/server").await;
+        let b: TestResponse = server.get("/incident_id/server").await;
+
+        assert_ne!(a.header(INCIDENT_ID_HEADER), b.header(INCIDENT_ID_HEADER));
+    }
+
+    #[tokio::test]
+    async fn test_client_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/incident_id/client").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+        assert!(res.maybe_header(INCIDENT_ID_HEADER).is_none());
+        assert!(res.json::<Value>().get("meta").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_problem_details() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/incident_id/problem").await;
+
+        assert_eq!(res.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let header: String =
+            res.header(INCIDENT_ID_HEADER).to_str().unwrap().to_string();
+
+        assert!(res.text().contains(&header));
+    }
+
+    #[test]
+    fn test_parse() {
+        let id: IncidentId = "01arz3nd-ektsv4rr-ffq69g5fav".parse().unwrap();
+
+        assert_eq!(id.to_string(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert_eq!(
+            "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse::<IncidentId>(),
+            "O1ARZ3NDEKTSV4RRFFQ69G5FAV".parse::<IncidentId>()
+        );
+        assert_eq!("01ARZ3".parse::<IncidentId>(), Err(IncidentIdError));
+        assert_eq!(
+            "81ARZ3NDEKTSV4RRFFQ69G5FAV".parse::<IncidentId>(),
+            Err(IncidentIdError)
+        );
+        assert_eq!(
+            "01ARZ3NDEKTSV4RRFFQ69G5FAU".parse::<IncidentId>(),
+            Err(IncidentIdError)
+        );
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{HeaderValue, StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, JsonResponseError};
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_success() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/into_response/success").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.maybe_header(header::CONTENT_TYPE),
+            Some(HeaderValue::from_static("application/json"))
+        );
+
+        let res: JsonResponse<String> = res.json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("Name".to_string()));
+        assert_eq!(res, JsonResponse::success_with("Name".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/into_response/failure").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, "parse");
+        assert_eq!(
+            res,
+            JsonResponse::failure_with(
+                [JsonResponseError::new().code("parse")]
+            )
+        );
+        assert_ne!(res, JsonResponse::failure_with([JsonResponseError::new()]));
+    }
+
+    #[tokio::test]
+    async fn test_status() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/into_response/status").await;
+
+        assert_eq!(res.status_code(), StatusCode::CREATED);
+
+        let res: Value = res.json::<Value>();
+
+        assert_eq!(res["success"], true);
+        assert_eq!(res.get("status"), None);
+    }
+}
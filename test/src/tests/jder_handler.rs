@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/jder_handler/users/1").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data.unwrap()["name"], "Name");
+    }
+
+    #[tokio::test]
+    async fn test_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/jder_handler/users/42").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, "not_found");
+        assert_eq!(
+            res.errors[0].message,
+            Some("User 42 not found".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/jder_handler/users").await;
+
+        assert_eq!(res.status_code(), StatusCode::CREATED);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.data.unwrap()["id"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_api_error() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<u32> = server
+            .get("/jder_handler/parse/7")
+            .await
+            .json::<JsonResponse<u32>>();
+
+        assert_eq!(res.data, Some(7));
+
+        let res: TestResponse = server.get("/jder_handler/parse/x").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(res.json::<JsonResponse>().errors[0].code, "parse");
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JobState, JobStatus, JsonResponse};
+
+    use crate::router::create_server;
+
+    type RouteResponse = JsonResponse<JobStatus>;
+
+    #[tokio::test]
+    async fn test_queued() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/job/queued").await;
+
+        assert_eq!(res.status_code(), StatusCode::ACCEPTED);
+        assert_eq!(res.header(header::RETRY_AFTER), "1");
+
+        let data: JobStatus = res.json::<RouteResponse>().data.unwrap();
+
+        assert_eq!(data.state, JobState::Queued);
+        assert_eq!(data.progress, None);
+    }
+
+    #[tokio::test]
+    async fn test_running() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/job/running").await;
+
+        assert_eq!(res.status_code(), StatusCode::ACCEPTED);
+        assert_eq!(res.header(header::RETRY_AFTER), "5");
+
+        let data: JobStatus = res.json::<RouteResponse>().data.unwrap();
+
+        assert_eq!(data.state, JobState::Running);
+        assert_eq!(data.progress, Some(40));
+    }
+
+    #[tokio::test]
+    async fn test_succeeded() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/job/succeeded").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.maybe_header(header::RETRY_AFTER), None);
+
+        let data: JobStatus = res.json::<RouteResponse>().data.unwrap();
+
+        assert_eq!(data.state, JobState::Succeeded);
+        assert_eq!(data.progress, Some(100));
+        assert_eq!(data.result_url, Some("/result/1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_failed() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/job/failed").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+
+        let data: JobStatus = res.data.unwrap();
+
+        assert_eq!(data.state, JobState::Failed);
+        assert_eq!(data.errors[0].code, "timeout");
+    }
+}
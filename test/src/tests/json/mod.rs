@@ -1,9 +1,12 @@
 pub mod optional;
+pub mod stream;
 
 #[cfg(test)]
 mod test {
-    use axum_test::TestServer;
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
     use jder_axum::response::json::{JsonResponse, ResponseError};
+    use serde_json::json;
 
     use crate::router::create_server;
     use crate::router::json::RouteJsonResponseData;
@@ -76,4 +79,51 @@ mod test {
         assert_eq!(res.success, false);
         assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
     }
+
+    #[tokio::test]
+    async fn test_unsupported_media_type() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/json").text("{}").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_syntax_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/json")
+            .text("{")
+            .content_type("application/json")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+    }
+
+    #[tokio::test]
+    async fn test_data_error() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/json").json(&json!({ "id": "abc" })).await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["json", "id"]);
+    }
 }
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    type RouteResponse = JsonResponse<Vec<usize>>;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: RouteResponse =
+            server.post("/json/stream").await.json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(vec![1, 2, 3]));
+        assert_eq!(res.errors.len(), 0);
+        assert_eq!(res.meta.unwrap()["total"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_empty() {
+        let server: TestServer = create_server();
+
+        let res: RouteResponse =
+            server.post("/json/stream/empty").await.json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(vec![]));
+        assert_eq!(res.meta, None);
+    }
+}
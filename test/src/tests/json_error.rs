@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_ok() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> =
+            server.post("/json_error/1").await.json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("Name".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_unit() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/json_error/0").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNAUTHORIZED);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, "auth");
+        assert_eq!(res.errors[0].message, Some("Unauthorized".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_named() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/json_error/42").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, "not_found");
+        assert_eq!(
+            res.errors[0].message,
+            Some("User 42 not found".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tuple() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/json_error/2").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, "invalid_name");
+        assert_eq!(
+            res.errors[0].message,
+            Some("Invalid name: x {0}".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_direct() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/json_error/direct").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNAUTHORIZED);
+        assert_eq!(res.json::<JsonResponse>().errors[0].code, "auth");
+    }
+}
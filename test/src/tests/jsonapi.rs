@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/jsonapi")
+            .add_header(header::ACCEPT, "application/vnd.api+json")
+            .await;
+
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/vnd.api+json"
+        );
+        assert_eq!(res.json::<Value>(), json!({ "data": { "id": "1" } }));
+    }
+
+    #[tokio::test]
+    async fn test_json() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/jsonapi").await;
+
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/json"
+        );
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data.unwrap()["id"], "1");
+    }
+
+    #[tokio::test]
+    async fn test_quality() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/jsonapi")
+            .add_header(
+                header::ACCEPT,
+                "application/vnd.api+json;q=0.5, application/json",
+            )
+            .await;
+
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/json"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/jsonapi/failure")
+            .add_header(header::ACCEPT, "application/vnd.api+json")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/vnd.api+json"
+        );
+        assert_eq!(
+            res.json::<Value>(),
+            json!({
+                "errors": [{
+                    "code": "parse",
+                    "detail": "Invalid name",
+                    "source": { "pointer": "/body/name" },
+                }],
+            })
+        );
+    }
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        server.post("/latency/1").await;
+        server.post("/latency/2").await;
+
+        let res: JsonResponse<Vec<Value>> = server
+            .get("/latency/summary")
+            .await
+            .json::<JsonResponse<Vec<Value>>>();
+
+        assert_eq!(res.success, true);
+
+        let summaries: Vec<Value> = res.data.unwrap();
+
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0]["route"], "/latency/{id}");
+        assert_eq!(summaries[0]["count"], 2);
+        assert!(summaries[0]["p50"].as_f64().unwrap() >= 0.0);
+        assert!(
+            summaries[0]["p99"].as_f64().unwrap()
+                <= summaries[0]["max"].as_f64().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_empty() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<Vec<Value>> = server
+            .get("/latency/summary")
+            .await
+            .json::<JsonResponse<Vec<Value>>>();
+
+        assert_eq!(res.data, Some(vec![]));
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::{JsonResponse, JsonResponseConfig};
+    use tokio::sync::MutexGuard;
+
+    use crate::{router::create_server, tests::CONFIG_LOCK};
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/message_max_length").await.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].message, Some("He...".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_global() {
+        let _lock: MutexGuard<'_, ()> = CONFIG_LOCK.lock().await;
+
+        let server: TestServer = create_server();
+
+        JsonResponseConfig::new().message_max_length(256).set();
+
+        let res: JsonResponse = server
+            .post("/message_max_length/global")
+            .await
+            .json::<JsonResponse>();
+
+        JsonResponseConfig::reset();
+
+        let message: String = res.errors[0].message.clone().unwrap();
+
+        assert_eq!(message.chars().count(), 256);
+        assert_eq!(message, format!("{}...", "a".repeat(253)));
+
+        // not truncated after reset
+        let res: JsonResponse = server
+            .post("/message_max_length/global")
+            .await
+            .json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].message, Some("a".repeat(300)));
+    }
+}
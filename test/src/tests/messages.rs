@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::{JsonResponse, JsonResponseConfig};
+    use tokio::sync::MutexGuard;
+
+    use crate::{router::create_server, tests::CONFIG_LOCK};
+
+    async fn get_messages() -> Vec<Option<String>> {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/messages").await.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+
+        res.errors.into_iter().map(|error| error.message).collect()
+    }
+
+    async fn wait_message(message: &str) {
+        for _ in 0..200 {
+            if get_messages().await[0].as_deref() == Some(message) {
+                return;
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        panic!("message is not reloaded: {message}");
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let _lock: MutexGuard<'_, ()> = CONFIG_LOCK.lock().await;
+
+        JsonResponseConfig::update(|config| {
+            config.message("messages", "Updated")
+        });
+
+        assert_eq!(
+            get_messages().await,
+            vec![Some("Updated".to_string()), Some("Other".to_string())]
+        );
+
+        let path: std::path::PathBuf = std::env::temp_dir()
+            .join(format!("jder_axum_messages_{}.json", std::process::id()));
+
+        std::fs::write(&path, r#"{ "messages": "Reloaded" }"#).unwrap();
+
+        JsonResponseConfig::reload_messages(&path).unwrap();
+
+        assert_eq!(
+            get_messages().await,
+            vec![Some("Reloaded".to_string()), Some("Other".to_string())]
+        );
+
+        std::fs::write(&path, "invalid").unwrap();
+
+        assert!(JsonResponseConfig::reload_messages(&path).is_err());
+
+        assert_eq!(
+            get_messages().await,
+            vec![Some("Reloaded".to_string()), Some("Other".to_string())]
+        );
+
+        std::fs::write(&path, r#"{ "messages": "Watched" }"#).unwrap();
+
+        let watch = JsonResponseConfig::watch_messages(
+            &path,
+            std::time::Duration::from_millis(10),
+        );
+
+        wait_message("Watched").await;
+
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+
+        std::fs::write(&path, r#"{ "messages": "Rewatched" }"#).unwrap();
+
+        wait_message("Rewatched").await;
+
+        watch.abort();
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/meta").await.json::<JsonResponse>();
+
+        assert_eq!(res.success, true);
+
+        let meta = res.meta.unwrap();
+
+        assert_eq!(meta["request_id"], "abc");
+    }
+
+    #[tokio::test]
+    async fn test_empty() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse = server.get("/").await.json::<JsonResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.meta, None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/meta/invalid").await.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+    }
+}
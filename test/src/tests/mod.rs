@@ -1,17 +1,103 @@
+pub mod api_result;
+pub mod archive;
+pub mod auth;
+pub mod body_read;
+pub mod broadcast;
+pub mod cache_control;
+pub mod cache_ttl;
+pub mod capabilities;
+pub mod catch_panic;
+pub mod cbor;
+pub mod client_ip;
+pub mod compression;
+pub mod concurrency_limit;
+pub mod conflict;
 pub mod connect_info;
+pub mod convert;
+pub mod cookie;
+pub mod cookie_jar;
+pub mod core;
+pub mod cors;
+pub mod cross_valid;
+pub mod csv;
+pub mod data_arc;
+pub mod data_cache;
+pub mod data_default;
+pub mod data_result;
+pub mod decompression;
+pub mod echo;
+pub mod error_shadow;
+pub mod etag;
+pub mod experiments;
+pub mod failure_from;
+pub mod failure_sampling;
+pub mod fallback;
+pub mod feature_flags;
 pub mod form;
+pub mod format;
+pub mod graphql;
 pub mod header;
+pub mod header_policy;
 pub mod host;
+pub mod html_error;
+pub mod i18n;
+pub mod image;
+pub mod incident_id;
+pub mod into_response;
+pub mod jder_handler;
+pub mod job;
 pub mod json;
+pub mod json_error;
+pub mod jsonapi;
+pub mod latency;
 pub mod matched_path;
+pub mod message_max_length;
+pub mod messages;
+pub mod meta;
+pub mod msgpack;
 pub mod multipart;
+pub mod ndjson;
+pub mod negotiate;
 pub mod nested_path;
+pub mod nonce;
+pub mod page_cursor;
+pub mod pagination;
 pub mod path;
+pub mod payload_sampling;
+pub mod problem_details;
 pub mod query;
+pub mod rate_limit;
+pub mod redirect;
+pub mod rejection_config;
 pub mod request_body_limit;
+pub mod request_context;
+pub mod request_id;
 pub mod request_time_limit;
+pub mod response_cache;
+pub mod response_data;
+pub mod schema_check;
+pub mod schema_version;
 pub mod scheme;
+pub mod security_headers;
+pub mod sitemap;
+pub mod size_hint;
+pub mod spill;
+pub mod sse;
+pub mod status;
+pub mod strict_success;
+pub mod ts_export;
 pub mod typed_header;
+pub mod upstream_error;
+pub mod valid;
+pub mod validation;
+pub mod webhook;
+pub mod wellknown;
+pub mod xml;
+
+/// Lock of the global configuration,
+/// held by tests changing it.
+pub static CONFIG_LOCK: tokio::sync::Mutex<()> =
+    tokio::sync::Mutex::const_new(());
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/msgpack").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/msgpack"
+        );
+
+        let expected: &[u8] = b"\x83\xa7success\xc3\xa4data\x83\xa2id\xcd\x03\xe8\xa4name\xa4Name\xa6offset\xd1\xfe\x0c\xa6errors\x90";
+
+        assert_eq!(res.as_bytes().as_ref(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/msgpack/invalid").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let expected: &[u8] = b"\x83\xa7success\xc2\xa4data\xc0\xa6errors\x91\x83\xa4code\xa6server\xa4path\x90\xa7message\xb6Internal server error.";
+
+        assert_eq!(res.as_bytes().as_ref(), expected);
+    }
+}
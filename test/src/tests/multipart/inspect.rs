@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{
+        TestResponse, TestServer,
+        multipart::{MultipartForm, Part},
+    };
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let form: MultipartForm =
+            MultipartForm::new().add_part("file", Part::text("clean"));
+
+        let res: JsonResponse<String> = server
+            .post("/multipart/inspect")
+            .multipart(form)
+            .await
+            .json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data.unwrap(), "clean");
+    }
+
+    #[tokio::test]
+    async fn test_rejected() {
+        let server: TestServer = create_server();
+
+        let form: MultipartForm =
+            MultipartForm::new().add_part("file", Part::text("a virus inside"));
+
+        let res: TestResponse =
+            server.post("/multipart/inspect").multipart(form).await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(
+            res.errors[0].code,
+            ResponseError::ContentRejected.as_code()
+        );
+        assert_eq!(res.errors[0].path, vec!["multipart", "file"]);
+        assert_eq!(res.errors[0].message.as_deref(), Some("Virus found"));
+    }
+
+    #[tokio::test]
+    async fn test_error_nobody() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/multipart/inspect").await.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+    }
+}
@@ -1,4 +1,6 @@
 pub mod file;
+pub mod inspect;
+pub mod spool;
 
 #[cfg(test)]
 mod test {
@@ -45,7 +47,11 @@ mod test {
             .json::<TypedMultipartFailureResponse>();
 
         assert_eq!(res.success, false);
-        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        // no multipart content type
+        assert_eq!(
+            res.errors[0].code,
+            ResponseError::UnsupportedMediaType.as_code()
+        );
     }
 
     #[tokio::test]
@@ -78,5 +84,41 @@ mod test {
 
         assert_eq!(res.success, false);
         assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["multipart", "number"]);
+    }
+
+    #[tokio::test]
+    async fn test_base() {
+        let server: TestServer = create_server();
+
+        let form: MultipartForm = MultipartForm::new()
+            .add_text("string", "String")
+            .add_text("number", "1");
+
+        let res: JsonResponse<Vec<String>> = server
+            .post("/multipart/base")
+            .multipart(form)
+            .await
+            .json::<JsonResponse<Vec<String>>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(
+            res.data,
+            Some(vec!["string".to_string(), "number".to_string()])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_base_error_nobody() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse =
+            server.post("/multipart/base").await.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(
+            res.errors[0].code,
+            ResponseError::UnsupportedMediaType.as_code()
+        );
     }
 }
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer, multipart::MultipartForm};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+    use crate::router::multipart::spool::RouteMultipartSpoolResponseData;
+
+    type RouteResponse = JsonResponse<Vec<RouteMultipartSpoolResponseData>>;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let form: MultipartForm =
+            MultipartForm::new().add_text("file", "content");
+
+        let res: RouteResponse = server
+            .post("/multipart/spool")
+            .multipart(form)
+            .await
+            .json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+
+        let files: Vec<RouteMultipartSpoolResponseData> = res.data.unwrap();
+
+        assert_eq!(files[0].name, Some("file".to_string()));
+        assert_eq!(files[0].size, 7);
+        assert_eq!(files[0].content, "content");
+    }
+
+    #[tokio::test]
+    async fn test_too_large() {
+        let server: TestServer = create_server();
+
+        let form: MultipartForm =
+            MultipartForm::new().add_text("file", "a".repeat(17));
+
+        let res: TestResponse =
+            server.post("/multipart/spool").multipart(form).await;
+
+        assert_eq!(res.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::TooLarge.as_code());
+        assert_eq!(res.errors[0].path, vec!["multipart", "file"]);
+    }
+}
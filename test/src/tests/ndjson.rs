@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod test {
+    use axum::http::header;
+    use axum_test::{TestResponse, TestServer};
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/ndjson").await;
+
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/x-ndjson"
+        );
+        assert_eq!(res.header("x-total").to_str().unwrap(), "3");
+
+        let text: String = res.text();
+
+        assert_eq!(text.ends_with('\n'), true);
+
+        let lines: Vec<Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["id"], 1);
+        assert_eq!(lines[2]["id"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/ndjson/stream").await;
+
+        assert_eq!(res.text(), "\"a\"\n\"b\"\n");
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/ndjson/shutdown").await;
+
+        assert_eq!(
+            res.text(),
+            "1\n2\n3\n{\"success\":false,\"data\":null,\"errors\":[{\"code\":\"draining\",\"path\":[],\"message\":\"Server is draining\"}]}\n"
+        );
+    }
+}
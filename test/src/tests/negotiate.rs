@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod test {
+    use axum::http::header;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    async fn content_type(accept: Option<&str>) -> String {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = match accept {
+            | Some(accept) => {
+                server
+                    .post("/negotiate")
+                    .add_header(header::ACCEPT, accept)
+                    .await
+            },
+            | None => server.post("/negotiate").await,
+        };
+
+        res.header(header::CONTENT_TYPE).to_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> =
+            server.post("/negotiate").await.json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data.unwrap(), "Name");
+    }
+
+    #[tokio::test]
+    async fn test_msgpack() {
+        assert_eq!(
+            content_type(Some("application/msgpack")).await,
+            "application/msgpack"
+        );
+        assert_eq!(
+            content_type(Some("application/x-msgpack")).await,
+            "application/msgpack"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cbor() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/negotiate")
+            .add_header(header::ACCEPT, "application/cbor")
+            .await;
+
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/cbor"
+        );
+
+        let expected: &[u8] = b"\xa3gsuccess\xf5ddatadNameferrors\x80";
+
+        assert_eq!(res.as_bytes().as_ref(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_quality() {
+        assert_eq!(
+            content_type(Some("application/json;q=0.5, application/cbor"))
+                .await,
+            "application/cbor"
+        );
+        assert_eq!(
+            content_type(Some("application/*;q=0.5, application/cbor;q=0.1"))
+                .await,
+            "application/json"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fallback() {
+        assert_eq!(content_type(None).await, "application/json");
+        assert_eq!(content_type(Some("*/*")).await, "application/json");
+        assert_eq!(content_type(Some("text/html")).await, "application/json");
+    }
+}
@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        extract::nonce::{
+            MemoryNonceStore, NONCE_HEADER, NONCE_TIMESTAMP_HEADER, NonceStore,
+        },
+        response::json::{JsonResponse, ResponseError},
+    };
+
+    use crate::router::create_server;
+
+    fn now() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    async fn post_nonce(
+        server: &TestServer,
+        path: &str,
+        nonce: &str,
+        timestamp: u64,
+    ) -> TestResponse {
+        server
+            .post(path)
+            .add_header(NONCE_HEADER, nonce)
+            .add_header(NONCE_TIMESTAMP_HEADER, timestamp.to_string())
+            .await
+    }
+
+    fn assert_nonce_error(
+        res: TestResponse,
+        status: StatusCode,
+        code: &str,
+        header: &str,
+    ) {
+        assert_eq!(res.status_code(), status);
+
+        let res: JsonResponse<String> = res.json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, code);
+        assert_eq!(res.errors[0].path, vec!["header", header]);
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> =
+            post_nonce(&server, "/nonce", "test-nonce", now())
+                .await
+                .json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("test-nonce".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_replay() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            post_nonce(&server, "/nonce", "test-replay", now()).await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: TestResponse =
+            post_nonce(&server, "/nonce", "test-replay", now()).await;
+
+        assert_nonce_error(
+            res,
+            StatusCode::CONFLICT,
+            ResponseError::Conflict.as_code(),
+            NONCE_HEADER,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/nonce")
+            .add_header(NONCE_TIMESTAMP_HEADER, now().to_string())
+            .await;
+
+        assert_nonce_error(
+            res,
+            StatusCode::UNAUTHORIZED,
+            ResponseError::Unauthorized.as_code(),
+            NONCE_HEADER,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_too_long() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            post_nonce(&server, "/nonce", &"a".repeat(129), now()).await;
+
+        assert_nonce_error(
+            res,
+            StatusCode::UNAUTHORIZED,
+            ResponseError::Unauthorized.as_code(),
+            NONCE_HEADER,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_timestamp() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/nonce").add_header(NONCE_HEADER, "test-nonce").await;
+
+        assert_nonce_error(
+            res,
+            StatusCode::UNAUTHORIZED,
+            ResponseError::Unauthorized.as_code(),
+            NONCE_TIMESTAMP_HEADER,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_timestamp() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/nonce")
+            .add_header(NONCE_HEADER, "test-nonce")
+            .add_header(NONCE_TIMESTAMP_HEADER, "yesterday")
+            .await;
+
+        assert_nonce_error(
+            res,
+            StatusCode::UNAUTHORIZED,
+            ResponseError::Unauthorized.as_code(),
+            NONCE_TIMESTAMP_HEADER,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_outside_window() {
+        let server: TestServer = create_server();
+
+        // default time to live of 300 seconds
+        for timestamp in [now() - 301, now() + 301] {
+            let res: TestResponse =
+                post_nonce(&server, "/nonce", "test-window", timestamp).await;
+
+            assert_nonce_error(
+                res,
+                StatusCode::UNAUTHORIZED,
+                ResponseError::Unauthorized.as_code(),
+                NONCE_TIMESTAMP_HEADER,
+            );
+        }
+
+        let res: TestResponse =
+            post_nonce(&server, "/nonce", "test-window", now() - 290).await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_store_expired() {
+        let store: MemoryNonceStore =
+            MemoryNonceStore::new().sweep_interval(Duration::from_secs(3600));
+
+        let ttl: Duration = Duration::from_millis(20);
+
+        assert!(store.insert("test-store", ttl).await);
+        assert!(!store.insert("test-store", ttl).await);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        // expired before the next sweep
+        assert!(store.insert("test-store", ttl).await);
+    }
+
+    #[tokio::test]
+    async fn test_missing_config() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            post_nonce(&server, "/nonce/missing_config", "test-nonce", now())
+                .await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime};
+
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        JsonResponse, ResponseError,
+        pagination::{PageCursor, PageCursorError},
+    };
+    use serde::{Deserialize, Serialize};
+
+    use crate::router::create_server;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct LastSeen {
+        id: usize,
+    }
+
+    const KEY: &[u8] = b"secret";
+
+    #[tokio::test]
+    async fn test() {
+        let token: String = PageCursor::new(LastSeen { id: 10 })
+            .expires_in(Duration::from_secs(60))
+            .encode(KEY);
+
+        let cursor: PageCursor<LastSeen> =
+            PageCursor::decode(&token, KEY).unwrap();
+
+        assert_eq!(cursor.data.id, 10);
+        assert_eq!(cursor.version, 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_key() {
+        let token: String = PageCursor::new(LastSeen { id: 10 }).encode(KEY);
+
+        let err = PageCursor::<LastSeen>::decode(&token, b"other").unwrap_err();
+
+        assert_eq!(err, PageCursorError::InvalidSignature);
+    }
+
+    #[tokio::test]
+    async fn test_tampered() {
+        let token: String = PageCursor::new(LastSeen { id: 10 }).encode(KEY);
+        let other: String = PageCursor::new(LastSeen { id: 11 }).encode(KEY);
+
+        let (payload, _) = other.split_once('.').unwrap();
+        let (_, signature) = token.split_once('.').unwrap();
+
+        let err = PageCursor::<LastSeen>::decode(
+            &format!("{}.{}", payload, signature),
+            KEY,
+        )
+        .unwrap_err();
+
+        assert_eq!(err, PageCursorError::InvalidSignature);
+    }
+
+    #[tokio::test]
+    async fn test_malformed() {
+        let err = PageCursor::<LastSeen>::decode("abc", KEY).unwrap_err();
+
+        assert_eq!(err, PageCursorError::Malformed);
+    }
+
+    #[tokio::test]
+    async fn test_version() {
+        let token: String =
+            PageCursor::new(LastSeen { id: 10 }).version(2).encode(KEY);
+
+        let err = PageCursor::<LastSeen>::decode(&token, KEY).unwrap_err();
+
+        assert_eq!(err, PageCursorError::InvalidVersion);
+
+        let cursor: PageCursor<LastSeen> =
+            PageCursor::decode_version(&token, KEY, 2).unwrap();
+
+        assert_eq!(cursor.data.id, 10);
+    }
+
+    #[tokio::test]
+    async fn test_expired() {
+        let token: String = PageCursor::new(LastSeen { id: 10 })
+            .expires_at(SystemTime::now() - Duration::from_secs(1))
+            .encode(KEY);
+
+        let err = PageCursor::<LastSeen>::decode(&token, KEY).unwrap_err();
+
+        assert_eq!(err, PageCursorError::Expired);
+    }
+
+    #[tokio::test]
+    async fn test_extract() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<Vec<u64>> =
+            server.post("/page_cursor").await.json::<JsonResponse<Vec<u64>>>();
+
+        assert_eq!(res.data, Some(vec![0, 1, 2]));
+
+        let meta = res.meta.unwrap();
+
+        assert_eq!(meta["pagination"]["page"], 1);
+
+        let next: &str = meta["pagination"]["next_cursor"].as_str().unwrap();
+
+        let res: JsonResponse<Vec<u64>> = server
+            .post("/page_cursor")
+            .add_query_param("cursor", next)
+            .await
+            .json::<JsonResponse<Vec<u64>>>();
+
+        assert_eq!(res.data, Some(vec![3, 4, 5]));
+        assert_eq!(res.meta.unwrap()["pagination"]["page"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_extract_invalid() {
+        let server: TestServer = create_server();
+
+        let token: String = PageCursor::new(3_u64).encode(b"other");
+
+        let res: TestResponse =
+            server.post("/page_cursor").add_query_param("cursor", &token).await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["query", "cursor"]);
+        assert_eq!(
+            res.errors[0].message.as_deref(),
+            Some(PageCursorError::InvalidSignature.as_message())
+        );
+    }
+}
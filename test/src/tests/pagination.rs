@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    type RouteResponse = JsonResponse<Vec<usize>>;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: RouteResponse =
+            server.post("/pagination").await.json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(vec![1, 2, 3]));
+
+        let meta = res.meta.unwrap();
+
+        assert_eq!(meta["pagination"]["page"], 1);
+        assert_eq!(meta["pagination"]["per_page"], 3);
+        assert_eq!(meta["pagination"]["total"], 10);
+        assert_eq!(meta["pagination"]["total_pages"], 4);
+        assert_eq!(meta["pagination"]["next_cursor"], "next");
+        assert_eq!(meta["pagination"].get("prev_cursor"), None);
+    }
+
+    #[tokio::test]
+    async fn test_meta() {
+        let server: TestServer = create_server();
+
+        let res: RouteResponse =
+            server.post("/pagination/meta").await.json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+
+        let meta = res.meta.unwrap();
+
+        assert_eq!(meta["cached"], true);
+        assert_eq!(meta["pagination"]["page"], 1);
+        assert_eq!(meta["pagination"]["per_page"], 3);
+    }
+}
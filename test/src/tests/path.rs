@@ -32,5 +32,6 @@ mod test {
 
         assert_eq!(res.success, false);
         assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["path", "id"]);
     }
 }
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    type Samples = Vec<(String, String, usize, Option<usize>)>;
+
+    #[tokio::test]
+    async fn test_list() {
+        let server: TestServer = create_server();
+
+        let body: String = server.post("/payload_sampling/list").await.text();
+
+        server.post("/payload_sampling/failure").await;
+
+        let samples: Samples = server
+            .get("/payload_sampling/list/samples")
+            .await
+            .json::<JsonResponse<Samples>>()
+            .data
+            .unwrap();
+
+        assert_eq!(
+            samples,
+            vec![(
+                "/payload_sampling/list".to_string(),
+                std::any::type_name::<Vec<u32>>().to_string(),
+                body.len(),
+                Some(3),
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate() {
+        let server: TestServer = create_server();
+
+        for _ in 0..4 {
+            server.post("/payload_sampling/half").await;
+        }
+
+        let samples: Samples = server
+            .get("/payload_sampling/half/samples")
+            .await
+            .json::<JsonResponse<Samples>>()
+            .data
+            .unwrap();
+
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].3, None);
+    }
+}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        CONTENT_TYPE_PROBLEM_JSON, PROBLEM_TYPE_DEFAULT, ProblemDetails,
+        ResponseError,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/problem_details").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            CONTENT_TYPE_PROBLEM_JSON
+        );
+
+        let problem: ProblemDetails = res.json::<ProblemDetails>();
+
+        assert_eq!(problem.problem_type, PROBLEM_TYPE_DEFAULT);
+        assert_eq!(problem.title, "Unprocessable Entity");
+        assert_eq!(problem.status, 422);
+        assert_eq!(problem.detail, Some("Invalid value".to_string()));
+        assert_eq!(problem.instance, Some("#/body/a~1b".to_string()));
+        assert_eq!(problem.errors.len(), 2);
+        assert_eq!(problem.errors[0].code, ResponseError::Parse.as_code());
+    }
+
+    #[tokio::test]
+    async fn test_empty() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/problem_details/empty").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let problem: ProblemDetails = res.json::<ProblemDetails>();
+
+        assert_eq!(problem.title, "Bad Request");
+        assert_eq!(problem.detail, None);
+        assert_eq!(problem.instance, None);
+        assert_eq!(problem.errors.len(), 0);
+    }
+}
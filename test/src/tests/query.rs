@@ -1,10 +1,11 @@
 #[cfg(test)]
 mod test {
-    use axum_test::TestServer;
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
     use jder_axum::response::json::{JsonResponse, ResponseError};
 
     use crate::router::create_server;
-    use crate::router::query::RouteQueryResponseData;
+    use crate::router::query::{RouteQueryQsData, RouteQueryResponseData};
 
     type RouteResponse = JsonResponse<RouteQueryResponseData>;
 
@@ -100,4 +101,146 @@ mod test {
         assert_eq!(res.success, false);
         assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
     }
+
+    #[tokio::test]
+    async fn test_invalid_num() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/query?num=abc").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["query", "num"]);
+    }
+
+    #[tokio::test]
+    async fn test_strict() {
+        let server: TestServer = create_server();
+
+        let res: RouteResponse = server
+            .post("/query/strict?num=1&empty=Empty")
+            .await
+            .json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data.unwrap().num, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_strict_unknown() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/query/strict?num=1&page=2&size=3").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors.len(), 2);
+        assert_eq!(res.errors[0].path, vec!["query", "page"]);
+        assert_eq!(res.errors[1].path, vec!["query", "size"]);
+    }
+
+    #[tokio::test]
+    async fn test_qs() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<RouteQueryQsData> = server
+            .post("/query/qs?tag=a&tag=b&page=2&filter[status]=open")
+            .await
+            .json::<JsonResponse<RouteQueryQsData>>();
+
+        assert_eq!(res.success, true);
+
+        let data: RouteQueryQsData = res.data.unwrap();
+
+        assert_eq!(data.tag, vec!["a", "b"]);
+        assert_eq!(data.page, Some(2));
+        assert_eq!(data.filter.unwrap().status, Some("open".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_qs_brackets() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<RouteQueryQsData> = server
+            .post("/query/qs?tag[]=a&tag[]=b&filter%5Bassignee%5D%5B1%5D=2&filter%5Bassignee%5D%5B0%5D=1")
+            .await
+            .json::<JsonResponse<RouteQueryQsData>>();
+
+        assert_eq!(res.success, true);
+
+        let data: RouteQueryQsData = res.data.unwrap();
+
+        assert_eq!(data.tag, vec!["a", "b"]);
+        assert_eq!(data.filter.unwrap().assignee, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_qs_single() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<RouteQueryQsData> = server
+            .post("/query/qs?tag[]=a")
+            .await
+            .json::<JsonResponse<RouteQueryQsData>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data.unwrap().tag, vec!["a"]);
+    }
+
+    #[tokio::test]
+    async fn test_qs_invalid_value() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/query/qs?filter[assignee][]=abc").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(
+            res.errors[0].path,
+            vec!["query", "filter", "assignee", "0"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_qs_invalid_key() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/query/qs?page=1&page[a]=2").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["query", "page"]);
+    }
+
+    #[tokio::test]
+    async fn test_qs_too_deep() {
+        let server: TestServer = create_server();
+
+        // status is a map of the rest of the deeper key as a literal key
+        let res: JsonResponse<RouteQueryQsData> = server
+            .post("/query/qs?filter[status][a][b][c][d][e]=open")
+            .await
+            .json::<JsonResponse<RouteQueryQsData>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["query", "filter", "status"]);
+    }
 }
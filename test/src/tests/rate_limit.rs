@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        layers::rate_limit::{
+            RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER,
+            RATE_LIMIT_RESET_HEADER,
+        },
+        response::json::{JsonResponse, ResponseError},
+    };
+
+    use crate::router::create_server;
+
+    async fn post_with_key(
+        server: &TestServer,
+        key: &str,
+    ) -> TestResponse {
+        server.post("/rate_limit").add_header("x-api-key", key).await
+    }
+
+    #[tokio::test]
+    async fn test_limited() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = post_with_key(&server, "limited").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(RATE_LIMIT_LIMIT_HEADER), "2");
+        assert_eq!(res.header(RATE_LIMIT_REMAINING_HEADER), "1");
+        assert_eq!(res.header(RATE_LIMIT_RESET_HEADER), "30");
+
+        let res: TestResponse = post_with_key(&server, "limited").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(RATE_LIMIT_REMAINING_HEADER), "0");
+
+        let res: TestResponse = post_with_key(&server, "limited").await;
+
+        assert_eq!(res.status_code(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(res.header(header::RETRY_AFTER), "30");
+        assert_eq!(res.header(RATE_LIMIT_REMAINING_HEADER), "0");
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(
+            res.errors[0].code,
+            ResponseError::TooManyRequests.as_code()
+        );
+        assert_eq!(
+            res.errors[0].message.as_deref(),
+            Some("Rate limit exceeded")
+        );
+
+        // other keys have their own quotas
+        let res: TestResponse = post_with_key(&server, "other").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_without_key() {
+        let server: TestServer = create_server();
+
+        // requests without a key or with an empty key share a quota
+        let res: TestResponse = server.post("/rate_limit").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(RATE_LIMIT_LIMIT_HEADER), "2");
+
+        let res: TestResponse = post_with_key(&server, "").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: TestResponse = server.post("/rate_limit").await;
+
+        assert_eq!(res.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn test_without_key_rejected() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/rate_limit/reject").await;
+
+        assert_eq!(res.status_code(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(res.header(header::RETRY_AFTER), "60");
+
+        let res: TestResponse = server
+            .post("/rate_limit/reject")
+            .add_header("x-api-key", "key")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_client_ip() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/rate_limit/client_ip").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(RATE_LIMIT_LIMIT_HEADER), "1");
+
+        let res: TestResponse = server.post("/rate_limit/client_ip").await;
+
+        assert_eq!(res.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    }
+}
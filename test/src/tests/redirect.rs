@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::{
+        json::{JsonResponse, ResponseError},
+        redirect::RedirectData,
+    };
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_temporary() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/redirect/temporary").await;
+
+        assert_eq!(res.status_code(), StatusCode::TEMPORARY_REDIRECT);
+        assert_eq!(res.header(header::LOCATION), "/login");
+        assert!(res.maybe_header(header::CONTENT_TYPE).is_none());
+        assert_eq!(res.text(), "");
+    }
+
+    #[tokio::test]
+    async fn test_permanent() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/redirect/permanent").await;
+
+        assert_eq!(res.status_code(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(res.header(header::LOCATION), "/new");
+        assert_eq!(res.header("x-redirect"), "permanent");
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+
+        let res: JsonResponse<RedirectData> =
+            res.json::<JsonResponse<RedirectData>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(
+            res.data,
+            Some(RedirectData { status: 308, location: "/new".to_string() })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_see_other() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/redirect/see_other").await;
+
+        assert_eq!(res.status_code(), StatusCode::SEE_OTHER);
+        assert_eq!(res.header(header::LOCATION), "/orders/1");
+        assert_eq!(res.header(header::SET_COOKIE), "draft=; Max-Age=0; Path=/");
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/redirect/invalid").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+    use serde_json::json;
+
+    use crate::router::create_server;
+
+    fn assert_overridden(res: TestResponse) {
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, "invalid_request");
+        assert_eq!(res.errors[0].path, vec!["json", "name"]);
+        assert_eq!(
+            res.errors[0].message,
+            Some("Invalid `json.name` (parse)".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> = server
+            .post("/rejection_config")
+            .json(&json!({ "name": "jder" }))
+            .await
+            .json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("jder".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_rejection() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/rejection_config").json(&json!({ "name": 1 })).await;
+
+        assert_overridden(res);
+    }
+
+    #[tokio::test]
+    async fn test_extension() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/rejection_config/extension")
+            .json(&json!({ "name": 1 }))
+            .await;
+
+        assert_overridden(res);
+    }
+
+    #[tokio::test]
+    async fn test_handler() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/rejection_config/handler").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].message, Some("Handler failure".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_missing_config() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/rejection_config/missing_config")
+            .json(&json!({ "name": 1 }))
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+    }
+}
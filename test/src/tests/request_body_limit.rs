@@ -38,6 +38,12 @@ mod test {
         let err: JsonResponseError = res.errors[0].clone();
 
         assert_eq!(err.code, ResponseError::TooLarge.as_code());
+        assert_eq!(
+            err.message.as_deref(),
+            Some(
+                "Request body is too large, limit is 1048576 bytes, got 1048577 bytes"
+            )
+        );
     }
 
     #[tokio::test]
@@ -73,4 +79,20 @@ mod test {
 
         assert_eq!(err.code, ResponseError::TooLarge.as_code());
     }
+
+    #[tokio::test]
+    async fn test_json() {
+        let server: TestServer = create_server();
+
+        type RouteResponse = JsonResponse<usize>;
+
+        let res: RouteResponse = server
+            .post("/request_body_limit/json")
+            .json(&"a".repeat(2048))
+            .await
+            .json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(2048));
+    }
 }
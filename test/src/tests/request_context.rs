@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+    use crate::router::request_context::RouteRequestContextResponseData;
+
+    type RouteResponse = JsonResponse<RouteRequestContextResponseData>;
+
+    #[tokio::test]
+    async fn test_header() {
+        let server: TestServer = create_server();
+
+        let res: RouteResponse = server
+            .post("/request_context")
+            .add_header(header::ACCEPT_LANGUAGE, "fr;q=0.8, en-US, *;q=0.1")
+            .add_header("x-timezone", "America/New_York")
+            .add_header("x-currency", "eur")
+            .await
+            .json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+
+        let data: RouteRequestContextResponseData = res.data.unwrap();
+
+        assert_eq!(data.locale, Some("en-US".to_string()));
+        assert_eq!(data.timezone, Some("America/New_York".to_string()));
+        assert_eq!(data.currency, Some("EUR".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let server: TestServer = create_server();
+
+        let res: RouteResponse = server
+            .post("/request_context?locale=ja-JP&timezone=Asia%2FTokyo")
+            .add_header(header::ACCEPT_LANGUAGE, "en-US")
+            .await
+            .json::<RouteResponse>();
+
+        assert_eq!(res.success, true);
+
+        let data: RouteRequestContextResponseData = res.data.unwrap();
+
+        assert_eq!(data.locale, Some("ja-JP".to_string()));
+        assert_eq!(data.timezone, Some("Asia/Tokyo".to_string()));
+        assert_eq!(data.currency, None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/request_context?timezone=Asia%2F..%2FTokyo")
+            .add_header("x-currency", "dollar")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+        let res: RouteResponse = res.json::<RouteResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors.len(), 2);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["query", "timezone"]);
+        assert_eq!(res.errors[1].path, vec!["header", "x-currency"]);
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        extract::request_id::{REQUEST_ID_HEADER, REQUEST_ID_META_KEY},
+        response::json::{JsonResponse, ResponseError},
+    };
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    fn get_meta_request_id(res: &JsonResponse<Value>) -> Option<String> {
+        res.meta
+            .as_ref()
+            .and_then(|meta| meta.get(REQUEST_ID_META_KEY))
+            .and_then(Value::as_str)
+            .map(ToString::to_string)
+    }
+
+    #[tokio::test]
+    async fn test_generate() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/request_id").await;
+
+        let header: String =
+            res.header(REQUEST_ID_HEADER).to_str().unwrap().to_string();
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(header.len(), 36);
+        assert_eq!(&header[14..15], "4");
+        assert_eq!(res.data, Some(Value::String(header.clone())));
+        assert_eq!(get_meta_request_id(&res), Some(header));
+    }
+
+    #[tokio::test]
+    async fn test_unique() {
+        let server: TestServer = create_server();
+
+        let a: TestResponse = server.post("/request_id").await;
+        let b: TestResponse = server.post("/request_id").await;
+
+        assert_ne!(a.header(REQUEST_ID_HEADER), b.header(REQUEST_ID_HEADER));
+    }
+
+    #[tokio::test]
+    async fn test_propagate() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/request_id")
+            .add_header(REQUEST_ID_HEADER, "abc")
+            .await;
+
+        assert_eq!(res.header(REQUEST_ID_HEADER), "abc");
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.data, Some(Value::String("abc".to_string())));
+        assert_eq!(get_meta_request_id(&res), Some("abc".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_propagate_too_long() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/request_id")
+            .add_header(REQUEST_ID_HEADER, "a".repeat(129))
+            .await;
+
+        assert_eq!(res.header(REQUEST_ID_HEADER).len(), 36);
+    }
+
+    #[tokio::test]
+    async fn test_rejection() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/request_id/json")
+            .add_header(REQUEST_ID_HEADER, "abc")
+            .text("invalid")
+            .await;
+
+        assert_eq!(res.header(REQUEST_ID_HEADER), "abc");
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(get_meta_request_id(&res), Some("abc".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_custom() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/request_id/custom").await;
+
+        assert_eq!(res.header(REQUEST_ID_HEADER), "custom");
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(get_meta_request_id(&res), Some("custom".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_missing() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/request_id/missing").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(res.maybe_header(REQUEST_ID_HEADER).is_none());
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+        assert_eq!(get_meta_request_id(&res), None);
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
-    use axum_test::TestServer;
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
     use jder_axum::response::json::{
         JsonResponse, JsonResponseError, ResponseError,
     };
@@ -36,4 +37,22 @@ mod test {
 
         assert_eq!(err.code, ResponseError::Timeout.as_code());
     }
+
+    #[tokio::test]
+    async fn test_from_duration() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/request_time_limit/from").await;
+
+        assert_eq!(res.status_code(), StatusCode::GATEWAY_TIMEOUT);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Timeout.as_code());
+        assert_eq!(
+            res.errors[0].message.as_deref(),
+            Some(ResponseError::Timeout.as_message())
+        );
+    }
 }
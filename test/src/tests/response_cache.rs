@@ -0,0 +1,229 @@
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use axum::http::header;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        layers::response_cache::CACHE_STATUS_HEADER,
+        response::json::JsonResponse,
+    };
+
+    use crate::router::create_server;
+
+    fn get_cache_status(res: &TestResponse) -> Option<String> {
+        res.maybe_header(CACHE_STATUS_HEADER)
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    fn get_data(res: &TestResponse) -> usize {
+        res.json::<JsonResponse<usize>>().data.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fresh() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/response_cache/fresh").await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; fwd=miss; stored".to_string())
+        );
+        assert_eq!(get_data(&res), 1);
+
+        let res: TestResponse = server.get("/response_cache/fresh").await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; hit; ttl=59".to_string())
+        );
+        assert_eq!(res.header(header::AGE).to_str().unwrap(), "0");
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/json"
+        );
+        assert_eq!(get_data(&res), 1);
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let server: TestServer = create_server();
+
+        server.get("/response_cache/fresh?page=1").await;
+
+        let res: TestResponse =
+            server.get("/response_cache/fresh?page=2").await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; fwd=miss; stored".to_string())
+        );
+        assert_eq!(get_data(&res), 2);
+    }
+
+    #[tokio::test]
+    async fn test_vary() {
+        let server: TestServer = create_server();
+
+        server
+            .get("/response_cache/fresh")
+            .add_header(header::ACCEPT_LANGUAGE, "en")
+            .await;
+
+        let res: TestResponse = server
+            .get("/response_cache/fresh")
+            .add_header(header::ACCEPT_LANGUAGE, "fr")
+            .await;
+
+        assert_eq!(get_data(&res), 2);
+
+        let res: TestResponse = server
+            .get("/response_cache/fresh")
+            .add_header(header::ACCEPT_LANGUAGE, "en")
+            .await;
+
+        assert_eq!(get_data(&res), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stale() {
+        let server: TestServer = create_server();
+
+        server.get("/response_cache/stale").await;
+
+        let res: TestResponse = server.get("/response_cache/stale").await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; hit; ttl=-0".to_string())
+        );
+        assert_eq!(get_data(&res), 1);
+
+        // wait for the background refresh
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let res: TestResponse = server.get("/response_cache/stale").await;
+
+        assert_eq!(get_data(&res), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expired() {
+        let server: TestServer = create_server();
+
+        server.get("/response_cache/expired").await;
+
+        let res: TestResponse = server.get("/response_cache/expired").await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; fwd=stale; stored".to_string())
+        );
+        assert_eq!(get_data(&res), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_store() {
+        let server: TestServer = create_server();
+
+        server.get("/response_cache/fresh/no_store").await;
+
+        let res: TestResponse =
+            server.get("/response_cache/fresh/no_store").await;
+
+        assert_eq!(get_cache_status(&res), Some("jder; fwd=miss".to_string()));
+        assert_eq!(get_data(&res), 2);
+    }
+
+    #[tokio::test]
+    async fn test_post() {
+        let server: TestServer = create_server();
+
+        server.post("/response_cache/fresh").await;
+
+        let res: TestResponse = server.post("/response_cache/fresh").await;
+
+        assert_eq!(get_cache_status(&res), None);
+        assert_eq!(get_data(&res), 2);
+    }
+
+    #[tokio::test]
+    async fn test_credentials() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/response_cache/fresh")
+            .add_header(header::AUTHORIZATION, "Bearer a")
+            .await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; fwd=bypass".to_string())
+        );
+        assert_eq!(get_data(&res), 1);
+
+        let res: TestResponse = server
+            .get("/response_cache/fresh")
+            .add_header(header::AUTHORIZATION, "Bearer b")
+            .await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; fwd=bypass".to_string())
+        );
+        assert_eq!(get_data(&res), 2);
+
+        let res: TestResponse = server
+            .get("/response_cache/fresh")
+            .add_header(header::COOKIE, "session=b")
+            .await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; fwd=bypass".to_string())
+        );
+        assert_eq!(get_data(&res), 3);
+
+        // anonymous responses are not served from the bypassed ones
+        let res: TestResponse = server.get("/response_cache/fresh").await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; fwd=miss; stored".to_string())
+        );
+        assert_eq!(get_data(&res), 4);
+    }
+
+    #[tokio::test]
+    async fn test_cache_credentials() {
+        let server: TestServer = create_server();
+
+        server
+            .get("/response_cache/credentials")
+            .add_header(header::AUTHORIZATION, "Bearer a")
+            .await;
+
+        let res: TestResponse = server
+            .get("/response_cache/credentials")
+            .add_header(header::AUTHORIZATION, "Bearer b")
+            .await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; fwd=miss; stored".to_string())
+        );
+        assert_eq!(get_data(&res), 2);
+
+        let res: TestResponse = server
+            .get("/response_cache/credentials")
+            .add_header(header::AUTHORIZATION, "Bearer a")
+            .await;
+
+        assert_eq!(
+            get_cache_status(&res),
+            Some("jder; hit; ttl=59".to_string())
+        );
+        assert_eq!(get_data(&res), 1);
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::{create_server, response_data::RouteResponseData};
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<RouteResponseData> = server
+            .post("/response_data")
+            .await
+            .json::<JsonResponse<RouteResponseData>>();
+
+        assert_eq!(res.success, true);
+
+        let data: RouteResponseData = res.data.unwrap();
+
+        assert_eq!(data.id, 1);
+        assert_eq!(data.display_name, "NAME");
+        assert_eq!(data.tags.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<RouteResponseData> = server
+            .post("/response_data/admin")
+            .await
+            .json::<JsonResponse<RouteResponseData>>();
+
+        let data: RouteResponseData = res.data.unwrap();
+
+        assert_eq!(data.id, 2);
+        assert_eq!(data.display_name, "ADMIN");
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{
+        CreateJsonResponse, DataSchema, JsonResponse,
+    };
+    use serde::Serialize;
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/schema_check").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(json!({ "id": 1, "name": "jder" })));
+    }
+
+    #[tokio::test]
+    async fn test_drift() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/schema_check/drift").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.data, Some(json!({ "id": "1" })));
+    }
+
+    #[test]
+    #[should_panic(expected = "`data.id`: expected type `integer`")]
+    fn test_strict() {
+        #[derive(Serialize)]
+        struct Strict {
+            id: String,
+        }
+
+        DataSchema::new(json!({
+            "type": "object",
+            "properties": { "id": { "type": "integer" } },
+        }))
+        .register::<Strict>();
+
+        CreateJsonResponse::success()
+            .data(Strict { id: "1".to_string() })
+            .create();
+    }
+
+    #[test]
+    fn test_validate() {
+        let schema: DataSchema = DataSchema::new(json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "role": { "enum": ["admin", "user"] },
+                "tags": { "type": "array", "items": { "type": "string" } },
+            },
+            "required": ["id", "name"],
+            "additionalProperties": false,
+        }));
+
+        assert_eq!(
+            schema
+                .validate(&json!({ "id": 1, "name": "jder", "role": "user" })),
+            Err(vec!["`data.name`: unexpected".to_string()])
+        );
+
+        assert_eq!(
+            schema.validate(&json!({
+                "id": 1.5,
+                "role": "guest",
+                "tags": ["a", 1],
+            })),
+            Err(vec![
+                "`data.name`: missing".to_string(),
+                "`data.id`: expected type `integer`, found `number`"
+                    .to_string(),
+                "`data.role`: value `\"guest\"` not in enum".to_string(),
+                "`data.tags[1]`: expected type `string`, found `number`"
+                    .to_string(),
+            ])
+        );
+
+        assert_eq!(
+            schema.validate(&json!({ "id": 1, "name": 0 })).is_err(),
+            true
+        );
+    }
+}
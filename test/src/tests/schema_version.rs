@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        extract::schema_version::SCHEMA_VERSION_HEADER,
+        response::json::{JsonResponse, ResponseError},
+    };
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_current() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/schema_version").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(SCHEMA_VERSION_HEADER), "3");
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(
+            res.data,
+            Some(json!({ "name": "Name", "email": "name@example.com" }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pinned() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/schema_version")
+            .add_header(SCHEMA_VERSION_HEADER, "2")
+            .await;
+
+        assert_eq!(res.header(SCHEMA_VERSION_HEADER), "2");
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(json!({ "name": "Name" })));
+
+        let res: TestResponse = server
+            .get("/schema_version")
+            .add_header(SCHEMA_VERSION_HEADER, "1")
+            .await;
+
+        assert_eq!(res.header(SCHEMA_VERSION_HEADER), "1");
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(json!({ "full_name": "Name" })));
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        for version in ["abc", "-1"] {
+            let res: TestResponse = server
+                .get("/schema_version")
+                .add_header(SCHEMA_VERSION_HEADER, version)
+                .await;
+
+            assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+            let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+            assert_eq!(res.success, false);
+            assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+            assert_eq!(res.errors[0].path, ["header", SCHEMA_VERSION_HEADER]);
+            assert_eq!(
+                res.errors[0].message.as_deref(),
+                Some("Invalid schema version")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unsupported() {
+        let server: TestServer = create_server();
+
+        for version in ["0", "4"] {
+            let res: TestResponse = server
+                .get("/schema_version")
+                .add_header(SCHEMA_VERSION_HEADER, version)
+                .await;
+
+            assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+
+            let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+            assert_eq!(res.success, false);
+            assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+            assert_eq!(
+                res.errors[0].message.as_deref(),
+                Some("Unsupported schema version")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_missing_migrations() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/schema_version/missing").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::security_headers::{
+        SecurityHeaders, SecurityPreset,
+    };
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_api() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/security_headers/api").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(res.header(header::X_CONTENT_TYPE_OPTIONS), "nosniff");
+        assert_eq!(res.header(header::X_FRAME_OPTIONS), "DENY");
+        assert_eq!(
+            res.headers().get_all(header::X_FRAME_OPTIONS).iter().count(),
+            1
+        );
+        assert_eq!(res.header(header::REFERRER_POLICY), "no-referrer");
+        assert_eq!(
+            res.header(header::CONTENT_SECURITY_POLICY),
+            "default-src 'none'; frame-ancestors 'none'"
+        );
+        assert!(res.maybe_header(header::STRICT_TRANSPORT_SECURITY).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_custom() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/security_headers/custom").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            res.header(header::CONTENT_SECURITY_POLICY),
+            "default-src 'self'; img-src *;"
+        );
+        assert_eq!(
+            res.header(header::STRICT_TRANSPORT_SECURITY),
+            "max-age=31536000; includeSubDomains"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/security_headers/invalid").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_REQUEST);
+        assert!(res.maybe_header(header::CONTENT_SECURITY_POLICY).is_none());
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let headers = |policy: &str| {
+            SecurityHeaders::new(SecurityPreset::Api)
+                .content_security_policy(policy)
+                .is_valid()
+        };
+
+        assert!(headers("default-src 'self'"));
+        assert!(headers("Default-Src 'self'; upgrade-insecure-requests"));
+        assert!(!headers(""));
+        assert!(!headers("default-src 'self'; default-src *"));
+        assert!(!headers("scripts-src 'self'"));
+        assert!(!headers("default-src 'self'\n"));
+    }
+}
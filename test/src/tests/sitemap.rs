@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_sitemap() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/sitemap.xml").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/xml; charset=utf-8"
+        );
+        assert_eq!(
+            res.text(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n\
+            <url><loc>https://example.com/</loc><priority>1</priority></url>\n\
+            <url><loc>https://example.com/search?q=a&amp;b</loc><lastmod>2026-01-01</lastmod></url>\n\
+            </urlset>\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sitemap_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/sitemap/invalid").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+        assert_eq!(res.errors[0].path, vec!["sitemap", "1", "loc"]);
+    }
+
+    #[tokio::test]
+    async fn test_robots() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/robots.txt").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(
+            res.text(),
+            "User-agent: *\nAllow: /\nDisallow: /admin\n\n\
+            User-agent: bot\nDisallow: /\n\
+            Sitemap: https://example.com/sitemap.xml\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_robots_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/robots/invalid").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].path, vec!["robots", "0"]);
+    }
+}
@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod test {
+    use axum::http::header;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/size_hint").await;
+
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/json"
+        );
+
+        let res: JsonResponse<Vec<usize>> =
+            res.json::<JsonResponse<Vec<usize>>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data.unwrap().len(), 1000);
+    }
+}
@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod test {
+    use axum_test::TestServer;
+    use jder_axum::response::json::JsonResponse;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<Vec<u64>> =
+            server.post("/spill/10").await.json::<JsonResponse<Vec<u64>>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some((0..10).collect()));
+    }
+
+    #[tokio::test]
+    async fn test_spilled() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<Vec<u64>> =
+            server.post("/spill/100000").await.json::<JsonResponse<Vec<u64>>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some((0..100000).collect()));
+    }
+}
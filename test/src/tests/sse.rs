@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod test {
+    use axum::http::header;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/sse").await;
+
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "text/event-stream"
+        );
+
+        let frames: Vec<String> = res
+            .text()
+            .split("\n\n")
+            .filter(|frame| !frame.is_empty())
+            .map(|frame| frame.to_string())
+            .collect();
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(
+            frames[0],
+            "data: {\"success\":true,\"data\":1,\"errors\":[]}\nevent: count\nid: 1\nretry: 3000"
+        );
+        assert_eq!(
+            frames[1],
+            "data: {\"success\":true,\"data\":2,\"errors\":[]}\nevent: last\nid: 2"
+        );
+
+        let data: &str = frames[2]
+            .lines()
+            .find_map(|line| line.strip_prefix("data: "))
+            .unwrap();
+
+        let failure: JsonResponse = serde_json::from_str(data).unwrap();
+
+        assert_eq!(failure.success, false);
+        assert_eq!(failure.errors[0].code, "done");
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let text: String = server.post("/sse/invalid").await.text();
+
+        let data: &str =
+            text.lines().find_map(|line| line.strip_prefix("data: ")).unwrap();
+
+        let failure: JsonResponse = serde_json::from_str(data).unwrap();
+
+        assert_eq!(failure.success, false);
+        assert_eq!(failure.errors[0].code, ResponseError::Server.as_code());
+        assert_eq!(failure.errors[0].path, vec!["response", "sse", "id"]);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/sse/shutdown").await;
+
+        let frames: Vec<String> = res
+            .text()
+            .split("\n\n")
+            .filter(|frame| !frame.is_empty())
+            .map(|frame| frame.to_string())
+            .collect();
+
+        assert_eq!(frames.len(), 3);
+
+        let data: &str = frames[2]
+            .lines()
+            .find_map(|line| line.strip_prefix("data: "))
+            .unwrap();
+
+        let res: JsonResponse = serde_json::from_str(data).unwrap();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Draining.as_code());
+    }
+}
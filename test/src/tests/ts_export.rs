@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod test {
+    use jder_axum::response::json::{JsonResponse, JsonResponseError};
+    use serde::Serialize;
+    use ts_rs::{Config, TS};
+
+    #[derive(Serialize, TS)]
+    struct User {
+        id: u32,
+        name: Option<String>,
+        tags: Vec<Option<String>>,
+    }
+
+    fn strip_docs(ts: String) -> String {
+        ts.split("\n")
+            .filter(|line| !line.starts_with("/**") && !line.starts_with(" *"))
+            .collect()
+    }
+
+    #[test]
+    fn test() {
+        let cfg: Config = Config::new();
+
+        assert_eq!(
+            strip_docs(JsonResponseError::decl(&cfg)),
+            "type JsonResponseError = { code: string, path: Array<string>, message: string | null, };"
+        );
+        assert_eq!(
+            strip_docs(JsonResponse::<()>::decl(&cfg)),
+            "type JsonResponse<D = null> = { success: boolean, data: D | null, errors: Array<JsonResponseError>, meta?: Record<string, unknown>, };"
+        );
+    }
+
+    #[test]
+    fn test_data() {
+        let cfg: Config = Config::new();
+
+        assert_eq!(JsonResponse::<User>::name(&cfg), "JsonResponse<User>");
+        assert_eq!(
+            User::decl(&cfg),
+            "type User = { id: number, name: string | null, tags: Array<string | null>, };"
+        );
+
+        let names: Vec<String> = JsonResponse::<User>::dependencies(&cfg)
+            .into_iter()
+            .map(|dependency| dependency.ts_name)
+            .collect();
+
+        assert!(names.contains(&"User".to_string()));
+        assert!(names.contains(&"JsonResponseError".to_string()));
+    }
+}
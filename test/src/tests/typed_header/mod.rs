@@ -2,7 +2,7 @@ pub mod optional;
 
 #[cfg(test)]
 mod test {
-    use axum::http::header;
+    use axum::http::{HeaderValue, header};
     use axum_test::TestServer;
     use jder_axum::response::json::{JsonResponse, ResponseError};
 
@@ -36,5 +36,28 @@ mod test {
 
         assert_eq!(res.success, false);
         assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["header", "user-agent"]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: RouteResponse = server
+            .post("/typed_header")
+            .add_header(
+                header::USER_AGENT,
+                HeaderValue::from_bytes("é".as_bytes()).unwrap(),
+            )
+            .await
+            .json::<RouteResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Parse.as_code());
+        assert_eq!(res.errors[0].path, vec!["header", "user-agent"]);
+        assert_eq!(
+            res.errors[0].message,
+            Some("Invalid header `user-agent`".to_string())
+        );
     }
 }
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::{
+        layers::upstream_error::{
+            UPSTREAM_ERROR_REQUEST_ID_HEADER,
+            UPSTREAM_ERROR_REQUEST_ID_META_KEY,
+        },
+        response::json::{JsonResponse, ResponseError},
+    };
+    use serde_json::Value;
+
+    use crate::router::create_server;
+
+    fn get_meta_request_id(res: &JsonResponse<Value>) -> Option<&str> {
+        res.meta
+            .as_ref()
+            .and_then(|meta| meta.get(UPSTREAM_ERROR_REQUEST_ID_META_KEY))
+            .and_then(Value::as_str)
+    }
+
+    #[tokio::test]
+    async fn test_bad_gateway() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/upstream_error/bad_gateway")
+            .add_header(UPSTREAM_ERROR_REQUEST_ID_HEADER, "abc")
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_GATEWAY);
+        assert_eq!(res.header(header::CONTENT_TYPE), "application/json");
+        assert_eq!(res.header(UPSTREAM_ERROR_REQUEST_ID_HEADER), "abc");
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::BadGateway.as_code());
+        assert_eq!(
+            res.errors[0].message.as_deref(),
+            Some(ResponseError::BadGateway.as_message())
+        );
+        assert_eq!(get_meta_request_id(&res), Some("abc"));
+    }
+
+    #[tokio::test]
+    async fn test_unavailable() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/upstream_error/unavailable").await;
+
+        assert_eq!(res.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(res.header(header::RETRY_AFTER), "120");
+        assert_eq!(res.maybe_header(UPSTREAM_ERROR_REQUEST_ID_HEADER), None);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Unavailable.as_code());
+        assert_eq!(get_meta_request_id(&res), None);
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/upstream_error/timeout").await;
+
+        assert_eq!(res.status_code(), StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(res.header(UPSTREAM_ERROR_REQUEST_ID_HEADER), "upstream");
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, ResponseError::Timeout.as_code());
+        assert_eq!(get_meta_request_id(&res), Some("upstream"));
+    }
+
+    #[tokio::test]
+    async fn test_body() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/upstream_error/body").await;
+
+        assert_eq!(res.status_code(), StatusCode::BAD_GATEWAY);
+        assert_eq!(res.text(), "Bad gateway");
+
+        let res: TestResponse = server.get("/upstream_error/failure").await;
+
+        assert_eq!(res.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let res: JsonResponse<Value> = res.json::<JsonResponse<Value>>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors.len(), 0);
+    }
+}
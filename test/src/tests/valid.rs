@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::JsonResponse;
+    use serde_json::json;
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<String> = server
+            .post("/valid")
+            .json(&json!({ "name": "Name", "age": 20 }))
+            .await
+            .json::<JsonResponse<String>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some("Name".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/valid")
+            .json(&json!({ "name": "N", "age": 10 }))
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors.len(), 2);
+
+        assert_eq!(res.errors[0].code, "length");
+        assert_eq!(res.errors[0].path, vec!["name"]);
+
+        assert_eq!(res.errors[1].code, "range");
+        assert_eq!(res.errors[1].path, vec!["age"]);
+    }
+
+    #[tokio::test]
+    async fn test_rejection() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.post("/valid").json(&json!({ "name": "Name" })).await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors[0].code, "parse");
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/valid/query")
+            .add_query_param("name", "Name")
+            .add_query_param("age", 17)
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors.len(), 1);
+        assert_eq!(res.errors[0].path, vec!["age"]);
+
+        let res: JsonResponse<u8> = server
+            .post("/valid/query")
+            .add_query_param("name", "Name")
+            .add_query_param("age", 18)
+            .await
+            .json::<JsonResponse<u8>>();
+
+        assert_eq!(res.data, Some(18));
+    }
+
+    #[tokio::test]
+    async fn test_garde() {
+        let server: TestServer = create_server();
+
+        let res: JsonResponse<usize> = server
+            .post("/valid/garde")
+            .json(&json!({ "name": "Name", "items": [{ "name": "Item" }] }))
+            .await
+            .json::<JsonResponse<usize>>();
+
+        assert_eq!(res.success, true);
+        assert_eq!(res.data, Some(1));
+
+        let res: TestResponse = server
+            .post("/valid/garde")
+            .json(&json!({
+                "name": "N",
+                "items": [{ "name": "Item" }, { "name": "" }],
+            }))
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors.len(), 2);
+
+        assert_eq!(res.errors[0].code, "validation");
+        assert_eq!(res.errors[0].path, vec!["items", "1", "name"]);
+        assert!(res.errors[0].message.is_some());
+
+        assert_eq!(res.errors[1].code, "validation");
+        assert_eq!(res.errors[1].path, vec!["name"]);
+    }
+}
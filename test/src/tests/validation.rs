@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, JsonResponseError};
+    use serde_json::json;
+
+    use crate::router::create_server;
+
+    fn assert_errors(errors: &[JsonResponseError]) {
+        assert_eq!(errors.len(), 2);
+
+        assert_eq!(errors[0].code, "length");
+        assert_eq!(errors[0].path, vec!["json", "name"]);
+        assert_eq!(errors[0].message, Some("Name is too short".to_string()));
+
+        assert_eq!(errors[1].code, "validation");
+        assert_eq!(errors[1].path, vec!["json", "age"]);
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/validation").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+
+        assert_errors(&res.errors);
+    }
+
+    #[tokio::test]
+    async fn test_api() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.post("/validation/api").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+
+        assert_errors(&res.errors);
+    }
+
+    #[tokio::test]
+    async fn test_validator() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .post("/validation/validator")
+            .json(&json!({
+                "name": "Name",
+                "address": { "city": "City" },
+                "items": [{ "quantity": 1 }],
+            }))
+            .await;
+
+        res.assert_status_ok();
+
+        let res: TestResponse = server
+            .post("/validation/validator")
+            .json(&json!({
+                "name": "N",
+                "address": { "city": "" },
+                "items": [{ "quantity": 1 }, { "quantity": 0 }],
+            }))
+            .await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.success, false);
+        assert_eq!(res.errors.len(), 3);
+
+        // nested struct
+        assert_eq!(res.errors[0].code, "length");
+        assert_eq!(res.errors[0].path, vec!["address", "city"]);
+        assert_eq!(res.errors[0].message, Some("City is required".to_string()));
+
+        // list
+        assert_eq!(res.errors[1].code, "range");
+        assert_eq!(res.errors[1].path, vec!["items", "1", "quantity"]);
+        assert_eq!(res.errors[1].message, None);
+
+        assert_eq!(res.errors[2].code, "length");
+        assert_eq!(res.errors[2].path, vec!["name"]);
+        assert_eq!(
+            res.errors[2].message,
+            Some("Name is too short".to_string())
+        );
+    }
+}
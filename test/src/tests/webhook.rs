@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod test {
+    use std::{
+        future::Future,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use axum::{
+        Router,
+        http::{HeaderMap, StatusCode},
+        routing::post,
+    };
+    use jder_axum::response::event::{
+        SignedWebhookEvent, WEBHOOK_DELIVERY_ERROR_CODE,
+        WEBHOOK_SIGNATURE_HEADER, WebhookDelivery, WebhookEvent, WebhookSender,
+        WebhookTransport, verify_signature,
+    };
+    use tokio::net::TcpListener;
+
+    const KEY: &[u8] = b"secret";
+
+    struct FlakyTransport {
+        failures: usize,
+        status: StatusCode,
+        calls: AtomicUsize,
+    }
+
+    impl WebhookTransport for FlakyTransport {
+        fn post(
+            &self,
+            _url: &str,
+            _headers: HeaderMap,
+            _body: String,
+        ) -> impl Future<Output = Result<StatusCode, String>> + Send {
+            let call: usize = self.calls.fetch_add(1, Ordering::SeqCst);
+            let failures: usize = self.failures;
+            let status: StatusCode = self.status;
+
+            async move {
+                if call < failures { Ok(status) } else { Ok(StatusCode::OK) }
+            }
+        }
+    }
+
+    fn create_event() -> WebhookEvent<String> {
+        WebhookEvent::new()
+            .id("evt_1")
+            .event_type("user.created")
+            .data("payload".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_sign() {
+        let signed: SignedWebhookEvent = create_event().sign(KEY).unwrap();
+
+        assert!(
+            verify_signature(
+                &signed.body,
+                &signed.signature,
+                KEY,
+                Duration::from_secs(60)
+            )
+            .is_ok()
+        );
+
+        assert!(
+            verify_signature(
+                &signed.body,
+                &signed.signature,
+                b"other",
+                Duration::from_secs(60)
+            )
+            .is_err()
+        );
+
+        assert!(
+            verify_signature(
+                "{}",
+                &signed.signature,
+                KEY,
+                Duration::from_secs(60)
+            )
+            .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_sha256() {
+        let signed: SignedWebhookEvent =
+            create_event().timestamp(1700000000).sign(KEY).unwrap();
+
+        assert_eq!(
+            signed.body,
+            r#"{"id":"evt_1","type":"user.created","timestamp":1700000000,"data":"payload"}"#
+        );
+        assert_eq!(
+            signed.signature,
+            "t=1700000000,v1=34a82bc24e013805cfe8fddbc419c9ce88b4e90beab1486523ca66a1459c5c55"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_reqwest() {
+        async fn receive(
+            headers: HeaderMap,
+            body: String,
+        ) -> StatusCode {
+            let signature: &str = headers
+                .get(WEBHOOK_SIGNATURE_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default();
+
+            match verify_signature(
+                &body,
+                signature,
+                KEY,
+                Duration::from_secs(60),
+            ) {
+                | Ok(()) => StatusCode::NO_CONTENT,
+                | Err(_) => StatusCode::UNAUTHORIZED,
+            }
+        }
+
+        let listener: TcpListener =
+            TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+        let url: String =
+            format!("http://{}/webhook", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            axum::serve(
+                listener,
+                Router::new().route("/webhook", post(receive)),
+            )
+            .await
+            .unwrap();
+        });
+
+        let sender: WebhookSender<reqwest::Client> =
+            WebhookSender::new(reqwest::Client::new(), KEY);
+
+        let delivery: WebhookDelivery =
+            sender.send(&url, &create_event()).await.unwrap();
+
+        assert_eq!(delivery.status, StatusCode::NO_CONTENT);
+        assert_eq!(delivery.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_retry() {
+        let sender: WebhookSender<FlakyTransport> = WebhookSender::new(
+            FlakyTransport {
+                failures: 2,
+                status: StatusCode::SERVICE_UNAVAILABLE,
+                calls: AtomicUsize::new(0),
+            },
+            KEY,
+        )
+        .backoff(Duration::from_millis(1));
+
+        let delivery: WebhookDelivery =
+            sender.send("http://localhost", &create_event()).await.unwrap();
+
+        assert_eq!(delivery.status, StatusCode::OK);
+        assert_eq!(delivery.attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_send_failure() {
+        let sender: WebhookSender<FlakyTransport> = WebhookSender::new(
+            FlakyTransport {
+                failures: 10,
+                status: StatusCode::BAD_REQUEST,
+                calls: AtomicUsize::new(0),
+            },
+            KEY,
+        )
+        .backoff(Duration::from_millis(1));
+
+        let errors =
+            sender.send("http://localhost", &create_event()).await.unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, WEBHOOK_DELIVERY_ERROR_CODE);
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+    use jder_axum::response::json::{JsonResponse, ResponseError};
+    use serde_json::{Value, json};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test_security_txt() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/wellknown/.well-known/security.txt").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(
+            res.text(),
+            "Contact: mailto:security@example.com\n\
+            Expires: 2027-01-01T00:00:00Z\n\
+            Preferred-Languages: en, ja\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_security_txt_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/wellknown/invalid/.well-known/security.txt").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let res: JsonResponse = res.json::<JsonResponse>();
+
+        assert_eq!(res.errors[0].code, ResponseError::Server.as_code());
+        assert_eq!(
+            res.errors[0].path,
+            vec!["wellknown", "security.txt", "contact"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_change_password() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/wellknown/.well-known/change-password").await;
+
+        assert_eq!(res.status_code(), StatusCode::FOUND);
+        assert_eq!(
+            res.header(header::LOCATION).to_str().unwrap(),
+            "/account/password"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_openid_configuration() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/wellknown/.well-known/openid-configuration").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/json"
+        );
+        assert_eq!(
+            res.json::<Value>(),
+            json!({ "issuer": "https://example.com" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse =
+            server.get("/wellknown/invalid/.well-known/change-password").await;
+
+        assert_eq!(res.status_code(), StatusCode::NOT_FOUND);
+    }
+}
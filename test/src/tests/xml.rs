@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod test {
+    use axum::http::{StatusCode, header};
+    use axum_test::{TestResponse, TestServer};
+
+    use crate::router::create_server;
+
+    #[tokio::test]
+    async fn test() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/xml").await;
+
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/xml"
+        );
+        assert_eq!(
+            res.text(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><response><success>true</success><data><id>1</id><name>&lt;Tom &amp; Jerry&gt;</name><tags>a</tags><tags>b</tags><note/></data></response>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failure() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/xml/failure").await;
+
+        assert_eq!(res.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            res.text(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><response><success>false</success><data/><errors><code>parse</code><path>json</path><path>name</path><message>Invalid name</message></errors></response>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server.get("/xml/invalid").await;
+
+        assert_eq!(res.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/xml"
+        );
+        assert_eq!(
+            res.text(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><response><success>false</success><data/><errors><code>server</code><message>Internal server error.</message></errors></response>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_negotiated() {
+        let server: TestServer = create_server();
+
+        let res: TestResponse = server
+            .get("/xml/negotiated")
+            .add_header(header::ACCEPT, "text/xml")
+            .await;
+
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/xml"
+        );
+
+        let res: TestResponse = server
+            .get("/xml/negotiated")
+            .add_header(header::ACCEPT, "application/json, application/xml")
+            .await;
+
+        assert_eq!(
+            res.header(header::CONTENT_TYPE).to_str().unwrap(),
+            "application/json"
+        );
+    }
+}